@@ -0,0 +1,109 @@
+/// Error returned when a `${ENV_VAR}` reference in a config value cannot be expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvVarError {
+    /// The referenced environment variable is not set (or is not valid UTF-8)
+    Missing {
+        /// Name of the missing variable
+        name: String,
+        /// Dotted path of the config value that references it (e.g. "hub_chain.rpc_url")
+        field: String,
+    },
+    /// A `${` with no closing `}`
+    Unterminated {
+        /// Dotted path of the config value
+        field: String,
+    },
+}
+
+impl std::fmt::Display for EnvVarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvVarError::Missing { name, field } => write!(
+                f,
+                "environment variable '{}' referenced by {} is not set",
+                name, field
+            ),
+            EnvVarError::Unterminated { field } => {
+                write!(f, "unterminated '${{' in {}", field)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnvVarError {}
+
+/// Expands `${ENV_VAR}` references in a single string value.
+///
+/// Text outside `${...}` is kept as is, so `"http://${HOST}:8080"` expands to
+/// `"http://127.0.0.1:8080"` when `HOST=127.0.0.1`. A `$` not followed by `{` is
+/// literal.
+///
+/// # Arguments
+///
+/// * `value` - The string to expand
+/// * `field` - Dotted path of the value, used in error messages
+///
+/// # Returns
+///
+/// The expanded string, or an error naming the first missing variable
+pub fn expand_env_vars(value: &str, field: &str) -> Result<String, EnvVarError> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| EnvVarError::Unterminated {
+            field: field.to_string(),
+        })?;
+        let name = &after[..end];
+        let var = std::env::var(name).map_err(|_| EnvVarError::Missing {
+            name: name.to_string(),
+            field: field.to_string(),
+        })?;
+        expanded.push_str(&var);
+        rest = &after[end + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+/// Expands `${ENV_VAR}` references in every string of a parsed TOML document.
+///
+/// Walks nested tables and arrays; keys and non-string values are left alone.
+/// Comments never reach the parsed value, so references inside them are ignored.
+///
+/// # Arguments
+///
+/// * `value` - The parsed document, expanded in place
+///
+/// # Returns
+///
+/// `Ok(())` when every reference was expanded, or an error naming the first missing variable
+pub fn expand_env_vars_in_toml(value: &mut toml::Value) -> Result<(), EnvVarError> {
+    expand_value(value, "")
+}
+
+fn expand_value(value: &mut toml::Value, field: &str) -> Result<(), EnvVarError> {
+    match value {
+        toml::Value::String(s) if s.contains("${") => {
+            *s = expand_env_vars(s, field)?;
+        }
+        toml::Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                expand_value(item, &format!("{}[{}]", field, i))?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (key, item) in table.iter_mut() {
+                let path = if field.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", field, key)
+                };
+                expand_value(item, &path)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
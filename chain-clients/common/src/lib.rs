@@ -1,3 +1,7 @@
+pub mod env_vars;
 pub mod intent_id;
+pub mod logging;
 
+pub use env_vars::{expand_env_vars, expand_env_vars_in_toml, EnvVarError};
 pub use intent_id::{normalize_intent_id, normalize_intent_id_to_64_chars};
+pub use logging::{init_logging, log_json_requested, with_service_field, LOG_FORMAT_ENV};
@@ -0,0 +1,90 @@
+//! Shared `tracing` subscriber setup for the service binaries.
+//!
+//! Human-readable output is the default. `LOG_FORMAT=json` (or a binary's `--log-json`
+//! flag) switches to one JSON object per line for log aggregation. Every JSON line
+//! carries a `service` field, and the fields of the enclosing spans (e.g. `intent_id`,
+//! `chain`, `nonce` on relay deliveries) under `span`/`spans`. `RUST_LOG` filters
+//! both formats.
+
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::format::{FormatEvent, FormatFields, Writer};
+use tracing_subscriber::fmt::FmtContext;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable selecting the log format (`json` or anything else for human output).
+pub const LOG_FORMAT_ENV: &str = "LOG_FORMAT";
+
+/// Returns whether JSON logs were requested by the `--log-json` flag or `LOG_FORMAT`.
+///
+/// # Arguments
+///
+/// * `log_json_flag` - Whether the binary was started with `--log-json`
+/// * `log_format` - Value of `LOG_FORMAT`, if set (matched case-insensitively)
+pub fn log_json_requested(log_json_flag: bool, log_format: Option<&str>) -> bool {
+    log_json_flag || log_format.is_some_and(|format| format.trim().eq_ignore_ascii_case("json"))
+}
+
+/// Inserts a `service` field at the start of a JSON log line.
+///
+/// Lines that are not JSON objects are returned unchanged.
+pub fn with_service_field(json_line: &str, service: &str) -> String {
+    match json_line.strip_prefix('{') {
+        Some(rest) if rest.trim_start().starts_with('}') => {
+            format!("{{\"service\":{:?}{}", service, rest)
+        }
+        Some(rest) => format!("{{\"service\":{:?},{}", service, rest),
+        None => json_line.to_string(),
+    }
+}
+
+/// Installs the global `tracing` subscriber for a service binary.
+///
+/// Must be called once, before the first log line. Human format behaves exactly like
+/// `tracing_subscriber::fmt::init()`.
+///
+/// # Arguments
+///
+/// * `service` - Service name added to every JSON line (e.g. "coordinator")
+/// * `log_json_flag` - Whether the binary was started with `--log-json`
+pub fn init_logging(service: &'static str, log_json_flag: bool) {
+    let log_format = std::env::var(LOG_FORMAT_ENV).ok();
+    if !log_json_requested(log_json_flag, log_format.as_deref()) {
+        tracing_subscriber::fmt::init();
+        return;
+    }
+
+    let json_format = tracing_subscriber::fmt::format().json();
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .fmt_fields(tracing_subscriber::fmt::format::JsonFields::new())
+        .event_format(ServiceJsonFormat {
+            service,
+            inner: json_format,
+        })
+        .init();
+}
+
+/// JSON event format that prefixes every line with the service name.
+struct ServiceJsonFormat<F> {
+    service: &'static str,
+    inner: F,
+}
+
+impl<S, N, F> FormatEvent<S, N> for ServiceJsonFormat<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+    F: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> std::fmt::Result {
+        let mut line = String::new();
+        self.inner.format_event(ctx, Writer::new(&mut line), event)?;
+        writer.write_str(&with_service_field(&line, self.service))
+    }
+}
@@ -0,0 +1,104 @@
+use chain_clients_common::{expand_env_vars, expand_env_vars_in_toml, EnvVarError};
+
+// ============================================================================
+// EXPAND_ENV_VARS TESTS
+// ============================================================================
+
+/// 1. Test: expand_env_vars substitutes set variables
+/// Verifies that `${VAR}` references are replaced and surrounding text is kept.
+/// Why: Operators write values like `rpc_url = "http://${HOST}:8080"` per deployment.
+#[test]
+fn test_expand_env_vars_substitutes_present_variables() {
+    std::env::set_var("ENV_VARS_TEST_HOST", "127.0.0.1");
+    std::env::set_var("ENV_VARS_TEST_PORT", "8080");
+
+    let expanded =
+        expand_env_vars("http://${ENV_VARS_TEST_HOST}:${ENV_VARS_TEST_PORT}/v1", "rpc_url").unwrap();
+
+    assert_eq!(expanded, "http://127.0.0.1:8080/v1");
+    assert_eq!(expand_env_vars("no references, $5", "name").unwrap(), "no references, $5");
+}
+
+/// 2. Test: expand_env_vars fails on a missing or unterminated reference
+/// Verifies that the error names the missing variable and the referencing field.
+/// Why: A silently empty RPC URL would only surface as a confusing connection error later.
+#[test]
+fn test_expand_env_vars_missing_variable_is_an_error() {
+    std::env::remove_var("ENV_VARS_TEST_MISSING");
+
+    let err = expand_env_vars("${ENV_VARS_TEST_MISSING}", "hub_chain.rpc_url").unwrap_err();
+    assert_eq!(
+        err,
+        EnvVarError::Missing {
+            name: "ENV_VARS_TEST_MISSING".to_string(),
+            field: "hub_chain.rpc_url".to_string(),
+        }
+    );
+    assert!(err.to_string().contains("ENV_VARS_TEST_MISSING"));
+    assert!(err.to_string().contains("hub_chain.rpc_url"));
+
+    let err = expand_env_vars("http://${ENV_VARS_TEST_HOST", "hub_chain.rpc_url").unwrap_err();
+    assert!(matches!(err, EnvVarError::Unterminated { .. }));
+}
+
+// ============================================================================
+// EXPAND_ENV_VARS_IN_TOML TESTS
+// ============================================================================
+
+/// 3. Test: expand_env_vars_in_toml expands strings in nested tables and arrays
+/// Verifies that strings at any depth are expanded while other values and comments are untouched.
+/// Why: Chain URLs and addresses live in nested tables and arrays of tables.
+#[test]
+fn test_expand_env_vars_in_toml_expands_nested_values() {
+    std::env::set_var("ENV_VARS_TEST_HUB_RPC", "http://hub:8080");
+    std::env::set_var("ENV_VARS_TEST_EVM_ADDR", "0xabc");
+    let mut value: toml::Value = toml::from_str(
+        r#"
+# comment mentioning ${ENV_VARS_TEST_NOT_SET}
+[hub_chain]
+rpc_url = "${ENV_VARS_TEST_HUB_RPC}"
+chain_id = 1
+
+[[connected_chain_evm]]
+escrow_contract_addr = "${ENV_VARS_TEST_EVM_ADDR}"
+tags = ["a", "${ENV_VARS_TEST_EVM_ADDR}"]
+"#,
+    )
+    .unwrap();
+
+    expand_env_vars_in_toml(&mut value).unwrap();
+
+    assert_eq!(value["hub_chain"]["rpc_url"].as_str(), Some("http://hub:8080"));
+    assert_eq!(value["hub_chain"]["chain_id"].as_integer(), Some(1));
+    let evm = &value["connected_chain_evm"][0];
+    assert_eq!(evm["escrow_contract_addr"].as_str(), Some("0xabc"));
+    assert_eq!(evm["tags"][1].as_str(), Some("0xabc"));
+}
+
+/// 4. Test: expand_env_vars_in_toml reports the path of a nested missing reference
+/// Verifies that the error names the dotted path, including array indices.
+/// Why: Operators need to know which of several chain entries is misconfigured.
+#[test]
+fn test_expand_env_vars_in_toml_missing_nested_variable() {
+    std::env::remove_var("ENV_VARS_TEST_SVM_RPC");
+    let mut value: toml::Value = toml::from_str(
+        r#"
+[[connected_chain_svm]]
+rpc_url = "http://ok"
+
+[[connected_chain_svm]]
+rpc_url = "${ENV_VARS_TEST_SVM_RPC}"
+"#,
+    )
+    .unwrap();
+
+    let err = expand_env_vars_in_toml(&mut value).unwrap_err();
+
+    assert_eq!(
+        err,
+        EnvVarError::Missing {
+            name: "ENV_VARS_TEST_SVM_RPC".to_string(),
+            field: "connected_chain_svm[1].rpc_url".to_string(),
+        }
+    );
+}
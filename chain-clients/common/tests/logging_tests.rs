@@ -0,0 +1,49 @@
+use chain_clients_common::{log_json_requested, with_service_field};
+
+// ============================================================================
+// LOG FORMAT SELECTION TESTS
+// ============================================================================
+
+/// 1. Test: JSON logs are off by default
+/// Verifies that neither an unset LOG_FORMAT nor another value selects JSON.
+/// Why: Human-readable output must stay the default for local runs.
+#[test]
+fn test_log_json_requested_defaults_to_human() {
+    assert!(!log_json_requested(false, None));
+    assert!(!log_json_requested(false, Some("text")));
+    assert!(!log_json_requested(false, Some("")));
+}
+
+/// 2. Test: LOG_FORMAT=json or --log-json select JSON
+/// Verifies that either switch enables JSON, and that the env value is case-insensitive.
+/// Why: Deployments set the env var; local debugging uses the flag.
+#[test]
+fn test_log_json_requested_by_env_or_flag() {
+    assert!(log_json_requested(true, None));
+    assert!(log_json_requested(false, Some("json")));
+    assert!(log_json_requested(false, Some(" JSON ")));
+    assert!(log_json_requested(true, Some("text")));
+}
+
+// ============================================================================
+// SERVICE FIELD TESTS
+// ============================================================================
+
+/// 3. Test: with_service_field prefixes JSON objects with the service name
+/// Verifies that the field is inserted before the existing fields, and into empty objects.
+/// Why: Aggregators filter by service; every JSON line must carry it and stay valid JSON.
+#[test]
+fn test_with_service_field_inserts_field() {
+    let line = with_service_field("{\"level\":\"INFO\",\"fields\":{}}\n", "coordinator");
+    assert_eq!(line, "{\"service\":\"coordinator\",\"level\":\"INFO\",\"fields\":{}}\n");
+
+    assert_eq!(with_service_field("{}", "solver"), "{\"service\":\"solver\"}");
+}
+
+/// 4. Test: with_service_field leaves non-object lines unchanged
+/// Verifies that input not starting with `{` is passed through.
+/// Why: A formatter change must not corrupt output, even if it drops the service field.
+#[test]
+fn test_with_service_field_passes_through_non_json() {
+    assert_eq!(with_service_field("plain text", "solver"), "plain text");
+}
@@ -0,0 +1,388 @@
+//! ABI decoding of EVM event logs
+//!
+//! An [`EventDecoder`] is declared once per event from its signature and named
+//! parameters, then decodes indexed parameters from the log topics and the rest
+//! from the log data. Dynamic `bytes`/`string` data is located through its head
+//! offset, so encoders that do not place it right after the head still decode.
+
+use anyhow::{bail, Context, Result};
+use sha3::{Digest, Keccak256};
+
+use crate::types::EvmLog;
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+/// Solidity type of an event parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiType {
+    /// `address`
+    Address,
+    /// `bool`
+    Bool,
+    /// `uintN` with N bits (8..=256)
+    Uint(u16),
+    /// `bytesN` with N bytes (1..=32)
+    FixedBytes(u8),
+    /// Dynamic `bytes`
+    Bytes,
+    /// Dynamic `string`
+    String,
+}
+
+impl AbiType {
+    /// Parse a canonical Solidity type name such as `uint64` or `bytes32`.
+    pub fn parse(name: &str) -> Result<Self> {
+        let kind = match name {
+            "address" => AbiType::Address,
+            "bool" => AbiType::Bool,
+            "bytes" => AbiType::Bytes,
+            "string" => AbiType::String,
+            _ => {
+                if let Some(bits) = name.strip_prefix("uint") {
+                    let bits: u16 = bits
+                        .parse()
+                        .with_context(|| format!("Invalid ABI type '{}'", name))?;
+                    if bits == 0 || bits > 256 || bits % 8 != 0 {
+                        bail!("Invalid ABI type '{}'", name);
+                    }
+                    AbiType::Uint(bits)
+                } else if let Some(len) = name.strip_prefix("bytes") {
+                    let len: u8 = len
+                        .parse()
+                        .with_context(|| format!("Invalid ABI type '{}'", name))?;
+                    if len == 0 || len > 32 {
+                        bail!("Invalid ABI type '{}'", name);
+                    }
+                    AbiType::FixedBytes(len)
+                } else {
+                    bail!("Unsupported ABI type '{}'", name);
+                }
+            }
+        };
+        Ok(kind)
+    }
+
+    /// Whether values of this type are stored after the head (`bytes`, `string`).
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, AbiType::Bytes | AbiType::String)
+    }
+}
+
+/// Decoded value of one event parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiValue {
+    /// 0x-prefixed, lowercase, 20-byte address
+    Address(String),
+    Bool(bool),
+    /// Unsigned integer; values above `u128::MAX` are rejected when decoding
+    Uint(u128),
+    /// `bytesN` value, or the keccak256 hash stored in the topic for an indexed
+    /// `bytes`/`string` parameter
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    String(String),
+}
+
+/// One declared event parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventParam {
+    /// Parameter name, used to look up the decoded value
+    pub name: String,
+    /// Parameter type
+    pub kind: AbiType,
+    /// Whether the parameter is in the topics instead of the data
+    pub indexed: bool,
+}
+
+// ============================================================================
+// DECODER
+// ============================================================================
+
+/// Decoder for the logs of one event.
+#[derive(Debug, Clone)]
+pub struct EventDecoder {
+    signature: String,
+    topic: String,
+    params: Vec<EventParam>,
+}
+
+impl EventDecoder {
+    /// Declare an event from its canonical signature and its parameters in order.
+    ///
+    /// Each parameter is `(name, type)`, where the type is written as in Solidity,
+    /// with ` indexed` for topic parameters (e.g. `("intentId", "bytes32 indexed")`).
+    /// The types must match the signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Canonical signature, e.g. `MessageSent(uint32,bytes32,bytes,uint64)`
+    /// * `params` - Parameter names and types in declaration order
+    pub fn new(signature: &str, params: &[(&str, &str)]) -> Result<Self> {
+        let types = signature
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .map(|(_, types)| types)
+            .with_context(|| format!("Invalid event signature '{}'", signature))?;
+        let signature_types: Vec<&str> = if types.is_empty() {
+            Vec::new()
+        } else {
+            types.split(',').collect()
+        };
+        if signature_types.len() != params.len() {
+            bail!(
+                "Event signature '{}' has {} parameters, {} declared",
+                signature,
+                signature_types.len(),
+                params.len()
+            );
+        }
+
+        let mut decoded = Vec::with_capacity(params.len());
+        for ((name, declared), expected) in params.iter().zip(&signature_types) {
+            let mut words = declared.split_whitespace();
+            let type_name = words.next().unwrap_or_default();
+            let indexed = match words.next() {
+                None => false,
+                Some("indexed") if words.next().is_none() => true,
+                Some(_) => bail!("Invalid parameter type '{}' for '{}'", declared, name),
+            };
+            if type_name != *expected {
+                bail!(
+                    "Parameter '{}' is declared as '{}' but the signature has '{}'",
+                    name,
+                    type_name,
+                    expected
+                );
+            }
+            decoded.push(EventParam {
+                name: name.to_string(),
+                kind: AbiType::parse(type_name)?,
+                indexed,
+            });
+        }
+        if decoded.iter().filter(|p| p.indexed).count() > 3 {
+            bail!("Event '{}' has more than 3 indexed parameters", signature);
+        }
+
+        Ok(Self {
+            signature: signature.to_string(),
+            topic: event_topic(signature),
+            params: decoded,
+        })
+    }
+
+    /// Canonical event signature.
+    pub fn signature(&self) -> &str {
+        &self.signature
+    }
+
+    /// 0x-prefixed keccak256 of the signature (`topics[0]` of matching logs).
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Declared parameters in order.
+    pub fn params(&self) -> &[EventParam] {
+        &self.params
+    }
+
+    /// Decode a log of this event.
+    ///
+    /// Fails if `topics[0]` is a different event, the topic count does not match
+    /// the indexed parameters, or the data is truncated or malformed.
+    pub fn decode(&self, log: &EvmLog) -> Result<DecodedEvent> {
+        let first = log
+            .topics
+            .first()
+            .with_context(|| format!("Log has no topics, expected {}", self.signature))?;
+        if !first.eq_ignore_ascii_case(&self.topic) {
+            bail!("Log topic {} is not {}", first, self.signature);
+        }
+        let indexed_count = self.params.iter().filter(|p| p.indexed).count();
+        if log.topics.len() != indexed_count + 1 {
+            bail!(
+                "{} expects {} topics, log has {}",
+                self.signature,
+                indexed_count + 1,
+                log.topics.len()
+            );
+        }
+
+        let data = decode_hex(&log.data).context("Invalid log data hex")?;
+        let mut topics = log.topics[1..].iter();
+        let mut head_index = 0usize;
+        let mut fields = Vec::with_capacity(self.params.len());
+
+        for param in &self.params {
+            let value = if param.indexed {
+                let topic = topics.next().expect("topic count checked above");
+                let word = decode_word(topic)
+                    .with_context(|| format!("Invalid topic for '{}'", param.name))?;
+                if param.kind.is_dynamic() {
+                    // Indexed dynamic values are stored as their hash
+                    AbiValue::FixedBytes(word.to_vec())
+                } else {
+                    decode_static(param.kind, &word)
+                        .with_context(|| format!("Invalid value for '{}'", param.name))?
+                }
+            } else {
+                let word = read_word(&data, head_index * 32)
+                    .with_context(|| format!("Log data too short for '{}'", param.name))?;
+                head_index += 1;
+                if param.kind.is_dynamic() {
+                    decode_dynamic(param.kind, &data, &word)
+                        .with_context(|| format!("Invalid value for '{}'", param.name))?
+                } else {
+                    decode_static(param.kind, &word)
+                        .with_context(|| format!("Invalid value for '{}'", param.name))?
+                }
+            };
+            fields.push((param.name.clone(), value));
+        }
+
+        Ok(DecodedEvent { fields })
+    }
+}
+
+/// Values decoded from one log, in declaration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// `(name, value)` per declared parameter
+    pub fields: Vec<(String, AbiValue)>,
+}
+
+impl DecodedEvent {
+    /// Value of the parameter called `name`.
+    pub fn get(&self, name: &str) -> Result<&AbiValue> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+            .with_context(|| format!("Event has no parameter '{}'", name))
+    }
+
+    /// Unsigned integer parameter as u128.
+    pub fn uint(&self, name: &str) -> Result<u128> {
+        match self.get(name)? {
+            AbiValue::Uint(value) => Ok(*value),
+            other => bail!("Parameter '{}' is not a uint: {:?}", name, other),
+        }
+    }
+
+    /// Unsigned integer parameter as u64.
+    pub fn u64(&self, name: &str) -> Result<u64> {
+        let value = self.uint(name)?;
+        u64::try_from(value).with_context(|| format!("Parameter '{}' exceeds u64: {}", name, value))
+    }
+
+    /// Address parameter as a 0x-prefixed, lowercase, 20-byte hex string.
+    pub fn address(&self, name: &str) -> Result<String> {
+        match self.get(name)? {
+            AbiValue::Address(value) => Ok(value.clone()),
+            other => bail!("Parameter '{}' is not an address: {:?}", name, other),
+        }
+    }
+
+    /// `bytesN` or `bytes` parameter as raw bytes.
+    pub fn bytes(&self, name: &str) -> Result<Vec<u8>> {
+        match self.get(name)? {
+            AbiValue::FixedBytes(value) | AbiValue::Bytes(value) => Ok(value.clone()),
+            other => bail!("Parameter '{}' is not bytes: {:?}", name, other),
+        }
+    }
+
+    /// `bytesN` or `bytes` parameter as a 0x-prefixed hex string.
+    pub fn hex(&self, name: &str) -> Result<String> {
+        Ok(format!("0x{}", hex::encode(self.bytes(name)?)))
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Compute the 0x-prefixed keccak256 topic hash of an event signature.
+pub fn event_topic(signature: &str) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(signature.as_bytes());
+    format!("0x{}", hex::encode(hasher.finalize()))
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>> {
+    let clean = value.strip_prefix("0x").unwrap_or(value);
+    Ok(hex::decode(clean)?)
+}
+
+fn decode_word(value: &str) -> Result<[u8; 32]> {
+    let bytes = decode_hex(value)?;
+    <[u8; 32]>::try_from(bytes.as_slice())
+        .map_err(|_| anyhow::anyhow!("Expected 32 bytes, got {}", bytes.len()))
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<[u8; 32]> {
+    let end = offset.checked_add(32).context("Offset overflow")?;
+    let slice = data
+        .get(offset..end)
+        .with_context(|| format!("Need {} bytes, data has {}", end, data.len()))?;
+    Ok(<[u8; 32]>::try_from(slice).expect("slice is 32 bytes"))
+}
+
+/// Interpret a word as an unsigned integer that must fit in `max_bytes` bytes.
+fn word_to_uint(word: &[u8; 32], max_bytes: usize) -> Result<u128> {
+    let used = 32 - max_bytes.min(16);
+    if word[..used].iter().any(|b| *b != 0) {
+        bail!("Value 0x{} is out of range", hex::encode(word));
+    }
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&word[16..]);
+    Ok(u128::from_be_bytes(bytes))
+}
+
+fn decode_static(kind: AbiType, word: &[u8; 32]) -> Result<AbiValue> {
+    let value = match kind {
+        AbiType::Address => {
+            if word[..12].iter().any(|b| *b != 0) {
+                bail!("Address word has nonzero padding: 0x{}", hex::encode(word));
+            }
+            AbiValue::Address(format!("0x{}", hex::encode(&word[12..])))
+        }
+        AbiType::Bool => match word_to_uint(word, 1)? {
+            0 => AbiValue::Bool(false),
+            1 => AbiValue::Bool(true),
+            other => bail!("Invalid bool value {}", other),
+        },
+        AbiType::Uint(bits) => AbiValue::Uint(word_to_uint(word, bits as usize / 8)?),
+        AbiType::FixedBytes(len) => AbiValue::FixedBytes(word[..len as usize].to_vec()),
+        AbiType::Bytes | AbiType::String => bail!("{:?} is not a static type", kind),
+    };
+    Ok(value)
+}
+
+/// Decode a dynamic value whose head word (`offset`) points into `data`.
+fn decode_dynamic(kind: AbiType, data: &[u8], offset: &[u8; 32]) -> Result<AbiValue> {
+    let offset = usize::try_from(word_to_uint(offset, 8)?).context("Offset exceeds usize")?;
+    let len_word = read_word(data, offset).context("Length word out of bounds")?;
+    let len = usize::try_from(word_to_uint(&len_word, 8)?).context("Length exceeds usize")?;
+    let start = offset + 32;
+    let end = start.checked_add(len).context("Length overflow")?;
+    let bytes = data
+        .get(start..end)
+        .with_context(|| {
+            format!(
+                "Value of {} bytes at offset {} exceeds data of {} bytes",
+                len,
+                offset,
+                data.len()
+            )
+        })?
+        .to_vec();
+    match kind {
+        AbiType::Bytes => Ok(AbiValue::Bytes(bytes)),
+        AbiType::String => Ok(AbiValue::String(
+            String::from_utf8(bytes).context("String is not valid UTF-8")?,
+        )),
+        _ => bail!("{:?} is not a dynamic type", kind),
+    }
+}
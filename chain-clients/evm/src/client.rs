@@ -8,7 +8,8 @@ use reqwest::Client;
 use sha3::{Digest, Keccak256};
 use std::time::Duration;
 
-use crate::types::{EscrowCreatedEvent, EvmLog};
+use crate::abi::EventDecoder;
+use crate::types::{EscrowCreatedEvent, EvmEscrow, EvmFeeHistory, EvmLog};
 
 /// Client for communicating with EVM-compatible blockchain nodes via JSON-RPC
 pub struct EvmClient {
@@ -133,6 +134,21 @@ impl EvmClient {
         u64::from_str_radix(clean, 16).context("Failed to parse block number")
     }
 
+    /// Gets the hash of the canonical block at `block_number` via eth_getBlockByNumber.
+    /// Returns None if the node has no block at that height.
+    pub async fn get_block_hash(&self, block_number: u64) -> Result<Option<String>> {
+        let block: Option<serde_json::Value> = self
+            .json_rpc(
+                "eth_getBlockByNumber",
+                vec![
+                    serde_json::json!(format!("0x{:x}", block_number)),
+                    serde_json::json!(false),
+                ],
+            )
+            .await?;
+        Ok(block.and_then(|block| block.get("hash")?.as_str().map(str::to_string)))
+    }
+
     /// Generic eth_call to a contract.
     pub async fn eth_call(&self, to: &str, data: &str) -> Result<String> {
         self.json_rpc(
@@ -145,6 +161,18 @@ impl EvmClient {
         .await
     }
 
+    /// Estimate the gas used by a call from `from` to `to` with `data`.
+    pub async fn estimate_gas(&self, from: &str, to: &str, data: &str) -> Result<u64> {
+        let hex: String = self
+            .json_rpc(
+                "eth_estimateGas",
+                vec![serde_json::json!({ "from": from, "to": to, "data": data })],
+            )
+            .await?;
+        let clean = hex.strip_prefix("0x").unwrap_or(&hex);
+        u64::from_str_radix(clean, 16).context("Failed to parse gas estimate")
+    }
+
     /// Query event logs via eth_getLogs.
     pub async fn get_logs(&self, filter: serde_json::Value) -> Result<Vec<EvmLog>> {
         self.json_rpc("eth_getLogs", vec![filter]).await
@@ -169,6 +197,24 @@ impl EvmClient {
         u64::from_str_radix(clean, 16).context("Failed to parse gas price")
     }
 
+    /// Get base fees and priority fees (at `reward_percentiles`) for the last
+    /// `block_count` blocks, for pricing EIP-1559 transactions.
+    pub async fn fee_history(
+        &self,
+        block_count: u64,
+        reward_percentiles: &[f64],
+    ) -> Result<EvmFeeHistory> {
+        self.json_rpc(
+            "eth_feeHistory",
+            vec![
+                serde_json::json!(format!("0x{:x}", block_count)),
+                serde_json::json!("latest"),
+                serde_json::json!(reward_percentiles),
+            ],
+        )
+        .await
+    }
+
     /// Broadcast a signed raw transaction, returns the transaction hash.
     pub async fn send_raw_transaction(&self, raw_tx: &str) -> Result<String> {
         self.json_rpc(
@@ -200,12 +246,7 @@ impl EvmClient {
         from_block: Option<u64>,
         to_block: Option<u64>,
     ) -> Result<Vec<EscrowCreatedEvent>> {
-        let signature_string =
-            "EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)";
-        let mut hasher = Keccak256::new();
-        hasher.update(signature_string.as_bytes());
-        let hash = hasher.finalize();
-        let event_signature = format!("0x{}", hex::encode(hash));
+        let decoder = escrow_created_decoder();
 
         let from_block_str = from_block
             .map(|n| format!("0x{:x}", n))
@@ -216,7 +257,7 @@ impl EvmClient {
 
         let filter = serde_json::json!({
             "address": self.escrow_contract_addr,
-            "topics": [event_signature],
+            "topics": [decoder.topic()],
             "fromBlock": from_block_str,
             "toBlock": to_block_str,
         });
@@ -225,34 +266,26 @@ impl EvmClient {
         let mut events = Vec::new();
 
         for log in logs {
-            if log.topics.len() < 4 {
-                continue;
-            }
-
-            let intent_id = log.topics[1].clone();
-            let requester_addr = format!("0x{}", &log.topics[2][26..]);
-            let token_addr = format!("0x{}", &log.topics[3][26..]);
-
-            let data = log.data.strip_prefix("0x").unwrap_or(&log.data);
-            if data.len() < 256 {
-                continue;
-            }
-
-            let escrow_id = format!("0x{}", &data[0..64]);
-            let amount = u64::from_str_radix(&data[112..128], 16)
-                .context("Failed to parse escrow amount from EVM log data")?;
-            let reserved_solver = format!("0x{}", &data[128..192]);
-            let expiry = u64::from_str_radix(&data[240..256], 16)
-                .context("Failed to parse escrow expiry from EVM log data")?;
+            let decoded = match decoder.decode(&log) {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping malformed EscrowCreated log in tx {}: {:#}",
+                        log.transaction_hash,
+                        e
+                    );
+                    continue;
+                }
+            };
 
             events.push(EscrowCreatedEvent {
-                intent_id,
-                escrow_id,
-                requester_addr,
-                amount,
-                token_addr,
-                reserved_solver,
-                expiry,
+                intent_id: decoded.hex("intentId")?,
+                escrow_id: decoded.hex("escrowId")?,
+                requester_addr: decoded.address("requester")?,
+                amount: decoded.u64("amount")?,
+                token_addr: decoded.address("token")?,
+                reserved_solver: decoded.hex("reservedSolver")?,
+                expiry: decoded.u64("expiry")?,
                 block_number: log.block_number,
                 transaction_hash: log.transaction_hash,
             });
@@ -345,6 +378,54 @@ impl EvmClient {
         let clean = result.strip_prefix("0x").unwrap_or(&result);
         Ok(clean.ends_with('1'))
     }
+
+    /// Reads the inflow escrow for an intent via eth_call getEscrow(bytes32)
+    ///
+    /// Returns `None` when no escrow exists (the contract returns a zeroed struct).
+    pub async fn get_escrow(&self, intent_id: &str) -> Result<Option<EvmEscrow>> {
+        // Function selector: keccak256("getEscrow(bytes32)")[0:4]
+        let mut hasher = Keccak256::new();
+        hasher.update(b"getEscrow(bytes32)");
+        let hash = hasher.finalize();
+        let selector = hex::encode(&hash[..4]);
+
+        let intent_id_clean = intent_id.strip_prefix("0x").unwrap_or(intent_id);
+        let intent_id_padded = format!("{:0>64}", intent_id_clean);
+        let calldata = format!("0x{}{}", selector, intent_id_padded);
+
+        let result: String = self.eth_call(&self.escrow_contract_addr.clone(), &calldata).await
+            .context("Failed eth_call for getEscrow")?;
+
+        // ABI-encoded StoredEscrow: 7 static words of 64 hex chars each
+        // (escrowId, creatorAddr, amount, token, solverAddr, fulfilled, released)
+        let clean = result.strip_prefix("0x").unwrap_or(&result);
+        if clean.len() < 7 * 64 {
+            anyhow::bail!(
+                "getEscrow returned {} hex chars, expected at least {}",
+                clean.len(),
+                7 * 64
+            );
+        }
+        let word = |i: usize| &clean[i * 64..(i + 1) * 64];
+
+        // escrowId is keccak256(intentId, requester) for every stored escrow
+        if word(0).chars().all(|c| c == '0') {
+            return Ok(None);
+        }
+
+        let amount = u64::from_str_radix(&word(2)[48..], 16)
+            .context("Failed to parse escrow amount from getEscrow result")?;
+
+        Ok(Some(EvmEscrow {
+            escrow_id: format!("0x{}", word(0)),
+            creator_addr: format!("0x{}", word(1)),
+            amount,
+            token_addr: format!("0x{}", &word(3)[24..]),
+            solver_addr: format!("0x{}", word(4)),
+            fulfilled: word(5).ends_with('1'),
+            released: word(6).ends_with('1'),
+        }))
+    }
 }
 
 /// Normalize an EVM address that may be 32-byte padded (for Move compatibility) to 20 bytes.
@@ -369,3 +450,20 @@ pub fn normalize_evm_address(addr: &str) -> Result<String> {
     }
     Ok(format!("0x{}", clean))
 }
+
+/// Decoder for `EscrowCreated` logs of the inflow escrow contract.
+fn escrow_created_decoder() -> EventDecoder {
+    EventDecoder::new(
+        "EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)",
+        &[
+            ("intentId", "bytes32 indexed"),
+            ("escrowId", "bytes32"),
+            ("requester", "address indexed"),
+            ("amount", "uint64"),
+            ("token", "address indexed"),
+            ("reservedSolver", "bytes32"),
+            ("expiry", "uint64"),
+        ],
+    )
+    .expect("EscrowCreated parameters match its signature")
+}
@@ -2,10 +2,12 @@
 //!
 //! Shared EVM JSON-RPC client used by coordinator, integrated-gmp, and solver.
 
+pub mod abi;
 pub mod client;
 pub mod types;
 
+pub use abi::{AbiType, AbiValue, DecodedEvent, EventDecoder};
 pub use client::{normalize_evm_address, EvmClient};
 pub use types::{
-    EscrowCreatedEvent, EvmLog, EvmTransaction, JsonRpcError, JsonRpcRequest, JsonRpcResponse,
+    EscrowCreatedEvent, EvmEscrow, EvmFeeHistory, EvmLog, EvmTransaction, JsonRpcError, JsonRpcRequest, JsonRpcResponse,
 };
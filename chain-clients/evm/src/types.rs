@@ -50,6 +50,22 @@ pub struct EvmLog {
     /// Log index (JSON-RPC uses camelCase: logIndex)
     #[serde(rename = "logIndex")]
     pub log_index: String,
+    /// Hash of the block containing the log (JSON-RPC uses camelCase: blockHash)
+    #[serde(rename = "blockHash", default)]
+    pub block_hash: Option<String>,
+}
+
+/// Fee history returned by eth_feeHistory (hex quantities)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmFeeHistory {
+    /// Oldest block in the returned range
+    pub oldest_block: String,
+    /// Base fee per gas for each block in the range, plus the next (pending) block
+    pub base_fee_per_gas: Vec<String>,
+    /// Priority fees per block at the requested percentiles (empty if none requested)
+    #[serde(default)]
+    pub reward: Vec<Vec<String>>,
 }
 
 /// EscrowCreated event data parsed from EVM logs
@@ -113,3 +129,25 @@ pub struct EvmTransaction {
     /// Transaction status (1 = success, 0 = failure, null = pending)
     pub status: Option<String>,
 }
+
+/// Inflow escrow state read via `getEscrow(bytes32)`
+///
+/// Mirrors the contract's `StoredEscrow` struct; addresses are 0x-prefixed hex
+/// (`bytes32` fields keep all 32 bytes, `address` fields are 20 bytes).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvmEscrow {
+    /// Escrow ID (bytes32)
+    pub escrow_id: String,
+    /// Requester that created the escrow (bytes32)
+    pub creator_addr: String,
+    /// Amount escrowed (uint64)
+    pub amount: u64,
+    /// Token contract address (address)
+    pub token_addr: String,
+    /// Solver the escrow is reserved for (bytes32)
+    pub solver_addr: String,
+    /// Whether the escrow has been fulfilled
+    pub fulfilled: bool,
+    /// Whether the escrow funds have been released
+    pub released: bool,
+}
@@ -0,0 +1,258 @@
+//! Unit tests for the EVM event-log ABI decoder
+//!
+//! EVM-specific; not part of the cross-VM checklist in chain-clients/extension-checklist.md.
+
+use chain_clients_evm::abi::event_topic;
+use chain_clients_evm::{AbiType, AbiValue, EventDecoder, EvmLog};
+
+// ============================================================================
+// FIXTURES
+// ============================================================================
+
+const MESSAGE_SENT_SIGNATURE: &str = "MessageSent(uint32,bytes32,bytes,uint64)";
+const ESCROW_CREATED_SIGNATURE: &str =
+    "EscrowCreated(bytes32,bytes32,address,uint64,address,bytes32,uint64)";
+
+const DST_ADDR_WORD: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+
+fn message_sent_decoder() -> EventDecoder {
+    EventDecoder::new(
+        MESSAGE_SENT_SIGNATURE,
+        &[
+            ("dstChainId", "uint32 indexed"),
+            ("dstAddr", "bytes32"),
+            ("payload", "bytes"),
+            ("nonce", "uint64"),
+        ],
+    )
+    .unwrap()
+}
+
+fn escrow_created_decoder() -> EventDecoder {
+    EventDecoder::new(
+        ESCROW_CREATED_SIGNATURE,
+        &[
+            ("intentId", "bytes32 indexed"),
+            ("escrowId", "bytes32"),
+            ("requester", "address indexed"),
+            ("amount", "uint64"),
+            ("token", "address indexed"),
+            ("reservedSolver", "bytes32"),
+            ("expiry", "uint64"),
+        ],
+    )
+    .unwrap()
+}
+
+fn log(topics: Vec<String>, data_words: &[&str]) -> EvmLog {
+    EvmLog {
+        address: "0x000000000000000000000000000000000000000e".to_string(),
+        topics,
+        data: format!("0x{}", data_words.concat()),
+        block_number: "0x1".to_string(),
+        transaction_hash: format!("0x{:064x}", 1),
+        log_index: "0x0".to_string(),
+        block_hash: None,
+    }
+}
+
+fn word(value: u64) -> String {
+    format!("{:064x}", value)
+}
+
+/// MessageSent log for chain 30106 with a 5-byte payload placed at `payload_offset`.
+///
+/// Words between the head (3 words) and the payload are filled with zeros, as an
+/// encoder that does not pack dynamic data right after the head would leave them.
+fn message_sent_log(payload_offset: u64) -> EvmLog {
+    let offset_word = word(payload_offset);
+    let nonce_word = word(7);
+    let len_word = word(5);
+    let padding = "0".repeat(((payload_offset - 96) * 2) as usize);
+    let payload_word = format!("{:0<64}", "0102030405");
+    log(
+        vec![message_sent_decoder().topic().to_string(), format!("0x{}", word(30106))],
+        &[DST_ADDR_WORD, &offset_word, &nonce_word, &padding, &len_word, &payload_word],
+    )
+}
+
+// ============================================================================
+// SIGNATURE TESTS
+// ============================================================================
+
+/// 1. Test: Event topic is keccak256 of the signature
+/// Verifies that event_topic matches the well-known ERC-20 Transfer topic.
+/// Why: A wrong topic makes eth_getLogs filters silently match nothing.
+#[test]
+fn test_event_topic_matches_known_hash() {
+    assert_eq!(
+        event_topic("Transfer(address,address,uint256)"),
+        "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+    );
+}
+
+/// 2. Test: Declared parameters must match the signature
+/// Verifies that a type mismatch, a count mismatch, and an unknown modifier are rejected.
+/// Why: A decoder declared against the wrong signature would misread every field.
+#[test]
+fn test_decoder_rejects_params_not_matching_signature() {
+    assert!(EventDecoder::new("E(uint64,bytes32)", &[("a", "uint64"), ("b", "bytes")]).is_err());
+    assert!(EventDecoder::new("E(uint64,bytes32)", &[("a", "uint64")]).is_err());
+    assert!(EventDecoder::new("E(uint64)", &[("a", "uint64 stored")]).is_err());
+    assert!(EventDecoder::new("E(uint64", &[("a", "uint64")]).is_err());
+}
+
+/// 3. Test: Type names parse into ABI types
+/// Verifies that sized uint/bytes types parse and invalid sizes are rejected.
+#[test]
+fn test_abi_type_parse() {
+    assert_eq!(AbiType::parse("uint64").unwrap(), AbiType::Uint(64));
+    assert_eq!(AbiType::parse("bytes32").unwrap(), AbiType::FixedBytes(32));
+    assert_eq!(AbiType::parse("bytes").unwrap(), AbiType::Bytes);
+    assert!(AbiType::parse("uint7").is_err());
+    assert!(AbiType::parse("bytes33").is_err());
+    assert!(AbiType::parse("int64").is_err());
+}
+
+// ============================================================================
+// DECODING TESTS
+// ============================================================================
+
+/// 4. Test: Decode MessageSent with the standard payload offset
+/// Verifies that the indexed chain ID comes from the topic and dstAddr, payload and
+/// nonce from the data, with the payload read through its 0x60 offset.
+#[test]
+fn test_decode_message_sent() {
+    let decoded = message_sent_decoder().decode(&message_sent_log(0x60)).unwrap();
+
+    assert_eq!(decoded.uint("dstChainId").unwrap(), 30106);
+    assert_eq!(decoded.hex("dstAddr").unwrap(), format!("0x{}", DST_ADDR_WORD));
+    assert_eq!(decoded.get("payload").unwrap(), &AbiValue::Bytes(vec![1, 2, 3, 4, 5]));
+    assert_eq!(decoded.u64("nonce").unwrap(), 7);
+}
+
+/// 5. Test: Decode MessageSent with a non-standard payload offset
+/// Verifies that the payload is located through its head offset, not assumed to
+/// follow the head.
+/// Why: The previous hand-rolled parsers hardcoded word positions.
+#[test]
+fn test_decode_message_sent_non_standard_offset() {
+    let decoded = message_sent_decoder().decode(&message_sent_log(0xa0)).unwrap();
+
+    assert_eq!(decoded.bytes("payload").unwrap(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(decoded.u64("nonce").unwrap(), 7);
+}
+
+/// 6. Test: Decode EscrowCreated with indexed addresses
+/// Verifies that the three indexed params come from topics in declaration order
+/// and the four data params from consecutive words.
+#[test]
+fn test_decode_escrow_created() {
+    let decoder = escrow_created_decoder();
+    let intent_id = format!("0x{}", word(1));
+    let escrow_log = log(
+        vec![
+            decoder.topic().to_string(),
+            intent_id.clone(),
+            format!("0x000000000000000000000000{}", "ab".repeat(20)),
+            format!("0x000000000000000000000000{}", "0a".repeat(20)),
+        ],
+        &[&word(2), &word(1_000_000), &word(9), &word(1_700_000_000)],
+    );
+
+    let decoded = decoder.decode(&escrow_log).unwrap();
+
+    assert_eq!(decoded.hex("intentId").unwrap(), intent_id);
+    assert_eq!(decoded.hex("escrowId").unwrap(), format!("0x{}", word(2)));
+    assert_eq!(decoded.address("requester").unwrap(), format!("0x{}", "ab".repeat(20)));
+    assert_eq!(decoded.u64("amount").unwrap(), 1_000_000);
+    assert_eq!(decoded.address("token").unwrap(), format!("0x{}", "0a".repeat(20)));
+    assert_eq!(decoded.hex("reservedSolver").unwrap(), format!("0x{}", word(9)));
+    assert_eq!(decoded.u64("expiry").unwrap(), 1_700_000_000);
+}
+
+/// 7. Test: Indexed dynamic params decode to their topic hash
+/// Verifies that an indexed `bytes` param yields the 32-byte topic as FixedBytes.
+/// Why: Indexed dynamic values are stored only as keccak256 hashes.
+#[test]
+fn test_decode_indexed_bytes_is_topic_hash() {
+    let decoder = EventDecoder::new("Tagged(bytes)", &[("tag", "bytes indexed")]).unwrap();
+    let hash = "cd".repeat(32);
+    let tagged = log(vec![decoder.topic().to_string(), format!("0x{}", hash)], &[]);
+
+    let decoded = decoder.decode(&tagged).unwrap();
+
+    assert_eq!(decoded.hex("tag").unwrap(), format!("0x{}", hash));
+}
+
+// ============================================================================
+// MALFORMED LOG TESTS
+// ============================================================================
+
+/// 8. Test: Logs of another event are rejected
+/// Verifies that decode fails when topics[0] is a different event.
+#[test]
+fn test_decode_rejects_other_event() {
+    let mut other = message_sent_log(0x60);
+    other.topics[0] = escrow_created_decoder().topic().to_string();
+
+    assert!(message_sent_decoder().decode(&other).is_err());
+}
+
+/// 9. Test: Missing topics are rejected
+/// Verifies that decode fails when an indexed param has no topic.
+#[test]
+fn test_decode_rejects_missing_topics() {
+    let mut missing = message_sent_log(0x60);
+    missing.topics.truncate(1);
+
+    assert!(message_sent_decoder().decode(&missing).is_err());
+}
+
+/// 10. Test: Out-of-bounds dynamic data is rejected
+/// Verifies that decode fails when the payload offset or length points past the data.
+/// Why: A bad offset must not panic or return bytes from the wrong region.
+#[test]
+fn test_decode_rejects_out_of_bounds_payload() {
+    let decoder = message_sent_decoder();
+
+    let mut bad_offset = message_sent_log(0x60);
+    bad_offset.data = bad_offset.data.replacen(&word(0x60), &word(0x1000), 1);
+    assert!(decoder.decode(&bad_offset).is_err());
+
+    let payload_word = format!("{:0<64}", "0102030405");
+    let mut bad_len = message_sent_log(0x60);
+    bad_len.data = bad_len.data.replacen(
+        &format!("{}{}", word(5), payload_word),
+        &format!("{}{}", word(33), payload_word),
+        1,
+    );
+    assert!(decoder.decode(&bad_len).is_err());
+
+    let mut truncated = message_sent_log(0x60);
+    truncated.data.truncate(2 + 64 * 2);
+    assert!(decoder.decode(&truncated).is_err());
+}
+
+/// 11. Test: Values wider than their type are rejected
+/// Verifies that nonzero high bytes in a uint64 or address word fail to decode.
+/// Why: Silently truncating would turn a corrupted log into a wrong amount.
+#[test]
+fn test_decode_rejects_dirty_padding() {
+    let decoder = escrow_created_decoder();
+    let topics = vec![
+        decoder.topic().to_string(),
+        format!("0x{}", word(1)),
+        format!("0x000000000000000000000000{}", "ab".repeat(20)),
+        format!("0x000000000000000000000000{}", "0a".repeat(20)),
+    ];
+    let wide_amount = format!("{:0>64}", "1".repeat(17));
+
+    let bad_amount = log(topics.clone(), &[&word(2), &wide_amount, &word(9), &word(1)]);
+    assert!(decoder.decode(&bad_amount).is_err());
+
+    let mut bad_address_topics = topics;
+    bad_address_topics[2] = format!("0xff0000000000000000000000{}", "ab".repeat(20));
+    let bad_address = log(bad_address_topics, &[&word(2), &word(1), &word(9), &word(1)]);
+    assert!(decoder.decode(&bad_address).is_err());
+}
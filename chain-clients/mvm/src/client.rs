@@ -6,6 +6,7 @@
 
 use anyhow::{Context, Result};
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use std::time::Duration;
 
 use crate::types::*;
@@ -1021,6 +1022,44 @@ impl MvmClient {
         Ok(result)
     }
 
+    /// Calls a view function and deserializes its result into `T`.
+    ///
+    /// The result is the JSON array of the function's return values, so `T` is usually a
+    /// tuple with one element per return value. Use [`ViewU64`] for u64 values.
+    pub async fn call_view_typed<T: DeserializeOwned>(
+        &self,
+        module_addr: &str,
+        module_name: &str,
+        function_name: &str,
+        type_args: Vec<String>,
+        args: Vec<serde_json::Value>,
+    ) -> Result<T> {
+        let result = self
+            .call_view_function(module_addr, module_name, function_name, type_args, args)
+            .await?;
+        T::deserialize(&result).with_context(|| {
+            format!(
+                "Unexpected response format from {}::{}: {}",
+                module_name, function_name, result
+            )
+        })
+    }
+
+    /// Calls a view function returning a single u64, given as a quoted string or an integer.
+    pub async fn call_view_u64(
+        &self,
+        module_addr: &str,
+        module_name: &str,
+        function_name: &str,
+        type_args: Vec<String>,
+        args: Vec<serde_json::Value>,
+    ) -> Result<u64> {
+        let (value,): (ViewU64,) = self
+            .call_view_typed(module_addr, module_name, function_name, type_args, args)
+            .await?;
+        Ok(value.0)
+    }
+
     /// Queries the intent registry for active requester addresses.
     pub async fn get_active_requesters(
         &self,
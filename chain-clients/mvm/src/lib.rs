@@ -5,5 +5,5 @@ pub use client::{normalize_hex_to_address, MvmClient};
 pub use types::{
     deserialize_move_option_string, deserialize_u64_string, AccountInfo, EventGuid, EventHandle,
     EventHandleGuid, EventHandleGuidId, LimitOrderEvent, LimitOrderFulfillmentEvent, ModuleInfo,
-    MvmEvent, MvmResponse, MvmTransaction, OracleLimitOrderEvent, ResourceData, Resources,
+    MvmEvent, MvmResponse, MvmTransaction, OracleLimitOrderEvent, ResourceData, Resources, ViewU64,
 };
@@ -23,6 +23,29 @@ where
     }
 }
 
+/// u64 in a view function result. Aptos returns u64 values as quoted strings, but some
+/// nodes and fixtures return raw integers; both decode to the same value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewU64(pub u64);
+
+impl<'de> Deserialize<'de> for ViewU64 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let value: serde_json::Value = Deserialize::deserialize(deserializer)?;
+        let parsed = match &value {
+            serde_json::Value::String(s) => s.parse().ok(),
+            serde_json::Value::Number(n) => n.as_u64(),
+            _ => None,
+        };
+        parsed.map(ViewU64).ok_or_else(|| {
+            D::Error::custom(format!("expected u64 as string or number, got: {}", value))
+        })
+    }
+}
+
 /// Deserialize Move's Option<T> format: {"vec": [value]} for Some, {"vec": []} for None.
 pub fn deserialize_move_option_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
@@ -147,6 +170,9 @@ pub struct MvmTransaction {
     pub hash: String,
     #[allow(dead_code)]
     pub success: bool,
+    /// VM status string (e.g. "Executed successfully" or a Move abort description)
+    #[serde(default)]
+    pub vm_status: String,
     #[allow(dead_code)]
     pub events: Vec<MvmEvent>,
 }
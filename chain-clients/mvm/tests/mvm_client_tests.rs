@@ -3,7 +3,7 @@
 //! Test ordering matches chain-clients/extension-checklist.md for cross-VM synchronization.
 //! Tests marked N/A in the checklist are skipped with comments.
 
-use chain_clients_mvm::{normalize_hex_to_address, MvmClient};
+use chain_clients_mvm::{normalize_hex_to_address, MvmClient, ViewU64};
 use serde_json::json;
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -236,3 +236,102 @@ fn test_normalize_hex_to_address_no_prefix() {
 // #22-#24: EVM address normalization - N/A for MVM
 // #25-#26: SVM pubkey from hex - N/A for MVM
 // #27-#28: SVM escrow parsing - N/A for MVM
+// #29-#31: EVM escrow lookup - N/A for MVM
+
+// ============================================================================
+// TYPED VIEW RESULTS (MVM-specific)
+// ============================================================================
+
+/// 32. Test: ViewU64 parses quoted strings and raw integers identically
+/// Verifies that "42" and 42 both deserialize to ViewU64(42), and that negative or
+/// non-numeric values are rejected.
+/// Why: Aptos returns u64 view results as strings, but some nodes and fixtures return
+/// raw integers. Callers must not care which representation they receive.
+#[test]
+fn test_view_u64_string_and_number_parse_identically() {
+    let from_string: ViewU64 = serde_json::from_value(json!("42")).unwrap();
+    let from_number: ViewU64 = serde_json::from_value(json!(42)).unwrap();
+    assert_eq!(from_string, ViewU64(42));
+    assert_eq!(from_string, from_number);
+
+    let max: ViewU64 = serde_json::from_value(json!(u64::MAX.to_string())).unwrap();
+    assert_eq!(max, ViewU64(u64::MAX));
+
+    assert!(serde_json::from_value::<ViewU64>(json!(-1)).is_err());
+    assert!(serde_json::from_value::<ViewU64>(json!("abc")).is_err());
+    assert!(serde_json::from_value::<ViewU64>(json!(true)).is_err());
+}
+
+/// 33. Test: call_view_u64 accepts both string and number results
+/// Verifies that a view returning ["7"] and one returning [7] both yield 7.
+/// Why: The relay's nonce polling goes through call_view_u64, so both node
+/// representations must produce the same nonce.
+#[tokio::test]
+async fn test_call_view_u64_accepts_string_and_number() {
+    for body in [json!(["7"]), json!([7])] {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/view"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body.clone()))
+            .mount(&mock_server)
+            .await;
+
+        let client = MvmClient::new(&mock_server.uri()).unwrap();
+        let value = client
+            .call_view_u64(
+                DUMMY_MODULE_ADDR,
+                "gmp_sender",
+                "get_next_nonce",
+                vec![],
+                vec![],
+            )
+            .await
+            .unwrap();
+        assert_eq!(value, 7, "unexpected value for response {}", body);
+    }
+}
+
+/// 34. Test: call_view_typed rejects results that don't match the requested type
+/// Verifies that a tuple decode fails with a descriptive error when the result has
+/// the wrong number of elements, and succeeds on a well-formed result.
+/// Why: Silent defaults on malformed view results would hide contract/client drift.
+#[tokio::test]
+async fn test_call_view_typed_rejects_unexpected_shape() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([30, "0xabcd"])))
+        .mount(&mock_server)
+        .await;
+
+    let client = MvmClient::new(&mock_server.uri()).unwrap();
+
+    let (chain_id, addr): (ViewU64, String) = client
+        .call_view_typed(
+            DUMMY_MODULE_ADDR,
+            "gmp_sender",
+            "get_message",
+            vec![],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(chain_id, ViewU64(30));
+    assert_eq!(addr, "0xabcd");
+
+    let result: anyhow::Result<(ViewU64, String, String)> = client
+        .call_view_typed(
+            DUMMY_MODULE_ADDR,
+            "gmp_sender",
+            "get_message",
+            vec![],
+            vec![],
+        )
+        .await;
+    let err = format!("{:#}", result.unwrap_err());
+    assert!(
+        err.contains("Unexpected response format from gmp_sender::get_message"),
+        "unexpected error: {}",
+        err
+    );
+}
@@ -80,13 +80,35 @@ impl SvmClient {
     ///
     /// Returns `None` if the account doesn't exist.
     pub async fn get_raw_account_data(&self, pubkey: &Pubkey) -> Result<Option<Vec<u8>>> {
+        self.get_account_info_raw(pubkey, serde_json::json!({ "encoding": "base64" }))
+            .await
+    }
+
+    /// Reads raw account data (base64-decoded) as seen at `commitment`
+    /// (`processed`, `confirmed` or `finalized`).
+    ///
+    /// Returns `None` if the account doesn't exist at that commitment.
+    pub async fn get_raw_account_data_with_commitment(
+        &self,
+        pubkey: &Pubkey,
+        commitment: &str,
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_account_info_raw(
+            pubkey,
+            serde_json::json!({ "encoding": "base64", "commitment": commitment }),
+        )
+        .await
+    }
+
+    async fn get_account_info_raw(
+        &self,
+        pubkey: &Pubkey,
+        config: serde_json::Value,
+    ) -> Result<Option<Vec<u8>>> {
         let request = JsonRpcRequest {
             jsonrpc: "2.0".to_string(),
             method: "getAccountInfo".to_string(),
-            params: serde_json::json!([
-                pubkey.to_string(),
-                { "encoding": "base64" }
-            ]),
+            params: serde_json::json!([pubkey.to_string(), config]),
             id: 1,
         };
 
@@ -265,6 +287,68 @@ impl SvmClient {
         Ok(escrows)
     }
 
+    /// Queries raw account data for accounts owned by `program_id` matching every
+    /// `(offset, bytes)` memcmp filter, as seen at `commitment`.
+    ///
+    /// Returns `(pubkey, account data)` pairs. getProgramAccounts makes the node scan
+    /// every account of the program, so it is expensive and is often rate-limited or
+    /// disabled on public RPC endpoints.
+    pub async fn get_program_accounts_raw(
+        &self,
+        program_id: &Pubkey,
+        memcmp: &[(usize, &[u8])],
+        commitment: &str,
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let filters: Vec<serde_json::Value> = memcmp
+            .iter()
+            .map(|(offset, bytes)| {
+                serde_json::json!({
+                    "memcmp": { "offset": offset, "bytes": STANDARD.encode(bytes), "encoding": "base64" }
+                })
+            })
+            .collect();
+        let params = serde_json::json!([
+            program_id.to_string(),
+            { "encoding": "base64", "commitment": commitment, "filters": filters }
+        ]);
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "getProgramAccounts".to_string(),
+            params,
+            id: 1,
+        };
+
+        let response: JsonRpcResponse<Vec<ProgramAccountResult>> = self
+            .client
+            .post(&self.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to call getProgramAccounts")?
+            .json()
+            .await
+            .context("Failed to parse getProgramAccounts response")?;
+
+        if let Some(error) = response.error {
+            return Err(anyhow::anyhow!("SVM RPC error: {}", error.message));
+        }
+
+        let accounts = response.result
+            .context("No result in getProgramAccounts response")?;
+        accounts
+            .into_iter()
+            .map(|account| {
+                let pubkey = Pubkey::from_str(&account.pubkey)
+                    .context("Invalid pubkey in getProgramAccounts response")?;
+                let data = STANDARD
+                    .decode(&account.account.data.0)
+                    .context("Failed to decode base64 account data")?;
+                Ok((pubkey, data))
+            })
+            .collect()
+    }
+
     /// Fetches a single escrow account by intent ID.
     pub async fn get_escrow_by_intent_id(
         &self,
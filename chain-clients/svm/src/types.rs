@@ -23,6 +23,10 @@ pub struct EscrowAccount {
     pub reserved_solver: Pubkey,
     pub intent_id: [u8; 32],
     pub bump: u8,
+    /// Client correlation tag supplied at creation (zeros = none)
+    pub memo: [u8; 32],
+    /// Claim mode: 0 = Reserved (reserved solver only), 1 = Open (any solver)
+    pub mode: u8,
 }
 
 /// Escrow account paired with its on-chain address
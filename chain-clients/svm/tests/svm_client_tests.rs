@@ -35,6 +35,8 @@ fn make_escrow(is_claimed: bool) -> EscrowAccount {
         reserved_solver: Pubkey::default(),
         intent_id: [0u8; 32],
         bump: 255,
+        memo: [0u8; 32],
+        mode: 0,
     }
 }
 
@@ -446,6 +448,8 @@ fn test_escrow_account_borsh_roundtrip() {
         reserved_solver: Pubkey::new_from_array([3u8; 32]),
         intent_id: [4u8; 32],
         bump: 1,
+        memo: [5u8; 32],
+        mode: 1,
     };
 
     let serialized = escrow.try_to_vec().expect("serialize escrow");
@@ -461,6 +465,8 @@ fn test_escrow_account_borsh_roundtrip() {
     assert_eq!(parsed.reserved_solver, escrow.reserved_solver);
     assert_eq!(parsed.intent_id, escrow.intent_id);
     assert_eq!(parsed.bump, escrow.bump);
+    assert_eq!(parsed.memo, escrow.memo);
+    assert_eq!(parsed.mode, escrow.mode);
 }
 
 /// 28. Test: parse_escrow_data returns Err for invalid base64
@@ -476,3 +482,6 @@ fn test_escrow_account_invalid_base64() {
     let result = parse_escrow_data(&too_short);
     assert!(result.is_err());
 }
+
+// #29-#31: EVM escrow lookup - N/A for SVM (see get_escrow_by_intent_id)
+// #32-#34: MVM typed view results - N/A for SVM
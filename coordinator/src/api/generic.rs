@@ -6,15 +6,22 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use chain_clients_mvm::MvmClient;
+use futures::future::{BoxFuture, FutureExt};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{debug, error, info};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{debug, error, info, warn};
 use warp::{http::{Method, StatusCode}, Filter, Rejection, Reply};
 use warp::hyper::body::Bytes;
 
-use crate::config::Config;
+use crate::config::{ChainConfig, Config, TokenPairConfig};
+use crate::metrics::CoordinatorMetrics;
 use crate::monitor::EventMonitor;
-use crate::storage::DraftintentStore;
+use crate::storage::{
+    AuditEntry, AuditLog, CachedResponse, DraftintentStatus, DraftintentStore, IdempotencyCache,
+    IdempotencyLookup, RateCache, SolverLivenessStore,
+};
 
 // ============================================================================
 // SHARED REQUEST/RESPONSE STRUCTURES
@@ -76,8 +83,156 @@ pub async fn get_events_handler(
     }))
 }
 
+/// Negotiation status of the draft behind an intent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentNegotiation {
+    /// Draft ID used for negotiation routing
+    pub draft_id: String,
+    /// Draft status (pending, signed, expired)
+    pub status: DraftintentStatus,
+    /// Hub address of the solver whose signature won (None until signed)
+    pub solver_hub_addr: Option<String>,
+}
+
+/// Everything the coordinator knows about one intent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentDetailsResponse {
+    /// Intent ID as requested
+    pub intent_id: String,
+    /// Intent creation event from the hub (None until observed on-chain)
+    pub intent: Option<crate::monitor::IntentEvent>,
+    /// Fulfillment event from the hub (None until fulfilled)
+    pub fulfillment: Option<crate::monitor::FulfillmentEvent>,
+    /// Negotiation status of the draft carrying this intent ID, if one exists
+    pub negotiation: Option<IntentNegotiation>,
+}
+
+/// Handler for the single-intent endpoint.
+///
+/// Looks the intent up in the monitor's indexed caches and the draft store and
+/// merges the results into one object. Returns 404 when none of them know the intent.
+///
+/// # Arguments
+///
+/// * `intent_id` - Intent ID from the path (any hex form, normalized for lookup)
+/// * `monitor` - The event monitor instance
+/// * `draft_store` - The draft intent store
+///
+/// # Returns
+///
+/// * `Ok(warp::Reply)` - JSON response with the merged intent details (200 or 404)
+pub async fn get_intent_handler(
+    intent_id: String,
+    monitor: Arc<RwLock<EventMonitor>>,
+    draft_store: Arc<RwLock<DraftintentStore>>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let monitor = monitor.read().await;
+    let intent = monitor.get_intent_event(&intent_id).await;
+    let fulfillment = monitor.get_fulfillment_event(&intent_id).await;
+    drop(monitor);
+
+    let negotiation = draft_store
+        .read()
+        .await
+        .find_by_intent_id(&intent_id)
+        .await
+        .map(|draft| IntentNegotiation {
+            draft_id: draft.draft_id,
+            status: draft.status,
+            solver_hub_addr: draft.signature.map(|sig| sig.solver_hub_addr),
+        });
+
+    if intent.is_none() && fulfillment.is_none() && negotiation.is_none() {
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&ApiResponse::<IntentDetailsResponse> {
+                success: false,
+                data: None,
+                error: Some(format!("Intent {} not found", intent_id)),
+            }),
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ApiResponse {
+            success: true,
+            data: Some(IntentDetailsResponse {
+                intent_id,
+                intent,
+                fulfillment,
+                negotiation,
+            }),
+            error: None,
+        }),
+        StatusCode::OK,
+    ))
+}
+
+/// Response for `GET /audit/:intent_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditTrailResponse {
+    /// Intent ID as requested
+    pub intent_id: String,
+    /// The coordinator's decisions about the intent, oldest first
+    pub events: Vec<AuditEntry>,
+}
+
+/// Handler for the audit trail endpoint.
+///
+/// Returns every decision the coordinator recorded about the intent (observed,
+/// validated, approval signed, rejected), including entries spilled to disk.
+/// Returns 404 when nothing was recorded.
+///
+/// # Arguments
+///
+/// * `intent_id` - Intent ID from the path (any hex form, normalized for lookup)
+/// * `audit_log` - The coordinator's audit log
+///
+/// # Returns
+///
+/// * `Ok(warp::Reply)` - JSON response with the ordered audit entries (200, 404 or 500)
+pub async fn get_audit_handler(
+    intent_id: String,
+    audit_log: Arc<AuditLog>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let events = match audit_log.trail(&intent_id).await {
+        Ok(events) => events,
+        Err(e) => {
+            error!("Failed to read audit trail for intent {}: {:#}", intent_id, e);
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&ApiResponse::<AuditTrailResponse> {
+                    success: false,
+                    data: None,
+                    error: Some("Failed to read audit log".to_string()),
+                }),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ));
+        }
+    };
+
+    if events.is_empty() {
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&ApiResponse::<AuditTrailResponse> {
+                success: false,
+                data: None,
+                error: Some(format!("No audit entries for intent {}", intent_id)),
+            }),
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ApiResponse {
+            success: true,
+            data: Some(AuditTrailResponse { intent_id, events }),
+            error: None,
+        }),
+        StatusCode::OK,
+    ))
+}
+
 /// Response structure for exchange rate query
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeRateResponse {
     /// Desired token metadata address
     pub desired_token: String,
@@ -93,6 +248,12 @@ pub struct ExchangeRateResponse {
     pub move_rate: f64,
     /// Fee in basis points (e.g., 50 = 0.5%, covers solver opportunity cost)
     pub fee_bps: u64,
+    /// True when the solver was unreachable and a cached rate is served instead
+    #[serde(default)]
+    pub stale: bool,
+    /// Age of the cached rate in seconds (only set when `stale` is true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_age_secs: Option<u64>,
 }
 
 /// Handler for the acceptance/exchange rate endpoint.
@@ -105,9 +266,13 @@ pub struct ExchangeRateResponse {
 ///
 /// Returns the desired token, desired chain ID, and exchange rate.
 ///
-/// Exchange rates are fetched live from the solver to avoid stale ratios.
+/// Exchange rates are fetched live from the solver to avoid stale ratios. If the
+/// solver is unreachable, the last fetched rate is served with `stale` set, as long
+/// as it is no older than the pair's `max_rate_staleness_secs`; otherwise the rate
+/// is reported as unavailable (503).
 pub async fn get_exchange_rate_handler(
     config: Arc<crate::config::Config>,
+    rate_cache: Arc<RateCache>,
     query: String,
 ) -> Result<impl warp::Reply, warp::Rejection> {
     use std::collections::HashMap;
@@ -161,40 +326,249 @@ pub async fn get_exchange_rate_handler(
         )))
     })?;
 
-    // Fetch live ratio from solver
-    let solver_url = acceptance.solver_url.trim_end_matches('/');
+    // Fetch live ratio from solver, falling back to a bounded-staleness cached rate
+    let exchange_rate = match fetch_solver_rate(&acceptance.solver_url, matched_pair).await {
+        Ok(rate) => {
+            rate_cache.insert(matched_pair, rate.clone()).await;
+            rate
+        }
+        Err(solver_error) => match rate_cache.get(matched_pair).await {
+            Some(cached) => {
+                let age_secs = cached.age_secs();
+                let max_secs = matched_pair.max_rate_staleness_secs;
+                if max_secs == 0 || age_secs > max_secs {
+                    return Err(warp::reject::custom(RateUnavailable(format!(
+                        "Rate unavailable: cached rate is {}s old (max {}s); {}",
+                        age_secs, max_secs, solver_error
+                    ))));
+                }
+                warn!(
+                    "Serving cached rate for {} ({}s old): {}",
+                    RateCache::pair_key(matched_pair),
+                    age_secs,
+                    solver_error
+                );
+                ExchangeRateResponse {
+                    stale: true,
+                    rate_age_secs: Some(age_secs),
+                    ..cached.rate
+                }
+            }
+            None => {
+                return Err(warp::reject::custom(RateUnavailable(format!(
+                    "Rate unavailable: {}",
+                    solver_error
+                ))));
+            }
+        },
+    };
+
+    Ok(warp::reply::json(&ApiResponse::<ExchangeRateResponse> {
+        success: true,
+        data: Some(exchange_rate),
+        error: None,
+    }))
+}
+
+/// Fetches the live exchange rate for a configured pair from the solver.
+///
+/// # Returns
+///
+/// * `Ok(ExchangeRateResponse)` - Fresh rate from the solver
+/// * `Err(String)` - Description of why the solver could not provide a rate
+async fn fetch_solver_rate(
+    solver_url: &str,
+    pair: &TokenPairConfig,
+) -> Result<ExchangeRateResponse, String> {
     let solver_request = format!(
         "{}/acceptance?offered_chain_id={}&offered_token={}&desired_chain_id={}&desired_token={}",
-        solver_url,
-        matched_pair.source_chain_id,
-        matched_pair.source_token,
-        matched_pair.target_chain_id,
-        matched_pair.target_token,
+        solver_url.trim_end_matches('/'),
+        pair.source_chain_id,
+        pair.source_token,
+        pair.target_chain_id,
+        pair.target_token,
     );
 
     let response = reqwest::get(&solver_request).await
-        .map_err(|e| warp::reject::custom(JsonDeserializeError(format!("Solver request failed: {}", e))))?;
+        .map_err(|e| format!("Solver request failed: {}", e))?;
     let status = response.status();
     if !status.is_success() {
-        return Err(warp::reject::custom(JsonDeserializeError(format!(
-            "Solver returned error status {}",
-            status
-        ))));
+        return Err(format!("Solver returned error status {}", status));
     }
 
     let solver_response: ApiResponse<ExchangeRateResponse> = response.json().await
-        .map_err(|e| warp::reject::custom(JsonDeserializeError(format!("Invalid solver response: {}", e))))?;
-    let exchange_rate = solver_response.data.ok_or_else(|| {
-        warp::reject::custom(JsonDeserializeError("Solver response missing data".to_string()))
-    })?;
+        .map_err(|e| format!("Invalid solver response: {}", e))?;
+    solver_response.data.ok_or_else(|| "Solver response missing data".to_string())
+}
 
-    Ok(warp::reply::json(&ApiResponse::<ExchangeRateResponse> {
+/// Response structure for a quote query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteResponse {
+    /// Source chain ID
+    pub source_chain_id: u64,
+    /// Source token address or mint
+    pub source_token: String,
+    /// Target chain ID
+    pub target_chain_id: u64,
+    /// Target token address or mint
+    pub target_token: String,
+    /// Quoted source amount (smallest units)
+    pub amount: u64,
+    /// Exchange rate (how many source tokens per 1 target token)
+    pub exchange_rate: f64,
+    /// Solver fee deducted from `amount` (source token smallest units)
+    pub fee_in_source_token: u64,
+    /// Target amount the requester receives after the fee (smallest units)
+    pub target_amount: u64,
+    /// Unix timestamp at which the rate was fetched from the solver
+    pub quoted_at: u64,
+    /// True when the solver was unreachable and the last cached rate is served instead
+    pub stale: bool,
+}
+
+/// Handler for the quote endpoint.
+///
+/// Query parameters (all required):
+/// - source_chain: Chain ID of the source token
+/// - source_token: Address or mint of the source token
+/// - target_chain: Chain ID of the target token
+/// - target_token: Address or mint of the target token
+/// - amount: Source amount in smallest units
+///
+/// Solver rates are reused for the acceptance config's `quote_cache_ttl_secs`. When
+/// the solver is unreachable, the last cached rate is served with `stale` set,
+/// regardless of its age; `quoted_at` tells the caller how old it is. Pairs that are
+/// not in the configured `pairs` are rejected with 404.
+pub async fn get_quote_handler(
+    config: Arc<crate::config::Config>,
+    rate_cache: Arc<RateCache>,
+    query: String,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    use std::collections::HashMap;
+    use url::Url;
+
+    let parsed = Url::parse(&format!("http://dummy?{}", query))
+        .map_err(|e| warp::reject::custom(JsonDeserializeError(format!("Invalid query string: {}", e))))?;
+    let params: HashMap<String, String> = parsed.query_pairs().into_owned().collect();
+
+    let param = |name: &str| {
+        params.get(name).ok_or_else(|| {
+            warp::reject::custom(JsonDeserializeError(format!("Missing {} parameter", name)))
+        })
+    };
+    let parse_u64 = |name: &str| {
+        param(name)?.parse::<u64>().map_err(|e| {
+            warp::reject::custom(JsonDeserializeError(format!("Invalid {}: {}", name, e)))
+        })
+    };
+    let source_chain_id = parse_u64("source_chain")?;
+    let source_token = param("source_token")?;
+    let target_chain_id = parse_u64("target_chain")?;
+    let target_token = param("target_token")?;
+    let amount = parse_u64("amount")?;
+
+    let (acceptance, matched_pair) = config
+        .acceptance
+        .as_ref()
+        .and_then(|acceptance| {
+            acceptance
+                .pairs
+                .iter()
+                .find(|pair| {
+                    pair.source_chain_id == source_chain_id
+                        && pair.source_token == *source_token
+                        && pair.target_chain_id == target_chain_id
+                        && pair.target_token == *target_token
+                })
+                .map(|pair| (acceptance, pair))
+        })
+        .ok_or_else(|| {
+            warp::reject::custom(UnsupportedPair(format!(
+                "Unsupported pair: {} on chain {} -> {} on chain {}",
+                source_token, source_chain_id, target_token, target_chain_id
+            )))
+        })?;
+
+    let cached = rate_cache.get(matched_pair).await;
+    let (rate, quoted_at, stale) = match cached {
+        Some(cached) if cached.age_secs() < acceptance.quote_cache_ttl_secs => {
+            (cached.rate, cached.fetched_at, false)
+        }
+        cached => match fetch_solver_rate(&acceptance.solver_url, matched_pair).await {
+            Ok(rate) => {
+                let fetched_at = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                rate_cache.insert_at(matched_pair, rate.clone(), fetched_at).await;
+                (rate, fetched_at, false)
+            }
+            Err(solver_error) => match cached {
+                Some(cached) => {
+                    warn!(
+                        "Serving stale quote for {} ({}s old): {}",
+                        RateCache::pair_key(matched_pair),
+                        cached.age_secs(),
+                        solver_error
+                    );
+                    (cached.rate, cached.fetched_at, true)
+                }
+                None => {
+                    return Err(warp::reject::custom(RateUnavailable(format!(
+                        "Rate unavailable: {}",
+                        solver_error
+                    ))));
+                }
+            },
+        },
+    };
+
+    let (fee_in_source_token, target_amount) = quote_target_amount(amount, &rate);
+
+    Ok(warp::reply::json(&ApiResponse::<QuoteResponse> {
         success: true,
-        data: Some(exchange_rate),
+        data: Some(QuoteResponse {
+            source_chain_id,
+            source_token: source_token.clone(),
+            target_chain_id,
+            target_token: target_token.clone(),
+            amount,
+            exchange_rate: rate.exchange_rate,
+            fee_in_source_token,
+            target_amount,
+            quoted_at,
+            stale,
+        }),
         error: None,
     }))
 }
 
+/// Computes the solver fee and the resulting target amount for a source amount.
+///
+/// Uses the solver's acceptance formula: the fee is
+/// `ceil(base_fee_in_move * move_rate) + ceil(amount * fee_bps / 10000)`, and the
+/// remainder is converted at `exchange_rate` (source per target, smallest units).
+///
+/// # Returns
+///
+/// `(fee_in_source_token, target_amount)`; the target amount is 0 when the fee
+/// consumes the whole amount or the rate is not positive.
+fn quote_target_amount(amount: u64, rate: &ExchangeRateResponse) -> (u64, u64) {
+    let min_fee = if rate.base_fee_in_move > 0 && rate.move_rate > 0.0 {
+        (rate.base_fee_in_move as f64 * rate.move_rate).ceil() as u64
+    } else {
+        0
+    };
+    let bps_fee = (amount as u128 * rate.fee_bps as u128).div_ceil(10_000) as u64;
+    let fee = min_fee.saturating_add(bps_fee);
+
+    let remaining = amount.saturating_sub(fee);
+    if remaining == 0 || rate.exchange_rate <= 0.0 {
+        return (fee, 0);
+    }
+    (fee, (remaining as f64 / rate.exchange_rate).floor() as u64)
+}
+
 // ============================================================================
 // WARP FILTER HELPERS
 // ============================================================================
@@ -217,6 +591,374 @@ pub fn with_monitor(
     warp::any().map(move || monitor.clone())
 }
 
+// ============================================================================
+// READINESS
+// ============================================================================
+
+/// How long a readiness report is served from cache before the chains are pinged again.
+///
+/// Keeps frequent orchestrator probes from turning into one RPC call per chain per probe.
+pub const READINESS_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Connectivity of one configured chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainReadiness {
+    /// Chain name from the configuration
+    pub name: String,
+    /// Chain role and VM: "hub", "mvm", "evm" or "svm"
+    pub chain_type: String,
+    /// Chain ID from the configuration
+    pub chain_id: u64,
+    /// Whether the RPC answered the ping
+    pub reachable: bool,
+    /// Ping failure (only set when unreachable)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Unix timestamp of the monitor's last successful poll (hub only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_successful_poll: Option<u64>,
+}
+
+/// Response data for the readiness endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadinessReport {
+    /// True when every configured chain is reachable
+    pub ready: bool,
+    /// Per-chain connectivity
+    pub chains: Vec<ChainReadiness>,
+    /// Unix timestamp when the chains were pinged
+    pub checked_at: u64,
+}
+
+/// Last readiness report, reused until it is older than its TTL.
+///
+/// The lock is held while the chains are pinged, so concurrent probes after
+/// expiry wait for one refresh instead of each pinging every chain.
+pub struct ReadinessCache {
+    /// Maximum age of a served report
+    ttl: Duration,
+    /// Last report and when it was produced
+    last: Mutex<Option<(Instant, ReadinessReport)>>,
+}
+
+impl ReadinessCache {
+    /// Create an empty cache serving reports for up to `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Return the cached report, or ping all chains if it is missing or expired.
+    pub async fn get_or_refresh(&self, config: &Config, monitor: &EventMonitor) -> ReadinessReport {
+        let mut last = self.last.lock().await;
+        if let Some((checked, report)) = last.as_ref() {
+            if checked.elapsed() < self.ttl {
+                return report.clone();
+            }
+        }
+        let report = check_chain_readiness(config, monitor).await;
+        *last = Some((Instant::now(), report.clone()));
+        report
+    }
+}
+
+/// Ping every configured chain with a lightweight RPC call.
+///
+/// - hub / mvm: Aptos ledger info (`GET /v1`)
+/// - evm: `eth_blockNumber`
+/// - svm: `getHealth`
+///
+/// Chains are pinged concurrently, each bounded by `validation_timeout_ms`.
+pub async fn check_chain_readiness(config: &Config, monitor: &EventMonitor) -> ReadinessReport {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_millis(config.coordinator.validation_timeout_ms))
+        .no_proxy()
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Failed to build readiness HTTP client: {}", e);
+            reqwest::Client::new()
+        }
+    };
+
+    let mut pings: Vec<BoxFuture<'_, ChainReadiness>> = Vec::new();
+    pings.push(ping_mvm(&config.hub_chain, "hub").boxed());
+    for chain in &config.connected_chain_mvm {
+        pings.push(ping_mvm(chain, "mvm").boxed());
+    }
+    for chain in &config.connected_chain_evm {
+        pings.push(ping_json_rpc(
+            &client,
+            &chain.name,
+            "evm",
+            chain.chain_id,
+            &chain.rpc_url,
+            "eth_blockNumber",
+        )
+        .boxed());
+    }
+    for chain in &config.connected_chain_svm {
+        pings.push(ping_json_rpc(
+            &client,
+            &chain.name,
+            "svm",
+            chain.chain_id,
+            &chain.rpc_url,
+            "getHealth",
+        )
+        .boxed());
+    }
+
+    let mut chains = futures::future::join_all(pings).await;
+    if let Some(hub) = chains.first_mut() {
+        hub.last_successful_poll = monitor.last_hub_poll_at().await;
+    }
+
+    let ready = chains.iter().all(|c| c.reachable);
+    if !ready {
+        for chain in chains.iter().filter(|c| !c.reachable) {
+            warn!(
+                "Readiness: {} chain {} ({}) unreachable: {}",
+                chain.chain_type,
+                chain.name,
+                chain.chain_id,
+                chain.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+    }
+
+    ReadinessReport {
+        ready,
+        chains,
+        checked_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+    }
+}
+
+/// Build a readiness entry from a ping result.
+fn chain_readiness(name: &str, chain_type: &str, chain_id: u64, result: Result<()>) -> ChainReadiness {
+    ChainReadiness {
+        name: name.to_string(),
+        chain_type: chain_type.to_string(),
+        chain_id,
+        reachable: result.is_ok(),
+        error: result.err().map(|e| format!("{:#}", e)),
+        last_successful_poll: None,
+    }
+}
+
+/// Ping a Move VM chain via its ledger info endpoint.
+async fn ping_mvm(chain: &ChainConfig, chain_type: &str) -> ChainReadiness {
+    let result = match MvmClient::new(&chain.rpc_url) {
+        Ok(client) => client.health_check().await,
+        Err(e) => Err(e),
+    };
+    chain_readiness(&chain.name, chain_type, chain.chain_id, result)
+}
+
+/// Ping an EVM or SVM chain with a parameterless JSON-RPC method.
+async fn ping_json_rpc(
+    client: &reqwest::Client,
+    name: &str,
+    chain_type: &str,
+    chain_id: u64,
+    rpc_url: &str,
+    rpc_method: &str,
+) -> ChainReadiness {
+    let result = async {
+        let response: serde_json::Value = client
+            .post(rpc_url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": rpc_method,
+                "params": [],
+            }))
+            .send()
+            .await
+            .with_context(|| format!("Failed to send {} request", rpc_method))?
+            .error_for_status()
+            .with_context(|| format!("{} request failed", rpc_method))?
+            .json()
+            .await
+            .with_context(|| format!("Failed to parse {} response", rpc_method))?;
+        if let Some(error) = response.get("error") {
+            anyhow::bail!("{} returned an error: {}", rpc_method, error);
+        }
+        Ok(())
+    }
+    .await;
+    chain_readiness(name, chain_type, chain_id, result)
+}
+
+/// Handler for the readiness endpoint.
+///
+/// Returns the (possibly cached) per-chain connectivity report with status 200
+/// when every configured chain is reachable, and 503 otherwise.
+pub async fn get_ready_handler(
+    config: Arc<Config>,
+    monitor: Arc<RwLock<EventMonitor>>,
+    cache: Arc<ReadinessCache>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let monitor = monitor.read().await;
+    let report = cache.get_or_refresh(&config, &monitor).await;
+
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let error = (!report.ready).then(|| "One or more chains are unreachable".to_string());
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ApiResponse {
+            success: report.ready,
+            data: Some(report),
+            error,
+        }),
+        status,
+    ))
+}
+
+/// Handler for the Prometheus metrics endpoint.
+///
+/// Sets the event cache gauges and renders all metrics in the text exposition
+/// format. Returns 404 when `api.metrics_enabled` is off.
+pub async fn get_metrics_handler(
+    monitor: Arc<RwLock<EventMonitor>>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let monitor = monitor.read().await;
+    let Some(metrics) = monitor.metrics() else {
+        return Ok(warp::http::Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body("Metrics are disabled".to_string())
+            .unwrap());
+    };
+
+    metrics.set_cache_sizes(
+        monitor.event_cache.read().await.len(),
+        monitor.fulfillment_cache.read().await.len(),
+    );
+    let response = match metrics.render() {
+        Ok(body) => warp::http::Response::builder()
+            .header("content-type", metrics.content_type())
+            .body(body),
+        Err(e) => warp::http::Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .body(format!("Failed to render metrics: {:#}", e)),
+    };
+    Ok(response.unwrap())
+}
+
+// ============================================================================
+// IDEMPOTENCY
+// ============================================================================
+
+/// Request header carrying a client-chosen idempotency key on POST endpoints.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Maximum number of responses kept for idempotent replay.
+pub const IDEMPOTENCY_CACHE_CAPACITY: usize = 1024;
+
+/// How long a response is replayed for a repeated idempotency key.
+///
+/// Covers client retry loops; a key reused after this is handled as a new request.
+pub const IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(600);
+
+/// Run `handler` at most once per idempotency key and replay its response.
+///
+/// Without a key the handler always runs. With a key, a repeated request with the
+/// same body within [`IDEMPOTENCY_WINDOW`] gets the stored response byte for byte;
+/// a repeated key with a different body is rejected with 422. Rejections and 5xx
+/// responses are not stored, so a retry after a server error runs again.
+///
+/// # Arguments
+///
+/// * `cache` - Stored responses
+/// * `scope` - Endpoint the key applies to (keys are not shared across endpoints)
+/// * `key` - Value of the `Idempotency-Key` header, if sent
+/// * `body` - Raw request body
+/// * `handler` - Handles the request when there is no stored response
+pub async fn with_idempotency<F, R>(
+    cache: Arc<IdempotencyCache>,
+    scope: &str,
+    key: Option<String>,
+    body: &[u8],
+    handler: F,
+) -> Result<warp::reply::Response, Rejection>
+where
+    F: std::future::Future<Output = Result<R, Rejection>>,
+    R: Reply,
+{
+    let key = match key {
+        Some(key) => format!("{}|{}", scope, key),
+        None => return handler.await.map(Reply::into_response),
+    };
+    let request_hash = IdempotencyCache::request_hash(body);
+
+    match cache.lookup(&key, request_hash).await {
+        IdempotencyLookup::Hit(cached) => {
+            debug!("Replaying stored response for idempotency key {}", key);
+            return Ok(cached_response_reply(cached));
+        }
+        IdempotencyLookup::Mismatch => {
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&ApiResponse::<()> {
+                    success: false,
+                    data: None,
+                    error: Some("Idempotency-Key was already used with a different request body".to_string()),
+                }),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            )
+            .into_response());
+        }
+        IdempotencyLookup::Miss => {}
+    }
+
+    let response = handler.await?.into_response();
+    if response.status().is_server_error() {
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = warp::hyper::body::to_bytes(body).await.map_err(|e| {
+        error!("Failed to buffer response for idempotency key {}: {}", key, e);
+        warp::reject::reject()
+    })?;
+    let cached = CachedResponse {
+        status: parts.status.as_u16(),
+        content_type: parts
+            .headers
+            .get(warp::http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string),
+        body: body.to_vec(),
+        request_hash,
+    };
+    cache.insert(key, cached.clone()).await;
+    Ok(cached_response_reply(cached))
+}
+
+/// Rebuild an HTTP response from a stored one.
+fn cached_response_reply(cached: CachedResponse) -> warp::reply::Response {
+    let mut response = warp::reply::Response::new(cached.body.into());
+    *response.status_mut() = StatusCode::from_u16(cached.status).unwrap_or(StatusCode::OK);
+    if let Some(content_type) = cached
+        .content_type
+        .and_then(|value| warp::http::HeaderValue::from_str(&value).ok())
+    {
+        response
+            .headers_mut()
+            .insert(warp::http::header::CONTENT_TYPE, content_type);
+    }
+    response
+}
+
 // ============================================================================
 // CUSTOM REJECTION TYPES
 // ============================================================================
@@ -227,6 +969,18 @@ pub struct JsonDeserializeError(pub String);
 
 impl warp::reject::Reject for JsonDeserializeError {}
 
+/// Custom rejection when no live or sufficiently fresh exchange rate is available
+#[derive(Debug)]
+pub struct RateUnavailable(pub String);
+
+impl warp::reject::Reject for RateUnavailable {}
+
+/// Custom rejection for token pairs that are not in the acceptance config
+#[derive(Debug)]
+pub struct UnsupportedPair(pub String);
+
+impl warp::reject::Reject for UnsupportedPair {}
+
 // ============================================================================
 // CORS CONFIGURATION
 // ============================================================================
@@ -245,13 +999,13 @@ fn create_cors_filter(allowed_origins: &[String]) -> warp::cors::Builder {
         warp::cors()
             .allow_any_origin()
             .allow_methods(methods.clone())
-            .allow_headers(vec!["content-type"])
+            .allow_headers(vec!["content-type", IDEMPOTENCY_KEY_HEADER])
     } else {
         let origins: Vec<&str> = allowed_origins.iter().map(|s| s.as_str()).collect();
         warp::cors()
             .allow_origins(origins)
             .allow_methods(methods)
-            .allow_headers(vec!["content-type"])
+            .allow_headers(vec!["content-type", IDEMPOTENCY_KEY_HEADER])
     }
 }
 
@@ -274,6 +1028,10 @@ fn create_cors_filter(allowed_origins: &[String]) -> warp::cors::Builder {
 pub async fn handle_rejection(rej: Rejection) -> Result<impl Reply, std::convert::Infallible> {
     let (status, message) = if let Some(err) = rej.find::<JsonDeserializeError>() {
         (StatusCode::BAD_REQUEST, err.0.clone())
+    } else if let Some(err) = rej.find::<RateUnavailable>() {
+        (StatusCode::SERVICE_UNAVAILABLE, err.0.clone())
+    } else if let Some(err) = rej.find::<UnsupportedPair>() {
+        (StatusCode::NOT_FOUND, err.0.clone())
     } else if let Some(err) = rej.find::<warp::filters::body::BodyDeserializeError>() {
         (StatusCode::BAD_REQUEST, format!("Invalid JSON: {}", err))
     } else if rej.is_not_found() {
@@ -316,6 +1074,18 @@ pub struct ApiServer {
     monitor: Arc<RwLock<EventMonitor>>,
     /// Draft intent store for negotiation routing
     draft_store: Arc<RwLock<DraftintentStore>>,
+    /// Solver heartbeat store for liveness-aware negotiation routing
+    liveness_store: Arc<RwLock<SolverLivenessStore>>,
+    /// Trail of the coordinator's own decisions per intent (shared with the monitor)
+    audit_log: Arc<AuditLog>,
+    /// Last solver rate per token pair, served while the solver is unreachable
+    rate_cache: Arc<RateCache>,
+    /// Last chain connectivity report served by `/ready`
+    readiness_cache: Arc<ReadinessCache>,
+    /// Responses replayed for repeated `Idempotency-Key` headers
+    idempotency_cache: Arc<IdempotencyCache>,
+    /// Prometheus metrics shared with the monitor (None unless `api.metrics_enabled`)
+    metrics: Option<CoordinatorMetrics>,
 }
 
 impl ApiServer {
@@ -336,10 +1106,22 @@ impl ApiServer {
         config: Config,
         monitor: EventMonitor,
     ) -> Self {
+        let draft_store = DraftintentStore::with_updates(monitor.update_sender());
+        let audit_log = monitor.audit_log();
+        let metrics = monitor.metrics().cloned();
         Self {
             config: Arc::new(config),
             monitor: Arc::new(RwLock::new(monitor)),
-            draft_store: Arc::new(RwLock::new(DraftintentStore::new())),
+            draft_store: Arc::new(RwLock::new(draft_store)),
+            liveness_store: Arc::new(RwLock::new(SolverLivenessStore::new())),
+            audit_log,
+            rate_cache: Arc::new(RateCache::new()),
+            readiness_cache: Arc::new(ReadinessCache::new(READINESS_CACHE_TTL)),
+            idempotency_cache: Arc::new(IdempotencyCache::new(
+                IDEMPOTENCY_CACHE_CAPACITY,
+                IDEMPOTENCY_WINDOW,
+            )),
+            metrics,
         }
     }
 
@@ -358,16 +1140,34 @@ impl ApiServer {
             self.config.api.host, self.config.api.port
         );
 
-        // Start background draft expiry cleanup (runs every 10 seconds)
+        // Start background draft expiry cleanup and stale draft eviction (runs every 10 seconds)
         let cleanup_store = self.draft_store.clone();
+        let draft_retention_secs = self.config.api.draft_retention_secs;
         tokio::spawn(async move {
             loop {
                 tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
                 let store = cleanup_store.read().await;
                 store.cleanup_expired().await;
+                store.evict_stale(draft_retention_secs).await;
             }
         });
 
+        // Start background reassignment of drafts signed by silent solvers (runs every 10 seconds)
+        if let Some(liveness_config) = self.config.solver_liveness.clone() {
+            let reassign_store = self.draft_store.clone();
+            let reassign_liveness = self.liveness_store.clone();
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+                    let store = reassign_store.read().await;
+                    let liveness = reassign_liveness.read().await;
+                    store
+                        .reassign_silent_drafts(&liveness, liveness_config.reassign_timeout_secs)
+                        .await;
+                }
+            });
+        }
+
         // Create and configure all API routes
         let routes = self.create_routes();
 
@@ -396,10 +1196,12 @@ impl ApiServer {
     pub(crate) fn create_routes(
         &self,
     ) -> impl Filter<Extract = impl warp::Reply, Error = std::convert::Infallible> + Clone {
-        use super::negotiation;
+        use super::{negotiation, updates};
 
         let monitor = self.monitor.clone();
         let draft_store = self.draft_store.clone();
+        let liveness_store = self.liveness_store.clone();
+        let audit_log = self.audit_log.clone();
 
         // Health check endpoint - returns service status
         let health = warp::path("health").and(warp::get()).map(|| {
@@ -410,46 +1212,101 @@ impl ApiServer {
             })
         });
 
+        // Readiness endpoint - pings every configured chain (cached), 503 if any is unreachable
+        let ready_config = self.config.clone();
+        let ready_cache = self.readiness_cache.clone();
+        let ready = warp::path("ready")
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(with_monitor(monitor.clone()))
+            .and_then(move |monitor: Arc<RwLock<EventMonitor>>| {
+                let config = ready_config.clone();
+                let cache = ready_cache.clone();
+                async move { get_ready_handler(config, monitor, cache).await }
+            });
+
         // Get cached events endpoint - returns all monitored events (read-only)
         let events = warp::path("events")
             .and(warp::get())
             .and(with_monitor(monitor.clone()))
             .and_then(get_events_handler);
 
+        // GET /intents/:intent_id - Intent, fulfillment and negotiation status for one intent
+        let get_intent = warp::path("intents")
+            .and(warp::path::param())
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(with_monitor(monitor.clone()))
+            .and(negotiation::with_draft_store(draft_store.clone()))
+            .and_then(get_intent_handler);
+
+        // GET /audit/:intent_id - The coordinator's own decisions about one intent, oldest first
+        let audit = warp::path("audit")
+            .and(warp::path::param())
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(negotiation::with_audit_log(audit_log.clone()))
+            .and_then(get_audit_handler);
+
         // Get exchange rate endpoint - returns desired token and exchange rate for offered token
         let exchange_rate_config = self.config.clone();
+        let exchange_rate_cache = self.rate_cache.clone();
         let exchange_rate = warp::path("acceptance")
             .and(warp::get())
             .and(warp::query::raw())
             .and_then(move |query: String| {
                 let config = exchange_rate_config.clone();
+                let rate_cache = exchange_rate_cache.clone();
                 async move {
-                    get_exchange_rate_handler(config, query).await
+                    get_exchange_rate_handler(config, rate_cache, query).await
+                }
+            });
+
+        // GET /quote - Quoted target amount for a source amount, from a short-TTL rate cache
+        let quote_config = self.config.clone();
+        let quote_cache = self.rate_cache.clone();
+        let quote = warp::path("quote")
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(warp::query::raw())
+            .and_then(move |query: String| {
+                let config = quote_config.clone();
+                let rate_cache = quote_cache.clone();
+                async move {
+                    get_quote_handler(config, rate_cache, query).await
                 }
             });
 
         // Negotiation routing endpoints
         // POST /draftintent - Submit draft intent (open to any solver)
         let create_draft_store = draft_store.clone();
+        let create_draft_audit_log = audit_log.clone();
+        let create_draft_idempotency = self.idempotency_cache.clone();
         let create_draft = warp::path("draftintent")
             .and(warp::path::end()) // Exact match - don't match /draftintent/:id/...
             .and(warp::post())
+            .and(warp::header::optional::<String>(IDEMPOTENCY_KEY_HEADER))
             .and(warp::body::bytes())
-            .and_then(move |body: Bytes| {
+            .and_then(move |idempotency_key: Option<String>, body: Bytes| {
                 let store = create_draft_store.clone();
+                let audit_log = create_draft_audit_log.clone();
+                let idempotency = create_draft_idempotency.clone();
                 async move {
                     // Log raw request body for debugging
                     let body_str = String::from_utf8_lossy(&body);
                     debug!("POST /draftintent - Received body: {}", body_str);
 
                     // Deserialize and handle
-                    match serde_json::from_slice::<negotiation::DraftintentRequest>(&body) {
-                        Ok(request) => negotiation::create_draftintent_handler(request, store).await,
-                        Err(e) => {
-                            error!("Draft intent deserialization failed: {}. Body: {}", e, body_str);
-                            Err(warp::reject::custom(JsonDeserializeError(format!("Invalid JSON: {}", e))))
+                    let handle = async {
+                        match serde_json::from_slice::<negotiation::DraftintentRequest>(&body) {
+                            Ok(request) => negotiation::create_draftintent_handler(request, store, audit_log).await,
+                            Err(e) => {
+                                error!("Draft intent deserialization failed: {}. Body: {}", e, body_str);
+                                Err(warp::reject::custom(JsonDeserializeError(format!("Invalid JSON: {}", e))))
+                            }
                         }
-                    }
+                    };
+                    with_idempotency(idempotency, "POST /draftintent", idempotency_key, &body, handle).await
                 }
             });
 
@@ -470,30 +1327,58 @@ impl ApiServer {
             .and(negotiation::with_draft_store(get_pending_store))
             .and_then(negotiation::get_pending_drafts_handler);
 
+        // GET /draftintents/stats - Draft counts and expiry/eviction counters
+        let draft_stats = warp::path("draftintents")
+            .and(warp::path("stats"))
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(negotiation::with_draft_store(draft_store.clone()))
+            .and_then(negotiation::get_draft_stats_handler);
+
         // POST /draftintent/:id/signature - Solver submits signature (FCFS)
-        let submit_sig_store = draft_store.clone();
-        let submit_sig_config = self.config.clone();
+        let submit_sig_ctx = negotiation::SignatureSubmissionContext {
+            store: draft_store.clone(),
+            liveness: liveness_store.clone(),
+            config: self.config.clone(),
+            audit_log: audit_log.clone(),
+        };
+        let submit_sig_idempotency = self.idempotency_cache.clone();
         let submit_signature = warp::path("draftintent")
             .and(warp::path::param())
             .and(warp::path("signature"))
             .and(warp::post())
+            .and(warp::header::optional::<String>(negotiation::SOLVER_SIGNATURE_HEADER))
+            .and(warp::header::optional::<String>(IDEMPOTENCY_KEY_HEADER))
             .and(warp::body::bytes())
-            .and_then(move |draft_id: String, body: Bytes| {
-                let store = submit_sig_store.clone();
-                let config = submit_sig_config.clone();
+            .and_then(move |draft_id: String, request_signature: Option<String>, idempotency_key: Option<String>, body: Bytes| {
+                let ctx = submit_sig_ctx.clone();
+                let idempotency = submit_sig_idempotency.clone();
                 async move {
                     // Log raw request body for debugging
                     let body_str = String::from_utf8_lossy(&body);
                     debug!("POST /draftintent/{}/signature - Received body: {}", draft_id, body_str);
 
+                    // Keys are scoped per draft so one key cannot replay another draft's response
+                    let scope = format!("POST /draftintent/{}/signature", draft_id);
+
                     // Deserialize and handle
-                    match serde_json::from_slice::<negotiation::SignatureSubmissionRequest>(&body) {
-                        Ok(request) => negotiation::submit_signature_handler(draft_id, request, store, config).await,
-                        Err(e) => {
-                            error!("Signature submission deserialization failed: {}. Body: {}", e, body_str);
-                            Err(warp::reject::custom(JsonDeserializeError(format!("Invalid JSON: {}", e))))
+                    let handle = async {
+                        match serde_json::from_slice::<negotiation::SignatureSubmissionRequest>(&body) {
+                            Ok(request) => negotiation::submit_signature_handler(
+                                draft_id,
+                                request,
+                                &body,
+                                request_signature,
+                                ctx,
+                            )
+                            .await,
+                            Err(e) => {
+                                error!("Signature submission deserialization failed: {}. Body: {}", e, body_str);
+                                Err(warp::reject::custom(JsonDeserializeError(format!("Invalid JSON: {}", e))))
+                            }
                         }
-                    }
+                    };
+                    with_idempotency(idempotency, &scope, idempotency_key, &body, handle).await
                 }
             });
 
@@ -512,17 +1397,84 @@ impl ApiServer {
                 }
             });
 
+        // POST /solver/heartbeat - Solver reports it is online (signed with its registered key)
+        let heartbeat_config = self.config.clone();
+        let heartbeat = warp::path("solver")
+            .and(warp::path("heartbeat"))
+            .and(warp::path::end())
+            .and(warp::post())
+            .and(warp::header::optional::<String>(negotiation::SOLVER_SIGNATURE_HEADER))
+            .and(warp::body::bytes())
+            .and(negotiation::with_liveness_store(liveness_store.clone()))
+            .and_then(move |request_signature: Option<String>, body: Bytes, liveness: Arc<RwLock<SolverLivenessStore>>| {
+                let config = heartbeat_config.clone();
+                async move {
+                    match serde_json::from_slice::<negotiation::SolverHeartbeatRequest>(&body) {
+                        Ok(request) => negotiation::solver_heartbeat_handler(
+                            request,
+                            &body,
+                            request_signature,
+                            liveness,
+                            config,
+                        )
+                        .await,
+                        Err(e) => Err(warp::reject::custom(JsonDeserializeError(format!("Invalid JSON: {}", e)))),
+                    }
+                }
+            });
+
+        // GET /ws - WebSocket push of new events and draft status changes
+        let hub_chain_id = self.config.hub_chain.chain_id;
+        let live_updates = warp::path("ws")
+            .and(warp::path::end())
+            .and(warp::ws())
+            .and(warp::query::<updates::UpdateFilter>())
+            .and(with_monitor(monitor.clone()))
+            .and_then(
+                move |ws: warp::ws::Ws, filter: updates::UpdateFilter, monitor: Arc<RwLock<EventMonitor>>| async move {
+                    // Subscribe before the upgrade so no update is missed in between
+                    let receiver = monitor.read().await.subscribe_updates();
+                    Ok::<_, Rejection>(ws.on_upgrade(move |socket| {
+                        updates::handle_update_socket(socket, filter, receiver, hub_chain_id)
+                    }))
+                },
+            );
+
+        // GET /metrics - Prometheus metrics (404 unless api.metrics_enabled)
+        let metrics_route = warp::path("metrics")
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(with_monitor(monitor.clone()))
+            .and_then(get_metrics_handler);
+
+        // Count every response by method and status when metrics are enabled
+        let request_metrics = self.metrics.clone();
+        let count_requests = warp::log::custom(move |info| {
+            if let Some(metrics) = &request_metrics {
+                metrics.record_http_request(info.method().as_str(), info.status().as_u16());
+            }
+        });
+
         // Combine all routes and apply rejection handler
         health
+            .or(ready)
             .or(events)
+            .or(get_intent)
+            .or(audit)
             .or(create_draft)
             .or(get_draft)
             .or(get_pending)
+            .or(draft_stats)
             .or(submit_signature)
             .or(get_signature)
+            .or(heartbeat)
             .or(exchange_rate)
+            .or(quote)
+            .or(live_updates)
+            .or(metrics_route)
             .with(create_cors_filter(&self.config.api.cors_origins))
             .recover(handle_rejection)
+            .with(count_requests)
     }
 
     /// Public method for testing - exposes routes for integration tests
@@ -530,4 +1482,10 @@ impl ApiServer {
     pub fn test_routes(&self) -> impl Filter<Extract = impl warp::Reply, Error = std::convert::Infallible> + Clone {
         self.create_routes()
     }
+
+    /// Public method for testing - exposes the exchange rate cache so tests can seed aged rates
+    #[allow(dead_code)] // Used by tests
+    pub fn rate_cache(&self) -> Arc<RateCache> {
+        self.rate_cache.clone()
+    }
 }
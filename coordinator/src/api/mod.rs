@@ -9,17 +9,38 @@
 //! The coordinator API is read-only for blockchain data and provides
 //! negotiation routing. It does NOT hold private keys or generate signatures.
 
-// Generic shared code (health, events, exchange rate, draft intent routing)
+// Generic shared code (health, readiness, events, exchange rate, draft intent routing)
 mod generic;
 
 // Negotiation routing module (draft intent FCFS matching)
 mod negotiation;
 
+// Live update subscriptions (WebSocket)
+mod updates;
+
 // Re-export ApiServer for convenience
-pub use generic::ApiServer;
+pub use generic::{ApiServer, ExchangeRateResponse};
+// Re-export quote types for testing
+#[allow(unused_imports)]
+pub use generic::{IntentNegotiation, QuoteResponse};
+// Re-export the intent details response for testing
+#[allow(unused_imports)]
+pub use generic::IntentDetailsResponse;
+// Re-export the audit trail response for testing
+#[allow(unused_imports)]
+pub use generic::AuditTrailResponse;
 // Re-export ApiResponse for testing
 #[allow(unused_imports)]
 pub use generic::ApiResponse;
+// Re-export readiness types for testing
+#[allow(unused_imports)]
+pub use generic::{ChainReadiness, ReadinessCache, ReadinessReport, READINESS_CACHE_TTL};
+// Re-export the idempotency header name for clients and testing
+#[allow(unused_imports)]
+pub use generic::IDEMPOTENCY_KEY_HEADER;
+// Re-export live update types for clients and testing
+#[allow(unused_imports)]
+pub use updates::{ResyncHint, UpdateFilter};
 // Re-export negotiation validation functions for testing
 #[allow(unused_imports)]
-pub use negotiation::validate_signature_format;
+pub use negotiation::{validate_signature_format, verify_request_signature};
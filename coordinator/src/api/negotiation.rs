@@ -5,6 +5,7 @@
 //! Implements polling-based, FCFS (First Come First Served) negotiation routing.
 
 use anyhow::Result;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -15,7 +16,21 @@ use warp::{http::StatusCode, Filter};
 use crate::api::generic::ApiResponse;
 use crate::config::Config;
 use chain_clients_mvm::MvmClient;
-use crate::storage::{DraftintentStatus, DraftintentStore};
+use crate::storage::{
+    AuditAction, AuditLog, AuditSource, DraftClaimError, DraftintentStatus, DraftintentStore,
+    SolverLivenessStore,
+};
+
+/// Header carrying the solver's signature over the raw request body.
+pub const SOLVER_SIGNATURE_HEADER: &str = "x-solver-signature";
+
+/// Maximum distance in seconds between a heartbeat's timestamp and coordinator time.
+/// Bounds how long a captured signed heartbeat can be replayed to keep a solver live.
+pub const HEARTBEAT_MAX_CLOCK_SKEW_SECS: u64 = 30;
+
+/// Maximum distance in seconds between a signed submission's timestamp and coordinator time.
+/// Bounds how long a captured signed submission can be replayed.
+pub const SUBMISSION_MAX_CLOCK_SKEW_SECS: u64 = 30;
 
 // ============================================================================
 // REQUEST/RESPONSE STRUCTURES
@@ -54,6 +69,8 @@ pub struct DraftintentStatusResponse {
     pub timestamp: u64,
     /// Expiry time
     pub expiry_time: u64,
+    /// Draft version, to pass back as `expected_version` when signing
+    pub version: u64,
 }
 
 /// Request structure for submitting a signature for a draft intent.
@@ -65,6 +82,19 @@ pub struct SignatureSubmissionRequest {
     pub signature: String,
     /// Public key of the solver (hex format)
     pub public_key: String,
+    /// Draft version the solver read; the claim is rejected with 409 if the draft
+    /// changed since. Omit to skip the check (FCFS still applies).
+    #[serde(default)]
+    pub expected_version: Option<u64>,
+    /// Draft the request was signed for. Required when `api.require_signed_submissions`
+    /// is set and must match the path, so a signed body cannot be replayed on another draft.
+    #[serde(default)]
+    pub draft_id: Option<String>,
+    /// Unix timestamp (seconds) when the request was signed. Required when
+    /// `api.require_signed_submissions` is set and must be within
+    /// `SUBMISSION_MAX_CLOCK_SKEW_SECS` of coordinator time.
+    #[serde(default)]
+    pub timestamp: Option<u64>,
 }
 
 /// Response structure for signature submission.
@@ -76,6 +106,17 @@ pub struct SignatureSubmissionResponse {
     pub status: String,
 }
 
+/// Response data for a signature submission that lost the claim (409 Conflict).
+#[derive(Debug, Serialize)]
+pub struct ClaimConflictResponse {
+    /// Unique identifier for the draft
+    pub draft_id: String,
+    /// Solver holding the draft (None if the draft changed but is not signed)
+    pub winning_solver_hub_addr: Option<String>,
+    /// Current draft version
+    pub current_version: u64,
+}
+
 /// Response structure for signature retrieval.
 #[derive(Debug, Serialize)]
 pub struct SignatureResponse {
@@ -91,6 +132,37 @@ pub struct SignatureResponse {
     pub timestamp: u64,
 }
 
+/// Request structure for a solver heartbeat.
+#[derive(Debug, Deserialize)]
+pub struct SolverHeartbeatRequest {
+    /// Hub solver address sending the heartbeat
+    pub solver_hub_addr: String,
+    /// Unix timestamp at which the solver sent the heartbeat
+    pub timestamp: u64,
+}
+
+/// Response structure for a solver heartbeat.
+#[derive(Debug, Serialize)]
+pub struct SolverHeartbeatResponse {
+    /// Hub solver address that sent the heartbeat
+    pub solver_hub_addr: String,
+    /// Recorded last-seen timestamp (Unix timestamp)
+    pub last_seen: u64,
+}
+
+/// Shared state for the signature submission handler.
+#[derive(Clone)]
+pub struct SignatureSubmissionContext {
+    /// The draft intent store
+    pub store: Arc<RwLock<DraftintentStore>>,
+    /// The solver liveness store
+    pub liveness: Arc<RwLock<SolverLivenessStore>>,
+    /// Service configuration (for registry address and liveness window)
+    pub config: Arc<Config>,
+    /// Audit log, records validation results and the claim outcome for the draft's intent
+    pub audit_log: Arc<AuditLog>,
+}
+
 // ============================================================================
 // API HANDLERS
 // ============================================================================
@@ -104,6 +176,7 @@ pub struct SignatureResponse {
 ///
 /// * `store` - The draft intent store
 /// * `request` - The draft intent request
+/// * `audit_log` - Audit log, records the intent as observed when the draft carries an intent ID
 ///
 /// # Returns
 ///
@@ -112,6 +185,7 @@ pub struct SignatureResponse {
 pub async fn create_draftintent_handler(
     request: DraftintentRequest,
     store: Arc<RwLock<DraftintentStore>>,
+    audit_log: Arc<AuditLog>,
 ) -> Result<impl warp::Reply, warp::Rejection> {
     info!(
         "Received draft intent submission from requester: {}",
@@ -120,6 +194,7 @@ pub async fn create_draftintent_handler(
 
     // Generate unique draft ID (UUID)
     let draft_id = Uuid::new_v4().to_string();
+    let intent_id = request.draft_data["intent_id"].as_str().map(str::to_string);
 
     // Add draft to store
     {
@@ -135,6 +210,13 @@ pub async fn create_draftintent_handler(
     }
 
     info!("Created draft intent: {}", draft_id);
+    audit_draft(
+        &audit_log,
+        intent_id.as_deref(),
+        &draft_id,
+        AuditAction::Observed { source: AuditSource::Draft },
+    )
+    .await;
 
     Ok(warp::reply::json(&ApiResponse {
         success: true,
@@ -192,11 +274,36 @@ pub async fn get_draftintent_handler(
             requester_addr: draft.requester_addr,
             timestamp: draft.timestamp,
             expiry_time: draft.expiry_time,
+            version: draft.version,
         }),
         error: None,
     }))
 }
 
+/// Handler for GET /draftintents/stats endpoint.
+///
+/// Returns current draft counts by status and the expiry, eviction and
+/// reassignment counters since startup.
+///
+/// # Arguments
+///
+/// * `store` - The draft intent store
+///
+/// # Returns
+///
+/// * `Ok(warp::Reply)` - JSON response with draft store statistics
+pub async fn get_draft_stats_handler(
+    store: Arc<RwLock<DraftintentStore>>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let stats = store.read().await.stats().await;
+
+    Ok(warp::reply::json(&ApiResponse {
+        success: true,
+        data: Some(stats),
+        error: None,
+    }))
+}
+
 /// Handler for GET /draftintents/pending endpoint.
 ///
 /// Returns all pending drafts. All solvers see all pending drafts (no filtering).
@@ -226,6 +333,7 @@ pub async fn get_pending_drafts_handler(
                 "draft_data": draft.draft_data,
                 "timestamp": draft.timestamp,
                 "expiry_time": draft.expiry_time,
+                "version": draft.version,
             })
         })
         .collect();
@@ -246,40 +354,88 @@ pub async fn get_pending_drafts_handler(
 ///
 /// * `draft_id` - The draft ID to sign
 /// * `request` - The signature submission request
-/// * `store` - The draft intent store
-/// * `config` - Service configuration (for registry address)
+/// * `body` - Raw request body (for request signature verification)
+/// * `request_signature` - Value of the `X-Solver-Signature` header, if present
+/// * `ctx` - Draft store, liveness store, configuration and audit log
 ///
 /// # Returns
 ///
-/// * `Ok(warp::Reply)` - JSON response with draft_id and status (200 OK for first signature, 409 Conflict for later)
+/// * `Ok(warp::Reply)` - JSON response with draft_id and status (200 OK for first signature, 409 Conflict for later,
+///   401 Unauthorized if `api.require_signed_submissions` is set and the request signature is missing or forged,
+///   or the signed body names another draft or a stale timestamp)
 /// * `Err(warp::Rejection)` - Failed to process signature
 pub async fn submit_signature_handler(
     draft_id: String,
     request: SignatureSubmissionRequest,
-    store: Arc<RwLock<DraftintentStore>>,
-    config: Arc<Config>,
+    body: &[u8],
+    request_signature: Option<String>,
+    ctx: SignatureSubmissionContext,
 ) -> Result<impl warp::Reply, warp::Rejection> {
+    let SignatureSubmissionContext { store, liveness, config, audit_log } = ctx;
     info!(
         "Received signature submission for draft {} from solver {}",
         draft_id, request.solver_hub_addr
     );
 
+    // Decisions are audited under the intent ID the draft carries, if any
+    let intent_id = store
+        .read()
+        .await
+        .get_draft(&draft_id)
+        .await
+        .and_then(|draft| draft.draft_data["intent_id"].as_str().map(str::to_string));
+    let audit = |action: AuditAction| {
+        let audit_log = audit_log.clone();
+        let intent_id = intent_id.clone();
+        let draft_id = draft_id.clone();
+        async move { audit_draft(&audit_log, intent_id.as_deref(), &draft_id, action).await }
+    };
+    let validation_failed = |reason: String| AuditAction::Validated { passed: false, reason: Some(reason) };
+
     // Validate solver address format: must have 0x prefix
     if !request.solver_hub_addr.starts_with("0x") {
+        let error = format!(
+            "Invalid solver address '{}': must start with 0x prefix",
+            request.solver_hub_addr
+        );
+        audit(validation_failed(error.clone())).await;
         return Ok(warp::reply::with_status(
             warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
                 success: false,
                 data: None,
-                error: Some(format!(
-                    "Invalid solver address '{}': must start with 0x prefix",
-                    request.solver_hub_addr
-                )),
+                error: Some(error),
             }),
             StatusCode::BAD_REQUEST,
         ));
     }
     let solver_hub_addr = request.solver_hub_addr.clone();
 
+    // Limit assignment to live solvers when liveness tracking is enabled
+    if let Some(liveness_config) = &config.solver_liveness {
+        let liveness_read = liveness.read().await;
+        let is_live = liveness_read
+            .is_live(&solver_hub_addr, liveness_config.liveness_window_secs)
+            .await;
+        drop(liveness_read);
+
+        if !is_live {
+            warn!("Rejecting signature from solver {} without a recent heartbeat", solver_hub_addr);
+            let error = format!(
+                "Solver {} has not sent a heartbeat within the last {} seconds",
+                solver_hub_addr, liveness_config.liveness_window_secs
+            );
+            audit(validation_failed(error.clone())).await;
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
+                    success: false,
+                    data: None,
+                    error: Some(error),
+                }),
+                StatusCode::BAD_REQUEST,
+            ));
+        }
+    }
+
     // Validate solver is registered on-chain
     let solver_registry_addr = &config.hub_chain.intent_module_addr;
     let hub_rpc_url = &config.hub_chain.rpc_url;
@@ -300,12 +456,11 @@ pub async fn submit_signature_handler(
     };
 
     // Check if solver is registered
-    let solver_registered = match mvm_client
+    let solver_public_key = match mvm_client
         .get_solver_public_key(&solver_hub_addr, solver_registry_addr)
         .await
     {
-        Ok(Some(_)) => true,
-        Ok(None) => false,
+        Ok(public_key) => public_key,
         Err(e) => {
             warn!("Failed to query solver registry: {}", e);
             return Ok(warp::reply::with_status(
@@ -319,22 +474,49 @@ pub async fn submit_signature_handler(
         }
     };
 
-    if !solver_registered {
-        return Ok(warp::reply::with_status(
-            warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
-                success: false,
-                data: None,
-                error: Some(format!(
-                    "Solver {} is not registered on-chain",
-                    solver_hub_addr
-                )),
-            }),
-            StatusCode::BAD_REQUEST,
-        ));
+    let solver_public_key = match solver_public_key {
+        Some(public_key) => public_key,
+        None => {
+            let error = format!("Solver {} is not registered on-chain", solver_hub_addr);
+            audit(validation_failed(error.clone())).await;
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
+                    success: false,
+                    data: None,
+                    error: Some(error),
+                }),
+                StatusCode::BAD_REQUEST,
+            ));
+        }
+    };
+
+    // Verify the submitter controls the claimed solver identity
+    if config.api.require_signed_submissions {
+        let verification = match &request_signature {
+            Some(signature) => verify_request_signature(body, signature, &solver_public_key)
+                .and_then(|()| verify_submission_binding(&draft_id, &request)),
+            None => Err(format!(
+                "Missing {} header: submissions must be signed by the solver",
+                SOLVER_SIGNATURE_HEADER
+            )),
+        };
+        if let Err(e) = verification {
+            warn!("Rejecting unauthenticated signature submission for solver {}: {}", solver_hub_addr, e);
+            audit(validation_failed(e.clone())).await;
+            return Ok(warp::reply::with_status(
+                warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
+                    success: false,
+                    data: None,
+                    error: Some(e),
+                }),
+                StatusCode::UNAUTHORIZED,
+            ));
+        }
     }
 
     // Validate signature format
     if let Err(e) = validate_signature_format(&request.signature) {
+        audit(validation_failed(e.clone())).await;
         return Ok(warp::reply::with_status(
             warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
                 success: false,
@@ -345,22 +527,30 @@ pub async fn submit_signature_handler(
         ));
     }
 
-    // Add signature to store (FCFS logic handled in add_signature)
+    // Claim the draft (FCFS compare-and-set handled in claim)
     let store_write = store.write().await;
     let result = store_write
-        .add_signature(
+        .claim(
             &draft_id,
             solver_hub_addr.clone(),
             request.signature.clone(),
             request.public_key.clone(),
+            request.expected_version,
         )
         .await;
 
     drop(store_write);
 
     match result {
-        Ok(()) => {
+        Ok(_) => {
             info!("Successfully added signature for draft {}", draft_id);
+            audit(AuditAction::Validated { passed: true, reason: None }).await;
+            audit(AuditAction::ApprovalSigned {
+                solver_hub_addr: solver_hub_addr.clone(),
+                public_key: request.public_key.clone(),
+                chain_id: config.hub_chain.chain_id,
+            })
+            .await;
             Ok(warp::reply::with_status(
                 warp::reply::json(&ApiResponse {
                     success: true,
@@ -374,27 +564,44 @@ pub async fn submit_signature_handler(
             ))
         }
         Err(e) => {
-            // Check if it's an FCFS conflict (already signed)
-            if e.contains("already signed") {
-                warn!("Draft {} already signed - rejecting duplicate signature", draft_id);
-                Ok(warp::reply::with_status(
-                    warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
-                        success: false,
-                        data: None,
-                        error: Some("Draft already signed by another solver".to_string()),
-                    }),
-                    StatusCode::CONFLICT, // 409 Conflict
-                ))
-            } else {
-                warn!("Failed to add signature for draft {}: {}", draft_id, e);
-                Ok(warp::reply::with_status(
-                    warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
-                        success: false,
-                        data: None,
-                        error: Some(e),
-                    }),
-                    StatusCode::BAD_REQUEST,
-                ))
+            audit(AuditAction::Rejected { reason: e.to_string() }).await;
+            let conflict = match &e {
+                DraftClaimError::AlreadySigned { solver_hub_addr, version } => {
+                    Some((Some(solver_hub_addr.clone()), *version))
+                }
+                DraftClaimError::VersionMismatch { current, solver_hub_addr, .. } => {
+                    Some((solver_hub_addr.clone(), *current))
+                }
+                DraftClaimError::NotFound | DraftClaimError::Expired => None,
+            };
+            match conflict {
+                // FCFS conflict: another solver won, or the draft changed since it was read
+                Some((winning_solver_hub_addr, current_version)) => {
+                    warn!("Rejecting claim on draft {} by {}: {}", draft_id, solver_hub_addr, e);
+                    Ok(warp::reply::with_status(
+                        warp::reply::json(&ApiResponse {
+                            success: false,
+                            data: Some(ClaimConflictResponse {
+                                draft_id,
+                                winning_solver_hub_addr,
+                                current_version,
+                            }),
+                            error: Some(e.to_string()),
+                        }),
+                        StatusCode::CONFLICT, // 409 Conflict
+                    ))
+                }
+                None => {
+                    warn!("Failed to add signature for draft {}: {}", draft_id, e);
+                    Ok(warp::reply::with_status(
+                        warp::reply::json(&ApiResponse::<SignatureSubmissionResponse> {
+                            success: false,
+                            data: None,
+                            error: Some(e.to_string()),
+                        }),
+                        StatusCode::BAD_REQUEST,
+                    ))
+                }
             }
         }
     }
@@ -512,6 +719,131 @@ pub async fn get_signature_handler(
     }
 }
 
+/// Handler for POST /solver/heartbeat endpoint.
+///
+/// Records that a solver is online. Negotiation routing uses the last-seen
+/// timestamp to limit draft assignment to live solvers (when configured).
+/// Heartbeats must be signed with the solver's registered key, so no one can
+/// keep another solver's address live.
+///
+/// # Arguments
+///
+/// * `request` - The heartbeat request
+/// * `body` - Raw request body (for request signature verification)
+/// * `request_signature` - Value of the `X-Solver-Signature` header, if present
+/// * `liveness` - The solver liveness store
+/// * `config` - Service configuration (hub chain solver registry)
+///
+/// # Returns
+///
+/// * `Ok(warp::Reply)` - JSON response with the recorded last-seen timestamp (400 if the address is
+///   invalid, the solver is unregistered or the timestamp is stale, 401 if the request signature is
+///   missing or forged)
+pub async fn solver_heartbeat_handler(
+    request: SolverHeartbeatRequest,
+    body: &[u8],
+    request_signature: Option<String>,
+    liveness: Arc<RwLock<SolverLivenessStore>>,
+    config: Arc<Config>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let reject = |error: String, status: StatusCode| {
+        Ok(warp::reply::with_status(
+            warp::reply::json(&ApiResponse::<SolverHeartbeatResponse> {
+                success: false,
+                data: None,
+                error: Some(error),
+            }),
+            status,
+        ))
+    };
+
+    // Validate solver address format: must have 0x prefix
+    if !request.solver_hub_addr.starts_with("0x") {
+        return reject(
+            format!(
+                "Invalid solver address '{}': must start with 0x prefix",
+                request.solver_hub_addr
+            ),
+            StatusCode::BAD_REQUEST,
+        );
+    }
+
+    // Reject stale heartbeats so a captured signed request cannot be replayed indefinitely
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    if now.abs_diff(request.timestamp) > HEARTBEAT_MAX_CLOCK_SKEW_SECS {
+        return reject(
+            format!(
+                "Heartbeat timestamp {} is more than {} seconds from coordinator time {}",
+                request.timestamp, HEARTBEAT_MAX_CLOCK_SKEW_SECS, now
+            ),
+            StatusCode::BAD_REQUEST,
+        );
+    }
+
+    // Look up the solver's registered public key on the hub chain
+    let mvm_client = match MvmClient::new(&config.hub_chain.rpc_url) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Failed to create MvmClient: {}", e);
+            return reject(
+                "Failed to connect to hub chain".to_string(),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            );
+        }
+    };
+    let solver_public_key = match mvm_client
+        .get_solver_public_key(&request.solver_hub_addr, &config.hub_chain.intent_module_addr)
+        .await
+    {
+        Ok(Some(public_key)) => public_key,
+        Ok(None) => {
+            return reject(
+                format!("Solver {} is not registered on-chain", request.solver_hub_addr),
+                StatusCode::BAD_REQUEST,
+            );
+        }
+        Err(e) => {
+            warn!("Failed to query solver registry: {}", e);
+            return reject(
+                format!("Failed to verify solver registration: {}", e),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            );
+        }
+    };
+
+    // Verify the sender controls the claimed solver identity
+    let verification = match &request_signature {
+        Some(signature) => verify_request_signature(body, signature, &solver_public_key),
+        None => Err(format!(
+            "Missing {} header: heartbeats must be signed by the solver",
+            SOLVER_SIGNATURE_HEADER
+        )),
+    };
+    if let Err(e) = verification {
+        warn!("Rejecting unauthenticated heartbeat for solver {}: {}", request.solver_hub_addr, e);
+        return reject(e, StatusCode::UNAUTHORIZED);
+    }
+
+    let liveness_read = liveness.read().await;
+    let last_seen = liveness_read.record_heartbeat(&request.solver_hub_addr).await;
+    drop(liveness_read);
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ApiResponse {
+            success: true,
+            data: Some(SolverHeartbeatResponse {
+                solver_hub_addr: request.solver_hub_addr,
+                last_seen,
+            }),
+            error: None,
+        }),
+        StatusCode::OK,
+    ))
+}
+
 // ============================================================================
 // VALIDATION HELPERS
 // ============================================================================
@@ -549,6 +881,91 @@ pub fn validate_signature_format(signature: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Verifies a solver's Ed25519 signature over a raw request body.
+///
+/// # Arguments
+///
+/// * `body` - Raw request body bytes as received
+/// * `signature` - Signature string (with or without 0x prefix)
+/// * `public_key` - Solver's registered Ed25519 public key (32 bytes)
+///
+/// # Returns
+///
+/// * `Ok(())` if the signature was made over `body` by the holder of `public_key`
+/// * `Err(String)` with error message otherwise
+pub fn verify_request_signature(body: &[u8], signature: &str, public_key: &[u8]) -> Result<(), String> {
+    validate_signature_format(signature)?;
+    let signature_hex = signature.strip_prefix("0x").unwrap_or(signature);
+    let signature_bytes: [u8; 64] = hex::decode(signature_hex)
+        .map_err(|e| format!("Invalid request signature: {}", e))?
+        .try_into()
+        .map_err(|_| "Invalid request signature: expected 64 bytes".to_string())?;
+
+    let public_key_bytes: [u8; 32] = public_key.try_into().map_err(|_| {
+        format!(
+            "Invalid registered public key: expected 32 bytes, got {}",
+            public_key.len()
+        )
+    })?;
+    let verifying_key = VerifyingKey::from_bytes(&public_key_bytes)
+        .map_err(|e| format!("Invalid registered public key: {}", e))?;
+
+    verifying_key
+        .verify(body, &Signature::from_bytes(&signature_bytes))
+        .map_err(|_| "Request signature does not match the solver's registered key".to_string())
+}
+
+/// Checks that a signed signature submission was made for `draft_id` and recently.
+///
+/// The request signature covers only the body, so the body must name the draft and
+/// carry a timestamp; otherwise a captured submission could be replayed against another
+/// draft or long after it was made.
+///
+/// # Arguments
+///
+/// * `draft_id` - Draft ID from the request path
+/// * `request` - The signature submission request (already signature-verified)
+///
+/// # Returns
+///
+/// * `Ok(())` if the body names `draft_id` and its timestamp is within `SUBMISSION_MAX_CLOCK_SKEW_SECS`
+/// * `Err(String)` with error message otherwise
+fn verify_submission_binding(draft_id: &str, request: &SignatureSubmissionRequest) -> Result<(), String> {
+    match request.draft_id.as_deref() {
+        Some(signed_draft_id) if signed_draft_id == draft_id => {}
+        Some(signed_draft_id) => {
+            return Err(format!(
+                "Request was signed for draft {}, not {}",
+                signed_draft_id, draft_id
+            ))
+        }
+        None => return Err("Signed submissions must include draft_id".to_string()),
+    }
+
+    let timestamp = request
+        .timestamp
+        .ok_or_else(|| "Signed submissions must include timestamp".to_string())?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    if now.abs_diff(timestamp) > SUBMISSION_MAX_CLOCK_SKEW_SECS {
+        return Err(format!(
+            "Submission timestamp {} is more than {} seconds from coordinator time {}",
+            timestamp, SUBMISSION_MAX_CLOCK_SKEW_SECS, now
+        ));
+    }
+    Ok(())
+}
+
+/// Records a decision about a draft in the audit log, under the intent ID the
+/// draft carries. Drafts without an intent ID are not audited.
+async fn audit_draft(audit_log: &AuditLog, intent_id: Option<&str>, draft_id: &str, action: AuditAction) {
+    if let Some(intent_id) = intent_id {
+        audit_log.record(intent_id, Some(draft_id), action).await;
+    }
+}
+
 // ============================================================================
 // WARP FILTER HELPERS
 // ============================================================================
@@ -560,3 +977,18 @@ pub fn with_draft_store(
 {
     warp::any().map(move || store.clone())
 }
+
+/// Helper function to inject AuditLog into handlers.
+pub fn with_audit_log(
+    audit_log: Arc<AuditLog>,
+) -> impl Filter<Extract = (Arc<AuditLog>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || audit_log.clone())
+}
+
+/// Helper function to inject SolverLivenessStore into handlers.
+pub fn with_liveness_store(
+    liveness: Arc<RwLock<SolverLivenessStore>>,
+) -> impl Filter<Extract = (Arc<RwLock<SolverLivenessStore>>,), Error = std::convert::Infallible> + Clone
+{
+    warp::any().map(move || liveness.clone())
+}
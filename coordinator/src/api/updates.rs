@@ -0,0 +1,125 @@
+//! Live update subscriptions
+//!
+//! `GET /ws` upgrades to a WebSocket that pushes each [`CoordinatorUpdate`] as a
+//! JSON text frame as soon as the monitor or draft store records it, so frontends
+//! do not have to poll the REST endpoints. Updates are not replayed: a client
+//! loads the current state over REST once, then applies the pushed changes.
+
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{debug, warn};
+use warp::ws::{Message, WebSocket};
+
+use crate::monitor::{normalize_intent_id, CoordinatorUpdate};
+
+/// Query parameters of `GET /ws`. With both set, an update must match both.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateFilter {
+    /// Only updates for this intent (any hex form, normalized for matching)
+    pub intent_id: Option<String>,
+    /// Only updates involving this chain (hub or connected)
+    pub chain_id: Option<u64>,
+}
+
+impl UpdateFilter {
+    /// Whether `update` passes the filter.
+    ///
+    /// Intent and fulfillment events are hub chain events; an intent event also
+    /// matches its connected chain. Draft updates match the offered and desired
+    /// chains named in the draft data.
+    pub fn matches(&self, update: &CoordinatorUpdate, hub_chain_id: u64) -> bool {
+        let (intent_id, on_chain) = match update {
+            CoordinatorUpdate::IntentCreated(event) => (
+                Some(event.intent_id.as_str()),
+                self.chain_id.is_none_or(|c| {
+                    c == hub_chain_id || event.connected_chain_id == Some(c)
+                }),
+            ),
+            CoordinatorUpdate::Fulfillment(event) => (
+                Some(event.intent_id.as_str()),
+                self.chain_id.is_none_or(|c| c == hub_chain_id),
+            ),
+            CoordinatorUpdate::DraftStatus(draft) => (
+                draft.intent_id.as_deref(),
+                self.chain_id.is_none_or(|c| draft.chain_ids.contains(&c)),
+            ),
+        };
+        let for_intent = match &self.intent_id {
+            Some(wanted) => intent_id
+                .is_some_and(|id| normalize_intent_id(id) == normalize_intent_id(wanted)),
+            None => true,
+        };
+        on_chain && for_intent
+    }
+}
+
+/// Final frame sent to a subscriber that fell too far behind.
+///
+/// The connection is closed after it; the client should reload state over REST
+/// and reconnect.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResyncHint {
+    /// Always "resync"
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Number of updates the subscriber missed
+    pub skipped: u64,
+}
+
+/// Forwards updates matching `filter` to `socket` until either side goes away.
+///
+/// # Arguments
+///
+/// * `socket` - Upgraded WebSocket connection
+/// * `filter` - Subscription filter from the query string
+/// * `updates` - Receiver subscribed before the upgrade
+/// * `hub_chain_id` - Chain ID of the hub, for chain filtering of hub events
+pub async fn handle_update_socket(
+    socket: WebSocket,
+    filter: UpdateFilter,
+    mut updates: broadcast::Receiver<CoordinatorUpdate>,
+    hub_chain_id: u64,
+) {
+    let (mut sink, mut incoming) = socket.split();
+    debug!("Live update subscriber connected: {:?}", filter);
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(update) => {
+                    if !filter.matches(&update, hub_chain_id) {
+                        continue;
+                    }
+                    let frame = match serde_json::to_string(&update) {
+                        Ok(frame) => frame,
+                        Err(e) => {
+                            warn!("Failed to serialize live update: {}", e);
+                            continue;
+                        }
+                    };
+                    if sink.send(Message::text(frame)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Dropping live update subscriber that lagged by {} updates", skipped);
+                    let hint = ResyncHint { kind: "resync".to_string(), skipped };
+                    if let Ok(frame) = serde_json::to_string(&hint) {
+                        let _ = sink.send(Message::text(frame)).await;
+                    }
+                    let _ = sink.send(Message::close()).await;
+                    break;
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = incoming.next() => match message {
+                // Client frames carry no requests; pings are answered by warp
+                Some(Ok(message)) if !message.is_close() => {}
+                _ => break,
+            },
+        }
+    }
+
+    debug!("Live update subscriber disconnected");
+}
@@ -3,6 +3,7 @@
 //! This module handles loading and managing configuration for the coordinator service.
 //! Configuration includes chain endpoints, timing settings, and API settings.
 
+use chain_clients_common::expand_env_vars_in_toml;
 use serde::{Deserialize, Serialize};
 use chain_clients_svm::solana_program::pubkey::Pubkey;
 use std::str::FromStr;
@@ -40,6 +41,11 @@ pub struct Config {
     /// Used to provide exchange rate information to frontend
     #[serde(default)]
     pub acceptance: Option<AcceptanceConfig>,
+    /// Solver liveness tracking (optional). When set, only solvers that sent a
+    /// heartbeat within the liveness window can sign drafts, and signed drafts
+    /// whose solver goes silent are reassigned.
+    #[serde(default)]
+    pub solver_liveness: Option<SolverLivenessConfig>,
 }
 
 /// Configuration for a blockchain connection.
@@ -112,6 +118,53 @@ pub struct CoordinatorConfig {
     pub polling_interval_ms: u64,
     /// Timeout for validation operations in milliseconds
     pub validation_timeout_ms: u64,
+    /// Upper bound for the poll delay while the hub RPC keeps failing. The delay
+    /// doubles on each consecutive failure and resets to `polling_interval_ms` on
+    /// the first success. Default: 60000.
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    /// Upper bound for the poll delay while polls keep finding no new events. After
+    /// `idle_polls_before_widening` such polls in a row the delay doubles per idle
+    /// poll up to this value, and resets to `polling_interval_ms` on the first new
+    /// event. Unset keeps the delay fixed.
+    #[serde(default)]
+    pub max_idle_polling_interval_ms: Option<u64>,
+    /// Idle polls in a row before the poll delay starts widening. Default: 3.
+    #[serde(default = "default_idle_polls_before_widening")]
+    pub idle_polls_before_widening: u32,
+    /// Directory where the intent and fulfillment event caches are persisted as
+    /// JSON and reloaded on startup. Unset keeps the caches in memory only.
+    #[serde(default)]
+    pub event_cache_dir: Option<String>,
+    /// Seconds past `expiry_time` an intent is still accepted when first observed,
+    /// to tolerate clock skew between the coordinator host and the hub chain.
+    /// Default: 5.
+    #[serde(default = "default_expiry_grace_secs")]
+    pub expiry_grace_secs: u64,
+    /// Audit log entries (the coordinator's own decisions per intent, served at
+    /// `GET /audit/:intent_id`) kept in memory. Default: 10000.
+    #[serde(default = "default_audit_log_capacity")]
+    pub audit_log_capacity: usize,
+    /// JSON-lines file that audit entries evicted from memory are appended to, so
+    /// older trails stay queryable and survive restarts. Unset drops them.
+    #[serde(default)]
+    pub audit_log_spill_file: Option<String>,
+}
+
+fn default_max_backoff_ms() -> u64 {
+    60000
+}
+
+fn default_idle_polls_before_widening() -> u32 {
+    3
+}
+
+fn default_expiry_grace_secs() -> u64 {
+    5
+}
+
+fn default_audit_log_capacity() -> usize {
+    10000
 }
 
 /// API server configuration for external communication.
@@ -126,12 +179,32 @@ pub struct ApiConfig {
     pub port: u16,
     /// Allowed CORS origins for cross-origin requests
     pub cors_origins: Vec<String>,
+    /// Require solver signature submissions to be signed with the solver's registered
+    /// key (`X-Solver-Signature` header over the raw request body, which must name the
+    /// draft and carry a recent timestamp). Disabled by default for compatibility with
+    /// solvers that do not sign requests.
+    #[serde(default)]
+    pub require_signed_submissions: bool,
+    /// Seconds a draft is kept after its `expiry_time` before it is removed from the
+    /// draft store. Requesters can still read a draft's final status during this
+    /// window. Default: 3600.
+    #[serde(default = "default_draft_retention_secs")]
+    pub draft_retention_secs: u64,
+    /// Serve Prometheus metrics at `GET /metrics` on the API server (hub poll
+    /// counts and latency, API request counts, event cache sizes). Default: false.
+    #[serde(default)]
+    pub metrics_enabled: bool,
+}
+
+fn default_draft_retention_secs() -> u64 {
+    3600
 }
 
 /// Acceptance criteria configuration for default solver.
 ///
 /// Defines which token pairs are supported. Exchange rates are fetched live
-/// from the solver per request.
+/// from the solver per request, falling back to the last fetched rate within
+/// each pair's `max_rate_staleness_secs`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcceptanceConfig {
     /// Solver URL for live ratio lookup
@@ -139,6 +212,13 @@ pub struct AcceptanceConfig {
     /// Supported token pairs (no ratios)
     #[serde(default)]
     pub pairs: Vec<TokenPairConfig>,
+    /// Seconds a solver rate is reused by `/quote` before the solver is asked again
+    #[serde(default = "default_quote_cache_ttl_secs")]
+    pub quote_cache_ttl_secs: u64,
+}
+
+fn default_quote_cache_ttl_secs() -> u64 {
+    5
 }
 
 /// Acceptance token pair configuration (single entry).
@@ -152,6 +232,67 @@ pub struct TokenPairConfig {
     pub target_chain_id: u64,
     /// Target token address or mint
     pub target_token: String,
+    /// Seconds a cached rate may still be served while the solver is unreachable.
+    /// Older rates are reported as unavailable. 0 (default) never serves a cached rate.
+    #[serde(default)]
+    pub max_rate_staleness_secs: u64,
+}
+
+/// Solver heartbeat and liveness configuration.
+///
+/// Solvers report liveness via `POST /solver/heartbeat`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolverLivenessConfig {
+    /// Seconds since the last heartbeat within which a solver counts as live and may sign drafts
+    #[serde(default = "default_liveness_window_secs")]
+    pub liveness_window_secs: u64,
+    /// Seconds of solver silence after which its signed drafts are reassigned (returned to pending)
+    #[serde(default = "default_reassign_timeout_secs")]
+    pub reassign_timeout_secs: u64,
+}
+
+fn default_liveness_window_secs() -> u64 {
+    60
+}
+
+fn default_reassign_timeout_secs() -> u64 {
+    120
+}
+
+// ============================================================================
+// VALIDATION REPORT
+// ============================================================================
+
+/// Result of validating a configuration: every error found (not just the first) and
+/// warnings for settings that are valid but likely unintended.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    /// Problems that make the configuration unusable, each naming its field path
+    pub errors: Vec<String>,
+    /// Valid settings that leave part of the service idle
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Whether the configuration passed validation (warnings do not count).
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Records the error of a failed check.
+    fn check(&mut self, result: anyhow::Result<()>) {
+        if let Err(e) = result {
+            self.errors.push(format!("{:#}", e));
+        }
+    }
+
+    fn error(&mut self, message: String) {
+        self.errors.push(message);
+    }
+
+    fn warn(&mut self, message: String) {
+        self.warnings.push(message);
+    }
 }
 
 // ============================================================================
@@ -159,24 +300,126 @@ pub struct TokenPairConfig {
 // ============================================================================
 
 impl Config {
-    /// Validates the configuration for duplicate chain IDs.
+    /// Validates the configuration.
     ///
     /// This function ensures that:
+    /// - Each field holds a usable value (required fields per chain type, URL schemes, numeric ranges)
     /// - Hub chain ID is unique
     /// - Connected MVM chain ID (if present) is unique
     /// - Connected EVM chain ID (if present) is unique
     ///
     /// # Returns
     ///
-    /// - `Ok(())` - Configuration is valid
-    /// - `Err(anyhow::Error)` - Duplicate chain IDs detected
-    pub fn validate(&self) -> anyhow::Result<()> {
+    /// - `Ok(warnings)` - Configuration is valid; `warnings` lists settings that are valid but likely unintended
+    /// - `Err(anyhow::Error)` - The first invalid field (error names the field path) or duplicate chain ID
+    pub fn validate(&self) -> anyhow::Result<Vec<String>> {
+        let report = self.validation_report();
+        match report.errors.into_iter().next() {
+            Some(error) => Err(anyhow::anyhow!(error)),
+            None => Ok(report.warnings),
+        }
+    }
+
+    /// Runs every validation check and collects all errors and warnings instead of
+    /// stopping at the first error.
+    pub fn validation_report(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        self.validate_fields(&mut report);
+        self.validate_chain_ids(&mut report);
+        self.validate_acceptance(&mut report);
+        self.collect_warnings(&mut report);
+        report
+    }
+
+    /// Loads the configuration file at `config_path` and reports every problem found,
+    /// including chain addresses and program IDs that do not parse (`--check-config`).
+    ///
+    /// Never fails: a file that cannot be read or parsed is reported as an error.
+    pub fn check(config_path: &str) -> ValidationReport {
+        let config = std::fs::read_to_string(config_path)
+            .map_err(|e| anyhow::anyhow!("Configuration error: failed to read '{}': {}", config_path, e))
+            .and_then(|content| parse_toml::<Config>(config_path, &content));
+        match config {
+            Ok(config) => {
+                let mut report = config.validation_report();
+                config.validate_addresses(&mut report);
+                report
+            }
+            Err(e) => ValidationReport {
+                errors: vec![format!("{:#}", e)],
+                warnings: Vec::new(),
+            },
+        }
+    }
+
+    /// Validates individual fields, reporting each invalid one by its TOML path
+    /// (e.g. `api.port must be 1..65535, got 0`).
+    fn validate_fields(&self, report: &mut ValidationReport) {
+        validate_mvm_chain(report, "hub_chain", &self.hub_chain);
+        for (i, chain) in self.connected_chain_mvm.iter().enumerate() {
+            validate_mvm_chain(report, &format!("connected_chain_mvm[{}]", i), chain);
+        }
+        for (i, chain) in self.connected_chain_evm.iter().enumerate() {
+            let field = format!("connected_chain_evm[{}]", i);
+            report.check(require_non_empty(&format!("{}.name", field), &chain.name));
+            report.check(require_http_url(&format!("{}.rpc_url", field), &chain.rpc_url));
+            report.check(require_non_empty(&format!("{}.escrow_contract_addr", field), &chain.escrow_contract_addr));
+            report.check(require_positive(&format!("{}.event_block_range", field), chain.event_block_range));
+        }
+        for (i, chain) in self.connected_chain_svm.iter().enumerate() {
+            let field = format!("connected_chain_svm[{}]", i);
+            report.check(require_non_empty(&format!("{}.name", field), &chain.name));
+            report.check(require_http_url(&format!("{}.rpc_url", field), &chain.rpc_url));
+            report.check(require_non_empty(&format!("{}.escrow_program_id", field), &chain.escrow_program_id));
+        }
+
+        report.check(require_positive("coordinator.polling_interval_ms", self.coordinator.polling_interval_ms));
+        report.check(require_positive("coordinator.validation_timeout_ms", self.coordinator.validation_timeout_ms));
+        if let Some(dir) = &self.coordinator.event_cache_dir {
+            report.check(require_non_empty("coordinator.event_cache_dir", dir));
+        }
+        if self.coordinator.max_backoff_ms < self.coordinator.polling_interval_ms {
+            report.error(format!(
+                "Configuration error: coordinator.max_backoff_ms must be at least coordinator.polling_interval_ms ({}), got {}",
+                self.coordinator.polling_interval_ms,
+                self.coordinator.max_backoff_ms
+            ));
+        }
+        if let Some(max_idle_ms) = self.coordinator.max_idle_polling_interval_ms {
+            if max_idle_ms < self.coordinator.polling_interval_ms {
+                report.error(format!(
+                    "Configuration error: coordinator.max_idle_polling_interval_ms must be at least coordinator.polling_interval_ms ({}), got {}",
+                    self.coordinator.polling_interval_ms,
+                    max_idle_ms
+                ));
+            }
+        }
+
+        report.check(require_non_empty("api.host", &self.api.host));
+        report.check(require_port("api.port", self.api.port));
+        report.check(require_positive("api.draft_retention_secs", self.api.draft_retention_secs));
+
+        if let Some(acceptance) = &self.acceptance {
+            report.check(require_http_url("acceptance.solver_url", &acceptance.solver_url));
+        }
+
+        if let Some(liveness) = &self.solver_liveness {
+            if liveness.liveness_window_secs == 0 || liveness.reassign_timeout_secs == 0 {
+                report.error(
+                    "Configuration error: solver_liveness.liveness_window_secs and reassign_timeout_secs must be greater than zero".to_string()
+                );
+            }
+        }
+    }
+
+    /// Ensures the hub and all connected MVM/EVM/SVM chains have unique chain IDs.
+    fn validate_chain_ids(&self, report: &mut ValidationReport) {
         let hub_chain_id = self.hub_chain.chain_id;
 
         // Check all MVM chains against hub and each other
         for mvm_config in &self.connected_chain_mvm {
             if hub_chain_id == mvm_config.chain_id {
-                return Err(anyhow::anyhow!(
+                report.error(format!(
                     "Configuration error: Hub chain and connected MVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                     hub_chain_id, mvm_config.name
                 ));
@@ -185,7 +428,7 @@ impl Config {
         for i in 0..self.connected_chain_mvm.len() {
             for j in (i + 1)..self.connected_chain_mvm.len() {
                 if self.connected_chain_mvm[i].chain_id == self.connected_chain_mvm[j].chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected MVM chains '{}' and '{}' have the same chain ID {}. Each chain must have a unique chain ID.",
                         self.connected_chain_mvm[i].name, self.connected_chain_mvm[j].name, self.connected_chain_mvm[i].chain_id
                     ));
@@ -196,14 +439,14 @@ impl Config {
         // Check all SVM chains against hub, MVM, and each other
         for svm_config in &self.connected_chain_svm {
             if hub_chain_id == svm_config.chain_id {
-                return Err(anyhow::anyhow!(
+                report.error(format!(
                     "Configuration error: Hub chain and connected SVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                     hub_chain_id, svm_config.name
                 ));
             }
             for mvm_config in &self.connected_chain_mvm {
                 if mvm_config.chain_id == svm_config.chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected MVM chain and connected SVM chain have the same chain ID {}. Each chain must have a unique chain ID.",
                         svm_config.chain_id
                     ));
@@ -213,7 +456,7 @@ impl Config {
         for i in 0..self.connected_chain_svm.len() {
             for j in (i + 1)..self.connected_chain_svm.len() {
                 if self.connected_chain_svm[i].chain_id == self.connected_chain_svm[j].chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected SVM chains '{}' and '{}' have the same chain ID {}. Each chain must have a unique chain ID.",
                         self.connected_chain_svm[i].name, self.connected_chain_svm[j].name, self.connected_chain_svm[i].chain_id
                     ));
@@ -224,14 +467,14 @@ impl Config {
         // Check all EVM chains against hub, MVM, SVM, and each other
         for evm_config in &self.connected_chain_evm {
             if hub_chain_id == evm_config.chain_id {
-                return Err(anyhow::anyhow!(
+                report.error(format!(
                     "Configuration error: Hub chain and connected EVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                     hub_chain_id, evm_config.name
                 ));
             }
             for mvm_config in &self.connected_chain_mvm {
                 if mvm_config.chain_id == evm_config.chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected MVM chain and connected EVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                         evm_config.chain_id, evm_config.name
                     ));
@@ -239,7 +482,7 @@ impl Config {
             }
             for svm_config in &self.connected_chain_svm {
                 if evm_config.chain_id == svm_config.chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected EVM chain '{}' and connected SVM chain have the same chain ID {}. Each chain must have a unique chain ID.",
                         evm_config.name, evm_config.chain_id
                     ));
@@ -249,35 +492,100 @@ impl Config {
         for i in 0..self.connected_chain_evm.len() {
             for j in (i + 1)..self.connected_chain_evm.len() {
                 if self.connected_chain_evm[i].chain_id == self.connected_chain_evm[j].chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected EVM chains '{}' and '{}' have the same chain ID {}. Each chain must have a unique chain ID.",
                         self.connected_chain_evm[i].name, self.connected_chain_evm[j].name, self.connected_chain_evm[i].chain_id
                     ));
                 }
             }
         }
+    }
+
+    /// Validates that acceptance pairs reference configured chains and that their
+    /// tokens are well-formed for those chains.
+    fn validate_acceptance(&self, report: &mut ValidationReport) {
+        let Some(acceptance) = &self.acceptance else {
+            return;
+        };
+        for pair in &acceptance.pairs {
+            match self.chain_type_for_id(pair.source_chain_id) {
+                Some(chain_type) => report.check(
+                    validate_token_format(pair.source_token.as_str(), chain_type)
+                        .map_err(|e| anyhow::anyhow!("Invalid source_token for chain {}: {}", chain_type, e)),
+                ),
+                None => report.error(format!(
+                    "Unknown source_chain_id {} in acceptance pair",
+                    pair.source_chain_id
+                )),
+            }
+            match self.chain_type_for_id(pair.target_chain_id) {
+                Some(chain_type) => report.check(
+                    validate_token_format(pair.target_token.as_str(), chain_type)
+                        .map_err(|e| anyhow::anyhow!("Invalid target_token for chain {}: {}", chain_type, e)),
+                ),
+                None => report.error(format!(
+                    "Unknown target_chain_id {} in acceptance pair",
+                    pair.target_chain_id
+                )),
+            }
+        }
+    }
 
+    /// Warns about settings that are valid but leave part of the service idle.
+    fn collect_warnings(&self, report: &mut ValidationReport) {
+        if self.connected_chain_mvm.is_empty()
+            && self.connected_chain_evm.is_empty()
+            && self.connected_chain_svm.is_empty()
+        {
+            report.warn("no connected chains are configured; only hub events are monitored".to_string());
+        }
         if let Some(acceptance) = &self.acceptance {
-            for pair in &acceptance.pairs {
-                let source_chain_type = self.chain_type_for_id(pair.source_chain_id)
-                    .ok_or_else(|| anyhow::anyhow!(
-                        "Unknown source_chain_id {} in acceptance pair",
-                        pair.source_chain_id
-                    ))?;
-                let target_chain_type = self.chain_type_for_id(pair.target_chain_id)
-                    .ok_or_else(|| anyhow::anyhow!(
-                        "Unknown target_chain_id {} in acceptance pair",
-                        pair.target_chain_id
-                    ))?;
-
-                validate_token_format(pair.source_token.as_str(), source_chain_type)
-                    .map_err(|e| anyhow::anyhow!("Invalid source_token for chain {}: {}", source_chain_type, e))?;
-                validate_token_format(pair.target_token.as_str(), target_chain_type)
-                    .map_err(|e| anyhow::anyhow!("Invalid target_token for chain {}: {}", target_chain_type, e))?;
+            if acceptance.pairs.is_empty() {
+                report.warn("acceptance.pairs is empty; no exchange rates are served".to_string());
             }
         }
+        if let Some(liveness) = &self.solver_liveness {
+            if liveness.reassign_timeout_secs < liveness.liveness_window_secs {
+                report.warn(format!(
+                    "solver_liveness.reassign_timeout_secs ({}) is shorter than liveness_window_secs ({}); drafts of solvers that still count as live can be reassigned",
+                    liveness.reassign_timeout_secs, liveness.liveness_window_secs
+                ));
+            }
+        }
+    }
 
-        Ok(())
+    /// Checks that the chain addresses and program IDs the monitor uses parse for
+    /// their chain type.
+    ///
+    /// The monitor only parses these when it first queries a chain, so a typo would
+    /// otherwise surface after startup.
+    fn validate_addresses(&self, report: &mut ValidationReport) {
+        let mvm_chains = std::iter::once(("hub_chain".to_string(), &self.hub_chain)).chain(
+            self.connected_chain_mvm
+                .iter()
+                .enumerate()
+                .map(|(i, chain)| (format!("connected_chain_mvm[{}]", i), chain)),
+        );
+        for (field, chain) in mvm_chains {
+            report.check(require_mvm_address(&format!("{}.intent_module_addr", field), &chain.intent_module_addr));
+            if let Some(addr) = &chain.escrow_module_addr {
+                report.check(require_mvm_address(&format!("{}.escrow_module_addr", field), addr));
+            }
+        }
+        for (i, chain) in self.connected_chain_evm.iter().enumerate() {
+            let field = format!("connected_chain_evm[{}]", i);
+            report.check(require_evm_address(&format!("{}.escrow_contract_addr", field), &chain.escrow_contract_addr));
+            report.check(require_evm_address(
+                &format!("{}.outflow_validator_contract_addr", field),
+                &chain.outflow_validator_contract_addr,
+            ));
+        }
+        for (i, chain) in self.connected_chain_svm.iter().enumerate() {
+            report.check(require_svm_program_id(
+                &format!("connected_chain_svm[{}].escrow_program_id", i),
+                &chain.escrow_program_id,
+            ));
+        }
     }
 
     /// Resolves chain type for a chain ID based on configured chains.
@@ -332,9 +640,11 @@ impl Config {
         if std::path::Path::new(&config_path).exists() {
             // Load existing configuration
             let content = std::fs::read_to_string(&config_path)?;
-            let config: Config = toml::from_str(&content)?;
+            let config: Config = parse_toml(&config_path, &content)?;
             // Validate configuration
-            config.validate()?;
+            for warning in config.validate()? {
+                tracing::warn!("Configuration warning: {}", warning);
+            }
             Ok(config)
         } else {
             // Configuration file doesn't exist - user needs to copy template
@@ -366,19 +676,42 @@ impl Config {
             coordinator: CoordinatorConfig {
                 polling_interval_ms: 2000,
                 validation_timeout_ms: 30000,
+                max_backoff_ms: 60000,
+                max_idle_polling_interval_ms: None,
+                idle_polls_before_widening: 3,
+                event_cache_dir: None,
+                expiry_grace_secs: 5,
+                audit_log_capacity: 10000,
+                audit_log_spill_file: None,
             },
             api: ApiConfig {
                 host: "127.0.0.1".to_string(),
                 port: 3333,
                 cors_origins: vec!["http://localhost:3333".to_string()],
+                require_signed_submissions: false,
+                draft_retention_secs: 3600,
+                metrics_enabled: false,
             },
             connected_chain_evm: vec![], // No connected EVM chains by default
             connected_chain_svm: vec![], // No connected SVM chains by default
             acceptance: None, // Optional acceptance criteria
+            solver_liveness: None, // Solver liveness tracking disabled by default
         }
     }
 }
 
+/// Parses TOML read from `source`, expanding `${ENV_VAR}` references in string values.
+fn parse_toml<T: serde::de::DeserializeOwned>(source: &str, content: &str) -> anyhow::Result<T> {
+    let mut value: toml::Value = toml::from_str(content).map_err(|e| {
+        anyhow::anyhow!("Configuration error: failed to parse '{}': {}", source, e)
+    })?;
+    expand_env_vars_in_toml(&mut value)
+        .map_err(|e| anyhow::anyhow!("Configuration error: in '{}': {}", source, e))?;
+    value.try_into().map_err(|e| {
+        anyhow::anyhow!("Configuration error: failed to parse '{}': {}", source, e)
+    })
+}
+
 /// Validates token address format for a chain type.
 ///
 /// - MVM/EVM: `0x`-prefixed hex with expected byte length.
@@ -430,3 +763,73 @@ fn validate_hex_token(token: &str, expected_len: usize) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+/// Validates the required fields of an MVM chain section (hub or connected).
+fn validate_mvm_chain(report: &mut ValidationReport, field: &str, chain: &ChainConfig) {
+    report.check(require_non_empty(&format!("{}.name", field), &chain.name));
+    report.check(require_http_url(&format!("{}.rpc_url", field), &chain.rpc_url));
+    report.check(require_non_empty(&format!("{}.intent_module_addr", field), &chain.intent_module_addr));
+}
+
+fn require_non_empty(field: &str, value: &str) -> anyhow::Result<()> {
+    if value.trim().is_empty() {
+        anyhow::bail!("Configuration error: {} must not be empty", field);
+    }
+    Ok(())
+}
+
+fn require_positive(field: &str, value: u64) -> anyhow::Result<()> {
+    if value == 0 {
+        anyhow::bail!("Configuration error: {} must be greater than 0, got 0", field);
+    }
+    Ok(())
+}
+
+fn require_port(field: &str, port: u16) -> anyhow::Result<()> {
+    if port == 0 {
+        anyhow::bail!("Configuration error: {} must be 1..65535, got 0", field);
+    }
+    Ok(())
+}
+
+fn require_http_url(field: &str, url: &str) -> anyhow::Result<()> {
+    require_non_empty(field, url)?;
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        anyhow::bail!(
+            "Configuration error: {} must start with http:// or https://, got '{}'",
+            field,
+            url
+        );
+    }
+    Ok(())
+}
+
+fn require_mvm_address(field: &str, addr: &str) -> anyhow::Result<()> {
+    let hex_str = addr.strip_prefix("0x").unwrap_or(addr);
+    if hex_str.is_empty() || hex_str.len() > 64 || hex::decode(format!("{:0>64}", hex_str)).is_err() {
+        anyhow::bail!("Configuration error: {} is not a valid MVM address, got '{}'", field, addr);
+    }
+    Ok(())
+}
+
+fn require_evm_address(field: &str, addr: &str) -> anyhow::Result<()> {
+    let hex_str = addr.strip_prefix("0x").unwrap_or(addr);
+    match hex::decode(hex_str) {
+        Ok(bytes) if bytes.len() == 20 => Ok(()),
+        _ => anyhow::bail!(
+            "Configuration error: {} is not a valid EVM address (20 bytes hex), got '{}'",
+            field,
+            addr
+        ),
+    }
+}
+
+fn require_svm_program_id(field: &str, program_id: &str) -> anyhow::Result<()> {
+    Pubkey::from_str(program_id).map(|_| ()).map_err(|_| {
+        anyhow::anyhow!(
+            "Configuration error: {} is not a valid base58 SVM program ID, got '{}'",
+            field,
+            program_id
+        )
+    })
+}
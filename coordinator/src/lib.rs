@@ -6,12 +6,15 @@
 
 pub mod api;
 pub mod config;
+pub mod metrics;
 pub mod monitor;
 pub mod storage;
 
 // Re-export storage types for tests
 pub use storage::draftintents::{DraftintentStatus, DraftintentStore};
+pub use storage::solver_liveness::SolverLivenessStore;
+pub use storage::rate_cache::RateCache;
 
 // Re-export commonly used types
-pub use config::{ApiConfig, ChainConfig, Config, CoordinatorConfig, EvmChainConfig, SvmChainConfig};
+pub use config::{ApiConfig, ChainConfig, Config, CoordinatorConfig, EvmChainConfig, SolverLivenessConfig, SvmChainConfig, ValidationReport};
 pub use monitor::{EventMonitor, FulfillmentEvent, IntentEvent};
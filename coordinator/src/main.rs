@@ -18,14 +18,16 @@
 //! and approval signing is handled by the separate Integrated GMP service.
 
 use anyhow::Result;
+use chain_clients_common::init_logging;
 use tracing::info;
 
 mod api;
 mod config;
+mod metrics;
 mod monitor;
 mod storage;
 
-use config::Config;
+use config::{Config, ValidationReport};
 
 // ============================================================================
 // MAIN APPLICATION ENTRY POINT
@@ -41,14 +43,14 @@ use config::Config;
 /// 5. Runs the service until shutdown
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Parse command line arguments
+    let args: Vec<String> = std::env::args().collect();
+
     // Initialize structured logging for debugging and monitoring
-    tracing_subscriber::fmt::init();
+    init_logging("coordinator", args.iter().any(|arg| arg == "--log-json"));
 
     info!("Starting Coordinator Service");
 
-    // Parse command line arguments
-    let args: Vec<String> = std::env::args().collect();
-
     // Check for help flag
     if args.iter().any(|arg| arg == "--help" || arg == "-h") {
         println!("Coordinator Service");
@@ -58,10 +60,13 @@ async fn main() -> Result<()> {
         println!("Options:");
         println!("  --testnet, -t    Use testnet configuration (config/coordinator_testnet.toml)");
         println!("  --config <path>   Use custom config file path (overrides --testnet)");
+        println!("  --check-config    Validate the config file, print a report and exit (non-zero if invalid)");
+        println!("  --log-json        Log one JSON object per line (same as LOG_FORMAT=json)");
         println!("  --help, -h        Show this help message");
         println!();
         println!("Environment variables:");
         println!("  COORDINATOR_CONFIG_PATH    Path to config file (overrides --config and --testnet)");
+        println!("  LOG_FORMAT                 \"json\" for JSON logs (default: human-readable)");
         return Ok(());
     }
 
@@ -83,6 +88,18 @@ async fn main() -> Result<()> {
         info!("Using testnet configuration");
     }
 
+    // Validate only: no chain is contacted and the API server is not started
+    if args.iter().any(|arg| arg == "--check-config") {
+        let config_path = std::env::var("COORDINATOR_CONFIG_PATH")
+            .unwrap_or_else(|_| "config/coordinator.toml".to_string());
+        let report = Config::check(&config_path);
+        print_config_report(&config_path, &report);
+        if !report.is_valid() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Load configuration from config file (or COORDINATOR_CONFIG_PATH env var)
     let config = Config::load()?;
     info!("Configuration loaded successfully");
@@ -109,3 +126,21 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================================
+// CONFIG CHECK
+// ============================================================================
+
+/// Print the `--check-config` report: every error and warning, then the verdict.
+fn print_config_report(config_path: &str, report: &ValidationReport) {
+    println!("Config check: {}", config_path);
+    println!("Errors ({}):", report.errors.len());
+    for error in &report.errors {
+        println!("  - {}", error);
+    }
+    println!("Warnings ({}):", report.warnings.len());
+    for warning in &report.warnings {
+        println!("  - {}", warning);
+    }
+    println!("Result: {}", if report.is_valid() { "valid" } else { "invalid" });
+}
@@ -0,0 +1,109 @@
+//! Prometheus Metrics Module
+//!
+//! Counters, gauges and histograms for the coordinator, served as `GET /metrics`
+//! on the API server when `[api] metrics_enabled = true`. When disabled the
+//! monitor holds no metrics and the instrumented paths skip recording entirely.
+//!
+//! Exported metrics:
+//! - `coordinator_hub_polls_total` and `coordinator_hub_poll_errors_total`
+//! - `coordinator_hub_poll_duration_seconds`
+//! - `coordinator_http_requests_total{method, status}`
+//! - `coordinator_cached_intents` and `coordinator_cached_fulfillments` (set at scrape time)
+
+use anyhow::Result;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use std::time::Duration;
+
+/// Coordinator metrics and the registry they are exported from.
+///
+/// Cloning shares the underlying metrics, so every clone of the monitor records
+/// into the same registry.
+#[derive(Clone)]
+pub struct CoordinatorMetrics {
+    registry: Registry,
+    hub_polls: IntCounter,
+    hub_poll_errors: IntCounter,
+    hub_poll_duration: Histogram,
+    http_requests: IntCounterVec,
+    cached_intents: IntGauge,
+    cached_fulfillments: IntGauge,
+}
+
+impl CoordinatorMetrics {
+    /// Create and register all coordinator metrics in a fresh registry.
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+        let hub_polls = IntCounter::new("coordinator_hub_polls_total", "Hub chain polls")?;
+        let hub_poll_errors = IntCounter::new(
+            "coordinator_hub_poll_errors_total",
+            "Hub chain polls that failed with an RPC error",
+        )?;
+        let hub_poll_duration = Histogram::with_opts(HistogramOpts::new(
+            "coordinator_hub_poll_duration_seconds",
+            "Time spent polling the hub chain",
+        ))?;
+        let http_requests = IntCounterVec::new(
+            Opts::new("coordinator_http_requests_total", "API requests by method and status code"),
+            &["method", "status"],
+        )?;
+        let cached_intents = IntGauge::new("coordinator_cached_intents", "Intent events in the event cache")?;
+        let cached_fulfillments = IntGauge::new(
+            "coordinator_cached_fulfillments",
+            "Fulfillment events in the event cache",
+        )?;
+
+        registry.register(Box::new(hub_polls.clone()))?;
+        registry.register(Box::new(hub_poll_errors.clone()))?;
+        registry.register(Box::new(hub_poll_duration.clone()))?;
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(cached_intents.clone()))?;
+        registry.register(Box::new(cached_fulfillments.clone()))?;
+
+        Ok(Self {
+            registry,
+            hub_polls,
+            hub_poll_errors,
+            hub_poll_duration,
+            http_requests,
+            cached_intents,
+            cached_fulfillments,
+        })
+    }
+
+    /// Record one hub chain poll, its duration, and whether it failed.
+    pub fn record_hub_poll(&self, elapsed: Duration, failed: bool) {
+        self.hub_polls.inc();
+        if failed {
+            self.hub_poll_errors.inc();
+        }
+        self.hub_poll_duration.observe(elapsed.as_secs_f64());
+    }
+
+    /// Record one API request by method and response status code.
+    pub fn record_http_request(&self, method: &str, status: u16) {
+        self.http_requests
+            .with_label_values(&[method, &status.to_string()])
+            .inc();
+    }
+
+    /// Set the event cache gauges.
+    pub fn set_cache_sizes(&self, intents: usize, fulfillments: usize) {
+        self.cached_intents.set(intents as i64);
+        self.cached_fulfillments.set(fulfillments as i64);
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Content type of [`render`](Self::render) output.
+    pub fn content_type(&self) -> String {
+        TextEncoder::new().format_type().to_string()
+    }
+}
@@ -3,11 +3,17 @@
 //! This module contains shared event structures and the EventMonitor struct definition
 //! that are used across all flow types (inflow/outflow) and chain types (Move VM/EVM).
 
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{broadcast, Mutex, RwLock};
 
 use crate::config::Config;
+use crate::metrics::CoordinatorMetrics;
+use crate::storage::{AuditLog, DraftintentStatus};
+use crate::storage::{JsonFileBackend, MemoryBackend, StorageBackend};
 
 // Re-export normalize functions from chain-clients-common
 pub use chain_clients_common::{normalize_intent_id, normalize_intent_id_to_64_chars};
@@ -71,6 +77,148 @@ pub struct FulfillmentEvent {
     pub timestamp: u64,
 }
 
+// ============================================================================
+// LIVE UPDATES
+// ============================================================================
+
+/// Updates buffered per subscriber before the slowest one starts lagging.
+pub const UPDATE_CHANNEL_CAPACITY: usize = 1024;
+
+/// Change pushed to live subscribers (the `/ws` endpoint) as it is cached.
+///
+/// Serialized with a `type` tag, e.g. `{"type":"intent_created","intent_id":...}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoordinatorUpdate {
+    /// A new intent creation event was cached
+    IntentCreated(IntentEvent),
+    /// A new fulfillment event was cached
+    Fulfillment(FulfillmentEvent),
+    /// A draft was submitted or changed status
+    DraftStatus(DraftStatusUpdate),
+}
+
+/// Draft status change carried by [`CoordinatorUpdate::DraftStatus`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftStatusUpdate {
+    /// Draft ID used for negotiation routing
+    pub draft_id: String,
+    /// Intent ID from the draft data, if the requester supplied one
+    pub intent_id: Option<String>,
+    /// Offered and desired chain IDs from the draft data, where present
+    pub chain_ids: Vec<u64>,
+    /// New draft status
+    pub status: DraftintentStatus,
+    /// Hub address of the solver holding the draft (None unless signed)
+    pub solver_hub_addr: Option<String>,
+    /// Draft version after the change
+    pub version: u64,
+}
+
+// ============================================================================
+// POLL BACKOFF
+// ============================================================================
+
+/// Delay between hub chain polls, backing off while the RPC keeps failing and
+/// (optionally) while it keeps returning nothing new.
+///
+/// Each consecutive failure doubles the delay up to `max_ms`; the first success
+/// resets it to the base polling interval. Failure delays are jittered down by up
+/// to 20% so restarted coordinators do not retry in lockstep.
+///
+/// With idle widening enabled, successful polls without new events past
+/// `idle_threshold` in a row also double the delay, up to `max_idle_ms`; the first
+/// poll with a new event resets it.
+#[derive(Debug, Clone)]
+pub struct PollBackoff {
+    /// Delay after a successful poll (`polling_interval_ms`)
+    base_ms: u64,
+    /// Upper bound for the delay (`max_backoff_ms`)
+    max_ms: u64,
+    /// Upper bound for the delay while idle (`max_idle_polling_interval_ms`)
+    max_idle_ms: u64,
+    /// Idle polls in a row before the delay starts widening (`idle_polls_before_widening`)
+    idle_threshold: u32,
+    /// Delay before jitter for the next poll
+    current_ms: u64,
+    /// Failed polls since the last success
+    consecutive_failures: u32,
+    /// Successful polls without new events since the last one with new events
+    consecutive_idle_polls: u32,
+}
+
+impl PollBackoff {
+    /// Creates a backoff starting at `base_ms`, capped at `max_ms` (at least `base_ms`).
+    pub fn new(base_ms: u64, max_ms: u64) -> Self {
+        Self {
+            base_ms,
+            max_ms: max_ms.max(base_ms),
+            max_idle_ms: base_ms,
+            idle_threshold: 0,
+            current_ms: base_ms,
+            consecutive_failures: 0,
+            consecutive_idle_polls: 0,
+        }
+    }
+
+    /// Widens the delay after `idle_threshold` idle polls in a row, up to `max_idle_ms`
+    /// (at least the base delay).
+    pub fn with_idle_widening(mut self, max_idle_ms: u64, idle_threshold: u32) -> Self {
+        self.max_idle_ms = max_idle_ms.max(self.base_ms);
+        self.idle_threshold = idle_threshold;
+        self
+    }
+
+    /// Current delay before jitter, in milliseconds.
+    #[allow(dead_code)] // Used by tests
+    pub fn current_ms(&self) -> u64 {
+        self.current_ms
+    }
+
+    /// Failed polls since the last success.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Successful polls without new events since the last one with new events.
+    #[allow(dead_code)] // Used by tests
+    pub fn consecutive_idle_polls(&self) -> u32 {
+        self.consecutive_idle_polls
+    }
+
+    /// Records a successful poll that found new events and returns the base delay.
+    pub fn on_success(&mut self) -> Duration {
+        self.current_ms = self.base_ms;
+        self.consecutive_failures = 0;
+        self.consecutive_idle_polls = 0;
+        Duration::from_millis(self.base_ms)
+    }
+
+    /// Records a successful poll that found no new events and returns the delay
+    /// before the next one (the base delay until idle widening kicks in).
+    pub fn on_idle(&mut self) -> Duration {
+        self.consecutive_failures = 0;
+        self.consecutive_idle_polls = self.consecutive_idle_polls.saturating_add(1);
+        let widenings = self.consecutive_idle_polls.saturating_sub(self.idle_threshold);
+        self.current_ms = if widenings == 0 {
+            self.base_ms
+        } else {
+            self.base_ms
+                .saturating_mul(2u64.saturating_pow(widenings))
+                .min(self.max_idle_ms)
+        };
+        Duration::from_millis(self.current_ms)
+    }
+
+    /// Records a failed poll and returns the jittered delay before the next one.
+    pub fn on_failure(&mut self) -> Duration {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.current_ms = self.current_ms.saturating_mul(2).min(self.max_ms);
+        let jitter_ms = rand::thread_rng().gen_range(0..=self.current_ms / 5);
+        Duration::from_millis(self.current_ms - jitter_ms)
+    }
+}
+
 // ============================================================================
 // EVENT MONITOR STRUCTURE
 // ============================================================================
@@ -102,13 +250,34 @@ pub struct EventMonitor {
     /// It should not be accessed directly in production code.
     #[doc(hidden)]
     pub fulfillment_cache: Arc<RwLock<Vec<FulfillmentEvent>>>,
+    /// Position of each cached intent event in `event_cache`, keyed by normalized intent ID
+    intent_index: Arc<RwLock<HashMap<String, usize>>>,
+    /// Position of each cached fulfillment event in `fulfillment_cache`, keyed by normalized intent ID
+    fulfillment_index: Arc<RwLock<HashMap<String, usize>>>,
+    /// Hub chain poll delay, grown on consecutive RPC failures
+    pub poll_backoff: Arc<Mutex<PollBackoff>>,
+    /// Unix timestamp of the last successful hub chain poll (None until the first success)
+    last_hub_poll_at: Arc<RwLock<Option<u64>>>,
+    /// Persistence for the event caches (memory-only unless `event_cache_dir` is set)
+    storage: Arc<dyn StorageBackend>,
+    /// Live update channel, written whenever a new event is cached
+    updates: broadcast::Sender<CoordinatorUpdate>,
+    /// Normalized IDs of intents skipped as expired, so each is warned about once
+    expired_intents: Arc<Mutex<HashSet<String>>>,
+    /// Normalized IDs of intents rejected as revocable, so each is audited once
+    revocable_intents: Arc<Mutex<HashSet<String>>>,
+    /// Trail of the coordinator's own decisions per intent, shared with the API
+    audit_log: Arc<AuditLog>,
+    /// Prometheus metrics (None unless `api.metrics_enabled`)
+    metrics: Option<CoordinatorMetrics>,
 }
 
 impl EventMonitor {
     /// Creates a new event monitor with the given configuration.
     ///
     /// This function initializes HTTP clients with appropriate timeouts
-    /// and prepares the event cache for use.
+    /// and prepares the event cache for use. With `event_cache_dir` set, the
+    /// caches are reloaded from the events persisted by a previous run.
     ///
     /// # Arguments
     ///
@@ -127,14 +296,203 @@ impl EventMonitor {
             .no_proxy() // Avoid macOS system-configuration issues in tests
             .build()?;
 
+        let storage: Arc<dyn StorageBackend> = match &config.coordinator.event_cache_dir {
+            Some(dir) => Arc::new(JsonFileBackend::new(dir)?),
+            None => Arc::new(MemoryBackend),
+        };
+        let intent_events = storage.load_intent_events()?;
+        let fulfillment_events = storage.load_fulfillment_events()?;
+        if !intent_events.is_empty() || !fulfillment_events.is_empty() {
+            tracing::info!(
+                "Restored {} intent events and {} fulfillment events from the event cache",
+                intent_events.len(),
+                fulfillment_events.len()
+            );
+        }
+
+        let metrics = if config.api.metrics_enabled {
+            Some(CoordinatorMetrics::new()?)
+        } else {
+            None
+        };
+
+        let audit_log = match &config.coordinator.audit_log_spill_file {
+            Some(path) => AuditLog::with_spill_file(config.coordinator.audit_log_capacity, path)?,
+            None => AuditLog::new(config.coordinator.audit_log_capacity),
+        };
+
+        let intent_index = index_by_intent_id(intent_events.iter().map(|e| e.intent_id.as_str()));
+        let fulfillment_index =
+            index_by_intent_id(fulfillment_events.iter().map(|e| e.intent_id.as_str()));
+
         Ok(Self {
             config: Arc::new(config.clone()),
             hub_client,
-            event_cache: Arc::new(RwLock::new(Vec::new())),
-            fulfillment_cache: Arc::new(RwLock::new(Vec::new())),
+            event_cache: Arc::new(RwLock::new(intent_events)),
+            fulfillment_cache: Arc::new(RwLock::new(fulfillment_events)),
+            intent_index: Arc::new(RwLock::new(intent_index)),
+            fulfillment_index: Arc::new(RwLock::new(fulfillment_index)),
+            poll_backoff: Arc::new(Mutex::new(
+                PollBackoff::new(
+                    config.coordinator.polling_interval_ms,
+                    config.coordinator.max_backoff_ms,
+                )
+                .with_idle_widening(
+                    config
+                        .coordinator
+                        .max_idle_polling_interval_ms
+                        .unwrap_or(config.coordinator.polling_interval_ms),
+                    config.coordinator.idle_polls_before_widening,
+                ),
+            )),
+            last_hub_poll_at: Arc::new(RwLock::new(None)),
+            storage,
+            updates: broadcast::channel(UPDATE_CHANNEL_CAPACITY).0,
+            expired_intents: Arc::new(Mutex::new(HashSet::new())),
+            revocable_intents: Arc::new(Mutex::new(HashSet::new())),
+            audit_log: Arc::new(audit_log),
+            metrics,
         })
     }
 
+    /// Adds an intent event to the cache unless an event with the same intent ID
+    /// is already cached, persisting the cache on change.
+    ///
+    /// Persistence failures are logged; the event stays cached in memory.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event was new and added
+    pub async fn cache_intent_event(&self, event: IntentEvent) -> bool {
+        let mut cache = self.event_cache.write().await;
+        let mut index = self.intent_index.write().await;
+        let normalized_intent_id = normalize_intent_id(&event.intent_id);
+        if index.contains_key(&normalized_intent_id) {
+            return false;
+        }
+        index.insert(normalized_intent_id, cache.len());
+        cache.push(event.clone());
+        if let Err(e) = self.storage.save_intent_events(&cache) {
+            tracing::error!("Failed to persist intent event cache: {:#}", e);
+        }
+        // Fails only when nobody is subscribed
+        let _ = self.updates.send(CoordinatorUpdate::IntentCreated(event));
+        true
+    }
+
+    /// Adds a fulfillment event to the cache unless one for the same intent ID is
+    /// already cached, persisting the cache on change.
+    ///
+    /// Persistence failures are logged; the event stays cached in memory.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event was new and added
+    pub async fn cache_fulfillment_event(&self, event: FulfillmentEvent) -> bool {
+        let mut cache = self.fulfillment_cache.write().await;
+        let mut index = self.fulfillment_index.write().await;
+        let normalized_intent_id = normalize_intent_id(&event.intent_id);
+        if index.contains_key(&normalized_intent_id) {
+            return false;
+        }
+        index.insert(normalized_intent_id, cache.len());
+        cache.push(event.clone());
+        if let Err(e) = self.storage.save_fulfillment_events(&cache) {
+            tracing::error!("Failed to persist fulfillment event cache: {:#}", e);
+        }
+        let _ = self.updates.send(CoordinatorUpdate::Fulfillment(event));
+        true
+    }
+
+    /// Number of cached intent and fulfillment events.
+    pub async fn cached_event_count(&self) -> usize {
+        self.event_cache.read().await.len() + self.fulfillment_cache.read().await.len()
+    }
+
+    /// Returns the cached intent event for `intent_id`, if any.
+    ///
+    /// The ID is normalized, so `0x01` and `0x0000...01` find the same event.
+    pub async fn get_intent_event(&self, intent_id: &str) -> Option<IntentEvent> {
+        let cache = self.event_cache.read().await;
+        let index = self.intent_index.read().await;
+        index
+            .get(&normalize_intent_id(intent_id))
+            .and_then(|&position| cache.get(position))
+            .cloned()
+    }
+
+    /// Returns the cached fulfillment event for `intent_id`, if any.
+    pub async fn get_fulfillment_event(&self, intent_id: &str) -> Option<FulfillmentEvent> {
+        let cache = self.fulfillment_cache.read().await;
+        let index = self.fulfillment_index.read().await;
+        index
+            .get(&normalize_intent_id(intent_id))
+            .and_then(|&position| cache.get(position))
+            .cloned()
+    }
+
+    /// Subscribes to updates cached from now on.
+    pub fn subscribe_updates(&self) -> broadcast::Receiver<CoordinatorUpdate> {
+        self.updates.subscribe()
+    }
+
+    /// Sender for the live update channel, shared with the draft store so draft
+    /// status changes reach the same subscribers.
+    pub fn update_sender(&self) -> broadcast::Sender<CoordinatorUpdate> {
+        self.updates.clone()
+    }
+
+    /// Returns the Prometheus metrics, or None when `api.metrics_enabled` is off.
+    pub fn metrics(&self) -> Option<&CoordinatorMetrics> {
+        self.metrics.as_ref()
+    }
+
+    /// Records that `intent_id` was skipped as expired.
+    ///
+    /// # Returns
+    ///
+    /// `true` the first time the intent is recorded
+    pub async fn record_expired_intent(&self, intent_id: &str) -> bool {
+        self.expired_intents
+            .lock()
+            .await
+            .insert(normalize_intent_id(intent_id))
+    }
+
+    /// Records that `intent_id` was rejected as revocable.
+    ///
+    /// # Returns
+    ///
+    /// `true` the first time the intent is recorded
+    pub async fn record_revocable_intent(&self, intent_id: &str) -> bool {
+        self.revocable_intents
+            .lock()
+            .await
+            .insert(normalize_intent_id(intent_id))
+    }
+
+    /// Returns the audit log, shared with the draft negotiation handlers.
+    pub fn audit_log(&self) -> Arc<AuditLog> {
+        self.audit_log.clone()
+    }
+
+    /// Records a successful hub chain poll at the current time.
+    pub async fn record_hub_poll_success(&self) {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        *self.last_hub_poll_at.write().await = Some(now);
+    }
+
+    /// Returns the Unix timestamp of the last successful hub chain poll.
+    ///
+    /// Used by the readiness endpoint so operators can spot a stale monitor.
+    /// Connected chains are not polled by the coordinator and have no equivalent.
+    pub async fn last_hub_poll_at(&self) -> Option<u64> {
+        *self.last_hub_poll_at.read().await
+    }
+
     /// Starts the event monitoring process for the hub chain.
     ///
     /// This function runs the hub chain monitoring loop for intent and
@@ -201,3 +559,20 @@ impl EventMonitor {
     }
 
 }
+
+/// Whether an intent with `expiry_time` counts as expired at `now`.
+///
+/// The intent is still accepted up to `grace_secs` past its expiry, so a host
+/// clock running slightly ahead of the chain does not drop live intents.
+pub fn is_intent_expired(expiry_time: u64, now: u64, grace_secs: u64) -> bool {
+    expiry_time.saturating_add(grace_secs) < now
+}
+
+/// Map each normalized intent ID to its position in a cache (first occurrence wins).
+fn index_by_intent_id<'a>(intent_ids: impl Iterator<Item = &'a str>) -> HashMap<String, usize> {
+    let mut index = HashMap::new();
+    for (position, intent_id) in intent_ids.enumerate() {
+        index.entry(normalize_intent_id(intent_id)).or_insert(position);
+    }
+    index
+}
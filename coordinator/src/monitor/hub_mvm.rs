@@ -173,21 +173,11 @@ pub async fn poll_hub_events(monitor: &EventMonitor) -> Result<Vec<IntentEvent>>
                     };
 
                     // Cache the fulfillment event
-                    {
-                        let intent_id = fulfillment_event.intent_id.clone();
-                        let mut fulfillment_cache = monitor.fulfillment_cache.write().await;
-                        let normalized_intent_id =
-                            crate::monitor::generic::normalize_intent_id(&intent_id);
-                        if !fulfillment_cache.iter().any(|cached| {
-                            crate::monitor::generic::normalize_intent_id(&cached.intent_id)
-                                == normalized_intent_id
-                        }) {
-                            fulfillment_cache.push(fulfillment_event.clone());
-                            info!(
-                                "Received fulfillment event for intent {} by solver {}",
-                                data.intent_id, data.solver_addr
-                            );
-                        }
+                    if monitor.cache_fulfillment_event(fulfillment_event).await {
+                        info!(
+                            "Received fulfillment event for intent {} by solver {}",
+                            data.intent_id, data.solver_addr
+                        );
                     }
                 }
             } else if event_type.contains("OracleLimitOrderEvent") {
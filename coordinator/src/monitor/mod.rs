@@ -19,19 +19,25 @@ mod outflow_generic;
 mod hub_mvm;
 
 // Re-export public types and functions
-pub use generic::{
-    EventMonitor, FulfillmentEvent, IntentEvent,
-};
+pub use generic::{CoordinatorUpdate, DraftStatusUpdate, EventMonitor, FulfillmentEvent, IntentEvent};
+
+// Re-export the polling backoff for testing
+#[allow(unused_imports)] // Used by integration tests (monitor_tests.rs)
+pub use generic::PollBackoff;
 
 // Re-export utility functions (used in tests and API handlers)
 #[allow(unused_imports)] // Used by integration tests (monitor_tests.rs)
-pub use generic::normalize_intent_id;
+pub use generic::{is_intent_expired, normalize_intent_id};
 
 // Re-export poll_hub_events for testing
 #[doc(hidden)]
 #[allow(unused_imports)] // Only used in tests
 pub use outflow_generic::poll_hub_events;
 
+#[doc(hidden)]
+#[allow(unused_imports)] // Only used in tests
+pub use outflow_generic::poll_hub_once;
+
 // Re-export parse_amount_with_u64_limit for testing
 #[doc(hidden)]
 #[allow(unused_imports)] // Only used in tests
@@ -4,11 +4,12 @@
 //! Outflow intents have tokens locked on the hub chain and request tokens on the connected chain.
 
 use anyhow::Result;
-use std::time::{SystemTime, UNIX_EPOCH};
-use tracing::{error, info, trace};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{error, info, trace, warn};
 
-use super::generic::{EventMonitor, FulfillmentEvent, IntentEvent};
+use super::generic::{is_intent_expired, EventMonitor, FulfillmentEvent, IntentEvent};
 use super::hub_mvm;
+use crate::storage::{AuditAction, AuditSource};
 
 // ============================================================================
 // HUB CHAIN MONITORING
@@ -20,6 +21,10 @@ use super::hub_mvm;
 /// new intent events. When events are found, it validates their safety
 /// for escrow operations and caches them for later processing.
 ///
+/// While polling fails, the delay between polls backs off exponentially
+/// (see [`PollBackoff`](crate::monitor::PollBackoff)) so an RPC outage is not hammered at full rate.
+/// With `max_idle_polling_interval_ms` set, it also widens while polls find nothing new.
+///
 /// # Arguments
 ///
 /// * `monitor` - The event monitor instance
@@ -32,56 +37,136 @@ pub async fn monitor_hub_chain(monitor: &EventMonitor) -> Result<()> {
     info!("Starting hub chain monitoring for intent events");
 
     loop {
-        match poll_hub_events(monitor).await {
-            Ok(events) => {
-                for event in events {
-                    // CRITICAL SECURITY CHECK: Reject revocable intents
-                    if event.revocable {
-                        error!("SECURITY: Rejecting revocable intent {} from {} - NOT safe for escrow", event.intent_id, event.requester_addr);
-                        continue; // Skip this event - do not cache or process
+        let delay = poll_hub_once(monitor).await;
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Runs one hub chain poll, caches new events, and returns the delay before the next poll.
+///
+/// A success that caches a new intent or fulfillment event resets the monitor's
+/// [`PollBackoff`](crate::monitor::PollBackoff) to the polling interval; a success
+/// without new events counts as idle, and failure grows it. Repeated failures are
+/// logged at warn level after the first error. With metrics enabled, the poll's
+/// duration and outcome are recorded.
+///
+/// # Arguments
+///
+/// * `monitor` - The event monitor instance
+///
+/// # Returns
+///
+/// The delay to wait before the next poll
+pub async fn poll_hub_once(monitor: &EventMonitor) -> Duration {
+    let cached_before = monitor.cached_event_count().await;
+    let started = Instant::now();
+    let result = poll_hub_events(monitor).await;
+    if let Some(metrics) = monitor.metrics() {
+        metrics.record_hub_poll(started.elapsed(), result.is_err());
+    }
+    match result {
+        Ok(events) => {
+            for event in events {
+                // CRITICAL SECURITY CHECK: Reject revocable intents
+                if event.revocable {
+                    error!("SECURITY: Rejecting revocable intent {} from {} - NOT safe for escrow", event.intent_id, event.requester_addr);
+                    if monitor.record_revocable_intent(&event.intent_id).await {
+                        let reason = "Revocable intent is not safe for escrow".to_string();
+                        audit_observed_and_checked(monitor, &event.intent_id, Err(reason)).await;
                     }
+                    continue; // Skip this event - do not cache or process
+                }
 
-                    // Skip expired intents
-                    let current_time = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs();
-                    if event.expiry_time < current_time {
+                // Skip intents already expired when observed (beyond the clock skew grace)
+                let current_time = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                let grace_secs = monitor.config.coordinator.expiry_grace_secs;
+                if is_intent_expired(event.expiry_time, current_time, grace_secs) {
+                    // The event stays in the polled window, so only the first sighting is a warning
+                    if monitor.record_expired_intent(&event.intent_id).await {
+                        warn!(
+                            "Skipping intent {} from {}: expired at {}, observed at {} (grace {}s)",
+                            event.intent_id, event.requester_addr, event.expiry_time, current_time, grace_secs
+                        );
+                        let reason = format!(
+                            "Expired at {}, observed at {} (grace {}s)",
+                            event.expiry_time, current_time, grace_secs
+                        );
+                        audit_observed_and_checked(monitor, &event.intent_id, Err(reason)).await;
+                    } else {
                         trace!("Skipping expired intent {} (expired at {}, current {})", event.intent_id, event.expiry_time, current_time);
-                        continue;
                     }
+                    continue;
+                }
 
-                    // Cache the event for API access (only non-revocable, non-expired events)
-                    // Only log new events (not already in cache)
-                    {
-                        let intent_id = event.intent_id.clone();
-                        let mut cache = monitor.event_cache.write().await;
-                        let normalized_intent_id =
-                            crate::monitor::generic::normalize_intent_id(&intent_id);
-                        if !cache.iter().any(|cached| {
-                            crate::monitor::generic::normalize_intent_id(&cached.intent_id)
-                                == normalized_intent_id
-                        }) {
-                            info!("New intent event: {} from {}", event.intent_id, event.requester_addr);
-                            info!(
-                                "Request-intent {} is non-revocable - safe for escrow",
-                                event.intent_id
-                            );
-                            cache.push(event.clone());
-                        }
-                    }
+                // Cache the event for API access (only non-revocable, non-expired events)
+                // Only log new events (not already in cache)
+                let intent_id = event.intent_id.clone();
+                let requester_addr = event.requester_addr.clone();
+                if monitor.cache_intent_event(event).await {
+                    info!("New intent event: {} from {}", intent_id, requester_addr);
+                    info!("Request-intent {} is non-revocable - safe for escrow", intent_id);
+                    audit_observed_and_checked(monitor, &intent_id, Ok(())).await;
                 }
             }
-            Err(e) => {
+
+            monitor.record_hub_poll_success().await;
+            let active = monitor.cached_event_count().await > cached_before;
+
+            let mut backoff = monitor.poll_backoff.lock().await;
+            if backoff.consecutive_failures() > 0 {
+                info!(
+                    "Hub chain polling recovered after {} failed attempts",
+                    backoff.consecutive_failures()
+                );
+            }
+            if active {
+                backoff.on_success()
+            } else {
+                backoff.on_idle()
+            }
+        }
+        Err(e) => {
+            let mut backoff = monitor.poll_backoff.lock().await;
+            let delay = backoff.on_failure();
+            if backoff.consecutive_failures() == 1 {
                 error!("Error polling hub events: {}", e);
+            } else {
+                warn!(
+                    "Error polling hub events ({} consecutive failures, retrying in {} ms): {}",
+                    backoff.consecutive_failures(),
+                    delay.as_millis(),
+                    e
+                );
             }
+            delay
         }
+    }
+}
 
-        // Wait before next poll
-        tokio::time::sleep(std::time::Duration::from_millis(
-            monitor.config.coordinator.polling_interval_ms,
-        ))
+/// Records the first sighting of a hub intent and the outcome of the monitor's checks
+/// in the audit log: `Ok` validates it, `Err(reason)` fails validation and rejects it.
+async fn audit_observed_and_checked(monitor: &EventMonitor, intent_id: &str, outcome: Result<(), String>) {
+    let audit_log = monitor.audit_log();
+    audit_log
+        .record(intent_id, None, AuditAction::Observed { source: AuditSource::HubChain })
         .await;
+    match outcome {
+        Ok(()) => {
+            audit_log
+                .record(intent_id, None, AuditAction::Validated { passed: true, reason: None })
+                .await;
+        }
+        Err(reason) => {
+            audit_log
+                .record(intent_id, None, AuditAction::Validated { passed: false, reason: Some(reason.clone()) })
+                .await;
+            audit_log
+                .record(intent_id, None, AuditAction::Rejected { reason })
+                .await;
+        }
     }
 }
 
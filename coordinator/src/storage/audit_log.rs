@@ -0,0 +1,252 @@
+//! Intent Audit Log Module
+//!
+//! This module keeps an append-only trail of the coordinator's own decisions
+//! about each intent: when it observed the intent, whether it passed the
+//! coordinator's checks, which solver approval it accepted, and why it rejected
+//! the intent or a submission. Unlike the event caches, it records what the
+//! coordinator did, not what happened on chain.
+//!
+//! Memory is bounded by a ring buffer. With a spill file set, entries pushed out
+//! of the buffer are appended to the file as JSON lines and still returned by
+//! [`AuditLog::trail`].
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+use crate::monitor::normalize_intent_id;
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/// Where the coordinator first saw an intent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSource {
+    /// A requester submitted a draft carrying the intent ID
+    Draft,
+    /// The hub monitor read the intent creation event
+    HubChain,
+}
+
+/// One decision the coordinator made about an intent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AuditAction {
+    /// The intent was seen for the first time from `source`
+    Observed {
+        /// Where the intent was seen
+        source: AuditSource,
+    },
+    /// The intent or a submission for it was checked
+    Validated {
+        /// Whether the check passed
+        passed: bool,
+        /// Why the check failed (None when it passed)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+    /// A solver's signature was accepted as the intent's approval
+    ApprovalSigned {
+        /// Hub address of the approving solver
+        solver_hub_addr: String,
+        /// Public key the approval was signed with (hex)
+        public_key: String,
+        /// Chain the solver is registered on
+        chain_id: u64,
+    },
+    /// The intent, or a claim on its draft, was turned away
+    Rejected {
+        /// Why it was rejected
+        reason: String,
+    },
+}
+
+/// An audit log entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Position in the log; with a spill file it continues after the last spilled entry on restart
+    pub seq: u64,
+    /// Normalized intent ID
+    pub intent_id: String,
+    /// Draft the decision was made on, if it came from negotiation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft_id: Option<String>,
+    /// Unix timestamp of the decision
+    pub timestamp: u64,
+    /// The decision
+    #[serde(flatten)]
+    pub action: AuditAction,
+}
+
+/// Entries held in memory and the next sequence number.
+#[derive(Debug, Default)]
+struct AuditBuffer {
+    entries: VecDeque<AuditEntry>,
+    next_seq: u64,
+}
+
+// ============================================================================
+// STORAGE IMPLEMENTATION
+// ============================================================================
+
+/// Append-only audit trail of coordinator decisions, keyed by intent ID.
+///
+/// Holds at most `capacity` entries in memory. Older entries are dropped, or
+/// appended to the spill file when one is set. Thread-safe via Mutex.
+pub struct AuditLog {
+    /// Maximum number of entries held in memory
+    capacity: usize,
+    /// JSON-lines file receiving entries evicted from memory (None drops them)
+    spill_file: Option<PathBuf>,
+    /// In-memory ring buffer (front = oldest)
+    buffer: Mutex<AuditBuffer>,
+}
+
+impl AuditLog {
+    /// Create an in-memory audit log holding up to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            spill_file: None,
+            buffer: Mutex::new(AuditBuffer::default()),
+        }
+    }
+
+    /// Create an audit log that appends evicted entries to `spill_file`.
+    ///
+    /// Entries already in the file (from a previous run) stay queryable, and
+    /// sequence numbers continue after the highest one found.
+    pub fn with_spill_file(capacity: usize, spill_file: impl Into<PathBuf>) -> Result<Self> {
+        let spill_file = spill_file.into();
+        if let Some(parent) = spill_file.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create audit log directory {}", parent.display()))?;
+        }
+        let next_seq = read_spilled(&spill_file)?
+            .iter()
+            .map(|entry| entry.seq + 1)
+            .max()
+            .unwrap_or(0);
+        Ok(Self {
+            capacity,
+            spill_file: Some(spill_file),
+            buffer: Mutex::new(AuditBuffer {
+                entries: VecDeque::new(),
+                next_seq,
+            }),
+        })
+    }
+
+    /// Record a decision about `intent_id` at the current time.
+    ///
+    /// Spill failures are logged; the evicted entry is lost but the new one is kept.
+    ///
+    /// # Returns
+    ///
+    /// The recorded entry
+    pub async fn record(
+        &self,
+        intent_id: &str,
+        draft_id: Option<&str>,
+        action: AuditAction,
+    ) -> AuditEntry {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut buffer = self.buffer.lock().await;
+        let entry = AuditEntry {
+            seq: buffer.next_seq,
+            intent_id: normalize_intent_id(intent_id),
+            draft_id: draft_id.map(str::to_string),
+            timestamp,
+            action,
+        };
+        buffer.next_seq += 1;
+        buffer.entries.push_back(entry.clone());
+
+        let overflow = buffer.entries.len().saturating_sub(self.capacity);
+        let evicted: Vec<AuditEntry> = buffer.entries.drain(..overflow).collect();
+        if let Some(path) = self.spill_file.as_ref().filter(|_| !evicted.is_empty()) {
+            if let Err(e) = append_spilled(path, &evicted) {
+                tracing::error!("Failed to spill {} audit entries: {:#}", evicted.len(), e);
+            }
+        }
+        entry
+    }
+
+    /// All recorded decisions about `intent_id`, oldest first.
+    ///
+    /// The ID is normalized, so `0x01` and `0x0000...01` return the same trail.
+    pub async fn trail(&self, intent_id: &str) -> Result<Vec<AuditEntry>> {
+        let normalized = normalize_intent_id(intent_id);
+        // Hold the lock while reading the file so no entry moves between file and memory
+        let buffer = self.buffer.lock().await;
+        let mut trail = match &self.spill_file {
+            Some(path) => read_spilled(path)?
+                .into_iter()
+                .filter(|entry| entry.intent_id == normalized)
+                .collect(),
+            None => Vec::new(),
+        };
+        trail.extend(
+            buffer
+                .entries
+                .iter()
+                .filter(|entry| entry.intent_id == normalized)
+                .cloned(),
+        );
+        drop(buffer);
+        trail.sort_by_key(|entry| entry.seq);
+        Ok(trail)
+    }
+
+    /// Number of entries held in memory.
+    #[allow(dead_code)] // Used by tests
+    pub async fn len(&self) -> usize {
+        self.buffer.lock().await.entries.len()
+    }
+
+    /// Whether no entries are held in memory.
+    #[allow(dead_code)] // Used by tests
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+fn read_spilled(path: &Path) -> Result<Vec<AuditEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(l) if l.trim().is_empty()))
+        .map(|line| {
+            let line = line.with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str(&line).with_context(|| format!("Failed to parse {}", path.display()))
+        })
+        .collect()
+}
+
+fn append_spilled(path: &Path, entries: &[AuditEntry]) -> Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    for entry in entries {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        file.write_all(&line)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
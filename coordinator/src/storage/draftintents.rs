@@ -7,7 +7,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+
+use super::solver_liveness::SolverLivenessStore;
+use crate::monitor::{normalize_intent_id, CoordinatorUpdate, DraftStatusUpdate};
 
 // ============================================================================
 // DATA STRUCTURES
@@ -57,6 +60,67 @@ pub struct Draftintent {
     pub expiry_time: u64,
     /// First signature received (None if not yet signed)
     pub signature: Option<DraftSignature>,
+    /// Incremented on every status change; solvers pass it back when claiming
+    /// so a claim based on a stale read is rejected
+    #[serde(default)]
+    pub version: u64,
+}
+
+/// Reason a claim (signature submission) on a draft was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DraftClaimError {
+    /// No draft with this ID
+    #[error("Draft not found")]
+    NotFound,
+    /// The draft expired before it was claimed
+    #[error("Draft expired")]
+    Expired,
+    /// Another solver claimed the draft first (FCFS)
+    #[error("Draft already signed by {solver_hub_addr}")]
+    AlreadySigned {
+        /// Solver whose claim won
+        solver_hub_addr: String,
+        /// Current draft version
+        version: u64,
+    },
+    /// The draft changed since the solver read it
+    #[error("Draft version changed (expected {expected}, current {current})")]
+    VersionMismatch {
+        /// Version the solver based its claim on
+        expected: u64,
+        /// Current draft version
+        current: u64,
+        /// Solver holding the draft, if it is currently signed
+        solver_hub_addr: Option<String>,
+    },
+}
+
+/// Draft counts by status and lifetime sweep counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftStoreStats {
+    /// Drafts currently pending
+    pub pending: usize,
+    /// Drafts currently signed
+    pub signed: usize,
+    /// Drafts currently expired (kept until their retention window ends)
+    pub expired: usize,
+    /// Drafts marked expired since startup
+    pub expired_total: u64,
+    /// Drafts removed from the store since startup
+    pub evicted_total: u64,
+    /// Signed drafts returned to pending because their solver went silent
+    pub reassigned_total: u64,
+    /// Unix timestamp of the last eviction sweep (None before the first sweep)
+    pub last_sweep_at: Option<u64>,
+}
+
+/// Lifetime counters behind [`DraftStoreStats`].
+#[derive(Debug, Default)]
+struct SweepCounters {
+    expired_total: u64,
+    evicted_total: u64,
+    reassigned_total: u64,
+    last_sweep_at: Option<u64>,
 }
 
 // ============================================================================
@@ -70,6 +134,10 @@ pub struct Draftintent {
 pub struct DraftintentStore {
     /// Map of draft_id -> Draftintent
     drafts: RwLock<HashMap<String, Draftintent>>,
+    /// Expiry, eviction and reassignment counters
+    counters: RwLock<SweepCounters>,
+    /// Live update channel for draft status changes (None publishes nothing)
+    updates: Option<broadcast::Sender<CoordinatorUpdate>>,
 }
 
 impl DraftintentStore {
@@ -77,6 +145,16 @@ impl DraftintentStore {
     pub fn new() -> Self {
         Self {
             drafts: RwLock::new(HashMap::new()),
+            counters: RwLock::new(SweepCounters::default()),
+            updates: None,
+        }
+    }
+
+    /// Create a draft intent store that publishes every status change to `updates`.
+    pub fn with_updates(updates: broadcast::Sender<CoordinatorUpdate>) -> Self {
+        Self {
+            updates: Some(updates),
+            ..Self::new()
         }
     }
 
@@ -108,10 +186,12 @@ impl DraftintentStore {
             timestamp,
             expiry_time,
             signature: None,
+            version: 0,
         };
 
         let mut drafts = self.drafts.write().await;
         drafts.insert(draft_id, draft.clone());
+        self.publish(&draft);
         draft
     }
 
@@ -130,6 +210,31 @@ impl DraftintentStore {
         drafts.get(draft_id).cloned()
     }
 
+    /// Get the draft that will create (or created) the given on-chain intent.
+    ///
+    /// Matches the `intent_id` field of the draft data, comparing normalized IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `intent_id` - The intent ID to look up
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Draftintent)` if a draft carries this intent ID
+    /// * `None` otherwise
+    pub async fn find_by_intent_id(&self, intent_id: &str) -> Option<Draftintent> {
+        let normalized = normalize_intent_id(intent_id);
+        let drafts = self.drafts.read().await;
+        drafts
+            .values()
+            .find(|draft| {
+                draft.draft_data["intent_id"]
+                    .as_str()
+                    .is_some_and(|id| normalize_intent_id(id) == normalized)
+            })
+            .cloned()
+    }
+
     /// Get all pending drafts.
     ///
     /// Returns all drafts with status=Pending that haven't expired.
@@ -166,6 +271,7 @@ impl DraftintentStore {
     ///
     /// * `Ok(())` if signature was accepted (first signature)
     /// * `Err(String)` if draft not found, already signed, or expired
+    #[allow(dead_code)] // Used by tests; the API claims drafts through `claim`
     pub async fn add_signature(
         &self,
         draft_id: &str,
@@ -173,19 +279,72 @@ impl DraftintentStore {
         signature: String,
         public_key: String,
     ) -> Result<(), String> {
+        self.claim(draft_id, solver_hub_addr, signature, public_key, None)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Claim a draft for a solver by storing its signature (FCFS, compare-and-set).
+    ///
+    /// The status and version check and the update happen under one write lock, so
+    /// of several concurrent claims exactly one succeeds. With `expected_version`
+    /// set, the claim is also rejected if the draft changed since the solver read it.
+    ///
+    /// # Arguments
+    ///
+    /// * `draft_id` - The draft ID
+    /// * `solver_hub_addr` - Address of the solver signing
+    /// * `signature` - Signature in hex format
+    /// * `public_key` - Public key in hex format
+    /// * `expected_version` - Draft version the solver read (None skips the check)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(version)` - New draft version after the claim
+    /// * `Err(DraftClaimError)` - Why the claim was rejected
+    pub async fn claim(
+        &self,
+        draft_id: &str,
+        solver_hub_addr: String,
+        signature: String,
+        public_key: String,
+        expected_version: Option<u64>,
+    ) -> Result<u64, DraftClaimError> {
         let mut drafts = self.drafts.write().await;
-        let draft = drafts.get_mut(draft_id).ok_or("Draft not found")?;
+        let draft = drafts.get_mut(draft_id).ok_or(DraftClaimError::NotFound)?;
+        let current_signer = draft.signature.as_ref().map(|sig| sig.solver_hub_addr.clone());
+
+        if let Some(expected) = expected_version {
+            if draft.version != expected {
+                return Err(DraftClaimError::VersionMismatch {
+                    expected,
+                    current: draft.version,
+                    solver_hub_addr: current_signer,
+                });
+            }
+        }
 
         // FCFS: Only accept if still pending
-        if draft.status != DraftintentStatus::Pending {
-            return Err("Draft already signed or expired".to_string());
+        match draft.status {
+            DraftintentStatus::Pending => {}
+            DraftintentStatus::Signed => {
+                return Err(DraftClaimError::AlreadySigned {
+                    solver_hub_addr: current_signer.unwrap_or_default(),
+                    version: draft.version,
+                });
+            }
+            DraftintentStatus::Expired => return Err(DraftClaimError::Expired),
         }
 
         // Check expiry
         let current_time = Self::current_timestamp();
         if draft.expiry_time <= current_time {
             draft.status = DraftintentStatus::Expired;
-            return Err("Draft expired".to_string());
+            draft.version += 1;
+            self.publish(draft);
+            self.counters.write().await.expired_total += 1;
+            return Err(DraftClaimError::Expired);
         }
 
         // Store first signature
@@ -196,26 +355,174 @@ impl DraftintentStore {
             signature_timestamp: current_time,
         });
         draft.status = DraftintentStatus::Signed;
+        draft.version += 1;
+        self.publish(draft);
 
-        Ok(())
+        Ok(draft.version)
     }
 
     /// Remove expired drafts (cleanup).
     ///
     /// Marks drafts as expired if their expiry_time has passed.
     pub async fn cleanup_expired(&self) {
+        self.cleanup_expired_at(Self::current_timestamp()).await;
+    }
+
+    /// Mark pending drafts whose expiry_time is at or before `now` as expired.
+    ///
+    /// # Returns
+    ///
+    /// Number of drafts marked expired
+    pub async fn cleanup_expired_at(&self, now: u64) -> usize {
         let mut drafts = self.drafts.write().await;
-        let current_time = Self::current_timestamp();
+        let mut expired = 0;
 
         for draft in drafts.values_mut() {
-            if draft.status == DraftintentStatus::Pending && draft.expiry_time <= current_time {
+            if draft.status == DraftintentStatus::Pending && draft.expiry_time <= now {
                 tracing::info!(
                     "Draft expired: draft_id={}, requester={}, expiry_time={}",
                     draft.draft_id, draft.requester_addr, draft.expiry_time
                 );
                 draft.status = DraftintentStatus::Expired;
+                draft.version += 1;
+                self.publish(draft);
+                expired += 1;
+            }
+        }
+
+        self.counters.write().await.expired_total += expired as u64;
+        expired
+    }
+
+    /// Remove drafts whose expiry_time passed more than `retention_secs` ago.
+    ///
+    /// Applies to every status: once a draft's expiry has passed, its intent was
+    /// either created on-chain or abandoned, and the draft is only kept so the
+    /// requester can read its final status.
+    ///
+    /// # Returns
+    ///
+    /// IDs of the removed drafts
+    pub async fn evict_stale(&self, retention_secs: u64) -> Vec<String> {
+        self.evict_stale_at(Self::current_timestamp(), retention_secs).await
+    }
+
+    /// Same as [`Self::evict_stale`], evaluated at the Unix timestamp `now`.
+    pub async fn evict_stale_at(&self, now: u64, retention_secs: u64) -> Vec<String> {
+        let mut drafts = self.drafts.write().await;
+        let evicted: Vec<String> = drafts
+            .values()
+            .filter(|draft| draft.expiry_time.saturating_add(retention_secs) <= now)
+            .map(|draft| draft.draft_id.clone())
+            .collect();
+        for draft_id in &evicted {
+            drafts.remove(draft_id);
+        }
+        drop(drafts);
+
+        if !evicted.is_empty() {
+            tracing::info!("Evicted {} stale drafts", evicted.len());
+        }
+        let mut counters = self.counters.write().await;
+        counters.evicted_total += evicted.len() as u64;
+        counters.last_sweep_at = Some(now);
+        evicted
+    }
+
+    /// Current draft counts by status and lifetime sweep counters.
+    pub async fn stats(&self) -> DraftStoreStats {
+        let drafts = self.drafts.read().await;
+        let count = |status: DraftintentStatus| drafts.values().filter(|d| d.status == status).count();
+        let counters = self.counters.read().await;
+        DraftStoreStats {
+            pending: count(DraftintentStatus::Pending),
+            signed: count(DraftintentStatus::Signed),
+            expired: count(DraftintentStatus::Expired),
+            expired_total: counters.expired_total,
+            evicted_total: counters.evicted_total,
+            reassigned_total: counters.reassigned_total,
+            last_sweep_at: counters.last_sweep_at,
+        }
+    }
+
+    /// Reassign signed drafts whose solver has gone silent.
+    ///
+    /// A signed draft is returned to Pending (signature cleared) when its solver
+    /// has not been seen for more than `timeout_secs`. The signature itself counts
+    /// as a sign of life for solvers without any recorded heartbeat. Expired
+    /// drafts are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `liveness` - Solver heartbeat store
+    /// * `timeout_secs` - Seconds of silence after which a draft is reassigned
+    ///
+    /// # Returns
+    ///
+    /// IDs of the reassigned drafts
+    pub async fn reassign_silent_drafts(
+        &self,
+        liveness: &SolverLivenessStore,
+        timeout_secs: u64,
+    ) -> Vec<String> {
+        let mut drafts = self.drafts.write().await;
+        let current_time = Self::current_timestamp();
+        let mut reassigned = Vec::new();
+
+        for draft in drafts.values_mut() {
+            if draft.status != DraftintentStatus::Signed || draft.expiry_time <= current_time {
+                continue;
+            }
+            let Some(sig) = &draft.signature else {
+                continue;
+            };
+            let last_seen = liveness
+                .last_seen(&sig.solver_hub_addr)
+                .await
+                .unwrap_or(sig.signature_timestamp);
+            if current_time.saturating_sub(last_seen) <= timeout_secs {
+                continue;
             }
+
+            tracing::warn!(
+                "Reassigning draft: draft_id={}, silent solver={}, last_seen={}",
+                draft.draft_id, sig.solver_hub_addr, last_seen
+            );
+            draft.signature = None;
+            draft.status = DraftintentStatus::Pending;
+            draft.version += 1;
+            self.publish(draft);
+            reassigned.push(draft.draft_id.clone());
         }
+        drop(drafts);
+
+        self.counters.write().await.reassigned_total += reassigned.len() as u64;
+        reassigned
+    }
+
+    /// Publish a draft's current status to live subscribers, if any.
+    fn publish(&self, draft: &Draftintent) {
+        let Some(updates) = &self.updates else {
+            return;
+        };
+        // The SDK sends chain IDs as decimal strings; accept plain numbers too
+        let chain_id = |key: &str| {
+            let value = &draft.draft_data[key];
+            value.as_u64().or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+        };
+        let update = DraftStatusUpdate {
+            draft_id: draft.draft_id.clone(),
+            intent_id: draft.draft_data["intent_id"].as_str().map(str::to_string),
+            chain_ids: ["offered_chain_id", "desired_chain_id"]
+                .into_iter()
+                .filter_map(chain_id)
+                .collect(),
+            status: draft.status,
+            solver_hub_addr: draft.signature.as_ref().map(|sig| sig.solver_hub_addr.clone()),
+            version: draft.version,
+        };
+        // Fails only when nobody is subscribed
+        let _ = updates.send(CoordinatorUpdate::DraftStatus(update));
     }
 
     /// Get current Unix timestamp.
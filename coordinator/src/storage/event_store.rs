@@ -0,0 +1,125 @@
+//! Event Cache Persistence
+//!
+//! Backends that persist the monitor's intent and fulfillment caches so a restart
+//! does not lose observed events. [`MemoryBackend`] (the default) keeps nothing;
+//! [`JsonFileBackend`] writes each cache to a JSON file after every change and
+//! reloads it on startup.
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
+
+use crate::monitor::{FulfillmentEvent, IntentEvent};
+
+/// File name of the persisted intent event cache.
+const INTENT_EVENTS_FILE: &str = "intent_events.json";
+
+/// File name of the persisted fulfillment event cache.
+const FULFILLMENT_EVENTS_FILE: &str = "fulfillment_events.json";
+
+// ============================================================================
+// STORAGE BACKEND TRAIT
+// ============================================================================
+
+/// Persistence for the monitor's event caches.
+///
+/// Saves receive the full cache contents; backends overwrite their previous copy.
+pub trait StorageBackend: Send + Sync {
+    /// Load the persisted intent events (empty if none were saved).
+    fn load_intent_events(&self) -> Result<Vec<IntentEvent>>;
+
+    /// Replace the persisted intent events.
+    fn save_intent_events(&self, events: &[IntentEvent]) -> Result<()>;
+
+    /// Load the persisted fulfillment events (empty if none were saved).
+    fn load_fulfillment_events(&self) -> Result<Vec<FulfillmentEvent>>;
+
+    /// Replace the persisted fulfillment events.
+    fn save_fulfillment_events(&self, events: &[FulfillmentEvent]) -> Result<()>;
+}
+
+// ============================================================================
+// MEMORY BACKEND
+// ============================================================================
+
+/// Backend that persists nothing: caches live only in memory and start empty.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryBackend;
+
+impl StorageBackend for MemoryBackend {
+    fn load_intent_events(&self) -> Result<Vec<IntentEvent>> {
+        Ok(Vec::new())
+    }
+
+    fn save_intent_events(&self, _events: &[IntentEvent]) -> Result<()> {
+        Ok(())
+    }
+
+    fn load_fulfillment_events(&self) -> Result<Vec<FulfillmentEvent>> {
+        Ok(Vec::new())
+    }
+
+    fn save_fulfillment_events(&self, _events: &[FulfillmentEvent]) -> Result<()> {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// JSON FILE BACKEND
+// ============================================================================
+
+/// Backend that stores each cache as a JSON array in `dir`.
+///
+/// Files are written to a temporary path and renamed into place, so a crash
+/// mid-write leaves the previous copy intact.
+#[derive(Debug, Clone)]
+pub struct JsonFileBackend {
+    dir: PathBuf,
+}
+
+impl JsonFileBackend {
+    /// Create a backend storing its files in `dir`, creating the directory if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create event cache directory {}", dir.display()))?;
+        Ok(Self { dir })
+    }
+
+    fn load<T: DeserializeOwned>(&self, file: &str) -> Result<Vec<T>> {
+        let path = self.dir.join(file);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    fn save<T: Serialize>(&self, file: &str, events: &[T]) -> Result<()> {
+        let path = self.dir.join(file);
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec(events)?)
+            .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+        std::fs::rename(&tmp_path, &path)
+            .with_context(|| format!("Failed to replace {}", path.display()))
+    }
+}
+
+impl StorageBackend for JsonFileBackend {
+    fn load_intent_events(&self) -> Result<Vec<IntentEvent>> {
+        self.load(INTENT_EVENTS_FILE)
+    }
+
+    fn save_intent_events(&self, events: &[IntentEvent]) -> Result<()> {
+        self.save(INTENT_EVENTS_FILE, events)
+    }
+
+    fn load_fulfillment_events(&self) -> Result<Vec<FulfillmentEvent>> {
+        self.load(FULFILLMENT_EVENTS_FILE)
+    }
+
+    fn save_fulfillment_events(&self, events: &[FulfillmentEvent]) -> Result<()> {
+        self.save(FULFILLMENT_EVENTS_FILE, events)
+    }
+}
@@ -0,0 +1,138 @@
+//! Idempotency Cache Module
+//!
+//! This module keeps the responses of POST requests sent with an
+//! `Idempotency-Key` header, so a client retrying after a timeout or network
+//! error gets the exact original response instead of creating a second draft
+//! or submitting a second signature.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/// Response stored for one idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// `Content-Type` header, if the response had one
+    pub content_type: Option<String>,
+    /// Response body, replayed byte for byte
+    pub body: Vec<u8>,
+    /// Hash of the request body the response was produced for
+    pub request_hash: u64,
+}
+
+/// Result of looking up an idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyLookup {
+    /// Key not seen within the window; handle the request and store the response
+    Miss,
+    /// Same key and same request body; replay this response
+    Hit(CachedResponse),
+    /// Same key but a different request body; the client reused a key by mistake
+    Mismatch,
+}
+
+// ============================================================================
+// STORAGE IMPLEMENTATION
+// ============================================================================
+
+/// Bounded, least-recently-used store of responses keyed by idempotency key.
+///
+/// Entries expire `window` after they were stored. When full, the least
+/// recently used entry is evicted. Thread-safe via Mutex.
+pub struct IdempotencyCache {
+    /// Maximum number of stored responses
+    capacity: usize,
+    /// How long a stored response is replayed
+    window: Duration,
+    /// Stored responses and their LRU order
+    entries: Mutex<CacheEntries>,
+}
+
+/// Stored responses with their insertion time, plus keys in LRU order (front = oldest).
+#[derive(Default)]
+struct CacheEntries {
+    map: HashMap<String, (Instant, CachedResponse)>,
+    order: VecDeque<String>,
+}
+
+impl IdempotencyCache {
+    /// Create an empty cache holding up to `capacity` responses for `window` each.
+    pub fn new(capacity: usize, window: Duration) -> Self {
+        Self {
+            capacity,
+            window,
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    /// Hash of a request body, stored with the response to detect key reuse.
+    pub fn request_hash(body: &[u8]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Look up `key` for a request whose body hashes to `request_hash`.
+    ///
+    /// Expired entries are dropped and reported as a miss.
+    pub async fn lookup(&self, key: &str, request_hash: u64) -> IdempotencyLookup {
+        let mut guard = self.entries.lock().await;
+        let CacheEntries { map, order } = &mut *guard;
+        let response = match map.get(key) {
+            Some((stored_at, _)) if stored_at.elapsed() >= self.window => {
+                map.remove(key);
+                order.retain(|k| k != key);
+                return IdempotencyLookup::Miss;
+            }
+            Some((_, response)) => response.clone(),
+            None => return IdempotencyLookup::Miss,
+        };
+        order.retain(|k| k != key);
+        order.push_back(key.to_string());
+        if response.request_hash == request_hash {
+            IdempotencyLookup::Hit(response)
+        } else {
+            IdempotencyLookup::Mismatch
+        }
+    }
+
+    /// Store the response for `key`, evicting the least recently used entry if full.
+    pub async fn insert(&self, key: String, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut guard = self.entries.lock().await;
+        let CacheEntries { map, order } = &mut *guard;
+        order.retain(|k| k != &key);
+        while map.len() >= self.capacity && !map.contains_key(&key) {
+            match order.pop_front() {
+                Some(oldest) => {
+                    map.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+        order.push_back(key.clone());
+        map.insert(key, (Instant::now(), response));
+    }
+
+    /// Number of stored responses, including expired ones not yet dropped.
+    #[allow(dead_code)] // Used by tests
+    pub async fn len(&self) -> usize {
+        self.entries.lock().await.map.len()
+    }
+
+    /// Whether no responses are stored.
+    #[allow(dead_code)] // Used by tests
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
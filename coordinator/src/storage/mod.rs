@@ -1,10 +1,22 @@
 //! Storage Module
 //!
 //! This module provides storage abstractions for the coordinator service,
-//! including draft intent storage for negotiation routing.
+//! including draft intent storage for negotiation routing, solver
+//! liveness tracking, the exchange rate cache, idempotent request replay,
+//! event cache persistence, and the intent audit log.
 
+pub mod audit_log;
 pub mod draftintents;
+pub mod event_store;
+pub mod idempotency;
+pub mod rate_cache;
+pub mod solver_liveness;
 
 // Re-export for convenience
-pub use draftintents::{DraftintentStatus, DraftintentStore};
+pub use audit_log::{AuditAction, AuditEntry, AuditLog, AuditSource};
+pub use draftintents::{DraftClaimError, DraftintentStatus, DraftintentStore};
+pub use event_store::{JsonFileBackend, MemoryBackend, StorageBackend};
+pub use idempotency::{CachedResponse, IdempotencyCache, IdempotencyLookup};
+pub use rate_cache::RateCache;
+pub use solver_liveness::SolverLivenessStore;
 
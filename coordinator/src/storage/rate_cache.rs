@@ -0,0 +1,93 @@
+//! Exchange Rate Cache Module
+//!
+//! This module provides in-memory caching of exchange rates fetched from the
+//! solver. The acceptance proxy falls back to a cached rate while the solver is
+//! unreachable, as long as the rate is within the pair's staleness bound.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+use crate::api::ExchangeRateResponse;
+use crate::config::TokenPairConfig;
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/// Exchange rate last fetched from the solver for one token pair.
+#[derive(Debug, Clone)]
+pub struct CachedRate {
+    /// Rate as returned by the solver
+    pub rate: ExchangeRateResponse,
+    /// Unix timestamp at which the rate was fetched
+    pub fetched_at: u64,
+}
+
+impl CachedRate {
+    /// Seconds elapsed since the rate was fetched.
+    pub fn age_secs(&self) -> u64 {
+        current_timestamp().saturating_sub(self.fetched_at)
+    }
+}
+
+// ============================================================================
+// STORAGE IMPLEMENTATION
+// ============================================================================
+
+/// In-memory store of the most recent solver rate per token pair.
+///
+/// Keyed by `source_chain_id:source_token->target_chain_id:target_token`.
+/// Thread-safe via RwLock.
+pub struct RateCache {
+    /// Map of pair key -> last fetched rate
+    rates: RwLock<HashMap<String, CachedRate>>,
+}
+
+impl RateCache {
+    /// Create a new, empty rate cache.
+    pub fn new() -> Self {
+        Self {
+            rates: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Cache key for a configured token pair.
+    pub fn pair_key(pair: &TokenPairConfig) -> String {
+        format!(
+            "{}:{}->{}:{}",
+            pair.source_chain_id, pair.source_token, pair.target_chain_id, pair.target_token
+        )
+    }
+
+    /// Record a rate fetched from the solver at the current time.
+    pub async fn insert(&self, pair: &TokenPairConfig, rate: ExchangeRateResponse) {
+        self.insert_at(pair, rate, current_timestamp()).await;
+    }
+
+    /// Record a rate fetched from the solver at the given Unix timestamp.
+    pub async fn insert_at(&self, pair: &TokenPairConfig, rate: ExchangeRateResponse, fetched_at: u64) {
+        let mut rates = self.rates.write().await;
+        rates.insert(Self::pair_key(pair), CachedRate { rate, fetched_at });
+    }
+
+    /// Get the last rate fetched for a pair, regardless of age.
+    pub async fn get(&self, pair: &TokenPairConfig) -> Option<CachedRate> {
+        let rates = self.rates.read().await;
+        rates.get(&Self::pair_key(pair)).cloned()
+    }
+}
+
+impl Default for RateCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get current Unix timestamp.
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
@@ -0,0 +1,90 @@
+//! Solver Liveness Storage Module
+//!
+//! This module provides in-memory tracking of solver heartbeats. Negotiation
+//! routing uses the last-seen timestamps to limit draft assignment to live
+//! solvers and to reassign drafts whose solver has gone silent.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+// ============================================================================
+// STORAGE IMPLEMENTATION
+// ============================================================================
+
+/// In-memory store of the last heartbeat received from each solver.
+///
+/// Keyed by hub solver address. Thread-safe via RwLock.
+pub struct SolverLivenessStore {
+    /// Map of solver_hub_addr -> last-seen Unix timestamp
+    last_seen: RwLock<HashMap<String, u64>>,
+}
+
+impl SolverLivenessStore {
+    /// Create a new solver liveness store.
+    pub fn new() -> Self {
+        Self {
+            last_seen: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Record a heartbeat from a solver at the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `solver_hub_addr` - Hub address of the solver
+    ///
+    /// # Returns
+    ///
+    /// The recorded last-seen timestamp
+    pub async fn record_heartbeat(&self, solver_hub_addr: &str) -> u64 {
+        let timestamp = Self::current_timestamp();
+        self.record_heartbeat_at(solver_hub_addr, timestamp).await;
+        timestamp
+    }
+
+    /// Record a heartbeat from a solver at the given timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `solver_hub_addr` - Hub address of the solver
+    /// * `timestamp` - Last-seen Unix timestamp
+    pub async fn record_heartbeat_at(&self, solver_hub_addr: &str, timestamp: u64) {
+        let mut last_seen = self.last_seen.write().await;
+        last_seen.insert(solver_hub_addr.to_string(), timestamp);
+    }
+
+    /// Get the last-seen timestamp of a solver.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` if the solver has sent a heartbeat
+    /// * `None` if the solver has never been seen
+    pub async fn last_seen(&self, solver_hub_addr: &str) -> Option<u64> {
+        let last_seen = self.last_seen.read().await;
+        last_seen.get(solver_hub_addr).copied()
+    }
+
+    /// Check whether a solver sent a heartbeat within the last `window_secs` seconds.
+    pub async fn is_live(&self, solver_hub_addr: &str, window_secs: u64) -> bool {
+        let current_time = Self::current_timestamp();
+        match self.last_seen(solver_hub_addr).await {
+            Some(seen) => current_time.saturating_sub(seen) <= window_secs,
+            None => false,
+        }
+    }
+
+    /// Get current Unix timestamp.
+    fn current_timestamp() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+}
+
+impl Default for SolverLivenessStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,7 +3,12 @@
 //! Tests negotiation endpoints and error handling for the coordinator service.
 
 use serde_json::json;
-use coordinator::api::{ApiResponse, ApiServer};
+use coordinator::api::{
+    ApiResponse, ApiServer, AuditTrailResponse, ExchangeRateResponse, IntentDetailsResponse,
+    QuoteResponse, ReadinessReport, IDEMPOTENCY_KEY_HEADER,
+};
+use coordinator::storage::{AuditAction, AuditSource};
+use coordinator::config::{AcceptanceConfig, TokenPairConfig};
 use coordinator::monitor::EventMonitor;
 use warp::http::StatusCode;
 use warp::test::request;
@@ -11,7 +16,9 @@ use warp::test::request;
 #[path = "mod.rs"]
 mod test_helpers;
 use test_helpers::{
-    DUMMY_EXPIRY, DUMMY_REQUESTER_ADDR_HUB, DUMMY_SOLVER_ADDR_HUB,
+    create_default_fulfillment, create_default_intent_mvm, DUMMY_EXPIRY, DUMMY_INTENT_ID,
+    DUMMY_METADATA_ADDR_MVM, DUMMY_REQUESTER_ADDR_HUB, DUMMY_SOLVER_ADDR_HUB,
+    DUMMY_TOKEN_ADDR_FANTOM,
 };
 
 // ============================================================================
@@ -59,6 +66,141 @@ async fn test_health_endpoint() {
     assert!(body.data.is_some());
 }
 
+// ============================================================================
+// READINESS ENDPOINT TESTS
+// ============================================================================
+
+/// Mount a mock that answers the Aptos ledger info ping (`GET /v1`).
+async fn mock_ledger_info(server: &wiremock::MockServer, expected_calls: u64) {
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/v1"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(json!({
+            "chain_id": 250,
+            "ledger_version": "1"
+        })))
+        .expect(expected_calls)
+        .mount(server)
+        .await;
+}
+
+/// Test that the readiness endpoint reports reachable chains and caches the result
+/// What is tested: /ready pings the hub and EVM chain, returns 200, and a second
+/// request within the cache TTL does not ping the chains again
+/// Why: Liveness probes must not turn into one RPC call per chain per probe
+#[tokio::test]
+async fn test_ready_endpoint_all_chains_reachable() {
+    let hub_server = wiremock::MockServer::start().await;
+    let evm_server = wiremock::MockServer::start().await;
+    mock_ledger_info(&hub_server, 1).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": "0x10",
+            "id": 1
+        })))
+        .expect(1)
+        .mount(&evm_server)
+        .await;
+
+    let mut config = test_helpers::build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    // Only the hub and the mocked EVM chain are pinged
+    config.connected_chain_mvm.clear();
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let routes = ApiServer::new(config, monitor).test_routes();
+
+    for _ in 0..2 {
+        let response = request().method("GET").path("/ready").reply(&routes).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: ApiResponse<ReadinessReport> = serde_json::from_slice(response.body()).unwrap();
+        assert!(body.success);
+        let report = body.data.unwrap();
+        assert!(report.ready);
+        let chain_types: Vec<&str> = report.chains.iter().map(|c| c.chain_type.as_str()).collect();
+        assert_eq!(chain_types, vec!["hub", "evm"]);
+    }
+}
+
+/// Test that the readiness endpoint returns 503 when a chain is unreachable
+/// What is tested: /ready with a failing SVM RPC reports that chain as unreachable
+/// and the overall status as 503, while the hub stays reachable
+/// Why: Orchestrators should stop routing traffic to a coordinator that cannot see its chains
+#[tokio::test]
+async fn test_ready_endpoint_unreachable_chain_returns_503() {
+    let hub_server = wiremock::MockServer::start().await;
+    let svm_server = wiremock::MockServer::start().await;
+    mock_ledger_info(&hub_server, 1).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .respond_with(wiremock::ResponseTemplate::new(500))
+        .mount(&svm_server)
+        .await;
+
+    let mut config = test_helpers::build_test_config_with_svm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    // Only the hub and the mocked SVM chain are pinged
+    config.connected_chain_mvm.clear();
+    config.connected_chain_svm[0].rpc_url = svm_server.uri();
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let routes = ApiServer::new(config, monitor).test_routes();
+
+    let response = request().method("GET").path("/ready").reply(&routes).await;
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: ApiResponse<ReadinessReport> = serde_json::from_slice(response.body()).unwrap();
+    assert!(!body.success);
+    let report = body.data.unwrap();
+    assert!(!report.ready);
+    assert!(report.chains[0].reachable);
+    assert!(!report.chains[1].reachable);
+    assert_eq!(report.chains[1].chain_type, "svm");
+    assert!(report.chains[1].error.as_deref().unwrap().contains("getHealth"));
+}
+
+// ============================================================================
+// METRICS ENDPOINT TESTS
+// ============================================================================
+
+/// Test that the metrics endpoint is off unless enabled in the config
+/// What is tested: GET /metrics with the default config returns 404
+/// Why: Metrics are opt-in; a coordinator without metrics_enabled must not expose them
+#[tokio::test]
+async fn test_metrics_endpoint_disabled_by_default() {
+    let routes = create_test_api_server().await.test_routes();
+
+    let response = request().method("GET").path("/metrics").reply(&routes).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// Test that the metrics endpoint reports cache sizes and API requests when enabled
+/// What is tested: with api.metrics_enabled, one cached intent and a prior /health
+/// request show up in the Prometheus text output
+/// Why: Operators scrape these series to alert on stalled polling and API errors
+#[tokio::test]
+async fn test_metrics_endpoint_reports_cache_and_requests() {
+    let mut config = test_helpers::build_test_config_with_mvm();
+    config.api.metrics_enabled = true;
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    assert!(monitor.cache_intent_event(create_default_intent_mvm()).await);
+    let routes = ApiServer::new(config, monitor).test_routes();
+
+    let response = request().method("GET").path("/health").reply(&routes).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = request().method("GET").path("/metrics").reply(&routes).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = String::from_utf8(response.body().to_vec()).unwrap();
+    assert!(body.contains("coordinator_cached_intents 1"), "{}", body);
+    assert!(body.contains("coordinator_cached_fulfillments 0"), "{}", body);
+    assert!(
+        body.contains(r#"coordinator_http_requests_total{method="GET",status="200"} 1"#),
+        "{}",
+        body
+    );
+    assert!(body.contains("coordinator_hub_polls_total 0"), "{}", body);
+}
+
 // ============================================================================
 // EVENTS ENDPOINT TESTS
 // ============================================================================
@@ -82,6 +224,176 @@ async fn test_events_endpoint() {
     assert!(body.success);
 }
 
+// ============================================================================
+// SINGLE INTENT ENDPOINT TESTS
+// ============================================================================
+
+/// Test that GET /intents/:intent_id merges the intent, fulfillment and draft status
+/// What is tested: Cached hub events and the draft carrying the same intent ID are returned
+/// as one object, looked up with a non-padded form of the intent ID
+/// Why: A frontend tracking one intent should not have to download and filter every event
+#[tokio::test]
+async fn test_get_intent_merges_events_and_negotiation() {
+    let config = test_helpers::build_test_config_with_mvm();
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    assert!(monitor.cache_intent_event(create_default_intent_mvm()).await);
+    assert!(monitor.cache_fulfillment_event(create_default_fulfillment()).await);
+    let routes = ApiServer::new(config, monitor).test_routes();
+
+    let draft_response = request()
+        .method("POST")
+        .path("/draftintent")
+        .header("content-type", "application/json")
+        .json(&json!({
+            "requester_addr": DUMMY_REQUESTER_ADDR_HUB,
+            "draft_data": { "intent_id": DUMMY_INTENT_ID, "offered_amount": 100 },
+            "expiry_time": DUMMY_EXPIRY
+        }))
+        .reply(&routes)
+        .await;
+    assert_eq!(draft_response.status(), StatusCode::OK);
+
+    let response = request().method("GET").path("/intents/0x1").reply(&routes).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<IntentDetailsResponse> = serde_json::from_slice(response.body()).unwrap();
+    assert!(body.success);
+    let details = body.data.unwrap();
+    assert_eq!(details.intent.unwrap().intent_id, DUMMY_INTENT_ID);
+    assert_eq!(details.fulfillment.unwrap().intent_id, DUMMY_INTENT_ID);
+    let negotiation = details.negotiation.unwrap();
+    assert!(!negotiation.draft_id.is_empty());
+    assert!(negotiation.solver_hub_addr.is_none());
+}
+
+/// Test that GET /intents/:intent_id returns 404 for an unknown intent
+/// What is tested: No cached event or draft carries the requested intent ID
+/// Why: Callers must be able to tell "unknown" apart from "known but not yet fulfilled"
+#[tokio::test]
+async fn test_get_intent_unknown_returns_404() {
+    let api_server = create_test_api_server().await;
+    let routes = api_server.test_routes();
+
+    let response = request().method("GET").path("/intents/0xabc").reply(&routes).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: ApiResponse<IntentDetailsResponse> = serde_json::from_slice(response.body()).unwrap();
+    assert!(!body.success);
+    assert!(body.error.unwrap().contains("not found"));
+}
+
+// ============================================================================
+// LIVE UPDATE (WEBSOCKET) TESTS
+// ============================================================================
+
+/// Receive the next text frame from a live update socket as JSON.
+async fn recv_update(client: &mut warp::test::WsClient) -> serde_json::Value {
+    let message = tokio::time::timeout(std::time::Duration::from_secs(5), client.recv())
+        .await
+        .expect("timed out waiting for a live update")
+        .unwrap();
+    serde_json::from_str(message.to_str().unwrap()).unwrap()
+}
+
+/// Test that GET /ws pushes cached events and draft status changes as JSON frames
+/// What is tested: Intent, fulfillment and draft updates reach a subscriber in order
+/// Why: Frontends rely on the push channel instead of polling the REST endpoints
+#[tokio::test]
+async fn test_ws_pushes_live_updates() {
+    let config = test_helpers::build_test_config_with_mvm();
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let api_server = ApiServer::new(config, monitor.clone());
+    let routes = api_server.test_routes();
+    let mut client = warp::test::ws().path("/ws").handshake(routes.clone()).await.unwrap();
+
+    monitor.cache_intent_event(create_default_intent_mvm()).await;
+    monitor.cache_fulfillment_event(create_default_fulfillment()).await;
+    let draft_response = request()
+        .method("POST")
+        .path("/draftintent")
+        .header("content-type", "application/json")
+        .json(&json!({
+            "requester_addr": DUMMY_REQUESTER_ADDR_HUB,
+            "draft_data": { "intent_id": DUMMY_INTENT_ID, "offered_chain_id": "1", "desired_chain_id": "2" },
+            "expiry_time": DUMMY_EXPIRY
+        }))
+        .reply(&routes)
+        .await;
+    assert_eq!(draft_response.status(), StatusCode::OK);
+
+    let intent = recv_update(&mut client).await;
+    assert_eq!(intent["type"], "intent_created");
+    assert_eq!(intent["intent_id"], DUMMY_INTENT_ID);
+    let fulfillment = recv_update(&mut client).await;
+    assert_eq!(fulfillment["type"], "fulfillment");
+    let draft = recv_update(&mut client).await;
+    assert_eq!(draft["type"], "draft_status");
+    assert_eq!(draft["status"], "Pending");
+    assert_eq!(draft["chain_ids"], json!([1, 2]));
+}
+
+/// Test that GET /ws only forwards updates matching the intent_id filter
+/// What is tested: Events for other intents are skipped; the ID is normalized before matching
+/// Why: A frontend tracking one intent should not receive every event on the hub
+#[tokio::test]
+async fn test_ws_filters_by_intent_id() {
+    let config = test_helpers::build_test_config_with_mvm();
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let api_server = ApiServer::new(config, monitor.clone());
+    let routes = api_server.test_routes();
+    let mut client = warp::test::ws()
+        .path("/ws?intent_id=0x0001")
+        .handshake(routes)
+        .await
+        .unwrap();
+
+    let other_intent = coordinator::monitor::IntentEvent {
+        intent_id: "0xabc".to_string(),
+        ..create_default_intent_mvm()
+    };
+    monitor.cache_intent_event(other_intent).await;
+    monitor.cache_fulfillment_event(create_default_fulfillment()).await;
+
+    let update = recv_update(&mut client).await;
+    assert_eq!(update["type"], "fulfillment");
+    assert_eq!(update["intent_id"], DUMMY_INTENT_ID);
+}
+
+/// Test that UpdateFilter matches intent events by hub or connected chain
+/// What is tested: The chain_id filter for intent, fulfillment and draft updates
+/// Why: A chain-specific view must see intents escrowed on its chain and nothing else
+#[test]
+fn test_update_filter_matches_chain_id() {
+    use coordinator::api::UpdateFilter;
+    use coordinator::monitor::{CoordinatorUpdate, DraftStatusUpdate};
+    use coordinator::storage::DraftintentStatus;
+
+    let hub_chain_id = 1;
+    let intent = CoordinatorUpdate::IntentCreated(coordinator::monitor::IntentEvent {
+        connected_chain_id: Some(2),
+        ..create_default_intent_mvm()
+    });
+    let fulfillment = CoordinatorUpdate::Fulfillment(create_default_fulfillment());
+    let draft = CoordinatorUpdate::DraftStatus(DraftStatusUpdate {
+        draft_id: "draft-1".to_string(),
+        intent_id: None,
+        chain_ids: vec![1, 3],
+        status: DraftintentStatus::Pending,
+        solver_hub_addr: None,
+        version: 0,
+    });
+    let on_chain = |chain_id| UpdateFilter { intent_id: None, chain_id: Some(chain_id) };
+
+    assert!(on_chain(2).matches(&intent, hub_chain_id));
+    assert!(on_chain(1).matches(&intent, hub_chain_id));
+    assert!(!on_chain(3).matches(&intent, hub_chain_id));
+    assert!(on_chain(1).matches(&fulfillment, hub_chain_id));
+    assert!(!on_chain(2).matches(&fulfillment, hub_chain_id));
+    assert!(on_chain(3).matches(&draft, hub_chain_id));
+    assert!(!on_chain(2).matches(&draft, hub_chain_id));
+    assert!(UpdateFilter::default().matches(&draft, hub_chain_id));
+}
+
 // ============================================================================
 // DRAFT INTENT ENDPOINT TESTS
 // ============================================================================
@@ -173,6 +485,103 @@ async fn test_draftintent_empty_body() {
     assert!(!body.success);
 }
 
+// ============================================================================
+// IDEMPOTENCY TESTS
+// ============================================================================
+
+/// Test that a repeated Idempotency-Key replays the original response
+/// What is tested: Two POST /draftintent with the same key and body return byte-identical
+/// bodies, and only one draft is created
+/// Why: Client retries after a timeout must not create a second draft with a new ID and timestamp
+#[tokio::test]
+async fn test_draftintent_idempotency_key_replays_response() {
+    let api_server = create_test_api_server().await;
+    let routes = api_server.test_routes();
+
+    let send = || {
+        request()
+            .method("POST")
+            .path("/draftintent")
+            .header(IDEMPOTENCY_KEY_HEADER, "retry-1")
+            .json(&valid_draft_request())
+            .reply(&routes)
+    };
+    let first = send().await;
+    let second = send().await;
+
+    assert!(first.status().is_success());
+    assert_eq!(first.status(), second.status());
+    assert_eq!(first.body(), second.body(), "Replayed body must be byte-identical");
+
+    let pending = request()
+        .method("GET")
+        .path("/draftintents/pending")
+        .reply(&routes)
+        .await;
+    let body: ApiResponse<Vec<serde_json::Value>> = serde_json::from_slice(pending.body()).unwrap();
+    assert_eq!(body.data.unwrap().len(), 1, "Only one draft must be created");
+}
+
+/// Test that requests without an Idempotency-Key are never replayed
+/// What is tested: Two identical POST /draftintent without the header create two drafts
+/// Why: Idempotency is opt-in; existing clients must keep their behavior
+#[tokio::test]
+async fn test_draftintent_without_idempotency_key_creates_each_time() {
+    let api_server = create_test_api_server().await;
+    let routes = api_server.test_routes();
+
+    for _ in 0..2 {
+        let response = request()
+            .method("POST")
+            .path("/draftintent")
+            .json(&valid_draft_request())
+            .reply(&routes)
+            .await;
+        assert!(response.status().is_success());
+    }
+
+    let pending = request()
+        .method("GET")
+        .path("/draftintents/pending")
+        .reply(&routes)
+        .await;
+    let body: ApiResponse<Vec<serde_json::Value>> = serde_json::from_slice(pending.body()).unwrap();
+    assert_eq!(body.data.unwrap().len(), 2);
+}
+
+/// Test that reusing an Idempotency-Key with a different body is rejected
+/// What is tested: Second POST /draftintent with the same key but another body returns 422
+/// Why: Replaying the first response for a different request would silently drop the new draft
+#[tokio::test]
+async fn test_draftintent_idempotency_key_reused_with_different_body() {
+    let api_server = create_test_api_server().await;
+    let routes = api_server.test_routes();
+
+    let first = request()
+        .method("POST")
+        .path("/draftintent")
+        .header(IDEMPOTENCY_KEY_HEADER, "retry-2")
+        .json(&valid_draft_request())
+        .reply(&routes)
+        .await;
+    assert!(first.status().is_success());
+
+    let mut other = valid_draft_request();
+    other["expiry_time"] = json!(DUMMY_EXPIRY + 1);
+    let second = request()
+        .method("POST")
+        .path("/draftintent")
+        .header(IDEMPOTENCY_KEY_HEADER, "retry-2")
+        .json(&other)
+        .reply(&routes)
+        .await;
+
+    assert_eq!(second.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: ApiResponse<()> = serde_json::from_slice(second.body()).unwrap();
+    assert!(!body.success);
+    assert!(body.error.unwrap().contains("Idempotency-Key"));
+}
+
 // ============================================================================
 // SIGNATURE SUBMISSION ENDPOINT TESTS
 // ============================================================================
@@ -300,3 +709,771 @@ async fn test_signature_route_not_confused_with_draft_route() {
         );
     }
 }
+
+// ============================================================================
+// SOLVER HEARTBEAT TESTS
+// ============================================================================
+
+/// Mock the hub solver registry to return `registered_key` as the solver's public key
+async fn mock_solver_registry(mock_server: &wiremock::MockServer, registered_key: &ed25519_dalek::SigningKey) {
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/v1/view"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(json!([format!(
+            "0x{}",
+            hex::encode(registered_key.verifying_key().to_bytes())
+        )])))
+        .mount(mock_server)
+        .await;
+}
+
+/// Build a heartbeat body for the test solver sent at `timestamp`
+fn heartbeat_body(timestamp: u64) -> Vec<u8> {
+    serde_json::to_vec(&json!({ "solver_hub_addr": DUMMY_SOLVER_ADDR_HUB, "timestamp": timestamp })).unwrap()
+}
+
+/// Current Unix timestamp in seconds
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Test that a solver heartbeat is recorded
+/// What is tested: POST /solver/heartbeat signed with the registered key returns the recorded last-seen timestamp
+/// Why: Solvers must be able to report liveness for liveness-aware routing
+#[tokio::test]
+async fn test_solver_heartbeat() {
+    use ed25519_dalek::Signer;
+
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    mock_solver_registry(&mock_server, &solver_key).await;
+    let config = test_helpers::build_test_config_with_mock_server(&mock_server.uri());
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let api_server = ApiServer::new(config, monitor);
+    let routes = api_server.test_routes();
+
+    let body = heartbeat_body(now_secs());
+    let request_signature = hex::encode(solver_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path("/solver/heartbeat")
+        .header("content-type", "application/json")
+        .header("x-solver-signature", request_signature)
+        .body(body)
+        .reply(&routes)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<serde_json::Value> = serde_json::from_slice(response.body()).unwrap();
+    assert!(body.success);
+    let data = body.data.unwrap();
+    assert_eq!(data["solver_hub_addr"], DUMMY_SOLVER_ADDR_HUB);
+    assert!(data["last_seen"].as_u64().unwrap() > 0);
+}
+
+/// Test that a heartbeat not signed with the solver's registered key is rejected
+/// What is tested: Unsigned and forged heartbeats are rejected with 401
+/// Why: Anyone could otherwise keep any solver address live
+#[tokio::test]
+async fn test_unauthenticated_heartbeat_rejected() {
+    use ed25519_dalek::Signer;
+
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let attacker_key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    mock_solver_registry(&mock_server, &solver_key).await;
+    let config = test_helpers::build_test_config_with_mock_server(&mock_server.uri());
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let api_server = ApiServer::new(config, monitor);
+    let routes = api_server.test_routes();
+
+    let body = heartbeat_body(now_secs());
+    let forged_signature = hex::encode(attacker_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path("/solver/heartbeat")
+        .header("content-type", "application/json")
+        .header("x-solver-signature", forged_signature)
+        .body(body.clone())
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response_body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(response_body.error.unwrap().contains("registered key"));
+
+    let response = request()
+        .method("POST")
+        .path("/solver/heartbeat")
+        .header("content-type", "application/json")
+        .body(body)
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that a correctly signed heartbeat with a stale timestamp is rejected
+/// What is tested: Heartbeats outside the allowed clock skew are rejected with 400
+/// Why: A captured signed heartbeat must not keep a silent solver live indefinitely
+#[tokio::test]
+async fn test_stale_heartbeat_rejected() {
+    use ed25519_dalek::Signer;
+
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    mock_solver_registry(&mock_server, &solver_key).await;
+    let config = test_helpers::build_test_config_with_mock_server(&mock_server.uri());
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let api_server = ApiServer::new(config, monitor);
+    let routes = api_server.test_routes();
+
+    let body = heartbeat_body(now_secs() - 600);
+    let request_signature = hex::encode(solver_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path("/solver/heartbeat")
+        .header("content-type", "application/json")
+        .header("x-solver-signature", request_signature)
+        .body(body)
+        .reply(&routes)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response_body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(response_body.error.unwrap().contains("timestamp"));
+}
+
+/// Test that a signature from a solver without a heartbeat is rejected when liveness is enabled
+/// What is tested: Signature submission is limited to live solvers
+/// Why: Drafts must not be assigned to solvers that are not online
+#[tokio::test]
+async fn test_signature_rejected_without_heartbeat() {
+    let mut config = test_helpers::build_test_config_with_mvm();
+    config.solver_liveness = Some(coordinator::SolverLivenessConfig {
+        liveness_window_secs: 60,
+        reassign_timeout_secs: 120,
+    });
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    let api_server = ApiServer::new(config, monitor);
+    let routes = api_server.test_routes();
+
+    let create_response = request()
+        .method("POST")
+        .path("/draftintent")
+        .json(&valid_draft_request())
+        .reply(&routes)
+        .await;
+    let create_body: ApiResponse<serde_json::Value> =
+        serde_json::from_slice(create_response.body()).unwrap();
+    let draft_id = create_body.data.as_ref().unwrap()["draft_id"]
+        .as_str()
+        .unwrap();
+
+    let response = request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_id))
+        .json(&json!({
+            "solver_hub_addr": DUMMY_SOLVER_ADDR_HUB,
+            "signature": "0x".to_string() + &"ab".repeat(64),
+            "public_key": "0x1234"
+        }))
+        .reply(&routes)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(body.error.unwrap().contains("heartbeat"));
+}
+
+// ============================================================================
+// SIGNED SUBMISSION TESTS
+// ============================================================================
+
+/// Create an API server that requires signed submissions, with the hub solver registry
+/// mocked to return `registered_key` as the solver's public key
+async fn create_signed_submission_api_server(
+    mock_server: &wiremock::MockServer,
+    registered_key: &ed25519_dalek::SigningKey,
+) -> ApiServer {
+    mock_solver_registry(mock_server, registered_key).await;
+
+    let mut config = test_helpers::build_test_config_with_mock_server(&mock_server.uri());
+    config.api.require_signed_submissions = true;
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    ApiServer::new(config, monitor)
+}
+
+/// Create a draft through the API and return its ID
+async fn create_draft<F>(routes: &F) -> String
+where
+    F: warp::Filter + Clone + 'static,
+    F::Extract: warp::Reply + Send,
+{
+    let response = request()
+        .method("POST")
+        .path("/draftintent")
+        .json(&valid_draft_request())
+        .reply(routes)
+        .await;
+    let body: ApiResponse<serde_json::Value> = serde_json::from_slice(response.body()).unwrap();
+    body.data.unwrap()["draft_id"].as_str().unwrap().to_string()
+}
+
+/// Build a signature submission body for the test solver, signed for `draft_id` at `timestamp`
+fn signature_submission_body(draft_id: &str, timestamp: u64) -> Vec<u8> {
+    serde_json::to_vec(&json!({
+        "solver_hub_addr": DUMMY_SOLVER_ADDR_HUB,
+        "signature": "0x".to_string() + &"ab".repeat(64),
+        "public_key": "0x".to_string() + &"cd".repeat(32),
+        "draft_id": draft_id,
+        "timestamp": timestamp
+    }))
+    .unwrap()
+}
+
+/// Test that a submission signed with the solver's registered key is accepted
+/// What is tested: X-Solver-Signature over the raw body verifies against the registry public key
+/// Why: Solvers that control their registered key must be able to submit when signing is required
+#[tokio::test]
+async fn test_signed_submission_accepted() {
+    use ed25519_dalek::Signer;
+
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    let api_server = create_signed_submission_api_server(&mock_server, &solver_key).await;
+    let routes = api_server.test_routes();
+    let draft_id = create_draft(&routes).await;
+
+    let body = signature_submission_body(&draft_id, now_secs());
+    let request_signature = hex::encode(solver_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_id))
+        .header("content-type", "application/json")
+        .header("x-solver-signature", request_signature)
+        .body(body)
+        .reply(&routes)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<serde_json::Value> = serde_json::from_slice(response.body()).unwrap();
+    assert!(body.success);
+    assert_eq!(body.data.unwrap()["status"], "signed");
+}
+
+/// Test that a submission signed with a key other than the solver's registered key is rejected
+/// What is tested: A forged X-Solver-Signature is rejected with 401 and the draft stays unsigned
+/// Why: A solver must not be able to submit on another solver's behalf
+#[tokio::test]
+async fn test_forged_submission_rejected() {
+    use ed25519_dalek::Signer;
+
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let attacker_key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    let api_server = create_signed_submission_api_server(&mock_server, &solver_key).await;
+    let routes = api_server.test_routes();
+    let draft_id = create_draft(&routes).await;
+
+    let body = signature_submission_body(&draft_id, now_secs());
+    let forged_signature = hex::encode(attacker_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_id))
+        .header("content-type", "application/json")
+        .header("x-solver-signature", forged_signature)
+        .body(body)
+        .reply(&routes)
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response_body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(!response_body.success);
+    assert!(response_body.error.unwrap().contains("registered key"));
+
+    // Unsigned submission is rejected as well
+    let unsigned_body = signature_submission_body(&draft_id, now_secs());
+    let response = request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_id))
+        .json(&serde_json::from_slice::<serde_json::Value>(&unsigned_body).unwrap())
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Test that a signed submission cannot be replayed against another draft or later
+/// What is tested: A body validly signed for draft A is rejected with 401 on draft B, as is a stale signed body
+/// Why: The request signature covers only the body, so the body must bind the draft and a recent time
+#[tokio::test]
+async fn test_signed_submission_replay_rejected() {
+    use ed25519_dalek::Signer;
+
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    let api_server = create_signed_submission_api_server(&mock_server, &solver_key).await;
+    let routes = api_server.test_routes();
+    let draft_a = create_draft(&routes).await;
+    let draft_b = create_draft(&routes).await;
+
+    let body = signature_submission_body(&draft_a, now_secs());
+    let request_signature = hex::encode(solver_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_b))
+        .header("content-type", "application/json")
+        .header("x-solver-signature", request_signature)
+        .body(body)
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response_body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(response_body.error.unwrap().contains(&draft_a));
+
+    let body = signature_submission_body(&draft_b, now_secs() - 600);
+    let request_signature = hex::encode(solver_key.sign(&body).to_bytes());
+
+    let response = request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_b))
+        .header("content-type", "application/json")
+        .header("x-solver-signature", request_signature)
+        .body(body)
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response_body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(response_body.error.unwrap().contains("timestamp"));
+}
+
+// ============================================================================
+// EXCHANGE RATE STALENESS TESTS
+// ============================================================================
+
+/// Token pair served by the acceptance proxy in the staleness tests
+fn staleness_test_pair() -> TokenPairConfig {
+    TokenPairConfig {
+        source_chain_id: 1,
+        source_token: DUMMY_METADATA_ADDR_MVM.to_string(),
+        target_chain_id: 2,
+        target_token: DUMMY_TOKEN_ADDR_FANTOM.to_string(),
+        max_rate_staleness_secs: 60,
+    }
+}
+
+/// Exchange rate as reported by the solver for the staleness test pair
+fn staleness_test_rate() -> ExchangeRateResponse {
+    ExchangeRateResponse {
+        desired_token: DUMMY_TOKEN_ADDR_FANTOM.to_string(),
+        desired_chain_id: 2,
+        exchange_rate: 1.5,
+        base_fee_in_move: 1000,
+        move_rate: 0.5,
+        fee_bps: 50,
+        stale: false,
+        rate_age_secs: None,
+    }
+}
+
+/// Create an API server whose acceptance proxy points at the mocked solver
+async fn create_acceptance_api_server(solver: &wiremock::MockServer) -> ApiServer {
+    let mut config = test_helpers::build_test_config_with_mvm();
+    config.acceptance = Some(AcceptanceConfig {
+        solver_url: solver.uri(),
+        pairs: vec![staleness_test_pair()],
+        quote_cache_ttl_secs: 5,
+    });
+    let monitor = EventMonitor::new(&config).await.unwrap();
+    ApiServer::new(config, monitor)
+}
+
+/// Mock the solver acceptance endpoint as unreachable (500)
+async fn mount_failing_solver(solver: &wiremock::MockServer) {
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/acceptance"))
+        .respond_with(wiremock::ResponseTemplate::new(500))
+        .mount(solver)
+        .await;
+}
+
+/// Acceptance request path for the staleness test pair
+fn acceptance_path() -> String {
+    format!(
+        "/acceptance?offered_chain_id=1&offered_token={}&desired_chain_id=2&desired_token={}",
+        DUMMY_METADATA_ADDR_MVM, DUMMY_TOKEN_ADDR_FANTOM
+    )
+}
+
+/// Unix timestamp `secs_ago` seconds in the past
+fn timestamp_secs_ago(secs_ago: u64) -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        - secs_ago
+}
+
+/// Test that a live solver rate is returned fresh
+/// What is tested: GET /acceptance proxies the solver rate with stale=false and no age
+/// Why: Clients must be able to tell a live rate from a cached fallback
+#[tokio::test]
+async fn test_exchange_rate_fresh_from_solver() {
+    let solver = wiremock::MockServer::start().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/acceptance"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(json!({
+            "success": true,
+            "data": staleness_test_rate(),
+            "error": null
+        })))
+        .mount(&solver)
+        .await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    let response = request()
+        .method("GET")
+        .path(&acceptance_path())
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<ExchangeRateResponse> = serde_json::from_slice(response.body()).unwrap();
+    let rate = body.data.unwrap();
+    assert_eq!(rate.exchange_rate, 1.5);
+    assert!(!rate.stale);
+    assert_eq!(rate.rate_age_secs, None);
+    assert!(api_server.rate_cache().get(&staleness_test_pair()).await.is_some());
+}
+
+/// Test that a cached rate within the staleness bound is served when the solver fails
+/// What is tested: Solver returns 500, cached rate is 30s old with a 60s bound -> 200 with stale=true
+/// Why: Brief solver outages should not take quoting down while the last rate is still trustworthy
+#[tokio::test]
+async fn test_exchange_rate_stale_within_bound() {
+    let solver = wiremock::MockServer::start().await;
+    mount_failing_solver(&solver).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    api_server
+        .rate_cache()
+        .insert_at(&staleness_test_pair(), staleness_test_rate(), timestamp_secs_ago(30))
+        .await;
+
+    let response = request()
+        .method("GET")
+        .path(&acceptance_path())
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<ExchangeRateResponse> = serde_json::from_slice(response.body()).unwrap();
+    let rate = body.data.unwrap();
+    assert_eq!(rate.exchange_rate, 1.5);
+    assert!(rate.stale);
+    assert!(rate.rate_age_secs.unwrap() >= 30);
+}
+
+/// Test that a cached rate beyond the staleness bound is reported as unavailable
+/// What is tested: Solver returns 500, cached rate is 120s old with a 60s bound -> 503
+/// Why: Serving an arbitrarily old rate would let requesters sign intents at outdated prices
+#[tokio::test]
+async fn test_exchange_rate_stale_beyond_bound() {
+    let solver = wiremock::MockServer::start().await;
+    mount_failing_solver(&solver).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    api_server
+        .rate_cache()
+        .insert_at(&staleness_test_pair(), staleness_test_rate(), timestamp_secs_ago(120))
+        .await;
+
+    let response = request()
+        .method("GET")
+        .path(&acceptance_path())
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(!body.success);
+    assert!(body.error.unwrap().contains("Rate unavailable"));
+}
+
+// ============================================================================
+// QUOTE ENDPOINT TESTS
+// ============================================================================
+
+/// Quote request path for the staleness test pair
+fn quote_path(amount: u64) -> String {
+    format!(
+        "/quote?source_chain=1&source_token={}&target_chain=2&target_token={}&amount={}",
+        DUMMY_METADATA_ADDR_MVM, DUMMY_TOKEN_ADDR_FANTOM, amount
+    )
+}
+
+/// Mock the solver acceptance endpoint returning the staleness test rate, expecting `calls` requests
+async fn mount_solver_rate(solver: &wiremock::MockServer, calls: u64) {
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/acceptance"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(json!({
+            "success": true,
+            "data": staleness_test_rate(),
+            "error": null
+        })))
+        .expect(calls)
+        .mount(solver)
+        .await;
+}
+
+/// Test that a quote applies the solver fee and rate to the source amount
+/// What is tested: amount 10000 at rate 1.5 with base fee 1000 * 0.5 and 50 bps -> fee 550, target 6300
+/// Why: The quoted target amount must match what the solver will accept for the same pair
+#[tokio::test]
+async fn test_quote_fresh_from_solver() {
+    let solver = wiremock::MockServer::start().await;
+    mount_solver_rate(&solver, 1).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    let response = request()
+        .method("GET")
+        .path(&quote_path(10000))
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<QuoteResponse> = serde_json::from_slice(response.body()).unwrap();
+    let quote = body.data.unwrap();
+    assert_eq!(quote.amount, 10000);
+    assert_eq!(quote.exchange_rate, 1.5);
+    assert_eq!(quote.fee_in_source_token, 550);
+    assert_eq!(quote.target_amount, 6300);
+    assert!(!quote.stale);
+    assert!(quote.quoted_at >= timestamp_secs_ago(5));
+}
+
+/// Test that repeated quotes within the TTL reuse the cached solver rate
+/// What is tested: Two quotes in a row -> both 200, solver queried once
+/// Why: The cache exists to take the solver round trip off the request path
+#[tokio::test]
+async fn test_quote_reuses_cached_rate_within_ttl() {
+    let solver = wiremock::MockServer::start().await;
+    mount_solver_rate(&solver, 1).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    let routes = api_server.test_routes();
+    for _ in 0..2 {
+        let response = request().method("GET").path(&quote_path(10000)).reply(&routes).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: ApiResponse<QuoteResponse> = serde_json::from_slice(response.body()).unwrap();
+        assert!(!body.data.unwrap().stale);
+    }
+}
+
+/// Test that the last cached quote is served stale when the solver is unreachable
+/// What is tested: Solver returns 500, cached rate is 1h old -> 200 with stale=true and the original timestamp
+/// Why: Quotes are informational; an old rate marked stale is more useful than an error
+#[tokio::test]
+async fn test_quote_serves_stale_rate_when_solver_unreachable() {
+    let solver = wiremock::MockServer::start().await;
+    mount_failing_solver(&solver).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    let fetched_at = timestamp_secs_ago(3600);
+    api_server
+        .rate_cache()
+        .insert_at(&staleness_test_pair(), staleness_test_rate(), fetched_at)
+        .await;
+
+    let response = request()
+        .method("GET")
+        .path(&quote_path(10000))
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<QuoteResponse> = serde_json::from_slice(response.body()).unwrap();
+    let quote = body.data.unwrap();
+    assert!(quote.stale);
+    assert_eq!(quote.quoted_at, fetched_at);
+    assert_eq!(quote.target_amount, 6300);
+}
+
+/// Test that a quote without any cached rate fails while the solver is unreachable
+/// What is tested: Solver returns 500, empty cache -> 503
+/// Why: There is no rate to serve, stale or otherwise
+#[tokio::test]
+async fn test_quote_unavailable_without_cached_rate() {
+    let solver = wiremock::MockServer::start().await;
+    mount_failing_solver(&solver).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    let response = request()
+        .method("GET")
+        .path(&quote_path(10000))
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+/// Test that quotes for pairs outside the acceptance config are rejected
+/// What is tested: Target chain 3 is not configured -> 404 with "Unsupported pair"
+/// Why: The coordinator must not proxy quotes for pairs the solver does not serve
+#[tokio::test]
+async fn test_quote_unsupported_pair_returns_404() {
+    let solver = wiremock::MockServer::start().await;
+    mount_solver_rate(&solver, 0).await;
+
+    let api_server = create_acceptance_api_server(&solver).await;
+    let path = format!(
+        "/quote?source_chain=1&source_token={}&target_chain=3&target_token={}&amount=10000",
+        DUMMY_METADATA_ADDR_MVM, DUMMY_TOKEN_ADDR_FANTOM
+    );
+    let response = request()
+        .method("GET")
+        .path(&path)
+        .reply(&api_server.test_routes())
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: ApiResponse<()> = serde_json::from_slice(response.body()).unwrap();
+    assert!(body.error.unwrap().contains("Unsupported pair"));
+}
+
+// ============================================================================
+// AUDIT TRAIL TESTS
+// ============================================================================
+
+/// Create a draft carrying `DUMMY_INTENT_ID` through the API and return its ID
+async fn create_draft_for_intent<F>(routes: &F) -> String
+where
+    F: warp::Filter + Clone + 'static,
+    F::Extract: warp::Reply + Send,
+{
+    let mut draft = valid_draft_request();
+    draft["draft_data"]["intent_id"] = json!(DUMMY_INTENT_ID);
+    let response = request()
+        .method("POST")
+        .path("/draftintent")
+        .json(&draft)
+        .reply(routes)
+        .await;
+    let body: ApiResponse<serde_json::Value> = serde_json::from_slice(response.body()).unwrap();
+    body.data.unwrap()["draft_id"].as_str().unwrap().to_string()
+}
+
+/// Submit the test solver's signature for `draft_id`, signing the request with `key`
+async fn submit_signed<F>(routes: &F, draft_id: &str, key: &ed25519_dalek::SigningKey) -> StatusCode
+where
+    F: warp::Filter + Clone + 'static,
+    F::Extract: warp::Reply + Send,
+{
+    use ed25519_dalek::Signer;
+
+    let body = signature_submission_body(draft_id, now_secs());
+    let request_signature = hex::encode(key.sign(&body).to_bytes());
+    request()
+        .method("POST")
+        .path(&format!("/draftintent/{}/signature", draft_id))
+        .header("content-type", "application/json")
+        .header("x-solver-signature", request_signature)
+        .body(body)
+        .reply(routes)
+        .await
+        .status()
+}
+
+/// Fetch the audit trail for `DUMMY_INTENT_ID`
+async fn get_audit_trail<F>(routes: &F) -> AuditTrailResponse
+where
+    F: warp::Filter + Clone + 'static,
+    F::Extract: warp::Reply + Send,
+{
+    let response = request()
+        .method("GET")
+        .path(&format!("/audit/{}", DUMMY_INTENT_ID))
+        .reply(routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: ApiResponse<AuditTrailResponse> = serde_json::from_slice(response.body()).unwrap();
+    body.data.unwrap()
+}
+
+/// Test that the audit trail records a full happy path
+/// What is tested: Draft submission and an accepted solver signature are recorded as
+/// observed, validated and approval signed, in that order, under the draft's intent ID
+/// Why: The trail must explain how the coordinator came to hand out an approval
+#[tokio::test]
+async fn test_audit_trail_records_happy_path() {
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    let api_server = create_signed_submission_api_server(&mock_server, &solver_key).await;
+    let routes = api_server.test_routes();
+
+    let draft_id = create_draft_for_intent(&routes).await;
+    assert_eq!(submit_signed(&routes, &draft_id, &solver_key).await, StatusCode::OK);
+
+    let trail = get_audit_trail(&routes).await;
+    let actions: Vec<AuditAction> = trail.events.iter().map(|e| e.action.clone()).collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::Observed { source: AuditSource::Draft },
+            AuditAction::Validated { passed: true, reason: None },
+            AuditAction::ApprovalSigned {
+                solver_hub_addr: DUMMY_SOLVER_ADDR_HUB.to_string(),
+                public_key: "0x".to_string() + &"cd".repeat(32),
+                chain_id: 1,
+            },
+        ]
+    );
+    assert!(trail.events.iter().all(|e| e.draft_id.as_deref() == Some(draft_id.as_str())));
+    assert!(trail.events.windows(2).all(|w| w[0].seq < w[1].seq));
+}
+
+/// Test that the audit trail records rejections
+/// What is tested: A forged submission is recorded as a failed validation with its reason,
+/// and a claim on an already signed draft as a rejection; unknown intents return 404
+/// Why: Refused submissions are exactly what a dispute or compliance review needs to see
+#[tokio::test]
+async fn test_audit_trail_records_rejection() {
+    let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let attacker_key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+    let mock_server = wiremock::MockServer::start().await;
+    let api_server = create_signed_submission_api_server(&mock_server, &solver_key).await;
+    let routes = api_server.test_routes();
+
+    let draft_id = create_draft_for_intent(&routes).await;
+    assert_eq!(submit_signed(&routes, &draft_id, &attacker_key).await, StatusCode::UNAUTHORIZED);
+    assert_eq!(submit_signed(&routes, &draft_id, &solver_key).await, StatusCode::OK);
+    assert_eq!(submit_signed(&routes, &draft_id, &solver_key).await, StatusCode::CONFLICT);
+
+    let trail = get_audit_trail(&routes).await;
+    assert_eq!(trail.events.len(), 5);
+    match &trail.events[1].action {
+        AuditAction::Validated { passed: false, reason: Some(reason) } => {
+            assert!(reason.contains("registered key"), "Unexpected reason: {}", reason)
+        }
+        other => panic!("Expected failed validation, got {:?}", other),
+    }
+    match &trail.events[4].action {
+        AuditAction::Rejected { reason } => {
+            assert!(reason.contains("already signed"), "Unexpected reason: {}", reason)
+        }
+        other => panic!("Expected rejection, got {:?}", other),
+    }
+
+    let response = request()
+        .method("GET")
+        .path("/audit/0xdead")
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -86,7 +86,9 @@ fn test_config_validate_acceptance_svm_base58() {
             source_token: DUMMY_TOKEN_ADDR_FANTOM.to_string(),
             target_chain_id: 901,
             target_token: DUMMY_SVM_ESCROW_PROGRAM_ID.to_string(),
+            max_rate_staleness_secs: 0,
         }],
+        quote_cache_ttl_secs: 5,
     });
 
     let result = config.validate();
@@ -237,3 +239,144 @@ fn test_config_validate_unique_chain_ids() {
     let result = config.validate();
     assert!(result.is_ok(), "Should accept unique chain IDs");
 }
+
+// ============================================================================
+// FIELD VALIDATION TESTS
+// ============================================================================
+
+/// What is tested: Config::validate() rejects a zero API port with its field path
+/// Why: Operators need to know which setting to fix, not just that the config is invalid
+#[test]
+fn test_config_validate_rejects_zero_api_port() {
+    let mut config = Config::default();
+    config.api.port = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("api.port must be 1..65535, got 0"), "Unexpected error: {}", err);
+}
+
+/// What is tested: Config::validate() rejects a zero polling interval
+/// Why: A zero interval would spin the event monitor against every RPC
+#[test]
+fn test_config_validate_rejects_zero_polling_interval() {
+    let mut config = Config::default();
+    config.coordinator.polling_interval_ms = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("coordinator.polling_interval_ms must be greater than 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: Config::validate() names the connected chain section and field that is invalid
+/// Why: With several connected chains, the error must point at the right section
+#[test]
+fn test_config_validate_rejects_invalid_chain_fields() {
+    let mut config = Config::default();
+    config.connected_chain_evm = vec![EvmChainConfig {
+        name: "EVM Chain".to_string(),
+        rpc_url: "127.0.0.1:8545".to_string(),
+        escrow_contract_addr: DUMMY_ESCROW_CONTRACT_ADDR_EVM.to_string(),
+        outflow_validator_contract_addr: "0x0000000000000000000000000000000000000010".to_string(),
+        chain_id: 31337,
+        event_block_range: 1000,
+    }];
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("connected_chain_evm[0].rpc_url must start with http:// or https://, got '127.0.0.1:8545'"),
+        "Unexpected error: {}",
+        err
+    );
+
+    config.connected_chain_evm[0].rpc_url = "http://127.0.0.1:8545".to_string();
+    config.connected_chain_evm[0].event_block_range = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("connected_chain_evm[0].event_block_range must be greater than 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: Config::validate() rejects an empty required hub field
+/// Why: An empty module address would only fail later at the first RPC call
+#[test]
+fn test_config_validate_rejects_empty_hub_module_addr() {
+    let mut config = Config::default();
+    config.hub_chain.intent_module_addr = String::new();
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("hub_chain.intent_module_addr must not be empty"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+// ============================================================================
+// CONFIG CHECK TESTS
+// ============================================================================
+
+/// What is tested: Config::validation_report() lists every error, not just the first
+/// Why: `--check-config` should let operators fix a config in one pass
+#[test]
+fn test_config_validation_report_collects_every_error() {
+    let mut config = Config::default();
+    config.api.port = 0;
+    config.coordinator.polling_interval_ms = 0;
+    config.connected_chain_mvm = vec![ChainConfig {
+        name: "Connected Chain".to_string(),
+        rpc_url: "http://127.0.0.1:8082".to_string(),
+        chain_id: config.hub_chain.chain_id,
+        intent_module_addr: "0x2".to_string(),
+        escrow_module_addr: None,
+    }];
+
+    let report = config.validation_report();
+    assert!(!report.is_valid());
+    assert_eq!(report.errors.len(), 3, "Unexpected errors: {:?}", report.errors);
+    assert!(report.errors.iter().any(|e| e.contains("api.port must be 1..65535, got 0")));
+    assert!(report.errors.iter().any(|e| e.contains("coordinator.polling_interval_ms must be greater than 0")));
+    assert!(report.errors.iter().any(|e| e.contains("Hub chain and connected MVM chain have the same chain ID")));
+
+    let err = config.validate().unwrap_err().to_string();
+    assert_eq!(err, report.errors[0]);
+}
+
+/// What is tested: Config::check() reports an unparseable contract address and warnings from the file
+/// Why: A mistyped address passes validate() and would otherwise only fail once the monitor queries the chain
+#[test]
+fn test_config_check_reports_unparseable_address() {
+    let mut config = Config::default();
+    config.connected_chain_evm = vec![EvmChainConfig {
+        name: "EVM Chain".to_string(),
+        rpc_url: "http://127.0.0.1:8545".to_string(),
+        escrow_contract_addr: "0x123".to_string(),
+        outflow_validator_contract_addr: DUMMY_ESCROW_CONTRACT_ADDR_EVM.to_string(),
+        chain_id: 31337,
+        event_block_range: 1000,
+    }];
+    config.acceptance = Some(AcceptanceConfig {
+        solver_url: "http://127.0.0.1:4444".to_string(),
+        pairs: vec![],
+        quote_cache_ttl_secs: 5,
+    });
+
+    let warnings = config.validate().expect("Addresses are only parsed by the config check");
+    assert_eq!(warnings, vec!["acceptance.pairs is empty; no exchange rates are served".to_string()]);
+
+    let config_path = std::env::temp_dir().join(format!("coordinator-check-{}.toml", rand::random::<u64>()));
+    std::fs::write(&config_path, toml::to_string(&config).unwrap()).unwrap();
+    let report = Config::check(config_path.to_str().unwrap());
+    std::fs::remove_file(&config_path).unwrap();
+
+    assert_eq!(
+        report.errors,
+        vec!["Configuration error: connected_chain_evm[0].escrow_contract_addr is not a valid EVM address (20 bytes hex), got '0x123'".to_string()]
+    );
+    assert_eq!(report.warnings, warnings);
+}
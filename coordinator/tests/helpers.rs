@@ -145,15 +145,26 @@ pub fn build_test_config_with_mvm() -> Config {
         coordinator: CoordinatorConfig {
             polling_interval_ms: 1000,
             validation_timeout_ms: 1000,
+            max_backoff_ms: 60000,
+            max_idle_polling_interval_ms: None,
+            idle_polls_before_widening: 3,
+            event_cache_dir: None,
+            expiry_grace_secs: 5,
+            audit_log_capacity: 10000,
+            audit_log_spill_file: None,
         },
         api: ApiConfig {
             host: "127.0.0.1".to_string(),
             port: 3999,
             cors_origins: vec![],
+            require_signed_submissions: false,
+            draft_retention_secs: 3600,
+            metrics_enabled: false,
         },
         connected_chain_evm: vec![], // No connected EVM chains for unit tests
         connected_chain_svm: vec![], // No connected SVM chains for unit tests
         acceptance: None, // No acceptance criteria for unit tests
+        solver_liveness: None, // No solver liveness tracking for unit tests
     }
 }
 
@@ -302,6 +313,7 @@ pub fn create_default_mvm_transaction() -> MvmTransaction {
         version: "12345".to_string(),
         hash: "0x123123".to_string(), // Transaction hash - arbitrary test value
         success: true,
+        vm_status: "Executed successfully".to_string(),
         events: vec![],
     }
 }
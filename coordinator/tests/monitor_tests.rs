@@ -6,12 +6,15 @@
 //! Note: Validation-related tests are in integrated-gmp since the coordinator
 //! is read-only and doesn't perform validation or signing.
 
-use coordinator::monitor::{EventMonitor, IntentEvent};
+use coordinator::monitor::{is_intent_expired, poll_hub_once, EventMonitor, IntentEvent, PollBackoff};
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 #[path = "mod.rs"]
 mod test_helpers;
 use test_helpers::{
-    build_test_config_with_mvm,
-    create_default_intent_mvm,
+    build_test_config_with_mock_server, build_test_config_with_mvm,
+    create_default_fulfillment, create_default_intent_mvm,
 };
 
 // ============================================================================
@@ -133,3 +136,203 @@ async fn test_monitor_initialization_and_basic_ops() {
     assert_eq!(cache.len(), 1);
     assert_eq!(cache[0].intent_id, intent.intent_id);
 }
+
+// ============================================================================
+// EXPIRY TESTS
+// ============================================================================
+
+/// Test that an intent past its expiry plus the grace window is expired
+/// Why: An intent already dead when first observed must not be cached for fulfillment
+#[test]
+fn test_intent_expired_beyond_grace() {
+    let now = 1_000_000;
+    assert!(is_intent_expired(now - 6, now, 5));
+    assert!(is_intent_expired(now - 1, now, 0), "Without grace, just-expired intents are expired");
+}
+
+/// Test that an intent within the grace window is still accepted
+/// Why: The coordinator clock may run a few seconds ahead of the hub chain
+#[test]
+fn test_intent_within_grace_not_expired() {
+    let now = 1_000_000;
+    assert!(!is_intent_expired(now - 5, now, 5));
+    assert!(!is_intent_expired(now, now, 0));
+    assert!(!is_intent_expired(now + 60, now, 5));
+    assert!(!is_intent_expired(u64::MAX, now, 5), "Grace must not overflow");
+}
+
+/// Test that each expired intent is recorded once, matching on the normalized ID
+/// Why: Expired intents stay in the polled event window, so the warning must not repeat every poll
+#[tokio::test]
+async fn test_expired_intent_recorded_once() {
+    let config = build_test_config_with_mvm();
+    let monitor = EventMonitor::new(&config).await.unwrap();
+
+    assert!(monitor.record_expired_intent("0x01").await);
+    assert!(!monitor.record_expired_intent("0x1").await);
+    assert!(monitor.record_expired_intent("0x2").await);
+}
+
+// ============================================================================
+// POLL BACKOFF TESTS
+// ============================================================================
+
+/// Test that the poll delay doubles per failure up to the cap and resets on success
+/// Why: During an RPC outage the monitor must slow down instead of polling at full rate,
+/// then return to the configured interval as soon as the RPC recovers
+#[test]
+fn test_poll_backoff_grows_caps_and_resets() {
+    let mut backoff = PollBackoff::new(1000, 5000);
+
+    for expected_ms in [2000, 4000, 5000, 5000] {
+        let delay = backoff.on_failure();
+        assert_eq!(backoff.current_ms(), expected_ms);
+        // Jitter only shortens the delay, by at most 20%
+        assert!(delay <= Duration::from_millis(expected_ms));
+        assert!(delay >= Duration::from_millis(expected_ms * 4 / 5));
+    }
+    assert_eq!(backoff.consecutive_failures(), 4);
+
+    assert_eq!(backoff.on_success(), Duration::from_millis(1000));
+    assert_eq!(backoff.current_ms(), 1000);
+    assert_eq!(backoff.consecutive_failures(), 0);
+}
+
+/// Test that idle polls widen the delay past the threshold, up to the idle cap
+/// What is tested: a sequence of idle polls and then an active one on a PollBackoff
+/// with idle widening after 2 idle polls, capped at 6000 ms
+/// Why: Quiet periods should not burn RPC quota at the full polling rate, but a new
+/// event must bring the monitor straight back to the configured interval
+#[test]
+fn test_poll_backoff_widens_while_idle_and_resets_on_activity() {
+    let mut backoff = PollBackoff::new(1000, 60000).with_idle_widening(6000, 2);
+
+    let schedule: Vec<u128> = (0..5).map(|_| backoff.on_idle().as_millis()).collect();
+    assert_eq!(schedule, vec![1000, 1000, 2000, 4000, 6000]);
+    assert_eq!(backoff.consecutive_idle_polls(), 5);
+
+    assert_eq!(backoff.on_success(), Duration::from_millis(1000));
+    assert_eq!(backoff.consecutive_idle_polls(), 0);
+    assert_eq!(backoff.on_idle(), Duration::from_millis(1000));
+
+    // Without idle widening, idle polls keep the base delay
+    let mut fixed = PollBackoff::new(1000, 60000);
+    for _ in 0..10 {
+        assert_eq!(fixed.on_idle(), Duration::from_millis(1000));
+    }
+}
+
+/// Test that hub polling backs off while the RPC fails and resets once it recovers
+/// Why: The monitor loop sleeps for the delay poll_hub_once returns; it must grow
+/// with consecutive RPC errors and drop back to polling_interval_ms on success
+#[tokio::test]
+async fn test_poll_hub_once_backs_off_on_rpc_failure() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mock_server = MockServer::start().await;
+    // First three view calls fail, later ones return empty address lists
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(3)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([[]])))
+        .mount(&mock_server)
+        .await;
+
+    let config = build_test_config_with_mock_server(&mock_server.uri());
+    let base_ms = config.coordinator.polling_interval_ms;
+    let monitor = EventMonitor::new(&config)
+        .await
+        .expect("Failed to create monitor");
+
+    let mut previous_ms = base_ms;
+    for _ in 0..3 {
+        poll_hub_once(&monitor).await;
+        let current_ms = monitor.poll_backoff.lock().await.current_ms();
+        assert!(current_ms > previous_ms, "Delay should grow on each failure");
+        previous_ms = current_ms;
+    }
+    assert_eq!(monitor.poll_backoff.lock().await.consecutive_failures(), 3);
+
+    let delay = poll_hub_once(&monitor).await;
+    assert_eq!(delay, Duration::from_millis(base_ms), "Delay should reset on success");
+    assert_eq!(monitor.poll_backoff.lock().await.consecutive_failures(), 0);
+}
+
+/// Test that hub polls are counted in the metrics when metrics are enabled
+/// What is tested: one failing and one successful poll_hub_once with api.metrics_enabled
+/// Why: The poll error counter is what operators alert on during an RPC outage
+#[tokio::test]
+async fn test_poll_hub_once_records_metrics() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([[]])))
+        .mount(&mock_server)
+        .await;
+
+    let mut config = build_test_config_with_mock_server(&mock_server.uri());
+    config.api.metrics_enabled = true;
+    let monitor = EventMonitor::new(&config)
+        .await
+        .expect("Failed to create monitor");
+
+    poll_hub_once(&monitor).await;
+    poll_hub_once(&monitor).await;
+
+    let rendered = monitor.metrics().expect("Metrics should be enabled").render().unwrap();
+    assert!(rendered.contains("coordinator_hub_polls_total 2"), "{}", rendered);
+    assert!(rendered.contains("coordinator_hub_poll_errors_total 1"), "{}", rendered);
+    assert!(rendered.contains("coordinator_hub_poll_duration_seconds_count 2"), "{}", rendered);
+}
+
+// ============================================================================
+// EVENT CACHE PERSISTENCE TESTS
+// ============================================================================
+
+/// Test that cached events survive a monitor restart when event_cache_dir is set
+/// Why: A restarted coordinator must not lose observed intents and fulfillments,
+/// which it may never see again once they drop out of the polled event window
+#[tokio::test]
+async fn test_event_cache_restored_after_restart() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let dir = std::env::temp_dir().join(format!("coordinator-event-cache-{}", rand::random::<u64>()));
+    let mut config = build_test_config_with_mvm();
+    config.coordinator.event_cache_dir = Some(dir.to_string_lossy().into_owned());
+
+    let intent = create_default_intent_mvm();
+    let fulfillment = create_default_fulfillment();
+    {
+        let monitor = EventMonitor::new(&config).await.expect("Failed to create monitor");
+        assert!(monitor.cache_intent_event(intent.clone()).await);
+        assert!(monitor.cache_fulfillment_event(fulfillment.clone()).await);
+    }
+
+    let monitor = EventMonitor::new(&config).await.expect("Failed to recreate monitor");
+    let events = monitor.get_cached_events().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].intent_id, intent.intent_id);
+    let fulfillments = monitor.get_cached_fulfillment_events().await;
+    assert_eq!(fulfillments.len(), 1);
+    assert_eq!(fulfillments[0].intent_id, fulfillment.intent_id);
+
+    // Restored events still count as seen
+    assert!(!monitor.cache_intent_event(intent).await);
+
+    // Without event_cache_dir the caches start empty
+    config.coordinator.event_cache_dir = None;
+    let memory_monitor = EventMonitor::new(&config).await.expect("Failed to create monitor");
+    assert!(memory_monitor.get_cached_events().await.is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -4,8 +4,14 @@
 //! FCFS signature handling, expiry, and status transitions.
 
 use coordinator::storage::draftintents::{
-    DraftintentStore, DraftintentStatus,
+    DraftClaimError, DraftintentStore, DraftintentStatus,
 };
+use std::sync::Arc;
+use coordinator::storage::solver_liveness::SolverLivenessStore;
+use coordinator::storage::{
+    AuditAction, AuditLog, AuditSource, CachedResponse, IdempotencyCache, IdempotencyLookup,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
 use serde_json;
 
 #[path = "mod.rs"]
@@ -289,6 +295,89 @@ async fn test_fcfs_second_signature_fails() {
     assert_eq!(draft.signature.unwrap().solver_hub_addr, DUMMY_SOLVER_ADDR_HUB);
 }
 
+/// Test that two concurrent claims on the same draft produce exactly one winner
+/// What is tested: Two tasks claim a pending draft at the same expected version; one
+/// succeeds and the other is rejected with the winner's address
+/// Why: Racing solvers must never both believe they hold the draft
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_concurrent_claims_exactly_one_wins() {
+    let store = Arc::new(DraftintentStore::new());
+    store
+        .add_draft(
+            "draft-race".to_string(),
+            DUMMY_REQUESTER_ADDR_HUB.to_string(),
+            create_test_draft_data(),
+            future_expiry_time(),
+        )
+        .await;
+
+    let claims: Vec<_> = ["0xsolver_a", "0xsolver_b"]
+        .into_iter()
+        .map(|solver| {
+            let store = store.clone();
+            tokio::spawn(async move {
+                let result = store
+                    .claim(
+                        "draft-race",
+                        solver.to_string(),
+                        format!("sig-{}", solver),
+                        "pub".to_string(),
+                        Some(0),
+                    )
+                    .await;
+                (solver, result)
+            })
+        })
+        .collect();
+
+    let mut winners = Vec::new();
+    let mut losers = Vec::new();
+    for claim in claims {
+        let (solver, result) = claim.await.unwrap();
+        match result {
+            Ok(version) => winners.push((solver, version)),
+            Err(e) => losers.push(e),
+        }
+    }
+
+    assert_eq!(winners.len(), 1, "Exactly one claim must win");
+    assert_eq!(losers.len(), 1);
+    let (winner, version) = winners[0];
+    assert_eq!(version, 1);
+    let loser_saw = match &losers[0] {
+        DraftClaimError::VersionMismatch { solver_hub_addr, .. } => solver_hub_addr.clone(),
+        DraftClaimError::AlreadySigned { solver_hub_addr, .. } => Some(solver_hub_addr.clone()),
+        other => panic!("Unexpected claim error: {:?}", other),
+    };
+    assert_eq!(loser_saw.as_deref(), Some(winner));
+
+    let draft = store.get_draft("draft-race").await.unwrap();
+    assert_eq!(draft.signature.unwrap().solver_hub_addr, winner);
+}
+
+/// Test that a claim based on a stale version is rejected
+/// What is tested: After a draft is signed and reassigned (version 2), a claim expecting
+/// version 0 fails with VersionMismatch even though the draft is pending again
+/// Why: A solver must not sign a draft whose state changed since it read it
+#[tokio::test]
+async fn test_claim_with_stale_version_rejected() {
+    let store = DraftintentStore::new();
+    let liveness = SolverLivenessStore::new();
+    add_signed_draft(&store, "draft-1").await;
+    liveness.record_heartbeat_at(DUMMY_SOLVER_ADDR_HUB, now() - 200).await;
+    store.reassign_silent_drafts(&liveness, 120).await;
+
+    let result = store
+        .claim("draft-1", "0xsolver_b".to_string(), "sig".to_string(), "pub".to_string(), Some(0))
+        .await;
+
+    assert_eq!(
+        result,
+        Err(DraftClaimError::VersionMismatch { expected: 0, current: 2, solver_hub_addr: None })
+    );
+    assert_eq!(store.get_draft("draft-1").await.unwrap().status, DraftintentStatus::Pending);
+}
+
 /// Test that signature to non-existent draft fails
 /// What is tested: Error handling for missing draft
 /// Why: Should handle invalid draft_id gracefully
@@ -384,6 +473,51 @@ async fn test_status_transition_pending_to_signed() {
     assert_eq!(draft.status, DraftintentStatus::Signed);
 }
 
+/// Test that a store created with an update channel publishes each status change
+/// What is tested: Submission, signing and expiry each send a DraftStatus update
+/// Why: The coordinator's /ws endpoint forwards these to frontends instead of them polling
+#[tokio::test]
+async fn test_status_changes_are_published() {
+    use coordinator::monitor::CoordinatorUpdate;
+
+    let (sender, mut updates) = tokio::sync::broadcast::channel(16);
+    let store = DraftintentStore::with_updates(sender);
+    let draft_data = serde_json::json!({
+        "intent_id": "0x1",
+        "offered_chain_id": "1",
+        "desired_chain_id": 2,
+    });
+
+    store
+        .add_draft("draft-1".to_string(), DUMMY_REQUESTER_ADDR_HUB.to_string(), draft_data.clone(), future_expiry_time())
+        .await;
+    store
+        .add_signature("draft-1", DUMMY_SOLVER_ADDR_HUB.to_string(), "sig1".to_string(), "pub1".to_string())
+        .await
+        .unwrap();
+    store
+        .add_draft("draft-2".to_string(), DUMMY_REQUESTER_ADDR_HUB.to_string(), draft_data, past_expiry_time())
+        .await;
+    store.cleanup_expired().await;
+
+    let mut next = || match updates.try_recv().unwrap() {
+        CoordinatorUpdate::DraftStatus(update) => update,
+        other => panic!("Unexpected update: {:?}", other),
+    };
+    let pending = next();
+    assert_eq!((pending.draft_id.as_str(), pending.status), ("draft-1", DraftintentStatus::Pending));
+    assert_eq!(pending.intent_id.as_deref(), Some("0x1"));
+    assert_eq!(pending.chain_ids, vec![1, 2]);
+    let signed = next();
+    assert_eq!(signed.status, DraftintentStatus::Signed);
+    assert_eq!(signed.solver_hub_addr.as_deref(), Some(DUMMY_SOLVER_ADDR_HUB));
+    assert_eq!(signed.version, 1);
+    assert_eq!(next().status, DraftintentStatus::Pending);
+    let expired = next();
+    assert_eq!((expired.draft_id.as_str(), expired.status), ("draft-2", DraftintentStatus::Expired));
+    assert!(updates.try_recv().is_err());
+}
+
 // ============================================================================
 // EXPIRY TESTS
 // ============================================================================
@@ -428,6 +562,161 @@ async fn test_cleanup_expired() {
     assert_eq!(pending_draft.status, DraftintentStatus::Pending);
 }
 
+/// Test that drafts move Pending -> Expired -> evicted as the clock advances
+/// What is tested: cleanup_expired_at/evict_stale_at evaluated at increasing timestamps
+/// expire a pending draft at its expiry_time and remove it only after the retention window,
+/// while the stats track each transition
+/// Why: Abandoned drafts must not accumulate forever, but requesters need time to read
+/// the final status
+#[tokio::test]
+async fn test_draft_expiry_and_eviction_with_clock() {
+    let store = DraftintentStore::new();
+    let expiry = 1_000;
+    let retention = 60;
+    store
+        .add_draft(
+            "draft-1".to_string(),
+            DUMMY_REQUESTER_ADDR_HUB.to_string(),
+            create_test_draft_data(),
+            expiry,
+        )
+        .await;
+
+    // Before expiry: nothing changes
+    assert_eq!(store.cleanup_expired_at(expiry - 1).await, 0);
+    assert!(store.evict_stale_at(expiry - 1, retention).await.is_empty());
+    assert_eq!(store.get_draft("draft-1").await.unwrap().status, DraftintentStatus::Pending);
+
+    // At expiry: marked expired but still readable
+    assert_eq!(store.cleanup_expired_at(expiry).await, 1);
+    assert!(store.evict_stale_at(expiry + retention - 1, retention).await.is_empty());
+    assert_eq!(store.get_draft("draft-1").await.unwrap().status, DraftintentStatus::Expired);
+
+    // After the retention window: removed
+    let evicted = store.evict_stale_at(expiry + retention, retention).await;
+    assert_eq!(evicted, vec!["draft-1".to_string()]);
+    assert!(store.get_draft("draft-1").await.is_none());
+
+    let stats = store.stats().await;
+    assert_eq!(stats.pending + stats.signed + stats.expired, 0);
+    assert_eq!(stats.expired_total, 1);
+    assert_eq!(stats.evicted_total, 1);
+    assert_eq!(stats.last_sweep_at, Some(expiry + retention));
+}
+
+/// Test that signed drafts are evicted once past expiry plus retention
+/// What is tested: A signed draft keeps its status through the retention window and
+/// is then removed by evict_stale_at
+/// Why: Signed drafts never transition to Expired, so eviction must not depend on status
+#[tokio::test]
+async fn test_signed_draft_evicted_after_retention() {
+    let store = DraftintentStore::new();
+    add_signed_draft(&store, "draft-signed").await;
+    let expiry = future_expiry_time();
+
+    assert!(store.evict_stale_at(expiry, 60).await.is_empty());
+    assert_eq!(store.stats().await.signed, 1);
+
+    assert_eq!(store.evict_stale_at(expiry + 60, 60).await.len(), 1);
+    assert_eq!(store.stats().await.signed, 0);
+}
+
+// ============================================================================
+// SOLVER LIVENESS TESTS
+// ============================================================================
+
+/// Get current Unix timestamp
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
+
+/// Add a draft and sign it with the dummy solver
+async fn add_signed_draft(store: &DraftintentStore, draft_id: &str) {
+    store
+        .add_draft(
+            draft_id.to_string(),
+            DUMMY_REQUESTER_ADDR_HUB.to_string(),
+            create_test_draft_data(),
+            future_expiry_time(),
+        )
+        .await;
+    store
+        .add_signature(
+            draft_id,
+            DUMMY_SOLVER_ADDR_HUB.to_string(),
+            "sig1".to_string(),
+            "pub1".to_string(),
+        )
+        .await
+        .unwrap();
+}
+
+/// Test that a draft signed by a now-silent solver is reassigned after the timeout
+/// What is tested: reassign_silent_drafts returns the draft to Pending and clears the signature
+/// Why: Drafts assigned to a dead solver must not stall settlement
+#[tokio::test]
+async fn test_reassign_draft_of_silent_solver() {
+    let store = DraftintentStore::new();
+    let liveness = SolverLivenessStore::new();
+    add_signed_draft(&store, "draft-1").await;
+
+    // Solver was last seen 200 seconds ago, past the 120 second timeout
+    liveness.record_heartbeat_at(DUMMY_SOLVER_ADDR_HUB, now() - 200).await;
+
+    let reassigned = store.reassign_silent_drafts(&liveness, 120).await;
+    assert_eq!(reassigned, vec!["draft-1".to_string()]);
+    assert_eq!(store.stats().await.reassigned_total, 1);
+
+    let draft = store.get_draft("draft-1").await.unwrap();
+    assert_eq!(draft.status, DraftintentStatus::Pending);
+    assert!(draft.signature.is_none(), "Signature should be cleared");
+
+    // Draft is open to other solvers again
+    let result = store
+        .add_signature(
+            "draft-1",
+            "0xsolver2".to_string(),
+            "sig2".to_string(),
+            "pub2".to_string(),
+        )
+        .await;
+    assert!(result.is_ok(), "Reassigned draft should accept a new signature");
+}
+
+/// Test that a draft signed by a live solver is not reassigned
+/// What is tested: reassign_silent_drafts leaves drafts alone within the timeout
+/// Why: Drafts of healthy solvers must not be taken away
+#[tokio::test]
+async fn test_no_reassign_within_timeout() {
+    let store = DraftintentStore::new();
+    let liveness = SolverLivenessStore::new();
+    add_signed_draft(&store, "draft-1").await;
+
+    liveness.record_heartbeat_at(DUMMY_SOLVER_ADDR_HUB, now() - 30).await;
+
+    let reassigned = store.reassign_silent_drafts(&liveness, 120).await;
+    assert!(reassigned.is_empty());
+
+    let draft = store.get_draft("draft-1").await.unwrap();
+    assert_eq!(draft.status, DraftintentStatus::Signed);
+    assert_eq!(draft.signature.unwrap().solver_hub_addr, DUMMY_SOLVER_ADDR_HUB);
+}
+
+/// Test that solver liveness respects the window
+/// What is tested: is_live for recent, stale, and unknown solvers
+/// Why: Only solvers seen within the window may be assigned drafts
+#[tokio::test]
+async fn test_solver_is_live_window() {
+    let liveness = SolverLivenessStore::new();
+    assert!(!liveness.is_live(DUMMY_SOLVER_ADDR_HUB, 60).await, "Unknown solver should not be live");
+
+    liveness.record_heartbeat(DUMMY_SOLVER_ADDR_HUB).await;
+    assert!(liveness.is_live(DUMMY_SOLVER_ADDR_HUB, 60).await);
+
+    liveness.record_heartbeat_at(DUMMY_SOLVER_ADDR_HUB, now() - 61).await;
+    assert!(!liveness.is_live(DUMMY_SOLVER_ADDR_HUB, 60).await);
+}
+
 // ============================================================================
 // DATA VALIDATION TESTS
 // ============================================================================
@@ -486,3 +775,101 @@ async fn test_signature_timestamp() {
     assert!(signature.signature_timestamp > 0, "Timestamp should be set");
 }
 
+// ============================================================================
+// IDEMPOTENCY CACHE TESTS
+// ============================================================================
+
+/// Create a stored 200 response with `body`, produced for request body `request`
+fn cached_response(body: &str, request: &[u8]) -> CachedResponse {
+    CachedResponse {
+        status: 200,
+        content_type: Some("application/json".to_string()),
+        body: body.as_bytes().to_vec(),
+        request_hash: IdempotencyCache::request_hash(request),
+    }
+}
+
+/// What is tested: The idempotency cache evicts the least recently used key when full
+/// Why: The cache must stay bounded, and a key that is still being retried should
+/// outlive keys that are not
+#[tokio::test]
+async fn test_idempotency_cache_evicts_least_recently_used() {
+    let cache = IdempotencyCache::new(2, std::time::Duration::from_secs(60));
+    let hash = IdempotencyCache::request_hash(b"req");
+    cache.insert("a".to_string(), cached_response("A", b"req")).await;
+    cache.insert("b".to_string(), cached_response("B", b"req")).await;
+
+    // Touch "a" so "b" becomes the least recently used
+    assert!(matches!(cache.lookup("a", hash).await, IdempotencyLookup::Hit(_)));
+    cache.insert("c".to_string(), cached_response("C", b"req")).await;
+
+    assert_eq!(cache.len().await, 2);
+    assert_eq!(cache.lookup("b", hash).await, IdempotencyLookup::Miss);
+    assert_eq!(
+        cache.lookup("a", hash).await,
+        IdempotencyLookup::Hit(cached_response("A", b"req"))
+    );
+    assert!(matches!(cache.lookup("c", hash).await, IdempotencyLookup::Hit(_)));
+}
+
+/// What is tested: Entries expire after the window and a different request body is a mismatch
+/// Why: Keys are only replayed within the retry window, and never for a different request
+#[tokio::test]
+async fn test_idempotency_cache_window_and_mismatch() {
+    let cache = IdempotencyCache::new(8, std::time::Duration::from_millis(50));
+    cache.insert("k".to_string(), cached_response("K", b"req")).await;
+
+    assert_eq!(
+        cache.lookup("k", IdempotencyCache::request_hash(b"other")).await,
+        IdempotencyLookup::Mismatch
+    );
+
+    tokio::time::sleep(std::time::Duration::from_millis(80)).await;
+    assert_eq!(
+        cache.lookup("k", IdempotencyCache::request_hash(b"req")).await,
+        IdempotencyLookup::Miss
+    );
+    assert!(cache.is_empty().await);
+}
+
+// ============================================================================
+// AUDIT LOG TESTS
+// ============================================================================
+
+/// What is tested: The audit log keeps `capacity` entries in memory, spills older ones to
+/// its file, and returns the full trail in order, also after a restart
+/// Why: Memory must stay bounded without losing the trail a compliance query needs
+#[tokio::test]
+async fn test_audit_log_ring_buffer_spills_to_disk() {
+    let dir = std::env::temp_dir().join(format!("coordinator-audit-{}", rand::random::<u64>()));
+    let spill_file = dir.join("audit.jsonl");
+    let observed = AuditAction::Observed { source: AuditSource::HubChain };
+    let validated = AuditAction::Validated { passed: true, reason: None };
+
+    let audit_log = AuditLog::with_spill_file(2, &spill_file).unwrap();
+    audit_log.record("0x01", None, observed.clone()).await;
+    audit_log.record("0x02", None, observed.clone()).await;
+    audit_log.record("0x01", Some("draft-1"), validated.clone()).await;
+    assert_eq!(audit_log.len().await, 2);
+
+    // "0x01"'s first entry now lives only in the spill file
+    let trail = audit_log.trail("0x0000000000000000000000000000000000000000000000000000000000000001").await.unwrap();
+    assert_eq!(trail.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(trail[0].action, observed);
+    assert_eq!(trail[1].action, validated);
+    assert_eq!(trail[1].draft_id.as_deref(), Some("draft-1"));
+
+    // A restarted log still serves the spilled entry and continues the sequence
+    let restarted = AuditLog::with_spill_file(2, &spill_file).unwrap();
+    let entry = restarted.record("0x01", None, validated.clone()).await;
+    assert_eq!(entry.seq, 1);
+    assert_eq!(restarted.trail("0x01").await.unwrap().len(), 2);
+
+    // Without a spill file, evicted entries are dropped
+    let in_memory = AuditLog::new(1);
+    in_memory.record("0x01", None, observed.clone()).await;
+    in_memory.record("0x01", None, validated.clone()).await;
+    assert_eq!(in_memory.trail("0x01").await.unwrap().len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,87 @@
+//! Alert Escalation Module
+//!
+//! Surfaces persistent relay failures to operators. When an operation exhausts its
+//! retry budget, the relay logs a critical alert and, if configured, POSTs a JSON
+//! notification to the alert webhook.
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{error, warn};
+
+// ============================================================================
+// ALERT PAYLOAD
+// ============================================================================
+
+/// What the relay did with an operation after it exhausted its retry budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EscalationAction {
+    /// The operation was parked in the dead-letter state and will not be retried
+    DeadLettered,
+    /// The operation was slowed to a capped backoff until it succeeds again
+    Paused,
+}
+
+/// Notification body sent to the alert webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryBudgetAlert {
+    /// Service that raised the alert
+    pub service: String,
+    /// Human-readable identifier of the failing operation (e.g. "deliver src_chain=1 nonce=7")
+    pub operation: String,
+    /// Total failed attempts when the budget was exhausted
+    pub attempts: u32,
+    /// Action taken on the operation
+    pub action: EscalationAction,
+    /// Last error observed for the operation
+    pub last_error: String,
+}
+
+// ============================================================================
+// NOTIFIER
+// ============================================================================
+
+/// Sends critical alerts to the log and the optional notification webhook.
+#[derive(Debug, Clone)]
+pub struct AlertNotifier {
+    webhook_url: Option<String>,
+    client: reqwest::Client,
+}
+
+impl AlertNotifier {
+    /// Create a notifier. With no webhook URL, alerts are only logged.
+    pub fn new(webhook_url: Option<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .no_proxy()
+            .build()
+            .unwrap_or_default();
+        Self { webhook_url, client }
+    }
+
+    /// Escalate an alert: log it as critical and fire the webhook.
+    ///
+    /// Webhook failures are logged and never propagated; alerting must not
+    /// interrupt the relay loop.
+    pub async fn escalate(&self, alert: &RetryBudgetAlert) {
+        error!(
+            "CRITICAL: retry budget exhausted for {} after {} attempts ({:?}). Last error: {}",
+            alert.operation, alert.attempts, alert.action, alert.last_error
+        );
+
+        let Some(ref url) = self.webhook_url else {
+            return;
+        };
+
+        match self.client.post(url).json(alert).send().await {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => warn!(
+                "Alert webhook {} returned status {} for {}",
+                url,
+                response.status(),
+                alert.operation
+            ),
+            Err(e) => warn!("Failed to send alert webhook to {}: {}", url, e),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Trace Intent
+//!
+//! Diagnostic tool that walks the cross-chain GMP path of one intent across all
+//! chains in the integrated-gmp configuration and prints a pass/fail timeline,
+//! ending with the stage where the flow is stuck.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! INTEGRATED_GMP_CONFIG_PATH=config/integrated-gmp_testnet.toml \
+//!   cargo run --bin trace_intent -- --intent-id 0x<64 hex chars> [--max-scan 500]
+//! ```
+
+use anyhow::{Context, Result};
+use integrated_gmp::config::Config;
+use integrated_gmp::trace::{IntentTracer, DEFAULT_OUTBOX_SCAN_LIMIT};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("Usage: trace_intent --intent-id <hex> [--config <path>] [--max-scan <n>]");
+        println!();
+        println!("Options:");
+        println!("  --intent-id <hex>   Intent ID to trace (32-byte hex)");
+        println!("  --config <path>     Use custom config file path");
+        println!(
+            "  --max-scan <n>      Most recent hub outbox messages to scan (default: {})",
+            DEFAULT_OUTBOX_SCAN_LIMIT
+        );
+        return Ok(());
+    }
+
+    let mut intent_id = None;
+    let mut max_scan = DEFAULT_OUTBOX_SCAN_LIMIT;
+
+    let mut i = 1; // Skip program name
+    while i < args.len() {
+        match args[i].as_str() {
+            "--intent-id" if i + 1 < args.len() => {
+                intent_id = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--config" if i + 1 < args.len() => {
+                std::env::set_var("INTEGRATED_GMP_CONFIG_PATH", &args[i + 1]);
+                i += 1;
+            }
+            "--max-scan" if i + 1 < args.len() => {
+                max_scan = args[i + 1].parse().context("Invalid --max-scan value")?;
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    let intent_id = intent_id.context("--intent-id is required")?;
+    if intent_id.trim_start_matches("0x").len() > 64 {
+        anyhow::bail!("--intent-id must be at most 32 bytes of hex");
+    }
+
+    let config = Config::load()?;
+    let trace = IntentTracer::new(config)
+        .with_outbox_scan_limit(max_scan)
+        .trace(&intent_id)
+        .await?;
+
+    println!("{}", trace);
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Verify Escrow Link
+//!
+//! Diagnostic tool that cross-checks an inflow escrow on a connected chain against
+//! the IntentRequirements the hub sent for the same intent, and flags any field on
+//! which they disagree. Exits with a non-zero status on mismatch.
+//!
+//! With `--watch <secs>`, the check is repeated on an interval as a lightweight
+//! monitor; mismatches and lookup errors are printed and the loop keeps running.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! INTEGRATED_GMP_CONFIG_PATH=config/integrated-gmp_testnet.toml \
+//!   cargo run --bin verify_escrow_link -- --intent-id 0x<64 hex chars> [--max-scan 500] [--watch 60]
+//! ```
+
+use anyhow::{Context, Result};
+use integrated_gmp::config::Config;
+use integrated_gmp::escrow_link::EscrowLinkChecker;
+use integrated_gmp::trace::DEFAULT_OUTBOX_SCAN_LIMIT;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("Usage: verify_escrow_link --intent-id <hex> [--config <path>] [--max-scan <n>] [--watch <secs>]");
+        println!();
+        println!("Options:");
+        println!("  --intent-id <hex>   Intent ID to check (32-byte hex)");
+        println!("  --config <path>     Use custom config file path");
+        println!(
+            "  --max-scan <n>      Most recent hub outbox messages to scan (default: {})",
+            DEFAULT_OUTBOX_SCAN_LIMIT
+        );
+        println!("  --watch <secs>      Repeat the check every <secs> seconds");
+        return Ok(());
+    }
+
+    let mut intent_id = None;
+    let mut max_scan = DEFAULT_OUTBOX_SCAN_LIMIT;
+    let mut watch_secs: Option<u64> = None;
+
+    let mut i = 1; // Skip program name
+    while i < args.len() {
+        match args[i].as_str() {
+            "--intent-id" if i + 1 < args.len() => {
+                intent_id = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--config" if i + 1 < args.len() => {
+                std::env::set_var("INTEGRATED_GMP_CONFIG_PATH", &args[i + 1]);
+                i += 1;
+            }
+            "--max-scan" if i + 1 < args.len() => {
+                max_scan = args[i + 1].parse().context("Invalid --max-scan value")?;
+                i += 1;
+            }
+            "--watch" if i + 1 < args.len() => {
+                watch_secs = Some(args[i + 1].parse().context("Invalid --watch value")?);
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    let intent_id = intent_id.context("--intent-id is required")?;
+    if intent_id.trim_start_matches("0x").len() > 64 {
+        anyhow::bail!("--intent-id must be at most 32 bytes of hex");
+    }
+    if watch_secs == Some(0) {
+        anyhow::bail!("--watch must be greater than zero");
+    }
+
+    let config = Config::load()?;
+    let checker = EscrowLinkChecker::new(config).with_outbox_scan_limit(max_scan);
+
+    let Some(watch_secs) = watch_secs else {
+        let report = checker.check(&intent_id).await?;
+        println!("{}", report);
+        if !report.is_consistent() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(watch_secs));
+    loop {
+        interval.tick().await;
+        match checker.check(&intent_id).await {
+            Ok(report) => println!("{}", report),
+            Err(e) => println!("Escrow link check failed: {:#}", e),
+        }
+    }
+}
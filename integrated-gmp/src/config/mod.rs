@@ -3,7 +3,13 @@
 //! This module handles loading and managing configuration for the Integrated GMP service.
 //! Configuration includes chain endpoints, cryptographic keys, API settings, and validation parameters.
 
+use crate::mvm_transaction::parse_account_address;
+use chain_clients_common::expand_env_vars_in_toml;
 use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 // ============================================================================
 // CONFIGURATION STRUCTURES
@@ -17,8 +23,13 @@ use serde::{Deserialize, Serialize};
 /// - Connected EVM chain configurations (supports multiple simultaneous EVM chains)
 /// - Integrated GMP cryptographic keys and settings
 /// - API server configuration
+/// - Prometheus metrics endpoint configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Directory of per-chain TOML files merged into the connected chains at load
+    /// time. Relative paths resolve against the config file's directory.
+    #[serde(default)]
+    pub chains_dir: Option<String>,
     /// Hub chain configuration (where intents are created)
     pub hub_chain: ChainConfig,
     /// Connected Move VM chain configurations (supports multiple simultaneous MVM chains)
@@ -34,13 +45,16 @@ pub struct Config {
     pub integrated_gmp: IntegratedGmpConfig,
     /// API server configuration (host, port, CORS settings)
     pub api: ApiConfig,
+    /// Prometheus metrics endpoint (disabled unless `[metrics] enabled = true`)
+    #[serde(default)]
+    pub metrics: MetricsConfig,
 }
 
 /// Configuration for a blockchain connection.
 ///
 /// Contains all necessary information to connect to and interact with a blockchain,
 /// including RPC endpoints, chain identifiers, and module addresses.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChainConfig {
     /// Human-readable name for the chain
     pub name: String,
@@ -52,12 +66,22 @@ pub struct ChainConfig {
     pub intent_module_addr: String,
     /// Address of the escrow module (optional for hub chain)
     pub escrow_module_addr: Option<String>,
+    /// Whether this chain is active (default: true).
+    /// Disabled chains stay in the config and are still validated, but the relay
+    /// skips polling, delivery, and startup authorization checks for them.
+    #[serde(default = "default_chain_enabled")]
+    pub enabled: bool,
+    /// Polling interval for this chain in milliseconds
+    /// (default: integrated_gmp.polling_interval_ms). Lets a rate-limited RPC be
+    /// polled slower than the other chains.
+    #[serde(default)]
+    pub polling_interval_ms: Option<u64>,
 }
 
 /// Configuration for an EVM-compatible chain (Ethereum, Hardhat, etc.)
 ///
 /// Used when escrows are hosted on EVM chains instead of Move-based chains.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EvmChainConfig {
     /// Human-readable name for the chain
     pub name: String,
@@ -77,10 +101,33 @@ pub struct EvmChainConfig {
     /// Address of the IntentOutflowValidator contract
     #[serde(default)]
     pub outflow_validator_addr: Option<String>,
+    /// Transaction type for deliveries to this chain (default: legacy). Use
+    /// `eip1559` on chains with a base fee to avoid overpaying or stalling in fee spikes.
+    #[serde(default)]
+    pub tx_type: EvmTxType,
+    /// Safety margin applied to the `eth_estimateGas` result to get a delivery's gas
+    /// limit (default: 1.2). Must be at least 1.0.
+    #[serde(default = "default_gas_limit_multiplier")]
+    pub gas_limit_multiplier: f64,
+    /// Whether this chain is active (default: true).
+    /// Disabled chains stay in the config and are still validated, but the relay
+    /// skips polling, delivery, and startup authorization checks for them.
+    #[serde(default = "default_chain_enabled")]
+    pub enabled: bool,
+    /// Polling interval for this chain in milliseconds
+    /// (default: integrated_gmp.polling_interval_ms). Lets a rate-limited RPC be
+    /// polled slower than the other chains.
+    #[serde(default)]
+    pub polling_interval_ms: Option<u64>,
+    /// Blocks a MessageSent log must be buried under before it is relayed (default: 0).
+    /// Above 0, the relay also checks each log's block hash before delivery and rescans
+    /// ranges replaced by a reorg.
+    #[serde(default)]
+    pub confirmations: u64,
 }
 
 /// Configuration for a Solana chain (SVM).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SvmChainConfig {
     /// Human-readable name for the chain
     pub name: String,
@@ -95,6 +142,93 @@ pub struct SvmChainConfig {
     /// Program ID of the integrated GMP endpoint (for polling outbound messages)
     #[serde(default)]
     pub gmp_endpoint_program_id: Option<String>,
+    /// Whether this chain is active (default: true).
+    /// Disabled chains stay in the config and are still validated, but the relay
+    /// skips polling, delivery, and startup authorization checks for them.
+    #[serde(default = "default_chain_enabled")]
+    pub enabled: bool,
+    /// Polling interval for this chain in milliseconds
+    /// (default: integrated_gmp.polling_interval_ms). Lets a rate-limited RPC be
+    /// polled slower than the other chains.
+    #[serde(default)]
+    pub polling_interval_ms: Option<u64>,
+    /// Scan the GMP program's message accounts with getProgramAccounts at startup
+    /// and deliver messages the outbound nonce counter no longer covers
+    /// (default: true). getProgramAccounts is expensive and often disabled on
+    /// public RPCs; set to false for such endpoints.
+    #[serde(default = "default_startup_message_scan")]
+    pub startup_message_scan: bool,
+    /// Commitment level for outbound nonce, message account, and delivered-marker
+    /// reads (default: confirmed). See [`SvmCommitment`] for the tradeoff.
+    #[serde(default)]
+    pub svm_commitment: SvmCommitment,
+}
+
+/// Commitment level the relay reads SVM accounts at.
+///
+/// Lower levels see new messages sooner but may act on a slot that is later
+/// rolled back; `finalized` never does, at the cost of roughly 13 seconds of
+/// extra latency per message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SvmCommitment {
+    /// Latest slot processed by the node; may be on a minority fork
+    Processed,
+    /// Voted on by a supermajority of the cluster; rollback is very unlikely
+    #[default]
+    Confirmed,
+    /// Rooted by the cluster; cannot be rolled back
+    Finalized,
+}
+
+impl SvmCommitment {
+    /// The JSON-RPC `commitment` value for this level.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SvmCommitment::Processed => "processed",
+            SvmCommitment::Confirmed => "confirmed",
+            SvmCommitment::Finalized => "finalized",
+        }
+    }
+}
+
+/// A chain defined in a `chains_dir` file, tagged with its VM type
+/// (`type = "mvm"`, `"evm"` or `"svm"`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ChainDefinition {
+    Mvm(ChainConfig),
+    Evm(EvmChainConfig),
+    Svm(SvmChainConfig),
+}
+
+impl ChainDefinition {
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            ChainDefinition::Mvm(chain) => chain.chain_id,
+            ChainDefinition::Evm(chain) => chain.chain_id,
+            ChainDefinition::Svm(chain) => chain.chain_id,
+        }
+    }
+}
+
+/// A file in `chains_dir`: exactly one `[[chain]]` block.
+#[derive(Debug, Deserialize)]
+struct ChainFile {
+    #[serde(default)]
+    chain: Vec<ChainDefinition>,
+}
+
+fn default_chain_enabled() -> bool {
+    true
+}
+
+fn default_startup_message_scan() -> bool {
+    true
+}
+
+fn default_gas_limit_multiplier() -> f64 {
+    1.2
 }
 
 /// Integrated GMP configuration including cryptographic keys and timing parameters.
@@ -114,10 +248,181 @@ pub struct IntegratedGmpConfig {
     /// Default: "INTEGRATED_GMP_PUBLIC_KEY"
     #[serde(default = "default_public_key_env")]
     pub public_key_env: String,
+    /// Environment variable name containing a separate secp256k1 private key for EVM
+    /// signing (hex encoded). When the variable is not set, the ECDSA key is derived
+    /// from the Ed25519 private key bytes.
+    /// Default: "INTEGRATED_GMP_ECDSA_PRIVATE_KEY"
+    #[serde(default = "default_ecdsa_private_key_env")]
+    pub ecdsa_private_key_env: String,
+    /// Environment variable name containing the Ethereum address expected for the
+    /// separate ECDSA key. Required when the ECDSA private key variable is set.
+    /// Default: "INTEGRATED_GMP_ECDSA_ADDRESS"
+    #[serde(default = "default_ecdsa_address_env")]
+    pub ecdsa_address_env: String,
+    /// Environment variable names holding backup relay operator keys (base64 Ed25519),
+    /// tried in order after the primary key. At startup the relay uses the first key
+    /// authorized on all enabled chains, and switches to the next authorized key if
+    /// the active one loses authorization. Default: none.
+    #[serde(default)]
+    pub backup_private_key_envs: Vec<String>,
     /// Polling interval for event monitoring in milliseconds
     pub polling_interval_ms: u64,
+    /// Upper bound, in milliseconds, for a chain's polling interval while it stays idle.
+    /// After `idle_polls_before_widening` consecutive polls without new messages the
+    /// interval doubles with each further idle poll, up to this value, and drops back
+    /// to the chain's polling interval as soon as a message is seen. Unset keeps the
+    /// interval fixed.
+    #[serde(default)]
+    pub max_idle_polling_interval_ms: Option<u64>,
+    /// Consecutive idle polls before a chain's polling interval starts widening.
+    /// Default: 3.
+    #[serde(default = "default_idle_polls_before_widening")]
+    pub idle_polls_before_widening: u32,
     /// Timeout for validation operations in milliseconds
     pub validation_timeout_ms: u64,
+    /// Total failed attempts allowed per operation (across backoff cycles) before
+    /// escalating. Failed deliveries are dead-lettered; failing chain polls are paused.
+    #[serde(default = "default_retry_budget")]
+    pub retry_budget: u32,
+    /// Webhook URL that receives a JSON POST when an operation exhausts its retry budget
+    #[serde(default)]
+    pub alert_webhook_url: Option<String>,
+    /// Destination chains each source chain may message. Sources without an entry
+    /// may route to any configured destination (permissive default).
+    #[serde(default)]
+    pub route_allowlist: Vec<RouteAllowlistEntry>,
+    /// Maximum outbox RPC reads per second while catching up on message history at
+    /// startup (backfill). Steady-state polling is not throttled. Unset disables the limit.
+    #[serde(default)]
+    pub backfill_max_rps: Option<u32>,
+    /// File where per-chain read positions (outbox nonces, EVM blocks) are saved and
+    /// resumed from after a restart. Unset keeps them in memory only.
+    #[serde(default)]
+    pub cursor_file: Option<String>,
+    /// File where messages skipped after a permanent delivery error are saved, so they
+    /// can be listed and re-attempted after a restart. Unset keeps them in memory only.
+    #[serde(default)]
+    pub dead_letter_file: Option<String>,
+    /// Blocks behind the head an EVM chain is scanned from when it has no saved
+    /// cursor (cold start). Default: 10.
+    #[serde(default = "default_evm_cold_start_blocks")]
+    pub evm_cold_start_blocks: u64,
+    /// Maximum `deliver_message` transactions in flight at once, across all
+    /// destination chains. Excess deliveries wait for a slot. Unset disables the limit.
+    #[serde(default)]
+    pub max_concurrent_deliveries: Option<u32>,
+    /// Verify at startup that the configured SVM GMP, escrow, and outflow programs
+    /// exist on each connected RPC, failing fast if one is missing (wrong network or
+    /// fresh ledger). Default: true.
+    #[serde(default = "default_check_program_deployment")]
+    pub check_program_deployment: bool,
+    /// Remove messages from MVM `gmp_sender` outboxes once they are delivered (or
+    /// permanently undeliverable). The relay must be the gmp_sender admin or an
+    /// authorized cleaner. Default: false.
+    #[serde(default)]
+    pub mvm_outbox_cleanup: bool,
+    /// How far an MVM delivery must progress before the relay moves past it.
+    /// Default: submitted (trust the submission result).
+    #[serde(default)]
+    pub mvm_commitment: MvmCommitment,
+    /// How long to wait for an MVM delivery to be committed when `mvm_commitment`
+    /// is `committed`. Unconfirmed deliveries are retried on the next poll.
+    #[serde(default = "default_mvm_confirmation_timeout_ms")]
+    pub mvm_confirmation_timeout_ms: u64,
+    /// How MVM `deliver_message_entry` and outbox cleanup transactions are submitted.
+    /// Default: native (REST API). `cli` requires the `aptos-cli` build feature.
+    #[serde(default)]
+    pub mvm_submission: MvmSubmission,
+    /// With `mvm_submission = "native"`, retry a delivery through the aptos CLI when
+    /// the native path fails before the node accepts the transaction. Requires the
+    /// `aptos-cli` build feature. Default: false.
+    #[serde(default)]
+    pub mvm_cli_fallback: bool,
+    /// Remote signer (HSM) holding the relay key. When set, EVM signing and relay
+    /// address derivation go through the signer instead of the in-memory key.
+    #[serde(default)]
+    pub remote_signer: Option<RemoteSignerConfig>,
+    /// How often to check the config file for changes, in milliseconds. Changed
+    /// polling interval, retry budget, and route allowlist are applied without a
+    /// restart; other changes are logged as requiring one. Unset disables reload.
+    #[serde(default)]
+    pub config_reload_interval_ms: Option<u64>,
+}
+
+/// Connection settings for a remote signer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSignerConfig {
+    /// Socket address of the signer (`host:port`)
+    pub addr: String,
+    /// Per-request connect/read/write timeout in milliseconds
+    #[serde(default = "default_remote_signer_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+/// Confirmation level required for MVM deliveries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MvmCommitment {
+    /// Accept the submission result (CLI VM status, or node acceptance for native)
+    #[default]
+    Submitted,
+    /// Query the transaction by hash until it is committed on chain
+    Committed,
+}
+
+/// Submission path for MVM deliveries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MvmSubmission {
+    /// Shell out to `aptos move run` (requires the aptos binary and the `aptos-cli` feature)
+    Cli,
+    /// Build, sign, and submit the transaction directly over the node REST API
+    #[default]
+    Native,
+}
+
+/// Transaction type the relay builds for deliveries to an EVM chain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvmTxType {
+    /// Type-0 transaction priced with `eth_gasPrice`
+    #[default]
+    Legacy,
+    /// Type-2 transaction priced from `eth_feeHistory` (`maxFeePerGas`/`maxPriorityFeePerGas`)
+    Eip1559,
+}
+
+/// Allowed destinations for messages originating on one source chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteAllowlistEntry {
+    /// Source chain ID the entry applies to
+    pub src_chain_id: u64,
+    /// Destination chain IDs the source chain is permitted to message
+    pub allowed_dst_chain_ids: Vec<u64>,
+}
+
+fn default_retry_budget() -> u32 {
+    3
+}
+
+fn default_idle_polls_before_widening() -> u32 {
+    3
+}
+
+fn default_evm_cold_start_blocks() -> u64 {
+    10
+}
+
+fn default_check_program_deployment() -> bool {
+    true
+}
+
+fn default_mvm_confirmation_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_remote_signer_timeout_ms() -> u64 {
+    5000
 }
 
 fn default_private_key_env() -> String {
@@ -128,6 +433,14 @@ fn default_public_key_env() -> String {
     "INTEGRATED_GMP_PUBLIC_KEY".to_string()
 }
 
+fn default_ecdsa_private_key_env() -> String {
+    "INTEGRATED_GMP_ECDSA_PRIVATE_KEY".to_string()
+}
+
+fn default_ecdsa_address_env() -> String {
+    "INTEGRATED_GMP_ECDSA_ADDRESS".to_string()
+}
+
 impl IntegratedGmpConfig {
     /// Loads the private key from the environment variable.
     ///
@@ -156,6 +469,49 @@ impl IntegratedGmpConfig {
                 self.public_key_env
             ))
     }
+
+    /// Loads the separate ECDSA private key from the environment variable, if set.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The secp256k1 private key (hex encoded)
+    /// * `None` - Not set; the ECDSA key is derived from the Ed25519 key
+    pub fn get_ecdsa_private_key(&self) -> Option<String> {
+        std::env::var(&self.ecdsa_private_key_env).ok()
+    }
+
+    /// Loads the expected Ethereum address for the separate ECDSA key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The expected address (0x-prefixed hex)
+    /// * `Err(anyhow::Error)` - Failed to load from environment
+    pub fn get_ecdsa_address(&self) -> anyhow::Result<String> {
+        std::env::var(&self.ecdsa_address_env)
+            .map_err(|_| anyhow::anyhow!(
+                "Environment variable '{}' not set. It is required when '{}' is set: use the Ethereum address of the ECDSA key.",
+                self.ecdsa_address_env,
+                self.ecdsa_private_key_env
+            ))
+    }
+
+    /// Loads the backup operator private keys, in configured order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The backup private keys (base64 encoded)
+    /// * `Err(anyhow::Error)` - A configured variable is not set
+    pub fn get_backup_private_keys(&self) -> anyhow::Result<Vec<String>> {
+        self.backup_private_key_envs
+            .iter()
+            .map(|env| {
+                std::env::var(env).map_err(|_| anyhow::anyhow!(
+                    "Environment variable '{}' not set. Please set it with a backup Ed25519 private key (base64 encoded) or remove it from backup_private_key_envs.",
+                    env
+                ))
+            })
+            .collect()
+    }
 }
 
 /// API server configuration for external communication.
@@ -164,6 +520,9 @@ impl IntegratedGmpConfig {
 /// and handles cross-origin requests.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiConfig {
+    /// Serve the operator status API (`GET /pending`). Default: false.
+    #[serde(default)]
+    pub enabled: bool,
     /// Host address to bind the API server to
     pub host: String,
     /// Port number to bind the API server to
@@ -172,26 +531,216 @@ pub struct ApiConfig {
     pub cors_origins: Vec<String>,
 }
 
+/// Prometheus metrics endpoint configuration.
+///
+/// Metrics are served on their own listener so scrapers do not need access to the
+/// operator status API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Record metrics and serve `GET /metrics`. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Host address to bind the metrics server to
+    #[serde(default = "default_metrics_host")]
+    pub host: String,
+    /// Port number to bind the metrics server to
+    #[serde(default = "default_metrics_port")]
+    pub port: u16,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: default_metrics_host(),
+            port: default_metrics_port(),
+        }
+    }
+}
+
+fn default_metrics_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_metrics_port() -> u16 {
+    9464
+}
+
+// ============================================================================
+// VALIDATION REPORT
+// ============================================================================
+
+/// Result of validating a configuration: every error found (not just the first) and
+/// warnings for settings that are valid but likely unintended.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    /// Problems that make the configuration unusable, each naming its field path
+    pub errors: Vec<String>,
+    /// Valid settings that make the relay skip work
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Whether the configuration passed validation (warnings do not count).
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Records the error of a failed check.
+    fn check(&mut self, result: anyhow::Result<()>) {
+        if let Err(e) = result {
+            self.errors.push(format!("{:#}", e));
+        }
+    }
+
+    fn error(&mut self, message: String) {
+        self.errors.push(message);
+    }
+
+    fn warn(&mut self, message: String) {
+        self.warnings.push(message);
+    }
+}
+
 // ============================================================================
 // CONFIGURATION LOADING AND MANAGEMENT
 // ============================================================================
 
 impl Config {
-    /// Validates the configuration for duplicate chain IDs.
+    /// Validates the configuration.
     ///
-    /// Ensures all configured chains (hub + all connected MVM/EVM/SVM chains) have unique chain IDs.
+    /// Checks individual fields first (required values per chain type, URL schemes,
+    /// numeric ranges), then ensures all configured chains (hub + all connected
+    /// MVM/EVM/SVM chains) have unique chain IDs.
     ///
     /// # Returns
     ///
-    /// - `Ok(())` - Configuration is valid
-    /// - `Err(anyhow::Error)` - Duplicate chain IDs detected
-    pub fn validate(&self) -> anyhow::Result<()> {
+    /// - `Ok(warnings)` - Configuration is valid; `warnings` lists settings that are valid but likely unintended
+    /// - `Err(anyhow::Error)` - The first invalid field (error names the field path) or duplicate chain ID
+    pub fn validate(&self) -> anyhow::Result<Vec<String>> {
+        let report = self.validation_report();
+        match report.errors.into_iter().next() {
+            Some(error) => Err(anyhow::anyhow!(error)),
+            None => Ok(report.warnings),
+        }
+    }
+
+    /// Runs every validation check and collects all errors and warnings instead of
+    /// stopping at the first error.
+    pub fn validation_report(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        self.validate_fields(&mut report);
+        self.validate_chain_ids(&mut report);
+        self.validate_route_allowlist(&mut report);
+        self.validate_relay_settings(&mut report);
+        self.collect_warnings(&mut report);
+        report
+    }
+
+    /// Loads the configuration file at `config_path` and reports every problem found,
+    /// including addresses and program IDs that do not parse (`--check-config`).
+    ///
+    /// Never fails: a file that cannot be read or parsed is reported as an error.
+    pub fn check(config_path: &str) -> ValidationReport {
+        let config = std::fs::read_to_string(config_path)
+            .map_err(|e| anyhow::anyhow!("Configuration error: failed to read '{}': {}", config_path, e))
+            .and_then(|content| Self::parse(config_path, &content));
+        match config {
+            Ok(config) => {
+                let mut report = config.validation_report();
+                config.validate_addresses(&mut report);
+                report
+            }
+            Err(e) => ValidationReport {
+                errors: vec![format!("{:#}", e)],
+                warnings: Vec::new(),
+            },
+        }
+    }
+
+    /// Validates individual fields, reporting each invalid one by its TOML path
+    /// (e.g. `api.port must be 1..65535, got 0`).
+    fn validate_fields(&self, report: &mut ValidationReport) {
+        validate_mvm_chain(report, "hub_chain", &self.hub_chain);
+        for (i, chain) in self.connected_chain_mvm.iter().enumerate() {
+            validate_mvm_chain(report, &format!("connected_chain_mvm[{}]", i), chain);
+        }
+        for (i, chain) in self.connected_chain_evm.iter().enumerate() {
+            let field = format!("connected_chain_evm[{}]", i);
+            report.check(require_non_empty(&format!("{}.name", field), &chain.name));
+            report.check(require_http_url(&format!("{}.rpc_url", field), &chain.rpc_url));
+            report.check(require_non_empty(&format!("{}.escrow_contract_addr", field), &chain.escrow_contract_addr));
+            report.check(require_non_empty(&format!("{}.approver_evm_pubkey_hash", field), &chain.approver_evm_pubkey_hash));
+            report.check(require_chain_polling_interval(&field, chain.polling_interval_ms));
+            if !chain.gas_limit_multiplier.is_finite() || chain.gas_limit_multiplier < 1.0 {
+                report.error(format!(
+                    "Configuration error: {}.gas_limit_multiplier must be at least 1.0, got {}",
+                    field, chain.gas_limit_multiplier
+                ));
+            }
+        }
+        for (i, chain) in self.connected_chain_svm.iter().enumerate() {
+            let field = format!("connected_chain_svm[{}]", i);
+            report.check(require_non_empty(&format!("{}.name", field), &chain.name));
+            report.check(require_http_url(&format!("{}.rpc_url", field), &chain.rpc_url));
+            report.check(require_non_empty(&format!("{}.escrow_program_id", field), &chain.escrow_program_id));
+            report.check(require_non_empty(&format!("{}.outflow_program_id", field), &chain.outflow_program_id));
+            report.check(require_chain_polling_interval(&field, chain.polling_interval_ms));
+        }
+
+        let gmp = &self.integrated_gmp;
+        report.check(require_non_empty("integrated_gmp.private_key_env", &gmp.private_key_env));
+        report.check(require_non_empty("integrated_gmp.public_key_env", &gmp.public_key_env));
+        report.check(require_non_empty("integrated_gmp.ecdsa_private_key_env", &gmp.ecdsa_private_key_env));
+        report.check(require_non_empty("integrated_gmp.ecdsa_address_env", &gmp.ecdsa_address_env));
+        report.check(require_positive("integrated_gmp.polling_interval_ms", gmp.polling_interval_ms));
+        if let Some(max_ms) = gmp.max_idle_polling_interval_ms {
+            if max_ms < gmp.polling_interval_ms {
+                report.error(format!(
+                    "Configuration error: integrated_gmp.max_idle_polling_interval_ms must be at least integrated_gmp.polling_interval_ms ({}), got {}",
+                    gmp.polling_interval_ms,
+                    max_ms
+                ));
+            }
+        }
+        report.check(require_positive("integrated_gmp.validation_timeout_ms", gmp.validation_timeout_ms));
+        report.check(require_positive("integrated_gmp.evm_cold_start_blocks", gmp.evm_cold_start_blocks));
+        if let Some(path) = &gmp.cursor_file {
+            report.check(require_non_empty("integrated_gmp.cursor_file", path));
+        }
+        if let Some(path) = &gmp.dead_letter_file {
+            report.check(require_non_empty("integrated_gmp.dead_letter_file", path));
+        }
+        if let Some(url) = &gmp.alert_webhook_url {
+            report.check(require_http_url("integrated_gmp.alert_webhook_url", url));
+        }
+        if let Some(signer) = &gmp.remote_signer {
+            report.check(require_non_empty("integrated_gmp.remote_signer.addr", &signer.addr));
+            report.check(require_positive("integrated_gmp.remote_signer.timeout_ms", signer.timeout_ms));
+        }
+
+        report.check(require_non_empty("api.host", &self.api.host));
+        report.check(require_port("api.port", self.api.port));
+        if self.metrics.enabled {
+            report.check(require_non_empty("metrics.host", &self.metrics.host));
+            report.check(require_port("metrics.port", self.metrics.port));
+            if self.api.enabled && self.metrics.host == self.api.host && self.metrics.port == self.api.port {
+                report.error(format!(
+                    "Configuration error: metrics.port must differ from api.port when both are served on {}",
+                    self.metrics.host
+                ));
+            }
+        }
+    }
+
+    /// Ensures the hub and all connected MVM/EVM/SVM chains have unique chain IDs.
+    fn validate_chain_ids(&self, report: &mut ValidationReport) {
         let hub_chain_id = self.hub_chain.chain_id;
 
         // Check all MVM chains against hub and each other
         for mvm_config in &self.connected_chain_mvm {
             if hub_chain_id == mvm_config.chain_id {
-                return Err(anyhow::anyhow!(
+                report.error(format!(
                     "Configuration error: Hub chain and connected MVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                     hub_chain_id, mvm_config.name
                 ));
@@ -200,7 +749,7 @@ impl Config {
         for i in 0..self.connected_chain_mvm.len() {
             for j in (i + 1)..self.connected_chain_mvm.len() {
                 if self.connected_chain_mvm[i].chain_id == self.connected_chain_mvm[j].chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected MVM chains '{}' and '{}' have the same chain ID {}. Each chain must have a unique chain ID.",
                         self.connected_chain_mvm[i].name, self.connected_chain_mvm[j].name, self.connected_chain_mvm[i].chain_id
                     ));
@@ -211,14 +760,14 @@ impl Config {
         // Check all SVM chains against hub, MVM, and each other
         for svm_config in &self.connected_chain_svm {
             if hub_chain_id == svm_config.chain_id {
-                return Err(anyhow::anyhow!(
+                report.error(format!(
                     "Configuration error: Hub chain and connected SVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                     hub_chain_id, svm_config.name
                 ));
             }
             for mvm_config in &self.connected_chain_mvm {
                 if mvm_config.chain_id == svm_config.chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected MVM chain and connected SVM chain have the same chain ID {}. Each chain must have a unique chain ID.",
                         svm_config.chain_id
                     ));
@@ -228,7 +777,7 @@ impl Config {
         for i in 0..self.connected_chain_svm.len() {
             for j in (i + 1)..self.connected_chain_svm.len() {
                 if self.connected_chain_svm[i].chain_id == self.connected_chain_svm[j].chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected SVM chains '{}' and '{}' have the same chain ID {}. Each chain must have a unique chain ID.",
                         self.connected_chain_svm[i].name, self.connected_chain_svm[j].name, self.connected_chain_svm[i].chain_id
                     ));
@@ -239,14 +788,14 @@ impl Config {
         // Check all EVM chains against hub, MVM, SVM, and each other
         for evm_config in &self.connected_chain_evm {
             if hub_chain_id == evm_config.chain_id {
-                return Err(anyhow::anyhow!(
+                report.error(format!(
                     "Configuration error: Hub chain and connected EVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                     hub_chain_id, evm_config.name
                 ));
             }
             for mvm_config in &self.connected_chain_mvm {
                 if mvm_config.chain_id == evm_config.chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected MVM chain and connected EVM chain have the same chain ID {} (chain: '{}'). Each chain must have a unique chain ID.",
                         evm_config.chain_id, evm_config.name
                     ));
@@ -254,7 +803,7 @@ impl Config {
             }
             for svm_config in &self.connected_chain_svm {
                 if evm_config.chain_id == svm_config.chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected EVM chain '{}' and connected SVM chain have the same chain ID {}. Each chain must have a unique chain ID.",
                         evm_config.name, evm_config.chain_id
                     ));
@@ -264,15 +813,180 @@ impl Config {
         for i in 0..self.connected_chain_evm.len() {
             for j in (i + 1)..self.connected_chain_evm.len() {
                 if self.connected_chain_evm[i].chain_id == self.connected_chain_evm[j].chain_id {
-                    return Err(anyhow::anyhow!(
+                    report.error(format!(
                         "Configuration error: Connected EVM chains '{}' and '{}' have the same chain ID {}. Each chain must have a unique chain ID.",
                         self.connected_chain_evm[i].name, self.connected_chain_evm[j].name, self.connected_chain_evm[i].chain_id
                     ));
                 }
             }
         }
+    }
 
-        Ok(())
+    /// Validates that route allowlist entries reference configured chains and that
+    /// each source chain has at most one entry.
+    fn validate_route_allowlist(&self, report: &mut ValidationReport) {
+        let configured: Vec<u64> = std::iter::once(self.hub_chain.chain_id)
+            .chain(self.connected_chain_mvm.iter().map(|c| c.chain_id))
+            .chain(self.connected_chain_evm.iter().map(|c| c.chain_id))
+            .chain(self.connected_chain_svm.iter().map(|c| c.chain_id))
+            .collect();
+
+        let entries = &self.integrated_gmp.route_allowlist;
+        for (i, entry) in entries.iter().enumerate() {
+            if entries[..i].iter().any(|e| e.src_chain_id == entry.src_chain_id) {
+                report.error(format!(
+                    "Configuration error: route_allowlist has more than one entry for source chain ID {}.",
+                    entry.src_chain_id
+                ));
+            }
+            if !configured.contains(&entry.src_chain_id) {
+                report.error(format!(
+                    "Configuration error: route_allowlist source chain ID {} is not a configured chain.",
+                    entry.src_chain_id
+                ));
+            }
+            for dst in entry
+                .allowed_dst_chain_ids
+                .iter()
+                .filter(|dst| !configured.contains(dst))
+            {
+                report.error(format!(
+                    "Configuration error: route_allowlist for source chain ID {} allows destination chain ID {} which is not a configured chain.",
+                    entry.src_chain_id, dst
+                ));
+            }
+        }
+    }
+
+    /// Validates relay settings that depend on each other or on the build features.
+    fn validate_relay_settings(&self, report: &mut ValidationReport) {
+        if self.integrated_gmp.backfill_max_rps == Some(0) {
+            report.error(
+                "Configuration error: backfill_max_rps must be greater than zero (omit it to disable the limit).".to_string()
+            );
+        }
+
+        if self.integrated_gmp.max_concurrent_deliveries == Some(0) {
+            report.error(
+                "Configuration error: max_concurrent_deliveries must be greater than zero (omit it to disable the limit).".to_string()
+            );
+        }
+
+        if self.integrated_gmp.config_reload_interval_ms == Some(0) {
+            report.error(
+                "Configuration error: config_reload_interval_ms must be greater than zero (omit it to disable reload).".to_string()
+            );
+        }
+
+        if self.integrated_gmp.mvm_commitment == MvmCommitment::Committed
+            && self.integrated_gmp.mvm_confirmation_timeout_ms == 0
+        {
+            report.error(
+                "Configuration error: mvm_confirmation_timeout_ms must be greater than zero when mvm_commitment is 'committed'.".to_string()
+            );
+        }
+
+        if !cfg!(feature = "aptos-cli")
+            && (self.integrated_gmp.mvm_submission == MvmSubmission::Cli
+                || self.integrated_gmp.mvm_cli_fallback)
+        {
+            report.error(
+                "Configuration error: mvm_submission = 'cli' and mvm_cli_fallback need a build with the aptos-cli feature.".to_string()
+            );
+        }
+    }
+
+    /// Warns about settings that are valid but make the relay skip work.
+    fn collect_warnings(&self, report: &mut ValidationReport) {
+        if self.connected_chain_mvm.is_empty()
+            && self.connected_chain_evm.is_empty()
+            && self.connected_chain_svm.is_empty()
+        {
+            report.warn("no connected chains are configured; only hub messages are relayed".to_string());
+        }
+        if !self.hub_chain.enabled {
+            report.warn("hub_chain is disabled; the relay will not poll or deliver to it".to_string());
+        }
+        for (i, chain) in self.connected_chain_mvm.iter().enumerate() {
+            if !chain.enabled {
+                report.warn(format!(
+                    "connected_chain_mvm[{}] ('{}') is disabled; the relay will not poll or deliver to it",
+                    i, chain.name
+                ));
+            }
+        }
+        for (i, chain) in self.connected_chain_evm.iter().enumerate() {
+            if !chain.enabled {
+                report.warn(format!(
+                    "connected_chain_evm[{}] ('{}') is disabled; the relay will not poll or deliver to it",
+                    i, chain.name
+                ));
+            } else if chain.gmp_endpoint_addr.is_none() {
+                report.warn(format!(
+                    "connected_chain_evm[{}].gmp_endpoint_addr is not set; the relay will not poll or deliver to '{}'",
+                    i, chain.name
+                ));
+            }
+        }
+        for (i, chain) in self.connected_chain_svm.iter().enumerate() {
+            if !chain.enabled {
+                report.warn(format!(
+                    "connected_chain_svm[{}] ('{}') is disabled; the relay will not poll or deliver to it",
+                    i, chain.name
+                ));
+            } else if chain.gmp_endpoint_program_id.is_none() {
+                report.warn(format!(
+                    "connected_chain_svm[{}].gmp_endpoint_program_id is not set; the relay will not poll or deliver to '{}'",
+                    i, chain.name
+                ));
+            }
+        }
+        for entry in &self.integrated_gmp.route_allowlist {
+            if entry.allowed_dst_chain_ids.is_empty() {
+                report.warn(format!(
+                    "route_allowlist entry for source chain ID {} allows no destinations; all its messages are rejected",
+                    entry.src_chain_id
+                ));
+            }
+        }
+    }
+
+    /// Checks that the addresses and program IDs the relay uses parse for their chain type.
+    ///
+    /// The relay only parses these when it first talks to a chain, so a typo would
+    /// otherwise surface after startup.
+    fn validate_addresses(&self, report: &mut ValidationReport) {
+        let mvm_chains = std::iter::once(("hub_chain".to_string(), &self.hub_chain)).chain(
+            self.connected_chain_mvm
+                .iter()
+                .enumerate()
+                .map(|(i, chain)| (format!("connected_chain_mvm[{}]", i), chain)),
+        );
+        for (field, chain) in mvm_chains {
+            report.check(require_mvm_address(&format!("{}.intent_module_addr", field), &chain.intent_module_addr));
+            if let Some(addr) = &chain.escrow_module_addr {
+                report.check(require_mvm_address(&format!("{}.escrow_module_addr", field), addr));
+            }
+        }
+        for (i, chain) in self.connected_chain_evm.iter().enumerate() {
+            let field = format!("connected_chain_evm[{}]", i);
+            report.check(require_evm_address(&format!("{}.escrow_contract_addr", field), &chain.escrow_contract_addr));
+            report.check(require_evm_address(&format!("{}.approver_evm_pubkey_hash", field), &chain.approver_evm_pubkey_hash));
+            if let Some(addr) = &chain.gmp_endpoint_addr {
+                report.check(require_evm_address(&format!("{}.gmp_endpoint_addr", field), addr));
+            }
+            if let Some(addr) = &chain.outflow_validator_addr {
+                report.check(require_evm_address(&format!("{}.outflow_validator_addr", field), addr));
+            }
+        }
+        for (i, chain) in self.connected_chain_svm.iter().enumerate() {
+            let field = format!("connected_chain_svm[{}]", i);
+            report.check(require_svm_program_id(&format!("{}.escrow_program_id", field), &chain.escrow_program_id));
+            report.check(require_svm_program_id(&format!("{}.outflow_program_id", field), &chain.outflow_program_id));
+            if let Some(program_id) = &chain.gmp_endpoint_program_id {
+                report.check(require_svm_program_id(&format!("{}.gmp_endpoint_program_id", field), program_id));
+            }
+        }
     }
 
     /// Loads configuration from the TOML file.
@@ -288,17 +1002,10 @@ impl Config {
     /// - `Ok(Config)` - Successfully loaded and validated configuration
     /// - `Err(anyhow::Error)` - Failed to load configuration, file doesn't exist, or validation failed
     pub fn load() -> anyhow::Result<Self> {
-        // Check for custom config path via environment variable (for tests)
-        let config_path = std::env::var("INTEGRATED_GMP_CONFIG_PATH")
-            .unwrap_or_else(|_| "config/integrated-gmp.toml".to_string());
+        let config_path = Self::path();
 
         if std::path::Path::new(&config_path).exists() {
-            // Load existing configuration
-            let content = std::fs::read_to_string(&config_path)?;
-            let config: Config = toml::from_str(&content)?;
-            // Validate configuration
-            config.validate()?;
-            Ok(config)
+            Self::load_from(&config_path)
         } else {
             // Configuration file doesn't exist - user needs to copy template
             Err(anyhow::anyhow!(
@@ -310,6 +1017,112 @@ impl Config {
         }
     }
 
+    /// Path of the configuration file: `INTEGRATED_GMP_CONFIG_PATH` if set (tests,
+    /// `--config`, `--testnet`), otherwise `config/integrated-gmp.toml`.
+    pub fn path() -> String {
+        std::env::var("INTEGRATED_GMP_CONFIG_PATH")
+            .unwrap_or_else(|_| "config/integrated-gmp.toml".to_string())
+    }
+
+    /// Loads, parses, and validates the configuration file at `config_path`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Config)` - Successfully loaded and validated configuration
+    /// - `Err(anyhow::Error)` - The file could not be read or parsed, or validation failed
+    pub fn load_from(config_path: &str) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(config_path)?;
+        let config = Self::parse(config_path, &content)?;
+        for warning in config.validate()? {
+            tracing::warn!("Configuration warning: {}", warning);
+        }
+        Ok(config)
+    }
+
+    /// Parses config file contents read from `config_path` and merges the chain files
+    /// in its `chains_dir`, if set. Does not validate.
+    pub fn parse(config_path: &str, content: &str) -> anyhow::Result<Self> {
+        let mut config: Config = parse_toml(config_path, content)?;
+        if let Some(chains_dir) = &config.chains_dir {
+            let dir = Path::new(config_path)
+                .parent()
+                .unwrap_or(Path::new(""))
+                .join(chains_dir);
+            config.merge_chains_dir(&dir)?;
+        }
+        Ok(config)
+    }
+
+    /// Merges the `*.toml` chain files in `dir` into the connected chains.
+    ///
+    /// Files are read in name order and each must define exactly one `[[chain]]`.
+    /// A chain whose ID is already defined (inline or in an earlier file) with identical
+    /// settings is skipped; different settings for the same chain ID, or a file reusing
+    /// the hub chain ID, are an error naming both sources. Cross-type duplicates are
+    /// left to `validate`.
+    pub fn merge_chains_dir(&mut self, dir: &Path) -> anyhow::Result<()> {
+        let entries = std::fs::read_dir(dir).map_err(|e| {
+            anyhow::anyhow!("Configuration error: failed to read chains_dir '{}': {}", dir.display(), e)
+        })?;
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        // Chain ID -> (where it is defined, definition; None for the hub chain)
+        let mut defined: HashMap<u64, (String, Option<ChainDefinition>)> = HashMap::new();
+        defined.insert(self.hub_chain.chain_id, ("hub_chain".to_string(), None));
+        for (i, chain) in self.connected_chain_mvm.iter().enumerate() {
+            defined
+                .entry(chain.chain_id)
+                .or_insert_with(|| (format!("connected_chain_mvm[{}]", i), Some(ChainDefinition::Mvm(chain.clone()))));
+        }
+        for (i, chain) in self.connected_chain_evm.iter().enumerate() {
+            defined
+                .entry(chain.chain_id)
+                .or_insert_with(|| (format!("connected_chain_evm[{}]", i), Some(ChainDefinition::Evm(chain.clone()))));
+        }
+        for (i, chain) in self.connected_chain_svm.iter().enumerate() {
+            defined
+                .entry(chain.chain_id)
+                .or_insert_with(|| (format!("connected_chain_svm[{}]", i), Some(ChainDefinition::Svm(chain.clone()))));
+        }
+
+        for path in paths {
+            let content = std::fs::read_to_string(&path).map_err(|e| {
+                anyhow::anyhow!("Configuration error: failed to read '{}': {}", path.display(), e)
+            })?;
+            let file: ChainFile = parse_toml(&path.display().to_string(), &content)?;
+            let [chain] = <[ChainDefinition; 1]>::try_from(file.chain).map_err(|chains| {
+                anyhow::anyhow!(
+                    "Configuration error: '{}' must define exactly one [[chain]], found {}.",
+                    path.display(), chains.len()
+                )
+            })?;
+
+            let chain_id = chain.chain_id();
+            match defined.get(&chain_id) {
+                Some((_, Some(existing))) if *existing == chain => continue,
+                Some((source, _)) => {
+                    return Err(anyhow::anyhow!(
+                        "Configuration error: chain ID {} in '{}' conflicts with the definition in {}. Each chain ID must be defined once (repeated definitions must be identical).",
+                        chain_id, path.display(), source
+                    ));
+                }
+                None => {}
+            }
+            defined.insert(chain_id, (format!("'{}'", path.display()), Some(chain.clone())));
+
+            match chain {
+                ChainDefinition::Mvm(chain) => self.connected_chain_mvm.push(chain),
+                ChainDefinition::Evm(chain) => self.connected_chain_evm.push(chain),
+                ChainDefinition::Svm(chain) => self.connected_chain_svm.push(chain),
+            }
+        }
+        Ok(())
+    }
+
     /// Creates a default configuration with placeholder values.
     ///
     /// This configuration is suitable for local development and testing.
@@ -318,27 +1131,146 @@ impl Config {
     #[allow(dead_code)]
     pub fn default() -> Self {
         Self {
+            chains_dir: None,
             hub_chain: ChainConfig {
                 name: "Hub Chain".to_string(),
                 rpc_url: "http://127.0.0.1:8080".to_string(),
                 chain_id: 1,
                 intent_module_addr: "0x123".to_string(),
                 escrow_module_addr: None,
+                enabled: true,
+                polling_interval_ms: None,
             },
             connected_chain_mvm: vec![], // No connected MVM chains by default
             integrated_gmp: IntegratedGmpConfig {
                 private_key_env: "INTEGRATED_GMP_PRIVATE_KEY".to_string(),
                 public_key_env: "INTEGRATED_GMP_PUBLIC_KEY".to_string(),
+                ecdsa_private_key_env: "INTEGRATED_GMP_ECDSA_PRIVATE_KEY".to_string(),
+                ecdsa_address_env: "INTEGRATED_GMP_ECDSA_ADDRESS".to_string(),
+                backup_private_key_envs: vec![],
                 polling_interval_ms: 2000,
+                max_idle_polling_interval_ms: None,
+                idle_polls_before_widening: 3,
                 validation_timeout_ms: 30000,
+                retry_budget: 3,
+                alert_webhook_url: None,
+                route_allowlist: vec![],
+                backfill_max_rps: None,
+                cursor_file: None,
+                dead_letter_file: None,
+                evm_cold_start_blocks: 10,
+                max_concurrent_deliveries: None,
+                check_program_deployment: true,
+                mvm_outbox_cleanup: false,
+                mvm_commitment: MvmCommitment::Submitted,
+                mvm_confirmation_timeout_ms: 30000,
+                mvm_submission: MvmSubmission::Native,
+                mvm_cli_fallback: false,
+                remote_signer: None,
+                config_reload_interval_ms: None,
             },
             api: ApiConfig {
+                enabled: false,
                 host: "127.0.0.1".to_string(),
                 port: 3333,
                 cors_origins: vec!["http://localhost:3333".to_string()],
             },
+            metrics: MetricsConfig::default(),
             connected_chain_evm: vec![], // No connected EVM chains by default
             connected_chain_svm: vec![], // No connected SVM chains by default
         }
     }
 }
+
+// ============================================================================
+// FIELD VALIDATION HELPERS
+// ============================================================================
+
+/// Validates the required fields of an MVM chain section (hub or connected).
+fn validate_mvm_chain(report: &mut ValidationReport, field: &str, chain: &ChainConfig) {
+    report.check(require_non_empty(&format!("{}.name", field), &chain.name));
+    report.check(require_http_url(&format!("{}.rpc_url", field), &chain.rpc_url));
+    report.check(require_non_empty(&format!("{}.intent_module_addr", field), &chain.intent_module_addr));
+    report.check(require_chain_polling_interval(field, chain.polling_interval_ms));
+}
+
+/// Parses TOML read from `source`, expanding `${ENV_VAR}` references in string values.
+fn parse_toml<T: serde::de::DeserializeOwned>(source: &str, content: &str) -> anyhow::Result<T> {
+    let mut value: toml::Value = toml::from_str(content).map_err(|e| {
+        anyhow::anyhow!("Configuration error: failed to parse '{}': {}", source, e)
+    })?;
+    expand_env_vars_in_toml(&mut value)
+        .map_err(|e| anyhow::anyhow!("Configuration error: in '{}': {}", source, e))?;
+    value.try_into().map_err(|e| {
+        anyhow::anyhow!("Configuration error: failed to parse '{}': {}", source, e)
+    })
+}
+
+/// Validates an optional per-chain polling interval override.
+fn require_chain_polling_interval(field: &str, value: Option<u64>) -> anyhow::Result<()> {
+    match value {
+        Some(ms) => require_positive(&format!("{}.polling_interval_ms", field), ms),
+        None => Ok(()),
+    }
+}
+
+fn require_non_empty(field: &str, value: &str) -> anyhow::Result<()> {
+    if value.trim().is_empty() {
+        anyhow::bail!("Configuration error: {} must not be empty", field);
+    }
+    Ok(())
+}
+
+fn require_positive(field: &str, value: u64) -> anyhow::Result<()> {
+    if value == 0 {
+        anyhow::bail!("Configuration error: {} must be greater than 0, got 0", field);
+    }
+    Ok(())
+}
+
+fn require_port(field: &str, port: u16) -> anyhow::Result<()> {
+    if port == 0 {
+        anyhow::bail!("Configuration error: {} must be 1..65535, got 0", field);
+    }
+    Ok(())
+}
+
+fn require_http_url(field: &str, url: &str) -> anyhow::Result<()> {
+    require_non_empty(field, url)?;
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        anyhow::bail!(
+            "Configuration error: {} must start with http:// or https://, got '{}'",
+            field,
+            url
+        );
+    }
+    Ok(())
+}
+
+fn require_mvm_address(field: &str, addr: &str) -> anyhow::Result<()> {
+    parse_account_address(addr)
+        .map(|_| ())
+        .map_err(|_| anyhow::anyhow!("Configuration error: {} is not a valid MVM address, got '{}'", field, addr))
+}
+
+fn require_evm_address(field: &str, addr: &str) -> anyhow::Result<()> {
+    let hex_str = addr.strip_prefix("0x").unwrap_or(addr);
+    match hex::decode(hex_str) {
+        Ok(bytes) if bytes.len() == 20 => Ok(()),
+        _ => anyhow::bail!(
+            "Configuration error: {} is not a valid EVM address (20 bytes hex), got '{}'",
+            field,
+            addr
+        ),
+    }
+}
+
+fn require_svm_program_id(field: &str, program_id: &str) -> anyhow::Result<()> {
+    Pubkey::from_str(program_id).map(|_| ()).map_err(|_| {
+        anyhow::anyhow!(
+            "Configuration error: {} is not a valid base58 SVM program ID, got '{}'",
+            field,
+            program_id
+        )
+    })
+}
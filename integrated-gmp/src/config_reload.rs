@@ -0,0 +1,183 @@
+//! Configuration Reload Module
+//!
+//! Watches the config file and applies safe-to-change settings to a running relay
+//! without a restart, so warm state (processed nonces, retry budgets, pending
+//! deliveries) survives a tuning change.
+//!
+//! Reloadable: `integrated_gmp.polling_interval_ms`, `retry_budget`, `route_allowlist`.
+//! Everything else (keys, chains, RPC URLs, limiters, API) is logged as requiring a
+//! restart and keeps its running value. A reloaded file that fails to parse or
+//! validate is rejected and the running config is kept.
+
+use anyhow::{Context, Result};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::config::Config;
+use crate::integrated_gmp_relay::{NativeGmpRelay, RelayTunables};
+
+/// `integrated_gmp` fields applied at runtime by reload.
+const RELOADABLE_FIELDS: &[&str] = &["polling_interval_ms", "retry_budget", "route_allowlist"];
+
+// ============================================================================
+// RELOAD OUTCOME
+// ============================================================================
+
+/// Result of checking the config file once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReloadOutcome {
+    /// File contents have not changed since the last check
+    Unchanged,
+    /// File changed and validated; reloadable settings were applied.
+    /// Lists changed settings that still require a restart.
+    Applied { restart_required: Vec<String> },
+    /// File changed but could not be read, parsed, or validated; running config kept
+    Rejected(String),
+}
+
+// ============================================================================
+// RELOADER
+// ============================================================================
+
+/// Tracks the config file and the configuration the relay is running with.
+pub struct ConfigReloader {
+    path: String,
+    /// Configuration currently in effect (reloadable fields updated on apply)
+    current: Config,
+    /// File contents at the last check, to detect changes
+    last_content: String,
+}
+
+impl ConfigReloader {
+    /// Create a reloader for `path`, where `current` is the config the relay started with.
+    pub fn new(path: impl Into<String>, current: Config) -> Result<Self> {
+        let path = path.into();
+        let last_content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file '{}'", path))?;
+        Ok(Self {
+            path,
+            current,
+            last_content,
+        })
+    }
+
+    /// Configuration currently in effect.
+    pub fn current(&self) -> &Config {
+        &self.current
+    }
+
+    /// Check the file once and apply reloadable changes to `relay`.
+    pub async fn check(&mut self, relay: &NativeGmpRelay) -> ReloadOutcome {
+        let content = match std::fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) => {
+                let reason = format!("Failed to read config file '{}': {}", self.path, e);
+                error!("Config reload rejected, keeping current config: {}", reason);
+                return ReloadOutcome::Rejected(reason);
+            }
+        };
+        if content == self.last_content {
+            return ReloadOutcome::Unchanged;
+        }
+        self.last_content = content;
+
+        let reloaded = match parse_and_validate(&self.path, &self.last_content) {
+            Ok(config) => config,
+            Err(e) => {
+                let reason = format!("{:#}", e);
+                error!("Config reload rejected, keeping current config: {}", reason);
+                return ReloadOutcome::Rejected(reason);
+            }
+        };
+
+        let restart_required = restart_required_changes(&self.current, &reloaded);
+        if !restart_required.is_empty() {
+            warn!(
+                "Config reload: {} changed but require a restart to take effect; running values kept",
+                restart_required.join(", ")
+            );
+        }
+
+        let tunables = RelayTunables::from_config(&reloaded);
+        if tunables != relay.tunables() {
+            info!(
+                "Config reload: applying polling_interval_ms={}, retry_budget={}, route_allowlist entries={}",
+                tunables.polling_interval_ms,
+                tunables.retry_budget,
+                tunables.route_allowlist.len()
+            );
+            relay.apply_tunables(tunables).await;
+        }
+
+        let gmp = &mut self.current.integrated_gmp;
+        gmp.polling_interval_ms = reloaded.integrated_gmp.polling_interval_ms;
+        gmp.retry_budget = reloaded.integrated_gmp.retry_budget;
+        gmp.route_allowlist = reloaded.integrated_gmp.route_allowlist;
+
+        ReloadOutcome::Applied { restart_required }
+    }
+}
+
+/// Parse and validate config file contents read from `path`.
+fn parse_and_validate(path: &str, content: &str) -> Result<Config> {
+    let config = Config::parse(path, content)?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// List settings that differ between `running` and `reloaded` and cannot be
+/// applied without a restart.
+///
+/// `integrated_gmp` fields are reported individually (e.g. `integrated_gmp.private_key_env`);
+/// other sections by name (e.g. `hub_chain`, `connected_chain_evm`).
+pub fn restart_required_changes(running: &Config, reloaded: &Config) -> Vec<String> {
+    let (Ok(running), Ok(reloaded)) = (serde_json::to_value(running), serde_json::to_value(reloaded))
+    else {
+        return vec!["<config>".to_string()];
+    };
+    let (Some(running), Some(reloaded)) = (running.as_object(), reloaded.as_object()) else {
+        return vec!["<config>".to_string()];
+    };
+
+    let mut changed = Vec::new();
+    for (section, running_value) in running {
+        let reloaded_value = reloaded.get(section);
+        if section != "integrated_gmp" {
+            if reloaded_value != Some(running_value) {
+                changed.push(section.clone());
+            }
+            continue;
+        }
+
+        let empty = serde_json::Map::new();
+        let running_fields = running_value.as_object().unwrap_or(&empty);
+        let reloaded_fields = reloaded_value.and_then(|v| v.as_object()).unwrap_or(&empty);
+        for (field, running_field) in running_fields {
+            if RELOADABLE_FIELDS.contains(&field.as_str()) {
+                continue;
+            }
+            if reloaded_fields.get(field) != Some(running_field) {
+                changed.push(format!("integrated_gmp.{}", field));
+            }
+        }
+    }
+    changed
+}
+
+// ============================================================================
+// WATCH LOOP
+// ============================================================================
+
+/// Check the config file every `interval` and apply changes to `relay` until the
+/// process exits.
+pub async fn watch(relay: Arc<NativeGmpRelay>, mut reloader: ConfigReloader, interval: Duration) {
+    info!(
+        "Config reload enabled: watching '{}' every {:?}",
+        reloader.path, interval
+    );
+    loop {
+        tokio::time::sleep(interval).await;
+        reloader.check(&relay).await;
+    }
+}
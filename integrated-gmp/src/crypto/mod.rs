@@ -8,19 +8,24 @@
 //!
 //! **CRITICAL**: All cryptographic operations must use secure random number generation
 //! and proper key management practices. Private keys must never be exposed or logged.
+//! Buffers holding key material are wrapped in [`Zeroizing`] and wiped when dropped.
 
 use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
-use ed25519_dalek::{SigningKey, VerifyingKey};
+use ed25519_dalek::VerifyingKey;
 use hex;
-use k256::ecdsa::{
-    Signature as EcdsaSignature, SigningKey as EcdsaSigningKey, VerifyingKey as EcdsaVerifyingKey,
-};
+use k256::ecdsa::{Signature as EcdsaSignature, VerifyingKey as EcdsaVerifyingKey};
 use sha3::{Digest, Keccak256, Sha3_256};
+use std::time::Duration;
 use tracing::info;
+use zeroize::{Zeroizing, ZeroizeOnDrop};
 
 use crate::config::Config;
 
+pub mod signer;
+
+pub use signer::{InMemorySigner, RemoteSigner, Signer};
+
 // ============================================================================
 // CRYPTOGRAPHIC SERVICE IMPLEMENTATION
 // ============================================================================
@@ -30,44 +35,54 @@ use crate::config::Config;
 /// Manages Ed25519 keys (for MVM/SVM) and derived ECDSA keys (for EVM).
 /// Used by the relay for signing delivery transactions and by utility binaries
 /// for deriving relay addresses on each chain.
+///
+/// Signing is delegated to a [`Signer`]: the in-memory key from the environment
+/// by default, or a remote signer when `integrated_gmp.remote_signer` is configured.
+/// Dropping the service drops the signer, which wipes any key material it holds.
 pub struct CryptoService {
-    /// Ed25519 signing key (primary key, loaded from config)
-    #[allow(dead_code)]
-    signing_key: SigningKey,
-    /// Ed25519 verifying key (derived from signing key)
+    /// Holds the keys and performs all signing operations
+    signer: Box<dyn Signer>,
+    /// Ed25519 verifying key (reported by the signer)
     verifying_key: VerifyingKey,
-    /// ECDSA signing key for EVM operations (secp256k1)
-    /// Derived from Ed25519 private key by using same 32-byte seed
-    ecdsa_signing_key: EcdsaSigningKey,
+    /// ECDSA verifying key for EVM operations (secp256k1, reported by the signer)
+    ecdsa_verifying_key: EcdsaVerifyingKey,
 }
 
 impl CryptoService {
     /// Creates a new cryptographic service from configuration.
     ///
-    /// Loads the Ed25519 keypair from environment variables specified in config,
-    /// verifies the public key matches, and derives the ECDSA key for EVM.
+    /// With a remote signer configured, connects to it and uses its keys. Otherwise
+    /// loads the Ed25519 keypair from environment variables specified in config and
+    /// verifies the public key matches. The ECDSA key for EVM is loaded from
+    /// `ecdsa_private_key_env` when set (and checked against `ecdsa_address_env`),
+    /// otherwise derived from the Ed25519 private key.
     pub fn new(config: &Config) -> Result<Self> {
-        // Load private key from environment variable
-        let private_key_b64 = config.integrated_gmp.get_private_key()?;
-        let private_key_bytes = general_purpose::STANDARD.decode(&private_key_b64)?;
-
-        if private_key_bytes.len() != 32 {
-            return Err(anyhow::anyhow!(
-                "Invalid private key length: expected 32 bytes, got {}",
-                private_key_bytes.len()
-            ));
+        if let Some(remote) = &config.integrated_gmp.remote_signer {
+            let signer = RemoteSigner::connect(&remote.addr, Duration::from_millis(remote.timeout_ms))?;
+            info!("Crypto service initialized with remote signer at {}", remote.addr);
+            return Self::with_signer(Box::new(signer));
         }
 
-        let secret_key_bytes: [u8; 32] = private_key_bytes
-            .try_into()
-            .map_err(|_| anyhow::anyhow!("Failed to convert private key to array"))?;
+        // Load private key from environment variable
+        let private_key_b64 = Zeroizing::new(config.integrated_gmp.get_private_key()?);
+        let secret_key_bytes = decode_ed25519_seed(&private_key_b64)?;
 
-        let signing_key = SigningKey::from_bytes(&secret_key_bytes);
-        let verifying_key = signing_key.verifying_key();
+        let ecdsa_private_key = match config.integrated_gmp.get_ecdsa_private_key().map(Zeroizing::new) {
+            Some(ecdsa_private_key_hex) => Some(parse_ecdsa_private_key(
+                &ecdsa_private_key_hex,
+                &config.integrated_gmp.ecdsa_private_key_env,
+            )?),
+            None => None,
+        };
+        let signer = match &ecdsa_private_key {
+            Some(ecdsa_key_bytes) => InMemorySigner::from_keys(&secret_key_bytes, ecdsa_key_bytes)?,
+            None => InMemorySigner::from_seed(&secret_key_bytes)?,
+        };
+        let service = Self::with_signer(Box::new(signer))?;
 
         // Verify public key matches environment variable
         let expected_public_key_b64 = config.integrated_gmp.get_public_key()?;
-        let actual_public_key_b64 = general_purpose::STANDARD.encode(verifying_key.to_bytes());
+        let actual_public_key_b64 = service.get_public_key();
 
         if actual_public_key_b64 != expected_public_key_b64 {
             return Err(anyhow::anyhow!(
@@ -78,17 +93,47 @@ impl CryptoService {
             ));
         }
 
+        // Verify the separate ECDSA key matches the expected Ethereum address
+        if ecdsa_private_key.is_some() {
+            let expected_address = config.integrated_gmp.get_ecdsa_address()?;
+            let actual_address = service.get_ethereum_address()?;
+
+            if !actual_address.eq_ignore_ascii_case(expected_address.trim()) {
+                return Err(anyhow::anyhow!(
+                    "ECDSA address mismatch: environment variable '{}' has {}, but ECDSA private key corresponds to {}",
+                    config.integrated_gmp.ecdsa_address_env,
+                    expected_address,
+                    actual_address
+                ));
+            }
+            info!("Crypto service using separate ECDSA key for EVM ({})", actual_address);
+        }
+
         info!("Crypto service initialized with key pair from environment variables");
 
-        // Derive ECDSA key from Ed25519 private key for EVM compatibility
-        let ecdsa_secret_bytes: [u8; 32] = secret_key_bytes;
-        let ecdsa_signing_key = EcdsaSigningKey::from_bytes(&ecdsa_secret_bytes.into())
-            .map_err(|e| anyhow::anyhow!("Failed to create ECDSA signing key: {}", e))?;
+        Ok(service)
+    }
+
+    /// Creates a cryptographic service from a base64 Ed25519 private key.
+    ///
+    /// Used for backup relay operator keys: the ECDSA key is derived from the
+    /// Ed25519 key and there is no public key variable to check against.
+    pub fn from_private_key(private_key_b64: &str) -> Result<Self> {
+        let secret_key_bytes = decode_ed25519_seed(private_key_b64)?;
+        Self::with_signer(Box::new(InMemorySigner::from_seed(&secret_key_bytes)?))
+    }
+
+    /// Creates a cryptographic service that signs with `signer`.
+    pub fn with_signer(signer: Box<dyn Signer>) -> Result<Self> {
+        let verifying_key = VerifyingKey::from_bytes(&signer.ed25519_public_key()?)
+            .map_err(|e| anyhow::anyhow!("Invalid Ed25519 public key from signer: {}", e))?;
+        let ecdsa_verifying_key = EcdsaVerifyingKey::from_sec1_bytes(&signer.ecdsa_public_key()?)
+            .map_err(|e| anyhow::anyhow!("Invalid ECDSA public key from signer: {}", e))?;
 
         Ok(Self {
-            signing_key,
+            signer,
             verifying_key,
-            ecdsa_signing_key,
+            ecdsa_verifying_key,
         })
     }
 
@@ -97,11 +142,20 @@ impl CryptoService {
         general_purpose::STANDARD.encode(self.verifying_key.to_bytes())
     }
 
+    /// Signs a message with the Ed25519 key.
+    pub fn sign_ed25519(&self, message: &[u8]) -> Result<[u8; 64]> {
+        self.signer.sign_ed25519(message)
+    }
+
     /// Signs a raw EVM transaction hash with the ECDSA key.
     ///
     /// This does NOT apply the Ethereum signed message prefix — the caller is expected
     /// to pass a keccak256 hash of a RLP-encoded transaction.
     ///
+    /// The signature is normalized to low-s (EIP-2): a remote signer may return the
+    /// malleable high-s form, which EVM chains and contracts reject. The recovery ID
+    /// is computed for the normalized signature.
+    ///
     /// # Returns
     ///
     /// * `Ok((r, s, recovery_id))` — r and s are 32-byte big-endian, recovery_id is 0 or 1
@@ -109,19 +163,11 @@ impl CryptoService {
         &self,
         tx_hash: &[u8; 32],
     ) -> Result<([u8; 32], [u8; 32], u8)> {
-        use k256::ecdsa::signature::hazmat::PrehashSigner;
-        let signature: EcdsaSignature = self
-            .ecdsa_signing_key
-            .sign_prehash(tx_hash)
-            .map_err(|e| anyhow::anyhow!("Failed to sign transaction hash: {}", e))?;
-
+        let sig_bytes = self.signer.sign_ecdsa_prehash(tx_hash)?;
+        let signature = EcdsaSignature::from_slice(&sig_bytes)
+            .map_err(|e| anyhow::anyhow!("Invalid ECDSA signature from signer: {}", e))?;
+        let signature = signature.normalize_s().unwrap_or(signature);
         let sig_bytes = signature.to_bytes();
-        if sig_bytes.len() != 64 {
-            return Err(anyhow::anyhow!(
-                "Invalid signature length: expected 64 bytes, got {}",
-                sig_bytes.len()
-            ));
-        }
 
         let mut r = [0u8; 32];
         let mut s = [0u8; 32];
@@ -129,8 +175,7 @@ impl CryptoService {
         s.copy_from_slice(&sig_bytes[32..64]);
 
         // Calculate recovery ID by trying both 0 and 1
-        let verifying_key = self.ecdsa_signing_key.verifying_key();
-        let public_key_point = verifying_key.to_encoded_point(false);
+        let public_key_point = self.ecdsa_verifying_key.to_encoded_point(false);
         let public_key_bytes = public_key_point.as_bytes();
 
         let recovery_id_0 = k256::ecdsa::RecoveryId::try_from(0u8).unwrap();
@@ -155,8 +200,7 @@ impl CryptoService {
     /// The Ethereum address is computed as:
     /// keccak256(uncompressed_public_key)[12:32] (last 20 bytes)
     pub fn get_ethereum_address(&self) -> Result<String> {
-        let verifying_key = self.ecdsa_signing_key.verifying_key();
-        let public_key_point = verifying_key.to_encoded_point(false); // Uncompressed format
+        let public_key_point = self.ecdsa_verifying_key.to_encoded_point(false); // Uncompressed format
         let public_key_bytes = public_key_point.as_bytes();
 
         // Remove the 0x04 prefix (uncompressed point indicator)
@@ -204,3 +248,38 @@ impl CryptoService {
         bs58::encode(self.verifying_key.as_bytes()).into_string()
     }
 }
+
+// The only secret is inside the signer, and every `Signer` wipes its keys on drop.
+impl ZeroizeOnDrop for CryptoService {}
+
+/// Decode a hex secp256k1 private key (optional 0x prefix) loaded from `env_name`.
+fn parse_ecdsa_private_key(value: &str, env_name: &str) -> Result<Zeroizing<[u8; 32]>> {
+    let bytes = Zeroizing::new(
+        hex::decode(value.trim().trim_start_matches("0x"))
+            .map_err(|e| anyhow::anyhow!("Environment variable '{}' is not valid hex: {}", env_name, e))?,
+    );
+    if bytes.len() != 32 {
+        return Err(anyhow::anyhow!(
+            "Invalid ECDSA private key length in '{}': expected 32 bytes, got {}",
+            env_name,
+            bytes.len()
+        ));
+    }
+    let mut key = Zeroizing::new([0u8; 32]);
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+/// Decode a base64 Ed25519 private key (32-byte seed).
+fn decode_ed25519_seed(private_key_b64: &str) -> Result<Zeroizing<[u8; 32]>> {
+    let bytes = Zeroizing::new(general_purpose::STANDARD.decode(private_key_b64)?);
+    if bytes.len() != 32 {
+        return Err(anyhow::anyhow!(
+            "Invalid private key length: expected 32 bytes, got {}",
+            bytes.len()
+        ));
+    }
+    let mut seed = Zeroizing::new([0u8; 32]);
+    seed.copy_from_slice(&bytes);
+    Ok(seed)
+}
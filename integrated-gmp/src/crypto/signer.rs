@@ -0,0 +1,245 @@
+//! Signer Abstraction
+//!
+//! Separates the relay's signing operations from where the key lives. The default
+//! [`InMemorySigner`] holds the key loaded from the environment; [`RemoteSigner`]
+//! forwards each operation to an external signer (e.g. an HSM front-end) so the key
+//! never enters this process.
+//!
+//! ## Remote Signer Protocol
+//!
+//! One JSON request per TCP connection, newline-terminated, answered by one JSON
+//! response line. Byte strings are hex encoded (no 0x prefix).
+//!
+//! | Request | Response |
+//! | ------- | -------- |
+//! | `{"method":"public_keys"}` | `{"ed25519_public_key":"..","ecdsa_public_key":".."}` |
+//! | `{"method":"sign_ed25519","message":".."}` | `{"signature":".."}` (64 bytes) |
+//! | `{"method":"sign_ecdsa_prehash","hash":".."}` | `{"signature":".."}` (64 bytes, r \|\| s) |
+//!
+//! Failures are reported as `{"error":".."}`. The ECDSA public key is the 65-byte
+//! uncompressed secp256k1 point.
+
+use anyhow::{Context, Result};
+use ed25519_dalek::{Signer as _, SigningKey};
+use k256::ecdsa::{Signature as EcdsaSignature, SigningKey as EcdsaSigningKey};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use zeroize::{Zeroizing, ZeroizeOnDrop};
+
+// ============================================================================
+// SIGNER TRAIT
+// ============================================================================
+
+/// Signing operations used by the relay.
+///
+/// Implementations must wipe any key material they hold when dropped.
+pub trait Signer: Send + Sync + ZeroizeOnDrop {
+    /// Ed25519 public key (32 bytes).
+    fn ed25519_public_key(&self) -> Result<[u8; 32]>;
+
+    /// Uncompressed secp256k1 public key (65 bytes, 0x04 prefix).
+    fn ecdsa_public_key(&self) -> Result<[u8; 65]>;
+
+    /// Sign `message` with the Ed25519 key.
+    fn sign_ed25519(&self, message: &[u8]) -> Result<[u8; 64]>;
+
+    /// Sign a 32-byte prehash with the secp256k1 key, returning `r || s`.
+    fn sign_ecdsa_prehash(&self, hash: &[u8; 32]) -> Result<[u8; 64]>;
+}
+
+// ============================================================================
+// IN-MEMORY SIGNER
+// ============================================================================
+
+/// Signer holding the relay keys in process memory.
+///
+/// By default the ECDSA key reuses the Ed25519 seed bytes, so both keys derive from
+/// one secret; [`InMemorySigner::from_keys`] takes an independent ECDSA key instead.
+/// Both signing keys zeroize their secret bytes on drop.
+pub struct InMemorySigner {
+    signing_key: SigningKey,
+    ecdsa_signing_key: EcdsaSigningKey,
+}
+
+impl InMemorySigner {
+    /// Create a signer from a 32-byte Ed25519 seed, deriving the ECDSA key from it.
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Self> {
+        Self::from_keys(seed, seed)
+    }
+
+    /// Create a signer from a 32-byte Ed25519 seed and a separate 32-byte secp256k1
+    /// private key.
+    pub fn from_keys(seed: &[u8; 32], ecdsa_private_key: &[u8; 32]) -> Result<Self> {
+        // Field-element copy of the key, wiped once the signing key is built
+        let ecdsa_secret_bytes = Zeroizing::new(k256::FieldBytes::from(*ecdsa_private_key));
+        let ecdsa_signing_key = EcdsaSigningKey::from_bytes(&ecdsa_secret_bytes)
+            .map_err(|e| anyhow::anyhow!("Failed to create ECDSA signing key: {}", e))?;
+        Ok(Self {
+            signing_key: SigningKey::from_bytes(seed),
+            ecdsa_signing_key,
+        })
+    }
+}
+
+impl ZeroizeOnDrop for InMemorySigner {}
+
+impl Signer for InMemorySigner {
+    fn ed25519_public_key(&self) -> Result<[u8; 32]> {
+        Ok(self.signing_key.verifying_key().to_bytes())
+    }
+
+    fn ecdsa_public_key(&self) -> Result<[u8; 65]> {
+        let point = self.ecdsa_signing_key.verifying_key().to_encoded_point(false);
+        point
+            .as_bytes()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid public key format: expected 65 bytes"))
+    }
+
+    fn sign_ed25519(&self, message: &[u8]) -> Result<[u8; 64]> {
+        Ok(self.signing_key.sign(message).to_bytes())
+    }
+
+    fn sign_ecdsa_prehash(&self, hash: &[u8; 32]) -> Result<[u8; 64]> {
+        use k256::ecdsa::signature::hazmat::PrehashSigner;
+        let signature: EcdsaSignature = self
+            .ecdsa_signing_key
+            .sign_prehash(hash)
+            .map_err(|e| anyhow::anyhow!("Failed to sign transaction hash: {}", e))?;
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&signature.to_bytes());
+        Ok(bytes)
+    }
+}
+
+// ============================================================================
+// REMOTE SIGNER
+// ============================================================================
+
+/// Request sent to a remote signer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteSignerRequest {
+    /// Fetch both public keys
+    PublicKeys,
+    /// Sign a message with the Ed25519 key
+    SignEd25519 { message: String },
+    /// Sign a 32-byte prehash with the secp256k1 key
+    SignEcdsaPrehash { hash: String },
+}
+
+/// Response from a remote signer. Fields not relevant to the request are omitted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RemoteSignerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ed25519_public_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecdsa_public_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Signer that forwards every operation to an external signer over TCP.
+///
+/// Public keys are fetched once at connect time. Signing calls block for at most
+/// the configured timeout.
+pub struct RemoteSigner {
+    addr: String,
+    timeout: Duration,
+    ed25519_public_key: [u8; 32],
+    ecdsa_public_key: [u8; 65],
+}
+
+impl RemoteSigner {
+    /// Connect to the signer at `addr` and fetch its public keys.
+    pub fn connect(addr: &str, timeout: Duration) -> Result<Self> {
+        let mut signer = Self {
+            addr: addr.to_string(),
+            timeout,
+            ed25519_public_key: [0u8; 32],
+            ecdsa_public_key: [0u8; 65],
+        };
+
+        let response = signer
+            .call(&RemoteSignerRequest::PublicKeys)
+            .with_context(|| format!("Failed to fetch public keys from remote signer {}", addr))?;
+        signer.ed25519_public_key = decode_field(response.ed25519_public_key, "ed25519_public_key")?;
+        signer.ecdsa_public_key = decode_field(response.ecdsa_public_key, "ecdsa_public_key")?;
+        Ok(signer)
+    }
+
+    /// Send one request and read its response.
+    fn call(&self, request: &RemoteSignerRequest) -> Result<RemoteSignerResponse> {
+        let socket_addr = self
+            .addr
+            .to_socket_addrs()
+            .with_context(|| format!("Invalid remote signer address {}", self.addr))?
+            .next()
+            .with_context(|| format!("Remote signer address {} did not resolve", self.addr))?;
+
+        let mut stream = TcpStream::connect_timeout(&socket_addr, self.timeout)
+            .with_context(|| format!("Failed to connect to remote signer {}", self.addr))?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        stream.write_all(line.as_bytes())?;
+
+        let mut response_line = String::new();
+        BufReader::new(stream)
+            .read_line(&mut response_line)
+            .context("Failed to read remote signer response")?;
+        let response: RemoteSignerResponse =
+            serde_json::from_str(&response_line).context("Invalid remote signer response")?;
+
+        if let Some(error) = response.error {
+            anyhow::bail!("Remote signer error: {}", error);
+        }
+        Ok(response)
+    }
+
+    fn sign(&self, request: &RemoteSignerRequest) -> Result<[u8; 64]> {
+        decode_field(self.call(request)?.signature, "signature")
+    }
+}
+
+// Holds only public keys; nothing to wipe.
+impl ZeroizeOnDrop for RemoteSigner {}
+
+impl Signer for RemoteSigner {
+    fn ed25519_public_key(&self) -> Result<[u8; 32]> {
+        Ok(self.ed25519_public_key)
+    }
+
+    fn ecdsa_public_key(&self) -> Result<[u8; 65]> {
+        Ok(self.ecdsa_public_key)
+    }
+
+    fn sign_ed25519(&self, message: &[u8]) -> Result<[u8; 64]> {
+        self.sign(&RemoteSignerRequest::SignEd25519 {
+            message: hex::encode(message),
+        })
+    }
+
+    fn sign_ecdsa_prehash(&self, hash: &[u8; 32]) -> Result<[u8; 64]> {
+        self.sign(&RemoteSignerRequest::SignEcdsaPrehash {
+            hash: hex::encode(hash),
+        })
+    }
+}
+
+/// Decode a required hex field of a remote signer response into a fixed-size array.
+fn decode_field<const N: usize>(value: Option<String>, field: &str) -> Result<[u8; N]> {
+    let value = value.with_context(|| format!("Remote signer response missing {}", field))?;
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .with_context(|| format!("Remote signer {} is not valid hex", field))?;
+    let len = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Remote signer {} must be {} bytes, got {}", field, N, len))
+}
@@ -0,0 +1,63 @@
+//! Dead-Letter Store
+//!
+//! Keeps the messages the relay skipped after a permanent delivery error (unknown
+//! remote endpoint, already delivered, intent not found). The relay still advances
+//! past them, but the full message and error are kept so an operator can see what
+//! was skipped and re-attempt it via the status API.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::integrated_gmp_relay::GmpMessage;
+
+/// A message skipped after a permanent delivery error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// The message as read from the source chain
+    pub message: GmpMessage,
+    /// Error from the most recent delivery attempt
+    pub error: String,
+    /// Unix timestamp the message was dead-lettered (or last re-attempted)
+    pub dead_lettered_at: u64,
+}
+
+/// JSON file holding the dead-lettered messages.
+///
+/// Writes go to a temporary file that is renamed into place, so a crash mid-write
+/// leaves the previous entries intact.
+#[derive(Debug, Clone)]
+pub struct DeadLetterFile {
+    path: PathBuf,
+}
+
+impl DeadLetterFile {
+    /// Dead-letter file at `path`. The file is created on the first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Load the saved entries, or none if the file does not exist yet.
+    pub fn load(&self) -> Result<Vec<DeadLetter>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read dead-letter file {}", self.path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse dead-letter file {}", self.path.display()))
+    }
+
+    /// Replace the saved entries.
+    pub fn save(&self, entries: &[DeadLetter]) -> Result<()> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec_pretty(entries)?)
+            .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+        std::fs::rename(&tmp_path, &self.path)
+            .with_context(|| format!("Failed to replace dead-letter file {}", self.path.display()))
+    }
+}
@@ -0,0 +1,677 @@
+//! Chain-Abstracted Message Delivery
+//!
+//! Each destination VM implements [`MessageDeliverer`]: it submits a GMP message and
+//! classifies the result into a [`DeliveryOutcome`]. The relay handles every
+//! destination through the outcome, so the chain-specific idempotency checks and
+//! error strings live with the deliverer. Supporting a new destination VM means
+//! adding one implementation here.
+
+use anyhow::{Context, Result};
+use borsh::BorshSerialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::future::Future;
+use std::str::FromStr;
+use tracing::{debug, error, info};
+
+use crate::crypto::CryptoService;
+use crate::evm_client::GmpEvmClient;
+use crate::integrated_gmp_relay::{
+    hex_to_bytes, is_unconfirmed_delivery, parse_32_byte_address, parse_svm_pubkey, GmpMessage,
+    SvmRelayChainConfig,
+};
+use crate::mvm_client::GmpMvmClient;
+use crate::svm_client::GmpSvmClient;
+
+// Well-known Solana program IDs.
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+const TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Move abort codes that retrying cannot fix.
+const MVM_PERMANENT_ERRORS: &[&str] = &[
+    "E_UNKNOWN_REMOTE_GMP_ENDPOINT",
+    "E_ALREADY_DELIVERED",
+    "E_INTENT_NOT_FOUND",
+];
+
+/// IntentGmp revert reasons that retrying cannot fix.
+const EVM_PERMANENT_ERRORS: &[&str] = &[
+    "E_UNKNOWN_REMOTE_GMP_ENDPOINT",
+    "E_ALREADY_DELIVERED",
+    "E_INTENT_NOT_FOUND",
+];
+
+/// GMP program errors that retrying cannot fix.
+const SVM_PERMANENT_ERRORS: &[&str] = &[
+    "E_UNKNOWN_REMOTE_GMP_ENDPOINT",
+    "AlreadyDelivered",
+    "Already delivered",
+    "E_INTENT_NOT_FOUND",
+];
+
+/// Errors the GMP endpoints raise when the signing key is not an authorized relay
+/// (Move abort code, Solidity custom error, SVM program error and its message).
+const UNAUTHORIZED_RELAY_ERRORS: &[&str] = &[
+    "E_UNAUTHORIZED_RELAY",
+    "UnauthorizedRelay",
+    "not an authorized relay",
+];
+
+/// Errors the GMP endpoints raise while paused by their admin (program log and error message).
+const PAUSED_ERRORS: &[&str] = &["Endpoint is paused", "GMP endpoint is paused"];
+
+// ============================================================================
+// DELIVERY INTERFACE
+// ============================================================================
+
+/// Result of one delivery attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// The message was delivered by this attempt
+    Delivered,
+    /// The destination had already recorded the message; nothing was submitted
+    AlreadyDelivered,
+    /// Retrying cannot succeed (unknown remote endpoint, already delivered, intent
+    /// not found); the message is dead-lettered
+    PermanentFailure(String),
+    /// The attempt failed but may succeed later; the message is retried with backoff
+    TransientFailure(String),
+    /// A transaction was submitted but not confirmed in time; the source cursor holds
+    /// so the message is re-attempted
+    Unconfirmed(String),
+    /// The destination GMP endpoint is paused; the source cursor holds and the
+    /// destination is retried after a backoff, without using the retry budget
+    Paused(String),
+}
+
+impl DeliveryOutcome {
+    /// Whether the relay is done with the message: delivered, or never deliverable.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            DeliveryOutcome::Delivered
+                | DeliveryOutcome::AlreadyDelivered
+                | DeliveryOutcome::PermanentFailure(_)
+        )
+    }
+}
+
+/// Delivers GMP messages to one destination chain.
+pub trait MessageDeliverer {
+    /// Submit `message` to the destination chain and classify the result.
+    fn deliver(&self, message: &GmpMessage) -> impl Future<Output = DeliveryOutcome> + Send;
+
+    /// Whether the destination chain has already recorded `message`, keyed by the
+    /// (intent_id, msg_type) at the start of its payload.
+    fn is_delivered(&self, message: &GmpMessage) -> impl Future<Output = Result<bool>> + Send;
+}
+
+/// Split a message payload into its (intent_id, msg_type) dedup key.
+///
+/// All GMP messages start with msg_type (1 byte) + intent_id (32 bytes). Returns
+/// None for shorter payloads.
+fn delivery_key(message: &GmpMessage) -> Result<Option<([u8; 32], u8)>> {
+    let payload = hex_to_bytes(&message.payload).context("Failed to hex-decode payload")?;
+    if payload.len() < 33 {
+        return Ok(None);
+    }
+    let mut intent_id = [0u8; 32];
+    intent_id.copy_from_slice(&payload[1..33]);
+    Ok(Some((intent_id, payload[0])))
+}
+
+/// Whether a delivery error says the signing key is not an authorized relay on the
+/// destination, so another operator key may succeed where retrying this one cannot.
+pub fn is_unauthorized_relay_error(error: &str) -> bool {
+    UNAUTHORIZED_RELAY_ERRORS.iter().any(|marker| error.contains(marker))
+}
+
+/// Derive the solver's associated token account for an escrow release of `token_mint`.
+///
+/// `token_program` is the owner of the mint account. Only SPL Token and Token-2022 mints
+/// are accepted, since the escrow vault can only be held by one of them.
+pub fn svm_solver_ata(solver: &Pubkey, token_mint: &Pubkey, token_program: &Pubkey) -> Result<Pubkey> {
+    if *token_program != TOKEN_PROGRAM_ID && *token_program != TOKEN_2022_PROGRAM_ID {
+        return Err(anyhow::anyhow!(
+            "Token mint {} is owned by {}, not SPL Token or Token-2022",
+            token_mint,
+            token_program
+        ));
+    }
+    // ATA = PDA([owner, token_program, mint], ASSOCIATED_TOKEN_PROGRAM_ID)
+    let (ata, _) = Pubkey::find_program_address(
+        &[solver.as_ref(), token_program.as_ref(), token_mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+    Ok(ata)
+}
+
+/// Classify a failed delivery: paused endpoint, then the chain's permanent error strings.
+fn classify_error(error: &anyhow::Error, permanent_errors: &[&str]) -> DeliveryOutcome {
+    let error = format!("{:#}", error);
+    if PAUSED_ERRORS.iter().any(|marker| error.contains(marker)) {
+        DeliveryOutcome::Paused(error)
+    } else if permanent_errors.iter().any(|marker| error.contains(marker)) {
+        DeliveryOutcome::PermanentFailure(error)
+    } else {
+        DeliveryOutcome::TransientFailure(error)
+    }
+}
+
+// ============================================================================
+// MVM
+// ============================================================================
+
+/// Delivers to an MVM chain (hub or connected) via `intent_gmp::deliver_message_entry`.
+pub struct MvmDeliverer<'a> {
+    client: &'a GmpMvmClient,
+    operator_private_key: &'a str,
+}
+
+impl<'a> MvmDeliverer<'a> {
+    pub fn new(client: &'a GmpMvmClient, operator_private_key: &'a str) -> Self {
+        Self { client, operator_private_key }
+    }
+
+    /// Classify a failed MVM delivery.
+    ///
+    /// A transaction that was submitted but not seen committed is `Unconfirmed`.
+    pub fn classify_error(error: &anyhow::Error) -> DeliveryOutcome {
+        if is_unconfirmed_delivery(error) {
+            return DeliveryOutcome::Unconfirmed(format!("{:#}", error));
+        }
+        classify_error(error, MVM_PERMANENT_ERRORS)
+    }
+}
+
+impl MessageDeliverer for MvmDeliverer<'_> {
+    /// Submits without a pre-check: a duplicate aborts with `E_ALREADY_DELIVERED`.
+    async fn deliver(&self, message: &GmpMessage) -> DeliveryOutcome {
+        info!(
+            "Delivering message to MVM chain_id={}: nonce={}",
+            self.client.chain_id(), message.nonce
+        );
+        match self.client.deliver_message(message, self.operator_private_key).await {
+            Ok(()) => DeliveryOutcome::Delivered,
+            Err(e) => Self::classify_error(&e),
+        }
+    }
+
+    async fn is_delivered(&self, message: &GmpMessage) -> Result<bool> {
+        let Some((intent_id, msg_type)) = delivery_key(message)? else {
+            return Ok(false);
+        };
+        self.client.is_message_delivered(&intent_id, msg_type).await
+    }
+}
+
+// ============================================================================
+// EVM
+// ============================================================================
+
+/// Delivers to an EVM chain via `IntentGmp.deliverMessage()`.
+pub struct EvmDeliverer<'a> {
+    client: &'a GmpEvmClient,
+    crypto_service: &'a CryptoService,
+}
+
+impl<'a> EvmDeliverer<'a> {
+    pub fn new(client: &'a GmpEvmClient, crypto_service: &'a CryptoService) -> Self {
+        Self { client, crypto_service }
+    }
+
+    /// Classify a failed EVM delivery by its revert reason.
+    pub fn classify_error(error: &anyhow::Error) -> DeliveryOutcome {
+        classify_error(error, EVM_PERMANENT_ERRORS)
+    }
+
+    /// Send the delivery transaction and wait for a successful receipt.
+    async fn submit(&self, message: &GmpMessage) -> Result<()> {
+        let tx_hash = self
+            .client
+            .deliver_message(
+                message.src_chain_id,
+                &message.remote_gmp_endpoint_addr,
+                &message.payload,
+                self.crypto_service,
+            )
+            .await?;
+
+        info!("EVM: waiting for receipt for tx_hash={}", tx_hash);
+        self.client.wait_for_receipt(&tx_hash).await?;
+
+        info!(
+            "EVM deliver_message submitted successfully: nonce={}, tx_hash={}",
+            message.nonce, tx_hash
+        );
+        Ok(())
+    }
+}
+
+impl MessageDeliverer for EvmDeliverer<'_> {
+    /// Checks `isMessageDelivered` first, so a duplicate does not waste gas on a revert.
+    async fn deliver(&self, message: &GmpMessage) -> DeliveryOutcome {
+        info!(
+            "Delivering message to EVM: dst_chain={}, nonce={}",
+            message.dst_chain_id, message.nonce
+        );
+        match self.is_delivered(message).await {
+            Ok(true) => {
+                info!("EVM: message already delivered (nonce={}), skipping", message.nonce);
+                return DeliveryOutcome::AlreadyDelivered;
+            }
+            Ok(false) => {}
+            Err(e) => return Self::classify_error(&e),
+        }
+        match self.submit(message).await {
+            Ok(()) => DeliveryOutcome::Delivered,
+            Err(e) => Self::classify_error(&e),
+        }
+    }
+
+    async fn is_delivered(&self, message: &GmpMessage) -> Result<bool> {
+        let Some((intent_id, msg_type)) = delivery_key(message)? else {
+            return Ok(false);
+        };
+        self.client.is_message_delivered(&intent_id, msg_type).await
+    }
+}
+
+// ============================================================================
+// SVM
+// ============================================================================
+
+/// Delivers to an SVM chain via the GMP program's DeliverMessage instruction.
+pub struct SvmDeliverer<'a> {
+    chain: &'a SvmRelayChainConfig,
+    client: &'a GmpSvmClient,
+    relay_keypair: Keypair,
+}
+
+impl<'a> SvmDeliverer<'a> {
+    pub fn new(chain: &'a SvmRelayChainConfig, client: &'a GmpSvmClient, relay_keypair: Keypair) -> Self {
+        Self { chain, client, relay_keypair }
+    }
+
+    /// Classify a failed SVM delivery by its program error.
+    pub fn classify_error(error: &anyhow::Error) -> DeliveryOutcome {
+        classify_error(error, SVM_PERMANENT_ERRORS)
+    }
+
+    fn gmp_program_id(&self) -> Result<Pubkey> {
+        let program_id = self.chain.gmp_program_id.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("SVM GMP program ID not configured for chain {}", self.chain.chain_id))?;
+        Pubkey::from_str(program_id).context("Invalid SVM GMP program ID")
+    }
+
+    /// Build and submit a DeliverMessage transaction to the GMP program.
+    ///
+    /// For IntentRequirements messages (0x01), also derives and passes the
+    /// outflow-validator accounts needed for GmpReceive CPI.
+    async fn submit(&self, message: &GmpMessage) -> Result<()> {
+        let rpc_url = &self.chain.rpc_url;
+        let program_id = self.gmp_program_id()?;
+        let relay_keypair = &self.relay_keypair;
+        let relay_pubkey = relay_keypair.pubkey();
+
+        // Parse remote GMP endpoint address (32 bytes)
+        let remote_gmp_endpoint_addr = parse_32_byte_address(&message.remote_gmp_endpoint_addr)?;
+
+        // Parse destination address (the receiving program on SVM - e.g., outflow-validator)
+        let dst_program = parse_svm_pubkey(&message.dst_addr)?;
+
+        // Parse payload
+        let payload = hex_to_bytes(&message.payload)?;
+
+        // Derive GMP endpoint PDAs
+        let (config_pda, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+        let (relay_pda, _) =
+            Pubkey::find_program_address(&[b"relay", relay_pubkey.as_ref()], &program_id);
+        let (remote_gmp_endpoint_pda, _) = Pubkey::find_program_address(
+            &[b"remote_gmp_endpoint", &message.src_chain_id.to_le_bytes()],
+            &program_id,
+        );
+        // Derive delivered message PDA from payload (intent_id + msg_type)
+        // All GMP messages: msg_type (1 byte) + intent_id (32 bytes) at the start
+        if payload.len() < 33 {
+            return Err(anyhow::anyhow!("Payload too short to extract intent_id for dedup PDA"));
+        }
+        let msg_type = payload[0];
+        let intent_id = &payload[1..33];
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[b"delivered", intent_id, &[msg_type]],
+            &program_id,
+        );
+        let (routing_pda, _) = Pubkey::find_program_address(&[b"routing"], &program_id);
+
+        // Get outflow_validator program for destination_program_1 (required for routing IntentRequirements)
+        let outflow_program = if let Some(ref outflow_id) = self.chain.outflow_program_id {
+            Pubkey::from_str(outflow_id).context("Invalid SVM outflow program ID")?
+        } else {
+            // If no outflow configured, use dst_program as placeholder (routing won't be used)
+            dst_program
+        };
+
+        // Get intent_escrow program for destination_program_2 (required for routing)
+        let escrow_program = if let Some(ref escrow_id) = self.chain.escrow_program_id {
+            Pubkey::from_str(escrow_id).context("Invalid SVM escrow program ID")?
+        } else {
+            // If no escrow configured, use dst_program as placeholder (routing won't be used)
+            dst_program
+        };
+
+        // Build base accounts for DeliverMessage
+        // Account order (updated for intent_id-based dedup):
+        // 0. Config, 1. Relay, 2. RemoteGmpEndpoint, 3. DeliveredMessage, 4. RelaySigner, 5. Payer
+        // Track if we need to create an ATA before delivering the message (for FulfillmentProof)
+        // Tuple: (ata, owner, mint, token_program, associated_token_program)
+        #[allow(clippy::type_complexity)]
+        let mut ata_create_info: Option<(Pubkey, Pubkey, Pubkey, Pubkey, Pubkey)> = None;
+
+        // 6. SystemProgram, 7. RoutingConfig, 8. DestProgram1, 9. DestProgram2, 10+. Remaining
+        let mut accounts = vec![
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(relay_pda, false), // writable: rate-limit counters
+            AccountMeta::new_readonly(remote_gmp_endpoint_pda, false),
+            AccountMeta::new(delivered_pda, false),
+            AccountMeta::new_readonly(relay_pubkey, true), // signer
+            AccountMeta::new(relay_pubkey, true),          // payer (signer)
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(routing_pda, false), // routing config (may not exist)
+            AccountMeta::new_readonly(outflow_program, false), // destination program 1 (outflow_validator)
+            AccountMeta::new_readonly(escrow_program, false), // destination program 2 (intent_escrow)
+        ];
+
+        // For IntentRequirements (0x01), add accounts for both destination programs' GmpReceive CPI.
+        // The GMP endpoint routes to BOTH outflow_validator AND intent_escrow when routing is configured.
+        //
+        // Account layout for remaining_accounts (passed to GMP endpoint after base accounts):
+        // Indices 0-4: outflow_validator's GmpReceive accounts
+        // Indices 5-9: intent_escrow's GmpReceive accounts
+        //
+        // Each program's GmpReceive expects: requirements(w), config(r), authority(s), payer(s,w), system_program
+        if !payload.is_empty() && payload[0] == 0x01 {
+            // IntentRequirements format: [type(1)] [intent_id(32)] [...]
+            if payload.len() >= 33 {
+                let mut intent_id = [0u8; 32];
+                intent_id.copy_from_slice(&payload[1..33]);
+
+                // Derive outflow-validator PDAs
+                let (outflow_requirements_pda, _) = Pubkey::find_program_address(
+                    &[b"requirements", &intent_id],
+                    &outflow_program,
+                );
+                let (outflow_config_pda, _) = Pubkey::find_program_address(
+                    &[b"config"],
+                    &outflow_program,
+                );
+
+                // Derive intent_escrow PDAs (escrow_program is the intent_escrow)
+                let (escrow_requirements_pda, _) = Pubkey::find_program_address(
+                    &[b"requirements", &intent_id],
+                    &escrow_program,
+                );
+                let (escrow_gmp_config_pda, _) = Pubkey::find_program_address(
+                    &[b"gmp_config"],
+                    &escrow_program,
+                );
+
+                debug!(
+                    "Adding accounts for multi-destination GmpReceive CPI: outflow_req={}, outflow_cfg={}, escrow_req={}, escrow_cfg={}",
+                    outflow_requirements_pda, outflow_config_pda, escrow_requirements_pda, escrow_gmp_config_pda
+                );
+
+                // Accounts for outflow_validator's GmpReceive (indices 0-4)
+                // GmpReceive expects: requirements(w), config(r), authority(s), payer(s,w), system_program
+                accounts.push(AccountMeta::new(outflow_requirements_pda, false));  // 0
+                accounts.push(AccountMeta::new_readonly(outflow_config_pda, false)); // 1
+                accounts.push(AccountMeta::new_readonly(relay_pubkey, true));  // 2: authority (signer)
+                accounts.push(AccountMeta::new(relay_pubkey, true));           // 3: payer (signer)
+                accounts.push(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)); // 4
+
+                // Accounts for intent_escrow's GmpReceive (indices 5-9)
+                // GmpReceive expects: requirements(w), gmp_config(r), authority(s), payer(s,w), system_program
+                accounts.push(AccountMeta::new(escrow_requirements_pda, false));  // 5
+                accounts.push(AccountMeta::new_readonly(escrow_gmp_config_pda, false)); // 6
+                accounts.push(AccountMeta::new_readonly(relay_pubkey, true));  // 7: authority (signer)
+                accounts.push(AccountMeta::new(relay_pubkey, true));           // 8: payer (signer)
+                accounts.push(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)); // 9
+            }
+        } else if !payload.is_empty() && payload[0] == 0x03 {
+            // FulfillmentProof (0x03) - route to intent_escrow only
+            // Payload format: [type(1)] [intent_id(32)] [solver_addr(32)] [amount(8)] [timestamp(8)]
+            if payload.len() >= 65 {
+                let mut intent_id = [0u8; 32];
+                intent_id.copy_from_slice(&payload[1..33]);
+
+                let mut solver_addr = [0u8; 32];
+                solver_addr.copy_from_slice(&payload[33..65]);
+
+                // Derive intent_escrow PDAs
+                let (escrow_requirements_pda, _) = Pubkey::find_program_address(
+                    &[b"requirements", &intent_id],
+                    &escrow_program,
+                );
+                let (escrow_pda, _) = Pubkey::find_program_address(
+                    &[b"escrow", &intent_id],
+                    &escrow_program,
+                );
+                let (vault_pda, _) = Pubkey::find_program_address(
+                    &[b"vault", &intent_id],
+                    &escrow_program,
+                );
+                let (escrow_gmp_config_pda, _) = Pubkey::find_program_address(
+                    &[b"gmp_config"],
+                    &escrow_program,
+                );
+                // The escrow only accepts a proof signed by the GMP endpoint's signer PDA,
+                // which the endpoint adds when it invokes the escrow
+                let (gmp_signer_pda, _) =
+                    Pubkey::find_program_address(&[b"gmp_signer"], &program_id);
+
+                // Read requirements account to get token_addr (mint)
+                let rpc_client_for_read = RpcClient::new_with_commitment(
+                    rpc_url.clone(),
+                    CommitmentConfig::confirmed(),
+                );
+                let requirements_data = rpc_client_for_read
+                    .get_account_data(&escrow_requirements_pda)
+                    .context("Failed to read requirements account for FulfillmentProof")?;
+
+                // Parse token_addr from StoredIntentRequirements
+                // Layout: discriminator(8) + intent_id(32) + requester_addr(32) + amount_required(8) + token_addr(32)
+                // token_addr starts at offset 80
+                if requirements_data.len() < 112 {
+                    return Err(anyhow::anyhow!(
+                        "Requirements account too small: {} bytes",
+                        requirements_data.len()
+                    ));
+                }
+                let mut token_mint_bytes = [0u8; 32];
+                token_mint_bytes.copy_from_slice(&requirements_data[80..112]);
+                let token_mint = Pubkey::new_from_array(token_mint_bytes);
+
+                // Native SOL escrows (all-zero mint) pay the solver wallet via the system
+                // program; token escrows pay the solver's ATA, created if missing
+                let solver_pubkey = Pubkey::new_from_array(solver_addr);
+                let (solver_token, token_program_id) = if token_mint == Pubkey::default() {
+                    (solver_pubkey, SYSTEM_PROGRAM_ID)
+                } else {
+                    // The mint's owner is the token program (SPL Token or Token-2022)
+                    let token_program_id = rpc_client_for_read
+                        .get_account(&token_mint)
+                        .context("Failed to read token mint account for FulfillmentProof")?
+                        .owner;
+                    let solver_ata = svm_solver_ata(&solver_pubkey, &token_mint, &token_program_id)?;
+
+                    // Store ATA creation info for use when building transaction
+                    ata_create_info = Some((solver_ata, solver_pubkey, token_mint, token_program_id, ASSOCIATED_TOKEN_PROGRAM_ID));
+                    (solver_ata, token_program_id)
+                };
+
+                debug!(
+                    "FulfillmentProof accounts: requirements={}, escrow={}, vault={}, solver_token={}, gmp_config={}, token_mint={}",
+                    escrow_requirements_pda, escrow_pda, vault_pda, solver_token, escrow_gmp_config_pda, token_mint
+                );
+
+                // Accounts for intent_escrow's GmpReceiveFulfillmentProof
+                // Expected: requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s by CPI), token_program, [token_mint]
+                accounts.push(AccountMeta::new(escrow_requirements_pda, false));     // 0: requirements (writable)
+                accounts.push(AccountMeta::new(escrow_pda, false));                  // 1: escrow (writable)
+                accounts.push(AccountMeta::new(vault_pda, false));                   // 2: vault (writable)
+                accounts.push(AccountMeta::new(solver_token, false));                // 3: solver_token (writable)
+                accounts.push(AccountMeta::new_readonly(escrow_gmp_config_pda, false)); // 4: gmp_config
+                accounts.push(AccountMeta::new_readonly(gmp_signer_pda, false));     // 5: gmp_caller (endpoint signer PDA)
+                accounts.push(AccountMeta::new_readonly(token_program_id, false));   // 6: token_program
+                if token_mint != Pubkey::default() {
+                    accounts.push(AccountMeta::new_readonly(token_mint, false));     // 7: token_mint (required for Token-2022)
+                }
+            }
+        }
+
+        // Build DeliverMessage instruction
+        let instruction_data = SvmDeliverMessageInstruction {
+            src_chain_id: message.src_chain_id,
+            remote_gmp_endpoint_addr,
+            payload,
+        };
+
+        let deliver_instruction = Instruction {
+            program_id,
+            accounts,
+            data: instruction_data
+                .try_to_vec()
+                .context("Failed to serialize DeliverMessage instruction")?,
+        };
+
+        // Build instructions list - may include ATA creation for FulfillmentProof
+        let mut instructions = Vec::new();
+
+        // If we need to create an ATA (for FulfillmentProof), add that instruction first
+        if let Some((ata, owner, mint, token_program, ata_program)) = ata_create_info {
+            // Build create_associated_token_account_idempotent instruction manually
+            // Instruction data: [1] for idempotent create
+            // Accounts: payer(s,w), ata(w), owner(r), mint(r), system_program(r), token_program(r)
+            let create_ata_ix = Instruction {
+                program_id: ata_program,
+                accounts: vec![
+                    AccountMeta::new(relay_pubkey, true),         // payer (signer, writable)
+                    AccountMeta::new(ata, false),                 // associated token account (writable)
+                    AccountMeta::new_readonly(owner, false),      // wallet owner
+                    AccountMeta::new_readonly(mint, false),       // token mint
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false), // system program
+                    AccountMeta::new_readonly(token_program, false), // token program
+                ],
+                data: vec![1], // 1 = create_idempotent
+            };
+            debug!(
+                "Adding create_associated_token_account_idempotent instruction: ata={}, owner={}, mint={}",
+                ata, owner, mint
+            );
+            instructions.push(create_ata_ix);
+        }
+
+        instructions.push(deliver_instruction);
+
+        // Create RPC client and submit transaction
+        let rpc_client = RpcClient::new_with_commitment(
+            rpc_url.clone(),
+            CommitmentConfig::confirmed(),
+        );
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .context("Failed to get latest blockhash")?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&relay_pubkey),
+            &[relay_keypair],
+            blockhash,
+        );
+
+        let signature = rpc_client
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| {
+                error!(
+                    "SVM DeliverMessage failed: {}. Accounts: config={}, relay={}, remote_gmp_endpoint={}, delivered={}, dst_program={}",
+                    e, config_pda, relay_pda, remote_gmp_endpoint_pda, delivered_pda, dst_program
+                );
+                e
+            })
+            .context("Failed to submit SVM DeliverMessage transaction")?;
+
+        info!(
+            "SVM deliver_message submitted successfully: nonce={}, signature={}",
+            message.nonce, signature
+        );
+
+        Ok(())
+    }
+}
+
+impl MessageDeliverer for SvmDeliverer<'_> {
+    /// Checks the DeliveredMessage PDA first; a check that fails is not fatal, since
+    /// the program rejects duplicates itself.
+    async fn deliver(&self, message: &GmpMessage) -> DeliveryOutcome {
+        info!(
+            "Delivering message to SVM: dst_chain={}, nonce={}",
+            message.dst_chain_id, message.nonce
+        );
+        if let Ok(true) = self.is_delivered(message).await {
+            info!("SVM: message already delivered (nonce={}), skipping", message.nonce);
+            return DeliveryOutcome::AlreadyDelivered;
+        }
+        match self.submit(message).await {
+            Ok(()) => DeliveryOutcome::Delivered,
+            Err(e) => Self::classify_error(&e),
+        }
+    }
+
+    async fn is_delivered(&self, message: &GmpMessage) -> Result<bool> {
+        let Some((intent_id, msg_type)) = delivery_key(message)? else {
+            return Ok(false);
+        };
+        self.client
+            .is_message_delivered(&self.gmp_program_id()?, &intent_id, msg_type)
+            .await
+    }
+}
+
+// ============================================================================
+// SVM INSTRUCTION TYPES
+// ============================================================================
+
+/// SVM DeliverMessage instruction data (matches integrated-gmp-endpoint program).
+///
+/// This is the 6th variant (index 6) in the NativeGmpInstruction enum.
+/// Deduplication uses (intent_id, msg_type) from the payload — no nonce needed.
+#[derive(BorshSerialize)]
+struct SvmDeliverMessageInstruction {
+    src_chain_id: u32,
+    remote_gmp_endpoint_addr: [u8; 32],
+    payload: Vec<u8>,
+}
+
+impl SvmDeliverMessageInstruction {
+    fn try_to_vec(&self) -> Result<Vec<u8>> {
+        // Instruction discriminator: DeliverMessage is variant 6 in the enum
+        // (Initialize=0, AddRelay=1, RemoveRelay=2, SetRemoteGmpEndpointAddr=3, SetRouting=4, Send=5, DeliverMessage=6)
+        let mut data = vec![6u8];
+        data.extend(
+            borsh::to_vec(self).context("Failed to serialize instruction data")?,
+        );
+        Ok(data)
+    }
+}
@@ -0,0 +1,335 @@
+//! Escrow Link Verification Module
+//!
+//! Cross-checks an inflow escrow on a connected chain against the IntentRequirements
+//! the hub sent for the same intent. A mismatch means the two sides disagree on what
+//! is being settled, which usually points at a cross-chain encoding bug.
+//!
+//! Fields checked:
+//! - intent_id: escrow intent ID equals the requirements intent ID (SVM)
+//! - amount: escrowed amount covers `amount_required`
+//! - token: escrow token (SVM mint, or left-padded EVM ERC20 address) equals the
+//!   requirements token address
+//! - solver: escrow reserved solver equals the requirements solver (when one is reserved)
+//! - expiry: escrow expiry equals the hub-provided expiry (SVM)
+//!
+//! SVM and EVM escrows are supported. The EVM contract looks escrows up by intent ID and
+//! does not store the expiry, so those two fields are only checked on SVM. MVM escrows
+//! are not readable from this crate.
+
+use anyhow::{Context, Result};
+use chain_clients_evm::{EvmClient, EvmEscrow};
+use chain_clients_svm::{EscrowAccount, SvmClient};
+use std::fmt;
+
+use crate::config::Config;
+use crate::integrated_gmp_relay::{hex_to_bytes, parse_32_byte_address};
+use crate::mvm_client::GmpMvmClient;
+use crate::trace::{find_hub_outbox_message, DEFAULT_OUTBOX_SCAN_LIMIT, MSG_TYPE_INTENT_REQUIREMENTS};
+
+/// Encoded IntentRequirements payload size (see gmp-common wire format)
+const INTENT_REQUIREMENTS_SIZE: usize = 145;
+
+// ============================================================================
+// REQUIREMENTS PAYLOAD
+// ============================================================================
+
+/// Decoded IntentRequirements payload (message type 0x01).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementsPayload {
+    pub intent_id: [u8; 32],
+    pub requester_addr: [u8; 32],
+    pub amount_required: u64,
+    pub token_addr: [u8; 32],
+    pub solver_addr: [u8; 32],
+    pub expiry: u64,
+}
+
+impl RequirementsPayload {
+    /// Decode an IntentRequirements payload (fixed-width, big-endian).
+    pub fn decode(payload: &[u8]) -> Result<Self> {
+        if payload.len() != INTENT_REQUIREMENTS_SIZE {
+            anyhow::bail!(
+                "IntentRequirements payload must be {} bytes, got {}",
+                INTENT_REQUIREMENTS_SIZE,
+                payload.len()
+            );
+        }
+        if payload[0] != MSG_TYPE_INTENT_REQUIREMENTS {
+            anyhow::bail!("Expected IntentRequirements (0x01), got message type 0x{:02x}", payload[0]);
+        }
+
+        let bytes32 = |start: usize| {
+            let mut out = [0u8; 32];
+            out.copy_from_slice(&payload[start..start + 32]);
+            out
+        };
+        let u64_be = |start: usize| u64::from_be_bytes(payload[start..start + 8].try_into().unwrap());
+
+        Ok(Self {
+            intent_id: bytes32(1),
+            requester_addr: bytes32(33),
+            amount_required: u64_be(65),
+            token_addr: bytes32(73),
+            solver_addr: bytes32(105),
+            expiry: u64_be(137),
+        })
+    }
+}
+
+// ============================================================================
+// COMPARISON
+// ============================================================================
+
+/// A field on which the escrow and the requirements disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkMismatch {
+    /// Field name (intent_id, amount, token, solver, expiry)
+    pub field: &'static str,
+    /// Value from the hub requirements
+    pub requirements: String,
+    /// Value from the connected chain escrow
+    pub escrow: String,
+}
+
+/// Compare an SVM escrow against the requirements the hub sent for it.
+///
+/// Returns an empty list when the pair is consistent.
+pub fn compare_requirements_to_escrow(
+    requirements: &RequirementsPayload,
+    escrow: &EscrowAccount,
+) -> Vec<LinkMismatch> {
+    let mut mismatches = Vec::new();
+
+    if escrow.intent_id != requirements.intent_id {
+        mismatches.push(LinkMismatch {
+            field: "intent_id",
+            requirements: hex_32(&requirements.intent_id),
+            escrow: hex_32(&escrow.intent_id),
+        });
+    }
+
+    if escrow.amount < requirements.amount_required {
+        mismatches.push(LinkMismatch {
+            field: "amount",
+            requirements: format!(">= {}", requirements.amount_required),
+            escrow: escrow.amount.to_string(),
+        });
+    }
+
+    let token_mint = escrow.token_mint.to_bytes();
+    if token_mint != requirements.token_addr {
+        mismatches.push(LinkMismatch {
+            field: "token",
+            requirements: hex_32(&requirements.token_addr),
+            escrow: hex_32(&token_mint),
+        });
+    }
+
+    // A zero solver address means the requirements do not reserve a solver
+    let reserved_solver = escrow.reserved_solver.to_bytes();
+    if requirements.solver_addr != [0u8; 32] && reserved_solver != requirements.solver_addr {
+        mismatches.push(LinkMismatch {
+            field: "solver",
+            requirements: hex_32(&requirements.solver_addr),
+            escrow: hex_32(&reserved_solver),
+        });
+    }
+
+    // The escrow program caps the hub expiry at i64::MAX
+    let expected_expiry = requirements.expiry.min(i64::MAX as u64) as i64;
+    if escrow.expiry != expected_expiry {
+        mismatches.push(LinkMismatch {
+            field: "expiry",
+            requirements: expected_expiry.to_string(),
+            escrow: escrow.expiry.to_string(),
+        });
+    }
+
+    mismatches
+}
+
+/// Compare an EVM escrow against the requirements the hub sent for it.
+///
+/// The amount must cover `amount_required` and the ERC20 token, left-padded to 32 bytes,
+/// must equal the requirements token address; a matching amount in a different token
+/// is still a mismatch. Returns an empty list when the pair is consistent.
+pub fn compare_requirements_to_evm_escrow(
+    requirements: &RequirementsPayload,
+    escrow: &EvmEscrow,
+) -> Result<Vec<LinkMismatch>> {
+    let mut mismatches = Vec::new();
+
+    if escrow.amount < requirements.amount_required {
+        mismatches.push(LinkMismatch {
+            field: "amount",
+            requirements: format!(">= {}", requirements.amount_required),
+            escrow: escrow.amount.to_string(),
+        });
+    }
+
+    let token = parse_32_byte_address(&escrow.token_addr).context("Invalid EVM escrow token address")?;
+    if token != requirements.token_addr {
+        mismatches.push(LinkMismatch {
+            field: "token",
+            requirements: hex_32(&requirements.token_addr),
+            escrow: hex_32(&token),
+        });
+    }
+
+    // A zero solver address means the requirements do not reserve a solver
+    let solver = parse_32_byte_address(&escrow.solver_addr).context("Invalid EVM escrow solver address")?;
+    if requirements.solver_addr != [0u8; 32] && solver != requirements.solver_addr {
+        mismatches.push(LinkMismatch {
+            field: "solver",
+            requirements: hex_32(&requirements.solver_addr),
+            escrow: hex_32(&solver),
+        });
+    }
+
+    Ok(mismatches)
+}
+
+// ============================================================================
+// LINK REPORT
+// ============================================================================
+
+/// Result of cross-checking one intent.
+#[derive(Debug, Clone)]
+pub struct LinkReport {
+    /// Intent ID (0x-prefixed hex)
+    pub intent_id: String,
+    /// Connected chain holding the escrow
+    pub chain_id: u64,
+    /// Hub outbox nonce of the IntentRequirements message
+    pub requirements_nonce: u64,
+    /// Fields on which the escrow and the requirements disagree
+    pub mismatches: Vec<LinkMismatch>,
+}
+
+impl LinkReport {
+    /// True when the escrow matches the requirements on every checked field.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl fmt::Display for LinkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Escrow link check for intent {} (chain {}, hub outbox nonce {})",
+            self.intent_id, self.chain_id, self.requirements_nonce
+        )?;
+        for mismatch in &self.mismatches {
+            writeln!(
+                f,
+                "  [MISMATCH] {}: requirements={} escrow={}",
+                mismatch.field, mismatch.requirements, mismatch.escrow
+            )?;
+        }
+        if self.is_consistent() {
+            write!(f, "Consistent")
+        } else {
+            write!(f, "{} mismatch(es) found", self.mismatches.len())
+        }
+    }
+}
+
+// ============================================================================
+// CHECKER
+// ============================================================================
+
+/// Fetches the hub requirements and the connected chain escrow for an intent and compares them.
+pub struct EscrowLinkChecker {
+    config: Config,
+    outbox_scan_limit: u64,
+}
+
+impl EscrowLinkChecker {
+    /// Create a checker over all chains in the integrated-gmp configuration.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            outbox_scan_limit: DEFAULT_OUTBOX_SCAN_LIMIT,
+        }
+    }
+
+    /// Limit how many of the most recent hub outbox messages are scanned.
+    pub fn with_outbox_scan_limit(mut self, limit: u64) -> Self {
+        self.outbox_scan_limit = limit;
+        self
+    }
+
+    /// Cross-check the escrow for `intent_id` against its hub requirements.
+    ///
+    /// Errors when either side cannot be found (requirements not sent, escrow not yet
+    /// created, or the escrow lives on a chain type that is not supported).
+    pub async fn check(&self, intent_id: &str) -> Result<LinkReport> {
+        let intent_bytes = parse_32_byte_address(intent_id).context("Invalid intent ID")?;
+        let hub = GmpMvmClient::new(
+            &self.config.hub_chain.rpc_url,
+            &self.config.hub_chain.intent_module_addr,
+            self.config.hub_chain.chain_id as u32,
+        )?;
+
+        let message = find_hub_outbox_message(
+            &hub,
+            &intent_bytes,
+            MSG_TYPE_INTENT_REQUIREMENTS,
+            self.outbox_scan_limit,
+        )
+        .await?
+        .with_context(|| {
+            format!(
+                "IntentRequirements not found in the last {} hub outbox messages",
+                self.outbox_scan_limit
+            )
+        })?;
+        let requirements = RequirementsPayload::decode(&hex_to_bytes(&message.payload)?)
+            .context("Failed to decode IntentRequirements from hub outbox")?;
+
+        let dst = message.dst_chain_id as u64;
+        let mismatches = if let Some(svm) = self.config.connected_chain_svm.iter().find(|c| c.chain_id == dst) {
+            let client = SvmClient::new(&svm.rpc_url, &svm.escrow_program_id)?;
+            let escrow = client
+                .get_escrow_by_intent_id(&intent_bytes)
+                .await?
+                .with_context(|| {
+                    format!(
+                        "No escrow for intent on chain {} (escrow PDA {})",
+                        dst,
+                        client.escrow_pda(&intent_bytes)
+                    )
+                })?;
+            compare_requirements_to_escrow(&requirements, &escrow)
+        } else if let Some(evm) = self.config.connected_chain_evm.iter().find(|c| c.chain_id == dst) {
+            let client = EvmClient::new(&evm.rpc_url, &evm.escrow_contract_addr)?;
+            let escrow = client
+                .get_escrow(&hex_32(&intent_bytes))
+                .await?
+                .with_context(|| {
+                    format!(
+                        "No escrow for intent on chain {} (escrow contract {})",
+                        dst, evm.escrow_contract_addr
+                    )
+                })?;
+            compare_requirements_to_evm_escrow(&requirements, &escrow)?
+        } else {
+            anyhow::bail!(
+                "Escrow link check only supports SVM and EVM chains; chain {} is not a configured SVM or EVM chain",
+                dst
+            );
+        };
+
+        Ok(LinkReport {
+            intent_id: hex_32(&intent_bytes),
+            chain_id: dst,
+            requirements_nonce: message.nonce,
+            mismatches,
+        })
+    }
+}
+
+fn hex_32(bytes: &[u8; 32]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
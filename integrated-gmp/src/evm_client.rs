@@ -4,11 +4,12 @@
 //! for message delivery, event polling, and relay authorization checks.
 
 use anyhow::{Context, Result};
-use chain_clients_evm::{EvmClient, EvmLog};
+use chain_clients_evm::{EventDecoder, EvmClient, EvmLog};
 use sha3::{Digest, Keccak256};
 use std::time::Duration;
-use tracing::warn;
+use tracing::{info, warn};
 
+use crate::config::EvmTxType;
 use crate::crypto::CryptoService;
 use crate::integrated_gmp_relay::GmpMessage;
 
@@ -16,11 +17,31 @@ use crate::integrated_gmp_relay::GmpMessage;
 // CLIENT
 // ============================================================================
 
+/// Gas limit used when `eth_estimateGas` fails.
+pub const FALLBACK_GAS_LIMIT: u64 = 2_000_000;
+
+/// Blocks of fee history sampled when pricing an EIP-1559 transaction.
+const FEE_HISTORY_BLOCKS: u64 = 5;
+
+/// Priority fee percentile sampled from each block's fee history.
+const FEE_HISTORY_REWARD_PERCENTILE: f64 = 50.0;
+
+/// A MessageSent log and the block it was read from.
+#[derive(Debug, Clone)]
+pub struct EvmSentMessage {
+    pub message: GmpMessage,
+    pub block_number: u64,
+    /// Block hash reported with the log (None if the node omits it)
+    pub block_hash: Option<String>,
+}
+
 pub struct GmpEvmClient {
     evm_client: EvmClient,
     gmp_endpoint_addr: String,
     chain_id: u32,
     relay_address: String,
+    tx_type: EvmTxType,
+    gas_limit_multiplier: f64,
 }
 
 impl GmpEvmClient {
@@ -37,9 +58,27 @@ impl GmpEvmClient {
             gmp_endpoint_addr: gmp_endpoint_addr.to_string(),
             chain_id,
             relay_address: relay_address.to_string(),
+            tx_type: EvmTxType::Legacy,
+            gas_limit_multiplier: 1.2,
         })
     }
 
+    /// Set the transaction type built for deliveries (default: legacy).
+    pub fn with_tx_type(mut self, tx_type: EvmTxType) -> Self {
+        self.tx_type = tx_type;
+        self
+    }
+
+    pub fn tx_type(&self) -> EvmTxType {
+        self.tx_type
+    }
+
+    /// Set the safety margin applied to gas estimates (default: 1.2).
+    pub fn with_gas_limit_multiplier(mut self, gas_limit_multiplier: f64) -> Self {
+        self.gas_limit_multiplier = gas_limit_multiplier;
+        self
+    }
+
     pub fn chain_id(&self) -> u32 {
         self.chain_id
     }
@@ -48,6 +87,17 @@ impl GmpEvmClient {
         &self.gmp_endpoint_addr
     }
 
+    /// Remote GMP endpoint address the relay sends for messages read from this chain:
+    /// the GMP endpoint contract padded to 32 bytes.
+    pub fn remote_gmp_endpoint_addr(&self) -> String {
+        let clean = self
+            .gmp_endpoint_addr
+            .strip_prefix("0x")
+            .unwrap_or(&self.gmp_endpoint_addr)
+            .to_lowercase();
+        format!("0x{:0>64}", clean)
+    }
+
     pub fn relay_address(&self) -> &str {
         &self.relay_address
     }
@@ -87,6 +137,31 @@ impl GmpEvmClient {
         Ok(clean.ends_with('1'))
     }
 
+    // ========================================================================
+    // Trusted remote GMP endpoints
+    // ========================================================================
+
+    /// Remote GMP endpoint addresses trusted for `src_chain_id`, via
+    /// `getRemoteGmpEndpointAddrs(uint32)`. Empty if none is configured.
+    pub async fn get_remote_gmp_endpoint_addrs(&self, src_chain_id: u32) -> Result<Vec<String>> {
+        let selector = &Keccak256::digest(b"getRemoteGmpEndpointAddrs(uint32)")[..4];
+
+        let mut calldata = Vec::with_capacity(36);
+        calldata.extend_from_slice(selector);
+        let mut chain_id_padded = [0u8; 32];
+        chain_id_padded[28..].copy_from_slice(&src_chain_id.to_be_bytes());
+        calldata.extend_from_slice(&chain_id_padded);
+
+        let data_hex = format!("0x{}", hex::encode(&calldata));
+        let result: String = self
+            .evm_client
+            .eth_call(&self.gmp_endpoint_addr, &data_hex)
+            .await
+            .context("Failed to call getRemoteGmpEndpointAddrs on EVM")?;
+
+        decode_bytes32_array(&result)
+    }
+
     // ========================================================================
     // Message delivery check
     // ========================================================================
@@ -129,8 +204,9 @@ impl GmpEvmClient {
 
     /// Deliver a GMP message to this EVM chain.
     ///
-    /// ABI-encodes `deliverMessage(uint32,bytes32,bytes)`, builds a legacy
-    /// transaction, signs it with the relay's ECDSA key, and broadcasts.
+    /// ABI-encodes `deliverMessage(uint32,bytes32,bytes)`, builds a legacy or
+    /// EIP-1559 transaction (per the chain's `tx_type`), signs it with the relay's
+    /// ECDSA key, and broadcasts.
     /// Returns the transaction hash.
     pub async fn deliver_message(
         &self,
@@ -190,12 +266,19 @@ impl GmpEvmClient {
         from_block: u64,
         to_block: u64,
     ) -> Result<Vec<GmpMessage>> {
-        let event_signature =
-            evm_event_topic("MessageSent(uint32,bytes32,bytes,uint64)");
+        let sent = self.poll_message_sent_logs(from_block, to_block).await?;
+        Ok(sent.into_iter().map(|sent| sent.message).collect())
+    }
 
+    /// Poll MessageSent events with the block each log was read from.
+    pub async fn poll_message_sent_logs(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EvmSentMessage>> {
         let filter = serde_json::json!({
             "address": self.gmp_endpoint_addr,
-            "topics": [event_signature],
+            "topics": [message_sent_decoder().topic()],
             "fromBlock": format!("0x{:x}", from_block),
             "toBlock": format!("0x{:x}", to_block),
         });
@@ -204,14 +287,27 @@ impl GmpEvmClient {
 
         let mut messages = Vec::new();
         for log in &logs {
-            if let Some(msg) = self.parse_message_sent(log) {
-                messages.push(msg);
-            }
+            let Some(message) = self.parse_message_sent(log) else {
+                continue;
+            };
+            let clean = log.block_number.strip_prefix("0x").unwrap_or(&log.block_number);
+            let block_number = u64::from_str_radix(clean, 16)
+                .with_context(|| format!("Invalid blockNumber in MessageSent log: {}", log.block_number))?;
+            messages.push(EvmSentMessage {
+                message,
+                block_number,
+                block_hash: log.block_hash.clone(),
+            });
         }
 
         Ok(messages)
     }
 
+    /// Hash of the canonical block at `block_number`, or None if there is none yet.
+    pub async fn get_block_hash(&self, block_number: u64) -> Result<Option<String>> {
+        self.evm_client.get_block_hash(block_number).await
+    }
+
     // ========================================================================
     // Private helpers
     // ========================================================================
@@ -222,19 +318,16 @@ impl GmpEvmClient {
         calldata: &str,
         crypto_service: &CryptoService,
     ) -> Result<String> {
+        // The signer's own address, which differs from `relay_address` after a failover
+        // to a backup operator key
+        let sender = crypto_service.get_ethereum_address()?;
         let nonce = self
             .evm_client
-            .get_transaction_count(&self.relay_address)
+            .get_transaction_count(&sender)
             .await
             .context("eth_getTransactionCount failed")?;
 
-        let gas_price = self
-            .evm_client
-            .gas_price()
-            .await
-            .context("eth_gasPrice failed")?;
-
-        let gas_limit: u64 = 2_000_000;
+        let gas_limit = self.gas_limit_for(&sender, calldata).await;
 
         let to_hex = self
             .gmp_endpoint_addr
@@ -247,6 +340,84 @@ impl GmpEvmClient {
         let data_bytes =
             hex::decode(calldata_hex).context("Failed to decode EVM calldata")?;
 
+        let signed_tx = match self.tx_type {
+            EvmTxType::Legacy => {
+                let gas_price = self
+                    .evm_client
+                    .gas_price()
+                    .await
+                    .context("eth_gasPrice failed")?;
+                self.sign_legacy_transaction(
+                    nonce,
+                    gas_price,
+                    gas_limit,
+                    to_bytes,
+                    data_bytes,
+                    crypto_service,
+                )?
+            }
+            EvmTxType::Eip1559 => {
+                let (max_priority_fee_per_gas, max_fee_per_gas) = self
+                    .estimate_eip1559_fees()
+                    .await
+                    .context("eth_feeHistory failed")?;
+                Eip1559Transaction {
+                    chain_id: self.chain_id as u64,
+                    nonce,
+                    max_priority_fee_per_gas,
+                    max_fee_per_gas,
+                    gas_limit,
+                    to: to_bytes,
+                    data: data_bytes,
+                }
+                .sign(crypto_service)?
+            }
+        };
+        let raw_tx = format!("0x{}", hex::encode(&signed_tx));
+
+        self.evm_client
+            .send_raw_transaction(&raw_tx)
+            .await
+            .context("eth_sendRawTransaction failed")
+    }
+
+    /// Gas limit for a delivery: `eth_estimateGas` times the configured multiplier,
+    /// or [`FALLBACK_GAS_LIMIT`] if estimation fails.
+    async fn gas_limit_for(&self, sender: &str, calldata: &str) -> u64 {
+        match self
+            .evm_client
+            .estimate_gas(sender, &self.gmp_endpoint_addr, calldata)
+            .await
+        {
+            Ok(estimate) => {
+                let gas_limit = (estimate as f64 * self.gas_limit_multiplier).ceil() as u64;
+                info!(
+                    "EVM (chain_id={}): gas estimate {}, applying limit {} ({}x)",
+                    self.chain_id, estimate, gas_limit, self.gas_limit_multiplier
+                );
+                gas_limit
+            }
+            Err(e) => {
+                warn!(
+                    "EVM (chain_id={}): eth_estimateGas failed, applying fallback limit {}: {:#}",
+                    self.chain_id, FALLBACK_GAS_LIMIT, e
+                );
+                FALLBACK_GAS_LIMIT
+            }
+        }
+    }
+
+    /// Build and sign a legacy (type-0) transaction with EIP-155 replay protection.
+    /// Returns the RLP-encoded signed transaction.
+    fn sign_legacy_transaction(
+        &self,
+        nonce: u64,
+        gas_price: u64,
+        gas_limit: u64,
+        to_bytes: Vec<u8>,
+        data_bytes: Vec<u8>,
+        crypto_service: &CryptoService,
+    ) -> Result<Vec<u8>> {
         // RLP-encode unsigned tx for EIP-155 signing:
         //   [nonce, gasPrice, gasLimit, to, value, data, chainId, 0, 0]
         let unsigned_items: Vec<Vec<u8>> = vec![
@@ -290,127 +461,70 @@ impl GmpEvmClient {
             r_trimmed,
             s_trimmed,
         ];
-        let signed_rlp = rlp_encode_list(&signed_items);
-        let raw_tx = format!("0x{}", hex::encode(&signed_rlp));
+        Ok(rlp_encode_list(&signed_items))
+    }
 
-        self.evm_client
-            .send_raw_transaction(&raw_tx)
-            .await
-            .context("eth_sendRawTransaction failed")
+    /// Price an EIP-1559 transaction from recent fee history.
+    ///
+    /// The priority fee is the median of the sampled blocks' median priority fees; the
+    /// max fee leaves room for the base fee to double before the transaction stops
+    /// being includable.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((max_priority_fee_per_gas, max_fee_per_gas))`
+    async fn estimate_eip1559_fees(&self) -> Result<(u64, u64)> {
+        let history = self
+            .evm_client
+            .fee_history(FEE_HISTORY_BLOCKS, &[FEE_HISTORY_REWARD_PERCENTILE])
+            .await?;
+
+        let next_base_fee = history
+            .base_fee_per_gas
+            .last()
+            .ok_or_else(|| anyhow::anyhow!("eth_feeHistory returned no base fees"))
+            .and_then(|fee| parse_hex_u64(fee))?;
+
+        let mut rewards = history
+            .reward
+            .iter()
+            .filter_map(|block| block.first())
+            .map(|reward| parse_hex_u64(reward))
+            .collect::<Result<Vec<u64>>>()?;
+        rewards.sort_unstable();
+        let max_priority_fee_per_gas = rewards.get(rewards.len() / 2).copied().unwrap_or(0);
+
+        let max_fee_per_gas = next_base_fee
+            .saturating_mul(2)
+            .saturating_add(max_priority_fee_per_gas);
+        Ok((max_priority_fee_per_gas, max_fee_per_gas))
     }
 
-    /// Parse an EVM MessageSent log into a GmpMessage.
+    /// Parse an EVM MessageSent log into a GmpMessage, skipping malformed logs.
     fn parse_message_sent(&self, log: &EvmLog) -> Option<GmpMessage> {
-        if log.topics.len() < 2 {
-            return None;
-        }
-
-        // topics[1] = dstChainId (uint32, padded to 32 bytes)
-        let dst_chain_id_hex = log.topics[1]
-            .strip_prefix("0x")
-            .unwrap_or(&log.topics[1]);
-        let dst_chain_id =
-            match u32::from_str_radix(dst_chain_id_hex.trim_start_matches('0'), 16) {
-                Ok(id) => id,
-                Err(e) => {
-                    warn!(
-                        "Failed to parse EVM MessageSent dstChainId hex '{}': {}",
-                        dst_chain_id_hex, e
-                    );
-                    return None;
-                }
-            };
-
-        // Parse non-indexed data: (bytes32 dstAddr, bytes payload, uint64 nonce)
-        let data = log.data.strip_prefix("0x").unwrap_or(&log.data);
-
-        // Minimum: dstAddr(64) + payloadOffset(64) + nonce(64) + payloadLen(64) = 256 hex chars
-        if data.len() < 256 {
-            warn!(
-                "EVM MessageSent data too short: {} hex chars",
-                data.len()
-            );
-            return None;
-        }
-
-        // Word 0 (0..64): dstAddr (bytes32)
-        let dst_addr = format!("0x{}", &data[0..64]);
-
-        // Word 1 (64..128): offset to payload data
-        let payload_offset_hex = &data[64..128];
-        let payload_offset = match usize::from_str_radix(
-            payload_offset_hex.trim_start_matches('0'),
-            16,
-        ) {
-            Ok(offset) => offset,
+        match self.decode_message_sent(log) {
+            Ok(msg) => Some(msg),
             Err(e) => {
                 warn!(
-                    "Failed to parse EVM MessageSent payload offset hex '{}': {}",
-                    payload_offset_hex, e
+                    "Skipping malformed EVM MessageSent log in tx {}: {:#}",
+                    log.transaction_hash, e
                 );
-                return None;
+                None
             }
-        };
-
-        // Word 2 (128..192): nonce (uint64)
-        let nonce_hex = &data[128..192];
-        let nonce = match u64::from_str_radix(nonce_hex.trim_start_matches('0'), 16) {
-            Ok(n) => n,
-            Err(e) => {
-                warn!(
-                    "Failed to parse EVM MessageSent nonce hex '{}': {}",
-                    nonce_hex, e
-                );
-                return None;
-            }
-        };
-
-        // Payload at offset (in bytes, so offset*2 in hex chars)
-        let payload_start = payload_offset * 2;
-        if data.len() < payload_start + 64 {
-            warn!(
-                "EVM MessageSent data too short for payload at offset {}",
-                payload_offset
-            );
-            return None;
         }
+    }
 
-        // Payload length
-        let payload_len_hex = &data[payload_start..payload_start + 64];
-        let payload_len = match usize::from_str_radix(
-            payload_len_hex.trim_start_matches('0'),
-            16,
-        ) {
-            Ok(len) => len,
-            Err(e) => {
-                warn!(
-                    "Failed to parse EVM MessageSent payload length hex '{}': {}",
-                    payload_len_hex, e
-                );
-                return None;
-            }
-        };
-
-        // Payload data
-        let payload_data_start = payload_start + 64;
-        let payload_data_end = payload_data_start + payload_len * 2;
-        let payload = if payload_len > 0 && data.len() >= payload_data_end {
-            format!("0x{}", &data[payload_data_start..payload_data_end])
-        } else {
-            "0x".to_string()
-        };
-
-        // Source address: GMP endpoint contract padded to 32 bytes
-        let clean = self
-            .gmp_endpoint_addr
-            .strip_prefix("0x")
-            .unwrap_or(&self.gmp_endpoint_addr)
-            .to_lowercase();
-        let gmp_addr = format!("0x{:0>64}", clean);
+    /// Decode an EVM MessageSent log into a GmpMessage.
+    fn decode_message_sent(&self, log: &EvmLog) -> Result<GmpMessage> {
+        let decoded = message_sent_decoder().decode(log)?;
+        let dst_chain_id = u32::try_from(decoded.uint("dstChainId")?)?;
+        let dst_addr = decoded.hex("dstAddr")?;
+        let payload = decoded.hex("payload")?;
+        let nonce = decoded.u64("nonce")?;
 
-        Some(GmpMessage {
+        Ok(GmpMessage {
             src_chain_id: self.chain_id,
-            remote_gmp_endpoint_addr: gmp_addr,
+            remote_gmp_endpoint_addr: self.remote_gmp_endpoint_addr(),
             dst_chain_id,
             dst_addr,
             payload,
@@ -423,11 +537,18 @@ impl GmpEvmClient {
 // FREE FUNCTIONS (moved from integrated_gmp_relay.rs)
 // ============================================================================
 
-/// Compute the Keccak256 topic hash for an EVM event signature.
-fn evm_event_topic(signature: &str) -> String {
-    let mut hasher = Keccak256::new();
-    hasher.update(signature.as_bytes());
-    format!("0x{}", hex::encode(hasher.finalize()))
+/// Decoder for `MessageSent` logs of the GMP endpoint contract.
+fn message_sent_decoder() -> EventDecoder {
+    EventDecoder::new(
+        "MessageSent(uint32,bytes32,bytes,uint64)",
+        &[
+            ("dstChainId", "uint32 indexed"),
+            ("dstAddr", "bytes32"),
+            ("payload", "bytes"),
+            ("nonce", "uint64"),
+        ],
+    )
+    .expect("MessageSent parameters match its signature")
 }
 
 /// ABI-encode a call to `deliverMessage(uint32,bytes32,bytes)`.
@@ -492,8 +613,109 @@ fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>> {
     hex::decode(hex_clean).context("Invalid hex string")
 }
 
+fn parse_hex_u64(hex_str: &str) -> Result<u64> {
+    let clean = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    u64::from_str_radix(clean, 16)
+        .with_context(|| format!("Invalid hex quantity '{}'", hex_str))
+}
+
+/// Decode an ABI-encoded `bytes32[]` return value into 0x-prefixed hex strings.
+fn decode_bytes32_array(result: &str) -> Result<Vec<String>> {
+    let data = hex_to_bytes(result)?;
+    // Layout: offset(32) + length(32) at offset + one 32-byte word per element
+    let word_at = |index: usize| -> Result<&[u8]> {
+        index
+            .checked_add(32)
+            .and_then(|end| data.get(index..end))
+            .ok_or_else(|| anyhow::anyhow!("bytes32[] return value truncated at byte {}", index))
+    };
+    let as_usize = |word: &[u8]| -> Result<usize> {
+        Ok(usize::try_from(u64::from_be_bytes(word[24..].try_into()?))?)
+    };
+    let offset = as_usize(word_at(0)?)?;
+    let len = as_usize(word_at(offset)?)?;
+    (0..len)
+        .map(|i| {
+            let start = i
+                .checked_add(1)
+                .and_then(|n| n.checked_mul(32))
+                .and_then(|n| n.checked_add(offset))
+                .unwrap_or(usize::MAX);
+            word_at(start).map(|item| format!("0x{}", hex::encode(item)))
+        })
+        .collect()
+}
+
+// ============================================================================
+// EIP-1559 TRANSACTIONS
+// ============================================================================
+
+/// EIP-2718 type byte of an EIP-1559 transaction.
+pub const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// An unsigned EIP-1559 (type-2) transaction with zero value and an empty access list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: u64,
+    pub max_fee_per_gas: u64,
+    pub gas_limit: u64,
+    /// Recipient address (20 bytes)
+    pub to: Vec<u8>,
+    /// Calldata
+    pub data: Vec<u8>,
+}
+
+impl Eip1559Transaction {
+    /// RLP items of the unsigned fields:
+    /// `[chainId, nonce, maxPriorityFeePerGas, maxFeePerGas, gasLimit, to, value, data, accessList]`
+    fn rlp_payload(&self) -> Vec<u8> {
+        let items: [Vec<u8>; 8] = [
+            rlp_encode_u64(self.chain_id),
+            rlp_encode_u64(self.nonce),
+            rlp_encode_u64(self.max_priority_fee_per_gas),
+            rlp_encode_u64(self.max_fee_per_gas),
+            rlp_encode_u64(self.gas_limit),
+            self.to.clone(),
+            vec![], // value = 0
+            self.data.clone(),
+        ];
+        let mut payload: Vec<u8> = items.iter().flat_map(|item| rlp_encode_item(item)).collect();
+        payload.push(0xc0); // empty access list
+        payload
+    }
+
+    /// Hash the sender signs: `keccak256(0x02 || rlp(unsigned fields))`.
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update([EIP1559_TX_TYPE]);
+        hasher.update(rlp_wrap_list(self.rlp_payload()));
+        hasher.finalize().into()
+    }
+
+    /// Sign with the relay's ECDSA key.
+    ///
+    /// Returns the raw transaction for `eth_sendRawTransaction`:
+    /// `0x02 || rlp([...unsigned fields, yParity, r, s])`.
+    pub fn sign(&self, crypto_service: &CryptoService) -> Result<Vec<u8>> {
+        let (r, s, recovery_id) = crypto_service
+            .sign_evm_transaction_hash(&self.signing_hash())
+            .context("Failed to sign EVM transaction")?;
+
+        let mut payload = self.rlp_payload();
+        payload.extend(rlp_encode_item(&rlp_encode_u64(recovery_id as u64)));
+        payload.extend(rlp_encode_item(&strip_leading_zeros(&r)));
+        payload.extend(rlp_encode_item(&strip_leading_zeros(&s)));
+
+        let mut raw_tx = vec![EIP1559_TX_TYPE];
+        raw_tx.extend(rlp_wrap_list(payload));
+        Ok(raw_tx)
+    }
+}
+
 // ============================================================================
-// RLP ENCODING HELPERS (for legacy EVM transactions)
+// RLP ENCODING HELPERS (for EVM transactions)
 // ============================================================================
 
 fn strip_leading_zeros(bytes: &[u8]) -> Vec<u8> {
@@ -533,7 +755,11 @@ fn rlp_encode_list(items: &[Vec<u8>]) -> Vec<u8> {
     for item in items {
         payload.extend(rlp_encode_item(item));
     }
+    rlp_wrap_list(payload)
+}
 
+/// Prefix already RLP-encoded items with a list header.
+fn rlp_wrap_list(payload: Vec<u8>) -> Vec<u8> {
     if payload.len() <= 55 {
         let mut out = vec![0xc0 + payload.len() as u8];
         out.extend(payload);
@@ -19,32 +19,32 @@
 
 use anyhow::{Context, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use borsh::BorshSerialize;
 use ed25519_dalek::SigningKey;
 use serde::{Deserialize, Serialize};
 use solana_client::rpc_client::RpcClient;
-use solana_sdk::{
-    commitment_config::CommitmentConfig,
-    instruction::{AccountMeta, Instruction},
-    pubkey::Pubkey,
-    signature::{Keypair, Signer},
-    transaction::Transaction,
-};
-use std::collections::{HashMap, HashSet};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Keypair};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
-use crate::config::Config;
+use crate::alert::{AlertNotifier, EscalationAction, RetryBudgetAlert};
+use crate::config::{Config, EvmTxType, MvmCommitment, MvmSubmission, SvmCommitment};
 use crate::crypto::CryptoService;
-use crate::evm_client::GmpEvmClient;
-use crate::mvm_client::GmpMvmClient;
-use crate::svm_client::GmpSvmClient;
-
-// Well-known Solana program IDs.
-const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+use crate::dead_letter::{DeadLetter, DeadLetterFile};
+use crate::deliverer::{
+    is_unauthorized_relay_error, DeliveryOutcome, EvmDeliverer, MessageDeliverer, MvmDeliverer, SvmDeliverer,
+};
+use crate::evm_client::{EvmSentMessage, GmpEvmClient};
+use crate::metrics::RelayMetrics;
+use crate::mvm_client::{DeliveryNotConfirmed, GmpMvmClient};
+use crate::rate_limit::{DeliveryLimiter, RateLimiter};
+use crate::relay_cursor::{CursorFile, RelayCursors};
+use crate::svm_client::{GmpSvmClient, SvmOutboundMessage};
 
 // ============================================================================
 // CONFIGURATION
@@ -59,6 +59,10 @@ pub struct MvmRelayChainConfig {
     pub module_addr: String,
     /// MVM chain ID
     pub chain_id: u32,
+    /// Whether the relay polls and delivers to this chain
+    pub enabled: bool,
+    /// Polling interval override for this chain (None = relay-wide interval)
+    pub polling_interval_ms: Option<u64>,
 }
 
 /// Per-chain relay configuration for a connected EVM chain.
@@ -72,6 +76,16 @@ pub struct EvmRelayChainConfig {
     pub chain_id: u32,
     /// EVM relay address (the `from` address for eth_sendRawTransaction, must be authorized relay in IntentGmp)
     pub relay_address: String,
+    /// Transaction type built for deliveries to this chain
+    pub tx_type: EvmTxType,
+    /// Safety margin applied to gas estimates for deliveries to this chain
+    pub gas_limit_multiplier: f64,
+    /// Whether the relay polls and delivers to this chain
+    pub enabled: bool,
+    /// Polling interval override for this chain (None = relay-wide interval)
+    pub polling_interval_ms: Option<u64>,
+    /// Blocks a MessageSent log must be buried under before it is relayed (0 = no reorg checks)
+    pub confirmations: u64,
 }
 
 /// Per-chain relay configuration for a connected SVM chain.
@@ -87,6 +101,14 @@ pub struct SvmRelayChainConfig {
     pub outflow_program_id: Option<String>,
     /// SVM chain ID
     pub chain_id: u32,
+    /// Whether the relay polls and delivers to this chain
+    pub enabled: bool,
+    /// Polling interval override for this chain (None = relay-wide interval)
+    pub polling_interval_ms: Option<u64>,
+    /// Scan the outbox with getProgramAccounts at startup
+    pub startup_message_scan: bool,
+    /// Commitment level for outbox and delivered-marker reads
+    pub commitment: SvmCommitment,
 }
 
 /// Configuration for the integrated GMP relay.
@@ -98,6 +120,10 @@ pub struct NativeGmpRelayConfig {
     pub mvm_module_addr: String,
     /// MVM chain ID
     pub mvm_chain_id: u32,
+    /// Whether the relay polls and delivers to the MVM hub chain
+    pub mvm_hub_enabled: bool,
+    /// Polling interval override for the MVM hub chain (None = relay-wide interval)
+    pub mvm_hub_polling_interval_ms: Option<u64>,
     /// Connected MVM chains (each can send/receive GMP messages)
     pub mvm_chains: Vec<MvmRelayChainConfig>,
     /// Connected EVM chains (each can send/receive GMP messages)
@@ -106,14 +132,53 @@ pub struct NativeGmpRelayConfig {
     pub svm_chains: Vec<SvmRelayChainConfig>,
     /// Polling interval in milliseconds
     pub polling_interval_ms: u64,
+    /// Upper bound for a chain's polling interval while it stays idle (None = fixed interval)
+    pub max_idle_polling_interval_ms: Option<u64>,
+    /// Consecutive idle polls before a chain's polling interval starts widening
+    pub idle_polls_before_widening: u32,
+    /// Total failed attempts per operation before escalating
+    pub retry_budget: u32,
+    /// Webhook notified when an operation exhausts its retry budget
+    pub alert_webhook_url: Option<String>,
+    /// Allowed destination chain IDs per source chain ID. Sources without an
+    /// entry may route to any configured destination.
+    pub route_allowlist: HashMap<u32, HashSet<u32>>,
+    /// Maximum outbox RPC reads per second during startup backfill (None = unlimited)
+    pub backfill_max_rps: Option<u32>,
+    /// File the per-chain read cursors are saved to and resumed from (None = memory only)
+    pub cursor_file: Option<String>,
+    /// File permanently failed messages are saved to (None = memory only)
+    pub dead_letter_file: Option<String>,
+    /// Blocks behind the head an EVM chain without a saved cursor is scanned from
+    pub evm_cold_start_blocks: u64,
+    /// Maximum deliveries in flight across all destinations (None = unlimited)
+    pub max_concurrent_deliveries: Option<u32>,
+    /// Verify configured SVM programs exist on their RPC at startup
+    pub check_program_deployment: bool,
+    /// Remove delivered messages from MVM gmp_sender outboxes
+    pub mvm_outbox_cleanup: bool,
+    /// Confirmation level required for MVM deliveries
+    pub mvm_commitment: MvmCommitment,
+    /// Max wait for an MVM delivery to commit, in milliseconds
+    pub mvm_confirmation_timeout_ms: u64,
+    /// Submission path for MVM deliveries
+    pub mvm_submission: MvmSubmission,
+    /// Retry failed native MVM submissions through the aptos CLI
+    pub mvm_cli_fallback: bool,
+    /// Record Prometheus metrics for polls and deliveries
+    pub metrics_enabled: bool,
     /// Relay operator private key (base64 encoded Ed25519)
     pub operator_private_key: String,
+    /// Backup operator private keys (base64 encoded Ed25519), tried in order when the
+    /// primary key is not authorized
+    pub backup_operator_private_keys: Vec<String>,
 }
 
 impl NativeGmpRelayConfig {
     /// Create relay config from main config.
     pub fn from_config(config: &Config) -> Result<Self> {
         let operator_private_key = config.integrated_gmp.get_private_key()?;
+        let backup_operator_private_keys = config.integrated_gmp.get_backup_private_keys()?;
 
         let mvm_chains: Vec<MvmRelayChainConfig> = config
             .connected_chain_mvm
@@ -122,6 +187,8 @@ impl NativeGmpRelayConfig {
                 rpc_url: mvm.rpc_url.clone(),
                 module_addr: mvm.intent_module_addr.clone(),
                 chain_id: mvm.chain_id as u32,
+                enabled: mvm.enabled,
+                polling_interval_ms: mvm.polling_interval_ms,
             })
             .collect();
 
@@ -133,6 +200,11 @@ impl NativeGmpRelayConfig {
                 gmp_endpoint_addr: evm.gmp_endpoint_addr.clone(),
                 chain_id: evm.chain_id as u32,
                 relay_address: evm.approver_evm_pubkey_hash.clone(),
+                tx_type: evm.tx_type,
+                gas_limit_multiplier: evm.gas_limit_multiplier,
+                enabled: evm.enabled,
+                polling_interval_ms: evm.polling_interval_ms,
+                confirmations: evm.confirmations,
             })
             .collect();
 
@@ -145,18 +217,44 @@ impl NativeGmpRelayConfig {
                 escrow_program_id: Some(svm.escrow_program_id.clone()),
                 outflow_program_id: Some(svm.outflow_program_id.clone()),
                 chain_id: svm.chain_id as u32,
+                enabled: svm.enabled,
+                polling_interval_ms: svm.polling_interval_ms,
+                startup_message_scan: svm.startup_message_scan,
+                commitment: svm.svm_commitment,
             })
             .collect();
 
+        let tunables = RelayTunables::from_config(config);
+
         Ok(Self {
             mvm_rpc_url: config.hub_chain.rpc_url.clone(),
             mvm_module_addr: config.hub_chain.intent_module_addr.clone(),
             mvm_chain_id: config.hub_chain.chain_id as u32,
+            mvm_hub_enabled: config.hub_chain.enabled,
+            mvm_hub_polling_interval_ms: config.hub_chain.polling_interval_ms,
             mvm_chains,
             evm_chains,
             svm_chains,
-            polling_interval_ms: config.integrated_gmp.polling_interval_ms,
+            polling_interval_ms: tunables.polling_interval_ms,
+            max_idle_polling_interval_ms: config.integrated_gmp.max_idle_polling_interval_ms,
+            idle_polls_before_widening: config.integrated_gmp.idle_polls_before_widening,
+            retry_budget: tunables.retry_budget,
+            alert_webhook_url: config.integrated_gmp.alert_webhook_url.clone(),
+            route_allowlist: tunables.route_allowlist,
+            backfill_max_rps: config.integrated_gmp.backfill_max_rps,
+            cursor_file: config.integrated_gmp.cursor_file.clone(),
+            dead_letter_file: config.integrated_gmp.dead_letter_file.clone(),
+            evm_cold_start_blocks: config.integrated_gmp.evm_cold_start_blocks,
+            max_concurrent_deliveries: config.integrated_gmp.max_concurrent_deliveries,
+            check_program_deployment: config.integrated_gmp.check_program_deployment,
+            mvm_outbox_cleanup: config.integrated_gmp.mvm_outbox_cleanup,
+            mvm_commitment: config.integrated_gmp.mvm_commitment,
+            mvm_confirmation_timeout_ms: config.integrated_gmp.mvm_confirmation_timeout_ms,
+            mvm_submission: config.integrated_gmp.mvm_submission,
+            mvm_cli_fallback: config.integrated_gmp.mvm_cli_fallback,
+            metrics_enabled: config.metrics.enabled,
             operator_private_key,
+            backup_operator_private_keys,
         })
     }
 
@@ -174,6 +272,90 @@ impl NativeGmpRelayConfig {
     pub fn find_svm_chain(&self, chain_id: u32) -> Option<&SvmRelayChainConfig> {
         self.svm_chains.iter().find(|c| c.chain_id == chain_id)
     }
+
+    /// Check whether a configured chain is enabled.
+    ///
+    /// Returns `None` if the chain ID is not configured at all.
+    pub fn is_chain_enabled(&self, chain_id: u32) -> Option<bool> {
+        if chain_id == self.mvm_chain_id {
+            return Some(self.mvm_hub_enabled);
+        }
+        if let Some(c) = self.find_mvm_chain(chain_id) {
+            return Some(c.enabled);
+        }
+        if let Some(c) = self.find_svm_chain(chain_id) {
+            return Some(c.enabled);
+        }
+        self.find_evm_chain(chain_id).map(|c| c.enabled)
+    }
+
+    /// Check whether messages from `src_chain_id` may be delivered to `dst_chain_id`.
+    ///
+    /// Permissive when the source chain has no allowlist entry.
+    pub fn is_route_allowed(&self, src_chain_id: u32, dst_chain_id: u32) -> bool {
+        route_allowed(&self.route_allowlist, src_chain_id, dst_chain_id)
+    }
+}
+
+/// Relay settings that may change while the relay is running (config reload).
+///
+/// Everything else in [`NativeGmpRelayConfig`] (chains, keys, clients, limiters) is
+/// fixed for the lifetime of the relay and requires a restart to change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayTunables {
+    /// Polling interval in milliseconds
+    pub polling_interval_ms: u64,
+    /// Total failed attempts per operation before escalating
+    pub retry_budget: u32,
+    /// Allowed destination chain IDs per source chain ID
+    pub route_allowlist: HashMap<u32, HashSet<u32>>,
+}
+
+impl RelayTunables {
+    /// Extract the runtime-changeable settings from the main config.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            polling_interval_ms: config.integrated_gmp.polling_interval_ms,
+            retry_budget: config.integrated_gmp.retry_budget,
+            route_allowlist: config
+                .integrated_gmp
+                .route_allowlist
+                .iter()
+                .map(|entry| {
+                    let dsts = entry.allowed_dst_chain_ids.iter().map(|d| *d as u32).collect();
+                    (entry.src_chain_id as u32, dsts)
+                })
+                .collect(),
+        }
+    }
+
+    /// Extract the runtime-changeable settings from a relay config.
+    pub fn from_relay_config(config: &NativeGmpRelayConfig) -> Self {
+        Self {
+            polling_interval_ms: config.polling_interval_ms,
+            retry_budget: config.retry_budget,
+            route_allowlist: config.route_allowlist.clone(),
+        }
+    }
+
+    /// Check whether messages from `src_chain_id` may be delivered to `dst_chain_id`.
+    ///
+    /// Permissive when the source chain has no allowlist entry.
+    pub fn is_route_allowed(&self, src_chain_id: u32, dst_chain_id: u32) -> bool {
+        route_allowed(&self.route_allowlist, src_chain_id, dst_chain_id)
+    }
+}
+
+/// Check `src_chain_id` -> `dst_chain_id` against a route allowlist (permissive for
+/// sources without an entry).
+fn route_allowed(
+    route_allowlist: &HashMap<u32, HashSet<u32>>,
+    src_chain_id: u32,
+    dst_chain_id: u32,
+) -> bool {
+    route_allowlist
+        .get(&src_chain_id)
+        .is_none_or(|allowed| allowed.contains(&dst_chain_id))
 }
 
 // ============================================================================
@@ -197,6 +379,16 @@ pub struct GmpMessage {
     pub nonce: u64,
 }
 
+impl GmpMessage {
+    /// Intent ID carried in the payload (bytes 1..33, after the msg_type byte), as 0x hex.
+    ///
+    /// Returns None for payloads shorter than 33 bytes.
+    pub fn intent_id(&self) -> Option<String> {
+        let hex = self.payload.strip_prefix("0x").unwrap_or(&self.payload);
+        hex.get(2..66).map(|intent_id| format!("0x{}", intent_id.to_lowercase()))
+    }
+}
+
 /// MVM MessageSent event data structure.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize)]
@@ -217,17 +409,30 @@ pub struct MvmMessageSentEvent {
 // DELIVERY RETRY CONFIGURATION
 // ============================================================================
 
-/// Maximum number of delivery attempts per message before permanently skipping
-pub const MAX_DELIVERY_RETRIES: u32 = 3;
-
 /// Initial backoff duration in seconds after first delivery failure (doubles each retry)
 const INITIAL_DELIVERY_BACKOFF_SECS: u64 = 5;
 
+/// Initial wait in seconds before retrying a destination whose GMP endpoint is paused
+/// (doubles while it stays paused)
+const INITIAL_PAUSED_BACKOFF_SECS: u64 = 5;
+
+/// Longest wait in seconds between retries of a paused destination
+const MAX_PAUSED_BACKOFF_SECS: u64 = 300;
+
+/// Wait in seconds between polls of a chain that exhausted its poll retry budget
+const PARKED_POLL_BACKOFF_SECS: u64 = 300;
+
+/// Maximum MVM outbox messages removed per cleanup transaction
+const MVM_CLEANUP_BATCH_SIZE: usize = 50;
+
+/// Scanned EVM block hashes kept per chain for reorg detection
+const EVM_TRACKED_BLOCK_HASHES: usize = 32;
+
 // ============================================================================
 // RELAY STATE
 // ============================================================================
 
-/// Tracks delivery attempts for a single message.
+/// Failure count and backoff state for a single retried operation.
 #[derive(Debug, Clone)]
 pub struct DeliveryAttempt {
     /// Number of failed delivery attempts
@@ -237,11 +442,6 @@ pub struct DeliveryAttempt {
 }
 
 impl DeliveryAttempt {
-    /// Check if max retries have been exhausted.
-    pub fn is_exhausted(&self) -> bool {
-        self.count >= MAX_DELIVERY_RETRIES
-    }
-
     /// Check if this attempt is currently in backoff.
     pub fn is_in_backoff(&self) -> bool {
         let current_time = std::time::SystemTime::now()
@@ -250,26 +450,182 @@ impl DeliveryAttempt {
             .as_secs();
         self.next_retry_after > current_time
     }
+}
 
-    /// Record a failure, returning true if max retries are now exhausted.
-    pub fn record_failure(&mut self) -> bool {
-        self.count += 1;
-        if self.count >= MAX_DELIVERY_RETRIES {
-            return true;
+/// Outcome of recording a failure against a [`RetryBudget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryOutcome {
+    /// Budget not yet exhausted; retry after the backoff
+    Retry { attempt: u32, backoff_secs: u64 },
+    /// Budget exhausted by this failure; the operation is now parked and the
+    /// caller must escalate. Returned exactly once per operation.
+    Escalate { attempts: u32 },
+    /// Operation was already parked; nothing further to do
+    AlreadyParked,
+}
+
+/// Tracks failed attempts per operation against a total retry budget.
+///
+/// Failures back off exponentially. Once an operation's failures reach the budget
+/// it is parked: `should_attempt` returns false until `clear` is called, or, with a
+/// park cool-down, until the cool-down has passed since the last failure.
+#[derive(Debug)]
+pub struct RetryBudget<K> {
+    max_attempts: u32,
+    park_cooldown_secs: Option<u64>,
+    attempts: HashMap<K, DeliveryAttempt>,
+    parked: HashSet<K>,
+}
+
+impl<K: Eq + Hash + Clone> RetryBudget<K> {
+    /// Create a budget allowing `max_attempts` failures per operation (minimum 1).
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            park_cooldown_secs: None,
+            attempts: HashMap::new(),
+            parked: HashSet::new(),
         }
-        // Exponential backoff: INITIAL_DELIVERY_BACKOFF_SECS * 2^(attempt-1)
-        let backoff_secs = INITIAL_DELIVERY_BACKOFF_SECS * 2u64.pow(self.count - 1);
+    }
+
+    /// Let parked operations be attempted again `cooldown_secs` after their last failure.
+    ///
+    /// Escalation still happens once; later failures while parked only restart the cool-down.
+    pub fn with_park_cooldown(mut self, cooldown_secs: u64) -> Self {
+        self.park_cooldown_secs = Some(cooldown_secs);
+        self
+    }
+
+    /// Check if the operation may be attempted now (not parked and not in backoff).
+    pub fn should_attempt(&self, key: &K) -> bool {
+        if self.parked.contains(key) && self.park_cooldown_secs.is_none() {
+            return false;
+        }
+        !self.attempts.get(key).is_some_and(|attempt| attempt.is_in_backoff())
+    }
+
+    /// Check if the operation has been parked after exhausting its budget.
+    pub fn is_parked(&self, key: &K) -> bool {
+        self.parked.contains(key)
+    }
+
+    /// Get the backoff state for an operation, if it has failed before.
+    pub fn attempt(&self, key: &K) -> Option<&DeliveryAttempt> {
+        self.attempts.get(key)
+    }
+
+    /// Record a failed attempt.
+    pub fn record_failure(&mut self, key: K) -> RetryOutcome {
         let current_time = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs();
-        self.next_retry_after = current_time + backoff_secs;
-        false
+
+        if self.parked.contains(&key) {
+            if let (Some(cooldown_secs), Some(attempt)) = (self.park_cooldown_secs, self.attempts.get_mut(&key)) {
+                attempt.count = attempt.count.saturating_add(1);
+                attempt.next_retry_after = current_time.saturating_add(cooldown_secs);
+            }
+            return RetryOutcome::AlreadyParked;
+        }
+
+        let attempt = self.attempts.entry(key.clone()).or_insert(DeliveryAttempt {
+            count: 0,
+            next_retry_after: 0,
+        });
+        attempt.count += 1;
+
+        if attempt.count >= self.max_attempts {
+            let attempts = attempt.count;
+            if let Some(cooldown_secs) = self.park_cooldown_secs {
+                attempt.next_retry_after = current_time.saturating_add(cooldown_secs);
+            }
+            self.parked.insert(key);
+            return RetryOutcome::Escalate { attempts };
+        }
+
+        // Exponential backoff: INITIAL_DELIVERY_BACKOFF_SECS * 2^(attempt-1)
+        let backoff_secs = INITIAL_DELIVERY_BACKOFF_SECS
+            .saturating_mul(2u64.saturating_pow(attempt.count - 1));
+        attempt.next_retry_after = current_time.saturating_add(backoff_secs);
+
+        RetryOutcome::Retry { attempt: attempt.count, backoff_secs }
+    }
+
+    /// Change the number of failures allowed per operation (minimum 1).
+    ///
+    /// Applies to the next recorded failure; operations already parked stay parked.
+    pub fn set_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts.max(1);
+    }
+
+    /// Forget all failures for an operation (including a parked state).
+    pub fn clear(&mut self, key: &K) {
+        self.attempts.remove(key);
+        self.parked.remove(key);
     }
 }
 
+/// A message the relay has attempted but not yet delivered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingMessage {
+    /// Source chain ID
+    pub src_chain_id: u32,
+    /// Destination chain ID
+    pub dst_chain_id: u32,
+    /// Nonce on the source chain
+    pub nonce: u64,
+    /// Unix timestamp of the first failed delivery attempt
+    pub first_seen_at: u64,
+    /// Unix timestamp of the most recent delivery attempt
+    pub last_attempt_at: u64,
+    /// Seconds since the first failed delivery attempt
+    pub age_secs: u64,
+    /// Number of failed delivery attempts
+    pub attempts: u32,
+    /// Error from the most recent delivery attempt
+    pub last_error: String,
+    /// True when the message exhausted its retry budget and is no longer retried
+    pub dead_lettered: bool,
+}
+
+/// Result of re-attempting a dead-lettered message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeadLetterRetry {
+    /// Delivered; removed from the dead-letter store
+    Delivered,
+    /// No dead-lettered message with that source chain and nonce
+    NotFound,
+    /// Delivery failed again; the entry is kept with this error
+    Failed(String),
+}
+
+/// Result of replaying one message in a backfill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackfillResult {
+    /// Delivered to the destination chain by this backfill
+    Delivered,
+    /// Not submitted, for the given reason (already delivered, route not allowed, ...)
+    Skipped(String),
+    /// Delivery failed with this error
+    Failed(String),
+}
+
+/// One message visited by a backfill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillEntry {
+    /// Source chain ID
+    pub src_chain_id: u32,
+    /// Nonce on the source chain
+    pub nonce: u64,
+    /// Destination chain ID (None when the message could not be read)
+    pub dst_chain_id: Option<u32>,
+    /// What happened to the message
+    pub result: BackfillResult,
+}
+
 /// Internal state for tracking processed messages.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct RelayState {
     /// Processed nonces per source chain (chain_id -> set of processed nonces)
     processed_nonces: HashMap<u32, HashSet<u64>>,
@@ -281,11 +637,137 @@ struct RelayState {
     svm_last_nonces: HashMap<u32, u64>,
     /// Last polled EVM block number per chain (chain_id -> block number)
     evm_last_blocks: HashMap<u32, u64>,
-    /// Per-message delivery attempt tracking: (src_chain_id, nonce) -> DeliveryAttempt
-    delivery_attempts: HashMap<(u32, u64), DeliveryAttempt>,
-    /// Per-chain poll failure tracking: chain_name -> DeliveryAttempt
-    /// When a poll fails (RPC unreachable), the chain enters backoff before retrying.
-    chain_poll_failures: HashMap<String, DeliveryAttempt>,
+    /// Hashes of the last blocks of recently scanned EVM ranges, per chain
+    /// (chain_id -> block number -> hash). Only kept for chains with `confirmations` > 0.
+    evm_block_hashes: HashMap<u32, BTreeMap<u64, String>>,
+    /// MVM outbox nonces finished with and awaiting removal (src chain_id -> nonces)
+    mvm_cleanup_pending: HashMap<u32, Vec<u64>>,
+    /// Messages whose last delivery attempt failed, keyed by (src_chain_id, nonce).
+    /// Removed once delivered or permanently skipped.
+    pending_deliveries: HashMap<(u32, u64), PendingMessage>,
+    /// Messages skipped after a permanent delivery error, keyed by (src_chain_id, nonce).
+    dead_letters: HashMap<(u32, u64), DeadLetter>,
+    /// Per-message delivery failure tracking, keyed by (src_chain_id, nonce).
+    /// Messages that exhaust the budget are parked in the dead-letter state.
+    delivery_budget: RetryBudget<(u32, u64)>,
+    /// Per-chain poll failure tracking, keyed by chain name.
+    /// When a poll fails (RPC unreachable), the chain enters backoff before retrying;
+    /// chains that exhaust the budget are paused.
+    poll_budget: RetryBudget<String>,
+    /// Consecutive successful polls without new messages, keyed by chain name.
+    /// Widens the chain's polling interval (see [`adaptive_polling_interval`]).
+    idle_polls: HashMap<String, u32>,
+    /// Destination chains whose GMP endpoint reported it is paused, keyed by chain ID.
+    /// Deliveries to them are held until the backoff ends; removed on the next delivery.
+    paused_destinations: HashMap<u32, DeliveryAttempt>,
+}
+
+impl RelayState {
+    fn new(retry_budget: u32) -> Self {
+        Self {
+            processed_nonces: HashMap::new(),
+            mvm_hub_last_nonce: 0,
+            mvm_connected_last_nonces: HashMap::new(),
+            svm_last_nonces: HashMap::new(),
+            evm_last_blocks: HashMap::new(),
+            evm_block_hashes: HashMap::new(),
+            mvm_cleanup_pending: HashMap::new(),
+            pending_deliveries: HashMap::new(),
+            dead_letters: HashMap::new(),
+            delivery_budget: RetryBudget::new(retry_budget),
+            poll_budget: RetryBudget::new(retry_budget).with_park_cooldown(PARKED_POLL_BACKOFF_SECS),
+            idle_polls: HashMap::new(),
+            paused_destinations: HashMap::new(),
+        }
+    }
+
+    /// Seed the read cursors from ones saved by a previous run.
+    fn restore_cursors(&mut self, cursors: RelayCursors, hub_chain_id: u32) {
+        let mut mvm_last_nonces = cursors.mvm_last_nonces;
+        if let Some(hub_last_nonce) = mvm_last_nonces.remove(&hub_chain_id) {
+            self.mvm_hub_last_nonce = hub_last_nonce;
+        }
+        self.mvm_connected_last_nonces = mvm_last_nonces;
+        self.svm_last_nonces = cursors.svm_last_nonces;
+        self.evm_last_blocks = cursors.evm_last_blocks;
+    }
+
+    /// Snapshot the read cursors for persistence.
+    fn cursors(&self, hub_chain_id: u32) -> RelayCursors {
+        let mut mvm_last_nonces = self.mvm_connected_last_nonces.clone();
+        if self.mvm_hub_last_nonce > 0 {
+            mvm_last_nonces.insert(hub_chain_id, self.mvm_hub_last_nonce);
+        }
+        RelayCursors {
+            mvm_last_nonces,
+            svm_last_nonces: self.svm_last_nonces.clone(),
+            evm_last_blocks: self.evm_last_blocks.clone(),
+        }
+    }
+}
+
+/// Interval before the next poll of a chain that has had `idle_polls` consecutive
+/// polls without new messages.
+///
+/// Stays at `base` for the first `idle_threshold` idle polls, then doubles with each
+/// further idle poll up to `max` (never below `base`). Without a `max` the interval
+/// is fixed at `base`.
+pub fn adaptive_polling_interval(
+    base: Duration,
+    max: Option<Duration>,
+    idle_polls: u32,
+    idle_threshold: u32,
+) -> Duration {
+    let Some(max) = max else {
+        return base;
+    };
+    let widenings = idle_polls.saturating_sub(idle_threshold);
+    if widenings == 0 {
+        return base;
+    }
+    base.saturating_mul(2u32.saturating_pow(widenings)).min(max.max(base))
+}
+
+/// A chain the relay polls for outbound messages, each run in its own task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PollTarget {
+    /// The MVM hub chain
+    MvmHub,
+    /// A connected MVM chain, by chain ID
+    MvmConnected(u32),
+    /// A connected SVM chain, by chain ID
+    Svm(u32),
+    /// A connected EVM chain, by chain ID
+    Evm(u32),
+}
+
+impl PollTarget {
+    /// Key the chain's poll failures are tracked under.
+    pub fn poll_key(&self) -> String {
+        match self {
+            PollTarget::MvmHub => "mvm_hub".to_string(),
+            PollTarget::MvmConnected(chain_id) => format!("mvm_connected_{}", chain_id),
+            PollTarget::Svm(chain_id) => format!("svm_{}", chain_id),
+            PollTarget::Evm(chain_id) => format!("evm_{}", chain_id),
+        }
+    }
+}
+
+/// A relay operator key and the signer built from it.
+struct RelayOperator {
+    /// Base64 Ed25519 private key; signs MVM and SVM deliveries
+    private_key: String,
+    /// Signs EVM deliveries and derives the operator's relay addresses
+    crypto_service: CryptoService,
+}
+
+/// Log label for the operator key at `index` (0 is the primary key).
+fn operator_label(index: usize) -> String {
+    if index == 0 {
+        "primary".to_string()
+    } else {
+        format!("backup {}", index)
+    }
 }
 
 // ============================================================================
@@ -296,7 +778,12 @@ struct RelayState {
 /// and delivers messages to destination chains.
 pub struct NativeGmpRelay {
     config: NativeGmpRelayConfig,
-    crypto_service: CryptoService,
+    /// Primary operator key followed by the configured backups
+    operators: Vec<RelayOperator>,
+    /// Index into `operators` of the key deliveries sign with
+    active_operator: AtomicUsize,
+    /// Serializes failovers so concurrent authorization failures switch keys once
+    failover_lock: Mutex<()>,
     /// Hub MVM client
     mvm_hub_client: GmpMvmClient,
     /// Connected MVM clients keyed by chain ID
@@ -305,24 +792,45 @@ pub struct NativeGmpRelay {
     evm_clients: HashMap<u32, GmpEvmClient>,
     /// Connected SVM clients keyed by chain ID
     svm_clients: HashMap<u32, GmpSvmClient>,
+    /// Escalates operations that exhaust their retry budget
+    alert_notifier: AlertNotifier,
+    /// Throttles outbox reads while catching up on history at startup
+    backfill_limiter: Option<RateLimiter>,
+    /// Caps deliveries in flight across all destinations
+    delivery_limiter: Option<DeliveryLimiter>,
+    /// Prometheus metrics (None when metrics are disabled)
+    metrics: Option<RelayMetrics>,
+    /// Persists read cursors across restarts
+    cursor_file: Option<CursorFile>,
+    /// Persists dead-lettered messages across restarts
+    dead_letter_file: Option<DeadLetterFile>,
+    /// Settings updated in place by config reload
+    tunables: std::sync::RwLock<RelayTunables>,
+    /// Signals the per-chain polling tasks to stop
+    shutdown: watch::Sender<bool>,
     state: Arc<RwLock<RelayState>>,
 }
 
 impl NativeGmpRelay {
     /// Create a new integrated GMP relay.
     pub fn new(config: NativeGmpRelayConfig, crypto_service: CryptoService) -> Result<Self> {
+        let mvm_confirmation_timeout = Duration::from_millis(config.mvm_confirmation_timeout_ms);
         let mvm_hub_client = GmpMvmClient::new(
             &config.mvm_rpc_url,
             &config.mvm_module_addr,
             config.mvm_chain_id,
         )
-        .context("Failed to create MVM hub client")?;
+        .context("Failed to create MVM hub client")?
+        .with_commitment(config.mvm_commitment, mvm_confirmation_timeout)
+        .with_submission(config.mvm_submission, config.mvm_cli_fallback);
 
         // Initialize MVM connected clients
         let mut mvm_connected_clients = HashMap::new();
         for mvm_chain in &config.mvm_chains {
             let client = GmpMvmClient::new(&mvm_chain.rpc_url, &mvm_chain.module_addr, mvm_chain.chain_id)
-                .with_context(|| format!("Failed to create MVM client for chain {}", mvm_chain.chain_id))?;
+                .with_context(|| format!("Failed to create MVM client for chain {}", mvm_chain.chain_id))?
+                .with_commitment(config.mvm_commitment, mvm_confirmation_timeout)
+                .with_submission(config.mvm_submission, config.mvm_cli_fallback);
             mvm_connected_clients.insert(mvm_chain.chain_id, client);
         }
 
@@ -336,7 +844,9 @@ impl NativeGmpRelay {
                     evm_chain.chain_id,
                     &evm_chain.relay_address,
                 )
-                .with_context(|| format!("Failed to create EVM client for chain {}", evm_chain.chain_id))?;
+                .with_context(|| format!("Failed to create EVM client for chain {}", evm_chain.chain_id))?
+                .with_tx_type(evm_chain.tx_type)
+                .with_gas_limit_multiplier(evm_chain.gas_limit_multiplier);
                 evm_clients.insert(evm_chain.chain_id, client);
             }
         }
@@ -346,43 +856,147 @@ impl NativeGmpRelay {
         for svm_chain in &config.svm_chains {
             if let Some(ref program_id) = svm_chain.gmp_program_id {
                 let client = GmpSvmClient::new(&svm_chain.rpc_url, program_id)
-                    .with_context(|| format!("Failed to create SVM client for chain {}", svm_chain.chain_id))?;
+                    .with_context(|| format!("Failed to create SVM client for chain {}", svm_chain.chain_id))?
+                    .with_commitment(svm_chain.commitment);
                 svm_clients.insert(svm_chain.chain_id, client);
             }
         }
 
+        let alert_notifier = AlertNotifier::new(config.alert_webhook_url.clone());
+        let backfill_limiter = config.backfill_max_rps.map(RateLimiter::new);
+        let delivery_limiter = config.max_concurrent_deliveries.map(DeliveryLimiter::new);
+        let metrics = if config.metrics_enabled {
+            Some(RelayMetrics::new().context("Failed to register relay metrics")?)
+        } else {
+            None
+        };
+        let mut state = RelayState::new(config.retry_budget);
+        let cursor_file = config.cursor_file.as_ref().map(CursorFile::new);
+        if let Some(cursor_file) = &cursor_file {
+            let cursors = cursor_file.load()?;
+            info!(
+                "Resuming from saved relay cursors: MVM={:?}, SVM={:?}, EVM blocks={:?}",
+                cursors.mvm_last_nonces, cursors.svm_last_nonces, cursors.evm_last_blocks
+            );
+            state.restore_cursors(cursors, config.mvm_chain_id);
+        }
+        let dead_letter_file = config.dead_letter_file.as_ref().map(DeadLetterFile::new);
+        if let Some(dead_letter_file) = &dead_letter_file {
+            let entries = dead_letter_file.load()?;
+            if !entries.is_empty() {
+                warn!("{} dead-lettered message(s) loaded from a previous run", entries.len());
+            }
+            state.dead_letters = entries
+                .into_iter()
+                .map(|entry| ((entry.message.src_chain_id, entry.message.nonce), entry))
+                .collect();
+        }
+        let tunables = std::sync::RwLock::new(RelayTunables::from_relay_config(&config));
+
+        let mut operators = vec![RelayOperator {
+            private_key: config.operator_private_key.clone(),
+            crypto_service,
+        }];
+        for (i, private_key) in config.backup_operator_private_keys.iter().enumerate() {
+            let crypto_service = CryptoService::from_private_key(private_key)
+                .with_context(|| format!("Invalid backup operator key {}", i + 1))?;
+            operators.push(RelayOperator {
+                private_key: private_key.clone(),
+                crypto_service,
+            });
+        }
+
         Ok(Self {
             config,
-            crypto_service,
+            operators,
+            active_operator: AtomicUsize::new(0),
+            failover_lock: Mutex::new(()),
             mvm_hub_client,
             mvm_connected_clients,
             evm_clients,
             svm_clients,
-            state: Arc::new(RwLock::new(RelayState::default())),
+            alert_notifier,
+            backfill_limiter,
+            delivery_limiter,
+            metrics,
+            cursor_file,
+            dead_letter_file,
+            tunables,
+            shutdown: watch::channel(false).0,
+            state: Arc::new(RwLock::new(state)),
         })
     }
 
-    /// Check relay authorization on all configured destination chains at startup.
+    /// Select the relay operator key at startup.
+    ///
+    /// Tries the primary key, then each backup key in order, and activates the first
+    /// one authorized on all enabled destination chains (see
+    /// `check_operator_authorization`). Fails if no key is authorized, with the last
+    /// key's error. Then warns about destinations that do not trust the remote GMP
+    /// endpoint address the relay sends (see `check_remote_gmp_endpoints`).
+    pub async fn check_authorization(&self) -> Result<()> {
+        let mut last_error = None;
+        for (index, operator) in self.operators.iter().enumerate() {
+            match self.check_operator_authorization(&operator.crypto_service).await {
+                Ok(()) => {
+                    self.active_operator.store(index, Ordering::SeqCst);
+                    info!(
+                        "Relay operator key {} active (MVM={})",
+                        operator_label(index),
+                        operator.crypto_service.get_move_address()?
+                    );
+                    self.check_remote_gmp_endpoints().await;
+                    return Ok(());
+                }
+                Err(e) if index + 1 < self.operators.len() => {
+                    warn!(
+                        "Relay operator key {} is not usable, trying the next key: {:#}",
+                        operator_label(index),
+                        e
+                    );
+                    last_error = Some(e);
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.expect("the primary operator key is always configured"))
+    }
+
+    /// Index of the operator key deliveries currently sign with (0 is the primary key).
+    pub fn active_operator_index(&self) -> usize {
+        self.active_operator.load(Ordering::SeqCst)
+    }
+
+    /// Check that the operator key behind `crypto_service` is authorized on all enabled
+    /// destination chains.
     ///
-    /// Queries each chain's GMP endpoint to verify this relay operator is authorized.
-    /// Fails fast if any chain reports the relay is NOT authorized.
-    async fn check_authorization(&self) -> Result<()> {
-        let mvm_addr = self.crypto_service.get_move_address()?;
-        let evm_addr = self.crypto_service.get_ethereum_address()?;
-        let svm_addr = self.crypto_service.get_solana_address();
+    /// Queries each chain's GMP endpoint and fails fast if any chain reports the relay
+    /// is NOT authorized. Disabled chains are skipped.
+    async fn check_operator_authorization(&self, crypto_service: &CryptoService) -> Result<()> {
+        let mvm_addr = crypto_service.get_move_address()?;
+        let evm_addr = crypto_service.get_ethereum_address()?;
+        let svm_addr = crypto_service.get_solana_address();
 
         info!("Relay addresses: MVM={}, EVM={}, SVM={}", mvm_addr, evm_addr, svm_addr);
 
         // Check MVM hub
-        let authorized = self.mvm_hub_client.is_relay_authorized(&mvm_addr).await
-            .context("Failed to check relay authorization on MVM hub")?;
-        if !authorized {
-            anyhow::bail!("Relay {} is NOT authorized on MVM hub. Run add_relay first.", mvm_addr);
+        if self.config.mvm_hub_enabled {
+            let authorized = self.mvm_hub_client.is_relay_authorized(&mvm_addr).await
+                .context("Failed to check relay authorization on MVM hub")?;
+            if !authorized {
+                anyhow::bail!("Relay {} is NOT authorized on MVM hub. Run add_relay first.", mvm_addr);
+            }
+            info!("MVM hub: relay {} authorized", mvm_addr);
+        } else {
+            info!("MVM hub: disabled, skipping authorization check");
         }
-        info!("MVM hub: relay {} authorized", mvm_addr);
 
-        // Check all connected MVM chains
-        for (chain_id, client) in &self.mvm_connected_clients {
+        // Check all enabled connected MVM chains
+        for mvm_chain in self.config.mvm_chains.iter().filter(|c| c.enabled) {
+            let chain_id = &mvm_chain.chain_id;
+            let Some(client) = self.mvm_connected_clients.get(chain_id) else {
+                continue;
+            };
             let authorized = client.is_relay_authorized(&mvm_addr).await
                 .with_context(|| format!("Failed to check relay authorization on MVM chain {}", chain_id))?;
             if !authorized {
@@ -391,8 +1005,12 @@ impl NativeGmpRelay {
             info!("MVM connected (chain_id={}): relay {} authorized", chain_id, mvm_addr);
         }
 
-        // Check all connected EVM chains
-        for (chain_id, client) in &self.evm_clients {
+        // Check all enabled connected EVM chains
+        for evm_chain in self.config.evm_chains.iter().filter(|c| c.enabled) {
+            let chain_id = &evm_chain.chain_id;
+            let Some(client) = self.evm_clients.get(chain_id) else {
+                continue;
+            };
             let authorized = client.is_relay_authorized(&evm_addr).await
                 .with_context(|| format!("Failed to check relay authorization on EVM chain {}", chain_id))?;
             if !authorized {
@@ -404,15 +1022,184 @@ impl NativeGmpRelay {
             info!("EVM (chain_id={}): relay {} authorized", chain_id, evm_addr);
         }
 
-        // Check all connected SVM chains
-        for svm_chain in &self.config.svm_chains {
+        // Check all enabled connected SVM chains
+        for svm_chain in self.config.svm_chains.iter().filter(|c| c.enabled) {
             if let Some(ref program_id_str) = svm_chain.gmp_program_id {
                 self.check_svm_relay_auth(&svm_chain.rpc_url, program_id_str, &svm_addr)
                     .await?;
             }
         }
 
-        info!("Relay authorization verified on all configured chains");
+        info!("Relay authorization verified on all enabled chains");
+        Ok(())
+    }
+
+    /// Switch deliveries to the next operator key authorized on all enabled chains.
+    ///
+    /// Called when a delivery signed with the key at `failed_index` is rejected as an
+    /// unauthorized relay. Keys are tried in configured order after the failed one,
+    /// wrapping around. Keeps the current key when no other key is authorized; the
+    /// delivery is retried as usual either way.
+    async fn fail_over_operator(&self, failed_index: usize) {
+        let _failover = self.failover_lock.lock().await;
+        if self.active_operator.load(Ordering::SeqCst) != failed_index {
+            // Another delivery already switched keys
+            return;
+        }
+
+        let count = self.operators.len();
+        for offset in 1..count {
+            let index = (failed_index + offset) % count;
+            match self.check_operator_authorization(&self.operators[index].crypto_service).await {
+                Ok(()) => {
+                    self.active_operator.store(index, Ordering::SeqCst);
+                    warn!(
+                        "Relay operator key {} lost authorization, failed over to key {}",
+                        operator_label(failed_index),
+                        operator_label(index)
+                    );
+                    return;
+                }
+                Err(e) => warn!(
+                    "Relay operator key {} cannot take over: {:#}",
+                    operator_label(index),
+                    e
+                ),
+            }
+        }
+        error!(
+            "Relay operator key {} lost authorization and no other key is authorized on all enabled chains",
+            operator_label(failed_index)
+        );
+    }
+
+    /// Warn about destination chains that would reject the relay's remote GMP endpoint address.
+    ///
+    /// For each enabled, allowed route between the hub and a connected chain, reads the remote
+    /// GMP endpoint the destination trusts for the source chain and compares it with the
+    /// address the relay sends: the module address for MVM sources, the GMP endpoint contract
+    /// padded to 32 bytes for EVM sources. A mismatch makes every delivery on the route fail
+    /// as an unknown remote GMP endpoint. SVM sources are not checked, since the address comes
+    /// from the outbound message account. Returns the warnings it logged.
+    pub async fn check_remote_gmp_endpoints(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.config.mvm_hub_enabled {
+            return warnings;
+        }
+        let hub_chain_id = self.config.mvm_chain_id;
+        let hub_addr = self.mvm_hub_client.remote_gmp_endpoint_addr();
+
+        for mvm_chain in self.config.mvm_chains.iter().filter(|c| c.enabled) {
+            let chain_id = mvm_chain.chain_id;
+            let Some(client) = self.mvm_connected_clients.get(&chain_id) else {
+                continue;
+            };
+            if self.is_route_allowed(hub_chain_id, chain_id) {
+                let trusted = client.get_remote_gmp_endpoint_addrs(hub_chain_id).await;
+                warnings.extend(remote_gmp_endpoint_warning(hub_chain_id, chain_id, &hub_addr, trusted));
+            }
+            if self.is_route_allowed(chain_id, hub_chain_id) {
+                let trusted = self.mvm_hub_client.get_remote_gmp_endpoint_addrs(chain_id).await;
+                let sent = client.remote_gmp_endpoint_addr();
+                warnings.extend(remote_gmp_endpoint_warning(chain_id, hub_chain_id, &sent, trusted));
+            }
+        }
+
+        for evm_chain in self.config.evm_chains.iter().filter(|c| c.enabled) {
+            let chain_id = evm_chain.chain_id;
+            let Some(client) = self.evm_clients.get(&chain_id) else {
+                continue;
+            };
+            if self.is_route_allowed(hub_chain_id, chain_id) {
+                let trusted = client.get_remote_gmp_endpoint_addrs(hub_chain_id).await;
+                warnings.extend(remote_gmp_endpoint_warning(hub_chain_id, chain_id, &hub_addr, trusted));
+            }
+            if self.is_route_allowed(chain_id, hub_chain_id) {
+                let trusted = self.mvm_hub_client.get_remote_gmp_endpoint_addrs(chain_id).await;
+                let sent = client.remote_gmp_endpoint_addr();
+                warnings.extend(remote_gmp_endpoint_warning(chain_id, hub_chain_id, &sent, trusted));
+            }
+        }
+
+        for svm_chain in self.config.svm_chains.iter().filter(|c| c.enabled) {
+            let chain_id = svm_chain.chain_id;
+            let (Some(client), Some(program_id_str)) =
+                (self.svm_clients.get(&chain_id), svm_chain.gmp_program_id.as_ref())
+            else {
+                continue;
+            };
+            if !self.is_route_allowed(hub_chain_id, chain_id) {
+                continue;
+            }
+            let endpoint = match Pubkey::from_str(program_id_str) {
+                Ok(program_id) => client.get_remote_gmp_endpoint(&program_id, hub_chain_id).await,
+                Err(e) => Err(anyhow::anyhow!("Invalid SVM GMP program ID: {}", e)),
+            };
+            let endpoint = match endpoint {
+                Ok(Some(endpoint)) => endpoint,
+                // No endpoint configured, or the read failed
+                other => {
+                    let trusted = other.map(|_| Vec::new());
+                    warnings.extend(remote_gmp_endpoint_warning(hub_chain_id, chain_id, &hub_addr, trusted));
+                    continue;
+                }
+            };
+            let trusted = Ok(vec![format!("0x{}", hex::encode(endpoint.addr))]);
+            warnings.extend(remote_gmp_endpoint_warning(hub_chain_id, chain_id, &hub_addr, trusted));
+            // A message type with its own trusted address fails on its own
+            for (msg_type, addr) in &endpoint.type_overrides {
+                let trusted = [format!("0x{}", hex::encode(addr))];
+                if let Some(reason) = remote_gmp_endpoint_mismatch(&hub_addr, &trusted) {
+                    let warning = format!(
+                        "Remote GMP endpoint mismatch: chain {} rejects message type {} from chain {}: {}",
+                        chain_id, msg_type, hub_chain_id, reason
+                    );
+                    warn!("{}", warning);
+                    warnings.push(warning);
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Check that the configured programs are deployed on each enabled SVM chain.
+    ///
+    /// Issues a getAccountInfo for the GMP endpoint, escrow, and outflow program IDs.
+    /// An RPC pointed at the wrong network or a fresh ledger returns no account, and
+    /// the relay would otherwise keep polling and silently find nothing.
+    pub async fn check_program_deployment(&self) -> Result<()> {
+        for svm_chain in self.config.svm_chains.iter().filter(|c| c.enabled) {
+            let chain_id = svm_chain.chain_id;
+            let Some(client) = self.svm_clients.get(&chain_id) else {
+                continue;
+            };
+
+            let programs = [
+                ("GMP endpoint", svm_chain.gmp_program_id.as_ref()),
+                ("escrow", svm_chain.escrow_program_id.as_ref()),
+                ("outflow validator", svm_chain.outflow_program_id.as_ref()),
+            ];
+            for (label, program_id_str) in programs {
+                let Some(program_id_str) = program_id_str else {
+                    continue;
+                };
+                let program_id = Pubkey::from_str(program_id_str)
+                    .with_context(|| format!("Invalid SVM {} program ID", label))?;
+                let exists = client
+                    .account_exists(&program_id)
+                    .await
+                    .with_context(|| format!("Failed to look up SVM {} program on chain {}", label, chain_id))?;
+                if !exists {
+                    anyhow::bail!(
+                        "SVM {} program {} not found on chain {} ({}). Check that the RPC points at the network the program is deployed to.",
+                        label, program_id_str, chain_id, svm_chain.rpc_url
+                    );
+                }
+                info!("SVM (chain_id={}): {} program {} deployed", chain_id, label, program_id_str);
+            }
+        }
+
         Ok(())
     }
 
@@ -462,185 +1249,541 @@ impl NativeGmpRelay {
     // FAILURE TRACKING
     // ========================================================================
 
-    /// Check if a message should be skipped (max retries exhausted or in backoff).
-    /// Returns true if the message should be delivered, false if it should be skipped.
-    async fn should_attempt_delivery(&self, src_chain_id: u32, nonce: u64) -> bool {
-        let state = self.state.read().await;
-        let key = (src_chain_id, nonce);
-        if let Some(attempt) = state.delivery_attempts.get(&key) {
-            if attempt.count >= MAX_DELIVERY_RETRIES {
-                return false; // Already permanently skipped
-            }
-            let current_time = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs();
-            if attempt.next_retry_after > current_time {
-                debug!(
-                    "Skipping delivery for ({}, nonce={}): in backoff (retry after {}, now {})",
-                    src_chain_id, nonce, attempt.next_retry_after, current_time
-                );
-                return false; // In backoff period
+    /// Whether deliveries to `dst_chain_id` are on hold because its GMP endpoint is paused.
+    ///
+    /// Nothing is submitted while the backoff runs. When it ends, an SVM destination's
+    /// config account is read first: if the endpoint is still paused the backoff is
+    /// extended without sending a transaction. Otherwise the next delivery goes ahead.
+    async fn is_destination_paused(&self, dst_chain_id: u32) -> bool {
+        let in_backoff = match self.state.read().await.paused_destinations.get(&dst_chain_id) {
+            Some(attempt) => attempt.is_in_backoff(),
+            None => return false,
+        };
+        if in_backoff {
+            return true;
+        }
+
+        let program_id = self
+            .config
+            .find_svm_chain(dst_chain_id)
+            .and_then(|svm_chain| svm_chain.gmp_program_id.as_deref())
+            .and_then(|program_id| parse_svm_pubkey(program_id).ok());
+        let (Some(client), Some(program_id)) = (self.svm_clients.get(&dst_chain_id), program_id) else {
+            return false;
+        };
+        match client.is_paused(&program_id).await {
+            Ok(true) => {
+                self.mark_destination_paused(dst_chain_id).await;
+                true
+            }
+            Ok(false) => false,
+            Err(e) => {
+                warn!("SVM (chain_id={}): failed to read GMP pause state: {:#}", dst_chain_id, e);
+                false
             }
         }
-        true
     }
 
-    /// Record a transient delivery failure. Returns true if max retries now exhausted.
-    async fn record_delivery_failure(&self, message: &GmpMessage, error: &str) -> bool {
+    /// Update the pause backoff of `dst_chain_id` after a delivery attempt.
+    async fn track_destination_pause(&self, dst_chain_id: u32, outcome: &DeliveryOutcome) {
+        if let DeliveryOutcome::Paused(_) = outcome {
+            self.mark_destination_paused(dst_chain_id).await;
+        } else if matches!(outcome, DeliveryOutcome::Delivered | DeliveryOutcome::AlreadyDelivered)
+            && self.state.write().await.paused_destinations.remove(&dst_chain_id).is_some()
+        {
+            info!("Destination chain {} GMP endpoint unpaused, deliveries resumed", dst_chain_id);
+        }
+    }
+
+    /// Hold deliveries to `dst_chain_id`, doubling the wait each time it is still
+    /// paused, up to `MAX_PAUSED_BACKOFF_SECS`. Does not use any message's retry budget.
+    async fn mark_destination_paused(&self, dst_chain_id: u32) {
         let mut state = self.state.write().await;
-        let key = (message.src_chain_id, message.nonce);
-        let attempt = state.delivery_attempts.entry(key).or_insert(DeliveryAttempt {
+        let attempt = state.paused_destinations.entry(dst_chain_id).or_insert(DeliveryAttempt {
             count: 0,
             next_retry_after: 0,
         });
-
-        let exhausted = attempt.record_failure();
-
-        if exhausted {
-            error!(
-                "Message permanently failed after {} attempts: src_chain={}, nonce={}, dst_chain={}, payload_len={}. Last error: {}",
-                attempt.count, message.src_chain_id, message.nonce,
-                message.dst_chain_id, message.payload.len(), error
-            );
-        } else {
-            let backoff_secs = INITIAL_DELIVERY_BACKOFF_SECS * 2u64.pow(attempt.count - 1);
+        attempt.count += 1;
+        let backoff_secs = INITIAL_PAUSED_BACKOFF_SECS
+            .saturating_mul(2u64.saturating_pow(attempt.count - 1))
+            .min(MAX_PAUSED_BACKOFF_SECS);
+        attempt.next_retry_after = current_timestamp().saturating_add(backoff_secs);
+        if attempt.count == 1 {
             warn!(
-                "Delivery attempt {}/{} failed for src_chain={}, nonce={}. Next retry after {}s. Error: {}",
-                attempt.count, MAX_DELIVERY_RETRIES,
-                message.src_chain_id, message.nonce, backoff_secs, error
+                "Destination chain {} GMP endpoint is paused, holding deliveries to it",
+                dst_chain_id
             );
         }
-
-        exhausted
+        debug!("Destination chain {} still paused, next check in {}s", dst_chain_id, backoff_secs);
     }
 
-    /// Check if a chain's poll should be skipped due to recent failures.
-    /// Returns true if the chain is ready to be polled.
-    async fn should_poll_chain(&self, chain_name: &str) -> bool {
+    /// Check if a message should be skipped (dead-lettered or in backoff).
+    /// Returns true if the message should be delivered, false if it should be skipped.
+    async fn should_attempt_delivery(&self, src_chain_id: u32, nonce: u64) -> bool {
         let state = self.state.read().await;
-        if let Some(attempt) = state.chain_poll_failures.get(chain_name) {
-            if attempt.is_in_backoff() {
+        let key = (src_chain_id, nonce);
+        if state.delivery_budget.should_attempt(&key) {
+            return true;
+        }
+        if let Some(attempt) = state.delivery_budget.attempt(&key) {
+            if !state.delivery_budget.is_parked(&key) {
                 debug!(
-                    "Skipping {} poll: in backoff (retry after {})",
-                    chain_name, attempt.next_retry_after
+                    "Skipping delivery for ({}, nonce={}): in backoff (retry after {})",
+                    src_chain_id, nonce, attempt.next_retry_after
                 );
-                return false;
             }
         }
-        true
+        false
     }
 
-    /// Record a chain poll failure with backoff. Resets on next successful poll.
-    async fn record_chain_poll_failure(&self, chain_name: &str, error: &str) {
-        let mut state = self.state.write().await;
-        let attempt = state.chain_poll_failures.entry(chain_name.to_string()).or_insert(DeliveryAttempt {
-            count: 0,
-            next_retry_after: 0,
-        });
-        attempt.record_failure();
-        let backoff_secs = INITIAL_DELIVERY_BACKOFF_SECS * 2u64.pow(attempt.count.saturating_sub(1));
-        warn!(
-            "{} poll failed (attempt {}). Next poll after {}s. Error: {}",
-            chain_name, attempt.count, backoff_secs, error
-        );
+    /// Record a transient delivery failure. Returns true if the retry budget is now
+    /// exhausted, in which case the message is dead-lettered and an alert is escalated.
+    async fn record_delivery_failure(&self, message: &GmpMessage, error: &str) -> bool {
+        let outcome = {
+            let mut state = self.state.write().await;
+            state.delivery_budget.record_failure((message.src_chain_id, message.nonce))
+        };
+
+        match outcome {
+            RetryOutcome::Retry { attempt, backoff_secs } => {
+                warn!(
+                    "Delivery attempt {}/{} failed for src_chain={}, nonce={}. Next retry after {}s. Error: {}",
+                    attempt, self.tunables().retry_budget,
+                    message.src_chain_id, message.nonce, backoff_secs, error
+                );
+                false
+            }
+            RetryOutcome::Escalate { attempts } => {
+                self.mark_pending_dead_lettered(message).await;
+                error!(
+                    "Message permanently failed after {} attempts: src_chain={}, nonce={}, dst_chain={}, payload_len={}. Last error: {}",
+                    attempts, message.src_chain_id, message.nonce,
+                    message.dst_chain_id, message.payload.len(), error
+                );
+                self.alert_notifier
+                    .escalate(&RetryBudgetAlert {
+                        service: "integrated-gmp".to_string(),
+                        operation: format!(
+                            "deliver src_chain={} nonce={} dst_chain={}",
+                            message.src_chain_id, message.nonce, message.dst_chain_id
+                        ),
+                        attempts,
+                        action: EscalationAction::DeadLettered,
+                        last_error: error.to_string(),
+                    })
+                    .await;
+                true
+            }
+            RetryOutcome::AlreadyParked => true,
+        }
+    }
+
+    /// Check if a chain's poll should be skipped due to recent failures.
+    /// Returns true if the chain is ready to be polled.
+    async fn should_poll_chain(&self, chain_name: &str) -> bool {
+        let state = self.state.read().await;
+        let key = chain_name.to_string();
+        if state.poll_budget.should_attempt(&key) {
+            return true;
+        }
+        if state.poll_budget.is_parked(&key) {
+            debug!(
+                "Skipping {} poll: retry budget exhausted, next poll in {}s at most",
+                chain_name, PARKED_POLL_BACKOFF_SECS
+            );
+        } else if let Some(attempt) = state.poll_budget.attempt(&key) {
+            debug!(
+                "Skipping {} poll: in backoff (retry after {})",
+                chain_name, attempt.next_retry_after
+            );
+        }
+        false
+    }
+
+    /// Record a chain poll failure with backoff. Resets on next successful poll.
+    /// A chain that exhausts its retry budget escalates an alert once and is then
+    /// polled every `PARKED_POLL_BACKOFF_SECS` until a poll succeeds.
+    async fn record_chain_poll_failure(&self, chain_name: &str, error: &str) {
+        let outcome = {
+            let mut state = self.state.write().await;
+            state.poll_budget.record_failure(chain_name.to_string())
+        };
+
+        match outcome {
+            RetryOutcome::Retry { attempt, backoff_secs } => {
+                warn!(
+                    "{} poll failed (attempt {}). Next poll after {}s. Error: {}",
+                    chain_name, attempt, backoff_secs, error
+                );
+            }
+            RetryOutcome::Escalate { attempts } => {
+                self.alert_notifier
+                    .escalate(&RetryBudgetAlert {
+                        service: "integrated-gmp".to_string(),
+                        operation: format!("poll {}", chain_name),
+                        attempts,
+                        action: EscalationAction::Paused,
+                        last_error: error.to_string(),
+                    })
+                    .await;
+            }
+            RetryOutcome::AlreadyParked => {
+                warn!(
+                    "{} poll failed again after exhausting its retry budget. Next poll after {}s. Error: {}",
+                    chain_name, PARKED_POLL_BACKOFF_SECS, error
+                );
+            }
+        }
     }
 
     /// Clear chain poll failure tracking after a successful poll.
     async fn clear_chain_poll_failure(&self, chain_name: &str) {
         let mut state = self.state.write().await;
-        state.chain_poll_failures.remove(chain_name);
+        let key = chain_name.to_string();
+        if state.poll_budget.is_parked(&key) {
+            info!("{} poll succeeded after exhausting its retry budget, resuming", chain_name);
+        }
+        state.poll_budget.clear(&key);
+    }
+
+    /// Current runtime-changeable settings.
+    pub fn tunables(&self) -> RelayTunables {
+        self.tunables.read().unwrap().clone()
+    }
+
+    /// Relay-wide polling interval, used by chains without their own override.
+    pub fn polling_interval(&self) -> Duration {
+        Duration::from_millis(self.tunables.read().unwrap().polling_interval_ms)
+    }
+
+    /// Interval a chain's polling task sleeps between polls.
+    ///
+    /// A chain with `polling_interval_ms` set keeps it across config reloads; the
+    /// others follow the (reloadable) relay-wide interval.
+    pub fn chain_polling_interval(&self, target: PollTarget) -> Duration {
+        let override_ms = match target {
+            PollTarget::MvmHub => self.config.mvm_hub_polling_interval_ms,
+            PollTarget::MvmConnected(chain_id) => self
+                .config
+                .find_mvm_chain(chain_id)
+                .and_then(|c| c.polling_interval_ms),
+            PollTarget::Svm(chain_id) => self
+                .config
+                .find_svm_chain(chain_id)
+                .and_then(|c| c.polling_interval_ms),
+            PollTarget::Evm(chain_id) => self
+                .config
+                .find_evm_chain(chain_id)
+                .and_then(|c| c.polling_interval_ms),
+        };
+        override_ms
+            .map(Duration::from_millis)
+            .unwrap_or_else(|| self.polling_interval())
+    }
+
+    /// Interval a chain's polling task sleeps before its next poll: the chain's
+    /// polling interval, widened while the chain has been idle (see
+    /// [`adaptive_polling_interval`]).
+    pub async fn next_poll_interval(&self, target: PollTarget) -> Duration {
+        adaptive_polling_interval(
+            self.chain_polling_interval(target),
+            self.config.max_idle_polling_interval_ms.map(Duration::from_millis),
+            self.idle_polls(target).await,
+            self.config.idle_polls_before_widening,
+        )
     }
 
-    /// Start the relay service (blocking).
-    pub async fn run(&self) -> Result<()> {
+    /// Consecutive successful polls of a chain that found no new messages.
+    pub async fn idle_polls(&self, target: PollTarget) -> u32 {
+        self.state
+            .read()
+            .await
+            .idle_polls
+            .get(&target.poll_key())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Check whether the current route allowlist permits `src_chain_id` -> `dst_chain_id`.
+    fn is_route_allowed(&self, src_chain_id: u32, dst_chain_id: u32) -> bool {
+        self.tunables.read().unwrap().is_route_allowed(src_chain_id, dst_chain_id)
+    }
+
+    /// Replace the runtime-changeable settings.
+    ///
+    /// The new polling interval takes effect after each chain's current sleep (chains
+    /// with their own `polling_interval_ms` keep it); the new retry budget applies to
+    /// the next recorded failure.
+    pub async fn apply_tunables(&self, tunables: RelayTunables) {
+        {
+            let mut state = self.state.write().await;
+            state.delivery_budget.set_max_attempts(tunables.retry_budget);
+            state.poll_budget.set_max_attempts(tunables.retry_budget);
+        }
+        *self.tunables.write().unwrap() = tunables;
+    }
+
+    /// Start the relay service (blocking until `shutdown` is called).
+    ///
+    /// After the startup checks, each enabled chain is polled in its own task on its
+    /// own interval, sharing the relay state.
+    pub async fn run(self: Arc<Self>) -> Result<()> {
         info!(
             "Starting integrated GMP relay: MVM hub chain_id={}, polling_interval={}ms",
             self.config.mvm_chain_id, self.config.polling_interval_ms
         );
 
+        if !self.config.mvm_hub_enabled {
+            warn!("MVM hub chain is disabled: chain_id={}", self.config.mvm_chain_id);
+        }
+
         for mvm_chain in &self.config.mvm_chains {
-            info!("MVM connected chain configured: chain_id={}", mvm_chain.chain_id);
+            info!("MVM connected chain configured: chain_id={}, enabled={}", mvm_chain.chain_id, mvm_chain.enabled);
         }
 
         for svm_chain in &self.config.svm_chains {
-            info!("SVM chain configured: chain_id={}", svm_chain.chain_id);
+            info!("SVM chain configured: chain_id={}, enabled={}", svm_chain.chain_id, svm_chain.enabled);
         }
 
         for evm_chain in &self.config.evm_chains {
-            info!("EVM chain configured: chain_id={}", evm_chain.chain_id);
+            info!("EVM chain configured: chain_id={}, enabled={}", evm_chain.chain_id, evm_chain.enabled);
+        }
+
+        // Verify the configured programs exist before trusting (possibly empty) RPC results
+        if self.config.check_program_deployment {
+            self.check_program_deployment().await?;
         }
 
         // Verify relay is authorized on all destination chains before starting
         self.check_authorization().await?;
 
-        let interval = Duration::from_millis(self.config.polling_interval_ms);
+        self.reconcile_svm_outboxes().await;
 
-        loop {
-            // Poll MVM hub for MessageSent events
-            if self.should_poll_chain("mvm_hub").await {
-                match self.poll_mvm_events().await {
-                    Ok(()) => self.clear_chain_poll_failure("mvm_hub").await,
-                    Err(e) => self.record_chain_poll_failure("mvm_hub", &format!("{:#}", e)).await,
+        let targets = self.poll_targets();
+        if targets.is_empty() {
+            warn!("No enabled chains to poll; waiting for shutdown");
+            let mut shutdown_rx = self.shutdown.subscribe();
+            while !*shutdown_rx.borrow_and_update() {
+                if shutdown_rx.changed().await.is_err() {
+                    break;
                 }
             }
+        }
 
-            // Poll all connected MVM chains for MessageSent events
-            for mvm_chain in &self.config.mvm_chains {
-                let poll_key = format!("mvm_connected_{}", mvm_chain.chain_id);
-                if self.should_poll_chain(&poll_key).await {
-                    match self.poll_mvm_connected_events(mvm_chain).await {
-                        Ok(()) => self.clear_chain_poll_failure(&poll_key).await,
-                        Err(e) => self.record_chain_poll_failure(&poll_key, &format!("{:#}", e)).await,
-                    }
-                }
+        let pollers: Vec<_> = targets
+            .into_iter()
+            .map(|target| tokio::spawn(self.clone().run_chain_poller(target)))
+            .collect();
+
+        for result in futures::future::join_all(pollers).await {
+            if let Err(e) = result {
+                error!("Chain polling task ended abnormally: {}", e);
             }
+        }
 
-            // Poll all connected SVM chains for outbound messages
-            for svm_chain in &self.config.svm_chains {
-                let poll_key = format!("svm_{}", svm_chain.chain_id);
-                if self.should_poll_chain(&poll_key).await {
-                    match self.poll_svm_events(svm_chain).await {
-                        Ok(()) => self.clear_chain_poll_failure(&poll_key).await,
-                        Err(e) => self.record_chain_poll_failure(&poll_key, &format!("{:#}", e)).await,
-                    }
-                }
+        self.save_cursors().await;
+        info!("Integrated GMP relay stopped");
+        Ok(())
+    }
+
+    /// Signal every chain polling task to stop, so `run` returns.
+    ///
+    /// A poll in progress finishes first; tasks stop instead of sleeping until their
+    /// next poll.
+    pub fn shutdown(&self) {
+        self.shutdown.send_replace(true);
+    }
+
+    /// Poll one chain on its own interval until shutdown.
+    ///
+    /// Runs as a separate task per chain, so a slow or unreachable chain only delays
+    /// its own polls.
+    async fn run_chain_poller(self: Arc<Self>, target: PollTarget) {
+        let mut shutdown_rx = self.shutdown.subscribe();
+        info!(
+            "Polling {} every {}ms",
+            target.poll_key(),
+            self.chain_polling_interval(target).as_millis()
+        );
+
+        while !*shutdown_rx.borrow_and_update() {
+            self.poll_chain(target).await;
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.next_poll_interval(target).await) => {}
+                _ = shutdown_rx.changed() => {}
             }
+        }
 
-            // Poll all connected EVM chains for MessageSent events
-            for evm_chain in &self.config.evm_chains {
-                let poll_key = format!("evm_{}", evm_chain.chain_id);
-                if self.should_poll_chain(&poll_key).await {
-                    match self.poll_evm_events(evm_chain).await {
-                        Ok(()) => self.clear_chain_poll_failure(&poll_key).await,
-                        Err(e) => self.record_chain_poll_failure(&poll_key, &format!("{:#}", e)).await,
-                    }
+        debug!("Stopped polling {}", target.poll_key());
+    }
+
+    /// Enabled chains to poll, in hub, connected MVM, SVM, EVM order.
+    pub fn poll_targets(&self) -> Vec<PollTarget> {
+        let mut targets = Vec::new();
+        if self.config.mvm_hub_enabled {
+            targets.push(PollTarget::MvmHub);
+        }
+        targets.extend(
+            self.config
+                .mvm_chains
+                .iter()
+                .filter(|c| c.enabled)
+                .map(|c| PollTarget::MvmConnected(c.chain_id)),
+        );
+        targets.extend(
+            self.config
+                .svm_chains
+                .iter()
+                .filter(|c| c.enabled)
+                .map(|c| PollTarget::Svm(c.chain_id)),
+        );
+        targets.extend(
+            self.config
+                .evm_chains
+                .iter()
+                .filter(|c| c.enabled)
+                .map(|c| PollTarget::Evm(c.chain_id)),
+        );
+        targets
+    }
+
+    /// Chain a backfill of `chain_type` ("mvm", "evm" or "svm") applies to.
+    ///
+    /// Without `chain_id`, "mvm" means the hub chain, and "evm"/"svm" the only
+    /// configured chain of that type.
+    pub fn backfill_target(&self, chain_type: &str, chain_id: Option<u32>) -> Result<PollTarget> {
+        let (configured, make_target): (Vec<u32>, fn(u32) -> PollTarget) = match chain_type {
+            "mvm" => match chain_id {
+                None => return Ok(PollTarget::MvmHub),
+                Some(id) if id == self.config.mvm_chain_id => return Ok(PollTarget::MvmHub),
+                Some(_) => (
+                    self.config.mvm_chains.iter().map(|c| c.chain_id).collect(),
+                    PollTarget::MvmConnected,
+                ),
+            },
+            "evm" => (self.config.evm_chains.iter().map(|c| c.chain_id).collect(), PollTarget::Evm),
+            "svm" => (self.config.svm_chains.iter().map(|c| c.chain_id).collect(), PollTarget::Svm),
+            other => anyhow::bail!("Unknown chain type '{}', expected mvm, evm or svm", other),
+        };
+
+        match chain_id {
+            Some(id) if configured.contains(&id) => Ok(make_target(id)),
+            Some(id) => anyhow::bail!("No {} chain with chain ID {} is configured", chain_type, id),
+            None => match configured.as_slice() {
+                [id] => Ok(make_target(*id)),
+                [] => anyhow::bail!("No {} chain is configured", chain_type),
+                _ => anyhow::bail!(
+                    "Several {} chains are configured ({:?}); pass --chain-id",
+                    chain_type,
+                    configured
+                ),
+            },
+        }
+    }
+
+    /// Run one poll pass over every enabled chain, one chain after another.
+    ///
+    /// Poll failures are recorded per chain (with backoff) rather than returned,
+    /// so one unreachable chain does not stop the others from being polled.
+    pub async fn poll_all_chains(&self) {
+        for target in self.poll_targets() {
+            self.poll_chain(target).await;
+        }
+    }
+
+    /// Poll a single chain for outbound messages, unless it is in backoff or paused.
+    ///
+    /// Failures are recorded against the chain's retry budget rather than returned.
+    /// Successful polls without new messages count towards widening the chain's
+    /// polling interval.
+    #[tracing::instrument(name = "poll", skip_all, fields(chain = %target.poll_key()))]
+    pub async fn poll_chain(&self, target: PollTarget) {
+        let poll_key = target.poll_key();
+        if !self.should_poll_chain(&poll_key).await {
+            return;
+        }
+
+        let started = Instant::now();
+        let result = match target {
+            PollTarget::MvmHub => self.poll_mvm_events().await,
+            PollTarget::MvmConnected(chain_id) => match self.config.find_mvm_chain(chain_id) {
+                Some(chain) => self.poll_mvm_connected_events(chain).await,
+                None => return,
+            },
+            PollTarget::Svm(chain_id) => match self.config.find_svm_chain(chain_id) {
+                Some(chain) => self.poll_svm_events(chain).await,
+                None => return,
+            },
+            PollTarget::Evm(chain_id) => match self.config.find_evm_chain(chain_id) {
+                Some(chain) => self.poll_evm_events(chain).await,
+                None => return,
+            },
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics.record_poll(&poll_key, started.elapsed(), result.is_err());
+        }
+
+        match result {
+            Ok(active) => {
+                self.clear_chain_poll_failure(&poll_key).await;
+                self.record_chain_poll_activity(&poll_key, active).await;
+            }
+            Err(e) => self.record_chain_poll_failure(&poll_key, &format!("{:#}", e)).await,
+        }
+    }
+
+    /// Count a successful poll as idle, or reset the idle count when it found messages.
+    async fn record_chain_poll_activity(&self, chain_name: &str, active: bool) {
+        let mut state = self.state.write().await;
+        if active {
+            if let Some(idle_polls) = state.idle_polls.remove(chain_name) {
+                if idle_polls > self.config.idle_polls_before_widening {
+                    debug!(
+                        "Chain {} active again after {} idle polls, resuming base polling interval",
+                        chain_name, idle_polls
+                    );
                 }
             }
+        } else {
+            *state.idle_polls.entry(chain_name.to_string()).or_insert(0) += 1;
+        }
+    }
 
-            tokio::time::sleep(interval).await;
+    /// Wait for the backfill rate limiter, if one is configured.
+    async fn throttle_backfill(&self) {
+        if let Some(limiter) = &self.backfill_limiter {
+            limiter.acquire().await;
         }
     }
 
     /// Poll MVM hub outbox for new messages via view functions.
-    async fn poll_mvm_events(&self) -> Result<()> {
+    ///
+    /// Returns whether the outbox had new messages.
+    async fn poll_mvm_events(&self) -> Result<bool> {
         let last_nonce = {
             self.state.read().await.mvm_hub_last_nonce
         };
 
-        let new_last = self
+        let Some(new_last) = self
             .poll_mvm_outbox(&self.mvm_hub_client, last_nonce, "hub")
-            .await?;
+            .await?
+        else {
+            return Ok(false);
+        };
 
         if new_last > last_nonce {
             self.state.write().await.mvm_hub_last_nonce = new_last;
+            self.save_cursors().await;
         }
 
-        Ok(())
+        Ok(true)
     }
 
     /// Poll a connected MVM chain outbox for new messages via view functions.
-    async fn poll_mvm_connected_events(&self, mvm_chain: &MvmRelayChainConfig) -> Result<()> {
+    ///
+    /// Returns whether the outbox had new messages.
+    async fn poll_mvm_connected_events(&self, mvm_chain: &MvmRelayChainConfig) -> Result<bool> {
         let client = self.mvm_connected_clients.get(&mvm_chain.chain_id)
             .ok_or_else(|| anyhow::anyhow!("No MVM client for chain {}", mvm_chain.chain_id))?;
 
@@ -649,38 +1792,51 @@ impl NativeGmpRelay {
         };
 
         let chain_label = format!("connected({})", mvm_chain.chain_id);
-        let new_last = self
+        let Some(new_last) = self
             .poll_mvm_outbox(client, last_nonce, &chain_label)
-            .await?;
+            .await?
+        else {
+            return Ok(false);
+        };
 
         if new_last > last_nonce {
             self.state.write().await.mvm_connected_last_nonces.insert(mvm_chain.chain_id, new_last);
+            self.save_cursors().await;
         }
 
-        Ok(())
+        Ok(true)
     }
 
     /// Shared outbox polling logic for any MVM chain.
     ///
     /// Uses `GmpMvmClient` to read nonces and messages from the outbox.
-    /// Returns the new last_nonce value (highest nonce processed).
+    /// Returns the new last_nonce value (highest nonce processed), or None when the
+    /// outbox has no new messages.
     async fn poll_mvm_outbox(
         &self,
         client: &GmpMvmClient,
         last_nonce: u64,
         chain_name: &str,
-    ) -> Result<u64> {
+    ) -> Result<Option<u64>> {
         let src_chain_id = client.chain_id();
+
+        // Remove messages finished with on the previous pass before reading new ones
+        if self.config.mvm_outbox_cleanup {
+            self.flush_mvm_cleanup(client, chain_name).await;
+        }
+
         let next_nonce = client.get_next_nonce().await?;
 
         let start = if last_nonce == 0 { 1 } else { last_nonce + 1 };
+        // The first pass after startup catches up on the whole outbox history
+        let backfill = last_nonce == 0;
 
         if start >= next_nonce {
             debug!(
                 "MVM {} outbox: next_nonce={}, last_nonce={} (idle)",
                 chain_name, next_nonce, last_nonce
             );
-            return Ok(last_nonce);
+            return Ok(None);
         }
 
         info!(
@@ -691,6 +1847,9 @@ impl NativeGmpRelay {
         let mut new_last = last_nonce;
 
         for nonce in start..next_nonce {
+            if backfill {
+                self.throttle_backfill().await;
+            }
             let message = match client.get_message(nonce).await {
                 Ok(msg) => msg,
                 Err(e) => {
@@ -713,41 +1872,393 @@ impl NativeGmpRelay {
                 continue;
             }
 
-            if let Err(e) = self.deliver_message(&message).await {
-                let err_str = format!("{:#}", e);
-                if err_str.contains("E_UNKNOWN_REMOTE_GMP_ENDPOINT")
-                    || err_str.contains("E_ALREADY_DELIVERED")
-                    || err_str.contains("AlreadyDelivered")
-                    || err_str.contains("Already delivered")
-                    || err_str.contains("E_INTENT_NOT_FOUND")
-                {
+            let outcome = self.deliver_message(&message).await;
+            if let DeliveryOutcome::Unconfirmed(error) = &outcome {
+                warn!(
+                    "MVM {} outbox: delivery of nonce={} not confirmed, holding cursor: {}",
+                    chain_name, nonce, error
+                );
+                break;
+            }
+            if let DeliveryOutcome::Paused(error) = &outcome {
+                debug!("MVM {} outbox: holding cursor at nonce={}: {}", chain_name, nonce, error);
+                break;
+            }
+            if outcome.is_final() {
+                self.mark_for_cleanup(&message).await;
+            }
+            new_last = nonce;
+        }
+
+        Ok(Some(new_last))
+    }
+
+    /// Queue an MVM outbox message for removal once the relay is done with it.
+    ///
+    /// Messages skipped because of relay configuration (route not allowed, destination
+    /// disabled or unknown) are kept, since a config change may make them deliverable.
+    async fn mark_for_cleanup(&self, message: &GmpMessage) {
+        if !self.config.mvm_outbox_cleanup
+            || !self.is_route_allowed(message.src_chain_id, message.dst_chain_id)
+            || self.config.is_chain_enabled(message.dst_chain_id) != Some(true)
+        {
+            return;
+        }
+        self.state
+            .write()
+            .await
+            .mvm_cleanup_pending
+            .entry(message.src_chain_id)
+            .or_default()
+            .push(message.nonce);
+    }
+
+    /// Remove up to `MVM_CLEANUP_BATCH_SIZE` queued messages from an MVM outbox.
+    ///
+    /// Best-effort: on failure the nonces are re-queued and retried on the next pass.
+    async fn flush_mvm_cleanup(&self, client: &GmpMvmClient, chain_name: &str) {
+        let batch: Vec<u64> = {
+            let mut state = self.state.write().await;
+            let Some(pending) = state.mvm_cleanup_pending.get_mut(&client.chain_id()) else {
+                return;
+            };
+            let take = pending.len().min(MVM_CLEANUP_BATCH_SIZE);
+            pending.drain(..take).collect()
+        };
+        if batch.is_empty() {
+            return;
+        }
+
+        let operator = &self.operators[self.active_operator_index()];
+        if let Err(e) = client
+            .cleanup_delivered_messages(&batch, &operator.private_key)
+            .await
+        {
+            warn!(
+                "MVM {} outbox: cleanup of {} message(s) failed, will retry: {:#}",
+                chain_name, batch.len(), e
+            );
+            let mut state = self.state.write().await;
+            let pending = state.mvm_cleanup_pending.entry(client.chain_id()).or_default();
+            pending.splice(0..0, batch);
+        }
+    }
+
+    /// Current read cursors (last processed nonce or block per source chain).
+    pub async fn cursors(&self) -> RelayCursors {
+        self.state.read().await.cursors(self.config.mvm_chain_id)
+    }
+
+    /// Save the read cursors, if a cursor file is configured.
+    ///
+    /// Failures are logged: the relay keeps running on its in-memory cursors.
+    async fn save_cursors(&self) {
+        let Some(cursor_file) = &self.cursor_file else {
+            return;
+        };
+        let cursors = self.cursors().await;
+        if let Err(e) = cursor_file.save(&cursors) {
+            warn!("Failed to save relay cursors: {:#}", e);
+        }
+    }
+
+    /// MVM outbox nonces queued for removal on `chain_id`.
+    pub async fn pending_outbox_cleanup(&self, chain_id: u32) -> Vec<u64> {
+        self.state
+            .read()
+            .await
+            .mvm_cleanup_pending
+            .get(&chain_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Prometheus metrics, when `[metrics] enabled = true`.
+    pub fn metrics(&self) -> Option<&RelayMetrics> {
+        self.metrics.as_ref()
+    }
+
+    /// Messages attempted but not yet delivered, across all source/destination pairs,
+    /// ordered by source chain and nonce.
+    pub async fn pending_messages(&self) -> Vec<PendingMessage> {
+        let now = current_timestamp();
+        let state = self.state.read().await;
+        let mut messages: Vec<PendingMessage> = state
+            .pending_deliveries
+            .values()
+            .map(|pending| PendingMessage {
+                age_secs: now.saturating_sub(pending.first_seen_at),
+                ..pending.clone()
+            })
+            .collect();
+        messages.sort_by_key(|m| (m.src_chain_id, m.nonce));
+        messages
+    }
+
+    /// Drop a message from the pending list (permanently skipped).
+    async fn clear_pending_delivery(&self, message: &GmpMessage) {
+        let mut state = self.state.write().await;
+        state.pending_deliveries.remove(&(message.src_chain_id, message.nonce));
+    }
+
+    /// Record a message skipped after a permanent delivery error, so it can be listed
+    /// and re-attempted later.
+    async fn dead_letter(&self, message: &GmpMessage, error: &str) {
+        {
+            let mut state = self.state.write().await;
+            state.dead_letters.insert(
+                (message.src_chain_id, message.nonce),
+                DeadLetter {
+                    message: message.clone(),
+                    error: error.to_string(),
+                    dead_lettered_at: current_timestamp(),
+                },
+            );
+        }
+        self.save_dead_letters().await;
+    }
+
+    /// Messages skipped after a permanent delivery error, ordered by source chain and nonce.
+    pub async fn dead_letters(&self) -> Vec<DeadLetter> {
+        let state = self.state.read().await;
+        let mut entries: Vec<DeadLetter> = state.dead_letters.values().cloned().collect();
+        entries.sort_by_key(|e| (e.message.src_chain_id, e.message.nonce));
+        entries
+    }
+
+    /// Re-attempt delivery of a dead-lettered message.
+    ///
+    /// The message is removed from the store once delivered. On failure it stays,
+    /// with the new error and timestamp. Retry budgets and read cursors are not touched.
+    pub async fn retry_dead_letter(&self, src_chain_id: u32, nonce: u64) -> DeadLetterRetry {
+        let key = (src_chain_id, nonce);
+        let Some(entry) = self.state.read().await.dead_letters.get(&key).cloned() else {
+            return DeadLetterRetry::NotFound;
+        };
+        let message = &entry.message;
+
+        let result = if !self.is_route_allowed(message.src_chain_id, message.dst_chain_id) {
+            Err(anyhow::anyhow!(
+                "Route not allowed: src_chain={} -> dst_chain={}",
+                message.src_chain_id, message.dst_chain_id
+            ))
+        } else if self.config.is_chain_enabled(message.dst_chain_id) != Some(true) {
+            Err(anyhow::anyhow!(
+                "Destination chain {} is disabled or not configured",
+                message.dst_chain_id
+            ))
+        } else {
+            let operator = &self.operators[self.active_operator_index()];
+            match self.dispatch_delivery(message, operator).await {
+                DeliveryOutcome::Delivered | DeliveryOutcome::AlreadyDelivered => Ok(()),
+                DeliveryOutcome::PermanentFailure(error)
+                | DeliveryOutcome::TransientFailure(error)
+                | DeliveryOutcome::Unconfirmed(error)
+                | DeliveryOutcome::Paused(error) => Err(anyhow::anyhow!(error)),
+            }
+        };
+
+        let outcome = {
+            let mut state = self.state.write().await;
+            match result {
+                Ok(()) => {
+                    state.dead_letters.remove(&key);
+                    info!(
+                        "Dead-lettered message delivered on retry: src_chain={}, nonce={}, dst_chain={}",
+                        src_chain_id, nonce, message.dst_chain_id
+                    );
+                    DeadLetterRetry::Delivered
+                }
+                Err(e) => {
+                    let error = format!("{:#}", e);
+                    if let Some(entry) = state.dead_letters.get_mut(&key) {
+                        entry.error = error.clone();
+                        entry.dead_lettered_at = current_timestamp();
+                    }
                     warn!(
-                        "Permanent delivery failure for MVM {} nonce={}, skipping: {}",
-                        chain_name, nonce, err_str
+                        "Dead-lettered message retry failed: src_chain={}, nonce={}: {}",
+                        src_chain_id, nonce, error
                     );
-                    new_last = nonce;
-                    continue;
+                    DeadLetterRetry::Failed(error)
                 }
-                let exhausted = self.record_delivery_failure(&message, &err_str).await;
-                if exhausted {
-                    new_last = nonce;
-                    continue;
+            }
+        };
+        self.save_dead_letters().await;
+        outcome
+    }
+
+    /// Replay one chain's outbound messages over an explicit, inclusive range and
+    /// report what happened to each.
+    ///
+    /// The range is in outbox nonces for MVM and SVM chains and in block numbers for
+    /// EVM chains. Messages go through the regular delivery path, so ones the
+    /// destination already recorded are skipped rather than sent twice. Read cursors
+    /// are not moved.
+    pub async fn backfill(&self, target: PollTarget, from: u64, to: u64) -> Result<Vec<BackfillEntry>> {
+        if from > to {
+            anyhow::bail!("Backfill range is empty: from {} is after to {}", from, to);
+        }
+
+        match target {
+            PollTarget::MvmHub => self.backfill_mvm(&self.mvm_hub_client, from, to).await,
+            PollTarget::MvmConnected(chain_id) => {
+                let client = self.mvm_connected_clients.get(&chain_id)
+                    .ok_or_else(|| anyhow::anyhow!("No MVM client for chain {}", chain_id))?;
+                self.backfill_mvm(client, from, to).await
+            }
+            PollTarget::Svm(chain_id) => {
+                let svm_chain = self.config.find_svm_chain(chain_id)
+                    .ok_or_else(|| anyhow::anyhow!("SVM chain {} is not configured", chain_id))?;
+                self.backfill_svm(svm_chain, from, to).await
+            }
+            PollTarget::Evm(chain_id) => self.backfill_evm(chain_id, from, to).await,
+        }
+    }
+
+    /// Backfill MVM outbox nonces `from..=to`.
+    async fn backfill_mvm(&self, client: &GmpMvmClient, from: u64, to: u64) -> Result<Vec<BackfillEntry>> {
+        let src_chain_id = client.chain_id();
+        let mut entries = Vec::new();
+        for nonce in from..=to {
+            self.throttle_backfill().await;
+            let entry = match client.get_message(nonce).await {
+                Ok(message) => self.backfill_message(&message).await,
+                Err(e) => BackfillEntry {
+                    src_chain_id,
+                    nonce,
+                    dst_chain_id: None,
+                    result: BackfillResult::Skipped(format!("Message not readable: {:#}", e)),
+                },
+            };
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// Backfill SVM outbox nonces `from..=to`.
+    async fn backfill_svm(&self, svm_chain: &SvmRelayChainConfig, from: u64, to: u64) -> Result<Vec<BackfillEntry>> {
+        let svm_chain_id = svm_chain.chain_id;
+        let svm_client = self.svm_clients.get(&svm_chain_id)
+            .ok_or_else(|| anyhow::anyhow!("No SVM client for chain {}", svm_chain_id))?;
+        let gmp_program_id = Pubkey::from_str(
+            svm_chain.gmp_program_id.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("SVM GMP program ID not configured for chain {}", svm_chain_id))?,
+        )
+        .context("Invalid SVM GMP program ID")?;
+
+        let mut entries = Vec::new();
+        for nonce in from..=to {
+            self.throttle_backfill().await;
+            let entry = match svm_client.get_message_data(&gmp_program_id, nonce).await {
+                Ok(Some(msg)) => self.backfill_message(&svm_gmp_message(svm_chain_id, &msg)).await,
+                Ok(None) => BackfillEntry {
+                    src_chain_id: svm_chain_id,
+                    nonce,
+                    dst_chain_id: None,
+                    result: BackfillResult::Skipped("Message account not found".to_string()),
+                },
+                Err(e) => BackfillEntry {
+                    src_chain_id: svm_chain_id,
+                    nonce,
+                    dst_chain_id: None,
+                    result: BackfillResult::Failed(format!("Failed to read message: {:#}", e)),
+                },
+            };
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// Backfill the MessageSent logs of EVM blocks `from..=to`, read in the same
+    /// 10-block ranges as the regular poll.
+    async fn backfill_evm(&self, chain_id: u32, from: u64, to: u64) -> Result<Vec<BackfillEntry>> {
+        let client = self.evm_clients.get(&chain_id)
+            .ok_or_else(|| anyhow::anyhow!("No EVM client for chain {}", chain_id))?;
+
+        let max_range: u64 = 10;
+        let mut entries = Vec::new();
+        let mut range_start = from;
+        while range_start <= to {
+            let range_end = range_start.saturating_add(max_range - 1).min(to);
+            self.throttle_backfill().await;
+            let messages = client
+                .poll_message_sent_events(range_start, range_end)
+                .await
+                .with_context(|| format!("Failed to read EVM blocks {}..={}", range_start, range_end))?;
+            for message in &messages {
+                let entry = self.backfill_message(message).await;
+                if matches!(entry.result, BackfillResult::Delivered) {
+                    self.state
+                        .write()
+                        .await
+                        .processed_nonces
+                        .entry(chain_id)
+                        .or_default()
+                        .insert(message.nonce);
                 }
-                new_last = nonce;
-                continue;
+                entries.push(entry);
+            }
+            if range_end == u64::MAX {
+                break;
             }
+            range_start = range_end + 1;
+        }
+        Ok(entries)
+    }
 
-            new_last = nonce;
+    /// Deliver one backfilled message through the regular delivery path.
+    async fn backfill_message(&self, message: &GmpMessage) -> BackfillEntry {
+        let result = if !self.is_route_allowed(message.src_chain_id, message.dst_chain_id) {
+            BackfillResult::Skipped("Route not allowed".to_string())
+        } else if self.config.is_chain_enabled(message.dst_chain_id) != Some(true) {
+            BackfillResult::Skipped("Destination chain disabled or not configured".to_string())
+        } else {
+            match self.deliver_message(message).await {
+                DeliveryOutcome::Delivered => BackfillResult::Delivered,
+                DeliveryOutcome::AlreadyDelivered => {
+                    BackfillResult::Skipped("Already delivered".to_string())
+                }
+                DeliveryOutcome::PermanentFailure(error)
+                | DeliveryOutcome::TransientFailure(error)
+                | DeliveryOutcome::Unconfirmed(error)
+                | DeliveryOutcome::Paused(error) => BackfillResult::Failed(error),
+            }
+        };
+        BackfillEntry {
+            src_chain_id: message.src_chain_id,
+            nonce: message.nonce,
+            dst_chain_id: Some(message.dst_chain_id),
+            result,
+        }
+    }
+
+    /// Save the dead-lettered messages, if a dead-letter file is configured.
+    ///
+    /// Failures are logged: the relay keeps the entries in memory.
+    async fn save_dead_letters(&self) {
+        let Some(dead_letter_file) = &self.dead_letter_file else {
+            return;
+        };
+        let entries = self.dead_letters().await;
+        if let Err(e) = dead_letter_file.save(&entries) {
+            warn!("Failed to save dead-lettered messages: {:#}", e);
         }
+    }
 
-        Ok(new_last)
+    /// Flag a pending message as dead-lettered after it exhausts its retry budget.
+    async fn mark_pending_dead_lettered(&self, message: &GmpMessage) {
+        let mut state = self.state.write().await;
+        if let Some(pending) = state.pending_deliveries.get_mut(&(message.src_chain_id, message.nonce)) {
+            pending.dead_lettered = true;
+        }
     }
 
     /// Poll SVM for outbound messages using global nonce-based polling.
     ///
     /// Reads the single OutboundNonceAccount via getAccountInfo, then reads
     /// individual MessageAccount PDAs for any new nonces — same pattern as MVM.
-    async fn poll_svm_events(&self, svm_chain: &SvmRelayChainConfig) -> Result<()> {
+    /// Returns whether the outbox had new messages.
+    async fn poll_svm_events(&self, svm_chain: &SvmRelayChainConfig) -> Result<bool> {
         let svm_client = self.svm_clients.get(&svm_chain.chain_id)
             .ok_or_else(|| anyhow::anyhow!("No SVM client for chain {}", svm_chain.chain_id))?;
 
@@ -775,9 +2286,12 @@ impl NativeGmpRelay {
         };
 
         if start >= next_nonce {
-            return Ok(());
+            return Ok(false);
         }
 
+        // The first pass after startup catches up on the whole outbox history
+        let backfill = maybe_last.is_none();
+
         info!(
             "SVM outbox (chain_id={}): processing nonces {}..{} ({} messages)",
             svm_chain_id, start, next_nonce - 1, next_nonce - start
@@ -786,6 +2300,9 @@ impl NativeGmpRelay {
         let mut new_last = maybe_last;
 
         for nonce in start..next_nonce {
+            if backfill {
+                self.throttle_backfill().await;
+            }
             let msg = svm_client
                 .get_message_data(&gmp_program_id, nonce)
                 .await
@@ -800,14 +2317,7 @@ impl NativeGmpRelay {
                 continue;
             };
 
-            let message = GmpMessage {
-                src_chain_id: svm_chain_id,
-                remote_gmp_endpoint_addr: format!("0x{}", hex::encode(msg.remote_gmp_endpoint_addr)),
-                dst_chain_id: msg.dst_chain_id,
-                dst_addr: format!("0x{}", hex::encode(msg.dst_addr)),
-                payload: format!("0x{}", hex::encode(&msg.payload)),
-                nonce: msg.nonce,
-            };
+            let message = svm_gmp_message(svm_chain_id, &msg);
 
             info!(
                 "SVM outbox: nonce={}, src={}, dst_chain={}",
@@ -819,27 +2329,22 @@ impl NativeGmpRelay {
                 continue;
             }
 
-            if let Err(e) = self.deliver_message(&message).await {
-                let err_str = format!("{:#}", e);
-                if err_str.contains("E_UNKNOWN_REMOTE_GMP_ENDPOINT")
-                    || err_str.contains("E_ALREADY_DELIVERED")
-                    || err_str.contains("AlreadyDelivered")
-                    || err_str.contains("Already delivered")
-                    || err_str.contains("E_INTENT_NOT_FOUND")
-                {
+            match self.deliver_message(&message).await {
+                DeliveryOutcome::Unconfirmed(error) => {
                     warn!(
-                        "Permanent delivery failure for SVM nonce={}, skipping: {}",
-                        nonce, err_str
+                        "SVM outbox (chain_id={}): delivery of nonce={} not confirmed, holding cursor: {}",
+                        svm_chain_id, nonce, error
                     );
-                    new_last = Some(nonce);
-                    continue;
+                    break;
                 }
-                let exhausted = self.record_delivery_failure(&message, &err_str).await;
-                new_last = Some(nonce);
-                if exhausted {
-                    continue;
+                DeliveryOutcome::Paused(error) => {
+                    debug!(
+                        "SVM outbox (chain_id={}): holding cursor at nonce={}: {}",
+                        svm_chain_id, nonce, error
+                    );
+                    break;
                 }
-                continue;
+                _ => {}
             }
 
             new_last = Some(nonce);
@@ -848,34 +2353,221 @@ impl NativeGmpRelay {
         if let Some(last) = new_last {
             if maybe_last != new_last {
                 self.state.write().await.svm_last_nonces.insert(svm_chain_id, last);
+                self.save_cursors().await;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Deliver SVM messages the nonce-based poll can no longer reach.
+    ///
+    /// Scans each enabled SVM outbox (with `startup_message_scan` set) once per
+    /// destination chain via getProgramAccounts, and delivers every stored message
+    /// whose nonce is at or above the outbound nonce counter — left behind when the
+    /// counter account was reset or drifted. Messages below the counter are left to
+    /// the regular poll. Scan errors are logged and do not stop startup.
+    pub async fn reconcile_svm_outboxes(&self) {
+        for svm_chain in &self.config.svm_chains {
+            if !svm_chain.enabled || !svm_chain.startup_message_scan {
+                continue;
+            }
+            if let Err(e) = self.reconcile_svm_outbox(svm_chain).await {
+                warn!(
+                    "SVM outbox (chain_id={}): startup message scan failed: {:#}",
+                    svm_chain.chain_id, e
+                );
+            }
+        }
+    }
+
+    /// Reconcile one SVM outbox; see [`Self::reconcile_svm_outboxes`].
+    async fn reconcile_svm_outbox(&self, svm_chain: &SvmRelayChainConfig) -> Result<()> {
+        let svm_chain_id = svm_chain.chain_id;
+        let svm_client = self.svm_clients.get(&svm_chain_id)
+            .ok_or_else(|| anyhow::anyhow!("No SVM client for chain {}", svm_chain_id))?;
+        let Some(gmp_program_id) = svm_chain.gmp_program_id.as_ref() else {
+            return Ok(());
+        };
+        let gmp_program_id = Pubkey::from_str(gmp_program_id).context("Invalid SVM GMP program ID")?;
+
+        let next_nonce = svm_client
+            .get_outbound_nonce(&gmp_program_id)
+            .await
+            .context("Failed to read SVM outbound nonce")?;
+
+        let dst_chain_ids: Vec<u32> = std::iter::once(self.config.mvm_chain_id)
+            .chain(self.config.mvm_chains.iter().map(|c| c.chain_id))
+            .chain(self.config.evm_chains.iter().map(|c| c.chain_id))
+            .chain(self.config.svm_chains.iter().map(|c| c.chain_id))
+            .filter(|&chain_id| chain_id != svm_chain_id)
+            .collect();
+
+        for dst_chain_id in dst_chain_ids {
+            let messages = svm_client
+                .scan_outbound_messages(&gmp_program_id, dst_chain_id)
+                .await
+                .with_context(|| format!("Failed to scan messages to chain {}", dst_chain_id))?;
+
+            for msg in messages.iter().filter(|m| m.nonce >= next_nonce) {
+                warn!(
+                    "SVM outbox (chain_id={}): message nonce={} is beyond the outbound nonce counter ({}), delivering from startup scan",
+                    svm_chain_id, msg.nonce, next_nonce
+                );
+                self.deliver_message(&svm_gmp_message(svm_chain_id, msg)).await;
             }
         }
 
         Ok(())
     }
 
-    /// Deliver a GMP message to the destination chain.
-    async fn deliver_message(&self, message: &GmpMessage) -> Result<()> {
+    /// Deliver a GMP message and record the outcome.
+    ///
+    /// Failed attempts are tracked in the pending-message list; transient failures
+    /// also count against the retry budget, and permanent failures are dead-lettered.
+    #[tracing::instrument(
+        name = "delivery",
+        skip_all,
+        fields(
+            src_chain = message.src_chain_id,
+            dst_chain = message.dst_chain_id,
+            nonce = message.nonce,
+            intent_id = %message.intent_id().unwrap_or_else(|| "unknown".to_string()),
+        )
+    )]
+    async fn deliver_message(&self, message: &GmpMessage) -> DeliveryOutcome {
+        let started = Instant::now();
+        let operator_index = self.active_operator_index();
+        let outcome = if self.is_destination_paused(message.dst_chain_id).await {
+            DeliveryOutcome::Paused(format!("Destination chain {} GMP endpoint is paused", message.dst_chain_id))
+        } else {
+            let outcome = self.dispatch_delivery(message, &self.operators[operator_index]).await;
+            self.track_destination_pause(message.dst_chain_id, &outcome).await;
+            outcome
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics.record_delivery(message.src_chain_id, message.dst_chain_id, &outcome, started.elapsed());
+        }
+
+        let key = (message.src_chain_id, message.nonce);
+        match &outcome {
+            DeliveryOutcome::Delivered | DeliveryOutcome::AlreadyDelivered => {
+                self.state.write().await.pending_deliveries.remove(&key);
+            }
+            DeliveryOutcome::PermanentFailure(error) => {
+                warn!(
+                    "Permanent delivery failure for src_chain={}, nonce={}, skipping: {}",
+                    message.src_chain_id, message.nonce, error
+                );
+                self.clear_pending_delivery(message).await;
+                self.dead_letter(message, error).await;
+            }
+            DeliveryOutcome::TransientFailure(error)
+            | DeliveryOutcome::Unconfirmed(error)
+            | DeliveryOutcome::Paused(error) => {
+                {
+                    let now = current_timestamp();
+                    let mut state = self.state.write().await;
+                    let pending = state.pending_deliveries.entry(key).or_insert_with(|| PendingMessage {
+                        src_chain_id: message.src_chain_id,
+                        dst_chain_id: message.dst_chain_id,
+                        nonce: message.nonce,
+                        first_seen_at: now,
+                        last_attempt_at: now,
+                        age_secs: 0,
+                        attempts: 0,
+                        last_error: String::new(),
+                        dead_lettered: false,
+                    });
+                    // A paused destination is not a failed attempt
+                    if !matches!(outcome, DeliveryOutcome::Paused(_)) {
+                        pending.last_attempt_at = now;
+                        pending.attempts += 1;
+                    }
+                    pending.last_error = error.clone();
+                }
+                if matches!(outcome, DeliveryOutcome::TransientFailure(_)) {
+                    if is_unauthorized_relay_error(error) {
+                        self.fail_over_operator(operator_index).await;
+                    }
+                    self.record_delivery_failure(message, error).await;
+                }
+            }
+        }
+
+        outcome
+    }
+
+    /// Route a GMP message to the destination chain's deliverer, signed with `operator`.
+    ///
+    /// Messages for a disabled or unknown destination chain or a route outside the
+    /// source chain's allowlist are not submitted. They fail permanently, so the source
+    /// cursor moves past them and they are dead-lettered, to be retried once the config
+    /// allows them.
+    async fn dispatch_delivery(&self, message: &GmpMessage, operator: &RelayOperator) -> DeliveryOutcome {
         let dst = message.dst_chain_id;
 
+        if !self.is_route_allowed(message.src_chain_id, dst) {
+            error!(
+                "Route not allowed, rejecting delivery: src_chain={} -> dst_chain={}, nonce={}",
+                message.src_chain_id, dst, message.nonce
+            );
+            return DeliveryOutcome::PermanentFailure(format!(
+                "Route not allowed: src_chain={} -> dst_chain={}",
+                message.src_chain_id, dst
+            ));
+        }
+
+        if self.config.is_chain_enabled(dst) == Some(false) {
+            warn!(
+                "Destination chain {} is disabled, skipping delivery: src_chain={}, nonce={}",
+                dst, message.src_chain_id, message.nonce
+            );
+            return DeliveryOutcome::PermanentFailure(format!("Destination chain {} is disabled", dst));
+        }
+
+        // Held until the delivery completes; excess deliveries wait for a free slot
+        let _delivery_slot = match &self.delivery_limiter {
+            Some(limiter) => Some(limiter.acquire().await),
+            None => None,
+        };
+
+        let operator_private_key = &operator.private_key;
+
         // Destination is MVM hub
         if dst == self.config.mvm_chain_id {
-            return self.deliver_to_mvm_hub(message).await;
+            return MvmDeliverer::new(&self.mvm_hub_client, operator_private_key)
+                .deliver(message)
+                .await;
         }
 
         // Destination is a connected MVM chain
-        if let Some(mvm_chain) = self.config.find_mvm_chain(dst) {
-            return self.deliver_to_mvm_connected(message, mvm_chain).await;
+        if self.config.find_mvm_chain(dst).is_some() {
+            let Some(client) = self.mvm_connected_clients.get(&dst) else {
+                return DeliveryOutcome::TransientFailure(format!("No MVM client for chain {}", dst));
+            };
+            return MvmDeliverer::new(client, operator_private_key).deliver(message).await;
         }
 
         // Destination is a connected SVM chain
         if let Some(svm_chain) = self.config.find_svm_chain(dst) {
-            return self.deliver_to_svm(message, svm_chain).await;
+            let Some(client) = self.svm_clients.get(&dst) else {
+                return DeliveryOutcome::TransientFailure(format!("No SVM client for chain {}", dst));
+            };
+            // Operator private key (base64 Ed25519) -> Solana keypair
+            let relay_keypair = match Self::load_svm_keypair(operator_private_key) {
+                Ok(keypair) => keypair,
+                Err(e) => return DeliveryOutcome::TransientFailure(format!("{:#}", e)),
+            };
+            return SvmDeliverer::new(svm_chain, client, relay_keypair).deliver(message).await;
         }
 
         // Destination is a connected EVM chain
-        if let Some(evm_chain) = self.config.find_evm_chain(dst) {
-            return self.deliver_to_evm(message, evm_chain).await;
+        if self.config.find_evm_chain(dst).is_some() {
+            let Some(client) = self.evm_clients.get(&dst) else {
+                return DeliveryOutcome::TransientFailure(format!("No EVM client for chain {}", dst));
+            };
+            return EvmDeliverer::new(client, &operator.crypto_service).deliver(message).await;
         }
 
         let known_mvm: Vec<u32> = self.config.mvm_chains.iter().map(|c| c.chain_id).collect();
@@ -885,97 +2577,54 @@ impl NativeGmpRelay {
             "Unknown destination chain ID: {}. Known chains: MVM hub={}, MVM connected={:?}, SVM={:?}, EVM={:?}",
             dst, self.config.mvm_chain_id, known_mvm, known_svm, known_evm
         );
-        Ok(())
-    }
-
-    /// Deliver message to MVM hub chain via intent_gmp::deliver_message_entry.
-    ///
-    /// Uses the CLI-based transaction submission pattern (same as solver).
-    async fn deliver_to_mvm_hub(&self, message: &GmpMessage) -> Result<()> {
-        info!("Delivering message to MVM hub: dst_chain={}, nonce={}", message.dst_chain_id, message.nonce);
-        self.mvm_hub_client.deliver_message(message, &self.config.operator_private_key).await
-    }
-
-    /// Deliver message to a connected MVM chain via intent_gmp::deliver_message_entry.
-    async fn deliver_to_mvm_connected(&self, message: &GmpMessage, mvm_chain: &MvmRelayChainConfig) -> Result<()> {
-        let client = self.mvm_connected_clients.get(&mvm_chain.chain_id)
-            .ok_or_else(|| anyhow::anyhow!("No MVM client for chain {}", mvm_chain.chain_id))?;
-        info!("Delivering message to MVM connected({}): dst_chain={}, nonce={}",
-            mvm_chain.chain_id, message.dst_chain_id, message.nonce);
-        client.deliver_message(message, &self.config.operator_private_key).await
-    }
-
-    /// Deliver message to EVM chain via IntentGmp.deliverMessage().
-    async fn deliver_to_evm(&self, message: &GmpMessage, evm_chain: &EvmRelayChainConfig) -> Result<()> {
-        let client = self.evm_clients.get(&evm_chain.chain_id)
-            .ok_or_else(|| anyhow::anyhow!("No EVM client for chain {}", evm_chain.chain_id))?;
-
-        info!(
-            "Delivering message to EVM: dst_chain={}, nonce={}",
-            message.dst_chain_id, message.nonce
-        );
-
-        // Pre-check: skip if already delivered on EVM (avoids wasting gas on reverts)
-        let payload_hex = message.payload.strip_prefix("0x").unwrap_or(&message.payload);
-        let payload_bytes = hex::decode(payload_hex).context("Failed to hex-decode payload")?;
-        if payload_bytes.len() >= 33 {
-            let msg_type = payload_bytes[0];
-            let intent_id = &payload_bytes[1..33];
-            if client.is_message_delivered(intent_id, msg_type).await? {
-                info!(
-                    "EVM: message already delivered (nonce={}, msg_type=0x{:02x}), skipping",
-                    message.nonce, msg_type
-                );
-                return Ok(());
-            }
-        }
-
-        let tx_hash = client
-            .deliver_message(
-                message.src_chain_id,
-                &message.remote_gmp_endpoint_addr,
-                &message.payload,
-                &self.crypto_service,
-            )
-            .await?;
-
-        info!("EVM: waiting for receipt for tx_hash={}", tx_hash);
-        client.wait_for_receipt(&tx_hash).await?;
-
-        info!(
-            "EVM deliver_message submitted successfully: nonce={}, tx_hash={}",
-            message.nonce, tx_hash
-        );
-
-        Ok(())
+        DeliveryOutcome::PermanentFailure(format!("Unknown destination chain {}", dst))
     }
 
     /// Poll an EVM chain for MessageSent events from IntentGmp contract.
-    async fn poll_evm_events(&self, evm_chain: &EvmRelayChainConfig) -> Result<()> {
+    ///
+    /// With `confirmations` > 0, only blocks at least that deep are scanned, a log is
+    /// delivered only while its block is still canonical, and ranges replaced by a
+    /// reorg are scanned again.
+    ///
+    /// Returns whether new messages were found or more blocks remain to be scanned.
+    async fn poll_evm_events(&self, evm_chain: &EvmRelayChainConfig) -> Result<bool> {
         let evm_chain_id = evm_chain.chain_id;
         let client = self.evm_clients.get(&evm_chain_id)
             .ok_or_else(|| anyhow::anyhow!("No EVM client for chain {}", evm_chain_id))?;
+        let reorg_checks = evm_chain.confirmations > 0;
 
         let current_block = client.get_block_number().await?;
+        // Newest block deep enough to act on
+        let safe_block = current_block.saturating_sub(evm_chain.confirmations);
+
+        if reorg_checks {
+            self.rewind_evm_cursor_on_reorg(evm_chain_id, client).await?;
+        }
 
         // Max 10 block range for Alchemy free tier
         let max_range: u64 = 10;
         let last_block = { *self.state.read().await.evm_last_blocks.get(&evm_chain_id).unwrap_or(&0) };
         let from_block = if last_block == 0 {
-            current_block.saturating_sub(max_range)
+            // Cold start (no saved cursor): look back a bounded window, not the whole chain
+            safe_block.saturating_sub(self.config.evm_cold_start_blocks)
         } else {
             last_block + 1
         };
 
-        if from_block > current_block {
-            return Ok(());
+        if from_block > safe_block {
+            return Ok(false);
         }
 
-        let to_block = from_block.saturating_add(max_range - 1).min(current_block);
+        let to_block = from_block.saturating_add(max_range - 1).min(safe_block);
 
-        let messages = client.poll_message_sent_events(from_block, to_block).await?;
+        let messages = client.poll_message_sent_logs(from_block, to_block).await?;
+        // Set when an MVM delivery is unconfirmed or a log was orphaned; the block
+        // range is re-read next poll
+        let mut hold_cursor = false;
+        // Canonical block hashes looked up during this poll
+        let mut canonical_hashes: HashMap<u64, Option<String>> = HashMap::new();
 
-        for message in &messages {
+        for EvmSentMessage { message, block_number, block_hash } in &messages {
             info!(
                 "Found EVM MessageSent: dst_chain={}, nonce={}",
                 message.dst_chain_id, message.nonce
@@ -994,27 +2643,42 @@ impl NativeGmpRelay {
                 continue;
             }
 
-            if let Err(e) = self.deliver_message(message).await {
-                let err_str = format!("{:#}", e);
-                if err_str.contains("E_UNKNOWN_REMOTE_GMP_ENDPOINT")
-                    || err_str.contains("E_ALREADY_DELIVERED")
-                    || err_str.contains("AlreadyDelivered")
-                    || err_str.contains("Already delivered")
-                    || err_str.contains("E_INTENT_NOT_FOUND")
-                {
-                    warn!(
-                        "Permanent delivery failure for EVM nonce={}, skipping: {}",
-                        message.nonce, err_str
-                    );
-                    let mut state = self.state.write().await;
-                    state.processed_nonces.entry(evm_chain_id).or_default().insert(message.nonce);
-                    continue;
+            if reorg_checks {
+                if let Some(block_hash) = block_hash {
+                    if !canonical_hashes.contains_key(block_number) {
+                        let canonical = client.get_block_hash(*block_number).await?;
+                        canonical_hashes.insert(*block_number, canonical);
+                    }
+                    if canonical_hashes[block_number].as_deref() != Some(block_hash.as_str()) {
+                        warn!(
+                            "EVM (chain_id={}): MessageSent nonce={} in block {} ({}) was orphaned by a reorg, rescanning",
+                            evm_chain_id, message.nonce, block_number, block_hash
+                        );
+                        hold_cursor = true;
+                        break;
+                    }
                 }
-                self.record_delivery_failure(message, &err_str).await;
-                continue;
             }
 
-            {
+            let outcome = self.deliver_message(message).await;
+            if let DeliveryOutcome::Unconfirmed(error) = &outcome {
+                warn!(
+                    "EVM (chain_id={}): delivery of nonce={} not confirmed, holding cursor: {}",
+                    evm_chain_id, message.nonce, error
+                );
+                hold_cursor = true;
+                break;
+            }
+            if let DeliveryOutcome::Paused(error) = &outcome {
+                debug!(
+                    "EVM (chain_id={}): holding cursor at nonce={}: {}",
+                    evm_chain_id, message.nonce, error
+                );
+                hold_cursor = true;
+                break;
+            }
+
+            if outcome.is_final() {
                 let mut state = self.state.write().await;
                 state
                     .processed_nonces
@@ -1024,346 +2688,84 @@ impl NativeGmpRelay {
             }
         }
 
-        {
-            self.state.write().await.evm_last_blocks.insert(evm_chain_id, to_block);
+        if !hold_cursor {
+            // Remember where the scanned range ended, to notice a later reorg of it
+            let to_block_hash = if reorg_checks {
+                client.get_block_hash(to_block).await?
+            } else {
+                None
+            };
+            {
+                let mut state = self.state.write().await;
+                state.evm_last_blocks.insert(evm_chain_id, to_block);
+                if let Some(hash) = to_block_hash {
+                    let hashes = state.evm_block_hashes.entry(evm_chain_id).or_default();
+                    hashes.insert(to_block, hash);
+                    while hashes.len() > EVM_TRACKED_BLOCK_HASHES {
+                        hashes.pop_first();
+                    }
+                }
+            }
+            self.save_cursors().await;
         }
 
-        Ok(())
+        // Still catching up counts as active, so a backlog is read at the base interval
+        Ok(!messages.is_empty() || to_block < safe_block)
     }
 
-    /// Deliver message to SVM chain via integrated-gmp-endpoint DeliverMessage instruction.
+    /// Move an EVM chain's cursor back if a reorg replaced blocks it already scanned.
     ///
-    /// Builds and submits a DeliverMessage transaction to the SVM integrated-gmp-endpoint program.
-    /// For IntentRequirements messages (0x01), also derives and passes the outflow-validator
-    /// accounts needed for GmpReceive CPI.
-    async fn deliver_to_svm(&self, message: &GmpMessage, svm_chain: &SvmRelayChainConfig) -> Result<()> {
-        let rpc_url = &svm_chain.rpc_url;
-
-        let program_id_str = svm_chain.gmp_program_id.as_ref()
-            .ok_or_else(|| anyhow::anyhow!("SVM GMP program ID not configured for chain {}", svm_chain.chain_id))?;
-
-        info!(
-            "Delivering message to SVM: dst_chain={}, nonce={}",
-            message.dst_chain_id, message.nonce
-        );
-
-        // Parse program ID (integrated-gmp-endpoint)
-        let program_id = Pubkey::from_str(program_id_str)
-            .context("Invalid SVM GMP program ID")?;
-
-        // Load relay keypair from operator private key (base64 Ed25519 -> Solana keypair)
-        let relay_keypair = self.load_svm_keypair()?;
-        let relay_pubkey = relay_keypair.pubkey();
-
-        // Parse remote GMP endpoint address (32 bytes)
-        let remote_gmp_endpoint_addr = parse_32_byte_address(&message.remote_gmp_endpoint_addr)?;
-
-        // Parse destination address (the receiving program on SVM - e.g., outflow-validator)
-        let dst_program = parse_svm_pubkey(&message.dst_addr)?;
-
-        // Parse payload
-        let payload = hex_to_bytes(&message.payload)?;
-
-        // Derive GMP endpoint PDAs
-        let (config_pda, _) = Pubkey::find_program_address(&[b"config"], &program_id);
-        let (relay_pda, _) =
-            Pubkey::find_program_address(&[b"relay", relay_pubkey.as_ref()], &program_id);
-        let (remote_gmp_endpoint_pda, _) = Pubkey::find_program_address(
-            &[b"remote_gmp_endpoint", &message.src_chain_id.to_le_bytes()],
-            &program_id,
-        );
-        // Derive delivered message PDA from payload (intent_id + msg_type)
-        // All GMP messages: msg_type (1 byte) + intent_id (32 bytes) at the start
-        if payload.len() < 33 {
-            return Err(anyhow::anyhow!("Payload too short to extract intent_id for dedup PDA"));
-        }
-        let msg_type = payload[0];
-        let intent_id = &payload[1..33];
-        let (delivered_pda, _) = Pubkey::find_program_address(
-            &[b"delivered", intent_id, &[msg_type]],
-            &program_id,
-        );
-        let (routing_pda, _) = Pubkey::find_program_address(&[b"routing"], &program_id);
-
-        // Check if message was already delivered (delivered PDA already exists on-chain)
-        let rpc_client_check = RpcClient::new_with_commitment(
-            rpc_url.clone(),
-            CommitmentConfig::confirmed(),
-        );
-        if rpc_client_check.get_account(&delivered_pda).is_ok() {
-            info!(
-                "SVM: message already delivered (nonce={}, msg_type=0x{:02x}), skipping",
-                message.nonce, msg_type
-            );
-            return Ok(());
-        }
-
-        // Get outflow_validator program for destination_program_1 (required for routing IntentRequirements)
-        let outflow_program = if let Some(ref outflow_id) = svm_chain.outflow_program_id {
-            Pubkey::from_str(outflow_id).context("Invalid SVM outflow program ID")?
-        } else {
-            // If no outflow configured, use dst_program as placeholder (routing won't be used)
-            dst_program
+    /// Checks the tracked block hashes newest first and rewinds to the newest block that
+    /// is still canonical (or before the oldest tracked block if none is), so the replaced
+    /// range is scanned again. Messages already delivered from orphaned blocks are not
+    /// delivered twice: their nonces stay processed.
+    async fn rewind_evm_cursor_on_reorg(&self, evm_chain_id: u32, client: &GmpEvmClient) -> Result<()> {
+        let tracked: Vec<(u64, String)> = {
+            let state = self.state.read().await;
+            match state.evm_block_hashes.get(&evm_chain_id) {
+                Some(hashes) => hashes.iter().rev().map(|(block, hash)| (*block, hash.clone())).collect(),
+                None => return Ok(()),
+            }
         };
-
-        // Get intent_escrow program for destination_program_2 (required for routing)
-        let escrow_program = if let Some(ref escrow_id) = svm_chain.escrow_program_id {
-            Pubkey::from_str(escrow_id).context("Invalid SVM escrow program ID")?
-        } else {
-            // If no escrow configured, use dst_program as placeholder (routing won't be used)
-            dst_program
+        let (Some((newest, _)), Some((oldest, _))) = (tracked.first(), tracked.last()) else {
+            return Ok(());
         };
+        let (newest, oldest) = (*newest, *oldest);
 
-        // Build base accounts for DeliverMessage
-        // Account order (updated for intent_id-based dedup):
-        // 0. Config, 1. Relay, 2. RemoteGmpEndpoint, 3. DeliveredMessage, 4. RelaySigner, 5. Payer
-        // Track if we need to create an ATA before delivering the message (for FulfillmentProof)
-        // Tuple: (ata, owner, mint, token_program, associated_token_program)
-        #[allow(clippy::type_complexity)]
-        let mut ata_create_info: Option<(Pubkey, Pubkey, Pubkey, Pubkey, Pubkey)> = None;
-
-        // 6. SystemProgram, 7. RoutingConfig, 8. DestProgram1, 9. DestProgram2, 10+. Remaining
-        let mut accounts = vec![
-            AccountMeta::new_readonly(config_pda, false),
-            AccountMeta::new_readonly(relay_pda, false),
-            AccountMeta::new_readonly(remote_gmp_endpoint_pda, false),
-            AccountMeta::new(delivered_pda, false),
-            AccountMeta::new_readonly(relay_pubkey, true), // signer
-            AccountMeta::new(relay_pubkey, true),          // payer (signer)
-            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-            AccountMeta::new_readonly(routing_pda, false), // routing config (may not exist)
-            AccountMeta::new_readonly(outflow_program, false), // destination program 1 (outflow_validator)
-            AccountMeta::new_readonly(escrow_program, false), // destination program 2 (intent_escrow)
-        ];
-
-        // For IntentRequirements (0x01), add accounts for both destination programs' GmpReceive CPI.
-        // The GMP endpoint routes to BOTH outflow_validator AND intent_escrow when routing is configured.
-        //
-        // Account layout for remaining_accounts (passed to GMP endpoint after base accounts):
-        // Indices 0-4: outflow_validator's GmpReceive accounts
-        // Indices 5-9: intent_escrow's GmpReceive accounts
-        //
-        // Each program's GmpReceive expects: requirements(w), config(r), authority(s), payer(s,w), system_program
-        if !payload.is_empty() && payload[0] == 0x01 {
-            // IntentRequirements format: [type(1)] [intent_id(32)] [...]
-            if payload.len() >= 33 {
-                let mut intent_id = [0u8; 32];
-                intent_id.copy_from_slice(&payload[1..33]);
-
-                // Derive outflow-validator PDAs
-                let (outflow_requirements_pda, _) = Pubkey::find_program_address(
-                    &[b"requirements", &intent_id],
-                    &outflow_program,
-                );
-                let (outflow_config_pda, _) = Pubkey::find_program_address(
-                    &[b"config"],
-                    &outflow_program,
-                );
-
-                // Derive intent_escrow PDAs (escrow_program is the intent_escrow)
-                let (escrow_requirements_pda, _) = Pubkey::find_program_address(
-                    &[b"requirements", &intent_id],
-                    &escrow_program,
-                );
-                let (escrow_gmp_config_pda, _) = Pubkey::find_program_address(
-                    &[b"gmp_config"],
-                    &escrow_program,
-                );
-
-                debug!(
-                    "Adding accounts for multi-destination GmpReceive CPI: outflow_req={}, outflow_cfg={}, escrow_req={}, escrow_cfg={}",
-                    outflow_requirements_pda, outflow_config_pda, escrow_requirements_pda, escrow_gmp_config_pda
-                );
-
-                // Accounts for outflow_validator's GmpReceive (indices 0-4)
-                // GmpReceive expects: requirements(w), config(r), authority(s), payer(s,w), system_program
-                accounts.push(AccountMeta::new(outflow_requirements_pda, false));  // 0
-                accounts.push(AccountMeta::new_readonly(outflow_config_pda, false)); // 1
-                accounts.push(AccountMeta::new_readonly(relay_pubkey, true));  // 2: authority (signer)
-                accounts.push(AccountMeta::new(relay_pubkey, true));           // 3: payer (signer)
-                accounts.push(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)); // 4
-
-                // Accounts for intent_escrow's GmpReceive (indices 5-9)
-                // GmpReceive expects: requirements(w), gmp_config(r), authority(s), payer(s,w), system_program
-                accounts.push(AccountMeta::new(escrow_requirements_pda, false));  // 5
-                accounts.push(AccountMeta::new_readonly(escrow_gmp_config_pda, false)); // 6
-                accounts.push(AccountMeta::new_readonly(relay_pubkey, true));  // 7: authority (signer)
-                accounts.push(AccountMeta::new(relay_pubkey, true));           // 8: payer (signer)
-                accounts.push(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)); // 9
-            }
-        } else if !payload.is_empty() && payload[0] == 0x03 {
-            // FulfillmentProof (0x03) - route to intent_escrow only
-            // Payload format: [type(1)] [intent_id(32)] [solver_addr(32)] [amount(8)] [timestamp(8)]
-            if payload.len() >= 65 {
-                let mut intent_id = [0u8; 32];
-                intent_id.copy_from_slice(&payload[1..33]);
-
-                let mut solver_addr = [0u8; 32];
-                solver_addr.copy_from_slice(&payload[33..65]);
-
-                // Derive intent_escrow PDAs
-                let (escrow_requirements_pda, _) = Pubkey::find_program_address(
-                    &[b"requirements", &intent_id],
-                    &escrow_program,
-                );
-                let (escrow_pda, _) = Pubkey::find_program_address(
-                    &[b"escrow", &intent_id],
-                    &escrow_program,
-                );
-                let (vault_pda, _) = Pubkey::find_program_address(
-                    &[b"vault", &intent_id],
-                    &escrow_program,
-                );
-                let (escrow_gmp_config_pda, _) = Pubkey::find_program_address(
-                    &[b"gmp_config"],
-                    &escrow_program,
-                );
-
-                // Read requirements account to get token_addr (mint)
-                let rpc_client_for_read = RpcClient::new_with_commitment(
-                    rpc_url.clone(),
-                    CommitmentConfig::confirmed(),
-                );
-                let requirements_data = rpc_client_for_read
-                    .get_account_data(&escrow_requirements_pda)
-                    .context("Failed to read requirements account for FulfillmentProof")?;
-
-                // Parse token_addr from StoredIntentRequirements
-                // Layout: discriminator(8) + intent_id(32) + requester_addr(32) + amount_required(8) + token_addr(32)
-                // token_addr starts at offset 80
-                if requirements_data.len() < 112 {
-                    return Err(anyhow::anyhow!(
-                        "Requirements account too small: {} bytes",
-                        requirements_data.len()
-                    ));
-                }
-                let mut token_mint_bytes = [0u8; 32];
-                token_mint_bytes.copy_from_slice(&requirements_data[80..112]);
-                let token_mint = Pubkey::new_from_array(token_mint_bytes);
-
-                // Derive solver's ATA manually (PDA derivation)
-                // ATA = PDA([owner, TOKEN_PROGRAM_ID, mint], ASSOCIATED_TOKEN_PROGRAM_ID)
-                let solver_pubkey = Pubkey::new_from_array(solver_addr);
-                let token_program_id = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
-                    .expect("Invalid token program ID");
-                let associated_token_program_id = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
-                    .expect("Invalid associated token program ID");
-                let (solver_ata, _) = Pubkey::find_program_address(
-                    &[
-                        solver_pubkey.as_ref(),
-                        token_program_id.as_ref(),
-                        token_mint.as_ref(),
-                    ],
-                    &associated_token_program_id,
-                );
-
-                debug!(
-                    "FulfillmentProof accounts: requirements={}, escrow={}, vault={}, solver_ata={}, gmp_config={}, token_mint={}",
-                    escrow_requirements_pda, escrow_pda, vault_pda, solver_ata, escrow_gmp_config_pda, token_mint
-                );
-
-                // Store ATA creation info for use when building transaction
-                ata_create_info = Some((solver_ata, solver_pubkey, token_mint, token_program_id, associated_token_program_id));
-
-                // Accounts for intent_escrow's GmpReceiveFulfillmentProof
-                // Expected: requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s), token_program
-                accounts.push(AccountMeta::new(escrow_requirements_pda, false));     // 0: requirements (writable)
-                accounts.push(AccountMeta::new(escrow_pda, false));                  // 1: escrow (writable)
-                accounts.push(AccountMeta::new(vault_pda, false));                   // 2: vault (writable)
-                accounts.push(AccountMeta::new(solver_ata, false));                  // 3: solver_token (writable)
-                accounts.push(AccountMeta::new_readonly(escrow_gmp_config_pda, false)); // 4: gmp_config
-                accounts.push(AccountMeta::new_readonly(relay_pubkey, true));        // 5: gmp_caller (signer)
-                accounts.push(AccountMeta::new_readonly(token_program_id, false));   // 6: token_program
+        let mut canonical_block = None;
+        for (block_number, hash) in &tracked {
+            if client.get_block_hash(*block_number).await?.as_deref() == Some(hash.as_str()) {
+                canonical_block = Some(*block_number);
+                break;
             }
         }
-
-        // Build DeliverMessage instruction
-        let instruction_data = SvmDeliverMessageInstruction {
-            src_chain_id: message.src_chain_id,
-            remote_gmp_endpoint_addr,
-            payload,
-        };
-
-        let deliver_instruction = Instruction {
-            program_id,
-            accounts,
-            data: instruction_data
-                .try_to_vec()
-                .context("Failed to serialize DeliverMessage instruction")?,
-        };
-
-        // Build instructions list - may include ATA creation for FulfillmentProof
-        let mut instructions = Vec::new();
-
-        // If we need to create an ATA (for FulfillmentProof), add that instruction first
-        if let Some((ata, owner, mint, token_program, ata_program)) = ata_create_info {
-            // Build create_associated_token_account_idempotent instruction manually
-            // Instruction data: [1] for idempotent create
-            // Accounts: payer(s,w), ata(w), owner(r), mint(r), system_program(r), token_program(r)
-            let create_ata_ix = Instruction {
-                program_id: ata_program,
-                accounts: vec![
-                    AccountMeta::new(relay_pubkey, true),         // payer (signer, writable)
-                    AccountMeta::new(ata, false),                 // associated token account (writable)
-                    AccountMeta::new_readonly(owner, false),      // wallet owner
-                    AccountMeta::new_readonly(mint, false),       // token mint
-                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false), // system program
-                    AccountMeta::new_readonly(token_program, false), // token program
-                ],
-                data: vec![1], // 1 = create_idempotent
-            };
-            debug!(
-                "Adding create_associated_token_account_idempotent instruction: ata={}, owner={}, mint={}",
-                ata, owner, mint
-            );
-            instructions.push(create_ata_ix);
+        if canonical_block == Some(newest) {
+            return Ok(());
         }
 
-        instructions.push(deliver_instruction);
-
-        // Create RPC client and submit transaction
-        let rpc_client = RpcClient::new_with_commitment(
-            rpc_url.clone(),
-            CommitmentConfig::confirmed(),
-        );
-
-        let blockhash = rpc_client
-            .get_latest_blockhash()
-            .context("Failed to get latest blockhash")?;
-
-        let transaction = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&relay_pubkey),
-            &[&relay_keypair],
-            blockhash,
-        );
-
-        let signature = rpc_client
-            .send_and_confirm_transaction(&transaction)
-            .map_err(|e| {
-                error!(
-                    "SVM DeliverMessage failed: {}. Accounts: config={}, relay={}, remote_gmp_endpoint={}, delivered={}, dst_program={}",
-                    e, config_pda, relay_pda, remote_gmp_endpoint_pda, delivered_pda, dst_program
-                );
-                e
-            })
-            .context("Failed to submit SVM DeliverMessage transaction")?;
-
-        info!(
-            "SVM deliver_message submitted successfully: nonce={}, signature={}",
-            message.nonce, signature
+        let rewind_to = canonical_block.unwrap_or(oldest.saturating_sub(1));
+        warn!(
+            "EVM (chain_id={}): reorg replaced scanned blocks up to {}, rescanning from block {}",
+            evm_chain_id, newest, rewind_to + 1
         );
-
+        {
+            let mut state = self.state.write().await;
+            if let Some(hashes) = state.evm_block_hashes.get_mut(&evm_chain_id) {
+                hashes.retain(|block_number, _| *block_number <= rewind_to);
+            }
+            state.evm_last_blocks.insert(evm_chain_id, rewind_to);
+        }
+        self.save_cursors().await;
         Ok(())
     }
 
-    /// Load the relay keypair for SVM from the operator private key.
+    /// Load the relay keypair for SVM from an operator private key.
     ///
     /// The operator private key is a base64-encoded Ed25519 seed (32 bytes).
     /// For Solana, we expand this to a 64-byte keypair.
-    fn load_svm_keypair(&self) -> Result<Keypair> {
+    fn load_svm_keypair(operator_private_key: &str) -> Result<Keypair> {
         let seed_bytes = STANDARD
-            .decode(&self.config.operator_private_key)
+            .decode(operator_private_key)
             .context("Failed to decode base64 private key")?;
 
         if seed_bytes.len() != 32 {
@@ -1383,35 +2785,78 @@ impl NativeGmpRelay {
 }
 
 // ============================================================================
-// SVM INSTRUCTION TYPES
+// HELPER FUNCTIONS
 // ============================================================================
 
-/// SVM DeliverMessage instruction data (matches integrated-gmp-endpoint program).
-///
-/// This is the 6th variant (index 6) in the NativeGmpInstruction enum.
-/// Deduplication uses (intent_id, msg_type) from the payload — no nonce needed.
-#[derive(BorshSerialize)]
-struct SvmDeliverMessageInstruction {
-    src_chain_id: u32,
-    remote_gmp_endpoint_addr: [u8; 32],
-    payload: Vec<u8>,
+/// Whether a delivery error means the transaction was submitted but not confirmed,
+/// in which case the source cursor must not advance past the message.
+pub fn is_unconfirmed_delivery(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<DeliveryNotConfirmed>().is_some()
 }
 
-impl SvmDeliverMessageInstruction {
-    fn try_to_vec(&self) -> Result<Vec<u8>> {
-        // Instruction discriminator: DeliverMessage is variant 6 in the enum
-        // (Initialize=0, AddRelay=1, RemoveRelay=2, SetRemoteGmpEndpointAddr=3, SetRouting=4, Send=5, DeliverMessage=6)
-        let mut data = vec![6u8];
-        data.extend(
-            borsh::to_vec(self).context("Failed to serialize instruction data")?,
-        );
-        Ok(data)
+/// Convert an SVM MessageAccount into the relay's message representation.
+fn svm_gmp_message(src_chain_id: u32, msg: &SvmOutboundMessage) -> GmpMessage {
+    GmpMessage {
+        src_chain_id,
+        remote_gmp_endpoint_addr: format!("0x{}", hex::encode(msg.remote_gmp_endpoint_addr)),
+        dst_chain_id: msg.dst_chain_id,
+        dst_addr: format!("0x{}", hex::encode(msg.dst_addr)),
+        payload: format!("0x{}", hex::encode(&msg.payload)),
+        nonce: msg.nonce,
     }
 }
 
-// ============================================================================
-// HELPER FUNCTIONS
-// ============================================================================
+/// Get current Unix timestamp.
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Explain why a destination trusting `trusted` rejects messages sent with `sent`, or None
+/// if it accepts them. Addresses are hex and compared as left-padded 32-byte values.
+pub fn remote_gmp_endpoint_mismatch(sent: &str, trusted: &[String]) -> Option<String> {
+    if let Ok(sent_bytes) = parse_32_byte_address(sent) {
+        if trusted.iter().any(|addr| parse_32_byte_address(addr).ok() == Some(sent_bytes)) {
+            return None;
+        }
+    }
+    if trusted.is_empty() {
+        return Some(format!("no remote GMP endpoint is configured, the relay sends {}", sent));
+    }
+    Some(format!("it trusts {} but the relay sends {}", trusted.join(", "), sent))
+}
+
+/// Log and return a warning if `dst_chain_id` does not trust `sent` for `src_chain_id`.
+fn remote_gmp_endpoint_warning(
+    src_chain_id: u32,
+    dst_chain_id: u32,
+    sent: &str,
+    trusted: Result<Vec<String>>,
+) -> Option<String> {
+    let warning = match trusted {
+        Ok(trusted) => {
+            let Some(reason) = remote_gmp_endpoint_mismatch(sent, &trusted) else {
+                info!(
+                    "Chain {}: trusts remote GMP endpoint {} for chain {}",
+                    dst_chain_id, sent, src_chain_id
+                );
+                return None;
+            };
+            format!(
+                "Remote GMP endpoint mismatch: chain {} rejects every message from chain {}: {}",
+                dst_chain_id, src_chain_id, reason
+            )
+        }
+        Err(e) => format!(
+            "Could not read the remote GMP endpoint chain {} trusts for chain {}: {:#}",
+            dst_chain_id, src_chain_id, e
+        ),
+    };
+    warn!("{}", warning);
+    Some(warning)
+}
 
 /// Convert hex string (with or without 0x prefix) to bytes.
 pub fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>> {
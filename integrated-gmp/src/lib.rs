@@ -2,14 +2,25 @@
 //!
 //! This crate provides a message relay service for cross-chain intents.
 //! It watches GMP endpoint events (MessageSent) and delivers messages to destination contracts.
+pub mod alert;
 pub mod config;
+pub mod config_reload;
 pub mod crypto;
+pub mod dead_letter;
+pub mod deliverer;
+pub mod escrow_link;
 pub mod evm_client;
 pub mod mvm_client;
+pub mod mvm_transaction;
 pub mod svm_client;
 pub mod integrated_gmp_relay;
+pub mod metrics;
+pub mod rate_limit;
+pub mod relay_cursor;
+pub mod status_api;
+pub mod trace;
 
 // Re-export commonly used types
-pub use config::{ApiConfig, ChainConfig, Config, EvmChainConfig, SvmChainConfig, IntegratedGmpConfig};
+pub use config::{ApiConfig, ChainConfig, Config, EvmChainConfig, SvmChainConfig, IntegratedGmpConfig, MetricsConfig, ValidationReport};
 pub use crypto::CryptoService;
-pub use integrated_gmp_relay::{DeliveryAttempt, NativeGmpRelay, NativeGmpRelayConfig, MAX_DELIVERY_RETRIES};
+pub use integrated_gmp_relay::{BackfillEntry, BackfillResult, DeliveryAttempt, NativeGmpRelay, NativeGmpRelayConfig, PendingMessage, RelayTunables};
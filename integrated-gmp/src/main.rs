@@ -4,7 +4,16 @@
 //! and delivers cross-chain messages by calling `deliver_message` on destination chains.
 //!
 //! Integrated-gmp is a pure relay — invisible to clients. The coordinator is the single
-//! API surface for frontends and solvers.
+//! API surface for frontends and solvers. An optional operator status API (`[api] enabled`)
+//! exposes undelivered messages for incident triage, and an optional Prometheus
+//! endpoint (`[metrics] enabled`) serves relay metrics on its own port.
+//!
+//! `integrated-gmp backfill --chain <mvm|evm|svm> --from <n> --to <n>` replays one
+//! chain's outbound messages over an explicit range (outbox nonces, or blocks on EVM)
+//! instead of starting the relay loop, prints what happened to each message, and exits.
+//!
+//! `integrated-gmp --check-config [--config <path>]` validates the config file, prints
+//! every error and warning, and exits (non-zero if the config is invalid).
 //!
 //! ## Security Requirements
 //!
@@ -13,9 +22,16 @@
 //! In production, this can be used directly with your own relay infrastructure,
 //! or replaced by LZ's endpoint.
 
-use anyhow::Result;
-use integrated_gmp::{Config, CryptoService, NativeGmpRelay, NativeGmpRelayConfig};
-use tracing::info;
+use anyhow::{Context, Result};
+use chain_clients_common::init_logging;
+use integrated_gmp::config_reload::{self, ConfigReloader};
+use integrated_gmp::{
+    metrics, status_api, BackfillResult, Config, CryptoService, NativeGmpRelay, NativeGmpRelayConfig,
+    ValidationReport,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info};
 
 // ============================================================================
 // MAIN APPLICATION ENTRY POINT
@@ -27,32 +43,45 @@ use tracing::info;
 /// 1. Initializes logging and tracing
 /// 2. Loads configuration from TOML file
 /// 3. Initializes the integrated GMP relay
-/// 4. Runs the relay until shutdown
+/// 4. Starts the operator status API (if enabled)
+/// 5. Starts the metrics endpoint (if enabled)
+/// 6. Starts the config file watcher (if reload is enabled)
+/// 7. Runs the relay until shutdown
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Parse command line arguments
+    let args: Vec<String> = std::env::args().collect();
+
     // Initialize structured logging for debugging and monitoring
-    tracing_subscriber::fmt::init();
+    init_logging("integrated-gmp", args.iter().any(|arg| arg == "--log-json"));
 
-    info!("Starting Integrated GMP Relay Service");
+    if args.get(1).map(String::as_str) == Some("backfill") {
+        return run_backfill(&args[2..]).await;
+    }
 
-    // Parse command line arguments
-    let args: Vec<String> = std::env::args().collect();
+    info!("Starting Integrated GMP Relay Service");
 
     // Check for help flag
     if args.iter().any(|arg| arg == "--help" || arg == "-h") {
         println!("Integrated GMP Relay Service");
         println!();
         println!("Usage: integrated-gmp [OPTIONS]");
+        println!("       integrated-gmp backfill --chain <mvm|evm|svm> --from <n> --to <n> [--chain-id <id>] [--config <path>]");
         println!();
         println!("Options:");
         println!("  --testnet, -t     Use testnet configuration (config/integrated-gmp_testnet.toml)");
         println!("  --config <path>   Use custom config file path (overrides --testnet)");
+        println!("  --check-config    Validate the config file, print a report and exit (non-zero if invalid)");
+        println!("  --log-json        Log one JSON object per line (same as LOG_FORMAT=json)");
         println!("  --help, -h        Show this help message");
         println!();
+        println!("Run `integrated-gmp backfill --help` for the backfill options.");
+        println!();
         println!("Environment variables:");
         println!(
             "  INTEGRATED_GMP_CONFIG_PATH    Path to config file (overrides --config and --testnet)"
         );
+        println!("  LOG_FORMAT                    \"json\" for JSON logs (default: human-readable)");
         return Ok(());
     }
 
@@ -77,6 +106,17 @@ async fn main() -> Result<()> {
         info!("Using testnet configuration");
     }
 
+    // Validate only: the relay is not started and no keys are read
+    if args.iter().any(|arg| arg == "--check-config") {
+        let config_path = Config::path();
+        let report = Config::check(&config_path);
+        print_config_report(&config_path, &report);
+        if !report.is_valid() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Load configuration from config/integrated-gmp.toml (or INTEGRATED_GMP_CONFIG_PATH)
     let config = Config::load()?;
     info!("Configuration loaded successfully");
@@ -84,10 +124,178 @@ async fn main() -> Result<()> {
     // Initialize and run the integrated GMP relay
     let relay_config = NativeGmpRelayConfig::from_config(&config)?;
     let crypto_service = CryptoService::new(&config)?;
-    let relay = NativeGmpRelay::new(relay_config, crypto_service)?;
+    let relay = Arc::new(NativeGmpRelay::new(relay_config, crypto_service)?);
 
     info!("Integrated GMP relay initialized successfully");
 
+    if config.api.enabled {
+        let status_relay = relay.clone();
+        let api_config = config.api.clone();
+        tokio::spawn(async move {
+            if let Err(e) = status_api::serve(status_relay, &api_config).await {
+                error!("Status API stopped: {:#}", e);
+            }
+        });
+    }
+
+    if config.metrics.enabled {
+        let metrics_relay = relay.clone();
+        let metrics_config = config.metrics.clone();
+        tokio::spawn(async move {
+            if let Err(e) = metrics::serve(metrics_relay, &metrics_config).await {
+                error!("Metrics endpoint stopped: {:#}", e);
+            }
+        });
+    }
+
+    if let Some(interval_ms) = config.integrated_gmp.config_reload_interval_ms {
+        let reloader = ConfigReloader::new(Config::path(), config.clone())?;
+        tokio::spawn(config_reload::watch(
+            relay.clone(),
+            reloader,
+            Duration::from_millis(interval_ms),
+        ));
+    }
+
+    // Stop the per-chain polling tasks on Ctrl-C
+    let shutdown_relay = relay.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            info!("Shutdown signal received, stopping relay");
+            shutdown_relay.shutdown();
+        }
+    });
+
     // Run the relay (this blocks until shutdown)
     relay.run().await
 }
+
+// ============================================================================
+// CONFIG CHECK
+// ============================================================================
+
+/// Print the `--check-config` report: every error and warning, then the verdict.
+fn print_config_report(config_path: &str, report: &ValidationReport) {
+    println!("Config check: {}", config_path);
+    println!("Errors ({}):", report.errors.len());
+    for error in &report.errors {
+        println!("  - {}", error);
+    }
+    println!("Warnings ({}):", report.warnings.len());
+    for warning in &report.warnings {
+        println!("  - {}", warning);
+    }
+    println!("Result: {}", if report.is_valid() { "valid" } else { "invalid" });
+}
+
+// ============================================================================
+// BACKFILL COMMAND
+// ============================================================================
+
+/// Replay one chain's outbound messages over an explicit range, print a line per
+/// message and a summary, then exit (non-zero if any delivery failed).
+///
+/// The relay loop, status API, metrics endpoint and config watcher are not started.
+async fn run_backfill(args: &[String]) -> Result<()> {
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("Usage: integrated-gmp backfill --chain <mvm|evm|svm> --from <n> --to <n> [--chain-id <id>] [--config <path>]");
+        println!();
+        println!("Replays outbound messages in an inclusive range through the regular delivery path.");
+        println!("Messages the destination already recorded are skipped. Read cursors are not moved.");
+        println!();
+        println!("Options:");
+        println!("  --chain <type>     Source chain type: mvm, evm or svm");
+        println!("  --from <n>         First outbox nonce (mvm, svm) or block number (evm)");
+        println!("  --to <n>           Last outbox nonce or block number (inclusive)");
+        println!("  --chain-id <id>    Source chain ID (default: the hub for mvm, the only configured chain otherwise)");
+        println!("  --config <path>    Use custom config file path");
+        println!("  --log-json         Log one JSON object per line (same as LOG_FORMAT=json)");
+        return Ok(());
+    }
+
+    let mut chain_type = None;
+    let mut chain_id: Option<u32> = None;
+    let mut from: Option<u64> = None;
+    let mut to: Option<u64> = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--chain" if i + 1 < args.len() => {
+                chain_type = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--chain-id" if i + 1 < args.len() => {
+                chain_id = Some(args[i + 1].parse().context("Invalid --chain-id value")?);
+                i += 1;
+            }
+            "--from" if i + 1 < args.len() => {
+                from = Some(args[i + 1].parse().context("Invalid --from value")?);
+                i += 1;
+            }
+            "--to" if i + 1 < args.len() => {
+                to = Some(args[i + 1].parse().context("Invalid --to value")?);
+                i += 1;
+            }
+            "--config" if i + 1 < args.len() => {
+                std::env::set_var("INTEGRATED_GMP_CONFIG_PATH", &args[i + 1]);
+                i += 1;
+            }
+            // Handled by the logging setup in main
+            "--log-json" => {}
+            other => anyhow::bail!("Unknown backfill argument '{}'", other),
+        }
+        i += 1;
+    }
+
+    let chain_type = chain_type.context("--chain is required")?;
+    let from = from.context("--from is required")?;
+    let to = to.context("--to is required")?;
+
+    let config = Config::load()?;
+    let relay_config = NativeGmpRelayConfig::from_config(&config)?;
+    let crypto_service = CryptoService::new(&config)?;
+    let relay = NativeGmpRelay::new(relay_config, crypto_service)?;
+
+    let target = relay.backfill_target(&chain_type, chain_id)?;
+    info!("Backfilling {} from {} to {}", target.poll_key(), from, to);
+    let entries = relay.backfill(target, from, to).await?;
+
+    let (mut delivered, mut skipped, mut failed) = (0, 0, 0);
+    for entry in &entries {
+        let dst = entry
+            .dst_chain_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        let outcome = match &entry.result {
+            BackfillResult::Delivered => {
+                delivered += 1;
+                "delivered".to_string()
+            }
+            BackfillResult::Skipped(reason) => {
+                skipped += 1;
+                format!("skipped: {}", reason)
+            }
+            BackfillResult::Failed(error) => {
+                failed += 1;
+                format!("failed: {}", error)
+            }
+        };
+        println!(
+            "src_chain={} nonce={} dst_chain={} {}",
+            entry.src_chain_id, entry.nonce, dst, outcome
+        );
+    }
+    println!(
+        "Backfill of {} messages: {} delivered, {} skipped, {} failed",
+        entries.len(),
+        delivered,
+        skipped,
+        failed
+    );
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,197 @@
+//! Prometheus Metrics Module
+//!
+//! Counters, gauges and histograms for the relay, served as `GET /metrics` on a
+//! standalone HTTP server when `[metrics] enabled = true`. When disabled the relay
+//! holds no metrics and the instrumented paths skip recording entirely.
+//!
+//! Exported metrics:
+//! - `integrated_gmp_deliveries_total{src_chain_id, dst_chain_id, outcome}`
+//! - `integrated_gmp_delivery_duration_seconds{dst_chain_id}`
+//! - `integrated_gmp_polls_total{chain}` and `integrated_gmp_poll_errors_total{chain}`
+//! - `integrated_gmp_poll_duration_seconds{chain}`
+//! - `integrated_gmp_pending_messages` and `integrated_gmp_dead_letters` (set at scrape time)
+
+use anyhow::{Context, Result};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+use warp::{Filter, Rejection, Reply};
+
+use crate::config::MetricsConfig;
+use crate::deliverer::DeliveryOutcome;
+use crate::integrated_gmp_relay::NativeGmpRelay;
+
+// ============================================================================
+// METRICS
+// ============================================================================
+
+/// Relay metrics and the registry they are exported from.
+pub struct RelayMetrics {
+    registry: Registry,
+    deliveries: IntCounterVec,
+    delivery_duration: HistogramVec,
+    polls: IntCounterVec,
+    poll_errors: IntCounterVec,
+    poll_duration: HistogramVec,
+    pending_messages: IntGauge,
+    dead_letters: IntGauge,
+}
+
+impl RelayMetrics {
+    /// Create and register all relay metrics in a fresh registry.
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+        let deliveries = IntCounterVec::new(
+            Opts::new("integrated_gmp_deliveries_total", "Delivery attempts by route and outcome"),
+            &["src_chain_id", "dst_chain_id", "outcome"],
+        )?;
+        let delivery_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "integrated_gmp_delivery_duration_seconds",
+                "Time spent delivering one message, by destination chain",
+            ),
+            &["dst_chain_id"],
+        )?;
+        let polls = IntCounterVec::new(
+            Opts::new("integrated_gmp_polls_total", "Source chain polls"),
+            &["chain"],
+        )?;
+        let poll_errors = IntCounterVec::new(
+            Opts::new("integrated_gmp_poll_errors_total", "Source chain polls that failed with an RPC error"),
+            &["chain"],
+        )?;
+        let poll_duration = HistogramVec::new(
+            HistogramOpts::new("integrated_gmp_poll_duration_seconds", "Time spent polling one source chain"),
+            &["chain"],
+        )?;
+        let pending_messages = IntGauge::new(
+            "integrated_gmp_pending_messages",
+            "Messages attempted but not yet delivered",
+        )?;
+        let dead_letters = IntGauge::new(
+            "integrated_gmp_dead_letters",
+            "Messages skipped after a permanent delivery error",
+        )?;
+
+        registry.register(Box::new(deliveries.clone()))?;
+        registry.register(Box::new(delivery_duration.clone()))?;
+        registry.register(Box::new(polls.clone()))?;
+        registry.register(Box::new(poll_errors.clone()))?;
+        registry.register(Box::new(poll_duration.clone()))?;
+        registry.register(Box::new(pending_messages.clone()))?;
+        registry.register(Box::new(dead_letters.clone()))?;
+
+        Ok(Self {
+            registry,
+            deliveries,
+            delivery_duration,
+            polls,
+            poll_errors,
+            poll_duration,
+            pending_messages,
+            dead_letters,
+        })
+    }
+
+    /// Record one delivery attempt and how long it took.
+    pub fn record_delivery(
+        &self,
+        src_chain_id: u32,
+        dst_chain_id: u32,
+        outcome: &DeliveryOutcome,
+        elapsed: Duration,
+    ) {
+        let outcome = match outcome {
+            DeliveryOutcome::Delivered => "delivered",
+            DeliveryOutcome::AlreadyDelivered => "already_delivered",
+            DeliveryOutcome::PermanentFailure(_) => "permanent_failure",
+            DeliveryOutcome::TransientFailure(_) => "transient_failure",
+            DeliveryOutcome::Unconfirmed(_) => "unconfirmed",
+            DeliveryOutcome::Paused(_) => "paused",
+        };
+        let dst_chain_id = dst_chain_id.to_string();
+        self.deliveries
+            .with_label_values(&[&src_chain_id.to_string(), &dst_chain_id, outcome])
+            .inc();
+        self.delivery_duration
+            .with_label_values(&[&dst_chain_id])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Record one source chain poll, its duration, and whether it failed.
+    pub fn record_poll(&self, chain: &str, elapsed: Duration, failed: bool) {
+        self.polls.with_label_values(&[chain]).inc();
+        if failed {
+            self.poll_errors.with_label_values(&[chain]).inc();
+        }
+        self.poll_duration
+            .with_label_values(&[chain])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Set the queue-size gauges.
+    pub fn set_queue_sizes(&self, pending_messages: usize, dead_letters: usize) {
+        self.pending_messages.set(pending_messages as i64);
+        self.dead_letters.set(dead_letters as i64);
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+// ============================================================================
+// ROUTES
+// ============================================================================
+
+/// Build the `GET /metrics` route over a running relay.
+pub fn routes(
+    relay: Arc<NativeGmpRelay>,
+) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    warp::path("metrics")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::any().map(move || relay.clone()))
+        .and_then(get_metrics_handler)
+}
+
+/// Handler for `GET /metrics`.
+///
+/// Responds 404 when the relay was built without metrics.
+async fn get_metrics_handler(relay: Arc<NativeGmpRelay>) -> Result<impl Reply, Rejection> {
+    let Some(metrics) = relay.metrics() else {
+        return Ok(warp::http::Response::builder()
+            .status(warp::http::StatusCode::NOT_FOUND)
+            .body("Metrics are disabled".to_string())
+            .unwrap());
+    };
+
+    metrics.set_queue_sizes(relay.pending_messages().await.len(), relay.dead_letters().await.len());
+    let response = match metrics.render() {
+        Ok(body) => warp::http::Response::builder()
+            .header("content-type", TextEncoder::new().format_type())
+            .body(body),
+        Err(e) => warp::http::Response::builder()
+            .status(warp::http::StatusCode::INTERNAL_SERVER_ERROR)
+            .body(format!("Failed to render metrics: {:#}", e)),
+    };
+    Ok(response.unwrap())
+}
+
+/// Serve the metrics endpoint until the process exits.
+pub async fn serve(relay: Arc<NativeGmpRelay>, config: &MetricsConfig) -> Result<()> {
+    let addr: SocketAddr = format!("{}:{}", config.host, config.port)
+        .parse()
+        .with_context(|| format!("Invalid metrics.host/metrics.port: {}:{}", config.host, config.port))?;
+
+    info!("Metrics endpoint listening on http://{}/metrics", addr);
+    warp::serve(routes(relay)).run(addr).await;
+    Ok(())
+}
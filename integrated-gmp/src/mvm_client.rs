@@ -1,15 +1,52 @@
 //! GMP-specific MVM Client
 //!
 //! Wraps the shared `chain_clients_mvm::MvmClient` and adds GMP-specific methods
-//! for relay authorization, outbox reading, message parsing, and message delivery.
+//! for relay authorization, outbox reading, message parsing, message delivery,
+//! and per-intent state views.
 
 use anyhow::{Context, Result};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use chain_clients_mvm::MvmClient;
+use chain_clients_mvm::{MvmClient, ViewU64};
+use ed25519_dalek::SigningKey;
+#[cfg(feature = "aptos-cli")]
 use std::process::Command;
-use tracing::{debug, error, info, warn};
+use std::time::Duration;
+use tracing::{debug, info, warn};
 
+use crate::config::{MvmCommitment, MvmSubmission};
 use crate::integrated_gmp_relay::GmpMessage;
+use crate::mvm_transaction::{
+    cleanup_delivered_messages_payload, deliver_message_payload, ed25519_account_address,
+    RawTransaction,
+};
+
+/// Interval between transaction-by-hash queries while waiting for a commit
+const CONFIRMATION_POLL_INTERVAL_MS: u64 = 500;
+
+/// Gas limit for natively submitted deliveries
+const NATIVE_MAX_GAS_AMOUNT: u64 = 200_000;
+
+/// Lifetime of a natively submitted transaction before the node discards it
+const NATIVE_TX_EXPIRATION_SECS: u64 = 60;
+
+/// Content type for BCS-encoded signed transactions on `POST /v1/transactions`
+const SIGNED_TRANSACTION_CONTENT_TYPE: &str = "application/x.aptos.signed_transaction+bcs";
+
+// ============================================================================
+// ERRORS
+// ============================================================================
+
+/// A delivery transaction was submitted but not seen committed before the timeout.
+///
+/// The relay treats this as "not yet delivered" and holds its cursor so the
+/// message is re-attempted (delivery is idempotent on the destination).
+#[derive(Debug, thiserror::Error)]
+#[error("MVM transaction {tx_hash} on chain_id={chain_id} not committed within {timeout_ms}ms")]
+pub struct DeliveryNotConfirmed {
+    pub chain_id: u32,
+    pub tx_hash: String,
+    pub timeout_ms: u64,
+}
 
 // ============================================================================
 // CLIENT
@@ -19,19 +56,50 @@ pub struct GmpMvmClient {
     mvm_client: MvmClient,
     module_addr: String,
     chain_id: u32,
+    commitment: MvmCommitment,
+    confirmation_timeout: Duration,
+    submission: MvmSubmission,
+    cli_fallback: bool,
+    /// HTTP client for native submission
+    http_client: reqwest::Client,
 }
 
 impl GmpMvmClient {
     pub fn new(rpc_url: &str, module_addr: &str, chain_id: u32) -> Result<Self> {
         let mvm_client =
             MvmClient::new(rpc_url).context("Failed to create MVM client")?;
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .no_proxy()
+            .build()
+            .context("Failed to create HTTP client")?;
         Ok(Self {
             mvm_client,
             module_addr: module_addr.to_string(),
             chain_id,
+            commitment: MvmCommitment::Submitted,
+            confirmation_timeout: Duration::ZERO,
+            submission: MvmSubmission::Native,
+            cli_fallback: false,
+            http_client,
         })
     }
 
+    /// Require deliveries to reach `commitment`, waiting at most `timeout` for each.
+    pub fn with_commitment(mut self, commitment: MvmCommitment, timeout: Duration) -> Self {
+        self.commitment = commitment;
+        self.confirmation_timeout = timeout;
+        self
+    }
+
+    /// Submit deliveries via `submission`, optionally falling back to the aptos CLI
+    /// when native submission fails.
+    pub fn with_submission(mut self, submission: MvmSubmission, cli_fallback: bool) -> Self {
+        self.submission = submission;
+        self.cli_fallback = cli_fallback;
+        self
+    }
+
     pub fn chain_id(&self) -> u32 {
         self.chain_id
     }
@@ -40,6 +108,11 @@ impl GmpMvmClient {
         &self.module_addr
     }
 
+    /// Remote GMP endpoint address the relay sends for messages read from this chain's outbox.
+    pub fn remote_gmp_endpoint_addr(&self) -> String {
+        normalize_address(&self.module_addr)
+    }
+
     pub fn mvm_client(&self) -> &MvmClient {
         &self.mvm_client
     }
@@ -74,44 +147,96 @@ impl GmpMvmClient {
     }
 
     // ========================================================================
-    // Outbox reading
+    // Trusted remote GMP endpoints
     // ========================================================================
 
-    /// Get the next nonce from the GMP sender outbox.
-    pub async fn get_next_nonce(&self) -> Result<u64> {
-        let next_nonce_result = self
+    /// Remote GMP endpoint addresses trusted for `src_chain_id`, via
+    /// `intent_gmp::get_remote_gmp_endpoint_addrs`. Empty if none is configured.
+    pub async fn get_remote_gmp_endpoint_addrs(&self, src_chain_id: u32) -> Result<Vec<String>> {
+        let result = self
             .mvm_client
             .call_view_function(
                 &self.module_addr,
-                "gmp_sender",
-                "get_next_nonce",
+                "intent_gmp",
+                "get_remote_gmp_endpoint_addrs",
                 vec![],
+                vec![serde_json::json!(src_chain_id)],
+            )
+            .await
+            .context("Failed to call get_remote_gmp_endpoint_addrs")?;
+
+        let addrs = result
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!(
+                "Unexpected response format from get_remote_gmp_endpoint_addrs: {result}"
+            ))?;
+        addrs
+            .iter()
+            .map(|addr| parse_view_bytes(addr).map(|hex| format!("0x{}", hex)))
+            .collect()
+    }
+
+    // ========================================================================
+    // Message delivery check
+    // ========================================================================
+
+    /// Check if a message was already delivered, via `intent_gmp::is_message_delivered`.
+    pub async fn is_message_delivered(&self, intent_id: &[u8; 32], msg_type: u8) -> Result<bool> {
+        let result = self
+            .mvm_client
+            .call_view_function(
+                &self.module_addr,
+                "intent_gmp",
+                "is_message_delivered",
                 vec![],
+                vec![
+                    serde_json::json!(format!("0x{}", hex::encode(intent_id))),
+                    serde_json::json!(msg_type),
+                ],
             )
             .await
-            .context("Failed to call get_next_nonce")?;
+            .context("Failed to call is_message_delivered")?;
 
-        next_nonce_result
+        result
             .as_array()
             .and_then(|arr| arr.first())
-            .and_then(|v| {
-                v.as_str()
-                    .and_then(|s| s.parse().ok())
-                    .or_else(|| v.as_u64())
-            })
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Failed to parse get_next_nonce response: {:?}",
-                    next_nonce_result
-                )
-            })
+            .and_then(|v| v.as_bool())
+            .ok_or_else(|| anyhow::anyhow!(
+                "Unexpected response format from is_message_delivered: {result}"
+            ))
+    }
+
+    // ========================================================================
+    // Outbox reading
+    // ========================================================================
+
+    /// Get the next nonce from the GMP sender outbox.
+    pub async fn get_next_nonce(&self) -> Result<u64> {
+        self.mvm_client
+            .call_view_u64(
+                &self.module_addr,
+                "gmp_sender",
+                "get_next_nonce",
+                vec![],
+                vec![],
+            )
+            .await
+            .context("Failed to call get_next_nonce")
     }
 
     /// Read a message from the outbox by nonce.
     pub async fn get_message(&self, nonce: u64) -> Result<GmpMessage> {
-        let msg_value = self
+        // (dst_chain_id, dst_addr, payload, sender)
+        let (dst_chain_id, dst_addr, payload, _sender): (
+            ViewU64,
+            serde_json::Value,
+            serde_json::Value,
+            serde::de::IgnoredAny,
+        ) = self
             .mvm_client
-            .call_view_function(
+            .call_view_typed(
                 &self.module_addr,
                 "gmp_sender",
                 "get_message",
@@ -119,31 +244,17 @@ impl GmpMvmClient {
                 vec![serde_json::json!(nonce.to_string())],
             )
             .await
-            .context("Failed to call get_message")?;
-
-        let arr = msg_value
-            .as_array()
-            .context("get_message result is not an array")?;
-        if arr.len() < 4 {
-            anyhow::bail!(
-                "get_message({}) returned {} elements, expected 4",
-                nonce,
-                arr.len()
-            );
-        }
+            .with_context(|| format!("Failed to call get_message({})", nonce))?;
 
-        let dst_chain_id: u32 = arr[0]
-            .as_str()
-            .and_then(|s| s.parse().ok())
-            .or_else(|| arr[0].as_u64().map(|n| n as u32))
-            .context("Failed to parse dst_chain_id")?;
+        let dst_chain_id = u32::try_from(dst_chain_id.0)
+            .with_context(|| format!("dst_chain_id {} does not fit in u32", dst_chain_id.0))?;
 
-        let dst_addr_hex = parse_view_bytes(&arr[1])?;
-        let payload_hex = parse_view_bytes(&arr[2])?;
+        let dst_addr_hex = parse_view_bytes(&dst_addr)?;
+        let payload_hex = parse_view_bytes(&payload)?;
 
         Ok(GmpMessage {
             src_chain_id: self.chain_id,
-            remote_gmp_endpoint_addr: normalize_address(&self.module_addr),
+            remote_gmp_endpoint_addr: self.remote_gmp_endpoint_addr(),
             dst_chain_id,
             dst_addr: format!("0x{}", dst_addr_hex),
             payload: format!("0x{}", payload_hex),
@@ -151,16 +262,119 @@ impl GmpMvmClient {
         })
     }
 
+    // ========================================================================
+    // Intent state views
+    // ========================================================================
+
+    /// Read the hub GMP state for an intent via `gmp_intent_state::get_intent_state`.
+    ///
+    /// Returns `(exists, escrow_confirmed, fulfillment_proof_received)`.
+    pub async fn get_intent_state(&self, intent_id: &[u8; 32]) -> Result<(bool, bool, bool)> {
+        let result = self
+            .mvm_client
+            .call_view_function(
+                &self.module_addr,
+                "gmp_intent_state",
+                "get_intent_state",
+                vec![],
+                vec![serde_json::json!(format!("0x{}", hex::encode(intent_id)))],
+            )
+            .await
+            .context("Failed to call get_intent_state")?;
+
+        let flags: Vec<bool> = result
+            .as_array()
+            .map(|arr| arr.iter().filter_map(|v| v.as_bool()).collect())
+            .unwrap_or_default();
+        if flags.len() != 3 {
+            anyhow::bail!("Unexpected response format from get_intent_state: {result}");
+        }
+
+        Ok((flags[0], flags[1], flags[2]))
+    }
+
+    /// Call a `fun <name>(intent_id: vector<u8>): bool` view on this chain's intent module
+    /// (e.g. `intent_inflow_escrow::has_requirements`).
+    pub async fn view_intent_flag(
+        &self,
+        module_name: &str,
+        function_name: &str,
+        intent_id: &[u8; 32],
+    ) -> Result<bool> {
+        let result = self
+            .mvm_client
+            .call_view_function(
+                &self.module_addr,
+                module_name,
+                function_name,
+                vec![],
+                vec![serde_json::json!(format!("0x{}", hex::encode(intent_id)))],
+            )
+            .await
+            .with_context(|| format!("Failed to call {}::{}", module_name, function_name))?;
+
+        result
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|v| v.as_bool())
+            .ok_or_else(|| anyhow::anyhow!(
+                "Unexpected response format from {}::{}: {result}",
+                module_name, function_name
+            ))
+    }
+
     // ========================================================================
     // Message delivery
     // ========================================================================
 
-    /// Deliver a GMP message to this MVM chain via aptos CLI.
+    /// Deliver a GMP message to this MVM chain.
+    ///
+    /// Submits `deliver_message_entry` via the configured path. With native submission
+    /// and CLI fallback enabled, a native failure before the node accepts the
+    /// transaction is retried once through the aptos CLI.
     pub async fn deliver_message(
         &self,
         message: &GmpMessage,
         operator_private_key: &str,
     ) -> Result<()> {
+        let tx_hash = match self.submission {
+            MvmSubmission::Cli => self.deliver_via_cli(message, operator_private_key)?,
+            MvmSubmission::Native => {
+                match self.deliver_via_native(message, operator_private_key).await {
+                    Ok(tx_hash) => Some(tx_hash),
+                    Err(e) if self.cli_fallback => {
+                        warn!(
+                            "MVM chain_id={} native deliver_message failed, falling back to aptos CLI: nonce={}, error={:#}",
+                            self.chain_id, message.nonce, e
+                        );
+                        self.deliver_via_cli(message, operator_private_key)
+                            .with_context(|| format!("native submission failed first: {:#}", e))?
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        };
+
+        if self.commitment == MvmCommitment::Committed {
+            let tx_hash = tx_hash.with_context(|| {
+                format!(
+                    "deliver_message_entry on chain_id={} returned no transaction hash to confirm",
+                    self.chain_id
+                )
+            })?;
+            self.wait_for_commit(&tx_hash, self.confirmation_timeout).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Submit `deliver_message_entry` via `aptos move run` and return the transaction hash.
+    #[cfg(feature = "aptos-cli")]
+    fn deliver_via_cli(
+        &self,
+        message: &GmpMessage,
+        operator_private_key: &str,
+    ) -> Result<Option<String>> {
         let remote_gmp_endpoint_addr_hex = message
             .remote_gmp_endpoint_addr
             .strip_prefix("0x")
@@ -233,7 +447,7 @@ impl GmpMvmClient {
 
         let vm_success = check_vm_status_success(&stdout)?;
         if !vm_success {
-            error!(
+            tracing::error!(
                 "MVM chain_id={} deliver_message VM execution failed: nonce={}, tx_hash={:?}, stdout={}",
                 self.chain_id, message.nonce, tx_hash, stdout
             );
@@ -248,8 +462,321 @@ impl GmpMvmClient {
             self.chain_id, message.nonce, tx_hash
         );
 
+        Ok(tx_hash)
+    }
+
+    /// Without the `aptos-cli` feature there is no CLI path to submit through.
+    #[cfg(not(feature = "aptos-cli"))]
+    fn deliver_via_cli(
+        &self,
+        _message: &GmpMessage,
+        _operator_private_key: &str,
+    ) -> Result<Option<String>> {
+        anyhow::bail!(
+            "aptos CLI submission is unavailable for chain_id={} (built without the aptos-cli feature)",
+            self.chain_id
+        )
+    }
+
+    /// Build, sign, and submit `deliver_message_entry` over the node REST API and
+    /// return the hash of the accepted transaction.
+    ///
+    /// The node accepting the transaction is the `submitted` level; VM execution is
+    /// only checked when `mvm_commitment` is `committed`.
+    async fn deliver_via_native(
+        &self,
+        message: &GmpMessage,
+        operator_private_key: &str,
+    ) -> Result<String> {
+        let remote_gmp_endpoint_addr = hex::decode(
+            message
+                .remote_gmp_endpoint_addr
+                .strip_prefix("0x")
+                .unwrap_or(&message.remote_gmp_endpoint_addr),
+        )
+        .context("Invalid remote_gmp_endpoint_addr hex")?;
+        let payload = hex::decode(message.payload.strip_prefix("0x").unwrap_or(&message.payload))
+            .context("Invalid payload hex")?;
+
+        let payload = deliver_message_payload(
+            &self.module_addr,
+            message.src_chain_id,
+            &remote_gmp_endpoint_addr,
+            &payload,
+        )?;
+
+        debug!(
+            "MVM chain_id={} deliver_message native submit: src_chain_id={}, nonce={}",
+            self.chain_id, message.src_chain_id, message.nonce
+        );
+        let tx_hash = self.submit_entry_function(payload, operator_private_key).await?;
+
+        info!(
+            "MVM chain_id={} deliver_message submitted natively: nonce={}, tx_hash={}",
+            self.chain_id, message.nonce, tx_hash
+        );
+
+        Ok(tx_hash)
+    }
+
+    /// Sign a BCS-encoded entry-function `payload` with the operator key, submit it over
+    /// the node REST API, and return the hash of the accepted transaction.
+    ///
+    /// Sender, sequence number, chain ID, and gas price are read from the node.
+    async fn submit_entry_function(
+        &self,
+        payload: Vec<u8>,
+        operator_private_key: &str,
+    ) -> Result<String> {
+        let private_key_bytes: [u8; 32] = STANDARD
+            .decode(operator_private_key)
+            .context("Failed to decode base64 private key")?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Operator private key must be 32 bytes"))?;
+        let signing_key = SigningKey::from_bytes(&private_key_bytes);
+        let sender = ed25519_account_address(&signing_key);
+        let sender_hex = format!("0x{}", hex::encode(sender));
+
+        let chain_id = self.get_ledger_chain_id().await?;
+        let sequence_number: u64 = self
+            .mvm_client
+            .get_account(&sender_hex)
+            .await
+            .with_context(|| format!("Failed to read account {}", sender_hex))?
+            .sequence_number
+            .parse()
+            .context("Invalid sequence_number in account response")?;
+        let gas_unit_price = self.get_gas_unit_price().await?;
+        let expiration_timestamp_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .context("System clock is before the Unix epoch")?
+            .as_secs()
+            + NATIVE_TX_EXPIRATION_SECS;
+
+        let raw_txn = RawTransaction {
+            sender,
+            sequence_number,
+            payload,
+            max_gas_amount: NATIVE_MAX_GAS_AMOUNT,
+            gas_unit_price,
+            expiration_timestamp_secs,
+            chain_id,
+        };
+
+        debug!(
+            "MVM chain_id={} native submit: sender={}, sequence_number={}",
+            self.chain_id, sender_hex, sequence_number
+        );
+
+        let url = format!("{}/v1/transactions", self.mvm_client.base_url());
+        let response = self
+            .http_client
+            .post(&url)
+            .header("Content-Type", SIGNED_TRANSACTION_CONTENT_TYPE)
+            .body(raw_txn.sign(&signing_key))
+            .send()
+            .await
+            .context("Failed to submit transaction")?;
+        let status = response.status();
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .context("Failed to parse transaction submission response")?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "Transaction submission rejected on chain_id={}: status={}, body={}",
+                self.chain_id, status, body
+            );
+        }
+
+        body.get("hash")
+            .and_then(|h| h.as_str())
+            .map(|h| h.to_string())
+            .with_context(|| format!("Transaction submission response has no hash: {}", body))
+    }
+
+    /// Chain ID reported by the node's ledger info (`GET /v1`).
+    async fn get_ledger_chain_id(&self) -> Result<u8> {
+        let url = format!("{}/v1", self.mvm_client.base_url());
+        let info: serde_json::Value = self
+            .http_client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to query ledger info")?
+            .error_for_status()
+            .context("Ledger info request failed")?
+            .json()
+            .await
+            .context("Failed to parse ledger info")?;
+        info.get("chain_id")
+            .and_then(|v| v.as_u64())
+            .and_then(|v| u8::try_from(v).ok())
+            .with_context(|| format!("Unexpected chain_id in ledger info: {}", info))
+    }
+
+    /// Gas unit price estimate from the node (`GET /v1/estimate_gas_price`).
+    async fn get_gas_unit_price(&self) -> Result<u64> {
+        let url = format!("{}/v1/estimate_gas_price", self.mvm_client.base_url());
+        let estimate: serde_json::Value = self
+            .http_client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to query gas price estimate")?
+            .error_for_status()
+            .context("Gas price estimate request failed")?
+            .json()
+            .await
+            .context("Failed to parse gas price estimate")?;
+        estimate
+            .get("gas_estimate")
+            .and_then(|v| v.as_u64())
+            .with_context(|| format!("Unexpected gas price estimate: {}", estimate))
+    }
+
+    /// Query a transaction by hash until it is committed, or `timeout` elapses.
+    ///
+    /// Pending and unknown transactions are retried. Returns `DeliveryNotConfirmed`
+    /// on timeout and an error if the transaction committed with a failed VM status.
+    pub async fn wait_for_commit(&self, tx_hash: &str, timeout: Duration) -> Result<()> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            // Pending transactions lack `version`/`success` and fail to parse as committed
+            match self.mvm_client.get_transaction(tx_hash).await {
+                Ok(tx) if tx.success => {
+                    info!(
+                        "MVM chain_id={} transaction committed: tx_hash={}, version={}",
+                        self.chain_id, tx_hash, tx.version
+                    );
+                    return Ok(());
+                }
+                Ok(tx) => anyhow::bail!(
+                    "MVM transaction {} on chain_id={} committed with a failed VM status: {}",
+                    tx_hash, self.chain_id, tx.vm_status
+                ),
+                Err(e) => debug!(
+                    "MVM chain_id={} transaction {} not committed yet: {:#}",
+                    self.chain_id, tx_hash, e
+                ),
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(DeliveryNotConfirmed {
+                    chain_id: self.chain_id,
+                    tx_hash: tx_hash.to_string(),
+                    timeout_ms: timeout.as_millis() as u64,
+                }
+                .into());
+            }
+            let wait = Duration::from_millis(CONFIRMATION_POLL_INTERVAL_MS).min(deadline - now);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    // ========================================================================
+    // Outbox cleanup
+    // ========================================================================
+
+    /// Remove delivered messages from this chain's outbox via
+    /// `gmp_sender::cleanup_delivered_messages`.
+    ///
+    /// Uses the configured submission path. A native cleanup waits for the transaction
+    /// to commit, bounded by its expiration, so a failed VM status is reported.
+    /// The operator must be the gmp_sender admin or an authorized cleaner.
+    pub async fn cleanup_delivered_messages(
+        &self,
+        nonces: &[u64],
+        operator_private_key: &str,
+    ) -> Result<()> {
+        match self.submission {
+            MvmSubmission::Cli => self.cleanup_via_cli(nonces, operator_private_key)?,
+            MvmSubmission::Native => {
+                let payload = cleanup_delivered_messages_payload(&self.module_addr, nonces)?;
+                let tx_hash = self.submit_entry_function(payload, operator_private_key).await?;
+                self.wait_for_commit(&tx_hash, Duration::from_secs(NATIVE_TX_EXPIRATION_SECS))
+                    .await
+                    .with_context(|| format!(
+                        "cleanup_delivered_messages on chain_id={} did not succeed",
+                        self.chain_id
+                    ))?;
+            }
+        }
+
+        info!(
+            "MVM chain_id={} removed {} delivered outbox message(s): nonces={:?}",
+            self.chain_id, nonces.len(), nonces
+        );
+
+        Ok(())
+    }
+
+    /// Submit `gmp_sender::cleanup_delivered_messages` via `aptos move run`.
+    #[cfg(feature = "aptos-cli")]
+    fn cleanup_via_cli(&self, nonces: &[u64], operator_private_key: &str) -> Result<()> {
+        let private_key_bytes = STANDARD
+            .decode(operator_private_key)
+            .context("Failed to decode base64 private key")?;
+        let private_key_hex = hex::encode(&private_key_bytes);
+
+        let function_id = format!(
+            "{}::gmp_sender::cleanup_delivered_messages",
+            self.module_addr
+        );
+        let nonces_arg = format!(
+            "u64:[{}]",
+            nonces.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",")
+        );
+
+        let rpc_url = self.mvm_client.base_url();
+        let rpc_url_normalized = rpc_url
+            .trim_end_matches('/')
+            .trim_end_matches("/v1");
+
+        let output = Command::new("aptos")
+            .args([
+                "move",
+                "run",
+                "--private-key",
+                &private_key_hex,
+                "--url",
+                rpc_url_normalized,
+                "--assume-yes",
+                "--function-id",
+                &function_id,
+                "--args",
+                &nonces_arg,
+            ])
+            .output()
+            .context("Failed to execute aptos move run")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            anyhow::bail!(
+                "aptos move run failed for cleanup_delivered_messages on chain_id={}: stderr={}, stdout={}",
+                self.chain_id, String::from_utf8_lossy(&output.stderr), stdout
+            );
+        }
+        if !check_vm_status_success(&stdout)? {
+            anyhow::bail!(
+                "cleanup_delivered_messages VM execution failed on chain_id={}: tx_hash={:?}, stdout={}",
+                self.chain_id, extract_transaction_hash(&stdout), stdout
+            );
+        }
+
         Ok(())
     }
+
+    /// Without the `aptos-cli` feature there is no CLI path to submit through.
+    #[cfg(not(feature = "aptos-cli"))]
+    fn cleanup_via_cli(&self, _nonces: &[u64], _operator_private_key: &str) -> Result<()> {
+        anyhow::bail!(
+            "aptos CLI submission is unavailable for chain_id={} (built without the aptos-cli feature)",
+            self.chain_id
+        )
+    }
 }
 
 // ============================================================================
@@ -283,6 +810,8 @@ pub fn parse_view_bytes(value: &serde_json::Value) -> Result<String> {
     }
 }
 
+/// Read the VM `success` flag from aptos CLI JSON output.
+#[cfg(feature = "aptos-cli")]
 pub fn check_vm_status_success(output: &str) -> Result<bool, anyhow::Error> {
     if let Ok(json) = serde_json::from_str::<serde_json::Value>(output) {
         if let Some(result) = json.get("Result") {
@@ -309,6 +838,8 @@ pub fn check_vm_status_success(output: &str) -> Result<bool, anyhow::Error> {
     Ok(true)
 }
 
+/// Read the `transaction_hash` from aptos CLI JSON output.
+#[cfg(feature = "aptos-cli")]
 pub fn extract_transaction_hash(output: &str) -> Option<String> {
     if let Some(start) = output.find("\"transaction_hash\"") {
         let after_key = &output[start..];
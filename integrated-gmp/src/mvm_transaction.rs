@@ -0,0 +1,195 @@
+//! MVM Transaction Encoding
+//!
+//! BCS encoding and Ed25519 signing of Aptos entry-function transactions, used by
+//! the native MVM submission path to send `deliver_message_entry` and
+//! `cleanup_delivered_messages` over the node REST API (`POST /v1/transactions`)
+//! without the aptos CLI.
+//!
+//! Only the subset of the Aptos transaction format the relay needs is encoded:
+//! an entry-function payload with no type arguments, signed by a single Ed25519 key.
+
+use anyhow::{Context, Result};
+use ed25519_dalek::{Signer, SigningKey};
+use sha3::{Digest, Sha3_256};
+
+/// Domain separator hashed into the prefix of every raw-transaction signing message
+const RAW_TRANSACTION_SALT: &[u8] = b"APTOS::RawTransaction";
+
+/// `TransactionPayload::EntryFunction` variant index
+const PAYLOAD_ENTRY_FUNCTION: u8 = 2;
+
+/// `TransactionAuthenticator::Ed25519` variant index
+const AUTHENTICATOR_ED25519: u8 = 0;
+
+/// Ed25519 single-key authentication scheme byte
+const ED25519_SCHEME: u8 = 0x00;
+
+// ============================================================================
+// RAW TRANSACTION
+// ============================================================================
+
+/// An unsigned Aptos transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTransaction {
+    /// Sender account address
+    pub sender: [u8; 32],
+    /// Sender's next sequence number
+    pub sequence_number: u64,
+    /// BCS-encoded `TransactionPayload` (see [`deliver_message_payload`])
+    pub payload: Vec<u8>,
+    /// Maximum gas units the transaction may consume
+    pub max_gas_amount: u64,
+    /// Price per gas unit, in octas
+    pub gas_unit_price: u64,
+    /// Unix timestamp (seconds) after which the transaction is discarded
+    pub expiration_timestamp_secs: u64,
+    /// Chain ID from the node's ledger info
+    pub chain_id: u8,
+}
+
+impl RawTransaction {
+    /// BCS encoding of the raw transaction.
+    pub fn to_bcs(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32 + 8 + self.payload.len() + 8 + 8 + 8 + 1);
+        out.extend_from_slice(&self.sender);
+        out.extend_from_slice(&self.sequence_number.to_le_bytes());
+        out.extend_from_slice(&self.payload);
+        out.extend_from_slice(&self.max_gas_amount.to_le_bytes());
+        out.extend_from_slice(&self.gas_unit_price.to_le_bytes());
+        out.extend_from_slice(&self.expiration_timestamp_secs.to_le_bytes());
+        out.push(self.chain_id);
+        out
+    }
+
+    /// Bytes the sender signs: `sha3_256("APTOS::RawTransaction") || bcs(raw_txn)`.
+    pub fn signing_message(&self) -> Vec<u8> {
+        let mut message = Sha3_256::digest(RAW_TRANSACTION_SALT).to_vec();
+        message.extend_from_slice(&self.to_bcs());
+        message
+    }
+
+    /// Sign with `signing_key` and return the BCS-encoded `SignedTransaction`,
+    /// ready to POST as `application/x.aptos.signed_transaction+bcs`.
+    pub fn sign(&self, signing_key: &SigningKey) -> Vec<u8> {
+        let signature = signing_key.sign(&self.signing_message());
+        let public_key = signing_key.verifying_key();
+
+        let mut out = self.to_bcs();
+        out.push(AUTHENTICATOR_ED25519);
+        write_bytes(&mut out, public_key.as_bytes());
+        write_bytes(&mut out, &signature.to_bytes());
+        out
+    }
+}
+
+// ============================================================================
+// PAYLOADS
+// ============================================================================
+
+/// BCS-encoded `TransactionPayload` calling
+/// `{module_addr}::intent_gmp::deliver_message_entry(src_chain_id, remote_gmp_endpoint_addr, payload)`.
+pub fn deliver_message_payload(
+    module_addr: &str,
+    src_chain_id: u32,
+    remote_gmp_endpoint_addr: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>> {
+    let module_addr = parse_account_address(module_addr)?;
+
+    let mut remote_arg = Vec::new();
+    write_bytes(&mut remote_arg, remote_gmp_endpoint_addr);
+    let mut payload_arg = Vec::new();
+    write_bytes(&mut payload_arg, payload);
+
+    Ok(entry_function_payload(
+        &module_addr,
+        "intent_gmp",
+        "deliver_message_entry",
+        &[src_chain_id.to_le_bytes().to_vec(), remote_arg, payload_arg],
+    ))
+}
+
+/// BCS-encoded `TransactionPayload` calling
+/// `{module_addr}::gmp_sender::cleanup_delivered_messages(nonces)`.
+pub fn cleanup_delivered_messages_payload(module_addr: &str, nonces: &[u64]) -> Result<Vec<u8>> {
+    let module_addr = parse_account_address(module_addr)?;
+
+    let mut nonces_arg = Vec::with_capacity(1 + nonces.len() * 8);
+    write_uleb128(&mut nonces_arg, nonces.len() as u64);
+    for nonce in nonces {
+        nonces_arg.extend_from_slice(&nonce.to_le_bytes());
+    }
+
+    Ok(entry_function_payload(
+        &module_addr,
+        "gmp_sender",
+        "cleanup_delivered_messages",
+        &[nonces_arg],
+    ))
+}
+
+/// BCS-encoded `TransactionPayload::EntryFunction` with no type arguments.
+///
+/// Each element of `args` must already be the BCS encoding of one argument.
+fn entry_function_payload(
+    module_addr: &[u8; 32],
+    module_name: &str,
+    function_name: &str,
+    args: &[Vec<u8>],
+) -> Vec<u8> {
+    let mut out = vec![PAYLOAD_ENTRY_FUNCTION];
+    out.extend_from_slice(module_addr);
+    write_bytes(&mut out, module_name.as_bytes());
+    write_bytes(&mut out, function_name.as_bytes());
+    write_uleb128(&mut out, 0); // type arguments
+    write_uleb128(&mut out, args.len() as u64);
+    for arg in args {
+        write_bytes(&mut out, arg);
+    }
+    out
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Account address of an Ed25519 single-key account: `sha3_256(public_key || 0x00)`.
+pub fn ed25519_account_address(signing_key: &SigningKey) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(signing_key.verifying_key().as_bytes());
+    hasher.update([ED25519_SCHEME]);
+    hasher.finalize().into()
+}
+
+/// Parse a hex account address (with or without `0x`, short forms left-padded).
+pub fn parse_account_address(addr: &str) -> Result<[u8; 32]> {
+    let hex_str = addr.strip_prefix("0x").unwrap_or(addr);
+    if hex_str.is_empty() || hex_str.len() > 64 {
+        anyhow::bail!("Invalid MVM account address '{}'", addr);
+    }
+    let padded = format!("{:0>64}", hex_str);
+    let bytes = hex::decode(&padded)
+        .with_context(|| format!("Invalid MVM account address '{}'", addr))?;
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&bytes);
+    Ok(out)
+}
+
+/// Append a ULEB128-length-prefixed byte sequence.
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_uleb128(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+/// Append `value` as ULEB128.
+fn write_uleb128(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
@@ -0,0 +1,117 @@
+//! RPC Rate Limiting Module
+//!
+//! Token-bucket limiter used to cap RPC calls per second while the relay catches up
+//! on outbox history at startup (backfill). Steady-state polling is not throttled.
+//!
+//! Also provides a concurrency limiter that caps how many deliveries are in flight
+//! at once, bounding fee exposure and RPC load during a backlog burst.
+
+use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+// ============================================================================
+// TOKEN BUCKET
+// ============================================================================
+
+/// Token-bucket rate limiter.
+///
+/// The bucket holds at most one token and refills at `calls_per_second`, so calls
+/// are spread evenly instead of bursting at the start of each second.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Seconds between refilled tokens
+    interval: Duration,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens (0.0..=1.0)
+    tokens: f64,
+    /// When the bucket was last refilled
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter that allows `calls_per_second` calls per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `calls_per_second` is zero (rejected by config validation).
+    pub fn new(calls_per_second: u32) -> Self {
+        assert!(calls_per_second > 0, "calls_per_second must be greater than zero");
+        Self {
+            interval: Duration::from_secs_f64(1.0 / calls_per_second as f64),
+            bucket: Mutex::new(Bucket {
+                tokens: 1.0,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until a token is available and consume it.
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+
+        let now = Instant::now();
+        let refilled = now.duration_since(bucket.last_refill).as_secs_f64() / self.interval.as_secs_f64();
+        bucket.tokens = (bucket.tokens + refilled).min(1.0);
+        bucket.last_refill = now;
+
+        if bucket.tokens < 1.0 {
+            // Holding the lock while waiting keeps concurrent callers in FIFO order
+            let wait = self.interval.mul_f64(1.0 - bucket.tokens);
+            tokio::time::sleep(wait).await;
+            bucket.last_refill = Instant::now();
+            bucket.tokens = 1.0;
+        }
+
+        bucket.tokens -= 1.0;
+    }
+}
+
+// ============================================================================
+// DELIVERY LIMITER
+// ============================================================================
+
+/// Caps the number of deliveries in flight at once.
+///
+/// Callers hold the returned permit for the duration of the delivery; once the cap
+/// is reached, further callers wait (in FIFO order) until a permit is released.
+#[derive(Debug)]
+pub struct DeliveryLimiter {
+    /// Maximum deliveries in flight
+    max_in_flight: usize,
+    slots: Semaphore,
+}
+
+impl DeliveryLimiter {
+    /// Create a limiter that allows `max_in_flight` concurrent deliveries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_in_flight` is zero (rejected by config validation).
+    pub fn new(max_in_flight: u32) -> Self {
+        assert!(max_in_flight > 0, "max_in_flight must be greater than zero");
+        Self {
+            max_in_flight: max_in_flight as usize,
+            slots: Semaphore::new(max_in_flight as usize),
+        }
+    }
+
+    /// Wait until a delivery slot is free and take it.
+    ///
+    /// The slot is released when the returned permit is dropped.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.slots
+            .acquire()
+            .await
+            .expect("Delivery limiter semaphore is never closed")
+    }
+
+    /// Number of deliveries currently holding a slot.
+    pub fn in_flight(&self) -> usize {
+        self.max_in_flight - self.slots.available_permits()
+    }
+}
@@ -0,0 +1,64 @@
+//! Relay Cursor Persistence
+//!
+//! Saves how far the relay has read each source chain (MVM/SVM outbox nonces, EVM
+//! block numbers) to a small JSON file, so a restart resumes where it stopped
+//! instead of skipping messages sent while the relay was down.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Read positions per source chain, keyed by chain ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayCursors {
+    /// Last processed outbox nonce per MVM chain (hub and connected)
+    #[serde(default)]
+    pub mvm_last_nonces: HashMap<u32, u64>,
+    /// Last processed outbox nonce per SVM chain
+    #[serde(default)]
+    pub svm_last_nonces: HashMap<u32, u64>,
+    /// Last scanned block per EVM chain
+    #[serde(default)]
+    pub evm_last_blocks: HashMap<u32, u64>,
+}
+
+/// JSON file holding [`RelayCursors`].
+///
+/// Writes go to a temporary file that is renamed into place, so a crash mid-write
+/// leaves the previous cursors intact.
+#[derive(Debug, Clone)]
+pub struct CursorFile {
+    path: PathBuf,
+}
+
+impl CursorFile {
+    /// Cursor file at `path`. The file is created on the first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Load the saved cursors, or empty cursors if the file does not exist yet.
+    pub fn load(&self) -> Result<RelayCursors> {
+        if !self.path.exists() {
+            return Ok(RelayCursors::default());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read relay cursor file {}", self.path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse relay cursor file {}", self.path.display()))
+    }
+
+    /// Replace the saved cursors.
+    pub fn save(&self, cursors: &RelayCursors) -> Result<()> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec_pretty(cursors)?)
+            .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+        std::fs::rename(&tmp_path, &self.path)
+            .with_context(|| format!("Failed to replace relay cursor file {}", self.path.display()))
+    }
+}
@@ -0,0 +1,150 @@
+//! Operator Status API Module
+//!
+//! HTTP endpoints for incident triage. Integrated-gmp stays invisible to clients (the
+//! coordinator is their API surface); this API is for relay operators and is only
+//! served when `[api] enabled = true`.
+//!
+//! Endpoints:
+//! - `GET /pending`: messages the relay has attempted but not yet delivered, with
+//!   their age, attempt count, and last error
+//! - `GET /dead-letters`: messages skipped after a permanent delivery error
+//! - `POST /dead-letters/{src_chain_id}/{nonce}/retry`: re-attempt one of them
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::info;
+use warp::{Filter, Rejection, Reply};
+
+use crate::config::ApiConfig;
+use crate::dead_letter::DeadLetter;
+use crate::integrated_gmp_relay::{DeadLetterRetry, NativeGmpRelay, PendingMessage};
+
+// ============================================================================
+// RESPONSE TYPES
+// ============================================================================
+
+/// Response body for `GET /pending`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingMessagesResponse {
+    /// Number of pending messages
+    pub count: usize,
+    /// Pending messages ordered by source chain and nonce
+    pub messages: Vec<PendingMessage>,
+}
+
+/// Response body for `GET /dead-letters`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLettersResponse {
+    /// Number of dead-lettered messages
+    pub count: usize,
+    /// Dead-lettered messages ordered by source chain and nonce
+    pub messages: Vec<DeadLetter>,
+}
+
+/// Response body for `POST /dead-letters/{src_chain_id}/{nonce}/retry`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetterRetryResponse {
+    /// True when the message was delivered and removed from the dead-letter store
+    pub delivered: bool,
+    /// Error from the retry, if it failed
+    pub error: Option<String>,
+}
+
+// ============================================================================
+// ROUTES
+// ============================================================================
+
+/// Build the status API routes over a running relay.
+pub fn routes(
+    relay: Arc<NativeGmpRelay>,
+) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    let pending_relay = relay.clone();
+    let pending = warp::path("pending")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::any().map(move || pending_relay.clone()))
+        .and_then(get_pending_handler);
+
+    let dead_letters_relay = relay.clone();
+    let dead_letters = warp::path("dead-letters")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::any().map(move || dead_letters_relay.clone()))
+        .and_then(get_dead_letters_handler);
+
+    let retry = warp::path!("dead-letters" / u32 / u64 / "retry")
+        .and(warp::post())
+        .and(warp::any().map(move || relay.clone()))
+        .and_then(retry_dead_letter_handler);
+
+    pending.or(dead_letters).or(retry)
+}
+
+/// Handler for `GET /pending`.
+async fn get_pending_handler(relay: Arc<NativeGmpRelay>) -> Result<impl Reply, Rejection> {
+    let messages = relay.pending_messages().await;
+    Ok(warp::reply::json(&PendingMessagesResponse {
+        count: messages.len(),
+        messages,
+    }))
+}
+
+/// Handler for `GET /dead-letters`.
+async fn get_dead_letters_handler(relay: Arc<NativeGmpRelay>) -> Result<impl Reply, Rejection> {
+    let messages = relay.dead_letters().await;
+    Ok(warp::reply::json(&DeadLettersResponse {
+        count: messages.len(),
+        messages,
+    }))
+}
+
+/// Handler for `POST /dead-letters/{src_chain_id}/{nonce}/retry`.
+///
+/// Responds 404 when no such message is dead-lettered, 502 when the retry fails.
+async fn retry_dead_letter_handler(
+    src_chain_id: u32,
+    nonce: u64,
+    relay: Arc<NativeGmpRelay>,
+) -> Result<impl Reply, Rejection> {
+    let (status, body) = match relay.retry_dead_letter(src_chain_id, nonce).await {
+        DeadLetterRetry::Delivered => (
+            warp::http::StatusCode::OK,
+            DeadLetterRetryResponse { delivered: true, error: None },
+        ),
+        DeadLetterRetry::NotFound => (
+            warp::http::StatusCode::NOT_FOUND,
+            DeadLetterRetryResponse {
+                delivered: false,
+                error: Some(format!(
+                    "No dead-lettered message for src_chain_id={}, nonce={}",
+                    src_chain_id, nonce
+                )),
+            },
+        ),
+        DeadLetterRetry::Failed(error) => (
+            warp::http::StatusCode::BAD_GATEWAY,
+            DeadLetterRetryResponse { delivered: false, error: Some(error) },
+        ),
+    };
+    Ok(warp::reply::with_status(warp::reply::json(&body), status))
+}
+
+/// Serve the status API until the process exits.
+pub async fn serve(relay: Arc<NativeGmpRelay>, api: &ApiConfig) -> Result<()> {
+    let addr: SocketAddr = format!("{}:{}", api.host, api.port)
+        .parse()
+        .with_context(|| format!("Invalid api.host/api.port: {}:{}", api.host, api.port))?;
+
+    let cors = if api.cors_origins.iter().any(|origin| origin == "*") {
+        warp::cors().allow_any_origin()
+    } else {
+        warp::cors().allow_origins(api.cors_origins.iter().map(|s| s.as_str()))
+    }
+    .allow_methods(vec!["GET", "POST"]);
+
+    info!("Status API listening on http://{}", addr);
+    warp::serve(routes(relay).with(cors)).run(addr).await;
+    Ok(())
+}
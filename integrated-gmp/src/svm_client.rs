@@ -1,25 +1,38 @@
 //! GMP-specific SVM Client
 //!
 //! Wraps the shared `chain_clients_svm::SvmClient` and adds GMP-specific methods
-//! for reading outbound nonce counters and message accounts from the GMP program.
+//! for reading outbound nonce counters, message accounts, and delivered-message
+//! markers from the GMP program.
 
 use anyhow::{Context, Result};
 use chain_clients_svm::SvmClient;
 use solana_sdk::pubkey::Pubkey;
 
+use crate::config::SvmCommitment;
+
 // ============================================================================
 // CLIENT
 // ============================================================================
 
 pub struct GmpSvmClient {
     svm_client: SvmClient,
+    commitment: SvmCommitment,
 }
 
 impl GmpSvmClient {
     pub fn new(rpc_url: &str, program_id: &str) -> Result<Self> {
         let svm_client =
             SvmClient::new(rpc_url, program_id).context("Failed to create shared SVM client")?;
-        Ok(Self { svm_client })
+        Ok(Self {
+            svm_client,
+            commitment: SvmCommitment::Confirmed,
+        })
+    }
+
+    /// Read nonce, message, and delivered-marker accounts at `commitment`.
+    pub fn with_commitment(mut self, commitment: SvmCommitment) -> Self {
+        self.commitment = commitment;
+        self
     }
 
     /// Read the global outbound nonce from the GMP program.
@@ -36,7 +49,7 @@ impl GmpSvmClient {
                 &gmp_program_id,
             );
 
-        let data = self.svm_client.get_raw_account_data(&nonce_pda).await?;
+        let data = self.read_account(&nonce_pda).await?;
         let Some(data) = data else {
             return Ok(0); // No nonce account = no messages sent yet
         };
@@ -70,58 +83,130 @@ impl GmpSvmClient {
                 &gmp_program_id,
             );
 
-        let data = self.svm_client.get_raw_account_data(&message_pda).await?;
+        let data = self.read_account(&message_pda).await?;
         let Some(data) = data else {
             return Ok(None);
         };
 
-        // MessageAccount layout (Borsh):
-        //   disc(1) + src_chain_id(4) + dst_chain_id(4) + nonce(8) +
-        //   dst_addr(32) + remote_gmp_endpoint_addr(32) + payload_len(4) + payload(N) + bump(1)
-        if data.len() < 86 {
-            anyhow::bail!("MessageAccount too short: {} bytes", data.len());
-        }
+        parse_message_account(&data).map(Some)
+    }
+
+    /// Scan every stored outbound message to `dst_chain_id` via getProgramAccounts,
+    /// regardless of the outbound nonce counter.
+    ///
+    /// Matches MessageAccounts by discriminator and destination chain with memcmp
+    /// filters, reading at `finalized` commitment. Costs one getProgramAccounts call,
+    /// which is heavy on the RPC node and often rate-limited or disabled on public
+    /// endpoints. Returns the messages ordered by nonce.
+    pub async fn scan_outbound_messages(
+        &self,
+        gmp_program_id: &Pubkey,
+        dst_chain_id: u32,
+    ) -> Result<Vec<SvmOutboundMessage>> {
+        let dst_chain_id_bytes = dst_chain_id.to_le_bytes();
+        let accounts = self
+            .svm_client
+            .get_program_accounts_raw(
+                &to_solana_program_pubkey(gmp_program_id),
+                &[
+                    (0, &[MESSAGE_ACCOUNT_DISCRIMINATOR][..]),
+                    (5, &dst_chain_id_bytes[..]),
+                ],
+                "finalized",
+            )
+            .await?;
+
+        let mut messages = accounts
+            .iter()
+            .map(|(pubkey, data)| {
+                parse_message_account(data)
+                    .with_context(|| format!("Failed to parse MessageAccount {}", pubkey))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        messages.sort_by_key(|m| m.nonce);
+        Ok(messages)
+    }
 
-        let disc = data[0];
-        if disc != 7 {
-            anyhow::bail!(
-                "MessageAccount discriminator mismatch: expected 7, got {}",
-                disc
+    /// Read the remote GMP endpoint trusted for `src_chain_id`.
+    /// PDA seeds: ["remote_gmp_endpoint", src_chain_id.to_le_bytes()]
+    /// Returns None if no remote GMP endpoint is configured for the chain.
+    pub async fn get_remote_gmp_endpoint(
+        &self,
+        gmp_program_id: &Pubkey,
+        src_chain_id: u32,
+    ) -> Result<Option<SvmRemoteGmpEndpoint>> {
+        let chain_id_bytes = src_chain_id.to_le_bytes();
+        let gmp_program_id = to_solana_program_pubkey(gmp_program_id);
+        let (remote_pda, _) =
+            chain_clients_svm::solana_program::pubkey::Pubkey::find_program_address(
+                &[b"remote_gmp_endpoint", &chain_id_bytes],
+                &gmp_program_id,
             );
-        }
 
-        let src_chain_id =
-            u32::from_le_bytes(data[1..5].try_into().context("src_chain_id")?);
-        let dst_chain_id =
-            u32::from_le_bytes(data[5..9].try_into().context("dst_chain_id")?);
-        let msg_nonce = u64::from_le_bytes(data[9..17].try_into().context("nonce")?);
-
-        let mut dst_addr = [0u8; 32];
-        dst_addr.copy_from_slice(&data[17..49]);
-
-        let mut remote_gmp_endpoint_addr = [0u8; 32];
-        remote_gmp_endpoint_addr.copy_from_slice(&data[49..81]);
-
-        let payload_len =
-            u32::from_le_bytes(data[81..85].try_into().context("payload_len")?) as usize;
-        if data.len() < 85 + payload_len {
-            anyhow::bail!(
-                "MessageAccount payload truncated: need {} bytes, have {}",
-                85 + payload_len,
-                data.len()
+        let data = self.svm_client.get_raw_account_data(&remote_pda).await?;
+        let Some(data) = data else {
+            return Ok(None);
+        };
+
+        parse_remote_gmp_endpoint_account(&data).map(Some)
+    }
+
+    /// Read whether the GMP endpoint is paused (Send and DeliverMessage disabled).
+    /// PDA seeds: ["config"]
+    /// Config accounts written before the pause switch existed are reported as not paused.
+    pub async fn is_paused(&self, gmp_program_id: &Pubkey) -> Result<bool> {
+        let gmp_program_id = to_solana_program_pubkey(gmp_program_id);
+        let (config_pda, _) =
+            chain_clients_svm::solana_program::pubkey::Pubkey::find_program_address(
+                &[b"config"],
+                &gmp_program_id,
             );
-        }
-        let payload = data[85..85 + payload_len].to_vec();
 
-        Ok(Some(SvmOutboundMessage {
-            src_chain_id,
-            dst_chain_id,
-            nonce: msg_nonce,
-            dst_addr,
-            remote_gmp_endpoint_addr,
-            payload,
-        }))
+        let data = self
+            .read_account(&config_pda)
+            .await?
+            .context("GMP config account not found")?;
+        parse_config_paused(&data)
+    }
+
+    /// Check whether an account (e.g. a deployed program) exists at `pubkey`.
+    /// Returns false if getAccountInfo reports no account.
+    pub async fn account_exists(&self, pubkey: &Pubkey) -> Result<bool> {
+        let data = self
+            .svm_client
+            .get_raw_account_data(&to_solana_program_pubkey(pubkey))
+            .await?;
+        Ok(data.is_some())
+    }
+
+    /// Check whether a message was delivered by the GMP program.
+    /// PDA seeds: ["delivered", intent_id, &[msg_type]]
+    /// Returns true if the delivered-message marker account exists.
+    pub async fn is_message_delivered(
+        &self,
+        gmp_program_id: &Pubkey,
+        intent_id: &[u8; 32],
+        msg_type: u8,
+    ) -> Result<bool> {
+        let delivered_pda =
+            to_solana_program_pubkey(&delivered_pda(gmp_program_id, intent_id, msg_type));
+        let data = self.read_account(&delivered_pda).await?;
+        Ok(data.is_some())
     }
+
+    async fn read_account(
+        &self,
+        pubkey: &chain_clients_svm::solana_program::pubkey::Pubkey,
+    ) -> Result<Option<Vec<u8>>> {
+        self.svm_client
+            .get_raw_account_data_with_commitment(pubkey, self.commitment.as_str())
+            .await
+    }
+}
+
+/// Derive the delivered-message marker PDA for an (intent_id, msg_type) pair.
+pub fn delivered_pda(gmp_program_id: &Pubkey, intent_id: &[u8; 32], msg_type: u8) -> Pubkey {
+    Pubkey::find_program_address(&[b"delivered", intent_id, &[msg_type]], gmp_program_id).0
 }
 
 // ============================================================================
@@ -139,10 +224,144 @@ pub struct SvmOutboundMessage {
     pub payload: Vec<u8>,
 }
 
+/// Parsed SVM RemoteGmpEndpoint account: the addresses trusted for one source chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvmRemoteGmpEndpoint {
+    pub src_chain_id: u32,
+    /// Address trusted for message types without an override
+    pub addr: [u8; 32],
+    /// Per-message-type overrides as (msg_type, addr)
+    pub type_overrides: Vec<(u8, [u8; 32])>,
+}
+
 // ============================================================================
 // HELPERS
 // ============================================================================
 
+/// Account discriminator of the GMP program's MessageAccount.
+const MESSAGE_ACCOUNT_DISCRIMINATOR: u8 = 7;
+
+/// Account discriminator of the GMP program's RemoteGmpEndpoint account.
+const REMOTE_GMP_ENDPOINT_DISCRIMINATOR: u8 = 3;
+
+/// Account discriminator of the GMP program's ConfigAccount.
+const CONFIG_ACCOUNT_DISCRIMINATOR: u8 = 1;
+
+/// Read the `paused` flag from a ConfigAccount's data.
+fn parse_config_paused(data: &[u8]) -> Result<bool> {
+    // ConfigAccount layout (Borsh):
+    //   disc(1) + admin(32) + chain_id(4) + lenient(1) + require_trusted_remote(1)
+    //   + treasury(32) + paused(1) + bump(1)
+    const PAUSED_OFFSET: usize = 71;
+    let disc = *data.first().context("ConfigAccount is empty")?;
+    if disc != CONFIG_ACCOUNT_DISCRIMINATOR {
+        anyhow::bail!(
+            "ConfigAccount discriminator mismatch: expected {}, got {}",
+            CONFIG_ACCOUNT_DISCRIMINATOR, disc
+        );
+    }
+    // Older layouts end with the bump right after the treasury
+    if data.len() <= PAUSED_OFFSET + 1 {
+        return Ok(false);
+    }
+    Ok(data[PAUSED_OFFSET] != 0)
+}
+
+/// Parse a RemoteGmpEndpoint account's data.
+fn parse_remote_gmp_endpoint_account(data: &[u8]) -> Result<SvmRemoteGmpEndpoint> {
+    // RemoteGmpEndpoint layout (Borsh):
+    //   disc(1) + src_chain_id(4) + addr(32) + bump(1) + overrides_len(4) + (msg_type(1) + addr(32))*N
+    // Legacy accounts end after the bump and have no overrides.
+    if data.len() < 38 {
+        anyhow::bail!("RemoteGmpEndpoint too short: {} bytes", data.len());
+    }
+
+    let disc = data[0];
+    if disc != REMOTE_GMP_ENDPOINT_DISCRIMINATOR {
+        anyhow::bail!(
+            "RemoteGmpEndpoint discriminator mismatch: expected {}, got {}",
+            REMOTE_GMP_ENDPOINT_DISCRIMINATOR, disc
+        );
+    }
+
+    let src_chain_id =
+        u32::from_le_bytes(data[1..5].try_into().context("src_chain_id")?);
+    let mut addr = [0u8; 32];
+    addr.copy_from_slice(&data[5..37]);
+
+    let mut type_overrides = Vec::new();
+    if data.len() > 38 {
+        let count = u32::from_le_bytes(
+            data.get(38..42).context("RemoteGmpEndpoint overrides length truncated")?.try_into()?,
+        ) as usize;
+        for i in 0..count {
+            let start = 42 + i * 33;
+            let entry = data
+                .get(start..start + 33)
+                .with_context(|| format!("RemoteGmpEndpoint override {} truncated", i))?;
+            let mut override_addr = [0u8; 32];
+            override_addr.copy_from_slice(&entry[1..]);
+            type_overrides.push((entry[0], override_addr));
+        }
+    }
+
+    Ok(SvmRemoteGmpEndpoint {
+        src_chain_id,
+        addr,
+        type_overrides,
+    })
+}
+
+/// Parse a MessageAccount's data.
+fn parse_message_account(data: &[u8]) -> Result<SvmOutboundMessage> {
+    // MessageAccount layout (Borsh):
+    //   disc(1) + src_chain_id(4) + dst_chain_id(4) + nonce(8) +
+    //   dst_addr(32) + remote_gmp_endpoint_addr(32) + payload_len(4) + payload(N) + bump(1)
+    if data.len() < 86 {
+        anyhow::bail!("MessageAccount too short: {} bytes", data.len());
+    }
+
+    let disc = data[0];
+    if disc != MESSAGE_ACCOUNT_DISCRIMINATOR {
+        anyhow::bail!(
+            "MessageAccount discriminator mismatch: expected {}, got {}",
+            MESSAGE_ACCOUNT_DISCRIMINATOR, disc
+        );
+    }
+
+    let src_chain_id =
+        u32::from_le_bytes(data[1..5].try_into().context("src_chain_id")?);
+    let dst_chain_id =
+        u32::from_le_bytes(data[5..9].try_into().context("dst_chain_id")?);
+    let msg_nonce = u64::from_le_bytes(data[9..17].try_into().context("nonce")?);
+
+    let mut dst_addr = [0u8; 32];
+    dst_addr.copy_from_slice(&data[17..49]);
+
+    let mut remote_gmp_endpoint_addr = [0u8; 32];
+    remote_gmp_endpoint_addr.copy_from_slice(&data[49..81]);
+
+    let payload_len =
+        u32::from_le_bytes(data[81..85].try_into().context("payload_len")?) as usize;
+    if data.len() < 85 + payload_len {
+        anyhow::bail!(
+            "MessageAccount payload truncated: need {} bytes, have {}",
+            85 + payload_len,
+            data.len()
+        );
+    }
+    let payload = data[85..85 + payload_len].to_vec();
+
+    Ok(SvmOutboundMessage {
+        src_chain_id,
+        dst_chain_id,
+        nonce: msg_nonce,
+        dst_addr,
+        remote_gmp_endpoint_addr,
+        payload,
+    })
+}
+
 /// Convert solana_sdk::Pubkey to solana_program::Pubkey (same bytes, different crate types).
 fn to_solana_program_pubkey(
     pubkey: &Pubkey,
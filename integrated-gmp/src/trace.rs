@@ -0,0 +1,480 @@
+//! Intent Trace Module
+//!
+//! Walks the cross-chain GMP path of a single intent and reports a pass/fail
+//! timeline. Used by the `trace_intent` diagnostic tool to find where a stuck
+//! intent stopped progressing.
+//!
+//! Stages checked:
+//! 1. IntentRequirements sent by the hub (hub outbox)
+//! 2. IntentRequirements delivered on the connected chain
+//! 3. Inflow: EscrowConfirmation received on the hub (hub GMP state)
+//!    Outflow: FulfillmentProof received on the hub (hub GMP state)
+//! 4. Inflow: FulfillmentProof sent by the hub (hub outbox)
+//! 5. Inflow: FulfillmentProof delivered on the connected chain (escrow released)
+
+use anyhow::{Context, Result};
+use solana_sdk::pubkey::Pubkey;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config::Config;
+use crate::evm_client::GmpEvmClient;
+use crate::integrated_gmp_relay::{hex_to_bytes, parse_32_byte_address, GmpMessage};
+use crate::mvm_client::GmpMvmClient;
+use crate::svm_client::{delivered_pda, GmpSvmClient};
+
+/// GMP message type: IntentRequirements (hub -> connected)
+pub(crate) const MSG_TYPE_INTENT_REQUIREMENTS: u8 = 0x01;
+/// GMP message type: FulfillmentProof
+const MSG_TYPE_FULFILLMENT_PROOF: u8 = 0x03;
+
+/// Default number of most recent hub outbox messages scanned per message type
+pub const DEFAULT_OUTBOX_SCAN_LIMIT: u64 = 500;
+
+// ============================================================================
+// TRACE RESULT
+// ============================================================================
+
+/// Outcome of a single trace stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageStatus {
+    /// The stage completed
+    Pass,
+    /// The stage has not completed (or could not be checked)
+    Fail,
+    /// The stage was not checked (earlier stage failed or not applicable)
+    Skipped,
+}
+
+/// A single step of the cross-chain timeline.
+#[derive(Debug, Clone)]
+pub struct TraceStage {
+    /// Short description of what the stage checks
+    pub name: String,
+    /// Stage outcome
+    pub status: StageStatus,
+    /// Evidence for the outcome (nonce, account, view call, or error)
+    pub detail: String,
+}
+
+/// Direction of the intent, inferred from where the requirements were routed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntentFlow {
+    /// Requester escrows on the connected chain, solver fulfills on the hub
+    Inflow,
+    /// Solver fulfills on the connected chain, hub releases
+    Outflow,
+}
+
+/// Full trace of one intent.
+#[derive(Debug, Clone)]
+pub struct IntentTrace {
+    /// Intent ID (0x-prefixed hex)
+    pub intent_id: String,
+    /// Inferred flow, if the requirements were found
+    pub flow: Option<IntentFlow>,
+    /// Timeline in path order
+    pub stages: Vec<TraceStage>,
+}
+
+impl IntentTrace {
+    /// First failed stage, i.e. where the flow is stuck.
+    pub fn stuck_at(&self) -> Option<&TraceStage> {
+        self.stages.iter().find(|s| s.status == StageStatus::Fail)
+    }
+}
+
+impl fmt::Display for IntentTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Trace for intent {}", self.intent_id)?;
+        if let Some(flow) = self.flow {
+            writeln!(f, "Flow: {:?}", flow)?;
+        }
+        for (i, stage) in self.stages.iter().enumerate() {
+            let label = match stage.status {
+                StageStatus::Pass => "PASS",
+                StageStatus::Fail => "FAIL",
+                StageStatus::Skipped => "SKIP",
+            };
+            writeln!(f, "  [{}] {}. {}: {}", label, i + 1, stage.name, stage.detail)?;
+        }
+        match self.stuck_at() {
+            Some(stage) => write!(f, "Stuck at: {}", stage.name),
+            None if self.stages.iter().all(|s| s.status == StageStatus::Pass) => {
+                write!(f, "All stages passed")
+            }
+            None => write!(f, "No failed stage"),
+        }
+    }
+}
+
+// ============================================================================
+// TRACER
+// ============================================================================
+
+/// Queries every configured chain for the progress of an intent.
+pub struct IntentTracer {
+    config: Config,
+    outbox_scan_limit: u64,
+}
+
+impl IntentTracer {
+    /// Create a tracer over all chains in the integrated-gmp configuration.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            outbox_scan_limit: DEFAULT_OUTBOX_SCAN_LIMIT,
+        }
+    }
+
+    /// Limit how many of the most recent hub outbox messages are scanned.
+    pub fn with_outbox_scan_limit(mut self, limit: u64) -> Self {
+        self.outbox_scan_limit = limit;
+        self
+    }
+
+    /// Walk the cross-chain path of an intent.
+    ///
+    /// RPC errors do not abort the trace; they are reported as a failed stage.
+    pub async fn trace(&self, intent_id: &str) -> Result<IntentTrace> {
+        let intent_bytes = parse_32_byte_address(intent_id).context("Invalid intent ID")?;
+        let hub = GmpMvmClient::new(
+            &self.config.hub_chain.rpc_url,
+            &self.config.hub_chain.intent_module_addr,
+            self.config.hub_chain.chain_id as u32,
+        )?;
+
+        let mut trace = IntentTrace {
+            intent_id: format!("0x{}", hex::encode(intent_bytes)),
+            flow: None,
+            stages: Vec::new(),
+        };
+
+        // 1. IntentRequirements sent by hub
+        let requirements = match self
+            .find_hub_outbox_message(&hub, &intent_bytes, MSG_TYPE_INTENT_REQUIREMENTS)
+            .await
+        {
+            Ok(Some(message)) => {
+                trace.stages.push(pass(
+                    "IntentRequirements sent by hub",
+                    format!(
+                        "hub outbox nonce {} -> chain {} (dst {})",
+                        message.nonce, message.dst_chain_id, message.dst_addr
+                    ),
+                ));
+                message
+            }
+            Ok(None) => {
+                trace.stages.push(fail(
+                    "IntentRequirements sent by hub",
+                    format!(
+                        "not found in the last {} hub outbox messages",
+                        self.outbox_scan_limit
+                    ),
+                ));
+                return Ok(trace);
+            }
+            Err(e) => {
+                trace.stages.push(fail("IntentRequirements sent by hub", format!("RPC error: {:#}", e)));
+                return Ok(trace);
+            }
+        };
+
+        // 2. IntentRequirements delivered on the connected chain
+        let (delivered, flow) = self
+            .check_requirements_delivered(&requirements, &intent_bytes)
+            .await;
+        trace.flow = flow;
+        let delivered_ok = delivered.status == StageStatus::Pass;
+        trace.stages.push(delivered);
+
+        let Some(flow) = flow else {
+            trace.stages.push(skipped(
+                "Remaining stages",
+                "flow type (inflow/outflow) could not be determined".to_string(),
+            ));
+            return Ok(trace);
+        };
+
+        // 3. Confirmation back on the hub (hub GMP state)
+        let hub_stage_name = match flow {
+            IntentFlow::Inflow => "EscrowConfirmation received on hub",
+            IntentFlow::Outflow => "FulfillmentProof received on hub",
+        };
+        let hub_stage = if !delivered_ok {
+            skipped(hub_stage_name, "waiting on previous stage".to_string())
+        } else {
+            match hub.get_intent_state(&intent_bytes).await {
+                Ok((true, escrow_confirmed, proof_received)) => {
+                    let done = match flow {
+                        IntentFlow::Inflow => escrow_confirmed,
+                        IntentFlow::Outflow => proof_received,
+                    };
+                    let detail = format!(
+                        "gmp_intent_state: escrow_confirmed={}, fulfillment_proof_received={}",
+                        escrow_confirmed, proof_received
+                    );
+                    if done { pass(hub_stage_name, detail) } else { fail(hub_stage_name, detail) }
+                }
+                Ok((false, _, _)) => fail(
+                    hub_stage_name,
+                    "no gmp_intent_state entry on hub (unknown intent or already completed and removed)"
+                        .to_string(),
+                ),
+                Err(e) => fail(hub_stage_name, format!("RPC error: {:#}", e)),
+            }
+        };
+        let hub_ok = hub_stage.status == StageStatus::Pass;
+        trace.stages.push(hub_stage);
+
+        if flow == IntentFlow::Outflow {
+            return Ok(trace);
+        }
+
+        // 4. FulfillmentProof sent by hub (inflow)
+        let proof = if !hub_ok {
+            trace.stages.push(skipped("FulfillmentProof sent by hub", "waiting on previous stage".to_string()));
+            None
+        } else {
+            match self
+                .find_hub_outbox_message(&hub, &intent_bytes, MSG_TYPE_FULFILLMENT_PROOF)
+                .await
+            {
+                Ok(Some(message)) => {
+                    trace.stages.push(pass(
+                        "FulfillmentProof sent by hub",
+                        format!("hub outbox nonce {} -> chain {}", message.nonce, message.dst_chain_id),
+                    ));
+                    Some(message)
+                }
+                Ok(None) => {
+                    trace.stages.push(fail(
+                        "FulfillmentProof sent by hub",
+                        "solver has not fulfilled on hub (no FulfillmentProof in hub outbox)".to_string(),
+                    ));
+                    None
+                }
+                Err(e) => {
+                    trace.stages.push(fail("FulfillmentProof sent by hub", format!("RPC error: {:#}", e)));
+                    None
+                }
+            }
+        };
+
+        // 5. FulfillmentProof delivered on the connected chain (escrow released)
+        let released = match proof {
+            Some(message) => self.check_proof_delivered(&message, &intent_bytes).await,
+            None => skipped(
+                "FulfillmentProof delivered (escrow released)",
+                "waiting on previous stage".to_string(),
+            ),
+        };
+        trace.stages.push(released);
+
+        Ok(trace)
+    }
+
+    /// Scan the hub outbox (newest first) for a message of `msg_type` carrying `intent_id`.
+    async fn find_hub_outbox_message(
+        &self,
+        hub: &GmpMvmClient,
+        intent_id: &[u8; 32],
+        msg_type: u8,
+    ) -> Result<Option<GmpMessage>> {
+        find_hub_outbox_message(hub, intent_id, msg_type, self.outbox_scan_limit).await
+    }
+
+    /// Check IntentRequirements delivery on the destination chain and infer the flow.
+    async fn check_requirements_delivered(
+        &self,
+        message: &GmpMessage,
+        intent_id: &[u8; 32],
+    ) -> (TraceStage, Option<IntentFlow>) {
+        const NAME: &str = "IntentRequirements delivered on connected chain";
+        let dst = message.dst_chain_id as u64;
+        let dst_addr = message.dst_addr.trim_start_matches("0x").to_lowercase();
+
+        if let Some(svm) = self.config.connected_chain_svm.iter().find(|c| c.chain_id == dst) {
+            let flow = if svm_program_hex(&svm.escrow_program_id).as_deref() == Some(dst_addr.as_str()) {
+                Some(IntentFlow::Inflow)
+            } else if svm_program_hex(&svm.outflow_program_id).as_deref() == Some(dst_addr.as_str()) {
+                Some(IntentFlow::Outflow)
+            } else {
+                None
+            };
+            let stage = self
+                .check_svm_delivered(NAME, &svm.rpc_url, svm.gmp_endpoint_program_id.as_deref(), intent_id, MSG_TYPE_INTENT_REQUIREMENTS)
+                .await;
+            return (stage, flow);
+        }
+
+        if let Some(evm) = self.config.connected_chain_evm.iter().find(|c| c.chain_id == dst) {
+            let matches = |addr: &str| dst_addr.ends_with(&addr.trim_start_matches("0x").to_lowercase());
+            let flow = if matches(&evm.escrow_contract_addr) {
+                Some(IntentFlow::Inflow)
+            } else if evm.outflow_validator_addr.as_deref().is_some_and(matches) {
+                Some(IntentFlow::Outflow)
+            } else {
+                None
+            };
+            let stage = self
+                .check_evm_delivered(NAME, &evm.rpc_url, evm.gmp_endpoint_addr.as_deref(), dst, intent_id, MSG_TYPE_INTENT_REQUIREMENTS)
+                .await;
+            return (stage, flow);
+        }
+
+        if let Some(mvm) = self.config.connected_chain_mvm.iter().find(|c| c.chain_id == dst) {
+            let client = match GmpMvmClient::new(&mvm.rpc_url, &mvm.intent_module_addr, dst as u32) {
+                Ok(client) => client,
+                Err(e) => return (fail(NAME, format!("{:#}", e)), None),
+            };
+            for (module, flow) in [
+                ("intent_inflow_escrow", IntentFlow::Inflow),
+                ("intent_outflow_validator", IntentFlow::Outflow),
+            ] {
+                match client.view_intent_flag(module, "has_requirements", intent_id).await {
+                    Ok(true) => {
+                        let detail = format!("{}::has_requirements = true on chain {}", module, dst);
+                        return (pass(NAME, detail), Some(flow));
+                    }
+                    Ok(false) => {}
+                    Err(e) => return (fail(NAME, format!("RPC error: {:#}", e)), None),
+                }
+            }
+            return (
+                fail(NAME, format!("no stored requirements on MVM chain {}", dst)),
+                None,
+            );
+        }
+
+        (fail(NAME, format!("destination chain {} is not configured", dst)), None)
+    }
+
+    /// Check FulfillmentProof delivery on the destination chain (inflow escrow release).
+    async fn check_proof_delivered(&self, message: &GmpMessage, intent_id: &[u8; 32]) -> TraceStage {
+        const NAME: &str = "FulfillmentProof delivered (escrow released)";
+        let dst = message.dst_chain_id as u64;
+
+        if let Some(svm) = self.config.connected_chain_svm.iter().find(|c| c.chain_id == dst) {
+            return self
+                .check_svm_delivered(NAME, &svm.rpc_url, svm.gmp_endpoint_program_id.as_deref(), intent_id, MSG_TYPE_FULFILLMENT_PROOF)
+                .await;
+        }
+
+        if let Some(evm) = self.config.connected_chain_evm.iter().find(|c| c.chain_id == dst) {
+            return self
+                .check_evm_delivered(NAME, &evm.rpc_url, evm.gmp_endpoint_addr.as_deref(), dst, intent_id, MSG_TYPE_FULFILLMENT_PROOF)
+                .await;
+        }
+
+        if let Some(mvm) = self.config.connected_chain_mvm.iter().find(|c| c.chain_id == dst) {
+            let result = match GmpMvmClient::new(&mvm.rpc_url, &mvm.intent_module_addr, dst as u32) {
+                Ok(client) => client.view_intent_flag("intent_inflow_escrow", "is_released", intent_id).await,
+                Err(e) => Err(e),
+            };
+            return match result {
+                Ok(true) => pass(NAME, format!("intent_inflow_escrow::is_released = true on chain {}", dst)),
+                Ok(false) => fail(NAME, format!("intent_inflow_escrow::is_released = false on chain {}", dst)),
+                Err(e) => fail(NAME, format!("RPC error: {:#}", e)),
+            };
+        }
+
+        fail(NAME, format!("destination chain {} is not configured", dst))
+    }
+
+    /// Check the delivered-message marker PDA on an SVM GMP endpoint.
+    async fn check_svm_delivered(
+        &self,
+        name: &str,
+        rpc_url: &str,
+        gmp_program_id: Option<&str>,
+        intent_id: &[u8; 32],
+        msg_type: u8,
+    ) -> TraceStage {
+        let Some(gmp_program_id) = gmp_program_id else {
+            return fail(name, "gmp_endpoint_program_id not configured for SVM chain".to_string());
+        };
+        let result = async {
+            let program_id = Pubkey::from_str(gmp_program_id).context("Invalid gmp_endpoint_program_id")?;
+            let client = GmpSvmClient::new(rpc_url, gmp_program_id)?;
+            let pda = delivered_pda(&program_id, intent_id, msg_type);
+            let delivered = client.is_message_delivered(&program_id, intent_id, msg_type).await?;
+            Ok::<_, anyhow::Error>((pda, delivered))
+        }
+        .await;
+
+        match result {
+            Ok((pda, true)) => pass(name, format!("delivered PDA {} exists", pda)),
+            Ok((pda, false)) => fail(name, format!("delivered PDA {} not found (relay has not delivered)", pda)),
+            Err(e) => fail(name, format!("RPC error: {:#}", e)),
+        }
+    }
+
+    /// Check `isMessageDelivered` on an EVM GMP endpoint.
+    async fn check_evm_delivered(
+        &self,
+        name: &str,
+        rpc_url: &str,
+        gmp_endpoint_addr: Option<&str>,
+        chain_id: u64,
+        intent_id: &[u8; 32],
+        msg_type: u8,
+    ) -> TraceStage {
+        let Some(gmp_endpoint_addr) = gmp_endpoint_addr else {
+            return fail(name, "gmp_endpoint_addr not configured for EVM chain".to_string());
+        };
+        let result = match GmpEvmClient::new(rpc_url, gmp_endpoint_addr, chain_id as u32, "") {
+            Ok(client) => client.is_message_delivered(intent_id, msg_type).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(true) => pass(name, format!("{}.isMessageDelivered = true", gmp_endpoint_addr)),
+            Ok(false) => fail(name, format!("{}.isMessageDelivered = false (relay has not delivered)", gmp_endpoint_addr)),
+            Err(e) => fail(name, format!("RPC error: {:#}", e)),
+        }
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Scan the last `scan_limit` hub outbox messages (newest first) for a message of
+/// `msg_type` carrying `intent_id`.
+pub(crate) async fn find_hub_outbox_message(
+    hub: &GmpMvmClient,
+    intent_id: &[u8; 32],
+    msg_type: u8,
+    scan_limit: u64,
+) -> Result<Option<GmpMessage>> {
+    let next_nonce = hub.get_next_nonce().await?;
+    let oldest = next_nonce.saturating_sub(scan_limit).max(1);
+
+    for nonce in (oldest..next_nonce).rev() {
+        let message = hub.get_message(nonce).await?;
+        let payload = hex_to_bytes(&message.payload)?;
+        if payload.len() >= 33 && payload[0] == msg_type && payload[1..33] == intent_id[..] {
+            return Ok(Some(message));
+        }
+    }
+    Ok(None)
+}
+
+fn pass(name: &str, detail: String) -> TraceStage {
+    TraceStage { name: name.to_string(), status: StageStatus::Pass, detail }
+}
+
+fn fail(name: &str, detail: String) -> TraceStage {
+    TraceStage { name: name.to_string(), status: StageStatus::Fail, detail }
+}
+
+fn skipped(name: &str, detail: String) -> TraceStage {
+    TraceStage { name: name.to_string(), status: StageStatus::Skipped, detail }
+}
+
+/// Lowercase hex of a base58 SVM program ID (as it appears in GMP dst_addr).
+fn svm_program_hex(program_id: &str) -> Option<String> {
+    Pubkey::from_str(program_id).ok().map(|p| hex::encode(p.to_bytes()))
+}
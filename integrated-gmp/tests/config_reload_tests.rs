@@ -0,0 +1,122 @@
+//! Tests for config file hot reload.
+//!
+//! Each test writes a config to a temp file, starts a relay from it, edits the file,
+//! and checks what the reloader applied to the running relay.
+
+mod helpers;
+
+use helpers::build_test_config_with_mvm;
+use integrated_gmp::config_reload::{ConfigReloader, ReloadOutcome};
+use integrated_gmp::{Config, CryptoService, NativeGmpRelay, NativeGmpRelayConfig};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Write `config` as TOML to a per-test file in the temp directory.
+fn write_config(name: &str, config: &Config) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "integrated-gmp-reload-{}-{}.toml",
+        name,
+        std::process::id()
+    ));
+    std::fs::write(&path, toml::to_string(config).unwrap()).unwrap();
+    path
+}
+
+/// Build a relay from `config`.
+fn build_relay(config: &Config) -> NativeGmpRelay {
+    let relay_config = NativeGmpRelayConfig::from_config(config).unwrap();
+    let crypto_service = CryptoService::new(config).unwrap();
+    NativeGmpRelay::new(relay_config, crypto_service).unwrap()
+}
+
+/// 1. Test: Changing the polling interval in the file updates the running relay
+/// Verifies that after the file's `polling_interval_ms` changes, one reload check makes
+/// the relay's run loop use the new interval, with no restart-required changes reported.
+/// Why: Operators tune polling without restarting and losing warm relay state
+#[tokio::test]
+async fn test_reload_updates_polling_interval() {
+    let mut config = build_test_config_with_mvm();
+    let path = write_config("polling", &config);
+    let relay = build_relay(&config);
+    let mut reloader = ConfigReloader::new(path.to_str().unwrap(), config.clone()).unwrap();
+
+    assert_eq!(relay.polling_interval(), Duration::from_millis(1000));
+    assert_eq!(reloader.check(&relay).await, ReloadOutcome::Unchanged);
+
+    config.integrated_gmp.polling_interval_ms = 250;
+    std::fs::write(&path, toml::to_string(&config).unwrap()).unwrap();
+
+    assert_eq!(
+        reloader.check(&relay).await,
+        ReloadOutcome::Applied { restart_required: vec![] }
+    );
+    assert_eq!(relay.polling_interval(), Duration::from_millis(250));
+    assert_eq!(reloader.current().integrated_gmp.polling_interval_ms, 250);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// 2. Test: An invalid reloaded config is rejected and the running config is kept
+/// Verifies that a zero polling interval in the file fails validation and leaves the
+/// relay's interval unchanged.
+/// Why: A typo during a live edit must not stall or break the running relay
+#[tokio::test]
+async fn test_reload_rejects_invalid_config() {
+    let mut config = build_test_config_with_mvm();
+    let path = write_config("invalid", &config);
+    let relay = build_relay(&config);
+    let mut reloader = ConfigReloader::new(path.to_str().unwrap(), config.clone()).unwrap();
+
+    config.integrated_gmp.polling_interval_ms = 0;
+    std::fs::write(&path, toml::to_string(&config).unwrap()).unwrap();
+
+    let outcome = reloader.check(&relay).await;
+    assert!(
+        matches!(&outcome, ReloadOutcome::Rejected(reason) if reason.contains("polling_interval_ms")),
+        "Reload should be rejected naming the field: {:?}",
+        outcome
+    );
+    assert_eq!(relay.polling_interval(), Duration::from_millis(1000));
+    assert_eq!(reloader.current().integrated_gmp.polling_interval_ms, 1000);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// 3. Test: Unsafe changes are reported as requiring a restart and not applied
+/// Verifies that changing the key env var and a chain ID alongside the polling interval
+/// applies the interval but lists the other two as restart-required.
+/// Why: Keys and chain IDs are baked into clients and signers; swapping them live
+/// would leave the relay half-reconfigured
+#[tokio::test]
+async fn test_reload_reports_restart_required_changes() {
+    let mut config = build_test_config_with_mvm();
+    let path = write_config("restart", &config);
+    let relay = build_relay(&config);
+    let mut reloader = ConfigReloader::new(path.to_str().unwrap(), config.clone()).unwrap();
+
+    config.integrated_gmp.polling_interval_ms = 500;
+    config.integrated_gmp.private_key_env = "OTHER_PRIVATE_KEY".to_string();
+    config.hub_chain.chain_id += 100;
+    std::fs::write(&path, toml::to_string(&config).unwrap()).unwrap();
+
+    match reloader.check(&relay).await {
+        ReloadOutcome::Applied { restart_required } => {
+            assert!(restart_required.contains(&"hub_chain".to_string()), "{:?}", restart_required);
+            assert!(
+                restart_required.contains(&"integrated_gmp.private_key_env".to_string()),
+                "{:?}",
+                restart_required
+            );
+            assert!(
+                !restart_required.iter().any(|f| f.contains("polling_interval_ms")),
+                "{:?}",
+                restart_required
+            );
+        }
+        other => panic!("Expected reload to apply, got {:?}", other),
+    }
+    assert_eq!(relay.polling_interval(), Duration::from_millis(500));
+    assert_eq!(reloader.current().hub_chain.chain_id, build_test_config_with_mvm().hub_chain.chain_id);
+
+    std::fs::remove_file(&path).unwrap();
+}
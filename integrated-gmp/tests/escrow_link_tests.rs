@@ -0,0 +1,262 @@
+//! Tests for the requirements-to-escrow link check.
+//!
+//! Comparison tests use in-memory escrow accounts; the end-to-end test runs the
+//! checker against mocked hub (MVM REST) and connected chain (SVM JSON-RPC) endpoints.
+
+mod helpers;
+
+use base64::{engine::general_purpose, Engine as _};
+use borsh::BorshSerialize;
+use chain_clients_svm::solana_program::pubkey::Pubkey;
+use chain_clients_evm::EvmEscrow;
+use chain_clients_svm::EscrowAccount;
+use helpers::{
+    build_test_config_with_evm, build_test_config_with_svm, DUMMY_ESCROW_CONTRACT_ADDR_EVM, DUMMY_INTENT_ID,
+    DUMMY_SVM_ESCROW_PROGRAM_ID,
+};
+use integrated_gmp::escrow_link::{
+    compare_requirements_to_escrow, compare_requirements_to_evm_escrow, EscrowLinkChecker, RequirementsPayload,
+};
+use serde_json::json;
+use std::str::FromStr;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DUMMY_AMOUNT: u64 = 1_000;
+const DUMMY_EXPIRY: u64 = 1_900_000_000;
+
+fn intent_id_bytes() -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[31] = 1;
+    bytes
+}
+
+/// Requirements as the hub would send them for DUMMY_INTENT_ID.
+fn build_requirements() -> RequirementsPayload {
+    RequirementsPayload {
+        intent_id: intent_id_bytes(),
+        requester_addr: [0x0a; 32],
+        amount_required: DUMMY_AMOUNT,
+        token_addr: [0x0b; 32],
+        solver_addr: [0x0c; 32],
+        expiry: DUMMY_EXPIRY,
+    }
+}
+
+/// Escrow created from `build_requirements()` by the SVM escrow program.
+fn build_matching_escrow() -> EscrowAccount {
+    EscrowAccount {
+        discriminator: [0u8; 8],
+        requester: Pubkey::new_from_array([0x0d; 32]),
+        token_mint: Pubkey::new_from_array([0x0b; 32]),
+        amount: DUMMY_AMOUNT,
+        is_claimed: false,
+        expiry: DUMMY_EXPIRY as i64,
+        reserved_solver: Pubkey::new_from_array([0x0c; 32]),
+        intent_id: intent_id_bytes(),
+        bump: 255,
+        memo: [0u8; 32],
+        mode: 0,
+    }
+}
+
+/// Escrow created from `build_requirements()` by the EVM escrow contract, holding the
+/// 20-byte ERC20 token `0x0b..0b` (requirements carry it left-padded to 32 bytes).
+fn build_matching_evm_escrow() -> (RequirementsPayload, EvmEscrow) {
+    let mut requirements = build_requirements();
+    requirements.token_addr = [0u8; 32];
+    requirements.token_addr[12..].copy_from_slice(&[0x0b; 20]);
+    let escrow = EvmEscrow {
+        escrow_id: format!("0x{}", "ab".repeat(32)),
+        creator_addr: format!("0x{}", "0a".repeat(32)),
+        amount: DUMMY_AMOUNT,
+        token_addr: format!("0x{}", "0b".repeat(20)),
+        solver_addr: format!("0x{}", "0c".repeat(32)),
+        fulfilled: false,
+        released: false,
+    };
+    (requirements, escrow)
+}
+
+/// Hex-encode requirements in the GMP wire format (0x-prefixed).
+fn encode_requirements(requirements: &RequirementsPayload) -> String {
+    let mut payload = vec![0x01];
+    payload.extend_from_slice(&requirements.intent_id);
+    payload.extend_from_slice(&requirements.requester_addr);
+    payload.extend_from_slice(&requirements.amount_required.to_be_bytes());
+    payload.extend_from_slice(&requirements.token_addr);
+    payload.extend_from_slice(&requirements.solver_addr);
+    payload.extend_from_slice(&requirements.expiry.to_be_bytes());
+    format!("0x{}", hex::encode(payload))
+}
+
+/// Mount a hub view function mock returning `result`.
+async fn mock_hub_view(server: &MockServer, function: &str, result: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": format!("0x1::{}", function) })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(result))
+        .mount(server)
+        .await;
+}
+
+/// 1. Test: Consistent requirements/escrow pair has no mismatches
+/// Verifies that an escrow created exactly from the hub requirements passes every field check.
+/// Why: A healthy intent must not be flagged, or operators learn to ignore the check.
+#[test]
+fn test_consistent_pair_has_no_mismatches() {
+    let mismatches = compare_requirements_to_escrow(&build_requirements(), &build_matching_escrow());
+    assert!(mismatches.is_empty(), "Unexpected mismatches: {:?}", mismatches);
+}
+
+/// 2. Test: Mismatched amount and token are both flagged
+/// Verifies that an escrow holding less than `amount_required` in a different token reports
+/// an `amount` and a `token` mismatch, and nothing else.
+/// Why: These are the fields a cross-chain encoding bug (endianness, address padding) corrupts.
+#[test]
+fn test_mismatched_pair_flags_amount_and_token() {
+    let mut escrow = build_matching_escrow();
+    escrow.amount = DUMMY_AMOUNT - 1;
+    escrow.token_mint = Pubkey::new_from_array([0xee; 32]);
+
+    let mismatches = compare_requirements_to_escrow(&build_requirements(), &escrow);
+
+    let fields: Vec<&str> = mismatches.iter().map(|m| m.field).collect();
+    assert_eq!(fields, vec!["amount", "token"]);
+    assert_eq!(mismatches[0].escrow, (DUMMY_AMOUNT - 1).to_string());
+    assert_eq!(mismatches[1].escrow, format!("0x{}", "ee".repeat(32)));
+}
+
+/// 3. Test: Unreserved solver is not compared
+/// Verifies that requirements with a zero solver address accept any reserved solver on the escrow.
+/// Why: A zero solver means "any solver"; flagging it would report every open intent.
+#[test]
+fn test_zero_solver_is_not_compared() {
+    let mut requirements = build_requirements();
+    requirements.solver_addr = [0u8; 32];
+    let mut escrow = build_matching_escrow();
+    escrow.reserved_solver = Pubkey::new_from_array([0xff; 32]);
+
+    assert!(compare_requirements_to_escrow(&requirements, &escrow).is_empty());
+}
+
+/// 4. Test: Checker flags a mismatch between hub outbox requirements and the on-chain escrow
+/// Verifies that the checker reads IntentRequirements from the hub outbox, fetches the SVM
+/// escrow PDA, and reports the field on which they disagree.
+/// Why: The end-to-end path (payload decoding, chain routing, account parsing) is where
+/// encoding bugs would hide.
+#[tokio::test]
+async fn test_checker_reports_mismatched_escrow() {
+    let hub_server = MockServer::start().await;
+    let svm_server = MockServer::start().await;
+
+    let requirements = build_requirements();
+    let escrow_program_hex =
+        hex::encode(Pubkey::from_str(DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap().to_bytes());
+    mock_hub_view(&hub_server, "gmp_sender::get_next_nonce", json!(["2"])).await;
+    mock_hub_view(
+        &hub_server,
+        "gmp_sender::get_message",
+        json!(["901", format!("0x{}", escrow_program_hex), encode_requirements(&requirements), "0"]),
+    )
+    .await;
+
+    // Escrow on the SVM chain carries an expiry that does not match the hub
+    let mut escrow = build_matching_escrow();
+    escrow.expiry = DUMMY_EXPIRY as i64 + 60;
+    let escrow_data = general_purpose::STANDARD.encode(escrow.try_to_vec().unwrap());
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "getAccountInfo" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "context": { "slot": 1 }, "value": { "data": [escrow_data, "base64"] } },
+            "id": 1
+        })))
+        .mount(&svm_server)
+        .await;
+
+    let mut config = build_test_config_with_svm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_svm[0].rpc_url = svm_server.uri();
+
+    let report = EscrowLinkChecker::new(config).check(DUMMY_INTENT_ID).await.unwrap();
+
+    assert_eq!(report.chain_id, 901);
+    assert_eq!(report.requirements_nonce, 1);
+    assert!(!report.is_consistent());
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(report.mismatches[0].field, "expiry");
+    assert!(report.to_string().contains("[MISMATCH] expiry"));
+}
+
+/// 5. Test: EVM escrow in the wrong token is flagged even when the amount matches
+/// Verifies that an EVM escrow holding exactly `amount_required` of a different ERC20 token
+/// reports a `token` mismatch, while the matching escrow (token left-padded to 32 bytes) passes.
+/// Why: Checking only the amount would accept a fulfillment paid in a worthless token.
+#[test]
+fn test_evm_escrow_wrong_token_is_flagged() {
+    let (requirements, escrow) = build_matching_evm_escrow();
+    assert!(compare_requirements_to_evm_escrow(&requirements, &escrow).unwrap().is_empty());
+
+    let wrong_token = EvmEscrow {
+        token_addr: format!("0x{}", "ee".repeat(20)),
+        ..escrow
+    };
+    let mismatches = compare_requirements_to_evm_escrow(&requirements, &wrong_token).unwrap();
+
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].field, "token");
+    assert_eq!(mismatches[0].escrow, format!("0x{}{}", "00".repeat(12), "ee".repeat(20)));
+}
+
+/// 6. Test: Checker reads EVM escrows through getEscrow
+/// Verifies that requirements routed to a configured EVM chain are compared against the escrow
+/// returned by the escrow contract's `getEscrow(bytes32)` view.
+/// Why: EVM intents were previously rejected as unsupported and never cross-checked.
+#[tokio::test]
+async fn test_checker_reports_mismatched_evm_escrow() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+
+    let (requirements, _) = build_matching_evm_escrow();
+    mock_hub_view(&hub_server, "gmp_sender::get_next_nonce", json!(["2"])).await;
+    let escrow_contract_padded =
+        format!("0x{:0>64}", DUMMY_ESCROW_CONTRACT_ADDR_EVM.trim_start_matches("0x"));
+    mock_hub_view(
+        &hub_server,
+        "gmp_sender::get_message",
+        json!(["31337", escrow_contract_padded, encode_requirements(&requirements), "0"]),
+    )
+    .await;
+
+    // Escrow holds the required amount, but of a different ERC20 token
+    let stored_escrow = format!(
+        "0x{}{}{:064x}{:0>64}{}{:064x}{:064x}",
+        "ab".repeat(32),
+        "0a".repeat(32),
+        DUMMY_AMOUNT,
+        "ee".repeat(20),
+        "0c".repeat(32),
+        0u64,
+        0u64
+    );
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": stored_escrow,
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+
+    let report = EscrowLinkChecker::new(config).check(DUMMY_INTENT_ID).await.unwrap();
+
+    assert_eq!(report.chain_id, 31337);
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(report.mismatches[0].field, "token");
+}
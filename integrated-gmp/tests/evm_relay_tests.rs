@@ -1,12 +1,23 @@
 //! EVM counterpart of *vm_relay_tests.rs.
 //!
 //! SVM-specific tests (pubkey parsing, message parsing, fulfillment proof, ATA derivation)
-//! are N/A for EVM. Relay config tests apply across VMs.
+//! are N/A for EVM. Relay config tests apply across VMs. EIP-1559 and gas estimation tests
+//! are EVM-only.
 
 mod helpers;
 
-use helpers::build_test_config_with_evm;
+use helpers::{build_test_config_with_evm, build_test_gmp_message};
+use integrated_gmp::deliverer::{DeliveryOutcome, EvmDeliverer, MessageDeliverer};
+use integrated_gmp::evm_client::{
+    Eip1559Transaction, GmpEvmClient, EIP1559_TX_TYPE, FALLBACK_GAS_LIMIT,
+};
 use integrated_gmp::integrated_gmp_relay::NativeGmpRelayConfig;
+use integrated_gmp::CryptoService;
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // SVM PUBKEY PARSING TESTS
@@ -144,3 +155,279 @@ fn test_relay_config_extracts_evm_connected_chain() {
 
 // 31. Test: SVM Message Account Payload Truncation
 // NOTE: N/A for EVM - SVM account data parsing is SVM-specific
+
+// ============================================================================
+// EIP-1559 TRANSACTION TESTS
+// ============================================================================
+
+/// Decode one RLP item at the start of `data`: (payload, is_list, bytes consumed).
+fn rlp_decode_item(data: &[u8]) -> (&[u8], bool, usize) {
+    let prefix = data[0];
+    let long_len = |len_of_len: usize| {
+        let len = data[1..1 + len_of_len]
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+        (1 + len_of_len, len)
+    };
+    let (header, len, is_list) = match prefix {
+        0x00..=0x7f => return (&data[..1], false, 1),
+        0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+        0xb8..=0xbf => {
+            let (header, len) = long_len((prefix - 0xb7) as usize);
+            (header, len, false)
+        }
+        0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+        _ => {
+            let (header, len) = long_len((prefix - 0xf7) as usize);
+            (header, len, true)
+        }
+    };
+    (&data[header..header + len], is_list, header + len)
+}
+
+/// Decode the items of an RLP list payload.
+fn rlp_decode_list(mut payload: &[u8]) -> Vec<(&[u8], bool)> {
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let (item, is_list, consumed) = rlp_decode_item(payload);
+        items.push((item, is_list));
+        payload = &payload[consumed..];
+    }
+    items
+}
+
+fn be_bytes_to_u64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64)
+}
+
+// 32. Test: EIP-1559 Transaction Encoding And Signature Recovery
+/// Verifies that a signed type-2 transaction is `0x02 || rlp([...])` with the expected
+/// fields, an empty access list, and a signature that recovers to the relay's EVM address.
+/// Why: A malformed envelope or wrong signing hash is rejected by the node, so EIP-1559
+/// delivery would fail on every chain configured with tx_type = "eip1559"
+#[test]
+fn test_eip1559_transaction_encoding_and_signature_recovery() {
+    let config = build_test_config_with_evm();
+    let crypto_service = CryptoService::new(&config).unwrap();
+
+    let tx = Eip1559Transaction {
+        chain_id: 31337,
+        nonce: 7,
+        max_priority_fee_per_gas: 1_500_000_000,
+        max_fee_per_gas: 40_000_000_000,
+        gas_limit: 2_000_000,
+        to: vec![0xab; 20],
+        data: vec![0x12, 0x34, 0x56, 0x78],
+    };
+    let raw_tx = tx.sign(&crypto_service).unwrap();
+
+    assert_eq!(raw_tx[0], EIP1559_TX_TYPE);
+    let (payload, is_list, consumed) = rlp_decode_item(&raw_tx[1..]);
+    assert!(is_list);
+    assert_eq!(consumed, raw_tx.len() - 1, "No trailing bytes after the RLP list");
+
+    let fields = rlp_decode_list(payload);
+    assert_eq!(fields.len(), 12);
+    assert_eq!(be_bytes_to_u64(fields[0].0), 31337);
+    assert_eq!(be_bytes_to_u64(fields[1].0), 7);
+    assert_eq!(be_bytes_to_u64(fields[2].0), 1_500_000_000);
+    assert_eq!(be_bytes_to_u64(fields[3].0), 40_000_000_000);
+    assert_eq!(be_bytes_to_u64(fields[4].0), 2_000_000);
+    assert_eq!(fields[5].0, &[0xab; 20][..]);
+    assert!(fields[6].0.is_empty(), "Value should be zero");
+    assert_eq!(fields[7].0, &[0x12, 0x34, 0x56, 0x78][..]);
+    assert!(fields[8].1 && fields[8].0.is_empty(), "Access list should be an empty list");
+
+    let y_parity = be_bytes_to_u64(fields[9].0);
+    assert!(y_parity <= 1, "yParity must be 0 or 1, got {}", y_parity);
+    let mut signature_bytes = [0u8; 64];
+    signature_bytes[32 - fields[10].0.len()..32].copy_from_slice(fields[10].0);
+    signature_bytes[64 - fields[11].0.len()..].copy_from_slice(fields[11].0);
+
+    let signature = Signature::from_slice(&signature_bytes).unwrap();
+    let recovery_id = RecoveryId::try_from(y_parity as u8).unwrap();
+    let recovered =
+        VerifyingKey::recover_from_prehash(&tx.signing_hash(), &signature, recovery_id).unwrap();
+    let public_key = recovered.to_encoded_point(false);
+    let address = &Keccak256::digest(&public_key.as_bytes()[1..])[12..];
+
+    assert_eq!(
+        format!("0x{}", hex::encode(address)),
+        crypto_service.get_ethereum_address().unwrap()
+    );
+}
+
+// ============================================================================
+// EVM GAS ESTIMATION TESTS
+// ============================================================================
+
+const DUMMY_EVM_TX_HASH: &str = "0x00000000000000000000000000000000000000000000000000000000000000ab";
+
+/// Mount EVM mocks for a legacy delivery: nonce, gas price, the given `eth_estimateGas`
+/// result (or none, so estimation fails), and an accepting `eth_sendRawTransaction`.
+async fn mock_evm_delivery(server: &MockServer, gas_estimate: Option<&str>) {
+    let mut results = vec![
+        ("eth_getTransactionCount", "0x0"),
+        ("eth_gasPrice", "0x1"),
+        ("eth_sendRawTransaction", DUMMY_EVM_TX_HASH),
+    ];
+    if let Some(estimate) = gas_estimate {
+        results.push(("eth_estimateGas", estimate));
+    }
+    for (rpc_method, result) in results {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": rpc_method })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "result": result,
+                "id": 1
+            })))
+            .mount(server)
+            .await;
+    }
+}
+
+/// Deliver a message through `server` and return the gas limit of the broadcast
+/// legacy transaction.
+async fn delivered_gas_limit(server: &MockServer) -> u64 {
+    let config = build_test_config_with_evm();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let client = GmpEvmClient::new(
+        &server.uri(),
+        "0x00000000000000000000000000000000000000ab",
+        31337,
+        &crypto_service.get_ethereum_address().unwrap(),
+    )
+    .unwrap()
+    .with_gas_limit_multiplier(1.2);
+
+    let tx_hash = client
+        .deliver_message(1, &format!("0x{}", "00".repeat(32)), "0x0102", &crypto_service)
+        .await
+        .unwrap();
+    assert_eq!(tx_hash, DUMMY_EVM_TX_HASH);
+
+    let requests = server.received_requests().await.unwrap();
+    let body: serde_json::Value = requests
+        .iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+        .find(|body| body["method"] == "eth_sendRawTransaction")
+        .expect("eth_sendRawTransaction was not called");
+    let raw_tx = hex::decode(body["params"][0].as_str().unwrap().trim_start_matches("0x")).unwrap();
+
+    // Legacy tx: [nonce, gasPrice, gasLimit, to, value, data, v, r, s]
+    let (payload, is_list, _) = rlp_decode_item(&raw_tx);
+    assert!(is_list);
+    be_bytes_to_u64(rlp_decode_list(payload)[2].0)
+}
+
+// 33. Test: EVM Delivery Gas Limit From Estimate
+/// Verifies that the delivery transaction's gas limit is the `eth_estimateGas` result
+/// times the configured multiplier (100000 * 1.2 = 120000).
+/// Why: A fixed 2,000,000 limit overpays on cheap deliveries and can still be too low
+/// for large payloads
+#[tokio::test]
+async fn test_evm_delivery_gas_limit_from_estimate() {
+    let server = MockServer::start().await;
+    mock_evm_delivery(&server, Some("0x186a0")).await;
+
+    assert_eq!(delivered_gas_limit(&server).await, 120_000);
+}
+
+// 34. Test: EVM Delivery Gas Limit Falls Back When Estimation Fails
+/// Verifies that the fallback limit is used when `eth_estimateGas` returns an error.
+/// Why: An RPC without estimation support (or a transient failure) must not block delivery
+#[tokio::test]
+async fn test_evm_delivery_gas_limit_fallback() {
+    let server = MockServer::start().await;
+    mock_evm_delivery(&server, None).await;
+
+    assert_eq!(delivered_gas_limit(&server).await, FALLBACK_GAS_LIMIT);
+}
+
+// ============================================================================
+// SVM MESSAGE SCAN TESTS
+// ============================================================================
+
+// 35. Test: SVM Outbound Message Scan
+// NOTE: N/A for EVM - getProgramAccounts message scanning is SVM-specific
+
+// ============================================================================
+// DELIVERY OUTCOME TESTS
+// ============================================================================
+
+// 36. Test: EVM Delivery Error Classification
+/// Verifies that EvmDeliverer classifies unknown-endpoint, already-delivered and
+/// intent-not-found reverts as permanent, and any other error as transient.
+/// Why: The outcome decides whether the relay dead-letters or retries
+#[test]
+fn test_evm_delivery_error_classification() {
+    for reason in ["E_UNKNOWN_REMOTE_GMP_ENDPOINT", "E_ALREADY_DELIVERED", "E_INTENT_NOT_FOUND"] {
+        let error = anyhow::anyhow!("EVM RPC error: execution reverted: {}", reason);
+        let outcome = EvmDeliverer::classify_error(&error);
+        assert!(matches!(outcome, DeliveryOutcome::PermanentFailure(_)), "{}: {:?}", reason, outcome);
+    }
+
+    assert_eq!(
+        EvmDeliverer::classify_error(&anyhow::anyhow!("nonce too low")),
+        DeliveryOutcome::TransientFailure("nonce too low".to_string())
+    );
+}
+
+// 37. Test: EVM Deliverer Detects Already Delivered Message
+/// Verifies that deliver returns AlreadyDelivered without broadcasting when
+/// isMessageDelivered reports the message.
+/// Why: A duplicate delivery would revert and waste gas
+#[tokio::test]
+async fn test_evm_deliverer_detects_already_delivered() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}1", "0".repeat(63)),
+            "id": 1
+        })))
+        .mount(&server)
+        .await;
+
+    let config = build_test_config_with_evm();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let client = GmpEvmClient::new(
+        &server.uri(),
+        "0x00000000000000000000000000000000000000ab",
+        31337,
+        &crypto_service.get_ethereum_address().unwrap(),
+    )
+    .unwrap();
+    let deliverer = EvmDeliverer::new(&client, &crypto_service);
+
+    let outcome = deliverer.deliver(&build_test_gmp_message(31337)).await;
+
+    assert_eq!(outcome, DeliveryOutcome::AlreadyDelivered);
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests.iter().all(|r| {
+        serde_json::from_slice::<serde_json::Value>(&r.body).unwrap()["method"] != "eth_sendRawTransaction"
+    }));
+}
+
+// ============================================================================
+// COMMITMENT TESTS
+// ============================================================================
+
+// 38. Test: SVM Reads Use Configured Commitment
+// NOTE: N/A for EVM - commitment levels are SVM-specific
+
+// ============================================================================
+// PAUSE TESTS
+// ============================================================================
+
+// 39. Test: SVM Paused Endpoint Detection
+// NOTE: N/A for EVM - only the SVM GMP endpoint has a pause switch
+
+// ============================================================================
+// SOLVER TOKEN ACCOUNT TESTS
+// ============================================================================
+
+// 40. Test: SVM Solver ATA Follows Mint Owner
+// NOTE: N/A for EVM - associated token accounts are SVM-specific
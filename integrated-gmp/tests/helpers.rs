@@ -12,8 +12,10 @@ use base64::{engine::general_purpose, Engine as _};
 use ed25519_dalek::SigningKey;
 use rand::{Rng, RngCore};
 use integrated_gmp::config::{
-    ApiConfig, ChainConfig, Config, EvmChainConfig, SvmChainConfig, IntegratedGmpConfig,
+    ApiConfig, ChainConfig, Config, EvmChainConfig, EvmTxType, MetricsConfig, MvmCommitment, MvmSubmission,
+    SvmChainConfig, SvmCommitment, IntegratedGmpConfig,
 };
+use integrated_gmp::integrated_gmp_relay::GmpMessage;
 
 // ============================================================================
 // CONSTANTS
@@ -98,12 +100,15 @@ pub fn build_test_config_with_mvm() -> Config {
     std::env::set_var(&public_key_env_name, &public_key_b64);
 
     Config {
+        chains_dir: None,
         hub_chain: ChainConfig {
             name: "hub".to_string(),
             rpc_url: "http://127.0.0.1:18080".to_string(),
             chain_id: 1,
             intent_module_addr: "0x1".to_string(),
             escrow_module_addr: None,
+            enabled: true,
+            polling_interval_ms: None,
         },
         connected_chain_mvm: vec![ChainConfig {
             name: "connected".to_string(),
@@ -111,18 +116,44 @@ pub fn build_test_config_with_mvm() -> Config {
             chain_id: 2,
             intent_module_addr: "0x2".to_string(),
             escrow_module_addr: Some("0x2".to_string()),
+            enabled: true,
+            polling_interval_ms: None,
         }],
         integrated_gmp: IntegratedGmpConfig {
             private_key_env: private_key_env_name,
             public_key_env: public_key_env_name,
+            // Unset unless a test opts in, so the ECDSA key is derived from the Ed25519 key
+            ecdsa_private_key_env: format!("TEST_APPROVER_ECDSA_PRIVATE_KEY_{}", unique_id),
+            ecdsa_address_env: format!("TEST_APPROVER_ECDSA_ADDRESS_{}", unique_id),
+            backup_private_key_envs: vec![],
             polling_interval_ms: 1000,
+            max_idle_polling_interval_ms: None,
+            idle_polls_before_widening: 3,
             validation_timeout_ms: 1000,
+            retry_budget: 3,
+            alert_webhook_url: None,
+            route_allowlist: vec![],
+            backfill_max_rps: None,
+            cursor_file: None,
+            dead_letter_file: None,
+            evm_cold_start_blocks: 10,
+            max_concurrent_deliveries: None,
+            check_program_deployment: true,
+            mvm_outbox_cleanup: false,
+            mvm_commitment: MvmCommitment::Submitted,
+            mvm_confirmation_timeout_ms: 30000,
+            mvm_submission: MvmSubmission::Native,
+            mvm_cli_fallback: false,
+            remote_signer: None,
+            config_reload_interval_ms: None,
         },
         api: ApiConfig {
+            enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3999,
             cors_origins: vec![],
         },
+        metrics: MetricsConfig::default(),
         connected_chain_evm: vec![],
         connected_chain_svm: vec![],
     }
@@ -140,6 +171,11 @@ pub fn build_test_config_with_evm() -> Config {
         approver_evm_pubkey_hash: DUMMY_APPROVER_EVM_PUBKEY_HASH.to_string(),
         gmp_endpoint_addr: None,
         outflow_validator_addr: None,
+        tx_type: EvmTxType::Legacy,
+        gas_limit_multiplier: 1.2,
+        enabled: true,
+        polling_interval_ms: None,
+        confirmations: 0,
     }];
     config
 }
@@ -155,6 +191,26 @@ pub fn build_test_config_with_svm() -> Config {
         escrow_program_id: DUMMY_SVM_ESCROW_PROGRAM_ID.to_string(),
         outflow_program_id: DUMMY_SVM_ESCROW_PROGRAM_ID.to_string(),
         gmp_endpoint_program_id: Some(DUMMY_SVM_ESCROW_PROGRAM_ID.to_string()),
+        enabled: true,
+        polling_interval_ms: None,
+        startup_message_scan: true,
+        svm_commitment: SvmCommitment::Confirmed,
     }];
     config
 }
+
+// ============================================================================
+// MESSAGE BUILDERS
+// ============================================================================
+
+/// Build an IntentRequirements (0x01) message for DUMMY_INTENT_ID to `dst_chain_id`.
+pub fn build_test_gmp_message(dst_chain_id: u32) -> GmpMessage {
+    GmpMessage {
+        src_chain_id: TEST_MVM_CHAIN_ID,
+        remote_gmp_endpoint_addr: format!("0x{}", "11".repeat(32)),
+        dst_chain_id,
+        dst_addr: format!("0x{}", "22".repeat(32)),
+        payload: format!("0x01{}", DUMMY_INTENT_ID.trim_start_matches("0x")),
+        nonce: 1,
+    }
+}
@@ -1,19 +1,51 @@
 //! Tests for integrated GMP relay generic helper functions.
 //!
 //! These tests cover the generic helper functions used by the relay:
-//! address parsing, Ed25519 keypair conversion, hex-to-bytes, and delivery retry tracking.
+//! address parsing, Ed25519 keypair conversion, hex-to-bytes, delivery retry tracking,
+//! per-chain enable switches, retry budget escalation, route allowlists, backfill rate limiting,
+//! startup program deployment checks, MVM outbox cleanup, MVM delivery confirmation,
+//! field-level config validation, the global delivery concurrency cap, the
+//! pending-message status endpoint, relay cursor persistence, adaptive polling
+//! intervals, range backfill, EVM reorg handling, config checks, remote GMP
+//! endpoint verification, log correlation fields, and operator key failover.
 //!
 //! VM-specific tests are in relay_vm_tests.rs.
 
 mod helpers;
 
-use helpers::{DUMMY_INTENT_ID, DUMMY_SOLVER_ADDR_HUB};
+use helpers::{
+    build_test_config_with_evm, build_test_config_with_mvm, build_test_config_with_svm,
+    DUMMY_INTENT_ID, DUMMY_SOLVER_ADDR_HUB, DUMMY_SVM_ESCROW_PROGRAM_ID,
+};
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
 use solana_sdk::signature::Keypair;
+use std::sync::Arc;
+use std::time::Duration;
+use integrated_gmp::alert::{AlertNotifier, EscalationAction, RetryBudgetAlert};
+use integrated_gmp::config::{ChainConfig, IntegratedGmpConfig, RouteAllowlistEntry, SvmChainConfig, SvmCommitment};
+use integrated_gmp::dead_letter::DeadLetterFile;
 use integrated_gmp::integrated_gmp_relay::{
-    ed25519_seed_to_keypair_bytes, hex_to_bytes,
-    parse_32_byte_address, DeliveryAttempt,
+    adaptive_polling_interval, ed25519_seed_to_keypair_bytes, hex_to_bytes, is_unconfirmed_delivery,
+    parse_32_byte_address, remote_gmp_endpoint_mismatch, DeadLetterRetry, GmpMessage, PollTarget,
+    RetryBudget, RetryOutcome,
+};
+use integrated_gmp::metrics;
+use integrated_gmp::mvm_client::GmpMvmClient;
+use integrated_gmp::rate_limit::DeliveryLimiter;
+use integrated_gmp::relay_cursor::{CursorFile, RelayCursors};
+use integrated_gmp::status_api::{
+    self, DeadLetterRetryResponse, DeadLettersResponse, PendingMessagesResponse,
 };
-use integrated_gmp::MAX_DELIVERY_RETRIES;
+use integrated_gmp::{BackfillResult, Config, CryptoService, NativeGmpRelay, NativeGmpRelayConfig};
+use wiremock::matchers::{any, body_partial_json, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// SVM GMP endpoint program ID distinct from the escrow program ID
+const DUMMY_SVM_GMP_PROGRAM_ID: &str = "SysvarC1ock11111111111111111111111111111111";
+/// SVM outflow validator program ID distinct from the escrow program ID
+const DUMMY_SVM_OUTFLOW_PROGRAM_ID: &str = "SysvarRent111111111111111111111111111111111";
 
 // ============================================================================
 // ADDRESS PARSING TESTS
@@ -150,56 +182,1831 @@ fn test_hex_to_bytes_without_prefix() {
 // DELIVERY RETRY TRACKING TESTS
 // ============================================================================
 
-/// 9. Test: DeliveryAttempt record_failure increments count and sets backoff
+/// 9. Test: RetryBudget first failure returns Retry and sets backoff
 /// Why: First failure must not be terminal — relay must retry with backoff
 #[test]
-fn test_delivery_attempt_first_failure_sets_backoff() {
-    let mut attempt = DeliveryAttempt { count: 0, next_retry_after: 0 };
-    let exhausted = attempt.record_failure();
+fn test_retry_budget_first_failure_sets_backoff() {
+    let mut budget: RetryBudget<(u32, u64)> = RetryBudget::new(3);
+    let key = (1, 7);
 
-    assert!(!exhausted, "First failure should not exhaust retries");
+    let outcome = budget.record_failure(key);
+
+    assert!(matches!(outcome, RetryOutcome::Retry { attempt: 1, .. }), "First failure should be retried");
+    let attempt = budget.attempt(&key).expect("Failure should be tracked");
     assert_eq!(attempt.count, 1);
     assert!(attempt.next_retry_after > 0, "Backoff should be set");
+    assert!(!budget.should_attempt(&key), "Operation should wait out its backoff");
 }
 
-/// 10. Test: DeliveryAttempt transitions to exhausted after MAX_DELIVERY_RETRIES
-/// Why: After max retries, message must be permanently skipped
+/// 10. Test: RetryBudget escalates after the configured number of failures
+/// Why: The `retry_budget` config value, not a fixed constant, must decide when to give up
 #[test]
-fn test_delivery_attempt_exhausted_after_max_retries() {
-    let mut attempt = DeliveryAttempt { count: 0, next_retry_after: 0 };
+fn test_retry_budget_uses_configured_max_attempts() {
+    let mut budget: RetryBudget<(u32, u64)> = RetryBudget::new(5);
+    let key = (1, 7);
 
-    for i in 0..MAX_DELIVERY_RETRIES {
-        let exhausted = attempt.record_failure();
-        if i + 1 < MAX_DELIVERY_RETRIES {
-            assert!(!exhausted, "Attempt {} should not exhaust retries", i + 1);
-        } else {
-            assert!(exhausted, "Attempt {} should exhaust retries", i + 1);
-        }
+    for i in 1..5 {
+        assert!(
+            matches!(budget.record_failure(key), RetryOutcome::Retry { .. }),
+            "Attempt {} should not exhaust the budget",
+            i
+        );
     }
 
-    assert_eq!(attempt.count, MAX_DELIVERY_RETRIES);
-    assert!(attempt.is_exhausted());
+    assert_eq!(budget.record_failure(key), RetryOutcome::Escalate { attempts: 5 });
+    assert!(budget.is_parked(&key));
 }
 
-/// 11. Test: DeliveryAttempt backoff increases with each retry
+/// 11. Test: RetryBudget backoff increases with each retry
 /// Why: Backoff must increase to avoid hammering a failing chain
 #[test]
-fn test_delivery_attempt_backoff_increases() {
-    let mut attempt = DeliveryAttempt { count: 0, next_retry_after: 0 };
+fn test_retry_budget_backoff_increases() {
+    let mut budget: RetryBudget<(u32, u64)> = RetryBudget::new(3);
+    let key = (1, 7);
+
+    let first = budget.record_failure(key);
+    let second = budget.record_failure(key);
+
+    match (first, second) {
+        (
+            RetryOutcome::Retry { backoff_secs: first_backoff, .. },
+            RetryOutcome::Retry { backoff_secs: second_backoff, .. },
+        ) => assert!(second_backoff > first_backoff, "Backoff should increase with each retry"),
+        other => panic!("Expected two retries, got {:?}", other),
+    }
+}
+
+/// 12. Test: RetryBudget does not park an operation under the limit, and clear resets it
+/// Why: Only exhausting the budget may park an operation; a success must forget past failures
+#[test]
+fn test_retry_budget_not_parked_under_limit() {
+    let mut budget: RetryBudget<(u32, u64)> = RetryBudget::new(3);
+    let key = (1, 7);
+
+    budget.record_failure(key);
+    budget.record_failure(key);
+    assert!(!budget.is_parked(&key), "Operation under the limit should not be parked");
+
+    budget.clear(&key);
+    assert!(budget.attempt(&key).is_none(), "Clear should forget past failures");
+    assert!(budget.should_attempt(&key));
+}
+
+// ============================================================================
+// CHAIN ENABLE SWITCH TESTS
+// ============================================================================
+
+/// 13. Test: Chain enabled flag defaults to true when omitted
+/// Why: Existing configs without an `enabled` field must keep every chain active
+#[test]
+fn test_chain_enabled_defaults_to_true() {
+    let chain: ChainConfig = toml::from_str(
+        r#"
+        name = "hub"
+        rpc_url = "http://127.0.0.1:18080"
+        chain_id = 1
+        intent_module_addr = "0x1"
+        "#,
+    )
+    .unwrap();
+
+    assert!(chain.enabled, "Chains should be enabled by default");
+}
+
+/// 14. Test: Disabled chain is not polled and its startup checks are skipped
+/// Why: Disabling a chain for debugging must not touch its RPC or trip config validation
+#[tokio::test]
+async fn test_disabled_chain_is_not_polled_or_auth_checked() {
+    let hub_server = MockServer::start().await;
+    let disabled_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::intent_gmp::is_relay_authorized" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([true])))
+        .mount(&hub_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["1"])))
+        .mount(&hub_server)
+        .await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&disabled_server)
+        .await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].rpc_url = disabled_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.validate().expect("Disabled chain should not fail validation");
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, crypto_service).unwrap();
+
+    relay.check_authorization().await.expect("Authorization should only check enabled chains");
+    relay.poll_all_chains().await;
+
+    let hub_requests = hub_server.received_requests().await.unwrap();
+    assert_eq!(hub_requests.len(), 2, "Enabled hub should be auth-checked and polled");
+    disabled_server.verify().await;
+}
+
+// ============================================================================
+// RETRY BUDGET ESCALATION TESTS
+// ============================================================================
+
+/// 15. Test: Exceeding the retry budget escalates exactly once and parks the operation
+/// Why: Persistent failures must surface loudly once, then stop being retried
+#[test]
+fn test_retry_budget_escalates_once_and_parks() {
+    let mut budget: RetryBudget<(u32, u64)> = RetryBudget::new(3);
+    let key = (1, 7);
+
+    let outcomes: Vec<RetryOutcome> = (0..5).map(|_| budget.record_failure(key)).collect();
+
+    let escalations = outcomes
+        .iter()
+        .filter(|o| matches!(o, RetryOutcome::Escalate { .. }))
+        .count();
+    assert_eq!(escalations, 1, "Budget exhaustion should escalate exactly once");
+    assert_eq!(outcomes[2], RetryOutcome::Escalate { attempts: 3 });
+    assert_eq!(outcomes[4], RetryOutcome::AlreadyParked);
+    assert!(budget.is_parked(&key), "Operation should be parked after exhausting the budget");
+    assert!(!budget.should_attempt(&key), "Parked operation must not be attempted");
+}
+
+/// 16. Test: Alert escalation fires the notification webhook
+/// Why: Operators rely on the webhook to learn about dead-lettered or paused operations
+#[tokio::test]
+async fn test_alert_notifier_posts_to_webhook() {
+    let webhook = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/alerts"))
+        .and(body_partial_json(json!({ "action": "dead_lettered", "attempts": 3 })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&webhook)
+        .await;
+
+    let notifier = AlertNotifier::new(Some(format!("{}/alerts", webhook.uri())));
+    notifier
+        .escalate(&RetryBudgetAlert {
+            service: "integrated-gmp".to_string(),
+            operation: "deliver src_chain=1 nonce=7 dst_chain=2".to_string(),
+            attempts: 3,
+            action: EscalationAction::DeadLettered,
+            last_error: "boom".to_string(),
+        })
+        .await;
+
+    webhook.verify().await;
+}
+
+// ============================================================================
+// ROUTE ALLOWLIST TESTS
+// ============================================================================
+
+/// 17. Test: Source with an allowlist may route only to listed destinations
+/// Why: A compromised or misconfigured source must not be able to reach chains outside its allowlist
+#[test]
+fn test_route_allowlist_allows_listed_and_rejects_other_destinations() {
+    let mut config = build_test_config_with_mvm();
+    config.integrated_gmp.route_allowlist = vec![RouteAllowlistEntry {
+        src_chain_id: 2,
+        allowed_dst_chain_ids: vec![1],
+    }];
+    config.validate().expect("Allowlist referencing configured chains should be valid");
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+
+    assert!(relay_config.is_route_allowed(2, 1), "Connected chain may route to the hub");
+    assert!(!relay_config.is_route_allowed(2, 2), "Destination outside the allowlist must be rejected");
+    assert!(relay_config.is_route_allowed(1, 2), "Source without an entry stays permissive");
+}
+
+/// 18. Test: Allowlist referencing an unconfigured chain fails validation
+/// Why: Typos in chain IDs would otherwise silently block or permit the wrong routes
+#[test]
+fn test_route_allowlist_rejects_unknown_chain() {
+    let mut config = build_test_config_with_mvm();
+    config.integrated_gmp.route_allowlist = vec![RouteAllowlistEntry {
+        src_chain_id: 2,
+        allowed_dst_chain_ids: vec![999],
+    }];
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("999"), "Error should name the unknown chain ID: {}", err);
+}
+
+// ============================================================================
+// BACKFILL RATE LIMIT TESTS
+// ============================================================================
+
+/// 19. Test: Startup backfill throttles outbox reads to the configured rate
+/// Why: Catching up on a long outbox history must not burst past the RPC provider's quota
+#[tokio::test]
+async fn test_backfill_rpc_calls_throttled_to_configured_rate() {
+    let hub_server = MockServer::start().await;
+
+    // Ten historical messages (nonces 1..=10); reads fail so no delivery is attempted
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["11"])))
+        .mount(&hub_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_message" })))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(10)
+        .mount(&hub_server)
+        .await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.integrated_gmp.backfill_max_rps = Some(20);
+    config.validate().expect("Positive backfill rate should be valid");
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, crypto_service).unwrap();
+
+    let started = std::time::Instant::now();
+    relay.poll_all_chains().await;
+    let elapsed = started.elapsed();
+
+    // 10 reads at 20/s: the first is immediate, the other 9 are spaced 50ms apart
+    assert!(
+        elapsed >= std::time::Duration::from_millis(450),
+        "Backfill reads should be throttled to 20/s, took {:?}",
+        elapsed
+    );
+    hub_server.verify().await;
+}
+
+/// 20. Test: A zero backfill rate fails validation
+/// Why: A zero rate would stall the startup backfill forever
+#[test]
+fn test_backfill_max_rps_zero_rejected() {
+    let mut config = build_test_config_with_mvm();
+    config.integrated_gmp.backfill_max_rps = Some(0);
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("backfill_max_rps"), "Error should name the setting: {}", err);
+}
+
+// ============================================================================
+// PROGRAM DEPLOYMENT CHECK TESTS
+// ============================================================================
+
+/// Build a relay whose SVM chain points at `svm_server`, with distinct GMP, escrow,
+/// and outflow program IDs.
+fn build_relay_with_svm_programs(svm_server: &MockServer) -> NativeGmpRelay {
+    let mut config = build_test_config_with_svm();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_svm[0].rpc_url = svm_server.uri();
+    config.connected_chain_svm[0].gmp_endpoint_program_id = Some(DUMMY_SVM_GMP_PROGRAM_ID.to_string());
+    config.connected_chain_svm[0].escrow_program_id = DUMMY_SVM_ESCROW_PROGRAM_ID.to_string();
+    config.connected_chain_svm[0].outflow_program_id = DUMMY_SVM_OUTFLOW_PROGRAM_ID.to_string();
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    NativeGmpRelay::new(relay_config, crypto_service).unwrap()
+}
+
+/// Mount a getAccountInfo mock for `pubkey` returning an existing (program) account.
+async fn mock_svm_account_present(server: &MockServer, pubkey: &str) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "getAccountInfo", "params": [pubkey] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "context": { "slot": 1 }, "value": { "data": ["AgAAAA==", "base64"], "executable": true } },
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+}
+
+/// 21. Test: Startup program check succeeds when all configured SVM programs exist
+/// Why: A correctly configured relay must start normally
+#[tokio::test]
+async fn test_program_deployment_check_passes_when_programs_present() {
+    let svm_server = MockServer::start().await;
+    mock_svm_account_present(&svm_server, DUMMY_SVM_GMP_PROGRAM_ID).await;
+    mock_svm_account_present(&svm_server, DUMMY_SVM_ESCROW_PROGRAM_ID).await;
+    mock_svm_account_present(&svm_server, DUMMY_SVM_OUTFLOW_PROGRAM_ID).await;
+
+    let relay = build_relay_with_svm_programs(&svm_server);
+
+    relay
+        .check_program_deployment()
+        .await
+        .expect("Deployed programs should pass the startup check");
+}
+
+/// 22. Test: Startup program check fails when a configured SVM program is absent
+/// Why: An RPC pointed at the wrong network returns empty results; the relay must fail fast
+/// instead of silently relaying nothing
+#[tokio::test]
+async fn test_program_deployment_check_fails_when_program_absent() {
+    let svm_server = MockServer::start().await;
+    mock_svm_account_present(&svm_server, DUMMY_SVM_GMP_PROGRAM_ID).await;
+    mock_svm_account_present(&svm_server, DUMMY_SVM_ESCROW_PROGRAM_ID).await;
+    // Outflow validator program is not deployed on this ledger
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "getAccountInfo", "params": [DUMMY_SVM_OUTFLOW_PROGRAM_ID] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "context": { "slot": 1 }, "value": null },
+            "id": 1
+        })))
+        .mount(&svm_server)
+        .await;
+
+    let relay = build_relay_with_svm_programs(&svm_server);
+
+    let err = relay.check_program_deployment().await.unwrap_err().to_string();
+    assert!(err.contains("outflow validator"), "Error should name the missing program: {}", err);
+    assert!(err.contains(DUMMY_SVM_OUTFLOW_PROGRAM_ID), "Error should include the program ID: {}", err);
+}
+
+// ============================================================================
+// MVM OUTBOX CLEANUP TESTS
+// ============================================================================
+
+/// Mount a hub `gmp_sender::get_message` mock for one nonce routed to EVM chain 31337.
+async fn mock_hub_outbox_message(server: &MockServer, nonce: u64, intent_fill: &str) {
+    let payload = format!("0x01{}{}", intent_fill.repeat(32), "00".repeat(112));
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({
+            "function": "0x1::gmp_sender::get_message",
+            "arguments": [nonce.to_string()]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            "31337",
+            format!("0x{}", "00".repeat(32)),
+            payload,
+            "0x1"
+        ])))
+        .mount(server)
+        .await;
+}
+
+/// 23. Test: Delivered MVM outbox message is queued for cleanup, undelivered one is not
+/// Why: Cleanup reclaims hub storage only for messages the relay is done with; removing an
+/// undelivered message would lose it before it reaches its destination
+#[tokio::test]
+async fn test_mvm_outbox_cleanup_marks_only_delivered_messages() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+
+    // Hub outbox: nonce 1 (intent 0xaa..) and nonce 2 (intent 0xbb..), both to the EVM chain
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["3"])))
+        .mount(&hub_server)
+        .await;
+    mock_hub_outbox_message(&hub_server, 1, "aa").await;
+    mock_hub_outbox_message(&hub_server, 2, "bb").await;
+
+    // EVM endpoint reports nonce 1 as delivered; nonce 2 is not, and its delivery
+    // transaction fails (no other RPC methods are mocked)
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .and(body_string_contains("aa".repeat(32)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}1", "0".repeat(63)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .and(body_string_contains("bb".repeat(32)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}", "0".repeat(64)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr =
+        Some("0x00000000000000000000000000000000000000ab".to_string());
+    config.integrated_gmp.mvm_outbox_cleanup = true;
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, crypto_service).unwrap();
+
+    relay.poll_all_chains().await;
+
+    assert_eq!(relay.pending_outbox_cleanup(1).await, vec![1]);
+}
+
+// ============================================================================
+// MVM DELIVERY CONFIRMATION TESTS
+// ============================================================================
+
+const DUMMY_MVM_TX_HASH: &str = "0x00000000000000000000000000000000000000000000000000000000000000ab";
+
+/// Mount a `transactions/by_hash` mock that reports the transaction as pending.
+async fn mock_mvm_tx_pending(server: &MockServer, times: Option<u64>) {
+    let mock = Mock::given(method("GET"))
+        .and(path(format!("/v1/transactions/by_hash/{}", DUMMY_MVM_TX_HASH)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "pending_transaction",
+            "hash": DUMMY_MVM_TX_HASH
+        })));
+    match times {
+        Some(n) => mock.up_to_n_times(n).mount(server).await,
+        None => mock.mount(server).await,
+    }
+}
+
+/// 24. Test: Submitted-but-uncommitted MVM delivery is reported as unconfirmed
+/// Why: Pollers hold their cursor only for this error; a generic failure would advance
+/// past a delivery that may still be dropped
+#[tokio::test]
+async fn test_mvm_delivery_unconfirmed_holds_cursor() {
+    let server = MockServer::start().await;
+    mock_mvm_tx_pending(&server, None).await;
+
+    let client = GmpMvmClient::new(&server.uri(), "0x1", 1).unwrap();
+    let err = client
+        .wait_for_commit(DUMMY_MVM_TX_HASH, std::time::Duration::from_millis(200))
+        .await
+        .unwrap_err();
+
+    assert!(is_unconfirmed_delivery(&err), "Timeout should be DeliveryNotConfirmed: {:#}", err);
+    assert!(!is_unconfirmed_delivery(&anyhow::anyhow!("E_ALREADY_DELIVERED")));
+}
+
+/// 25. Test: MVM delivery confirmation succeeds once the transaction commits
+/// Why: A pending transaction must be re-queried until committed rather than failing early
+#[tokio::test]
+async fn test_mvm_delivery_confirmed_after_pending() {
+    let server = MockServer::start().await;
+    mock_mvm_tx_pending(&server, Some(1)).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/v1/transactions/by_hash/{}", DUMMY_MVM_TX_HASH)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "user_transaction",
+            "version": "42",
+            "hash": DUMMY_MVM_TX_HASH,
+            "success": true,
+            "events": []
+        })))
+        .mount(&server)
+        .await;
+
+    let client = GmpMvmClient::new(&server.uri(), "0x1", 1).unwrap();
+    client
+        .wait_for_commit(DUMMY_MVM_TX_HASH, std::time::Duration::from_secs(5))
+        .await
+        .expect("Committed transaction should confirm");
+}
+
+// ============================================================================
+// FIELD VALIDATION TESTS
+// ============================================================================
+
+/// 26. Test: Zero API port is rejected with its field path
+/// Why: Operators need to know which setting to fix, not just that the config is invalid
+#[test]
+fn test_validate_rejects_zero_api_port() {
+    let mut config = build_test_config_with_mvm();
+    config.api.port = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("api.port must be 1..65535, got 0"), "Unexpected error: {}", err);
+}
+
+/// 27. Test: Zero polling interval is rejected with its field path
+/// Why: A zero interval would spin the poll loop against every RPC
+#[test]
+fn test_validate_rejects_zero_polling_interval() {
+    let mut config = build_test_config_with_mvm();
+    config.integrated_gmp.polling_interval_ms = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("integrated_gmp.polling_interval_ms must be greater than 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// 28. Test: Missing chain-type-specific fields are reported by index and name
+/// Why: With several connected chains, the error must point at the right section
+#[test]
+fn test_validate_rejects_missing_chain_fields() {
+    let mut config = build_test_config_with_svm();
+    config.connected_chain_svm[0].outflow_program_id = String::new();
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("connected_chain_svm[0].outflow_program_id must not be empty"),
+        "Unexpected error: {}",
+        err
+    );
+
+    let mut config = build_test_config_with_evm();
+    config.connected_chain_evm[0].rpc_url = "127.0.0.1:8545".to_string();
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("connected_chain_evm[0].rpc_url must start with http:// or https://"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// 29. Test: Unknown enum value fails to parse and names the offending key
+/// Why: A typo in `mvm_commitment` must not silently fall back to the default
+#[test]
+fn test_parse_rejects_unknown_mvm_commitment() {
+    let toml = r#"
+polling_interval_ms = 1000
+validation_timeout_ms = 1000
+mvm_commitment = "finalized"
+"#;
+
+    let err = toml::from_str::<IntegratedGmpConfig>(toml).unwrap_err().to_string();
+    assert!(err.contains("mvm_commitment"), "Error should name the key: {}", err);
+    assert!(err.contains("finalized"), "Error should show the bad value: {}", err);
+}
+
+// ============================================================================
+// DELIVERY CONCURRENCY CAP TESTS
+// ============================================================================
+
+/// 30. Test: No more than N deliveries are in flight given a large backlog
+/// Verifies that with a cap of 3, twenty concurrent deliveries never hold more than
+/// three slots at once, and that the cap is actually reached (excess deliveries wait).
+/// Why: The cap bounds the relay wallet's fee exposure and RPC load during a backlog burst
+#[tokio::test]
+async fn test_delivery_limiter_caps_in_flight_deliveries() {
+    let limiter = std::sync::Arc::new(DeliveryLimiter::new(3));
+    let peak = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let deliveries: Vec<_> = (0..20)
+        .map(|_| {
+            let limiter = limiter.clone();
+            let peak = peak.clone();
+            tokio::spawn(async move {
+                let _slot = limiter.acquire().await;
+                peak.fetch_max(limiter.in_flight(), std::sync::atomic::Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            })
+        })
+        .collect();
+    for delivery in deliveries {
+        delivery.await.unwrap();
+    }
+
+    assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    assert_eq!(limiter.in_flight(), 0, "All slots should be released");
+}
+
+/// 31. Test: A zero delivery cap fails validation
+/// Why: A zero cap would block every delivery forever
+#[test]
+fn test_max_concurrent_deliveries_zero_rejected() {
+    let mut config = build_test_config_with_mvm();
+    config.integrated_gmp.max_concurrent_deliveries = Some(0);
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("max_concurrent_deliveries"), "Error should name the setting: {}", err);
+
+    config.integrated_gmp.max_concurrent_deliveries = Some(4);
+    config.validate().expect("Positive delivery cap should be valid");
+}
+
+// ============================================================================
+// PENDING MESSAGE STATUS TESTS
+// ============================================================================
+
+/// 32. Test: Status endpoint lists undelivered messages with their age and last error
+/// Verifies that after a poll in which two hub messages fail to deliver to the EVM chain,
+/// `GET /pending` returns both with source/destination, attempt count, age, and last error.
+/// Why: This is the triage dashboard's view of what the relay is stuck on during an incident
+#[tokio::test]
+async fn test_status_endpoint_lists_pending_messages() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+
+    // Hub outbox: nonces 1 and 2, both to the EVM chain
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["3"])))
+        .mount(&hub_server)
+        .await;
+    mock_hub_outbox_message(&hub_server, 1, "aa").await;
+    mock_hub_outbox_message(&hub_server, 2, "bb").await;
+
+    // Neither message is delivered yet, and delivery transactions fail (no other RPC
+    // methods are mocked)
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}", "0".repeat(64)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr =
+        Some("0x00000000000000000000000000000000000000ab".to_string());
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let relay = std::sync::Arc::new(NativeGmpRelay::new(relay_config, crypto_service).unwrap());
+
+    relay.poll_all_chains().await;
+
+    let response = warp::test::request()
+        .method("GET")
+        .path("/pending")
+        .reply(&status_api::routes(relay.clone()))
+        .await;
+
+    assert_eq!(response.status(), 200);
+    let body: PendingMessagesResponse = serde_json::from_slice(response.body()).unwrap();
+    assert_eq!(body.count, 2);
+    let nonces: Vec<u64> = body.messages.iter().map(|m| m.nonce).collect();
+    assert_eq!(nonces, vec![1, 2]);
+    for message in &body.messages {
+        assert_eq!(message.src_chain_id, 1);
+        assert_eq!(message.dst_chain_id, 31337);
+        assert_eq!(message.attempts, 1);
+        assert!(message.age_secs < 60, "Age should reflect the recent failure: {}", message.age_secs);
+        assert!(!message.last_error.is_empty(), "Last error should be recorded");
+        assert!(!message.dead_lettered);
+    }
+}
+
+// ============================================================================
+// RELAY CURSOR PERSISTENCE TESTS
+// ============================================================================
+
+/// Unique cursor file path under the system temp directory.
+fn temp_cursor_path() -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("integrated-gmp-cursors-{}", rand::random::<u64>()))
+        .join("cursors.json")
+}
+
+/// 33. Test: Relay cursors are saved after a poll and restored by a new relay
+/// Verifies that the hub outbox cursor reached by one relay is written to the cursor
+/// file and loaded by a relay created afterwards with the same config.
+/// Why: Without a persisted cursor a restarted relay loses its position and can skip
+/// or re-scan messages sent while it was down
+#[tokio::test]
+async fn test_relay_cursors_persist_across_restart() {
+    let hub_server = MockServer::start().await;
+    // Hub outbox holds nonces 1 and 2; their messages are unreadable and get skipped
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["3"])))
+        .mount(&hub_server)
+        .await;
+
+    let cursor_path = temp_cursor_path();
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.integrated_gmp.cursor_file = Some(cursor_path.to_string_lossy().into_owned());
+
+    {
+        let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+        let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+        relay.poll_all_chains().await;
+        assert_eq!(relay.cursors().await.mvm_last_nonces.get(&1), Some(&2));
+    }
+
+    let saved = CursorFile::new(&cursor_path).load().unwrap();
+    assert_eq!(saved.mvm_last_nonces.get(&1), Some(&2));
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+    assert_eq!(relay.cursors().await, saved);
+
+    std::fs::remove_dir_all(cursor_path.parent().unwrap()).unwrap();
+}
+
+/// Mount EVM mocks: head at `head_block` and no MessageSent logs.
+async fn mock_evm_head_without_logs(server: &MockServer, head_block: u64) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_blockNumber" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:x}", head_block),
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_getLogs" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": [],
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+}
+
+/// Build a relay polling only the EVM chain at `evm_server`.
+fn build_evm_only_relay(evm_server: &MockServer, configure: impl FnOnce(&mut IntegratedGmpConfig)) -> NativeGmpRelay {
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.enabled = false;
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr =
+        Some("0x00000000000000000000000000000000000000ab".to_string());
+    configure(&mut config.integrated_gmp);
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap()
+}
+
+/// 34. Test: EVM polling resumes from the saved block cursor, or a bounded window on cold start
+/// Verifies that without a saved cursor the first scan starts `evm_cold_start_blocks`
+/// behind the head, and with one it continues right after the saved block.
+/// Why: Resuming from the head after downtime silently skips MessageSent logs, while an
+/// unbounded cold start would scan the whole chain
+#[tokio::test]
+async fn test_evm_poll_resumes_from_saved_cursor() {
+    let evm_server = MockServer::start().await;
+    mock_evm_head_without_logs(&evm_server, 1000).await;
+
+    // Cold start: scan 10 blocks starting 50 behind the head
+    let relay = build_evm_only_relay(&evm_server, |gmp| gmp.evm_cold_start_blocks = 50);
+    relay.poll_all_chains().await;
+    assert_eq!(relay.cursors().await.evm_last_blocks.get(&31337), Some(&959));
+
+    // Saved cursor at block 500: continue from 501, not from the head
+    let cursor_path = temp_cursor_path();
+    let cursor_file = CursorFile::new(&cursor_path);
+    cursor_file
+        .save(&RelayCursors {
+            evm_last_blocks: [(31337, 500)].into_iter().collect(),
+            ..Default::default()
+        })
+        .unwrap();
+    let relay = build_evm_only_relay(&evm_server, |gmp| {
+        gmp.cursor_file = Some(cursor_path.to_string_lossy().into_owned())
+    });
+    relay.poll_all_chains().await;
+    assert_eq!(relay.cursors().await.evm_last_blocks.get(&31337), Some(&510));
+    assert_eq!(cursor_file.load().unwrap().evm_last_blocks.get(&31337), Some(&510));
+
+    std::fs::remove_dir_all(cursor_path.parent().unwrap()).unwrap();
+}
+
+/// 35. Test: Each enabled chain is a poll target with its own interval
+/// Verifies that disabled chains are not polled, a chain with `polling_interval_ms`
+/// uses it, and the others fall back to `integrated_gmp.polling_interval_ms`.
+/// Why: A rate-limited RPC must be pollable slower than the other chains
+#[test]
+fn test_chain_polling_interval_override() {
+    let mut config = build_test_config_with_evm();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].polling_interval_ms = Some(5000);
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+
+    assert_eq!(relay.poll_targets(), vec![PollTarget::MvmHub, PollTarget::Evm(31337)]);
+    assert_eq!(relay.chain_polling_interval(PollTarget::MvmHub), Duration::from_millis(1000));
+    assert_eq!(relay.chain_polling_interval(PollTarget::Evm(31337)), Duration::from_millis(5000));
+}
+
+/// 36. Test: Zero per-chain polling interval is rejected with its field path
+/// Why: A zero interval would spin that chain's poll task against its RPC
+#[test]
+fn test_validate_rejects_zero_chain_polling_interval() {
+    let mut config = build_test_config_with_evm();
+    config.connected_chain_evm[0].polling_interval_ms = Some(0);
 
-    attempt.record_failure();
-    let first_retry_after = attempt.next_retry_after;
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("connected_chain_evm[0].polling_interval_ms must be greater than 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// Mount MVM mocks: relay authorized, and `get_next_nonce` answered after `delay`.
+async fn mock_mvm_authorized_empty_outbox(server: &MockServer, module_addr: &str, delay: Duration) {
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({
+            "function": format!("{}::intent_gmp::is_relay_authorized", module_addr)
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([true])))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({
+            "function": format!("{}::gmp_sender::get_next_nonce", module_addr)
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["1"])).set_delay(delay))
+        .mount(server)
+        .await;
+}
+
+/// Count `get_next_nonce` view calls (one per outbox poll) received by `server`.
+async fn count_outbox_polls(server: &MockServer) -> usize {
+    server
+        .received_requests()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter(|r| String::from_utf8_lossy(&r.body).contains("get_next_nonce"))
+        .count()
+}
+
+/// Wait until `server` has received at least `polls` outbox polls, or panic after 5s.
+async fn wait_for_outbox_polls(server: &MockServer, polls: usize) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while count_outbox_polls(server).await < polls {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("timed out waiting for outbox polls");
+}
+
+/// 37. Test: A slow chain does not stall polling of the other chains
+/// Verifies that while the hub outbox read hangs, the connected MVM chain keeps being
+/// polled on its own interval.
+/// Why: With one sequential loop, a timing-out RPC delayed delivery on every chain
+#[tokio::test]
+async fn test_slow_chain_does_not_stall_other_chains() {
+    let hub_server = MockServer::start().await;
+    let connected_server = MockServer::start().await;
+    mock_mvm_authorized_empty_outbox(&hub_server, "0x1", Duration::from_secs(30)).await;
+    mock_mvm_authorized_empty_outbox(&connected_server, "0x2", Duration::ZERO).await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].rpc_url = connected_server.uri();
+    config.connected_chain_mvm[0].polling_interval_ms = Some(50);
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = Arc::new(NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap());
+    let run = tokio::spawn(relay.clone().run());
+
+    wait_for_outbox_polls(&connected_server, 3).await;
+    assert_eq!(count_outbox_polls(&hub_server).await, 1);
+
+    run.abort();
+}
+
+/// 38. Test: Shutdown stops the per-chain poll tasks and returns from run
+/// Verifies that `shutdown` interrupts a chain's sleep between polls and that `run`
+/// then returns Ok.
+/// Why: The relay must stop cleanly (saving cursors) instead of waiting out long
+/// polling intervals or being killed mid-task
+#[tokio::test]
+async fn test_shutdown_stops_run() {
+    let hub_server = MockServer::start().await;
+    mock_mvm_authorized_empty_outbox(&hub_server, "0x1", Duration::ZERO).await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.hub_chain.polling_interval_ms = Some(60_000);
+    config.connected_chain_mvm[0].enabled = false;
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = Arc::new(NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap());
+    let run = tokio::spawn(relay.clone().run());
+
+    wait_for_outbox_polls(&hub_server, 1).await;
+    relay.shutdown();
+
+    let result = tokio::time::timeout(Duration::from_secs(5), run)
+        .await
+        .expect("run did not return after shutdown")
+        .unwrap();
+    assert!(result.is_ok());
+}
+
+// ============================================================================
+// DEAD-LETTER TESTS
+// ============================================================================
+
+/// Mount EVM mocks: nothing delivered yet, and delivery transactions revert with `error`.
+async fn mock_evm_delivery_reverts(server: &MockServer, error: &str) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}", "0".repeat(64)),
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+    for (rpc_method, result) in [("eth_getTransactionCount", "0x0"), ("eth_gasPrice", "0x1")] {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": rpc_method })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "result": result,
+                "id": 1
+            })))
+            .mount(server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_sendRawTransaction" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": 3, "message": format!("execution reverted: {}", error) },
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+}
+
+/// Build a relay delivering hub outbox nonce 1 to the EVM chain at `evm_server`,
+/// saving dead letters to `dead_letter_path`.
+async fn build_hub_to_evm_relay(
+    hub_server: &MockServer,
+    evm_server: &MockServer,
+    dead_letter_path: &std::path::Path,
+) -> Arc<NativeGmpRelay> {
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["2"])))
+        .mount(hub_server)
+        .await;
+    mock_hub_outbox_message(hub_server, 1, "aa").await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr =
+        Some("0x00000000000000000000000000000000000000ab".to_string());
+    config.integrated_gmp.dead_letter_file = Some(dead_letter_path.to_string_lossy().into_owned());
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    Arc::new(NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap())
+}
+
+/// 39. Test: A permanently failing message is dead-lettered, listed, and saved
+/// Verifies that a delivery reverting with E_UNKNOWN_REMOTE_GMP_ENDPOINT still advances
+/// the cursor, leaves no pending entry, and is returned by `GET /dead-letters` with the
+/// full message and error, and written to the dead-letter file.
+/// Why: Skipped cross-chain messages were only logged, so operators could not see or
+/// recover them
+#[tokio::test]
+async fn test_permanent_failure_is_dead_lettered() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+    mock_evm_delivery_reverts(&evm_server, "E_UNKNOWN_REMOTE_GMP_ENDPOINT").await;
+
+    let dead_letter_path = temp_cursor_path().with_file_name("dead-letters.json");
+    let relay = build_hub_to_evm_relay(&hub_server, &evm_server, &dead_letter_path).await;
+    relay.poll_all_chains().await;
+
+    assert_eq!(relay.cursors().await.mvm_last_nonces.get(&1), Some(&1));
+    assert!(relay.pending_messages().await.is_empty());
+
+    let response = warp::test::request()
+        .method("GET")
+        .path("/dead-letters")
+        .reply(&status_api::routes(relay.clone()))
+        .await;
+    assert_eq!(response.status(), 200);
+    let body: DeadLettersResponse = serde_json::from_slice(response.body()).unwrap();
+    assert_eq!(body.count, 1);
+    let entry = &body.messages[0];
+    assert_eq!(entry.message.src_chain_id, 1);
+    assert_eq!(entry.message.nonce, 1);
+    assert_eq!(entry.message.dst_chain_id, 31337);
+    assert!(entry.message.payload.starts_with(&format!("0x01{}", "aa".repeat(32))));
+    assert!(entry.error.contains("E_UNKNOWN_REMOTE_GMP_ENDPOINT"), "Unexpected error: {}", entry.error);
+
+    let saved = DeadLetterFile::new(&dead_letter_path).load().unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].message.nonce, 1);
+
+    std::fs::remove_dir_all(dead_letter_path.parent().unwrap()).unwrap();
+}
+
+/// 40. Test: Retrying a dead-lettered message keeps it on failure and removes it on success
+/// Verifies `POST /dead-letters/{src_chain_id}/{nonce}/retry` responds 404 for an unknown
+/// message, 502 while delivery still fails (entry kept), and 200 once the destination
+/// accepts it (entry removed from memory and file).
+/// Why: Operators must be able to recover a skipped message after fixing its cause
+/// (e.g. registering the missing remote endpoint)
+#[tokio::test]
+async fn test_retry_dead_lettered_message() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+    mock_evm_delivery_reverts(&evm_server, "E_UNKNOWN_REMOTE_GMP_ENDPOINT").await;
+
+    let dead_letter_path = temp_cursor_path().with_file_name("dead-letters.json");
+    let relay = build_hub_to_evm_relay(&hub_server, &evm_server, &dead_letter_path).await;
+    relay.poll_all_chains().await;
+    let routes = status_api::routes(relay.clone());
+
+    let response = warp::test::request()
+        .method("POST")
+        .path("/dead-letters/1/9/retry")
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), 404);
+
+    let response = warp::test::request()
+        .method("POST")
+        .path("/dead-letters/1/1/retry")
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), 502);
+    let body: DeadLetterRetryResponse = serde_json::from_slice(response.body()).unwrap();
+    assert!(!body.delivered);
+    assert_eq!(relay.dead_letters().await.len(), 1);
+
+    // The destination now reports the message as delivered
+    evm_server.reset().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}1", "0".repeat(63)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+
+    let response = warp::test::request()
+        .method("POST")
+        .path("/dead-letters/1/1/retry")
+        .reply(&routes)
+        .await;
+    assert_eq!(response.status(), 200);
+    let body: DeadLetterRetryResponse = serde_json::from_slice(response.body()).unwrap();
+    assert!(body.delivered);
+    assert!(relay.dead_letters().await.is_empty());
+    assert!(DeadLetterFile::new(&dead_letter_path).load().unwrap().is_empty());
+
+    std::fs::remove_dir_all(dead_letter_path.parent().unwrap()).unwrap();
+}
+
+// ============================================================================
+// CHAIN DEFINITION DIRECTORY TESTS
+// ============================================================================
+
+/// EVM chain file for chain 31337.
+const EVM_CHAIN_FILE: &str = r#"
+[[chain]]
+type = "evm"
+name = "Local EVM"
+rpc_url = "http://127.0.0.1:8545"
+chain_id = 31337
+escrow_contract_addr = "0x0000000000000000000000000000000000000010"
+approver_evm_pubkey_hash = "0x000000000000000000000000000000000000000c"
+gmp_endpoint_addr = "0x00000000000000000000000000000000000000ab"
+"#;
+
+/// Write `config` with `chains_dir = "chains.d"` and the given chain files to a fresh
+/// temp directory. Returns the config file path.
+fn write_config_with_chain_files(config: &Config, chain_files: &[(&str, &str)]) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("integrated-gmp-chains-{}", rand::random::<u64>()));
+    std::fs::create_dir_all(dir.join("chains.d")).unwrap();
+    for (name, content) in chain_files {
+        std::fs::write(dir.join("chains.d").join(name), content).unwrap();
+    }
+    let mut config = config.clone();
+    config.chains_dir = Some("chains.d".to_string());
+    let config_path = dir.join("integrated-gmp.toml");
+    std::fs::write(&config_path, toml::to_string(&config).unwrap()).unwrap();
+    config_path
+}
+
+/// 41. Test: Chain files are merged into the connected chains
+/// Verifies that each `[[chain]]` file in `chains_dir` is added to the connected chains
+/// of its `type`, with field defaults applied and non-TOML files ignored.
+/// Why: Operators add a chain by dropping in one file instead of editing every config
+#[test]
+fn test_chains_dir_files_are_merged() {
+    let svm_file = format!(
+        r#"
+[[chain]]
+type = "svm"
+name = "Local SVM"
+rpc_url = "http://127.0.0.1:8899"
+chain_id = 901
+escrow_program_id = "{id}"
+outflow_program_id = "{id}"
+"#,
+        id = DUMMY_SVM_ESCROW_PROGRAM_ID
+    );
+    let config_path = write_config_with_chain_files(
+        &build_test_config_with_mvm(),
+        &[("evm.toml", EVM_CHAIN_FILE), ("svm.toml", &svm_file), ("README.md", "not a chain")],
+    );
+
+    let config = Config::load_from(config_path.to_str().unwrap()).unwrap();
+
+    assert_eq!(config.connected_chain_mvm.len(), 1);
+    assert_eq!(config.connected_chain_evm.len(), 1);
+    let evm = &config.connected_chain_evm[0];
+    assert_eq!(evm.chain_id, 31337);
+    assert_eq!(evm.gmp_endpoint_addr.as_deref(), Some("0x00000000000000000000000000000000000000ab"));
+    assert_eq!(evm.gas_limit_multiplier, 1.2);
+    assert_eq!(config.connected_chain_svm.len(), 1);
+    assert_eq!(config.connected_chain_svm[0].chain_id, 901);
+    assert!(config.connected_chain_svm[0].startup_message_scan);
+
+    std::fs::remove_dir_all(config_path.parent().unwrap()).unwrap();
+}
+
+/// 42. Test: A chain file identical to an inline chain is deduplicated
+/// Verifies that a file repeating an inline chain's exact definition does not add a
+/// second chain with the same ID.
+/// Why: Moving chains into `chains_dir` one at a time must not break a config that
+/// still lists them inline
+#[test]
+fn test_chains_dir_identical_definition_is_deduplicated() {
+    let mvm_file = r#"
+[[chain]]
+type = "mvm"
+name = "connected"
+rpc_url = "http://127.0.0.1:18082"
+chain_id = 2
+intent_module_addr = "0x2"
+escrow_module_addr = "0x2"
+"#;
+    let config_path = write_config_with_chain_files(
+        &build_test_config_with_mvm(),
+        &[("connected.toml", mvm_file), ("evm-a.toml", EVM_CHAIN_FILE), ("evm-b.toml", EVM_CHAIN_FILE)],
+    );
+
+    let config = Config::load_from(config_path.to_str().unwrap()).unwrap();
+
+    assert_eq!(config.connected_chain_mvm.len(), 1);
+    assert_eq!(config.connected_chain_evm.len(), 1);
+
+    std::fs::remove_dir_all(config_path.parent().unwrap()).unwrap();
+}
+
+/// 43. Test: Conflicting chain definitions fail loading with both sources named
+/// Verifies that a file redefining an inline chain ID with different settings, or a
+/// file with more than one `[[chain]]`, is rejected.
+/// Why: Silently picking one of two definitions would route messages to the wrong RPC
+#[test]
+fn test_chains_dir_conflicts_are_rejected() {
+    let conflicting = r#"
+[[chain]]
+type = "mvm"
+name = "connected"
+rpc_url = "http://127.0.0.1:19999"
+chain_id = 2
+intent_module_addr = "0x2"
+"#;
+    let config_path =
+        write_config_with_chain_files(&build_test_config_with_mvm(), &[("connected.toml", conflicting)]);
+    let err = Config::load_from(config_path.to_str().unwrap()).unwrap_err().to_string();
+    assert!(err.contains("chain ID 2"), "Unexpected error: {}", err);
+    assert!(err.contains("connected.toml"), "Unexpected error: {}", err);
+    assert!(err.contains("connected_chain_mvm[0]"), "Unexpected error: {}", err);
+    std::fs::remove_dir_all(config_path.parent().unwrap()).unwrap();
+
+    let two_chains = format!("{}{}", EVM_CHAIN_FILE, EVM_CHAIN_FILE);
+    let config_path =
+        write_config_with_chain_files(&build_test_config_with_mvm(), &[("evm.toml", &two_chains)]);
+    let err = Config::load_from(config_path.to_str().unwrap()).unwrap_err().to_string();
+    assert!(err.contains("exactly one [[chain]], found 2"), "Unexpected error: {}", err);
+    std::fs::remove_dir_all(config_path.parent().unwrap()).unwrap();
+}
+
+// ============================================================================
+// ADAPTIVE POLLING INTERVAL TESTS
+// ============================================================================
+
+/// 44. Test: Idle polls widen the chain's interval up to the max, a new message resets it
+/// Verifies that with `idle_polls_before_widening = 2` the hub interval stays at its
+/// base for two empty polls, then doubles per empty poll up to
+/// `max_idle_polling_interval_ms`, and snaps back to the base on the poll that finds
+/// an outbox message.
+/// Why: A fixed interval either burns RPC quota on quiet public endpoints or adds
+/// latency to every cross-chain hop
+#[tokio::test]
+async fn test_idle_polls_widen_interval_and_activity_resets_it() {
+    let hub_server = MockServer::start().await;
+    // Empty outbox for five polls, then nonce 1 appears
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["1"])))
+        .up_to_n_times(5)
+        .mount(&hub_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["2"])))
+        .mount(&hub_server)
+        .await;
+    mock_hub_outbox_message(&hub_server, 1, "aa").await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.integrated_gmp.max_idle_polling_interval_ms = Some(6000);
+    config.integrated_gmp.idle_polls_before_widening = 2;
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+    assert_eq!(relay.next_poll_interval(PollTarget::MvmHub).await, Duration::from_millis(1000));
+
+    let mut schedule = Vec::new();
+    for _ in 0..5 {
+        relay.poll_chain(PollTarget::MvmHub).await;
+        schedule.push(relay.next_poll_interval(PollTarget::MvmHub).await.as_millis());
+    }
+    assert_eq!(schedule, vec![1000, 1000, 2000, 4000, 6000]);
+    assert_eq!(relay.idle_polls(PollTarget::MvmHub).await, 5);
+
+    // Nonce 1 is read: back to the base interval immediately
+    relay.poll_chain(PollTarget::MvmHub).await;
+    assert_eq!(relay.cursors().await.mvm_last_nonces.get(&1), Some(&1));
+    assert_eq!(relay.idle_polls(PollTarget::MvmHub).await, 0);
+    assert_eq!(relay.next_poll_interval(PollTarget::MvmHub).await, Duration::from_millis(1000));
+
+    // Quiet again: the idle count starts over
+    relay.poll_chain(PollTarget::MvmHub).await;
+    assert_eq!(relay.idle_polls(PollTarget::MvmHub).await, 1);
+    assert_eq!(relay.next_poll_interval(PollTarget::MvmHub).await, Duration::from_millis(1000));
+}
+
+/// 45. Test: Adaptive interval without a max stays fixed, and the max never undercuts the base
+/// Why: Omitting `max_idle_polling_interval_ms` must keep the previous fixed-interval behaviour
+#[test]
+fn test_adaptive_polling_interval_bounds() {
+    let base = Duration::from_millis(5000);
+    assert_eq!(adaptive_polling_interval(base, None, 100, 3), base);
+    assert_eq!(adaptive_polling_interval(base, Some(Duration::from_millis(1000)), 10, 3), base);
+    assert_eq!(
+        adaptive_polling_interval(base, Some(Duration::from_secs(60)), u32::MAX, 0),
+        Duration::from_secs(60)
+    );
+}
+
+/// 46. Test: An idle max below the polling interval fails validation
+#[test]
+fn test_validate_rejects_idle_max_below_polling_interval() {
+    let mut config = build_test_config_with_mvm();
+    config.integrated_gmp.max_idle_polling_interval_ms = Some(500);
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("integrated_gmp.max_idle_polling_interval_ms must be at least"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+// ============================================================================
+// BACKFILL TESTS
+// ============================================================================
+
+/// 47. Test: Backfill replays an explicit nonce range and reports each message
+/// Verifies that over hub nonces 1..=3 an already-delivered message is skipped, a
+/// failing delivery is reported as failed, an unreadable nonce is skipped, and the
+/// hub read cursor does not move.
+/// Why: Operators replaying a range after downtime must not double-deliver, and must
+/// see which messages still need attention
+#[tokio::test]
+async fn test_backfill_reports_each_message_and_keeps_cursor() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+    mock_hub_outbox_message(&hub_server, 1, "aa").await;
+    mock_hub_outbox_message(&hub_server, 2, "bb").await;
+
+    // Nonce 1 is already delivered; nonce 2 is not, and its delivery transaction fails
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .and(body_string_contains("aa".repeat(32)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}1", "0".repeat(63)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .and(body_string_contains("bb".repeat(32)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}", "0".repeat(64)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr =
+        Some("0x00000000000000000000000000000000000000ab".to_string());
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+
+    let target = relay.backfill_target("mvm", None).unwrap();
+    assert_eq!(target, PollTarget::MvmHub);
+    let entries = relay.backfill(target, 1, 3).await.unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].nonce, 1);
+    assert_eq!(entries[0].dst_chain_id, Some(31337));
+    assert_eq!(entries[0].result, BackfillResult::Skipped("Already delivered".to_string()));
+    assert_eq!(entries[1].nonce, 2);
+    assert!(matches!(entries[1].result, BackfillResult::Failed(_)), "Unexpected: {:?}", entries[1]);
+    assert_eq!(entries[2].nonce, 3);
+    assert_eq!(entries[2].dst_chain_id, None);
+    assert!(matches!(entries[2].result, BackfillResult::Skipped(_)), "Unexpected: {:?}", entries[2]);
+
+    assert!(relay.cursors().await.mvm_last_nonces.is_empty());
+    assert!(relay.backfill(target, 3, 1).await.is_err());
+}
+
+/// 48. Test: Backfill chain selection by type and chain ID
+/// Verifies that `mvm` defaults to the hub, a single EVM chain needs no chain ID, and
+/// unknown types or chain IDs are rejected.
+#[test]
+fn test_backfill_target_resolution() {
+    let config = build_test_config_with_evm();
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+
+    assert_eq!(relay.backfill_target("mvm", None).unwrap(), PollTarget::MvmHub);
+    assert_eq!(relay.backfill_target("mvm", Some(1)).unwrap(), PollTarget::MvmHub);
+    assert_eq!(relay.backfill_target("mvm", Some(2)).unwrap(), PollTarget::MvmConnected(2));
+    assert_eq!(relay.backfill_target("evm", None).unwrap(), PollTarget::Evm(31337));
+    assert!(relay.backfill_target("evm", Some(1)).is_err());
+    assert!(relay.backfill_target("svm", None).is_err());
+    assert!(relay.backfill_target("cosmos", None).is_err());
+}
+
+/// 49. Test: Metrics endpoint is off by default and exports relay metrics when enabled
+/// Verifies that `GET /metrics` returns 404 without `[metrics] enabled`, and that with it
+/// a recorded poll failure and the queue-size gauges appear in the Prometheus output.
+/// Why: Operators alert on poll errors and a growing dead-letter queue
+#[tokio::test]
+async fn test_metrics_endpoint() {
+    let mut config = build_test_config_with_mvm();
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = Arc::new(NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap());
+    assert!(relay.metrics().is_none());
+    let response = warp::test::request()
+        .method("GET")
+        .path("/metrics")
+        .reply(&metrics::routes(relay))
+        .await;
+    assert_eq!(response.status(), 404);
+
+    config.metrics.enabled = true;
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = Arc::new(NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap());
+    relay
+        .metrics()
+        .expect("Metrics should be enabled")
+        .record_poll("mvm-hub", Duration::from_millis(5), true);
+
+    let response = warp::test::request()
+        .method("GET")
+        .path("/metrics")
+        .reply(&metrics::routes(relay))
+        .await;
+    assert_eq!(response.status(), 200);
+    let body = String::from_utf8(response.body().to_vec()).unwrap();
+    assert!(body.contains(r#"integrated_gmp_polls_total{chain="mvm-hub"} 1"#), "{}", body);
+    assert!(body.contains(r#"integrated_gmp_poll_errors_total{chain="mvm-hub"} 1"#), "{}", body);
+    assert!(body.contains("integrated_gmp_pending_messages 0"), "{}", body);
+    assert!(body.contains("integrated_gmp_dead_letters 0"), "{}", body);
+}
+
+/// Mount EVM mocks: head at `head_block`, every block canonical with `block_hash`,
+/// and `logs` returned for any MessageSent query.
+async fn mock_evm_chain(server: &MockServer, head_block: u64, block_hash: &str, logs: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_blockNumber" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{:x}", head_block),
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_getBlockByNumber" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "hash": block_hash },
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_getLogs" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": logs,
+            "id": 1
+        })))
+        .mount(server)
+        .await;
+}
+
+/// 50. Test: A MessageSent log orphaned by a reorg is not delivered
+/// Verifies that with `confirmations` set the relay scans only up to head minus
+/// confirmations, holds its cursor instead of delivering a log whose block hash is no
+/// longer canonical, and skips the message once the rescanned range no longer has it.
+/// Why: Delivering a message from an orphaned block acts on an event that never happened
+#[tokio::test]
+async fn test_evm_reorged_log_is_not_delivered() {
+    const GMP_ENDPOINT: &str = "0x00000000000000000000000000000000000000ab";
+    let canonical_hash = format!("0x{}", "bb".repeat(32));
+    let orphaned_hash = format!("0x{}", "aa".repeat(32));
+
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr = Some(GMP_ENDPOINT.to_string());
+    config.connected_chain_evm[0].confirmations = 5;
+    config.integrated_gmp.evm_cold_start_blocks = 5;
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+
+    // MessageSent(dstChainId = 1, dstAddr, payload = 0xdeadbeef, nonce = 7) in block 994
+    let topic = format!("0x{}", hex::encode(Keccak256::digest(b"MessageSent(uint32,bytes32,bytes,uint64)")));
+    let word = |value: u64| format!("{:064x}", value);
+    let orphaned_log = json!([{
+        "address": GMP_ENDPOINT,
+        "topics": [topic, format!("0x{}", word(1))],
+        "data": format!("0x{}{}{}{}{:0<64}", "11".repeat(32), word(0x60), word(7), word(4), "deadbeef"),
+        "blockNumber": "0x3e2",
+        "blockHash": orphaned_hash,
+        "transactionHash": format!("0x{}", word(1)),
+        "logIndex": "0x0"
+    }]);
+
+    // The node still returns the log, but block 994 now has a different hash
+    mock_evm_chain(&evm_server, 1000, &canonical_hash, orphaned_log).await;
+    relay.poll_chain(PollTarget::Evm(31337)).await;
+    assert_eq!(relay.cursors().await.evm_last_blocks.get(&31337), None);
+    assert!(relay.pending_messages().await.is_empty());
+    assert!(hub_server.received_requests().await.unwrap().is_empty());
 
-    attempt.record_failure();
-    let second_retry_after = attempt.next_retry_after;
+    // The rescan of the canonical chain no longer finds the log; scanning stops 5 blocks
+    // behind the head
+    evm_server.reset().await;
+    mock_evm_chain(&evm_server, 1000, &canonical_hash, json!([])).await;
+    relay.poll_chain(PollTarget::Evm(31337)).await;
+    assert_eq!(relay.cursors().await.evm_last_blocks.get(&31337), Some(&995));
+    assert!(relay.pending_messages().await.is_empty());
+    assert!(hub_server.received_requests().await.unwrap().is_empty());
+}
+
+// ============================================================================
+// CONFIG CHECK TESTS
+// ============================================================================
+
+/// 51. Test: The validation report lists every error, not just the first
+/// Verifies that a config with an invalid field, a zero polling interval and a
+/// duplicate chain ID reports all three, and that validate() still fails on the first.
+/// Why: `--check-config` should let operators fix a config in one pass
+#[test]
+fn test_validation_report_collects_every_error() {
+    let mut config = build_test_config_with_mvm();
+    config.api.port = 0;
+    config.integrated_gmp.polling_interval_ms = 0;
+    config.connected_chain_mvm[0].chain_id = config.hub_chain.chain_id;
 
-    assert!(second_retry_after > first_retry_after, "Backoff should increase with each retry");
+    let report = config.validation_report();
+    assert!(!report.is_valid());
+    assert_eq!(report.errors.len(), 3, "Unexpected errors: {:?}", report.errors);
+    assert!(report.errors.iter().any(|e| e.contains("api.port must be 1..65535, got 0")));
+    assert!(report.errors.iter().any(|e| e.contains("integrated_gmp.polling_interval_ms must be greater than 0")));
+    assert!(report.errors.iter().any(|e| e.contains("Hub chain and connected MVM chain have the same chain ID")));
+
+    let err = config.validate().unwrap_err().to_string();
+    assert_eq!(err, report.errors[0]);
 }
 
-/// 12. Test: DeliveryAttempt is_exhausted returns false when under limit
-/// Why: Should only be true after max retries
+/// 52. Test: Config check reports unparseable program IDs and warnings
+/// Verifies that `Config::check` flags an SVM program ID that is not base58 (which
+/// validate() alone accepts) and warns about a disabled chain without failing on it.
+/// Why: A mistyped program ID otherwise only fails once the relay talks to the chain
 #[test]
-fn test_delivery_attempt_not_exhausted_under_limit() {
-    let attempt = DeliveryAttempt { count: MAX_DELIVERY_RETRIES - 1, next_retry_after: 0 };
-    assert!(!attempt.is_exhausted());
+fn test_config_check_reports_unparseable_program_id() {
+    let mut config = build_test_config_with_svm();
+    config.connected_chain_svm[0].outflow_program_id = "not-a-program-id".to_string();
+    config.connected_chain_mvm[0].enabled = false;
+
+    let warnings = config.validate().expect("Program IDs are only parsed by the config check");
+    assert!(warnings.iter().any(|w| w.contains("connected_chain_mvm[0] ('connected') is disabled")));
+
+    let config_path = std::env::temp_dir().join(format!("integrated-gmp-check-{}.toml", rand::random::<u64>()));
+    std::fs::write(&config_path, toml::to_string(&config).unwrap()).unwrap();
+    let report = Config::check(config_path.to_str().unwrap());
+    std::fs::remove_file(&config_path).unwrap();
+
+    assert_eq!(
+        report.errors,
+        vec!["Configuration error: connected_chain_svm[0].outflow_program_id is not a valid base58 SVM program ID, got 'not-a-program-id'".to_string()]
+    );
+    assert_eq!(report.warnings, warnings);
+
+    let missing = Config::check("does-not-exist/integrated-gmp.toml");
+    assert!(!missing.is_valid());
+    assert!(missing.errors[0].contains("failed to read 'does-not-exist/integrated-gmp.toml'"));
+}
+
+// ============================================================================
+// REMOTE GMP ENDPOINT TESTS
+// ============================================================================
+
+/// 53. Test: Startup warns when a destination does not trust the address the relay sends
+/// Verifies that the hub trusting the padded EVM endpoint passes, while an EVM endpoint
+/// that trusts a different address for the hub produces exactly one warning naming the
+/// route and both addresses.
+/// Why: A drifted trusted remote otherwise makes every delivery fail and get skipped silently
+#[tokio::test]
+async fn test_remote_gmp_endpoint_mismatch_is_reported() {
+    const GMP_ENDPOINT: &str = "0x00000000000000000000000000000000000000ab";
+    let evm_remote = format!("0x{:0>64}", "ab");
+    let stale_remote = format!("0x{}", "cc".repeat(32));
+
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+
+    // The hub trusts the EVM endpoint for chain 31337
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({
+            "function": "0x1::intent_gmp::get_remote_gmp_endpoint_addrs",
+            "arguments": [31337]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([[evm_remote]])))
+        .mount(&hub_server)
+        .await;
+    // The EVM endpoint trusts a stale address for the hub: bytes32[] with one element
+    let word = |value: u64| format!("{:064x}", value);
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "eth_call" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": format!("0x{}{}{}", word(0x20), word(1), "cc".repeat(32)),
+            "id": 1
+        })))
+        .mount(&evm_server)
+        .await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].gmp_endpoint_addr = Some(GMP_ENDPOINT.to_string());
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+
+    let warnings = relay.check_remote_gmp_endpoints().await;
+    assert_eq!(
+        warnings,
+        vec![format!(
+            "Remote GMP endpoint mismatch: chain 31337 rejects every message from chain 1: it trusts {} but the relay sends 0x1",
+            stale_remote
+        )]
+    );
+}
+
+/// 54. Test: Remote GMP endpoint comparison pads short addresses
+/// Verifies that a Move address with stripped leading zeros matches its 32-byte form, and
+/// that an empty trusted list is reported as unconfigured.
+/// Why: MVM module addresses are sent in short form but stored padded on destinations
+#[test]
+fn test_remote_gmp_endpoint_mismatch_pads_addresses() {
+    let padded = format!("0x{:0>64}", "1");
+    assert_eq!(remote_gmp_endpoint_mismatch("0x1", std::slice::from_ref(&padded)), None);
+    assert_eq!(
+        remote_gmp_endpoint_mismatch("0x1", &[]),
+        Some("no remote GMP endpoint is configured, the relay sends 0x1".to_string())
+    );
+    assert_eq!(
+        remote_gmp_endpoint_mismatch("0x2", std::slice::from_ref(&padded)),
+        Some(format!("it trusts {} but the relay sends 0x2", padded))
+    );
+}
+
+// ============================================================================
+// LOG CORRELATION TESTS
+// ============================================================================
+
+/// 55. Test: GmpMessage::intent_id reads the intent ID after the msg_type byte
+/// Verifies that the intent ID is taken from payload bytes 1..33 and lowercased, and that
+/// payloads too short to carry one return None.
+/// Why: The delivery span's intent_id field correlates relay logs with the intent lifecycle
+#[test]
+fn test_gmp_message_intent_id_from_payload() {
+    let intent_id_hex = "AB".repeat(32);
+    let mut message = GmpMessage {
+        src_chain_id: 1,
+        remote_gmp_endpoint_addr: "0x1".to_string(),
+        dst_chain_id: 31337,
+        dst_addr: format!("0x{}", "00".repeat(32)),
+        payload: format!("0x02{}ff", intent_id_hex),
+        nonce: 7,
+    };
+    assert_eq!(message.intent_id(), Some(format!("0x{}", intent_id_hex.to_lowercase())));
+
+    message.payload = "0x02abcd".to_string();
+    assert_eq!(message.intent_id(), None);
+}
+
+// ============================================================================
+// SVM COMMITMENT TESTS
+// ============================================================================
+
+/// 56. Test: svm_commitment defaults to confirmed and reaches the relay config
+/// Verifies that an SVM chain without `svm_commitment` parses as `confirmed`, that
+/// `finalized` parses, and that the value is carried into SvmRelayChainConfig.
+/// Why: Omitting the key must keep the previous read behavior, and a configured level
+/// that never reaches the SVM client would give a false sense of safety
+#[test]
+fn test_svm_commitment_default_and_propagation() {
+    let chain = r#"
+name = "svm"
+rpc_url = "http://127.0.0.1:8899"
+chain_id = 901
+escrow_program_id = "x"
+outflow_program_id = "x"
+"#;
+    let parsed = toml::from_str::<SvmChainConfig>(chain).unwrap();
+    assert_eq!(parsed.svm_commitment, SvmCommitment::Confirmed);
+
+    let parsed = toml::from_str::<SvmChainConfig>(&format!("{}svm_commitment = \"finalized\"\n", chain)).unwrap();
+    assert_eq!(parsed.svm_commitment, SvmCommitment::Finalized);
+    assert_eq!(parsed.svm_commitment.as_str(), "finalized");
+
+    let mut config = build_test_config_with_svm();
+    config.connected_chain_svm[0].svm_commitment = SvmCommitment::Processed;
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    assert_eq!(relay_config.svm_chains[0].commitment, SvmCommitment::Processed);
+}
+
+// ============================================================================
+// OPERATOR KEY SELECTION TESTS
+// ============================================================================
+
+/// 57. Test: Relay selects the backup operator key when the primary is not authorized
+/// Verifies that with the hub reporting the primary relay address as unauthorized and
+/// the backup address as authorized, startup succeeds and activates the backup key.
+/// Why: A rotated-out primary key must not halt the relay while a backup is authorized
+#[tokio::test]
+async fn test_check_authorization_selects_authorized_backup_key() {
+    let hub_server = MockServer::start().await;
+
+    let mut config = build_test_config_with_mvm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+
+    let backup_key = general_purpose::STANDARD.encode([7u8; 32]);
+    let backup_env = "TEST_RELAY_BACKUP_OPERATOR_KEY_57";
+    std::env::set_var(backup_env, &backup_key);
+    config.integrated_gmp.backup_private_key_envs = vec![backup_env.to_string()];
+
+    let crypto_service = CryptoService::new(&config).unwrap();
+    let primary_addr = crypto_service.get_move_address().unwrap();
+    let backup_addr = CryptoService::from_private_key(&backup_key).unwrap().get_move_address().unwrap();
+    assert_ne!(primary_addr, backup_addr);
+
+    for (addr, authorized) in [(&primary_addr, false), (&backup_addr, true)] {
+        Mock::given(method("POST"))
+            .and(path("/v1/view"))
+            .and(body_partial_json(json!({
+                "function": "0x1::intent_gmp::is_relay_authorized",
+                "arguments": [addr],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([authorized])))
+            .expect(1)
+            .mount(&hub_server)
+            .await;
+    }
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    assert_eq!(relay_config.backup_operator_private_keys, vec![backup_key]);
+    let relay = NativeGmpRelay::new(relay_config, crypto_service).unwrap();
+
+    relay.check_authorization().await.expect("Backup key is authorized on every enabled chain");
+    assert_eq!(relay.active_operator_index(), 1, "Backup key should be active");
+    hub_server.verify().await;
+}
+
+/// 58. Test: A parked operation with a park cool-down is attempted again after it
+/// Verifies that the budget still escalates once, that the operation is attempted again
+/// once the cool-down has passed, that later failures only restart the cool-down, and
+/// that a success clears the parked state.
+/// Why: Chain polls use a cool-down; a chain that exhausts its budget must keep being polled
+#[test]
+fn test_retry_budget_park_cooldown_allows_another_attempt() {
+    let key = "svm:4".to_string();
+
+    let mut cooling: RetryBudget<String> = RetryBudget::new(2).with_park_cooldown(300);
+    cooling.record_failure(key.clone());
+    assert_eq!(cooling.record_failure(key.clone()), RetryOutcome::Escalate { attempts: 2 });
+    assert!(cooling.is_parked(&key));
+    assert!(!cooling.should_attempt(&key), "Parked poll must wait out the cool-down");
+
+    let mut budget: RetryBudget<String> = RetryBudget::new(2).with_park_cooldown(0);
+    budget.record_failure(key.clone());
+    assert_eq!(budget.record_failure(key.clone()), RetryOutcome::Escalate { attempts: 2 });
+    assert!(budget.is_parked(&key));
+    assert!(budget.should_attempt(&key), "Parked poll should run again after the cool-down");
+
+    assert_eq!(budget.record_failure(key.clone()), RetryOutcome::AlreadyParked);
+    assert!(budget.should_attempt(&key), "Later failures must not stop the poll for good");
+
+    budget.clear(&key);
+    assert!(!budget.is_parked(&key), "A successful poll should clear the parked state");
+}
+
+/// 59. Test: A message to a disabled destination is dead-lettered, not counted as delivered
+/// Verifies that the hub cursor moves past the message, nothing is sent to the disabled
+/// chain, the message is dead-lettered with the reason, and a retry fails while the
+/// destination stays disabled.
+/// Why: Counting skipped messages as delivered lost them for good when the chain was re-enabled
+#[tokio::test]
+async fn test_disabled_destination_is_dead_lettered() {
+    let hub_server = MockServer::start().await;
+    let evm_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({ "function": "0x1::gmp_sender::get_next_nonce" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["2"])))
+        .mount(&hub_server)
+        .await;
+    mock_hub_outbox_message(&hub_server, 1, "aa").await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&evm_server)
+        .await;
+
+    let mut config = build_test_config_with_evm();
+    config.hub_chain.rpc_url = hub_server.uri();
+    config.connected_chain_mvm[0].enabled = false;
+    config.connected_chain_evm[0].rpc_url = evm_server.uri();
+    config.connected_chain_evm[0].enabled = false;
+
+    let relay_config = NativeGmpRelayConfig::from_config(&config).unwrap();
+    let relay = NativeGmpRelay::new(relay_config, CryptoService::new(&config).unwrap()).unwrap();
+    relay.poll_all_chains().await;
+
+    assert_eq!(relay.cursors().await.mvm_last_nonces.get(&1), Some(&1));
+    let dead_letters = relay.dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].message.dst_chain_id, 31337);
+    assert!(dead_letters[0].error.contains("disabled"), "Unexpected error: {}", dead_letters[0].error);
+
+    assert!(matches!(relay.retry_dead_letter(1, 1).await, DeadLetterRetry::Failed(_)));
+    assert_eq!(relay.dead_letters().await.len(), 1, "Retry must keep the message while disabled");
+    evm_server.verify().await;
 }
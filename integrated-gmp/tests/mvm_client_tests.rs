@@ -3,13 +3,26 @@
 //! These tests cover the helper functions extracted from `integrated_gmp_relay.rs`
 //! into the `mvm_client` module: address normalization, transaction hash extraction,
 //! VM status checking, view function byte parsing, and byte array conversion.
+//! Native (CLI-free) delivery and cleanup submission is tested against a mock MVM node.
+//! Tests of the aptos CLI path only run with the `aptos-cli` feature.
 
 mod helpers;
 
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ed25519_dalek::{Signature, SigningKey, Verifier};
 use helpers::DUMMY_TX_HASH;
-use integrated_gmp::mvm_client::{
-    check_vm_status_success, extract_transaction_hash, normalize_address, parse_view_bytes,
+use integrated_gmp::config::MvmSubmission;
+use integrated_gmp::integrated_gmp_relay::GmpMessage;
+#[cfg(feature = "aptos-cli")]
+use integrated_gmp::mvm_client::{check_vm_status_success, extract_transaction_hash};
+use integrated_gmp::mvm_client::{normalize_address, parse_view_bytes, GmpMvmClient};
+use integrated_gmp::mvm_transaction::{
+    cleanup_delivered_messages_payload, deliver_message_payload, ed25519_account_address,
+    RawTransaction,
 };
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // ADDRESS NORMALIZATION TESTS
@@ -39,6 +52,7 @@ fn test_normalize_address_preserves_existing_prefix() {
 /// Verifies that extract_transaction_hash parses the transaction_hash from JSON-formatted aptos CLI output.
 /// Why: Modern aptos CLI outputs JSON with transaction_hash field.
 #[test]
+#[cfg(feature = "aptos-cli")]
 fn test_extract_transaction_hash_from_json_output() {
     let output = format!(
         r#"{{"Result":{{"transaction_hash":"{}","success":true}}}}"#,
@@ -54,6 +68,7 @@ fn test_extract_transaction_hash_from_json_output() {
 /// Verifies that extract_transaction_hash returns None when no transaction hash is present in the output.
 /// Why: Some CLI outputs may not contain a transaction hash.
 #[test]
+#[cfg(feature = "aptos-cli")]
 fn test_extract_transaction_hash_returns_none_when_missing() {
     let output = "Some output without a transaction hash";
 
@@ -70,6 +85,7 @@ fn test_extract_transaction_hash_returns_none_when_missing() {
 /// Verifies that check_vm_status_success parses success from {"Result":{"success":true}}.
 /// Why: Standard aptos CLI format wraps output in a Result object.
 #[test]
+#[cfg(feature = "aptos-cli")]
 fn test_check_vm_status_success_result_wrapper() {
     let output = r#"{"Result":{"success":true,"vm_status":"Executed successfully"}}"#;
     assert!(check_vm_status_success(output).unwrap());
@@ -79,6 +95,7 @@ fn test_check_vm_status_success_result_wrapper() {
 /// Verifies that check_vm_status_success detects failure from {"Result":{"success":false}}.
 /// Why: VM execution can fail even when CLI exits with code 0.
 #[test]
+#[cfg(feature = "aptos-cli")]
 fn test_check_vm_status_failure_result_wrapper() {
     let output = r#"{"Result":{"success":false,"vm_status":"Move abort"}}"#;
     assert!(!check_vm_status_success(output).unwrap());
@@ -88,6 +105,7 @@ fn test_check_vm_status_failure_result_wrapper() {
 /// Verifies that check_vm_status_success parses success from top-level {"success":true}.
 /// Why: Some output formats don't wrap in Result.
 #[test]
+#[cfg(feature = "aptos-cli")]
 fn test_check_vm_status_success_top_level() {
     let output = r#"{"success":true}"#;
     assert!(check_vm_status_success(output).unwrap());
@@ -136,3 +154,213 @@ fn test_parse_view_bytes_empty_array() {
     let result = parse_view_bytes(&value).unwrap();
     assert_eq!(result, "");
 }
+
+// ============================================================================
+// NATIVE SUBMISSION TESTS
+// ============================================================================
+
+/// Module address the mock node hosts `intent_gmp` at
+const NATIVE_MODULE_ADDR: &str = "0xabc";
+
+/// Fixed relay key so the sender address is deterministic
+fn native_signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn native_test_message() -> GmpMessage {
+    GmpMessage {
+        src_chain_id: 30168,
+        remote_gmp_endpoint_addr: format!("0x{}", "11".repeat(32)),
+        dst_chain_id: 1,
+        dst_addr: format!("0x{}", "22".repeat(32)),
+        payload: "0x0102030405".to_string(),
+        nonce: 9,
+    }
+}
+
+/// Mount ledger info, account, and gas price mocks, and a submission mock answering `submit_status`.
+async fn mock_native_node(server: &MockServer, submit_status: u16) {
+    let sender = format!("0x{}", hex::encode(ed25519_account_address(&native_signing_key())));
+    Mock::given(method("GET"))
+        .and(path("/v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "chain_id": 4 })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/v1/accounts/{}", sender)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "sequence_number": "7",
+            "authentication_key": sender
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/estimate_gas_price"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "gas_estimate": 100 })))
+        .mount(server)
+        .await;
+    let submit = if submit_status == 202 {
+        ResponseTemplate::new(202).set_body_json(json!({ "hash": DUMMY_TX_HASH }))
+    } else {
+        ResponseTemplate::new(submit_status).set_body_json(json!({ "message": "node unavailable" }))
+    };
+    Mock::given(method("POST"))
+        .and(path("/v1/transactions"))
+        .and(header("content-type", "application/x.aptos.signed_transaction+bcs"))
+        .respond_with(submit)
+        .mount(server)
+        .await;
+}
+
+/// 12. Test: Native Delivery Submits A Signed deliver_message_entry Transaction
+/// Verifies that the native path posts a BCS signed transaction whose sender, sequence
+/// number, payload, and chain ID match the node state, with a valid relay signature.
+/// Why: A malformed payload or signature is rejected by the node, stalling all MVM delivery.
+#[tokio::test]
+async fn test_native_delivery_submits_signed_payload() {
+    let server = MockServer::start().await;
+    mock_native_node(&server, 202).await;
+
+    let signing_key = native_signing_key();
+    let client = GmpMvmClient::new(&server.uri(), NATIVE_MODULE_ADDR, 1)
+        .unwrap()
+        .with_submission(MvmSubmission::Native, false);
+    client
+        .deliver_message(&native_test_message(), &STANDARD.encode(signing_key.to_bytes()))
+        .await
+        .expect("Native delivery should succeed");
+
+    let requests = server.received_requests().await.unwrap();
+    let submit = requests
+        .iter()
+        .find(|r| r.url.path() == "/v1/transactions")
+        .expect("Transaction should be submitted");
+
+    // SignedTransaction = raw_txn || 0x00 || len(32) || public_key || len(64) || signature
+    let body = &submit.body;
+    let raw_len = body.len() - (1 + 1 + 32 + 1 + 64);
+    let (raw_bcs, authenticator) = body.split_at(raw_len);
+
+    let expected_payload =
+        deliver_message_payload(NATIVE_MODULE_ADDR, 30168, &[0x11; 32], &[1, 2, 3, 4, 5]).unwrap();
+    let sender = ed25519_account_address(&signing_key);
+    assert_eq!(&raw_bcs[..32], &sender);
+    assert_eq!(&raw_bcs[32..40], &7u64.to_le_bytes());
+    assert_eq!(&raw_bcs[40..40 + expected_payload.len()], expected_payload.as_slice());
+    let gas_fields = &raw_bcs[40 + expected_payload.len()..];
+    assert_eq!(&gas_fields[8..16], &100u64.to_le_bytes(), "gas_unit_price from estimate");
+    assert_eq!(*raw_bcs.last().unwrap(), 4, "chain_id from ledger info");
+
+    assert_eq!(authenticator[0], 0, "Ed25519 authenticator");
+    assert_eq!(&authenticator[2..34], signing_key.verifying_key().as_bytes());
+    let signature = Signature::from_slice(&authenticator[35..]).unwrap();
+    let raw_txn = RawTransaction {
+        sender,
+        sequence_number: 7,
+        payload: expected_payload,
+        max_gas_amount: u64::from_le_bytes(gas_fields[..8].try_into().unwrap()),
+        gas_unit_price: 100,
+        expiration_timestamp_secs: u64::from_le_bytes(gas_fields[16..24].try_into().unwrap()),
+        chain_id: 4,
+    };
+    assert_eq!(raw_txn.to_bcs(), raw_bcs);
+    signing_key
+        .verifying_key()
+        .verify(&raw_txn.signing_message(), &signature)
+        .expect("Signature should verify over the raw transaction signing message");
+}
+
+/// 13. Test: Native Delivery Failure Falls Back To The CLI
+/// Verifies that a rejected native submission is retried through the aptos CLI when
+/// fallback is enabled.
+/// Why: A node-side issue with BCS submission must not stop deliveries the CLI can still make.
+#[tokio::test]
+#[cfg(feature = "aptos-cli")]
+async fn test_native_delivery_failure_falls_back_to_cli() {
+    let server = MockServer::start().await;
+    mock_native_node(&server, 500).await;
+
+    let client = GmpMvmClient::new(&server.uri(), NATIVE_MODULE_ADDR, 1)
+        .unwrap()
+        .with_submission(MvmSubmission::Native, true);
+    let err = client
+        .deliver_message(&native_test_message(), &STANDARD.encode(native_signing_key().to_bytes()))
+        .await
+        .unwrap_err();
+
+    // The CLI cannot deliver to the mock node either; the error shows both attempts
+    let err = format!("{:#}", err);
+    assert!(err.contains("native submission failed first"), "CLI fallback should run: {}", err);
+    assert!(err.contains("Transaction submission rejected"), "Native error should be kept: {}", err);
+}
+
+/// 14. Test: Native Delivery Failure Without Fallback Does Not Invoke The CLI
+/// Verifies that with fallback disabled the native error is returned as is.
+/// Why: Operators who removed the aptos binary need the real error, not a CLI spawn failure.
+#[tokio::test]
+async fn test_native_delivery_failure_without_fallback() {
+    let server = MockServer::start().await;
+    mock_native_node(&server, 500).await;
+
+    let client = GmpMvmClient::new(&server.uri(), NATIVE_MODULE_ADDR, 1)
+        .unwrap()
+        .with_submission(MvmSubmission::Native, false);
+    let err = client
+        .deliver_message(&native_test_message(), &STANDARD.encode(native_signing_key().to_bytes()))
+        .await
+        .unwrap_err();
+
+    let err = format!("{:#}", err);
+    assert!(err.contains("Transaction submission rejected"), "Unexpected error: {}", err);
+    assert!(!err.contains("native submission failed first"), "CLI should not run: {}", err);
+}
+
+/// 15. Test: Cleanup Payload Encodes The Nonce Vector
+/// Verifies that cleanup_delivered_messages_payload calls gmp_sender::cleanup_delivered_messages
+/// with a single BCS vector<u64> argument.
+/// Why: Outbox cleanup no longer goes through the CLI, so the relay must encode the argument itself.
+#[test]
+fn test_cleanup_payload_encodes_nonce_vector() {
+    let payload = cleanup_delivered_messages_payload(NATIVE_MODULE_ADDR, &[1, 300]).unwrap();
+
+    // vector<u64> = uleb128(2) || 1u64 LE || 300u64 LE, wrapped as one length-prefixed argument
+    let mut nonces_arg = vec![17u8, 2];
+    nonces_arg.extend_from_slice(&1u64.to_le_bytes());
+    nonces_arg.extend_from_slice(&300u64.to_le_bytes());
+    assert!(payload.ends_with(&nonces_arg), "Unexpected argument encoding: {}", hex::encode(&payload));
+    assert!(payload.windows(10).any(|w| w == b"gmp_sender"));
+    assert!(payload.windows(26).any(|w| w == b"cleanup_delivered_messages"));
+}
+
+/// 16. Test: Native Cleanup Reports The Structured VM Status
+/// Verifies that a natively submitted cleanup that commits with a failed VM status returns
+/// an error carrying the node's `vm_status`.
+/// Why: Without CLI output to scrape, the committed transaction is the only source of the abort reason.
+#[tokio::test]
+async fn test_native_cleanup_reports_vm_status() {
+    let server = MockServer::start().await;
+    mock_native_node(&server, 202).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/v1/transactions/by_hash/{}", DUMMY_TX_HASH)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "user_transaction",
+            "version": "42",
+            "hash": DUMMY_TX_HASH,
+            "success": false,
+            "vm_status": "Move abort in 0xabc::gmp_sender: E_NOT_CLEANER(0x3)",
+            "events": []
+        })))
+        .mount(&server)
+        .await;
+
+    let client = GmpMvmClient::new(&server.uri(), NATIVE_MODULE_ADDR, 1).unwrap();
+    let err = client
+        .cleanup_delivered_messages(&[1, 2], &STANDARD.encode(native_signing_key().to_bytes()))
+        .await
+        .unwrap_err();
+
+    let err = format!("{:#}", err);
+    assert!(err.contains("E_NOT_CLEANER"), "vm_status should be reported: {}", err);
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests.iter().any(|r| r.url.path() == "/v1/transactions"), "Cleanup should be submitted natively");
+}
@@ -5,8 +5,16 @@
 
 mod helpers;
 
-use helpers::{build_test_config_with_mvm, build_test_config_with_svm};
+use helpers::{
+    build_test_config_with_mvm, build_test_config_with_svm, build_test_gmp_message, DUMMY_INTENT_ID,
+    TEST_MVM_CHAIN_ID,
+};
+use integrated_gmp::deliverer::{DeliveryOutcome, MessageDeliverer, MvmDeliverer};
 use integrated_gmp::integrated_gmp_relay::NativeGmpRelayConfig;
+use integrated_gmp::mvm_client::{DeliveryNotConfirmed, GmpMvmClient};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // SVM PUBKEY PARSING TESTS
@@ -199,3 +207,101 @@ fn test_relay_config_extracts_both_connected_chains() {
 
 // 31. Test: SVM Message Account Payload Truncation
 // NOTE: N/A for MVM - SVM account data parsing is SVM-specific
+
+// ============================================================================
+// EIP-1559 TRANSACTION TESTS
+// ============================================================================
+
+// 32. Test: EIP-1559 Transaction Encoding And Signature Recovery
+// NOTE: N/A for MVM - EIP-1559 transactions are EVM-specific
+
+// ============================================================================
+// EVM GAS ESTIMATION TESTS
+// ============================================================================
+
+// 33. Test: EVM Delivery Gas Limit From Estimate
+// NOTE: N/A for MVM - gas estimation is EVM-specific
+
+// 34. Test: EVM Delivery Gas Limit Falls Back When Estimation Fails
+// NOTE: N/A for MVM - gas estimation is EVM-specific
+
+// ============================================================================
+// SVM MESSAGE SCAN TESTS
+// ============================================================================
+
+// 35. Test: SVM Outbound Message Scan
+// NOTE: N/A for MVM - getProgramAccounts message scanning is SVM-specific
+
+// ============================================================================
+// DELIVERY OUTCOME TESTS
+// ============================================================================
+
+// 36. Test: MVM Delivery Error Classification
+/// Verifies that MvmDeliverer classifies unknown-endpoint, already-delivered and
+/// intent-not-found aborts as permanent, an uncommitted transaction as unconfirmed,
+/// and any other error as transient.
+/// Why: The outcome decides whether the relay dead-letters, retries, or holds its cursor
+#[test]
+fn test_mvm_delivery_error_classification() {
+    for abort in ["E_UNKNOWN_REMOTE_GMP_ENDPOINT", "E_ALREADY_DELIVERED", "E_INTENT_NOT_FOUND"] {
+        let error = anyhow::anyhow!("Move abort in 0x1::intent_gmp: {}(0x4)", abort);
+        let outcome = MvmDeliverer::classify_error(&error);
+        assert!(matches!(outcome, DeliveryOutcome::PermanentFailure(_)), "{}: {:?}", abort, outcome);
+    }
+
+    let unconfirmed = anyhow::Error::new(DeliveryNotConfirmed {
+        chain_id: TEST_MVM_CHAIN_ID,
+        tx_hash: "0xab".to_string(),
+        timeout_ms: 100,
+    });
+    assert!(matches!(MvmDeliverer::classify_error(&unconfirmed), DeliveryOutcome::Unconfirmed(_)));
+
+    assert_eq!(
+        MvmDeliverer::classify_error(&anyhow::anyhow!("connection refused")),
+        DeliveryOutcome::TransientFailure("connection refused".to_string())
+    );
+}
+
+// 37. Test: MVM Deliverer Detects Already Delivered Message
+/// Verifies that is_delivered queries intent_gmp::is_message_delivered with the
+/// payload's intent_id and msg_type.
+/// Why: The (intent_id, msg_type) dedup key must match the one the endpoint records
+#[tokio::test]
+async fn test_mvm_deliverer_detects_already_delivered() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/view"))
+        .and(body_partial_json(json!({
+            "function": "0x1::intent_gmp::is_message_delivered",
+            "arguments": [DUMMY_INTENT_ID, 1]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([true])))
+        .mount(&server)
+        .await;
+
+    let client = GmpMvmClient::new(&server.uri(), "0x1", TEST_MVM_CHAIN_ID).unwrap();
+    let deliverer = MvmDeliverer::new(&client, "");
+
+    assert!(deliverer.is_delivered(&build_test_gmp_message(TEST_MVM_CHAIN_ID)).await.unwrap());
+}
+
+// ============================================================================
+// COMMITMENT TESTS
+// ============================================================================
+
+// 38. Test: SVM Reads Use Configured Commitment
+// NOTE: N/A for MVM - commitment levels are SVM-specific
+
+// ============================================================================
+// PAUSE TESTS
+// ============================================================================
+
+// 39. Test: SVM Paused Endpoint Detection
+// NOTE: N/A for MVM - only the SVM GMP endpoint has a pause switch
+
+// ============================================================================
+// SOLVER TOKEN ACCOUNT TESTS
+// ============================================================================
+
+// 40. Test: SVM Solver ATA Follows Mint Owner
+// NOTE: N/A for MVM - associated token accounts are SVM-specific
@@ -0,0 +1,253 @@
+//! Tests for the signer abstraction.
+//!
+//! The remote signer is exercised against a mock signer on a local TCP socket that
+//! holds the same key as the in-memory signer, so both must produce identical output.
+
+mod helpers;
+
+use base64::{engine::general_purpose, Engine as _};
+use helpers::build_test_config_with_mvm;
+use integrated_gmp::config::RemoteSignerConfig;
+use integrated_gmp::crypto::signer::{RemoteSignerRequest, RemoteSignerResponse};
+use integrated_gmp::crypto::{CryptoService, InMemorySigner, RemoteSigner, Signer};
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey as EcdsaVerifyingKey};
+use k256::elliptic_curve::scalar::IsHigh;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+use zeroize::ZeroizeOnDrop;
+
+const DUMMY_SEED: [u8; 32] = [7u8; 32];
+const DUMMY_TX_HASH: [u8; 32] = [0x42; 32];
+
+/// Start a mock remote signer backed by an in-memory key and return its address.
+///
+/// Serves requests on a background thread until the test process exits.
+fn start_mock_remote_signer(seed: [u8; 32]) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let signer = InMemorySigner::from_seed(&seed).unwrap();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut line = String::new();
+            BufReader::new(stream.try_clone().unwrap()).read_line(&mut line).unwrap();
+
+            let response = match serde_json::from_str(&line).unwrap() {
+                RemoteSignerRequest::PublicKeys => RemoteSignerResponse {
+                    ed25519_public_key: Some(hex::encode(signer.ed25519_public_key().unwrap())),
+                    ecdsa_public_key: Some(hex::encode(signer.ecdsa_public_key().unwrap())),
+                    ..Default::default()
+                },
+                RemoteSignerRequest::SignEd25519 { message } => RemoteSignerResponse {
+                    signature: Some(hex::encode(
+                        signer.sign_ed25519(&hex::decode(message).unwrap()).unwrap(),
+                    )),
+                    ..Default::default()
+                },
+                RemoteSignerRequest::SignEcdsaPrehash { hash } => {
+                    let hash: [u8; 32] = hex::decode(hash).unwrap().try_into().unwrap();
+                    RemoteSignerResponse {
+                        signature: Some(hex::encode(signer.sign_ecdsa_prehash(&hash).unwrap())),
+                        ..Default::default()
+                    }
+                }
+            };
+
+            let mut out = serde_json::to_string(&response).unwrap();
+            out.push('\n');
+            stream.write_all(out.as_bytes()).unwrap();
+        }
+    });
+
+    addr
+}
+
+/// 1. Test: Remote signer produces the same keys and signatures as the in-memory signer
+/// Verifies public keys, an Ed25519 signature, and an ECDSA prehash signature match
+/// byte for byte when both signers hold the same key.
+/// Why: Moving the key to an HSM must not change the relay's addresses or signatures
+#[test]
+fn test_remote_signer_matches_in_memory_signer() {
+    let in_memory = InMemorySigner::from_seed(&DUMMY_SEED).unwrap();
+    let remote = RemoteSigner::connect(&start_mock_remote_signer(DUMMY_SEED), Duration::from_secs(5)).unwrap();
+
+    assert_eq!(remote.ed25519_public_key().unwrap(), in_memory.ed25519_public_key().unwrap());
+    assert_eq!(remote.ecdsa_public_key().unwrap(), in_memory.ecdsa_public_key().unwrap());
+    assert_eq!(
+        remote.sign_ed25519(b"deliver_message").unwrap(),
+        in_memory.sign_ed25519(b"deliver_message").unwrap()
+    );
+    assert_eq!(
+        remote.sign_ecdsa_prehash(&DUMMY_TX_HASH).unwrap(),
+        in_memory.sign_ecdsa_prehash(&DUMMY_TX_HASH).unwrap()
+    );
+}
+
+/// 2. Test: CryptoService configured with a remote signer matches the env-key service
+/// Verifies that with `integrated_gmp.remote_signer` set, the relay addresses and the
+/// EVM transaction signature (including recovery ID) equal those from the env key.
+/// Why: The EVM relay address is registered on-chain; a different key or recovery ID
+/// would make every EVM delivery fail authorization
+#[test]
+fn test_crypto_service_with_remote_signer_matches_env_key() {
+    let mut config = build_test_config_with_mvm();
+    let env_service = CryptoService::new(&config).unwrap();
+
+    let private_key_b64 = config.integrated_gmp.get_private_key().unwrap();
+    let seed: [u8; 32] = general_purpose::STANDARD
+        .decode(private_key_b64)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    config.integrated_gmp.remote_signer = Some(RemoteSignerConfig {
+        addr: start_mock_remote_signer(seed),
+        timeout_ms: 5000,
+    });
+    let remote_service = CryptoService::new(&config).unwrap();
+
+    assert_eq!(remote_service.get_public_key(), env_service.get_public_key());
+    assert_eq!(remote_service.get_move_address().unwrap(), env_service.get_move_address().unwrap());
+    assert_eq!(
+        remote_service.get_ethereum_address().unwrap(),
+        env_service.get_ethereum_address().unwrap()
+    );
+    assert_eq!(remote_service.get_solana_address(), env_service.get_solana_address());
+    assert_eq!(
+        remote_service.sign_evm_transaction_hash(&DUMMY_TX_HASH).unwrap(),
+        env_service.sign_evm_transaction_hash(&DUMMY_TX_HASH).unwrap()
+    );
+}
+
+/// 3. Test: Remote signer errors are surfaced
+/// Verifies that connecting to an address with no signer listening fails with context.
+/// Why: A misconfigured signer must stop startup rather than leave the relay unable to sign
+#[test]
+fn test_remote_signer_unreachable_fails_to_connect() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    drop(listener);
+
+    let err = RemoteSigner::connect(&addr, Duration::from_millis(500)).err().unwrap();
+    assert!(
+        format!("{:#}", err).contains("remote signer"),
+        "Error should name the remote signer: {:#}",
+        err
+    );
+}
+
+/// 4. Test: CryptoService loads a separate ECDSA key when configured
+/// Verifies that with `ecdsa_private_key_env` set, the EVM address and signatures come
+/// from that key, while the Ed25519 key and MVM/SVM addresses are unchanged.
+/// Why: Operators manage the EVM relay key as a standard Ethereum keypair and rotate
+/// it independently of the Ed25519 key
+#[test]
+fn test_crypto_service_loads_separate_ecdsa_key() {
+    let config = build_test_config_with_mvm();
+    let derived_service = CryptoService::new(&config).unwrap();
+
+    let ecdsa_key = [9u8; 32];
+    let expected_signer = InMemorySigner::from_keys(&DUMMY_SEED, &ecdsa_key).unwrap();
+    let expected_service = CryptoService::with_signer(Box::new(expected_signer)).unwrap();
+    let expected_address = expected_service.get_ethereum_address().unwrap();
+    std::env::set_var(&config.integrated_gmp.ecdsa_private_key_env, format!("0x{}", hex::encode(ecdsa_key)));
+    std::env::set_var(&config.integrated_gmp.ecdsa_address_env, &expected_address);
+
+    let service = CryptoService::new(&config).unwrap();
+
+    assert_eq!(service.get_ethereum_address().unwrap(), expected_address);
+    assert_ne!(service.get_ethereum_address().unwrap(), derived_service.get_ethereum_address().unwrap());
+    assert_eq!(
+        service.sign_evm_transaction_hash(&DUMMY_TX_HASH).unwrap(),
+        expected_service.sign_evm_transaction_hash(&DUMMY_TX_HASH).unwrap()
+    );
+    assert_eq!(service.get_public_key(), derived_service.get_public_key());
+    assert_eq!(service.get_solana_address(), derived_service.get_solana_address());
+}
+
+/// 5. Test: CryptoService rejects a separate ECDSA key that does not match its address
+/// Verifies startup fails when the expected address is wrong or not set.
+/// Why: Signing with an unexpected EVM key would make every EVM delivery fail
+/// authorization; a mismatch must stop startup like the Ed25519 public key check
+#[test]
+fn test_crypto_service_rejects_ecdsa_address_mismatch() {
+    let config = build_test_config_with_mvm();
+    std::env::set_var(&config.integrated_gmp.ecdsa_private_key_env, hex::encode([9u8; 32]));
+
+    let err = CryptoService::new(&config).err().unwrap();
+    assert!(
+        err.to_string().contains(&config.integrated_gmp.ecdsa_address_env),
+        "Error should name the missing address variable: {}",
+        err
+    );
+
+    std::env::set_var(&config.integrated_gmp.ecdsa_address_env, format!("0x{}", "00".repeat(20)));
+    let err = CryptoService::new(&config).err().unwrap();
+    assert!(
+        err.to_string().contains("ECDSA address mismatch"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// Signer that returns the malleable high-s form of every ECDSA signature.
+struct HighSSigner(InMemorySigner);
+
+// The wrapped InMemorySigner wipes its keys on drop
+impl ZeroizeOnDrop for HighSSigner {}
+
+impl Signer for HighSSigner {
+    fn ed25519_public_key(&self) -> anyhow::Result<[u8; 32]> {
+        self.0.ed25519_public_key()
+    }
+
+    fn ecdsa_public_key(&self) -> anyhow::Result<[u8; 65]> {
+        self.0.ecdsa_public_key()
+    }
+
+    fn sign_ed25519(&self, message: &[u8]) -> anyhow::Result<[u8; 64]> {
+        self.0.sign_ed25519(message)
+    }
+
+    fn sign_ecdsa_prehash(&self, hash: &[u8; 32]) -> anyhow::Result<[u8; 64]> {
+        let signature = EcdsaSignature::from_slice(&self.0.sign_ecdsa_prehash(hash)?).unwrap();
+        let (r, s) = signature.split_scalars();
+        let high_s = EcdsaSignature::from_scalars(r, -s).unwrap();
+        assert!(bool::from(high_s.s().is_high()));
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&high_s.to_bytes());
+        Ok(bytes)
+    }
+}
+
+/// 6. Test: EVM transaction signatures are normalized to low-s
+/// Verifies that for a range of hashes, a signer returning high-s signatures still
+/// yields `s <= n/2` with a recovery ID that recovers the relay's ECDSA key.
+/// Why: EVM chains reject high-s (malleable) signatures per EIP-2; an un-normalized
+/// signature from a remote signer would make the delivery transaction revert
+#[test]
+fn test_evm_signature_normalized_to_low_s() {
+    let signer = HighSSigner(InMemorySigner::from_seed(&DUMMY_SEED).unwrap());
+    let expected_key = EcdsaVerifyingKey::from_sec1_bytes(&signer.ecdsa_public_key().unwrap()).unwrap();
+    let service = CryptoService::with_signer(Box::new(signer)).unwrap();
+
+    for i in 0..32u8 {
+        let tx_hash = [i; 32];
+        let (r, s, recovery_id) = service.sign_evm_transaction_hash(&tx_hash).unwrap();
+
+        let mut sig_bytes = [0u8; 64];
+        sig_bytes[..32].copy_from_slice(&r);
+        sig_bytes[32..].copy_from_slice(&s);
+        let signature = EcdsaSignature::from_slice(&sig_bytes).unwrap();
+        assert!(!bool::from(signature.s().is_high()), "s must be <= n/2 for hash {}", i);
+
+        let recovered = EcdsaVerifyingKey::recover_from_prehash(
+            &tx_hash,
+            &signature,
+            RecoveryId::try_from(recovery_id).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(recovered, expected_key, "Recovery ID must match the normalized signature");
+    }
+}
@@ -7,14 +7,22 @@
 mod helpers;
 
 use helpers::{
-    build_test_config_with_svm, DUMMY_INTENT_ID,
+    build_test_config_with_svm, build_test_gmp_message, DUMMY_INTENT_ID,
     DUMMY_SVM_ESCROW_PROGRAM_ID, TEST_MVM_CHAIN_ID, TEST_SVM_CHAIN_ID,
 };
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde_json::json;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use std::str::FromStr;
+use integrated_gmp::config::SvmCommitment;
+use integrated_gmp::deliverer::{svm_solver_ata, DeliveryOutcome, MessageDeliverer, SvmDeliverer};
 use integrated_gmp::integrated_gmp_relay::{
-    parse_svm_pubkey, NativeGmpRelayConfig,
+    parse_svm_pubkey, NativeGmpRelayConfig, SvmRelayChainConfig,
 };
+use integrated_gmp::svm_client::{delivered_pda, GmpSvmClient};
+use wiremock::matchers::{body_partial_json, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // SVM PUBKEY PARSING TESTS
@@ -160,7 +168,7 @@ fn test_relay_config_handles_missing_mvm_connected() {
 
 // 10. Test: FulfillmentProof Payload Intent ID Extraction
 /// Verifies that intent_id and solver_addr can be correctly extracted from a FulfillmentProof payload at the expected byte offsets.
-/// Why: deliver_to_svm must correctly parse intent_id from payload to derive PDAs.
+/// Why: SvmDeliverer must correctly parse intent_id from payload to derive PDAs.
 #[test]
 fn test_fulfillment_proof_payload_intent_id_extraction() {
     // Build a valid FulfillmentProof payload (81 bytes)
@@ -188,7 +196,7 @@ fn test_fulfillment_proof_payload_intent_id_extraction() {
 
 // 11. Test: FulfillmentProof Payload Minimum Length
 /// Verifies that FulfillmentProof payloads are validated against the minimum 65-byte length requirement.
-/// Why: deliver_to_svm checks payload.len() >= 65 for required fields.
+/// Why: SvmDeliverer checks payload.len() >= 65 for required fields.
 #[test]
 fn test_fulfillment_proof_payload_minimum_length() {
     // Valid payload: 81 bytes
@@ -225,7 +233,7 @@ fn test_ata_derivation_formula() {
     let mint = Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
         .expect("Invalid mint pubkey");
 
-    // Derive ATA using the same formula as in deliver_to_svm
+    // Derive ATA using the same formula as in SvmDeliverer
     let (derived_ata, _bump) = Pubkey::find_program_address(
         &[
             owner.as_ref(),
@@ -362,3 +370,279 @@ fn test_ata_differs_by_owner() {
 // 29. TODO test_svm_message_account_field_extraction — not yet implemented for SVM
 // 30. TODO test_svm_message_account_discriminator_check — not yet implemented for SVM
 // 31. TODO test_svm_message_account_payload_truncation — not yet implemented for SVM
+
+// ============================================================================
+// EIP-1559 TRANSACTION TESTS
+// ============================================================================
+
+// 32. Test: EIP-1559 Transaction Encoding And Signature Recovery
+// NOTE: N/A for SVM - EIP-1559 transactions are EVM-specific
+
+// ============================================================================
+// EVM GAS ESTIMATION TESTS
+// ============================================================================
+
+// 33. Test: EVM Delivery Gas Limit From Estimate
+// NOTE: N/A for SVM - gas estimation is EVM-specific
+
+// 34. Test: EVM Delivery Gas Limit Falls Back When Estimation Fails
+// NOTE: N/A for SVM - gas estimation is EVM-specific
+
+// ============================================================================
+// SVM MESSAGE SCAN TESTS
+// ============================================================================
+
+/// Encode a GMP program MessageAccount (discriminator 7) as stored on chain.
+fn message_account_data(dst_chain_id: u32, nonce: u64, payload: &[u8]) -> Vec<u8> {
+    let mut data = vec![7u8];
+    data.extend_from_slice(&TEST_SVM_CHAIN_ID.to_le_bytes());
+    data.extend_from_slice(&dst_chain_id.to_le_bytes());
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&[0xaa; 32]);
+    data.extend_from_slice(&[0xbb; 32]);
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(payload);
+    data.push(255); // bump
+    data
+}
+
+// 35. Test: SVM Outbound Message Scan
+/// Verifies that scan_outbound_messages filters getProgramAccounts by the MessageAccount
+/// discriminator and destination chain at finalized commitment, and returns the parsed
+/// messages ordered by nonce.
+/// Why: The startup reconciliation relies on the scan to find messages the outbound
+/// nonce counter no longer covers
+#[tokio::test]
+async fn test_svm_scan_outbound_messages() {
+    let server = MockServer::start().await;
+    let dst_chain_id = TEST_MVM_CHAIN_ID;
+    let accounts: Vec<_> = [(5u64, &[0x05u8][..]), (2, &[0x02, 0x03][..])]
+        .iter()
+        .map(|(nonce, payload)| {
+            json!({
+                "pubkey": Pubkey::new_unique().to_string(),
+                "account": {
+                    "data": [STANDARD.encode(message_account_data(dst_chain_id, *nonce, payload)), "base64"]
+                }
+            })
+        })
+        .collect();
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "method": "getProgramAccounts",
+            "params": [DUMMY_SVM_ESCROW_PROGRAM_ID, {
+                "commitment": "finalized",
+                "filters": [
+                    { "memcmp": { "offset": 0, "bytes": STANDARD.encode([7u8]) } },
+                    { "memcmp": { "offset": 5, "bytes": STANDARD.encode(dst_chain_id.to_le_bytes()) } }
+                ]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": accounts,
+            "id": 1
+        })))
+        .mount(&server)
+        .await;
+
+    let client = GmpSvmClient::new(&server.uri(), DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let program_id = Pubkey::from_str(DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let messages = client.scan_outbound_messages(&program_id, dst_chain_id).await.unwrap();
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].nonce, 2);
+    assert_eq!(messages[0].payload, vec![0x02, 0x03]);
+    assert_eq!(messages[1].nonce, 5);
+    assert_eq!(messages[1].src_chain_id, TEST_SVM_CHAIN_ID);
+    assert_eq!(messages[1].dst_chain_id, dst_chain_id);
+    assert_eq!(messages[1].dst_addr, [0xaa; 32]);
+    assert_eq!(messages[1].remote_gmp_endpoint_addr, [0xbb; 32]);
+}
+
+// ============================================================================
+// DELIVERY OUTCOME TESTS
+// ============================================================================
+
+// 36. Test: SVM Delivery Error Classification
+/// Verifies that SvmDeliverer classifies unknown-endpoint, already-delivered and
+/// intent-not-found errors as permanent, and any other error as transient.
+/// Why: The outcome decides whether the relay dead-letters or retries
+#[test]
+fn test_svm_delivery_error_classification() {
+    for error in [
+        "E_UNKNOWN_REMOTE_GMP_ENDPOINT",
+        "Program log: AlreadyDelivered",
+        "Already delivered",
+        "E_INTENT_NOT_FOUND",
+    ] {
+        let outcome = SvmDeliverer::classify_error(&anyhow::anyhow!("{}", error));
+        assert!(matches!(outcome, DeliveryOutcome::PermanentFailure(_)), "{}: {:?}", error, outcome);
+    }
+
+    assert_eq!(
+        SvmDeliverer::classify_error(&anyhow::anyhow!("Failed to get latest blockhash")),
+        DeliveryOutcome::TransientFailure("Failed to get latest blockhash".to_string())
+    );
+}
+
+// 37. Test: SVM Deliverer Detects Already Delivered Message
+/// Verifies that deliver returns AlreadyDelivered without submitting when the
+/// DeliveredMessage PDA for the payload's (intent_id, msg_type) exists.
+/// Why: The PDA is the program's dedup record; resubmitting would fail and cost fees
+#[tokio::test]
+async fn test_svm_deliverer_detects_already_delivered() {
+    let server = MockServer::start().await;
+    let program_id = Pubkey::from_str(DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let intent_id: [u8; 32] = hex::decode(DUMMY_INTENT_ID.trim_start_matches("0x"))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let marker = delivered_pda(&program_id, &intent_id, 0x01);
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "getAccountInfo", "params": [marker.to_string()] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "context": { "slot": 1 }, "value": { "data": ["AQ==", "base64"] } },
+            "id": 1
+        })))
+        .mount(&server)
+        .await;
+
+    let chain = SvmRelayChainConfig {
+        rpc_url: server.uri(),
+        gmp_program_id: Some(DUMMY_SVM_ESCROW_PROGRAM_ID.to_string()),
+        escrow_program_id: None,
+        outflow_program_id: None,
+        chain_id: TEST_SVM_CHAIN_ID,
+        enabled: true,
+        polling_interval_ms: None,
+        startup_message_scan: false,
+        commitment: SvmCommitment::Confirmed,
+    };
+    let client = GmpSvmClient::new(&server.uri(), DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let deliverer = SvmDeliverer::new(&chain, &client, Keypair::new());
+
+    let outcome = deliverer.deliver(&build_test_gmp_message(TEST_SVM_CHAIN_ID)).await;
+
+    assert_eq!(outcome, DeliveryOutcome::AlreadyDelivered);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+// ============================================================================
+// COMMITMENT TESTS
+// ============================================================================
+
+// 38. Test: SVM Reads Use Configured Commitment
+/// Verifies that get_outbound_nonce, get_message_data and is_message_delivered
+/// send the configured commitment level with every getAccountInfo call.
+/// Why: Reading at a lower level than configured could relay a message from a
+/// slot that is later rolled back
+#[tokio::test]
+async fn test_svm_reads_use_configured_commitment() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "getAccountInfo" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "result": { "context": { "slot": 1 }, "value": null },
+            "id": 1
+        })))
+        .mount(&server)
+        .await;
+
+    let client = GmpSvmClient::new(&server.uri(), DUMMY_SVM_ESCROW_PROGRAM_ID)
+        .unwrap()
+        .with_commitment(SvmCommitment::Finalized);
+    let program_id = Pubkey::from_str(DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+
+    assert_eq!(client.get_outbound_nonce(&program_id).await.unwrap(), 0);
+    assert!(client.get_message_data(&program_id, 3).await.unwrap().is_none());
+    assert!(!client.is_message_delivered(&program_id, &[0x01; 32], 0x01).await.unwrap());
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 3);
+    for request in requests {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["params"][1]["commitment"], "finalized", "{}", body);
+    }
+}
+
+// ============================================================================
+// PAUSE TESTS
+// ============================================================================
+
+// 39. Test: SVM Paused Endpoint Detection
+/// Verifies that a DeliverMessage rejected by a paused endpoint is classified as
+/// Paused, and that is_paused reads the flag from the config account, treating a
+/// config account from before the pause switch as not paused.
+/// Why: A paused endpoint must hold deliveries without spending their retry budget,
+/// and the relay checks the config account before submitting again
+#[tokio::test]
+async fn test_svm_paused_endpoint_detection() {
+    let error = anyhow::anyhow!(
+        "Failed to submit SVM DeliverMessage transaction: custom program error: 0x14; Program log: Endpoint is paused, rejecting DeliverMessage"
+    );
+    assert!(matches!(SvmDeliverer::classify_error(&error), DeliveryOutcome::Paused(_)));
+
+    let server = MockServer::start().await;
+    let program_id = Pubkey::from_str(DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    let (config_pda, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    // disc(1) + admin(32) + chain_id(4) + lenient(1) + require_trusted_remote(1) + treasury(32) + paused(1) + bump(1)
+    let mut paused_config = vec![0u8; 73];
+    paused_config[0] = 1;
+    paused_config[71] = 1;
+    let mut legacy_config = vec![0u8; 72];
+    legacy_config[0] = 1;
+    legacy_config[71] = 255; // bump
+    for data in [paused_config, legacy_config] {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "method": "getAccountInfo", "params": [config_pda.to_string()] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "result": { "context": { "slot": 1 }, "value": { "data": [STANDARD.encode(&data), "base64"] } },
+                "id": 1
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = GmpSvmClient::new(&server.uri(), DUMMY_SVM_ESCROW_PROGRAM_ID).unwrap();
+    assert!(client.is_paused(&program_id).await.unwrap());
+    assert!(!client.is_paused(&program_id).await.unwrap());
+}
+
+// ============================================================================
+// SOLVER TOKEN ACCOUNT TESTS
+// ============================================================================
+
+// 40. Test: SVM Solver ATA Follows Mint Owner
+/// Verifies that svm_solver_ata derives the solver's ATA under the program that owns the
+/// mint, so SPL Token and Token-2022 mints give different accounts, and rejects mints
+/// owned by any other program.
+/// Why: A Token-2022 escrow releases to a Token-2022 ATA; an ATA derived under SPL Token
+/// does not exist for that mint and the fulfillment proof would fail to release.
+#[test]
+fn test_svm_solver_ata_follows_mint_owner() {
+    let token_program_id = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
+    let token_2022_program_id =
+        Pubkey::from_str("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb").unwrap();
+    let associated_token_program_id =
+        Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
+    let solver = Pubkey::new_from_array([0xAA; 32]);
+    let mint = Pubkey::new_from_array([0xBB; 32]);
+
+    for program in [token_program_id, token_2022_program_id] {
+        let (expected, _) = Pubkey::find_program_address(
+            &[solver.as_ref(), program.as_ref(), mint.as_ref()],
+            &associated_token_program_id,
+        );
+        assert_eq!(svm_solver_ata(&solver, &mint, &program).unwrap(), expected);
+    }
+    assert_ne!(
+        svm_solver_ata(&solver, &mint, &token_program_id).unwrap(),
+        svm_solver_ata(&solver, &mint, &token_2022_program_id).unwrap()
+    );
+    assert!(svm_solver_ata(&solver, &mint, &Pubkey::new_unique()).is_err());
+}
@@ -535,4 +535,8 @@ describe("IntentGmp", function () {
       expect(await outflowHandler.requirementsReceived()).to.equal(true);
     });
   });
+
+  // #51: test_reset_nonce_restarts_sequence — N/A for EVM (SVM-specific test-only instruction)
+  // #52: test_reset_nonce_rejects_non_admin — N/A for EVM (SVM-specific test-only instruction)
+  // #53: test_reset_nonce_absent_without_feature — N/A for EVM (SVM-specific test-only instruction)
 });
@@ -492,4 +492,7 @@ module mvmt_intent::intent_gmp_tests {
     // #48: test_set_escrow_handler — N/A for MVM (EVM-specific handler configuration)
     // #49: test_set_outflow_handler — N/A for MVM (EVM-specific handler configuration)
     // #50: test_route_to_both_handlers — N/A for MVM (EVM-specific dual handler routing)
+    // #51: test_reset_nonce_restarts_sequence — N/A for MVM (SVM-specific test-only instruction)
+    // #52: test_reset_nonce_rejects_non_admin — N/A for MVM (SVM-specific test-only instruction)
+    // #53: test_reset_nonce_absent_without_feature — N/A for MVM (SVM-specific test-only instruction)
 }
@@ -23,13 +23,26 @@
 /// keyed by nonce. The GMP relay polls `get_next_nonce()` and reads new
 /// messages via `get_message(nonce)` view functions. Expired messages can
 /// be cleaned up via `cleanup_expired_messages`, which sweeps all expired
-/// entries in a single call.
+/// entries in a single call. Authorized cleaners (the relay) can also remove
+/// specific messages they have delivered via `cleanup_delivered_messages`.
 module mvmt_intent::gmp_sender {
     use std::signer;
+    use std::vector;
     use aptos_framework::event;
     use aptos_framework::table::{Self, Table};
     use aptos_framework::timestamp;
 
+    // ============================================================================
+    // ERROR CODES
+    // ============================================================================
+
+    /// Caller is not the admin
+    const E_UNAUTHORIZED_ADMIN: u64 = 1;
+    /// Caller is not the admin or an authorized cleaner
+    const E_UNAUTHORIZED_CLEANER: u64 = 2;
+    /// Nonce has not been assigned to a message yet
+    const E_NONCE_NOT_SENT: u64 = 3;
+
     // ============================================================================
     // CONSTANTS
     // ============================================================================
@@ -81,12 +94,18 @@ module mvmt_intent::gmp_sender {
         next_nonce: u64,
         /// Oldest nonce still in the outbox (cleanup cursor)
         oldest_nonce: u64,
-        /// Admin address (can be used for future extensions)
+        /// Admin address (manages cleaners)
         admin: address,
         /// Outbox: nonce -> message. Relay reads via view functions.
         outbox: Table<u64, OutboundMessage>,
     }
 
+    /// Addresses allowed to remove delivered messages from the outbox.
+    /// Stored separately from `SenderConfig` so existing deployments can add it.
+    struct CleanupAuthority has key {
+        cleaners: vector<address>,
+    }
+
     // ============================================================================
     // INITIALIZATION
     // ============================================================================
@@ -201,10 +220,99 @@ module mvmt_intent::gmp_sender {
         };
     }
 
+    /// Remove specific messages the caller has confirmed as delivered (or
+    /// permanently undeliverable) on their destination chain.
+    ///
+    /// Only the admin or an authorized cleaner may call this; the hub cannot
+    /// observe remote delivery, so the caller is trusted to pass only nonces it
+    /// has finished with. Nonces that were already removed are ignored. After
+    /// removal, `oldest_nonce` advances past any leading gap so
+    /// `cleanup_expired_messages` does not rescan removed entries.
+    ///
+    /// # Aborts
+    /// - E_UNAUTHORIZED_CLEANER: If caller is not the admin or an authorized cleaner
+    /// - E_NONCE_NOT_SENT: If a nonce is >= `next_nonce`
+    public entry fun cleanup_delivered_messages(
+        caller: &signer,
+        nonces: vector<u64>,
+    ) acquires SenderConfig, CleanupAuthority {
+        let caller_addr = signer::address_of(caller);
+        assert!(is_cleaner(caller_addr), E_UNAUTHORIZED_CLEANER);
+
+        let config = borrow_global_mut<SenderConfig>(@mvmt_intent);
+        let i = 0;
+        let len = vector::length(&nonces);
+        while (i < len) {
+            let nonce = *vector::borrow(&nonces, i);
+            assert!(nonce < config.next_nonce, E_NONCE_NOT_SENT);
+            if (table::contains(&config.outbox, nonce)) {
+                table::remove(&mut config.outbox, nonce);
+            };
+            i = i + 1;
+        };
+
+        while (config.oldest_nonce < config.next_nonce
+            && !table::contains(&config.outbox, config.oldest_nonce)) {
+            config.oldest_nonce = config.oldest_nonce + 1;
+        };
+    }
+
+    /// Authorize an address to call `cleanup_delivered_messages`. Only the admin can call this.
+    public entry fun add_cleaner(
+        admin: &signer,
+        cleaner_addr: address,
+    ) acquires SenderConfig, CleanupAuthority {
+        assert_admin(admin);
+        if (!exists<CleanupAuthority>(@mvmt_intent)) {
+            move_to(admin, CleanupAuthority { cleaners: vector::empty() });
+        };
+        let authority = borrow_global_mut<CleanupAuthority>(@mvmt_intent);
+        if (!vector::contains(&authority.cleaners, &cleaner_addr)) {
+            vector::push_back(&mut authority.cleaners, cleaner_addr);
+        };
+    }
+
+    /// Revoke an address's cleanup authorization. Only the admin can call this.
+    public entry fun remove_cleaner(
+        admin: &signer,
+        cleaner_addr: address,
+    ) acquires SenderConfig, CleanupAuthority {
+        assert_admin(admin);
+        if (!exists<CleanupAuthority>(@mvmt_intent)) {
+            return
+        };
+        let authority = borrow_global_mut<CleanupAuthority>(@mvmt_intent);
+        let (found, index) = vector::index_of(&authority.cleaners, &cleaner_addr);
+        if (found) {
+            vector::remove(&mut authority.cleaners, index);
+        };
+    }
+
+    fun assert_admin(admin: &signer) acquires SenderConfig {
+        let config = borrow_global<SenderConfig>(@mvmt_intent);
+        assert!(config.admin == signer::address_of(admin), E_UNAUTHORIZED_ADMIN);
+    }
+
     // ============================================================================
     // VIEW FUNCTIONS
     // ============================================================================
 
+    #[view]
+    /// Check if an address may remove delivered messages (admin or authorized cleaner).
+    public fun is_cleaner(addr: address): bool acquires SenderConfig, CleanupAuthority {
+        if (borrow_global<SenderConfig>(@mvmt_intent).admin == addr) {
+            return true
+        };
+        exists<CleanupAuthority>(@mvmt_intent)
+            && vector::contains(&borrow_global<CleanupAuthority>(@mvmt_intent).cleaners, &addr)
+    }
+
+    #[view]
+    /// Check if a message is still stored in the outbox.
+    public fun has_message(nonce: u64): bool acquires SenderConfig {
+        table::contains(&borrow_global<SenderConfig>(@mvmt_intent).outbox, nonce)
+    }
+
     #[view]
     /// Get the next outbound nonce.
     public fun get_next_nonce(): u64 acquires SenderConfig {
@@ -2,4 +2,5 @@ pub mod messages;
 
 pub use messages::{
     EscrowConfirmation, FulfillmentProof, GmpError, GmpMessageType, IntentRequirements,
+    PayloadVersionMode,
 };
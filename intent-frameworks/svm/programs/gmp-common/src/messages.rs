@@ -28,6 +28,7 @@ pub enum GmpError {
     InvalidMessageType { expected: u8, got: u8 },
     InvalidLength { expected: usize, got: usize },
     UnknownMessageType(u8),
+    UnsupportedPayloadVersion { msg_type: u8, len: usize },
 }
 
 impl core::fmt::Display for GmpError {
@@ -50,6 +51,13 @@ impl core::fmt::Display for GmpError {
             GmpError::UnknownMessageType(t) => {
                 write!(f, "unknown message type: 0x{:02x}", t)
             }
+            GmpError::UnsupportedPayloadVersion { msg_type, len } => {
+                write!(
+                    f,
+                    "unsupported payload version for message type 0x{:02x}: {} bytes",
+                    msg_type, len
+                )
+            }
         }
     }
 }
@@ -267,3 +275,51 @@ pub fn peek_message_type(data: &[u8]) -> Result<GmpMessageType, GmpError> {
     }
     GmpMessageType::from_byte(data[0])
 }
+
+// ---------------------------------------------------------------------------
+// Payload Versions
+// ---------------------------------------------------------------------------
+
+/// How receivers treat payloads from a newer codec version.
+///
+/// The wire format has no version byte: new versions only append fields, so a
+/// payload longer than the known size for its message type is a newer version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PayloadVersionMode {
+    /// Reject payloads that do not exactly match a known version.
+    #[default]
+    Strict,
+    /// Accept newer versions by processing the known fields and ignoring
+    /// unknown trailing data.
+    Lenient,
+}
+
+/// Returns the encoded size of the known version of a message type.
+pub fn known_payload_size(msg_type: GmpMessageType) -> usize {
+    match msg_type {
+        GmpMessageType::IntentRequirements => INTENT_REQUIREMENTS_SIZE,
+        GmpMessageType::EscrowConfirmation => ESCROW_CONFIRMATION_SIZE,
+        GmpMessageType::FulfillmentProof => FULFILLMENT_PROOF_SIZE,
+    }
+}
+
+/// Reduces a raw GMP payload to the known version according to `mode`.
+///
+/// Payloads of the known size are returned unchanged. Longer payloads are
+/// rejected with `UnsupportedPayloadVersion` in strict mode and truncated to
+/// the known fields in lenient mode. Shorter payloads are returned unchanged
+/// so the message decoder reports the length error.
+pub fn normalize_payload(data: &[u8], mode: PayloadVersionMode) -> Result<&[u8], GmpError> {
+    let msg_type = peek_message_type(data)?;
+    let known_size = known_payload_size(msg_type);
+    if data.len() <= known_size {
+        return Ok(data);
+    }
+    match mode {
+        PayloadVersionMode::Strict => Err(GmpError::UnsupportedPayloadVersion {
+            msg_type: msg_type as u8,
+            len: data.len(),
+        }),
+        PayloadVersionMode::Lenient => Ok(&data[..known_size]),
+    }
+}
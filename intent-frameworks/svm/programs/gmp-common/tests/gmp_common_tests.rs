@@ -952,3 +952,72 @@ fn test_cross_chain_encoding_intent_requirements_max() {
     );
     println!("IntentRequirements max values encoding matches expected: {} bytes", encoded.len());
 }
+
+// ============================================================================
+// PAYLOAD VERSION TESTS
+// ============================================================================
+
+/// 41. Test: Known Payload Version Decodes In Both Modes
+/// Verifies that a payload of the known size is passed through unchanged by strict and lenient modes.
+/// Why: Version handling must never alter current-version messages.
+#[test]
+fn test_payload_version_known_version_decodes() {
+    let msg = FulfillmentProof {
+        intent_id: test_intent_id(),
+        solver_addr: test_addr_1(),
+        amount_fulfilled: DUMMY_AMOUNT,
+        timestamp: DUMMY_TIMESTAMP,
+    };
+    let encoded = msg.encode();
+
+    for mode in [PayloadVersionMode::Strict, PayloadVersionMode::Lenient] {
+        let payload = normalize_payload(&encoded, mode).unwrap();
+        assert_eq!(payload, &encoded[..]);
+        assert_eq!(FulfillmentProof::decode(payload).unwrap(), msg);
+    }
+}
+
+/// 42. Test: Strict Mode Rejects Newer Payload Version
+/// Verifies that a payload with trailing data beyond the known fields is rejected with UnsupportedPayloadVersion.
+/// Why: Strict receivers must fail with an explicit version error instead of an opaque length error.
+#[test]
+fn test_payload_version_strict_rejects_newer_version() {
+    let msg = FulfillmentProof {
+        intent_id: test_intent_id(),
+        solver_addr: test_addr_1(),
+        amount_fulfilled: DUMMY_AMOUNT,
+        timestamp: DUMMY_TIMESTAMP,
+    };
+    let mut newer = msg.encode().to_vec();
+    newer.extend_from_slice(&[0xEE; 16]);
+
+    let result = normalize_payload(&newer, PayloadVersionMode::Strict);
+    assert_eq!(
+        result.unwrap_err(),
+        GmpError::UnsupportedPayloadVersion {
+            msg_type: 0x03,
+            len: FULFILLMENT_PROOF_SIZE + 16,
+        }
+    );
+}
+
+/// 43. Test: Lenient Mode Processes Known Fields Of Newer Payload Version
+/// Verifies that lenient mode truncates unknown trailing data so the known fields decode correctly.
+/// Why: Receivers must keep working during rolling upgrades when the sender ships a newer version first.
+#[test]
+fn test_payload_version_lenient_processes_newer_version() {
+    let msg = IntentRequirements {
+        intent_id: test_intent_id(),
+        requester_addr: test_addr_1(),
+        amount_required: DUMMY_AMOUNT,
+        token_addr: test_addr_1(),
+        solver_addr: [0u8; 32],
+        expiry: DUMMY_EXPIRY,
+    };
+    let mut newer = msg.encode().to_vec();
+    newer.extend_from_slice(&[0xEE; 8]);
+
+    let payload = normalize_payload(&newer, PayloadVersionMode::Lenient).unwrap();
+    assert_eq!(payload.len(), INTENT_REQUIREMENTS_SIZE);
+    assert_eq!(IntentRequirements::decode(payload).unwrap(), msg);
+}
@@ -49,6 +49,24 @@ pub enum GmpError {
 
     #[error("Invalid account count for operation")]
     InvalidAccountCount,
+
+    #[error("Unsupported payload version: payload is newer than this endpoint understands")]
+    UnsupportedPayloadVersion,
+
+    #[error("No trusted remote configured for source chain")]
+    NoTrustedRemote,
+
+    #[error("Relay delivery rate limit exceeded for the current window")]
+    RateLimitExceeded,
+
+    #[error("Delivered message is still within its retention period")]
+    DeliveredMessageNotExpired,
+
+    #[error("Treasury account does not match the configured treasury")]
+    InvalidTreasury,
+
+    #[error("GMP endpoint is paused")]
+    Paused,
 }
 
 impl From<GmpError> for ProgramError {
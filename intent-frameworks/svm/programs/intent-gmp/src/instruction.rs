@@ -81,6 +81,8 @@ pub enum NativeGmpInstruction {
     /// The relay picks up the event and calls `DeliverMessage` on the
     /// destination chain.
     ///
+    /// Fails with `Paused` while the endpoint is paused.
+    ///
     /// Accounts expected:
     /// 0. `[]` Config account (PDA: ["config"])
     /// 1. `[writable]` Outbound nonce account (PDA: ["nonce_out"])
@@ -110,6 +112,7 @@ pub enum NativeGmpInstruction {
     ///
     /// Deduplication uses (intent_id, msg_type) extracted from the payload,
     /// making delivery immune to program redeployments (unlike sequential nonces).
+    /// Fails with `Paused` while the endpoint is paused.
     ///
     /// Message routing (similar to MVM's route_message):
     /// - IntentRequirements (0x01): Routes to BOTH outflow_validator AND intent_escrow (if routing configured)
@@ -118,7 +121,9 @@ pub enum NativeGmpInstruction {
     /// Accounts expected:
     /// 0. `[]` Config account (PDA: ["config"])
     /// 1. `[]` Relay account (PDA: ["relay", relay_pubkey])
+    ///    Must be writable when the relay has a rate limit (see `SetRelayLimit`).
     /// 2. `[]` Remote GMP endpoint account (PDA: ["remote_gmp_endpoint", src_chain_id])
+    ///    The message must come from the address set for its msg_type, else the chain-wide address.
     /// 3. `[writable]` Delivered message account (PDA: ["delivered", intent_id, &[msg_type]])
     /// 4. `[signer]` Relay (must be authorized)
     /// 5. `[signer]` Payer (for delivered message account creation)
@@ -135,4 +140,161 @@ pub enum NativeGmpInstruction {
         /// Message payload (encoded GMP message)
         payload: Vec<u8>,
     },
+
+    /// Set how DeliverMessage handles payloads from a newer codec version.
+    ///
+    /// Strict (default) rejects known message types whose payload is longer
+    /// than the known version with `UnsupportedPayloadVersion`. Lenient
+    /// truncates the unknown trailing data and delivers the known fields.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    /// 2. `[signer, writable]` Payer (covers the rent when a legacy config is reallocated)
+    /// 3. `[]` System program
+    SetPayloadVersionMode {
+        /// Accept newer payload versions by ignoring unknown trailing data
+        lenient: bool,
+    },
+
+    /// Set how DeliverMessage handles source chains without a remote GMP endpoint.
+    ///
+    /// Strict (default) rejects delivery from a source chain that has no
+    /// `SetRemoteGmpEndpointAddr` entry with `NoTrustedRemote`. Permissive
+    /// skips the remote endpoint check for such chains; intended for early
+    /// testing only. Configured remote endpoints are always enforced.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    /// 2. `[signer, writable]` Payer (covers the rent when a legacy config is reallocated)
+    /// 3. `[]` System program
+    SetTrustedRemoteMode {
+        /// Reject delivery from source chains without a configured remote GMP endpoint
+        require_trusted_remote: bool,
+    },
+
+    /// Set the per-relay DeliverMessage rate limit.
+    ///
+    /// A relay may deliver at most `max_per_window` messages per `window_secs`
+    /// seconds; further deliveries fail with `RateLimitExceeded` until the
+    /// window ends. `max_per_window = 0` (default) disables the limit.
+    /// Relay accounts created before rate limiting are reallocated to the
+    /// current size, with the payer covering the extra rent.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` Config account (PDA: ["config"])
+    /// 1. `[writable]` Relay account (PDA: ["relay", relay_pubkey])
+    /// 2. `[signer]` Admin
+    /// 3. `[signer, writable]` Payer
+    /// 4. `[]` System program
+    SetRelayLimit {
+        /// The relay public key to limit
+        relay: Pubkey,
+        /// Maximum deliveries per window (0 = unlimited)
+        max_per_window: u32,
+        /// Window length in seconds (must be non-zero when limited)
+        window_secs: u32,
+    },
+
+    /// Set the account that receives rent from pruned delivered message accounts.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    /// 2. `[signer, writable]` Payer (covers the rent when a legacy config is reallocated)
+    /// 3. `[]` System program
+    SetTreasury {
+        /// New treasury address
+        treasury: Pubkey,
+    },
+
+    /// Close a delivered message (dedup) account and refund its rent to the treasury.
+    ///
+    /// Permissionless. Fails with `DeliveredMessageNotExpired` until
+    /// `DeliveredMessage::RETENTION_SECS` have passed since delivery, so replay
+    /// protection holds for the whole retention period. Accounts created
+    /// before `delivered_at` was recorded cannot be pruned.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` Config account (PDA: ["config"])
+    /// 1. `[writable]` Delivered message account (PDA: ["delivered", intent_id, &[msg_type]])
+    /// 2. `[writable]` Treasury (must match the config treasury)
+    PruneDelivered {
+        /// Intent ID of the delivered message
+        intent_id: [u8; 32],
+        /// Message type of the delivered message
+        msg_type: u8,
+    },
+
+    /// Set or remove the trusted remote address for one message type from a source chain.
+    ///
+    /// DeliverMessage checks a message against the address set for its type,
+    /// falling back to the chain-wide `SetRemoteGmpEndpointAddr` address when
+    /// none is set. The chain-wide entry must exist first (`NoTrustedRemote`
+    /// otherwise). The account is resized to fit, with the payer covering the
+    /// extra rent.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` Config account (PDA: ["config"])
+    /// 1. `[writable]` Remote GMP endpoint account (PDA: ["remote_gmp_endpoint", src_chain_id])
+    /// 2. `[signer]` Admin
+    /// 3. `[signer, writable]` Payer
+    /// 4. `[]` System program
+    SetRemoteGmpEndpointAddrForType {
+        /// Source chain endpoint ID
+        src_chain_id: u32,
+        /// GMP message type the address applies to
+        msg_type: u8,
+        /// Trusted remote address (32 bytes), or None to fall back to the chain-wide address
+        addr: Option<[u8; 32]>,
+    },
+
+    /// Pause the endpoint: Send and DeliverMessage fail with `Paused`.
+    ///
+    /// A single circuit breaker for incidents, instead of removing every
+    /// relay. Admin instructions keep working while paused.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    /// 2. `[signer, writable]` Payer (covers the rent when a legacy config is reallocated)
+    /// 3. `[]` System program
+    Pause,
+
+    /// Unpause the endpoint so Send and DeliverMessage work again.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    /// 2. `[signer, writable]` Payer (covers the rent when a legacy config is reallocated)
+    /// 3. `[]` System program
+    Unpause,
+
+    /// Reset the outbound nonce to zero (test builds only).
+    ///
+    /// Only compiled with the `test-only` feature so that test suites can
+    /// rerun against the same validator with deterministic message PDAs.
+    /// Production builds do not contain this variant, so its discriminator
+    /// fails to deserialize with `InvalidInstructionData`.
+    ///
+    /// The SVM endpoint keeps a single outbound sequence shared by all
+    /// destinations, so the reset applies to every destination;
+    /// `dst_chain_id` is logged to identify the test run that requested it.
+    /// Message accounts passed after the admin are closed so their nonces
+    /// can be reused by subsequent `Send` calls.
+    ///
+    /// Must stay the last variant so that gating it does not shift the
+    /// discriminators of production instructions.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` Config account (PDA: ["config"])
+    /// 1. `[writable]` Outbound nonce account (PDA: ["nonce_out"])
+    /// 2. `[signer, writable]` Admin (receives rent from closed message accounts)
+    /// 3+. `[writable]` Message accounts to close (PDA: ["message", nonce])
+    #[cfg(feature = "test-only")]
+    ResetNonce {
+        /// Destination chain endpoint ID the caller is resetting for
+        dst_chain_id: u32,
+    },
 }
@@ -15,8 +15,11 @@
 //! - `AddRelay`: Authorize a relay to deliver messages
 //! - `RemoveRelay`: Deauthorize a relay
 //! - `SetRemoteGmpEndpointAddr`: Configure remote GMP endpoint addresses per chain
+//! - `SetRemoteGmpEndpointAddrForType`: Trust a different remote address for one message type
 //! - `Send`: Emit a MessageSent event for the relay to pick up
 //! - `DeliverMessage`: Called by relay to deliver messages to destination
+//! - `SetPayloadVersionMode`: Choose strict or lenient handling of newer payload versions
+//! - `ResetNonce`: Reset the outbound nonce between test runs (`test-only` feature)
 //!
 //! ## Security Model
 //!
@@ -39,6 +42,6 @@ pub use solana_program;
 pub use error::GmpError;
 pub use instruction::NativeGmpInstruction;
 pub use state::{
-    ConfigAccount, DeliveredMessage, MessageAccount, OutboundNonceAccount, RelayAccount,
-    RemoteGmpEndpoint,
+    ConfigAccount, DeliveredMessage, MessageAccount, MsgTypeRemote, OutboundNonceAccount,
+    RelayAccount, RemoteGmpEndpoint,
 };
@@ -1,13 +1,15 @@
 //! Instruction processor for the integrated GMP endpoint program.
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use gmp_common::messages::{normalize_payload, peek_message_type};
 #[allow(deprecated)]
 use solana_program::system_instruction;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    clock::Clock,
     msg,
-    program::invoke_signed,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
@@ -79,6 +81,51 @@ pub fn process_instruction(
             msg!("Instruction: DeliverMessage");
             process_deliver_message(program_id, accounts, src_chain_id, remote_gmp_endpoint_addr, payload)
         }
+        NativeGmpInstruction::SetPayloadVersionMode { lenient } => {
+            msg!("Instruction: SetPayloadVersionMode");
+            process_set_payload_version_mode(program_id, accounts, lenient)
+        }
+        NativeGmpInstruction::SetTrustedRemoteMode { require_trusted_remote } => {
+            msg!("Instruction: SetTrustedRemoteMode");
+            process_set_trusted_remote_mode(program_id, accounts, require_trusted_remote)
+        }
+        NativeGmpInstruction::SetRelayLimit {
+            relay,
+            max_per_window,
+            window_secs,
+        } => {
+            msg!("Instruction: SetRelayLimit");
+            process_set_relay_limit(program_id, accounts, relay, max_per_window, window_secs)
+        }
+        NativeGmpInstruction::SetTreasury { treasury } => {
+            msg!("Instruction: SetTreasury");
+            process_set_treasury(program_id, accounts, treasury)
+        }
+        NativeGmpInstruction::PruneDelivered { intent_id, msg_type } => {
+            msg!("Instruction: PruneDelivered");
+            process_prune_delivered(program_id, accounts, intent_id, msg_type)
+        }
+        NativeGmpInstruction::SetRemoteGmpEndpointAddrForType {
+            src_chain_id,
+            msg_type,
+            addr,
+        } => {
+            msg!("Instruction: SetRemoteGmpEndpointAddrForType");
+            process_set_remote_gmp_endpoint_addr_for_type(program_id, accounts, src_chain_id, msg_type, addr)
+        }
+        NativeGmpInstruction::Pause => {
+            msg!("Instruction: Pause");
+            process_set_paused(program_id, accounts, true)
+        }
+        NativeGmpInstruction::Unpause => {
+            msg!("Instruction: Unpause");
+            process_set_paused(program_id, accounts, false)
+        }
+        #[cfg(feature = "test-only")]
+        NativeGmpInstruction::ResetNonce { dst_chain_id } => {
+            msg!("Instruction: ResetNonce");
+            process_reset_nonce(program_id, accounts, dst_chain_id)
+        }
     }
 }
 
@@ -156,7 +203,7 @@ fn process_add_relay(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
     if config.admin != *admin.key {
@@ -193,10 +240,10 @@ fn process_add_relay(
         relay_data.serialize(&mut &mut relay_account.data.borrow_mut()[..])?;
     } else {
         // Re-authorize existing relay
-        let mut relay_data = RelayAccount::try_from_slice(&relay_account.data.borrow())
+        let mut relay_data = RelayAccount::unpack(&relay_account.data.borrow())
             .map_err(|_| GmpError::InvalidDiscriminator)?;
         relay_data.is_authorized = true;
-        relay_data.serialize(&mut &mut relay_account.data.borrow_mut()[..])?;
+        relay_data.pack(&mut relay_account.data.borrow_mut())?;
     }
 
     msg!("Relay authorized: {}", relay);
@@ -220,7 +267,7 @@ fn process_remove_relay(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
     if config.admin != *admin.key {
@@ -236,10 +283,10 @@ fn process_remove_relay(
     }
 
     // Deauthorize relay
-    let mut relay_data = RelayAccount::try_from_slice(&relay_account.data.borrow())
+    let mut relay_data = RelayAccount::unpack(&relay_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
     relay_data.is_authorized = false;
-    relay_data.serialize(&mut &mut relay_account.data.borrow_mut()[..])?;
+    relay_data.pack(&mut relay_account.data.borrow_mut())?;
 
     msg!("Relay deauthorized: {}", relay);
     Ok(())
@@ -265,7 +312,7 @@ fn process_set_remote_gmp_endpoint_addr(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
     if config.admin != *admin.key {
@@ -313,12 +360,12 @@ fn process_set_remote_gmp_endpoint_addr(
             RemoteGmpEndpoint::new(src_chain_id, addr, remote_gmp_endpoint_bump);
         remote_gmp_endpoint.serialize(&mut &mut remote_gmp_endpoint_account.data.borrow_mut()[..])?;
     } else {
-        // Update existing remote GMP endpoint
+        // Update existing remote GMP endpoint (type overrides are kept)
         let mut remote_gmp_endpoint =
-            RemoteGmpEndpoint::try_from_slice(&remote_gmp_endpoint_account.data.borrow())
+            RemoteGmpEndpoint::unpack(&remote_gmp_endpoint_account.data.borrow())
                 .map_err(|_| GmpError::InvalidDiscriminator)?;
         remote_gmp_endpoint.addr = addr;
-        remote_gmp_endpoint.serialize(&mut &mut remote_gmp_endpoint_account.data.borrow_mut()[..])?;
+        remote_gmp_endpoint.pack(&mut remote_gmp_endpoint_account.data.borrow_mut())?;
     }
 
     msg!(
@@ -329,6 +376,95 @@ fn process_set_remote_gmp_endpoint_addr(
     Ok(())
 }
 
+/// Set or remove the trusted remote address for one message type from a source chain.
+fn process_set_remote_gmp_endpoint_addr_for_type(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    src_chain_id: u32,
+    msg_type: u8,
+    addr: Option<[u8; 32]>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let remote_gmp_endpoint_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    // Derive remote GMP endpoint PDA
+    let chain_id_bytes = src_chain_id.to_le_bytes();
+    let (remote_gmp_endpoint_pda, _) = Pubkey::find_program_address(
+        &[seeds::REMOTE_GMP_ENDPOINT_SEED, &chain_id_bytes],
+        program_id,
+    );
+
+    if remote_gmp_endpoint_account.key != &remote_gmp_endpoint_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Overrides refine the chain-wide entry, which must be configured first
+    if remote_gmp_endpoint_account.data_is_empty() {
+        msg!("No trusted remote configured for src_chain_id={}", src_chain_id);
+        return Err(GmpError::NoTrustedRemote.into());
+    }
+
+    let mut remote_gmp_endpoint =
+        RemoteGmpEndpoint::unpack(&remote_gmp_endpoint_account.data.borrow())
+            .map_err(|_| GmpError::InvalidDiscriminator)?;
+    remote_gmp_endpoint.set_type_override(msg_type, addr);
+
+    // Resize to fit the override list, topping up rent when it grows
+    let new_size = remote_gmp_endpoint.size();
+    if remote_gmp_endpoint_account.data_len() != new_size {
+        let rent = Rent::get()?;
+        let shortfall = rent
+            .minimum_balance(new_size)
+            .saturating_sub(remote_gmp_endpoint_account.lamports());
+        if shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, remote_gmp_endpoint_account.key, shortfall),
+                &[payer.clone(), remote_gmp_endpoint_account.clone(), system_program.clone()],
+            )?;
+        }
+        remote_gmp_endpoint_account.resize(new_size)?;
+    }
+    remote_gmp_endpoint.pack(&mut remote_gmp_endpoint_account.data.borrow_mut())?;
+
+    match addr {
+        Some(addr) => msg!(
+            "Remote GMP endpoint set: chain_id={}, msg_type={}, addr={}",
+            src_chain_id,
+            msg_type,
+            hex_encode(&addr)
+        ),
+        None => msg!(
+            "Remote GMP endpoint override removed: chain_id={}, msg_type={}",
+            src_chain_id,
+            msg_type
+        ),
+    }
+    Ok(())
+}
+
 /// Set routing configuration for message delivery.
 /// Configures which programs handle different message types (like MVM's route_message).
 fn process_set_routing(
@@ -350,7 +486,7 @@ fn process_set_routing(
     }
 
     // Load and verify config
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
     if config.admin != *admin.key {
@@ -429,9 +565,14 @@ fn process_send(
     }
 
     // Load config to get this chain's ID
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
+    if config.paused {
+        msg!("Endpoint is paused, rejecting Send");
+        return Err(GmpError::Paused.into());
+    }
+
     // Derive global nonce PDA (single sequence across all destinations)
     let (nonce_pda, nonce_bump) =
         Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], program_id);
@@ -534,6 +675,426 @@ fn process_send(
     Ok(())
 }
 
+/// Grow a config account created before the endpoint settings were added,
+/// with the payer covering the extra rent.
+fn grow_legacy_config<'a>(
+    config_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if config_account.data_len() >= ConfigAccount::SIZE {
+        return Ok(());
+    }
+    let rent = Rent::get()?;
+    let shortfall = rent
+        .minimum_balance(ConfigAccount::SIZE)
+        .saturating_sub(config_account.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, config_account.key, shortfall),
+            &[payer.clone(), config_account.clone(), system_program.clone()],
+        )?;
+    }
+    config_account.resize(ConfigAccount::SIZE)?;
+    Ok(())
+}
+
+/// Set strict or lenient payload version handling for DeliverMessage.
+fn process_set_payload_version_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lenient: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    config.lenient_payload_versions = lenient;
+    grow_legacy_config(config_account, payer, system_program)?;
+    config.pack(&mut config_account.data.borrow_mut())?;
+
+    msg!("Payload version mode set: lenient={}", lenient);
+    Ok(())
+}
+
+/// Set strict or permissive handling of source chains without a remote GMP endpoint.
+fn process_set_trusted_remote_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    require_trusted_remote: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    config.require_trusted_remote = require_trusted_remote;
+    grow_legacy_config(config_account, payer, system_program)?;
+    config.pack(&mut config_account.data.borrow_mut())?;
+
+    msg!("Trusted remote mode set: require_trusted_remote={}", require_trusted_remote);
+    Ok(())
+}
+
+/// Set the per-relay DeliverMessage rate limit.
+fn process_set_relay_limit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    relay: Pubkey,
+    max_per_window: u32,
+    window_secs: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let relay_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    // A limit needs a window to count in
+    if max_per_window > 0 && window_secs == 0 {
+        return Err(GmpError::InvalidInstructionData.into());
+    }
+
+    // Derive relay PDA
+    let (relay_pda, _) =
+        Pubkey::find_program_address(&[seeds::RELAY_SEED, relay.as_ref()], program_id);
+
+    if relay_account.key != &relay_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    let mut relay_data = RelayAccount::unpack(&relay_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    // Grow legacy relay accounts so they can store the limit
+    if relay_account.data_len() < RelayAccount::SIZE {
+        let rent = Rent::get()?;
+        let shortfall = rent
+            .minimum_balance(RelayAccount::SIZE)
+            .saturating_sub(relay_account.lamports());
+        if shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, relay_account.key, shortfall),
+                &[payer.clone(), relay_account.clone(), system_program.clone()],
+            )?;
+        }
+        relay_account.resize(RelayAccount::SIZE)?;
+    }
+
+    // Start a fresh window so the new limit applies from now
+    relay_data.max_per_window = max_per_window;
+    relay_data.window_secs = window_secs;
+    relay_data.window_start = 0;
+    relay_data.deliveries_in_window = 0;
+    relay_data.pack(&mut relay_account.data.borrow_mut())?;
+
+    msg!(
+        "Relay limit set: relay={}, max_per_window={}, window_secs={}",
+        relay,
+        max_per_window,
+        window_secs
+    );
+    Ok(())
+}
+
+/// Set the treasury that receives rent from pruned delivered message accounts.
+fn process_set_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    treasury: Pubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    config.treasury = treasury;
+    grow_legacy_config(config_account, payer, system_program)?;
+    config.pack(&mut config_account.data.borrow_mut())?;
+
+    msg!("Treasury set: {}", treasury);
+    Ok(())
+}
+
+/// Pause or unpause Send and DeliverMessage.
+fn process_set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Load and verify config
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    config.paused = paused;
+    grow_legacy_config(config_account, payer, system_program)?;
+    config.pack(&mut config_account.data.borrow_mut())?;
+
+    msg!("Endpoint paused: {}", paused);
+    Ok(())
+}
+
+/// Close an expired delivered message account, refunding rent to the treasury.
+fn process_prune_delivered(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    intent_id: [u8; 32],
+    msg_type: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let delivered_account = next_account_info(account_info_iter)?;
+    let treasury = next_account_info(account_info_iter)?;
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.treasury != *treasury.key {
+        return Err(GmpError::InvalidTreasury.into());
+    }
+
+    // Verify delivered message PDA
+    let (delivered_pda, _) = Pubkey::find_program_address(
+        &[seeds::DELIVERED_SEED, &intent_id, &[msg_type]],
+        program_id,
+    );
+    if delivered_account.key != &delivered_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    if delivered_account.owner != program_id {
+        return Err(GmpError::AccountNotInitialized.into());
+    }
+
+    // Legacy entries carry no delivery time, so their age is unknown
+    if delivered_account.data_len() == DeliveredMessage::LEGACY_SIZE {
+        msg!("Delivered message predates delivered_at and cannot be pruned");
+        return Err(GmpError::DeliveredMessageNotExpired.into());
+    }
+
+    let delivered = DeliveredMessage::try_from_slice(&delivered_account.data.borrow())
+        .map_err(|_| GmpError::InvalidDiscriminator)?;
+    if delivered.discriminator != DeliveredMessage::DISCRIMINATOR {
+        return Err(GmpError::InvalidDiscriminator.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if !delivered.is_prunable(now) {
+        msg!(
+            "Delivered message not expired: delivered_at={}, prunable_at={}",
+            delivered.delivered_at,
+            delivered.delivered_at.saturating_add(DeliveredMessage::RETENTION_SECS)
+        );
+        return Err(GmpError::DeliveredMessageNotExpired.into());
+    }
+
+    // Close the account: rent to the treasury, data cleared, ownership returned
+    let lamports = delivered_account.lamports();
+    **treasury.try_borrow_mut_lamports()? = treasury
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(GmpError::ArithmeticOverflow)?;
+    **delivered_account.try_borrow_mut_lamports()? = 0;
+    delivered_account.resize(0)?;
+    delivered_account.assign(&solana_program::system_program::id());
+
+    msg!(
+        "Delivered message pruned: intent_id={}, msg_type={}, refunded={}",
+        hex_encode(&intent_id),
+        msg_type,
+        lamports
+    );
+    Ok(())
+}
+
+/// Reset the outbound nonce to zero and close the given message accounts.
+///
+/// Only compiled with the `test-only` feature. The nonce is a single global
+/// sequence, so `dst_chain_id` is only logged.
+#[cfg(feature = "test-only")]
+fn process_reset_nonce(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    dst_chain_id: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let nonce_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Load and verify config
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| GmpError::AccountNotInitialized)?;
+
+    if config.admin != *admin.key {
+        return Err(GmpError::UnauthorizedAdmin.into());
+    }
+
+    // Verify nonce PDA
+    let (nonce_pda, _) = Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], program_id);
+    if nonce_account.key != &nonce_pda {
+        return Err(GmpError::InvalidPda.into());
+    }
+
+    // Nothing sent yet: the nonce already starts at zero
+    if !nonce_account.data_is_empty() {
+        let mut nonce_data = OutboundNonceAccount::try_from_slice(&nonce_account.data.borrow())
+            .map_err(|_| GmpError::InvalidDiscriminator)?;
+        nonce_data.nonce = 0;
+        nonce_data.serialize(&mut &mut nonce_account.data.borrow_mut()[..])?;
+    }
+
+    // Close message accounts so Send can recreate them at the reused nonces
+    let mut closed = 0u32;
+    for message_account in account_info_iter {
+        if message_account.owner != program_id {
+            return Err(GmpError::InvalidAccountOwner.into());
+        }
+        let message = MessageAccount::try_from_slice(&message_account.data.borrow())
+            .map_err(|_| GmpError::InvalidDiscriminator)?;
+        if message.discriminator != MessageAccount::DISCRIMINATOR {
+            return Err(GmpError::InvalidDiscriminator.into());
+        }
+        let (message_pda, _) = Pubkey::find_program_address(
+            &[seeds::MESSAGE_SEED, &message.nonce.to_le_bytes()],
+            program_id,
+        );
+        if message_account.key != &message_pda {
+            return Err(GmpError::InvalidPda.into());
+        }
+
+        let lamports = message_account.lamports();
+        **admin.try_borrow_mut_lamports()? = admin
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(GmpError::ArithmeticOverflow)?;
+        **message_account.try_borrow_mut_lamports()? = 0;
+        message_account.resize(0)?;
+        message_account.assign(&solana_program::system_program::id());
+        closed += 1;
+    }
+
+    msg!(
+        "Outbound nonce reset: dst_chain_id={}, closed_messages={}",
+        dst_chain_id,
+        closed
+    );
+    Ok(())
+}
+
 /// Process DeliverMessage instruction - verify relay and route to destination(s).
 ///
 /// Deduplication uses (intent_id, msg_type) extracted from the payload,
@@ -594,9 +1155,25 @@ fn process_deliver_message(
     }
 
     // Load config
-    let _config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
+    if config.paused {
+        msg!("Endpoint is paused, rejecting DeliverMessage");
+        return Err(GmpError::Paused.into());
+    }
+
+    // Payload version handling for known message types: strict rejects newer
+    // versions, lenient drops unknown trailing data. Unknown types pass through.
+    let payload = if peek_message_type(&payload).is_ok() {
+        normalize_payload(&payload, config.payload_version_mode()).map_err(|e| {
+            msg!("{}", e);
+            GmpError::UnsupportedPayloadVersion
+        })?
+    } else {
+        &payload[..]
+    };
+
     // Verify relay is authorized
     let (relay_pda, _) =
         Pubkey::find_program_address(&[seeds::RELAY_SEED, relay_signer.key.as_ref()], program_id);
@@ -605,13 +1182,28 @@ fn process_deliver_message(
         return Err(GmpError::InvalidPda.into());
     }
 
-    let relay_data = RelayAccount::try_from_slice(&relay_account.data.borrow())
+    let mut relay_data = RelayAccount::unpack(&relay_account.data.borrow())
         .map_err(|_| GmpError::AccountNotInitialized)?;
 
     if !relay_data.is_authorized {
         return Err(GmpError::UnauthorizedRelay.into());
     }
 
+    // Per-relay rate limit (max_per_window = 0 means unlimited, no write needed)
+    if relay_data.is_rate_limited() {
+        let now = Clock::get()?.unix_timestamp;
+        if !relay_data.record_delivery(now) {
+            msg!(
+                "Relay {} exceeded {} deliveries per {}s window",
+                relay_signer.key,
+                relay_data.max_per_window,
+                relay_data.window_secs
+            );
+            return Err(GmpError::RateLimitExceeded.into());
+        }
+        relay_data.pack(&mut relay_account.data.borrow_mut())?;
+    }
+
     // Verify remote GMP endpoint
     let chain_id_bytes = src_chain_id.to_le_bytes();
     let (remote_gmp_endpoint_pda, _) = Pubkey::find_program_address(
@@ -623,16 +1215,32 @@ fn process_deliver_message(
         return Err(GmpError::InvalidPda.into());
     }
 
-    let remote_gmp_endpoint = RemoteGmpEndpoint::try_from_slice(&remote_gmp_endpoint_account.data.borrow())
-        .map_err(|_| GmpError::UnknownRemoteGmpEndpoint)?;
-
-    if remote_gmp_endpoint.addr != remote_gmp_endpoint_addr {
+    // A source chain without a remote GMP endpoint is only accepted in permissive mode;
+    // a configured endpoint is always enforced.
+    if remote_gmp_endpoint_account.data_is_empty() {
+        if config.require_trusted_remote {
+            msg!("No trusted remote configured for src_chain_id={}", src_chain_id);
+            return Err(GmpError::NoTrustedRemote.into());
+        }
         msg!(
-            "Unknown remote GMP endpoint: expected={}, got={}",
-            hex_encode(&remote_gmp_endpoint.addr),
-            hex_encode(&remote_gmp_endpoint_addr)
+            "Permissive mode: accepting src_chain_id={} without a trusted remote",
+            src_chain_id
         );
-        return Err(GmpError::UnknownRemoteGmpEndpoint.into());
+    } else {
+        let remote_gmp_endpoint = RemoteGmpEndpoint::unpack(&remote_gmp_endpoint_account.data.borrow())
+            .map_err(|_| GmpError::UnknownRemoteGmpEndpoint)?;
+
+        // The address set for this message type takes precedence over the chain-wide one
+        let trusted_addr = remote_gmp_endpoint.trusted_addr(msg_type);
+        if trusted_addr != remote_gmp_endpoint_addr {
+            msg!(
+                "Unknown remote GMP endpoint: msg_type={}, expected={}, got={}",
+                msg_type,
+                hex_encode(&trusted_addr),
+                hex_encode(&remote_gmp_endpoint_addr)
+            );
+            return Err(GmpError::UnknownRemoteGmpEndpoint.into());
+        }
     }
 
     // Replay protection: deduplicate by (intent_id, msg_type) via DeliveredMessage PDA
@@ -672,7 +1280,7 @@ fn process_deliver_message(
         &[&[seeds::DELIVERED_SEED, intent_id, &[msg_type], &[delivered_bump]]],
     )?;
 
-    let delivered_data = DeliveredMessage::new(delivered_bump);
+    let delivered_data = DeliveredMessage::new(delivered_bump, Clock::get()?.unix_timestamp);
     delivered_data.serialize(&mut &mut delivered_account.data.borrow_mut()[..])?;
 
     // Check message type and determine routing
@@ -701,7 +1309,7 @@ fn process_deliver_message(
     gmp_receive_data.extend_from_slice(&src_chain_id.to_le_bytes());
     gmp_receive_data.extend_from_slice(&remote_gmp_endpoint_addr);
     gmp_receive_data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
-    gmp_receive_data.extend_from_slice(&payload);
+    gmp_receive_data.extend_from_slice(payload);
 
     // Route based on message type and configuration
     match (message_type, &routing_config) {
@@ -746,12 +1354,12 @@ fn process_deliver_message(
             // CPI to outflow_validator (destination_program_1) with its accounts (indices 0-4)
             let outflow_accounts = &remaining_accounts[0..5];
             msg!("Routing to outflow_validator: {} with {} accounts", destination_program_1.key, outflow_accounts.len());
-            invoke_gmp_receive(destination_program_1.key, &gmp_receive_data, outflow_accounts)?;
+            invoke_gmp_receive(program_id, destination_program_1.key, &gmp_receive_data, outflow_accounts)?;
 
             // CPI to intent_escrow (destination_program_2) with its accounts (indices 5-9)
             let escrow_accounts = &remaining_accounts[5..10];
             msg!("Routing to intent_escrow: {} with {} accounts", destination_program_2.key, escrow_accounts.len());
-            invoke_gmp_receive(destination_program_2.key, &gmp_receive_data, escrow_accounts)?;
+            invoke_gmp_receive(program_id, destination_program_2.key, &gmp_receive_data, escrow_accounts)?;
 
             msg!("Multi-destination routing succeeded");
         }
@@ -777,7 +1385,7 @@ fn process_deliver_message(
             );
 
             // Remaining accounts are for intent_escrow's GmpReceiveFulfillmentProof:
-            // requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_caller(s), token_program
+            // requirements(w), escrow(w), vault(w), solver_token(w), gmp_config(r), gmp_signer PDA (signed by this CPI), token_program
             if remaining_accounts.len() < 7 {
                 msg!("Insufficient remaining accounts for FulfillmentProof routing: need 7, got {}", remaining_accounts.len());
                 return Err(GmpError::InvalidAccountCount.into());
@@ -785,7 +1393,7 @@ fn process_deliver_message(
 
             // CPI to intent_escrow (destination_program_2) with all remaining accounts
             msg!("Routing FulfillmentProof to intent_escrow: {} with {} accounts", destination_program_2.key, remaining_accounts.len());
-            invoke_gmp_receive(destination_program_2.key, &gmp_receive_data, &remaining_accounts)?;
+            invoke_gmp_receive(program_id, destination_program_2.key, &gmp_receive_data, &remaining_accounts)?;
 
             msg!("FulfillmentProof routing to intent_escrow succeeded");
         }
@@ -801,7 +1409,7 @@ fn process_deliver_message(
             );
 
             // Pass remaining_accounts directly - destination program is invoked, not passed as account
-            invoke_gmp_receive(destination_program_1.key, &gmp_receive_data, &remaining_accounts)?;
+            invoke_gmp_receive(program_id, destination_program_1.key, &gmp_receive_data, &remaining_accounts)?;
 
             msg!("CPI to destination program succeeded");
         }
@@ -811,18 +1419,25 @@ fn process_deliver_message(
 }
 
 /// Helper to invoke GmpReceive on a destination program.
+/// The endpoint's GMP signer PDA signs the CPI, so destination programs can tell a
+/// delivery through this endpoint from a direct call.
 fn invoke_gmp_receive(
+    gmp_program_id: &Pubkey,
     program_id: &Pubkey,
     gmp_receive_data: &[u8],
     accounts: &[AccountInfo],
 ) -> ProgramResult {
+    let (gmp_signer_pda, gmp_signer_bump) =
+        Pubkey::find_program_address(&[seeds::GMP_SIGNER_SEED], gmp_program_id);
+
     // Build account metas for CPI
     let mut account_metas = Vec::with_capacity(accounts.len());
     for acc in accounts {
+        let is_signer = acc.is_signer || *acc.key == gmp_signer_pda;
         if acc.is_writable {
-            account_metas.push(solana_program::instruction::AccountMeta::new(*acc.key, acc.is_signer));
+            account_metas.push(solana_program::instruction::AccountMeta::new(*acc.key, is_signer));
         } else {
-            account_metas.push(solana_program::instruction::AccountMeta::new_readonly(*acc.key, acc.is_signer));
+            account_metas.push(solana_program::instruction::AccountMeta::new_readonly(*acc.key, is_signer));
         }
     }
 
@@ -833,7 +1448,11 @@ fn invoke_gmp_receive(
     };
 
     // Invoke the destination program
-    solana_program::program::invoke(&cpi_instruction, accounts)
+    invoke_signed(
+        &cpi_instruction,
+        accounts,
+        &[&[seeds::GMP_SIGNER_SEED, &[gmp_signer_bump]]],
+    )
 }
 
 /// Simple hex encoding for logging (no dependencies).
@@ -8,6 +8,7 @@
 //! - On read, verify discriminator matches expected type before trusting data.
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use gmp_common::PayloadVersionMode;
 use solana_program::pubkey::Pubkey;
 
 /// Program configuration account.
@@ -20,22 +21,92 @@ pub struct ConfigAccount {
     pub admin: Pubkey,
     /// This chain's endpoint ID (e.g., Solana devnet = 30168)
     pub chain_id: u32,
+    /// Accept newer payload versions by truncating unknown trailing data (default: strict)
+    pub lenient_payload_versions: bool,
+    /// Reject deliveries from source chains without a configured remote GMP endpoint
+    /// (default: true). When false, such deliveries skip the remote endpoint check.
+    pub require_trusted_remote: bool,
+    /// Receives the rent of pruned DeliveredMessage accounts (default: admin)
+    pub treasury: Pubkey,
+    /// Circuit breaker: while set, Send and DeliverMessage fail with `Paused`
+    pub paused: bool,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
 impl ConfigAccount {
     pub const DISCRIMINATOR: u8 = 1;
-    pub const SIZE: usize = 1 + 32 + 4 + 1; // 38 bytes
+    pub const SIZE: usize = 1 + 32 + 4 + 1 + 1 + 32 + 1 + 1; // 73 bytes
+    /// Size of config accounts created before the endpoint settings were added.
+    /// The settings sit before the bump, so the legacy layout is not a prefix.
+    pub const LEGACY_SIZE: usize = 1 + 32 + 4 + 1; // 38 bytes
 
     pub fn new(admin: Pubkey, chain_id: u32, bump: u8) -> Self {
         Self {
             discriminator: Self::DISCRIMINATOR,
             admin,
             chain_id,
+            lenient_payload_versions: false,
+            require_trusted_remote: true,
+            treasury: admin,
+            paused: false,
             bump,
         }
     }
+
+    /// Decode a config account, reading legacy 38-byte accounts with default settings.
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() == Self::LEGACY_SIZE {
+            let legacy = LegacyConfigAccount::try_from_slice(data)?;
+            return Ok(Self {
+                discriminator: legacy.discriminator,
+                ..Self::new(legacy.admin, legacy.chain_id, legacy.bump)
+            });
+        }
+        Self::try_from_slice(data)
+    }
+
+    /// Encode into account data, keeping the legacy layout for 38-byte accounts.
+    ///
+    /// Legacy accounts can only hold the default settings; the config setters reallocate them first.
+    pub fn pack(&self, data: &mut [u8]) -> std::io::Result<()> {
+        if data.len() == Self::LEGACY_SIZE {
+            let defaults = Self::new(self.admin, self.chain_id, self.bump);
+            if self.lenient_payload_versions != defaults.lenient_payload_versions
+                || self.require_trusted_remote != defaults.require_trusted_remote
+                || self.treasury != defaults.treasury
+                || self.paused != defaults.paused
+            {
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+            let legacy = LegacyConfigAccount {
+                discriminator: self.discriminator,
+                admin: self.admin,
+                chain_id: self.chain_id,
+                bump: self.bump,
+            };
+            return legacy.serialize(&mut &mut data[..]);
+        }
+        self.serialize(&mut &mut data[..])
+    }
+
+    /// Payload version handling applied by DeliverMessage.
+    pub fn payload_version_mode(&self) -> PayloadVersionMode {
+        if self.lenient_payload_versions {
+            PayloadVersionMode::Lenient
+        } else {
+            PayloadVersionMode::Strict
+        }
+    }
+}
+
+/// Config account layout before the endpoint settings were added.
+#[derive(BorshSerialize, BorshDeserialize)]
+struct LegacyConfigAccount {
+    discriminator: u8,
+    admin: Pubkey,
+    chain_id: u32,
+    bump: u8,
 }
 
 /// Authorized relay account.
@@ -50,11 +121,22 @@ pub struct RelayAccount {
     pub is_authorized: bool,
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// Maximum DeliverMessage calls per window (0 = unlimited)
+    pub max_per_window: u32,
+    /// Rate limit window length in seconds
+    pub window_secs: u32,
+    /// Unix timestamp at which the current window started
+    pub window_start: i64,
+    /// Deliveries counted in the current window
+    pub deliveries_in_window: u32,
 }
 
 impl RelayAccount {
     pub const DISCRIMINATOR: u8 = 2;
-    pub const SIZE: usize = 1 + 32 + 1 + 1; // 35 bytes
+    pub const SIZE: usize = 1 + 32 + 1 + 1 + 4 + 4 + 8 + 4; // 55 bytes
+    /// Size of relay accounts created before rate limiting was added.
+    /// The rate limit fields were appended, so the legacy layout is a prefix.
+    pub const LEGACY_SIZE: usize = 1 + 32 + 1 + 1; // 35 bytes
 
     pub fn new(relay: Pubkey, bump: u8) -> Self {
         Self {
@@ -62,12 +144,83 @@ impl RelayAccount {
             relay,
             is_authorized: true,
             bump,
+            max_per_window: 0,
+            window_secs: 0,
+            window_start: 0,
+            deliveries_in_window: 0,
+        }
+    }
+
+    /// Decode a relay account, reading legacy 35-byte accounts as unlimited.
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() == Self::LEGACY_SIZE {
+            let mut padded = [0u8; Self::SIZE];
+            padded[..Self::LEGACY_SIZE].copy_from_slice(data);
+            return Self::try_from_slice(&padded);
+        }
+        Self::try_from_slice(data)
+    }
+
+    /// Encode into account data, keeping the legacy layout for 35-byte accounts.
+    ///
+    /// Legacy accounts cannot hold a rate limit; `SetRelayLimit` reallocates them first.
+    pub fn pack(&self, data: &mut [u8]) -> std::io::Result<()> {
+        let bytes = self.try_to_vec()?;
+        if data.len() == Self::LEGACY_SIZE {
+            if bytes[Self::LEGACY_SIZE..].iter().any(|b| *b != 0) {
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+            data.copy_from_slice(&bytes[..Self::LEGACY_SIZE]);
+            return Ok(());
+        }
+        self.serialize(&mut &mut data[..])
+    }
+
+    /// Whether DeliverMessage is rate limited for this relay.
+    pub fn is_rate_limited(&self) -> bool {
+        self.max_per_window > 0
+    }
+
+    /// Count one delivery at `now`, starting a new window if the current one has ended.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - Delivery is within the limit and has been counted
+    /// * `false` - The limit for the current window is already reached
+    pub fn record_delivery(&mut self, now: i64) -> bool {
+        if !self.is_rate_limited() {
+            return true;
+        }
+        if now >= self.window_start.saturating_add(self.window_secs as i64) {
+            self.window_start = now;
+            self.deliveries_in_window = 0;
+        }
+        if self.deliveries_in_window >= self.max_per_window {
+            return false;
         }
+        self.deliveries_in_window += 1;
+        true
     }
 }
 
+/// Trusted remote address for one message type from a source chain.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MsgTypeRemote {
+    /// GMP message type this address is trusted for
+    pub msg_type: u8,
+    /// Remote address (32 bytes, zero-padded if needed)
+    pub addr: [u8; 32],
+}
+
+impl MsgTypeRemote {
+    pub const SIZE: usize = 1 + 32; // 33 bytes
+}
+
 /// Remote GMP endpoint configuration for a source chain.
 /// PDA seeds: ["remote_gmp_endpoint", src_chain_id (as bytes)]
+///
+/// `addr` is trusted for every message type without an entry in `type_overrides`;
+/// a message type with an entry is only accepted from that entry's address.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct RemoteGmpEndpoint {
     /// Discriminator for account type
@@ -78,11 +231,17 @@ pub struct RemoteGmpEndpoint {
     pub addr: [u8; 32],
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// Per-message-type trusted addresses, taking precedence over `addr`
+    pub type_overrides: Vec<MsgTypeRemote>,
 }
 
 impl RemoteGmpEndpoint {
     pub const DISCRIMINATOR: u8 = 3;
-    pub const SIZE: usize = 1 + 4 + 32 + 1; // 38 bytes
+    /// Size without type overrides
+    pub const SIZE: usize = 1 + 4 + 32 + 1 + 4; // 42 bytes
+    /// Size of remote endpoint accounts created before type overrides were added.
+    /// The override list was appended, so the legacy layout is a prefix.
+    pub const LEGACY_SIZE: usize = 1 + 4 + 32 + 1; // 38 bytes
 
     pub fn new(src_chain_id: u32, addr: [u8; 32], bump: u8) -> Self {
         Self {
@@ -90,6 +249,54 @@ impl RemoteGmpEndpoint {
             src_chain_id,
             addr,
             bump,
+            type_overrides: Vec::new(),
+        }
+    }
+
+    /// Account size needed to store this endpoint with its type overrides.
+    pub fn size(&self) -> usize {
+        Self::SIZE + self.type_overrides.len() * MsgTypeRemote::SIZE
+    }
+
+    /// Decode a remote endpoint account, reading legacy 38-byte accounts as having no overrides.
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() == Self::LEGACY_SIZE {
+            let mut padded = [0u8; Self::SIZE];
+            padded[..Self::LEGACY_SIZE].copy_from_slice(data);
+            return Self::try_from_slice(&padded);
+        }
+        Self::try_from_slice(data)
+    }
+
+    /// Encode into account data, keeping the legacy layout for 38-byte accounts.
+    ///
+    /// Legacy accounts cannot hold overrides; `SetRemoteGmpEndpointAddrForType` reallocates them first.
+    pub fn pack(&self, data: &mut [u8]) -> std::io::Result<()> {
+        let bytes = self.try_to_vec()?;
+        if data.len() == Self::LEGACY_SIZE {
+            if !self.type_overrides.is_empty() {
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+            data.copy_from_slice(&bytes[..Self::LEGACY_SIZE]);
+            return Ok(());
+        }
+        self.serialize(&mut &mut data[..])
+    }
+
+    /// Address trusted for `msg_type`: its override if set, the chain-wide address otherwise.
+    pub fn trusted_addr(&self, msg_type: u8) -> [u8; 32] {
+        self.type_overrides
+            .iter()
+            .find(|entry| entry.msg_type == msg_type)
+            .map(|entry| entry.addr)
+            .unwrap_or(self.addr)
+    }
+
+    /// Set (`Some`) or remove (`None`) the trusted address for `msg_type`.
+    pub fn set_type_override(&mut self, msg_type: u8, addr: Option<[u8; 32]>) {
+        self.type_overrides.retain(|entry| entry.msg_type != msg_type);
+        if let Some(addr) = addr {
+            self.type_overrides.push(MsgTypeRemote { msg_type, addr });
         }
     }
 }
@@ -130,25 +337,39 @@ impl OutboundNonceAccount {
 ///
 /// Replaces nonce-based replay protection — immune to program redeployments.
 /// Each unique (intent_id, msg_type) pair gets its own PDA. If the account
-/// exists, the message has already been delivered.
+/// exists, the message has already been delivered. Once `RETENTION_SECS` have
+/// passed since delivery, anyone may close it with `PruneDelivered`.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct DeliveredMessage {
     /// Discriminator for account type
     pub discriminator: u8,
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// Unix timestamp of delivery
+    pub delivered_at: i64,
 }
 
 impl DeliveredMessage {
     pub const DISCRIMINATOR: u8 = 5;
-    pub const SIZE: usize = 1 + 1; // 2 bytes
+    pub const SIZE: usize = 1 + 1 + 8; // 10 bytes
+    /// Size of delivered message accounts created before `delivered_at` was added.
+    /// These have no delivery time and are never pruned.
+    pub const LEGACY_SIZE: usize = 1 + 1; // 2 bytes
+    /// How long a dedup entry is kept before it may be pruned (30 days)
+    pub const RETENTION_SECS: i64 = 30 * 24 * 60 * 60;
 
-    pub fn new(bump: u8) -> Self {
+    pub fn new(bump: u8, delivered_at: i64) -> Self {
         Self {
             discriminator: Self::DISCRIMINATOR,
             bump,
+            delivered_at,
         }
     }
+
+    /// Whether the retention period has elapsed at `now`.
+    pub fn is_prunable(&self, now: i64) -> bool {
+        now >= self.delivered_at.saturating_add(Self::RETENTION_SECS)
+    }
 }
 
 /// Routing configuration for message delivery.
@@ -260,4 +481,6 @@ pub mod seeds {
     pub const DELIVERED_SEED: &[u8] = b"delivered";
     pub const ROUTING_SEED: &[u8] = b"routing";
     pub const MESSAGE_SEED: &[u8] = b"message";
+    /// Signs GmpReceive CPIs to destination programs (holds no data)
+    pub const GMP_SIGNER_SEED: &[u8] = b"gmp_signer";
 }
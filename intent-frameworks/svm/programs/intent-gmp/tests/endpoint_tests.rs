@@ -7,7 +7,7 @@ use borsh::BorshDeserialize;
 use intent_gmp::{
     instruction::NativeGmpInstruction,
     state::{
-        ConfigAccount, DeliveredMessage, OutboundNonceAccount, RelayAccount,
+        ConfigAccount, DeliveredMessage, MsgTypeRemote, OutboundNonceAccount, RelayAccount,
         RoutingConfig, RemoteGmpEndpoint,
     },
     GmpError,
@@ -256,6 +256,9 @@ fn test_config_account_serialization() {
     assert_eq!(decoded.discriminator, ConfigAccount::DISCRIMINATOR);
     assert_eq!(decoded.admin, original_admin);
     assert_eq!(decoded.chain_id, original_chain_id);
+    assert!(decoded.require_trusted_remote, "New endpoints must default to strict trusted remote mode");
+    assert_eq!(decoded.treasury, original_admin, "Treasury defaults to the admin");
+    assert!(!decoded.paused, "New endpoints must start unpaused");
     assert_eq!(decoded.bump, original_bump);
 }
 
@@ -299,6 +302,113 @@ fn test_remote_gmp_endpoint_account_serialization() {
     assert_eq!(decoded.bump, original_bump);
 }
 
+/// 58. Test: RelayAccount legacy layout and delivery window accounting
+/// Verifies that 35-byte relay accounts decode as unlimited and re-encode in place, and that
+/// record_delivery counts up to the limit and resets when the window ends.
+/// Why: Relays registered before rate limiting must keep working, and the window logic decides
+/// whether a delivery is rejected.
+#[test]
+fn test_relay_account_legacy_layout_and_window() {
+    let relay = Pubkey::new_from_array([0x78; 32]);
+    let mut account = RelayAccount::new(relay, 253);
+
+    // Legacy accounts are the first 35 bytes of the current layout
+    let encoded = borsh::to_vec(&account).unwrap();
+    assert_eq!(encoded.len(), RelayAccount::SIZE);
+    let mut legacy = encoded[..RelayAccount::LEGACY_SIZE].to_vec();
+    let decoded = RelayAccount::unpack(&legacy).unwrap();
+    assert_eq!(decoded, account);
+    assert!(!decoded.is_rate_limited());
+    decoded.pack(&mut legacy).unwrap();
+    assert_eq!(legacy, encoded[..RelayAccount::LEGACY_SIZE]);
+
+    // A legacy account cannot hold a limit
+    account.max_per_window = 2;
+    account.window_secs = 60;
+    assert!(account.pack(&mut legacy).is_err());
+
+    // Two deliveries fit in the window, the third does not, a new window resets the count
+    assert!(account.record_delivery(1_000));
+    assert!(account.record_delivery(1_059));
+    assert!(!account.record_delivery(1_059));
+    assert!(account.record_delivery(1_060));
+    assert_eq!(account.window_start, 1_060);
+    assert_eq!(account.deliveries_in_window, 1);
+}
+
+/// 63. Test: RemoteGmpEndpoint legacy layout and per-message-type matching
+/// Verifies that 38-byte remote endpoint accounts decode without overrides and re-encode in place,
+/// and that trusted_addr prefers a message type's override over the chain-wide address.
+/// Why: Endpoints configured before type overrides must keep working, and the most specific
+/// address decides which sender DeliverMessage accepts.
+#[test]
+fn test_remote_gmp_endpoint_legacy_layout_and_type_overrides() {
+    let chain_wide = dummy_remote_addr();
+    let mut endpoint = RemoteGmpEndpoint::new(DUMMY_CHAIN_ID_MVM, chain_wide, 253);
+
+    // Legacy accounts are the first 38 bytes of the current layout
+    let encoded = borsh::to_vec(&endpoint).unwrap();
+    assert_eq!(encoded.len(), RemoteGmpEndpoint::SIZE);
+    let mut legacy = encoded[..RemoteGmpEndpoint::LEGACY_SIZE].to_vec();
+    let decoded = RemoteGmpEndpoint::unpack(&legacy).unwrap();
+    assert_eq!(decoded, endpoint);
+    decoded.pack(&mut legacy).unwrap();
+    assert_eq!(legacy, encoded[..RemoteGmpEndpoint::LEGACY_SIZE]);
+
+    // An override applies to its message type only
+    let proof_sender = [0x42; 32];
+    endpoint.set_type_override(0x03, Some(proof_sender));
+    assert_eq!(endpoint.trusted_addr(0x03), proof_sender);
+    assert_eq!(endpoint.trusted_addr(0x02), chain_wide);
+    assert_eq!(endpoint.size(), RemoteGmpEndpoint::SIZE + MsgTypeRemote::SIZE);
+
+    // A legacy account cannot hold an override
+    assert!(endpoint.pack(&mut legacy).is_err());
+
+    // Setting again replaces the entry; removing it falls back to the chain-wide address
+    endpoint.set_type_override(0x03, Some([0x43; 32]));
+    assert_eq!(endpoint.type_overrides.len(), 1);
+    assert_eq!(endpoint.trusted_addr(0x03), [0x43; 32]);
+    endpoint.set_type_override(0x03, None);
+    assert_eq!(endpoint.trusted_addr(0x03), chain_wide);
+
+    let mut data = vec![0u8; endpoint.size()];
+    endpoint.pack(&mut data).unwrap();
+    assert_eq!(RemoteGmpEndpoint::unpack(&data).unwrap(), endpoint);
+}
+
+/// 68. Test: ConfigAccount legacy layout
+/// Verifies that 38-byte config accounts decode with the default endpoint settings and re-encode in place,
+/// and that a legacy account cannot hold non-default settings.
+/// Why: Endpoints initialized before the settings were added must keep authorizing the admin and delivering.
+#[test]
+fn test_config_account_legacy_layout() {
+    let admin = Pubkey::new_from_array([0x66; 32]);
+    let config = ConfigAccount::new(admin, DUMMY_CHAIN_ID_SVM, 254);
+
+    // Legacy layout: discriminator, admin, chain_id, bump
+    let mut legacy = vec![ConfigAccount::DISCRIMINATOR];
+    legacy.extend_from_slice(admin.as_ref());
+    legacy.extend_from_slice(&DUMMY_CHAIN_ID_SVM.to_le_bytes());
+    legacy.push(254);
+    assert_eq!(legacy.len(), ConfigAccount::LEGACY_SIZE);
+
+    let decoded = ConfigAccount::unpack(&legacy).unwrap();
+    assert_eq!(decoded, config);
+    let original = legacy.clone();
+    decoded.pack(&mut legacy).unwrap();
+    assert_eq!(legacy, original);
+
+    // A legacy account cannot hold a non-default setting
+    let mut paused = config.clone();
+    paused.paused = true;
+    assert!(paused.pack(&mut legacy).is_err());
+
+    let mut data = vec![0u8; ConfigAccount::SIZE];
+    paused.pack(&mut data).unwrap();
+    assert_eq!(ConfigAccount::unpack(&data).unwrap(), paused);
+}
+
 // ============================================================================
 // NONCE TRACKING TESTS
 // ============================================================================
@@ -328,7 +438,7 @@ fn test_outbound_nonce_account() {
 fn test_delivered_message_serialization() {
     let original_bump = 251u8;
 
-    let delivered = DeliveredMessage::new(original_bump);
+    let delivered = DeliveredMessage::new(original_bump, 1_700_000_000);
 
     let encoded = borsh::to_vec(&delivered).unwrap();
     assert_eq!(encoded.len(), DeliveredMessage::SIZE, "Serialized size should match SIZE constant");
@@ -337,6 +447,9 @@ fn test_delivered_message_serialization() {
 
     assert_eq!(decoded.discriminator, DeliveredMessage::DISCRIMINATOR);
     assert_eq!(decoded.bump, original_bump);
+    assert_eq!(decoded.delivered_at, 1_700_000_000);
+    assert!(!decoded.is_prunable(1_700_000_000 + DeliveredMessage::RETENTION_SECS - 1));
+    assert!(decoded.is_prunable(1_700_000_000 + DeliveredMessage::RETENTION_SECS));
 }
 
 // ============================================================================
@@ -374,6 +487,12 @@ fn test_error_codes_unique() {
         GmpError::UnknownRemoteGmpEndpoint,
         GmpError::AlreadyDelivered,
         GmpError::InvalidDiscriminator,
+        GmpError::UnsupportedPayloadVersion,
+        GmpError::NoTrustedRemote,
+        GmpError::RateLimitExceeded,
+        GmpError::DeliveredMessageNotExpired,
+        GmpError::InvalidTreasury,
+        GmpError::Paused,
     ];
 
     let codes: Vec<u32> = errors.iter().map(|e| e.clone() as u32).collect();
@@ -414,7 +533,7 @@ mod integration {
     use borsh::{BorshDeserialize, BorshSerialize};
     use intent_gmp::{
         instruction::NativeGmpInstruction,
-        state::{seeds, DeliveredMessage, MessageAccount, OutboundNonceAccount},
+        state::{seeds, ConfigAccount, DeliveredMessage, MessageAccount, OutboundNonceAccount, RemoteGmpEndpoint},
     };
     use solana_program::instruction::{AccountMeta, Instruction};
     use solana_program_test::{processor, ProgramTest, ProgramTestContext};
@@ -651,7 +770,7 @@ mod integration {
             program_id,
             accounts: vec![
                 AccountMeta::new_readonly(config_pda, false),
-                AccountMeta::new_readonly(relay_pda, false),
+                AccountMeta::new(relay_pda, false), // writable for rate-limit counters
                 AccountMeta::new_readonly(remote_gmp_endpoint_pda, false),
                 AccountMeta::new(delivered_pda, false),
                 AccountMeta::new_readonly(relay, true),
@@ -717,7 +836,7 @@ mod integration {
         let (routing_pda, _) = Pubkey::find_program_address(&[seeds::ROUTING_SEED], &program_id);
         let mut accounts = vec![
             AccountMeta::new_readonly(config_pda, false),
-            AccountMeta::new_readonly(relay_pda, false),
+            AccountMeta::new(relay_pda, false),
             AccountMeta::new_readonly(remote_gmp_endpoint_pda, false),
             AccountMeta::new(delivered_pda, false),
             AccountMeta::new_readonly(relay, true),
@@ -1426,4 +1545,913 @@ mod integration {
     // #48: test_set_escrow_handler — N/A for SVM (EVM-specific handler configuration)
     // #49: test_set_outflow_handler — N/A for SVM (EVM-specific handler configuration)
     // #50: test_route_to_both_handlers — N/A for SVM (EVM-specific dual handler routing)
+
+    // ========================================================================
+    // TEST-ONLY NONCE RESET TESTS
+    // ========================================================================
+
+    /// Helper: create ResetNonce instruction closing the given message accounts
+    #[cfg(feature = "test-only")]
+    fn create_reset_nonce_ix(program_id: Pubkey, admin: Pubkey, dst_chain_id: u32, message_nonces: &[u64]) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let (nonce_pda, _) = Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], &program_id);
+        let mut accounts = vec![
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(nonce_pda, false),
+            AccountMeta::new(admin, true),
+        ];
+        for nonce in message_nonces {
+            let (message_pda, _) = Pubkey::find_program_address(&[seeds::MESSAGE_SEED, &nonce.to_le_bytes()], &program_id);
+            accounts.push(AccountMeta::new(message_pda, false));
+        }
+        Instruction {
+            program_id,
+            accounts,
+            data: NativeGmpInstruction::ResetNonce { dst_chain_id }.try_to_vec().unwrap(),
+        }
+    }
+
+    /// 51. Test: ResetNonce restarts the outbound sequence
+    /// Verifies that ResetNonce sets the nonce back to 0 and closes message accounts so Send can reuse nonce 0.
+    /// Why: Test suites rerun against the same validator and rely on deterministic message PDAs.
+    #[cfg(feature = "test-only")]
+    #[tokio::test]
+    async fn test_reset_nonce_restarts_sequence() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+        // Send two messages (nonces 0 and 1)
+        let dst_addr = [0xab; 32];
+        let remote_gmp_endpoint_addr = program_id.to_bytes();
+        let send_ix0 = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, dst_addr, remote_gmp_endpoint_addr, vec![0x01], 0);
+        let send_ix1 = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, dst_addr, remote_gmp_endpoint_addr, vec![0x02], 1);
+        send_tx(&mut context, &admin, &[send_ix0, send_ix1], &[]).await.unwrap();
+
+        let (nonce_pda, _) = Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], &program_id);
+        let nonce_account: OutboundNonceAccount = read_account(&mut context, nonce_pda).await;
+        assert_eq!(nonce_account.nonce, 2);
+
+        // Reset and close both message accounts
+        let reset_ix = create_reset_nonce_ix(program_id, admin.pubkey(), CHAIN_ID_MVM, &[0, 1]);
+        send_tx(&mut context, &admin, &[reset_ix], &[]).await.unwrap();
+
+        let nonce_account: OutboundNonceAccount = read_account(&mut context, nonce_pda).await;
+        assert_eq!(nonce_account.nonce, 0, "Nonce should be 0 after reset");
+        let (message_pda, _) = Pubkey::find_program_address(&[seeds::MESSAGE_SEED, &0u64.to_le_bytes()], &program_id);
+        assert!(
+            context.banks_client.get_account(message_pda).await.unwrap().is_none(),
+            "Message account for nonce 0 should be closed"
+        );
+
+        // Sending again reuses nonce 0
+        context.warp_to_slot(100).unwrap();
+        let payload = vec![0x03];
+        let send_ix = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, dst_addr, remote_gmp_endpoint_addr, payload.clone(), 0);
+        send_tx(&mut context, &admin, &[send_ix], &[]).await.unwrap();
+
+        let message: MessageAccount = read_account(&mut context, message_pda).await;
+        assert_eq!(message.nonce, 0);
+        assert_eq!(message.payload, payload);
+        let nonce_account: OutboundNonceAccount = read_account(&mut context, nonce_pda).await;
+        assert_eq!(nonce_account.nonce, 1);
+    }
+
+    /// 52. Test: Non-admin cannot reset the nonce
+    /// Verifies that ResetNonce is restricted to the endpoint admin.
+    /// Why: Even in test builds, resetting the nonce must follow the same admin model as other config changes.
+    #[cfg(feature = "test-only")]
+    #[tokio::test]
+    async fn test_reset_nonce_rejects_non_admin() {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let non_admin = Keypair::new();
+        let program_id = gmp_program_id();
+
+        // Fund non-admin
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &non_admin.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+        let reset_ix = create_reset_nonce_ix(program_id, non_admin.pubkey(), CHAIN_ID_MVM, &[]);
+        let result = send_tx(&mut context, &non_admin, &[reset_ix], &[]).await;
+        assert!(result.is_err(), "Non-admin should not be able to reset nonce");
+    }
+
+    /// 53. Test: ResetNonce is absent without the test-only feature
+    /// Verifies that the ResetNonce discriminator is rejected as invalid instruction data in default builds.
+    /// Why: The reset must be impossible to include in a production build of the endpoint.
+    #[cfg(not(feature = "test-only"))]
+    #[tokio::test]
+    async fn test_reset_nonce_absent_without_feature() {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let init_ix = create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM);
+        send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+        // ResetNonce would be the last variant, after SetPayloadVersionMode (index 8)
+        let mut data = vec![8u8];
+        data.extend_from_slice(&CHAIN_ID_MVM.to_le_bytes());
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let (nonce_pda, _) = Pubkey::find_program_address(&[seeds::NONCE_OUT_SEED], &program_id);
+        let reset_ix = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(config_pda, false),
+                AccountMeta::new(nonce_pda, false),
+                AccountMeta::new(admin.pubkey(), true),
+            ],
+            data,
+        };
+
+        let err = send_tx(&mut context, &admin, &[reset_ix], &[]).await.unwrap_err().unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::InvalidInstructionData as u32)
+            )
+        );
+    }
+
+    // ========================================================================
+    // PAYLOAD VERSION MODE TESTS
+    // ========================================================================
+
+    /// Helper: create SetPayloadVersionMode instruction
+    fn create_set_payload_version_mode_ix(program_id: Pubkey, admin: Pubkey, lenient: bool) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(admin, true), // payer
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: NativeGmpInstruction::SetPayloadVersionMode { lenient }.try_to_vec().unwrap(),
+        }
+    }
+
+    /// Helper: deliver a FulfillmentProof carrying 16 bytes of unknown trailing
+    /// data (a newer payload version) with the given payload version mode.
+    async fn deliver_newer_fulfillment_proof(
+        lenient: bool,
+    ) -> (ProgramTestContext, Result<(), solana_program_test::BanksClientError>) {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        // Fund relay
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        // Initialize, add relay, set remote GMP endpoint, routing, and payload version mode
+        let remote_gmp_endpoint_addr = [0x99; 32];
+        let setup_ixs = [
+            create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM),
+            create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()),
+            create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, remote_gmp_endpoint_addr),
+            create_set_routing_ix(program_id, admin.pubkey(), admin.pubkey(), mock_receiver_id(), mock_escrow_receiver_id()),
+            create_set_payload_version_mode_ix(program_id, admin.pubkey(), lenient),
+        ];
+        send_tx(&mut context, &admin, &setup_ixs, &[]).await.unwrap();
+
+        // FulfillmentProof (81 bytes) followed by unknown trailing fields
+        let mut payload = vec![0x03];
+        payload.extend_from_slice(&[0xAA; 32]); // intent_id
+        payload.extend_from_slice(&[0xBB; 32]); // solver_addr
+        payload.extend_from_slice(&0u64.to_be_bytes()); // amount
+        payload.extend_from_slice(&0u64.to_be_bytes()); // timestamp
+        payload.extend_from_slice(&[0xEE; 16]); // newer-version fields
+
+        let remaining_accounts = vec![
+            AccountMeta::new(Pubkey::new_from_array([0xD1; 32]), false),
+            AccountMeta::new(Pubkey::new_from_array([0xD2; 32]), false),
+            AccountMeta::new(Pubkey::new_from_array([0xD3; 32]), false),
+            AccountMeta::new(Pubkey::new_from_array([0xD4; 32]), false),
+            AccountMeta::new_readonly(Pubkey::new_from_array([0xD5; 32]), false),
+            AccountMeta::new_readonly(relay.pubkey(), true),
+            AccountMeta::new_readonly(Pubkey::new_from_array([0xD6; 32]), false),
+        ];
+        let deliver_ix = create_deliver_message_with_routing_ix(
+            program_id,
+            relay.pubkey(),
+            relay.pubkey(),
+            mock_receiver_id(),
+            mock_escrow_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+            remaining_accounts,
+        );
+
+        let result = send_tx(&mut context, &relay, &[deliver_ix], &[]).await;
+        (context, result)
+    }
+
+    /// 54. Test: Strict mode rejects a newer payload version
+    /// Verifies that DeliverMessage fails with UnsupportedPayloadVersion when a known message type carries unknown trailing data.
+    /// Why: Receivers that do not understand a newer version must fail with an explicit error, not an opaque CPI failure.
+    #[tokio::test]
+    async fn test_deliver_message_strict_rejects_newer_payload_version() {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+        let (_context, result) = deliver_newer_fulfillment_proof(false).await;
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::UnsupportedPayloadVersion as u32)
+            )
+        );
+    }
+
+    /// 55. Test: Lenient mode delivers the known fields of a newer payload version
+    /// Verifies that DeliverMessage drops unknown trailing data and delivers the message when lenient mode is set.
+    /// Why: Rolling upgrades ship the sender first; lenient receivers must keep processing known fields meanwhile.
+    #[tokio::test]
+    async fn test_deliver_message_lenient_accepts_newer_payload_version() {
+        let (mut context, result) = deliver_newer_fulfillment_proof(true).await;
+        result.expect("Lenient mode should deliver a newer payload version");
+
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &[0xAA; 32], &[0x03]],
+            &gmp_program_id(),
+        );
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+        assert_eq!(delivered.discriminator, DeliveredMessage::DISCRIMINATOR);
+    }
+
+    // ========================================================================
+    // TRUSTED REMOTE MODE TESTS
+    // ========================================================================
+
+    /// Helper: create SetTrustedRemoteMode instruction
+    fn create_set_trusted_remote_mode_ix(program_id: Pubkey, admin: Pubkey, require_trusted_remote: bool) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(admin, true), // payer
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: NativeGmpInstruction::SetTrustedRemoteMode { require_trusted_remote }.try_to_vec().unwrap(),
+        }
+    }
+
+    /// Helper: deliver a message from a source chain that has no remote GMP
+    /// endpoint configured, with the given trusted remote mode.
+    async fn deliver_from_unconfigured_source(
+        require_trusted_remote: bool,
+    ) -> (ProgramTestContext, Result<(), solana_program_test::BanksClientError>) {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        // Fund relay
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        // Initialize, add relay, and set the trusted remote mode; no remote GMP endpoint for CHAIN_ID_MVM
+        let setup_ixs = [
+            create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM),
+            create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()),
+            create_set_trusted_remote_mode_ix(program_id, admin.pubkey(), require_trusted_remote),
+        ];
+        send_tx(&mut context, &admin, &setup_ixs, &[]).await.unwrap();
+
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0xC1; 32]); // intent_id
+        payload.extend_from_slice(&[0x02, 0x03]); // extra data
+        let deliver_ix = create_deliver_message_ix(
+            program_id,
+            relay.pubkey(),
+            relay.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            [0x77; 32],
+            payload,
+        );
+
+        let result = send_tx(&mut context, &relay, &[deliver_ix], &[]).await;
+        (context, result)
+    }
+
+    /// 56. Test: Strict mode rejects delivery from a source chain without a trusted remote
+    /// Verifies that DeliverMessage fails with NoTrustedRemote when SetRemoteGmpEndpointAddr was never called for the source chain.
+    /// Why: Strict is the secure default; an unconfigured source must fail with an explicit error so operators know what to configure.
+    #[tokio::test]
+    async fn test_deliver_message_strict_rejects_unconfigured_source() {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+        let (_context, result) = deliver_from_unconfigured_source(true).await;
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::NoTrustedRemote as u32)
+            )
+        );
+    }
+
+    /// 57. Test: Permissive mode delivers from a source chain without a trusted remote
+    /// Verifies that DeliverMessage skips the remote endpoint check and delivers when the mode is permissive.
+    /// Why: Early testing brings up chains before remote endpoints are wired; permissive mode must let those messages through.
+    #[tokio::test]
+    async fn test_deliver_message_permissive_accepts_unconfigured_source() {
+        let (mut context, result) = deliver_from_unconfigured_source(false).await;
+        result.expect("Permissive mode should deliver from an unconfigured source");
+
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &[0xC1; 32], &[0x01]],
+            &gmp_program_id(),
+        );
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+        assert_eq!(delivered.discriminator, DeliveredMessage::DISCRIMINATOR);
+    }
+
+    // ========================================================================
+    // RELAY RATE LIMIT TESTS
+    // ========================================================================
+
+    /// Helper: create SetRelayLimit instruction
+    fn create_set_relay_limit_ix(
+        program_id: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        relay: Pubkey,
+        max_per_window: u32,
+        window_secs: u32,
+    ) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let (relay_pda, _) = Pubkey::find_program_address(&[seeds::RELAY_SEED, relay.as_ref()], &program_id);
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(config_pda, false),
+                AccountMeta::new(relay_pda, false),
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: NativeGmpInstruction::SetRelayLimit { relay, max_per_window, window_secs }
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+
+    /// Helper: deliver an IntentRequirements message with the given intent_id byte
+    async fn deliver_with_intent_byte(
+        context: &mut ProgramTestContext,
+        relay: &Keypair,
+        remote_gmp_endpoint_addr: [u8; 32],
+        intent_byte: u8,
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[intent_byte; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(
+            gmp_program_id(),
+            relay.pubkey(),
+            relay.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+        );
+        send_tx(context, relay, &[deliver_ix], &[]).await
+    }
+
+    /// 59. Test: Relay rate limit rejects deliveries beyond the window limit
+    /// Verifies that after SetRelayLimit(2, 3600) a relay delivers two messages, the third fails with RateLimitExceeded, and delivery resumes once the window has passed.
+    /// Why: A compromised or buggy relay must not be able to flood destination programs faster than the configured bound.
+    #[tokio::test]
+    async fn test_deliver_message_relay_rate_limit() {
+        use intent_gmp::state::RelayAccount;
+        use solana_sdk::{clock::Clock, instruction::InstructionError, transaction::TransactionError};
+
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        // Fund relay
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        // Initialize, add relay, set remote GMP endpoint, and limit the relay to 2 per hour
+        let remote_gmp_endpoint_addr = [0x5A; 32];
+        let setup_ixs = [
+            create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM),
+            create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()),
+            create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, remote_gmp_endpoint_addr),
+            create_set_relay_limit_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey(), 2, 3600),
+        ];
+        send_tx(&mut context, &admin, &setup_ixs, &[]).await.unwrap();
+
+        deliver_with_intent_byte(&mut context, &relay, remote_gmp_endpoint_addr, 0xA1).await.unwrap();
+        deliver_with_intent_byte(&mut context, &relay, remote_gmp_endpoint_addr, 0xA2).await.unwrap();
+
+        let err = deliver_with_intent_byte(&mut context, &relay, remote_gmp_endpoint_addr, 0xA3)
+            .await
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::RateLimitExceeded as u32)
+            )
+        );
+
+        let (relay_pda, _) = Pubkey::find_program_address(&[seeds::RELAY_SEED, relay.pubkey().as_ref()], &program_id);
+        let relay_data: RelayAccount = read_account(&mut context, relay_pda).await;
+        assert_eq!(relay_data.deliveries_in_window, 2);
+
+        // Move past the window; the counter starts over
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = relay_data.window_start + 3600;
+        context.set_sysvar(&clock);
+
+        deliver_with_intent_byte(&mut context, &relay, remote_gmp_endpoint_addr, 0xA4).await.unwrap();
+        let relay_data: RelayAccount = read_account(&mut context, relay_pda).await;
+        assert_eq!(relay_data.deliveries_in_window, 1);
+    }
+
+    /// 60. Test: Non-admin cannot set a relay limit
+    /// Verifies that SetRelayLimit fails with UnauthorizedAdmin when signed by a non-admin.
+    /// Why: A relay that could lift its own limit, or an attacker that could zero-limit every relay, would defeat the control.
+    #[tokio::test]
+    async fn test_set_relay_limit_rejects_non_admin() {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        // Fund relay
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        let setup_ixs = [
+            create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM),
+            create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()),
+        ];
+        send_tx(&mut context, &admin, &setup_ixs, &[]).await.unwrap();
+
+        // The relay tries to configure its own limit
+        let set_limit_ix = create_set_relay_limit_ix(program_id, relay.pubkey(), relay.pubkey(), relay.pubkey(), 0, 0);
+        let err = send_tx(&mut context, &relay, &[set_limit_ix], &[]).await.unwrap_err().unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::UnauthorizedAdmin as u32)
+            )
+        );
+    }
+
+    // ========================================================================
+    // DELIVERED MESSAGE PRUNING TESTS
+    // ========================================================================
+
+    /// Helper: create SetTreasury instruction
+    fn create_set_treasury_ix(program_id: Pubkey, admin: Pubkey, treasury: Pubkey) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(admin, true), // payer
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: NativeGmpInstruction::SetTreasury { treasury }.try_to_vec().unwrap(),
+        }
+    }
+
+    /// Helper: create PruneDelivered instruction
+    fn create_prune_delivered_ix(program_id: Pubkey, treasury: Pubkey, intent_id: [u8; 32], msg_type: u8) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &intent_id, &[msg_type]],
+            &program_id,
+        );
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(config_pda, false),
+                AccountMeta::new(delivered_pda, false),
+                AccountMeta::new(treasury, false),
+            ],
+            data: NativeGmpInstruction::PruneDelivered { intent_id, msg_type }.try_to_vec().unwrap(),
+        }
+    }
+
+    /// Helper: initialize the endpoint with a separate treasury and deliver one
+    /// IntentRequirements message for intent_id [0xE1; 32]. Returns (context, treasury, delivered_pda).
+    async fn setup_delivered_message() -> (ProgramTestContext, Pubkey, Pubkey) {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let treasury = Pubkey::new_unique();
+        let program_id = gmp_program_id();
+
+        // Fund relay
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        let remote_gmp_endpoint_addr = [0x6B; 32];
+        let setup_ixs = [
+            create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM),
+            create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()),
+            create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, remote_gmp_endpoint_addr),
+            create_set_treasury_ix(program_id, admin.pubkey(), treasury),
+        ];
+        send_tx(&mut context, &admin, &setup_ixs, &[]).await.unwrap();
+
+        let mut payload = vec![0x01]; // msg_type
+        payload.extend_from_slice(&[0xE1; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(
+            program_id,
+            relay.pubkey(),
+            relay.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+        );
+        send_tx(&mut context, &relay, &[deliver_ix], &[]).await.unwrap();
+
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &[0xE1; 32], &[0x01]],
+            &program_id,
+        );
+        (context, treasury, delivered_pda)
+    }
+
+    /// 61. Test: PruneDelivered rejects a dedup entry within its retention period
+    /// Verifies that pruning fails with DeliveredMessageNotExpired one second before the retention period ends and the account is kept.
+    /// Why: Closing the dedup PDA early would let the same message be delivered again.
+    #[tokio::test]
+    async fn test_prune_delivered_rejects_fresh_entry() {
+        use solana_sdk::{clock::Clock, instruction::InstructionError, transaction::TransactionError};
+
+        let (mut context, treasury, delivered_pda) = setup_delivered_message().await;
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = delivered.delivered_at + DeliveredMessage::RETENTION_SECS - 1;
+        context.set_sysvar(&clock);
+
+        let payer = context.payer.insecure_clone();
+        let prune_ix = create_prune_delivered_ix(gmp_program_id(), treasury, [0xE1; 32], 0x01);
+        let err = send_tx(&mut context, &payer, &[prune_ix], &[]).await.unwrap_err().unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::DeliveredMessageNotExpired as u32)
+            )
+        );
+        assert!(context.banks_client.get_account(delivered_pda).await.unwrap().is_some());
+    }
+
+    /// 62. Test: PruneDelivered closes an expired dedup entry and refunds the treasury
+    /// Verifies that after the retention period anyone can prune the entry, its rent goes to the configured treasury, and a different treasury is rejected.
+    /// Why: Dedup accounts otherwise hold rent forever; the refund must not go to a caller-chosen account.
+    #[tokio::test]
+    async fn test_prune_delivered_refunds_treasury() {
+        use solana_sdk::{clock::Clock, instruction::InstructionError, transaction::TransactionError};
+
+        let (mut context, treasury, delivered_pda) = setup_delivered_message().await;
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+        let rent = context.banks_client.get_account(delivered_pda).await.unwrap().unwrap().lamports;
+
+        let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = delivered.delivered_at + DeliveredMessage::RETENTION_SECS;
+        context.set_sysvar(&clock);
+
+        // A keeper with no role prunes the entry
+        let keeper = Keypair::new();
+        let admin = context.payer.insecure_clone();
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &keeper.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &admin, &[fund_ix], &[]).await.unwrap();
+
+        let wrong_treasury_ix = create_prune_delivered_ix(gmp_program_id(), keeper.pubkey(), [0xE1; 32], 0x01);
+        let err = send_tx(&mut context, &keeper, &[wrong_treasury_ix], &[]).await.unwrap_err().unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::InvalidTreasury as u32)
+            )
+        );
+
+        let prune_ix = create_prune_delivered_ix(gmp_program_id(), treasury, [0xE1; 32], 0x01);
+        send_tx(&mut context, &keeper, &[prune_ix], &[]).await.unwrap();
+
+        assert!(context.banks_client.get_account(delivered_pda).await.unwrap().is_none());
+        assert_eq!(context.banks_client.get_balance(treasury).await.unwrap(), rent);
+    }
+
+    // ========================================================================
+    // PER-MESSAGE-TYPE TRUSTED REMOTE TESTS
+    // ========================================================================
+
+    /// Helper: create SetRemoteGmpEndpointAddrForType instruction
+    fn create_set_remote_gmp_endpoint_addr_for_type_ix(
+        program_id: Pubkey,
+        admin: Pubkey,
+        payer: Pubkey,
+        src_chain_id: u32,
+        msg_type: u8,
+        addr: Option<[u8; 32]>,
+    ) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let (remote_gmp_endpoint_pda, _) = Pubkey::find_program_address(
+            &[seeds::REMOTE_GMP_ENDPOINT_SEED, &src_chain_id.to_le_bytes()],
+            &program_id,
+        );
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(config_pda, false),
+                AccountMeta::new(remote_gmp_endpoint_pda, false),
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: NativeGmpInstruction::SetRemoteGmpEndpointAddrForType { src_chain_id, msg_type, addr }
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+
+    /// Helper: deliver a message of `msg_type` from CHAIN_ID_MVM claiming `remote_gmp_endpoint_addr`
+    async fn deliver_typed_message(
+        context: &mut ProgramTestContext,
+        relay: &Keypair,
+        msg_type: u8,
+        intent_byte: u8,
+        remote_gmp_endpoint_addr: [u8; 32],
+    ) -> Result<(), solana_program_test::BanksClientError> {
+        let mut payload = vec![msg_type];
+        payload.extend_from_slice(&[intent_byte; 32]); // intent_id
+        let deliver_ix = create_deliver_message_ix(
+            gmp_program_id(),
+            relay.pubkey(),
+            relay.pubkey(),
+            mock_receiver_id(),
+            CHAIN_ID_MVM,
+            remote_gmp_endpoint_addr,
+            payload,
+        );
+        send_tx(context, relay, &[deliver_ix], &[]).await
+    }
+
+    /// Helper: initialize the endpoint with a funded relay and a chain-wide remote for CHAIN_ID_MVM
+    async fn setup_chain_wide_remote(chain_wide: [u8; 32]) -> (ProgramTestContext, Keypair) {
+        let pt = program_test();
+        let mut context = pt.start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        let relay = Keypair::new();
+        let program_id = gmp_program_id();
+
+        let fund_ix = solana_sdk::system_instruction::transfer(&admin.pubkey(), &relay.pubkey(), 1_000_000_000);
+        let setup_ixs = [
+            fund_ix,
+            create_initialize_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM),
+            create_add_relay_ix(program_id, admin.pubkey(), admin.pubkey(), relay.pubkey()),
+            create_set_remote_gmp_endpoint_addr_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, chain_wide),
+        ];
+        send_tx(&mut context, &admin, &setup_ixs, &[]).await.unwrap();
+        (context, relay)
+    }
+
+    /// 64. Test: A message type's trusted remote takes precedence over the chain-wide one
+    /// Verifies that with an override for FulfillmentProof (0x03), a proof is only delivered from the override address, while other types from the chain still use the chain-wide address.
+    /// Why: Deployments where different contracts send different message types must not accept a type from the wrong sender.
+    #[tokio::test]
+    async fn test_deliver_message_type_override_takes_precedence() {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+        let chain_wide = [0x55; 32];
+        let proof_sender = [0x66; 32];
+        let (mut context, relay) = setup_chain_wide_remote(chain_wide).await;
+        let admin = context.payer.insecure_clone();
+
+        let override_ix = create_set_remote_gmp_endpoint_addr_for_type_ix(
+            gmp_program_id(), admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, 0x03, Some(proof_sender),
+        );
+        send_tx(&mut context, &admin, &[override_ix], &[]).await.unwrap();
+
+        let err = deliver_typed_message(&mut context, &relay, 0x03, 0xA1, chain_wide)
+            .await
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(intent_gmp::GmpError::UnknownRemoteGmpEndpoint as u32)
+            )
+        );
+
+        deliver_typed_message(&mut context, &relay, 0x03, 0xA2, proof_sender).await.unwrap();
+        deliver_typed_message(&mut context, &relay, 0x02, 0xA3, chain_wide).await.unwrap();
+    }
+
+    /// 65. Test: Without a type override, delivery falls back to the chain-wide remote
+    /// Verifies that a chain configured only with SetRemoteGmpEndpointAddr delivers every type from that address, that removing an override restores the fallback, and that an override cannot be set before the chain-wide entry exists.
+    /// Why: Single-address configurations must keep working unchanged.
+    #[tokio::test]
+    async fn test_deliver_message_falls_back_to_chain_wide_remote() {
+        let chain_wide = [0x55; 32];
+        let (mut context, relay) = setup_chain_wide_remote(chain_wide).await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        deliver_typed_message(&mut context, &relay, 0x03, 0xB1, chain_wide).await.unwrap();
+
+        let set_ix = create_set_remote_gmp_endpoint_addr_for_type_ix(
+            program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, 0x03, Some([0x66; 32]),
+        );
+        let remove_ix = create_set_remote_gmp_endpoint_addr_for_type_ix(
+            program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, 0x03, None,
+        );
+        send_tx(&mut context, &admin, &[set_ix, remove_ix], &[]).await.unwrap();
+        deliver_typed_message(&mut context, &relay, 0x03, 0xB2, chain_wide).await.unwrap();
+
+        // The account is back to the chain-wide entry alone
+        let (remote_gmp_endpoint_pda, _) = Pubkey::find_program_address(
+            &[seeds::REMOTE_GMP_ENDPOINT_SEED, &CHAIN_ID_MVM.to_le_bytes()],
+            &program_id,
+        );
+        let endpoint: RemoteGmpEndpoint = read_account(&mut context, remote_gmp_endpoint_pda).await;
+        assert!(endpoint.type_overrides.is_empty());
+        assert_eq!(endpoint.addr, chain_wide);
+
+        // No override without a chain-wide entry for the source chain
+        let orphan_ix = create_set_remote_gmp_endpoint_addr_for_type_ix(
+            program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_SVM, 0x03, Some([0x66; 32]),
+        );
+        assert!(send_tx(&mut context, &admin, &[orphan_ix], &[]).await.is_err());
+    }
+
+    // ========================================================================
+    // PAUSE TESTS
+    // ========================================================================
+
+    /// Helper: create Pause or Unpause instruction
+    fn create_set_paused_ix(program_id: Pubkey, admin: Pubkey, paused: bool) -> Instruction {
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let instruction = if paused { NativeGmpInstruction::Pause } else { NativeGmpInstruction::Unpause };
+        Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(admin, true), // payer
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: instruction.try_to_vec().unwrap(),
+        }
+    }
+
+    /// 66. Test: A paused endpoint rejects DeliverMessage and Send
+    /// Verifies that after Pause both instructions fail with Paused and that a non-admin cannot pause.
+    /// Why: The pause switch is the incident circuit breaker; it must stop traffic in both directions and only the admin may pull it.
+    #[tokio::test]
+    async fn test_paused_endpoint_rejects_deliver_and_send() {
+        use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+        let chain_wide = [0x55; 32];
+        let (mut context, relay) = setup_chain_wide_remote(chain_wide).await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        let non_admin_ix = create_set_paused_ix(program_id, relay.pubkey(), true);
+        assert!(send_tx(&mut context, &relay, &[non_admin_ix], &[]).await.is_err());
+
+        send_tx(&mut context, &admin, &[create_set_paused_ix(program_id, admin.pubkey(), true)], &[])
+            .await
+            .unwrap();
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+        let config: ConfigAccount = read_account(&mut context, config_pda).await;
+        assert!(config.paused);
+
+        let paused_err = TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(intent_gmp::GmpError::Paused as u32),
+        );
+        let err = deliver_typed_message(&mut context, &relay, 0x02, 0xD1, chain_wide)
+            .await
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, paused_err);
+
+        let send_ix = create_send_ix(program_id, admin.pubkey(), admin.pubkey(), CHAIN_ID_MVM, [0x11; 32], [0x22; 32], vec![0x01], 0);
+        let err = send_tx(&mut context, &admin, &[send_ix], &[]).await.unwrap_err().unwrap();
+        assert_eq!(err, paused_err);
+    }
+
+    /// 67. Test: Unpause restores delivery after a pause
+    /// Verifies that a message rejected while paused is delivered once the admin unpauses.
+    /// Why: Messages held back during an incident must go through after recovery, without any reconfiguration.
+    #[tokio::test]
+    async fn test_unpause_restores_delivery() {
+        let chain_wide = [0x55; 32];
+        let (mut context, relay) = setup_chain_wide_remote(chain_wide).await;
+        let admin = context.payer.insecure_clone();
+        let program_id = gmp_program_id();
+
+        send_tx(&mut context, &admin, &[create_set_paused_ix(program_id, admin.pubkey(), true)], &[])
+            .await
+            .unwrap();
+        assert!(deliver_typed_message(&mut context, &relay, 0x02, 0xD2, chain_wide).await.is_err());
+
+        send_tx(&mut context, &admin, &[create_set_paused_ix(program_id, admin.pubkey(), false)], &[])
+            .await
+            .unwrap();
+        deliver_typed_message(&mut context, &relay, 0x02, 0xD2, chain_wide)
+            .await
+            .expect("Delivery should succeed after unpause");
+
+        let (delivered_pda, _) = Pubkey::find_program_address(
+            &[seeds::DELIVERED_SEED, &[0xD2; 32], &[0x02]],
+            &program_id,
+        );
+        let delivered: DeliveredMessage = read_account(&mut context, delivered_pda).await;
+        assert_eq!(delivered.discriminator, DeliveredMessage::DISCRIMINATOR);
+    }
+
+    /// 69. Test: A config setter grows a legacy config account
+    /// Verifies that SetTreasury on a 38-byte config reallocates it to the current size, keeps the admin and chain id,
+    /// and stores the new treasury with default settings for the rest.
+    /// Why: Endpoints initialized before the settings were added could otherwise never be reconfigured.
+    #[tokio::test]
+    async fn test_set_treasury_grows_legacy_config() {
+        let mut pt = program_test();
+        let admin = Keypair::new();
+        let program_id = gmp_program_id();
+        let (config_pda, config_bump) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+
+        let mut legacy = vec![ConfigAccount::DISCRIMINATOR];
+        legacy.extend_from_slice(admin.pubkey().as_ref());
+        legacy.extend_from_slice(&CHAIN_ID_SVM.to_le_bytes());
+        legacy.push(config_bump);
+        pt.add_account(
+            config_pda,
+            solana_sdk::account::Account {
+                lamports: solana_sdk::rent::Rent::default().minimum_balance(ConfigAccount::LEGACY_SIZE),
+                data: legacy,
+                owner: program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+        let mut context = pt.start_with_context().await;
+        let payer = context.payer.insecure_clone();
+        let fund_ix = solana_sdk::system_instruction::transfer(&payer.pubkey(), &admin.pubkey(), 1_000_000_000);
+        send_tx(&mut context, &payer, &[fund_ix], &[]).await.unwrap();
+
+        let treasury = Pubkey::new_unique();
+        let set_ix = create_set_treasury_ix(program_id, admin.pubkey(), treasury);
+        send_tx(&mut context, &admin, &[set_ix], &[]).await.unwrap();
+
+        let account = context.banks_client.get_account(config_pda).await.unwrap().unwrap();
+        assert_eq!(account.data.len(), ConfigAccount::SIZE);
+        let config = ConfigAccount::unpack(&account.data).unwrap();
+        assert_eq!(config.admin, admin.pubkey());
+        assert_eq!(config.chain_id, CHAIN_ID_SVM);
+        assert_eq!(config.bump, config_bump);
+        assert_eq!(config.treasury, treasury);
+        assert!(config.require_trusted_remote);
+        assert!(!config.paused);
+    }
 }
@@ -37,6 +37,9 @@ pub enum OutflowError {
 
     #[error("Invalid PDA")]
     InvalidPda,
+
+    #[error("Invalid fee")]
+    InvalidFee,
 }
 
 impl From<OutflowError> for ProgramError {
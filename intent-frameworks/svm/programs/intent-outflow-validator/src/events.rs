@@ -5,20 +5,26 @@
 use solana_program::{msg, pubkey::Pubkey};
 
 /// Emitted when an intent is successfully fulfilled.
+///
+/// `amount` is what the recipient received; `fee` went to `fee_recipient`.
 pub fn emit_fulfillment_succeeded(
     intent_id: &[u8; 32],
     solver: &Pubkey,
     recipient: &Pubkey,
     amount: u64,
     token_mint: &Pubkey,
+    fee: u64,
+    fee_recipient: &Pubkey,
 ) {
     msg!(
-        "FulfillmentSucceeded: intent_id={}, solver={}, recipient={}, amount={}, token={}",
+        "FulfillmentSucceeded: intent_id={}, solver={}, recipient={}, amount={}, token={}, fee={}, fee_recipient={}",
         hex::encode(intent_id),
         solver,
         recipient,
         amount,
-        token_mint
+        token_mint,
+        fee,
+        fee_recipient
     );
 }
 
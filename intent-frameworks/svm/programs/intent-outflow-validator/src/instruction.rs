@@ -41,6 +41,11 @@ pub enum OutflowInstruction {
     /// Fulfill an intent by transferring tokens to the recipient.
     /// Only the authorized solver (or any solver if solver_addr is zero) can call this.
     ///
+    /// With a zero solver_addr, solvers race: the first valid fulfillment marks the
+    /// intent fulfilled and sends the proof; later attempts fail with `AlreadyFulfilled`.
+    /// The requirements account is writable, so the runtime serializes competing
+    /// transactions on it and only one can observe `fulfilled == false`.
+    ///
     /// The instruction:
     /// 1. Validates the caller is the authorized solver
     /// 2. Pulls tokens from solver's token account to this program
@@ -57,7 +62,11 @@ pub enum OutflowInstruction {
     /// 5. `[]` Token mint
     /// 6. `[]` Token program
     /// 7. `[]` GMP endpoint program (for sending message)
-    /// 8+ Additional accounts required by GMP endpoint
+    /// 8. `[writable]` Fee recipient token account (only when the config fee is nonzero)
+    /// 8+ (9+ with a fee) Additional accounts required by GMP endpoint
+    ///
+    /// With a fee, the solver still pays the full amount: the fee goes to the fee
+    /// recipient's token account and the rest to the recipient.
     FulfillIntent { intent_id: [u8; 32] },
 
     /// Update the hub chain configuration.
@@ -70,4 +79,20 @@ pub enum OutflowInstruction {
         hub_chain_id: u32,
         hub_gmp_endpoint_addr: [u8; 32],
     },
+
+    /// Set the protocol fee taken from each fulfillment.
+    /// Only the admin who initialized the program can call this.
+    /// `fee_bps = 0` disables the fee.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Config account (PDA: ["config"])
+    /// 1. `[signer]` Admin
+    /// 2. `[signer, writable]` Payer (funds the config realloc for legacy accounts)
+    /// 3. `[]` System program
+    SetFee {
+        /// Fee in basis points (at most 10_000)
+        fee_bps: u16,
+        /// Owner of the token account that receives the fee
+        fee_recipient: Pubkey,
+    },
 }
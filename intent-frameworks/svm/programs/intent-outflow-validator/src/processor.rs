@@ -60,6 +60,13 @@ pub fn process_instruction(
             msg!("Instruction: UpdateHubConfig");
             process_update_hub_config(program_id, accounts, hub_chain_id, hub_gmp_endpoint_addr)
         }
+        OutflowInstruction::SetFee {
+            fee_bps,
+            fee_recipient,
+        } => {
+            msg!("Instruction: SetFee");
+            process_set_fee(program_id, accounts, fee_bps, fee_recipient)
+        }
     }
 }
 
@@ -147,7 +154,7 @@ fn process_gmp_receive(
         return Err(OutflowError::InvalidPda.into());
     }
 
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| OutflowError::InvalidAccountOwner)?;
 
     // Verify source chain and address match hub GMP endpoint
@@ -249,8 +256,6 @@ fn process_fulfill_intent(
     let token_mint = next_account_info(account_info_iter)?;
     let token_program = next_account_info(account_info_iter)?;
     let gmp_endpoint_program = next_account_info(account_info_iter)?;
-    // Remaining accounts are for GMP endpoint CPI
-    let gmp_accounts: Vec<AccountInfo> = account_info_iter.cloned().collect();
 
     // Verify solver is signer
     if !solver.is_signer {
@@ -263,9 +268,18 @@ fn process_fulfill_intent(
         return Err(OutflowError::InvalidPda.into());
     }
 
-    let config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| OutflowError::InvalidAccountOwner)?;
 
+    // The fee token account is only passed while a fee is configured
+    let fee_token_account = if config.fee_bps > 0 {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+    // Remaining accounts are for GMP endpoint CPI
+    let gmp_accounts: Vec<AccountInfo> = account_info_iter.cloned().collect();
+
     // Verify GMP endpoint matches config
     if gmp_endpoint_program.key != &config.gmp_endpoint {
         msg!("Invalid GMP endpoint program");
@@ -334,14 +348,68 @@ fn process_fulfill_intent(
         }
     }
 
-    // Transfer tokens from solver to recipient
+    // Split the amount between the recipient and the fee recipient
+    let fee = config.fee_for(requirements.amount_required);
+    let recipient_amount = requirements.amount_required - fee;
+
+    if fee > 0 {
+        // A nonzero fee implies fee_bps > 0, so the account was read above
+        let fee_token_account = fee_token_account.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Verify the fee token account holds the intent token and belongs to the fee recipient
+        // SPL Token account layout: mint (32) | owner (32) | amount (8) | ...
+        {
+            let fee_data = fee_token_account.try_borrow_data()?;
+            if fee_data.len() < 64 {
+                msg!("Invalid fee token account data");
+                return Err(OutflowError::InvalidAccountOwner.into());
+            }
+            let fee_mint = Pubkey::try_from(&fee_data[0..32])
+                .map_err(|_| OutflowError::InvalidAccountOwner)?;
+            let fee_owner = Pubkey::try_from(&fee_data[32..64])
+                .map_err(|_| OutflowError::InvalidAccountOwner)?;
+            if fee_mint != requirements.token_mint {
+                return Err(OutflowError::TokenMismatch.into());
+            }
+            if fee_owner != config.fee_recipient {
+                msg!(
+                    "Fee recipient mismatch: token account owner {} != fee recipient {}",
+                    fee_owner,
+                    config.fee_recipient
+                );
+                return Err(OutflowError::RecipientMismatch.into());
+            }
+        }
+
+        // Transfer the fee from solver to the fee recipient
+        let fee_ix = spl_token::instruction::transfer(
+            token_program.key,
+            solver_token_account.key,
+            fee_token_account.key,
+            solver.key,
+            &[],
+            fee,
+        )?;
+
+        invoke(
+            &fee_ix,
+            &[
+                solver_token_account.clone(),
+                fee_token_account.clone(),
+                solver.clone(),
+                token_program.clone(),
+            ],
+        )?;
+    }
+
+    // Transfer the remaining tokens from solver to recipient
     let transfer_ix = spl_token::instruction::transfer(
         token_program.key,
         solver_token_account.key,
         recipient_token_account.key,
         solver.key,
         &[],
-        requirements.amount_required,
+        recipient_amount,
     )?;
 
     invoke(
@@ -363,8 +431,10 @@ fn process_fulfill_intent(
         &intent_id,
         solver.key,
         &requirements.recipient_addr,
-        requirements.amount_required,
+        recipient_amount,
         &requirements.token_mint,
+        fee,
+        &config.fee_recipient,
     );
 
     // Send FulfillmentProof GMP message to hub
@@ -433,7 +503,7 @@ fn process_update_hub_config(
     }
 
     // Load config and verify admin matches
-    let mut config = ConfigAccount::try_from_slice(&config_account.data.borrow())
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())
         .map_err(|_| OutflowError::InvalidAccountOwner)?;
 
     if config.admin != *admin.key {
@@ -444,7 +514,7 @@ fn process_update_hub_config(
     // Update config
     config.hub_chain_id = hub_chain_id;
     config.hub_gmp_endpoint_addr = hub_gmp_endpoint_addr;
-    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+    config.pack(&mut config_account.data.borrow_mut())?;
 
     msg!(
         "OutflowValidator hub config updated: hub_chain_id={}",
@@ -452,3 +522,68 @@ fn process_update_hub_config(
     );
     Ok(())
 }
+
+/// Set the protocol fee taken from each fulfillment.
+/// Only the admin who initialized the program can call this.
+fn process_set_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+    fee_recipient: Pubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let config_account = next_account_info(account_info_iter)?;
+    let admin = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Verify admin is signer
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify config PDA
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], program_id);
+    if config_account.key != &config_pda {
+        return Err(OutflowError::InvalidPda.into());
+    }
+
+    // Load config and verify admin matches
+    let mut config = ConfigAccount::unpack(&config_account.data.borrow())
+        .map_err(|_| OutflowError::InvalidAccountOwner)?;
+
+    if config.admin != *admin.key {
+        msg!("Unauthorized: signer is not admin");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if fee_bps > ConfigAccount::MAX_FEE_BPS {
+        return Err(OutflowError::InvalidFee.into());
+    }
+
+    // Grow legacy config accounts so they can store the fee
+    if config_account.data_len() < ConfigAccount::SIZE {
+        let rent = Rent::get()?;
+        let shortfall = rent
+            .minimum_balance(ConfigAccount::SIZE)
+            .saturating_sub(config_account.lamports());
+        if shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, config_account.key, shortfall),
+                &[payer.clone(), config_account.clone(), system_program.clone()],
+            )?;
+        }
+        config_account.resize(ConfigAccount::SIZE)?;
+    }
+
+    config.fee_bps = fee_bps;
+    config.fee_recipient = fee_recipient;
+    config.pack(&mut config_account.data.borrow_mut())?;
+
+    msg!(
+        "OutflowValidator fee set: fee_bps={}, fee_recipient={}",
+        fee_bps,
+        fee_recipient
+    );
+    Ok(())
+}
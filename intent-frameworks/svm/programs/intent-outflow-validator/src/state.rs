@@ -70,11 +70,20 @@ pub struct ConfigAccount {
     pub hub_gmp_endpoint_addr: [u8; 32],
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// Protocol fee taken from each fulfillment, in basis points (0 = no fee)
+    pub fee_bps: u16,
+    /// Owner of the token account that receives the fee
+    pub fee_recipient: Pubkey,
 }
 
 impl ConfigAccount {
     pub const DISCRIMINATOR: u8 = 2;
-    pub const SIZE: usize = 1 + 32 + 32 + 4 + 32 + 1; // 102 bytes (added bump)
+    pub const SIZE: usize = 1 + 32 + 32 + 4 + 32 + 1 + 2 + 32; // 136 bytes
+    /// Size of config accounts created before the fee was added.
+    /// The fee fields were appended, so the legacy layout is a prefix.
+    pub const LEGACY_SIZE: usize = 1 + 32 + 32 + 4 + 32 + 1; // 102 bytes
+    /// Largest allowed fee (100%)
+    pub const MAX_FEE_BPS: u16 = 10_000;
 
     pub fn new(admin: Pubkey, gmp_endpoint: Pubkey, hub_chain_id: u32, hub_gmp_endpoint_addr: [u8; 32], bump: u8) -> Self {
         Self {
@@ -84,8 +93,40 @@ impl ConfigAccount {
             hub_chain_id,
             hub_gmp_endpoint_addr,
             bump,
+            fee_bps: 0,
+            fee_recipient: Pubkey::default(),
         }
     }
+
+    /// Decode a config account, reading legacy 102-byte accounts as fee-free.
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() == Self::LEGACY_SIZE {
+            let mut padded = [0u8; Self::SIZE];
+            padded[..Self::LEGACY_SIZE].copy_from_slice(data);
+            return Self::try_from_slice(&padded);
+        }
+        Self::try_from_slice(data)
+    }
+
+    /// Encode into account data, keeping the legacy layout for 102-byte accounts.
+    ///
+    /// Legacy accounts cannot hold a fee; `SetFee` reallocates them first.
+    pub fn pack(&self, data: &mut [u8]) -> std::io::Result<()> {
+        let bytes = self.try_to_vec()?;
+        if data.len() == Self::LEGACY_SIZE {
+            if bytes[Self::LEGACY_SIZE..].iter().any(|b| *b != 0) {
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+            data.copy_from_slice(&bytes[..Self::LEGACY_SIZE]);
+            return Ok(());
+        }
+        self.serialize(&mut &mut data[..])
+    }
+
+    /// Fee owed on a fulfillment of `amount`, rounded down in the recipient's favor.
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee_bps as u128 / Self::MAX_FEE_BPS as u128) as u64
+    }
 }
 
 /// Seeds for PDA derivation
@@ -240,6 +240,7 @@ fn test_error_codes_unique() {
         OutflowError::IntentExpired,
         OutflowError::InvalidAccountOwner,
         OutflowError::InvalidPda,
+        OutflowError::InvalidFee,
     ];
 
     let codes: Vec<u32> = errors.iter().map(|e| *e as u32).collect();
@@ -251,3 +252,62 @@ fn test_error_codes_unique() {
         "Error codes must be unique"
     );
 }
+
+// ============================================================================
+// FEE TESTS
+// ============================================================================
+
+/// 8. Test: Legacy ConfigAccount reads as fee-free
+/// Verifies that a config account created before the fee fields unpacks with a zero fee
+/// and packs back into its 102-byte layout while no fee is set.
+/// Why: Already-initialized deployments must keep fulfilling without migrating first.
+#[test]
+fn test_config_account_legacy_unpack() {
+    let account = ConfigAccount::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        DUMMY_HUB_CHAIN_ID,
+        dummy_addr_1(),
+        254,
+    );
+    let full = account.try_to_vec().unwrap();
+    assert_eq!(full.len(), ConfigAccount::SIZE);
+    let legacy = &full[..ConfigAccount::LEGACY_SIZE];
+
+    let unpacked = ConfigAccount::unpack(legacy).unwrap();
+    assert_eq!(unpacked, account);
+    assert_eq!(unpacked.fee_bps, 0);
+
+    let mut data = [0u8; ConfigAccount::LEGACY_SIZE];
+    unpacked.pack(&mut data).unwrap();
+    assert_eq!(&data[..], legacy);
+
+    // A fee cannot be stored without reallocating first
+    let mut with_fee = unpacked.clone();
+    with_fee.fee_bps = 100;
+    assert!(with_fee.pack(&mut data).is_err());
+}
+
+/// 9. Test: ConfigAccount::fee_for rounds down
+/// Verifies fee amounts for zero, round and sub-unit fees.
+/// Why: Pins the rounding direction so the recipient never receives less than
+/// amount minus the exact fee.
+#[test]
+fn test_config_account_fee_for_rounding() {
+    let mut config = ConfigAccount::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        DUMMY_HUB_CHAIN_ID,
+        dummy_addr_1(),
+        254,
+    );
+    assert_eq!(config.fee_for(DUMMY_AMOUNT), 0);
+
+    config.fee_bps = 100; // 1%
+    assert_eq!(config.fee_for(DUMMY_AMOUNT), 10_000);
+    assert_eq!(config.fee_for(1), 0);
+    assert_eq!(config.fee_for(199), 1);
+
+    config.fee_bps = ConfigAccount::MAX_FEE_BPS;
+    assert_eq!(config.fee_for(u64::MAX), u64::MAX);
+}
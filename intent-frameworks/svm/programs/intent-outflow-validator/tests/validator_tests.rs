@@ -5,6 +5,7 @@
 //! - Receiving intent requirements via GMP (gmp_receive)
 //! - Fulfilling intents with token transfers
 //! - Sending fulfillment proofs via GMP
+//! - Splitting a configured protocol fee off each fulfillment
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use gmp_common::messages::IntentRequirements;
@@ -517,6 +518,94 @@ fn create_update_hub_config_ix(
     }
 }
 
+/// Builds a SetFee instruction with the correct account layout.
+/// The admin also pays for reallocating legacy config accounts.
+fn create_set_fee_ix(
+    program_id: Pubkey,
+    admin: Pubkey,
+    fee_bps: u16,
+    fee_recipient: Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+
+    let instruction = OutflowInstruction::SetFee {
+        fee_bps,
+        fee_recipient,
+    };
+
+    solana_sdk::instruction::Instruction {
+        program_id,
+        accounts: vec![
+            solana_sdk::instruction::AccountMeta::new(config_pda, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(admin, true),
+            solana_sdk::instruction::AccountMeta::new(admin, true), // payer
+            solana_sdk::instruction::AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Sets up a funded solver, requirements and a fee, then fulfills the intent.
+/// Returns (solver_token, recipient_token, fee_token) balances after fulfillment.
+/// With `fee_bps == 0` no fee token account is passed, as for a fee-free config.
+async fn fulfill_with_fee(fee_bps: u16, amount: u64) -> (u64, u64, u64) {
+    let pt = program_test_with_spl_and_gmp();
+    let mut context = pt.start_with_context().await;
+    let admin = context.payer.insecure_clone();
+    let program_id = outflow_program_id();
+    let solver = Keypair::new();
+    let fee_recipient = Keypair::new();
+    let intent_id = test_intent_id();
+
+    initialize_gmp_endpoint(&mut context, &admin, SVM_CHAIN_ID).await;
+
+    let mint = create_mint(&mut context, &admin, &admin.pubkey(), 6).await;
+    let solver_token = create_token_account(&mut context, &admin, &mint, &solver.pubkey()).await;
+    let recipient_token = create_token_account(&mut context, &admin, &mint, &admin.pubkey()).await;
+    let fee_token = create_token_account(&mut context, &admin, &mint, &fee_recipient.pubkey()).await;
+    mint_tokens(&mut context, &admin, &mint, &admin, &solver_token, 1_000_000).await;
+
+    setup_requirements(
+        &mut context,
+        &admin,
+        program_id,
+        intent_id,
+        admin.pubkey(),
+        mint,
+        Pubkey::default(), // any solver
+        amount,
+        FAR_FUTURE_EXPIRY,
+    ).await;
+
+    let set_fee_ix = create_set_fee_ix(program_id, admin.pubkey(), fee_bps, fee_recipient.pubkey());
+    send_tx(&mut context, &admin, &[set_fee_ix], &[]).await.unwrap();
+
+    let mut fulfill_ix = create_fulfill_intent_ix_with_gmp(
+        program_id,
+        solver.pubkey(),
+        solver_token,
+        recipient_token,
+        mint,
+        gmp_endpoint_id(),
+        intent_id,
+        admin.pubkey(),
+        HUB_CHAIN_ID,
+    );
+    if fee_bps > 0 {
+        // Fee token account goes right after the GMP endpoint program
+        fulfill_ix
+            .accounts
+            .insert(8, solana_sdk::instruction::AccountMeta::new(fee_token, false));
+    }
+    send_tx(&mut context, &admin, &[fulfill_ix], &[&solver]).await.unwrap();
+
+    (
+        get_token_balance(&mut context, solver_token).await,
+        get_token_balance(&mut context, recipient_token).await,
+        get_token_balance(&mut context, fee_token).await,
+    )
+}
+
 /// Gets the token balance for an account.
 async fn get_token_balance(
     context: &mut solana_program_test::ProgramTestContext,
@@ -1365,3 +1454,175 @@ async fn test_update_hub_config_then_gmp_receive() {
     assert_eq!(stored.amount_required, 1_000_000);
     assert!(!stored.fulfilled);
 }
+
+// ============================================================================
+// COMPETITIVE FULFILLMENT TESTS
+// ============================================================================
+
+/// 22. Test: Competing solvers in the same slot, exactly one fulfills
+/// Verifies that when two solvers submit FulfillIntent for an open (zero-solver) intent
+/// with the same blockhash, one succeeds and the other fails with AlreadyFulfilled,
+/// and only the winner's tokens reach the recipient.
+/// Why: Open intents are fulfilled competitively; a second payout or a second
+/// FulfillmentProof would double-pay the recipient or double-release on the hub.
+#[tokio::test]
+async fn test_fulfill_intent_competing_solvers_one_wins() {
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    let pt = program_test_with_spl_and_gmp();
+    let mut context = pt.start_with_context().await;
+    let admin = context.payer.insecure_clone();
+    let program_id = outflow_program_id();
+    let solver_a = Keypair::new();
+    let solver_b = Keypair::new();
+    let intent_id = test_intent_id();
+    let fulfillment_amount = 500_000u64;
+
+    initialize_gmp_endpoint(&mut context, &admin, SVM_CHAIN_ID).await;
+
+    let mint = create_mint(&mut context, &admin, &admin.pubkey(), 6).await;
+    let solver_a_token = create_token_account(&mut context, &admin, &mint, &solver_a.pubkey()).await;
+    let solver_b_token = create_token_account(&mut context, &admin, &mint, &solver_b.pubkey()).await;
+    let recipient_token = create_token_account(&mut context, &admin, &mint, &admin.pubkey()).await;
+    mint_tokens(&mut context, &admin, &mint, &admin, &solver_a_token, 1_000_000).await;
+    mint_tokens(&mut context, &admin, &mint, &admin, &solver_b_token, 1_000_000).await;
+
+    setup_requirements(
+        &mut context,
+        &admin,
+        program_id,
+        intent_id,
+        admin.pubkey(),
+        mint,
+        Pubkey::default(), // any solver
+        fulfillment_amount,
+        FAR_FUTURE_EXPIRY,
+    ).await;
+
+    // Both transactions are signed against the same blockhash, i.e. the same slot
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let build_tx = |solver: &Keypair, solver_token: Pubkey| {
+        let ix = create_fulfill_intent_ix_with_gmp(
+            program_id,
+            solver.pubkey(),
+            solver_token,
+            recipient_token,
+            mint,
+            gmp_endpoint_id(),
+            intent_id,
+            admin.pubkey(),
+            HUB_CHAIN_ID,
+        );
+        Transaction::new_signed_with_payer(&[ix], Some(&admin.pubkey()), &[&admin, solver], blockhash)
+    };
+    let tx_a = build_tx(&solver_a, solver_a_token);
+    let tx_b = build_tx(&solver_b, solver_b_token);
+
+    let results = [
+        context.banks_client.process_transaction(tx_a).await,
+        context.banks_client.process_transaction(tx_b).await,
+    ];
+    let winners = results.iter().filter(|r| r.is_ok()).count();
+    assert_eq!(winners, 1, "Exactly one solver must fulfill the intent");
+
+    let loser_err = results
+        .into_iter()
+        .find_map(Result::err)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        loser_err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(intent_outflow_validator::OutflowError::AlreadyFulfilled as u32)
+        )
+    );
+
+    // Only one payout reached the recipient; the loser kept its tokens
+    assert_eq!(get_token_balance(&mut context, recipient_token).await, fulfillment_amount);
+    let balance_a = get_token_balance(&mut context, solver_a_token).await;
+    let balance_b = get_token_balance(&mut context, solver_b_token).await;
+    assert_eq!(balance_a + balance_b, 2_000_000 - fulfillment_amount);
+    assert!(balance_a == 1_000_000 || balance_b == 1_000_000);
+}
+
+// ============================================================================
+// FEE TESTS
+// ============================================================================
+
+/// 23. Test: SetFee stores the fee and rejects non-admins and fees above 100%
+/// Verifies that the admin can set fee_bps and fee_recipient, and that a non-admin
+/// signer or fee_bps above 10_000 is rejected.
+/// Why: The fee diverts part of every fulfillment; only the admin may set it, and
+/// it can never exceed the fulfilled amount.
+#[tokio::test]
+async fn test_set_fee() {
+    let pt = program_test();
+    let mut context = pt.start_with_context().await;
+    let admin = context.payer.insecure_clone();
+    let program_id = outflow_program_id();
+    let fee_recipient = Pubkey::new_unique();
+
+    let init_ix = create_initialize_ix(
+        program_id,
+        admin.pubkey(),
+        gmp_endpoint_id(),
+        HUB_CHAIN_ID,
+        hub_gmp_endpoint_addr(),
+    );
+    send_tx(&mut context, &admin, &[init_ix], &[]).await.unwrap();
+
+    let set_fee_ix = create_set_fee_ix(program_id, admin.pubkey(), 250, fee_recipient);
+    send_tx(&mut context, &admin, &[set_fee_ix], &[]).await.unwrap();
+
+    let (config_pda, _) = Pubkey::find_program_address(&[seeds::CONFIG_SEED], &program_id);
+    let config: ConfigAccount = read_account(&mut context, config_pda).await;
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.fee_recipient, fee_recipient);
+
+    // Fee above 100% is rejected
+    let too_high_ix = create_set_fee_ix(program_id, admin.pubkey(), ConfigAccount::MAX_FEE_BPS + 1, fee_recipient);
+    assert!(send_tx(&mut context, &admin, &[too_high_ix], &[]).await.is_err());
+
+    // Non-admin cannot set the fee
+    let non_admin = Keypair::new();
+    let non_admin_ix = create_set_fee_ix(program_id, non_admin.pubkey(), 0, fee_recipient);
+    assert!(send_tx(&mut context, &admin, &[non_admin_ix], &[&non_admin]).await.is_err());
+
+    let config: ConfigAccount = read_account(&mut context, config_pda).await;
+    assert_eq!(config.fee_bps, 250);
+}
+
+/// 24. Test: FulfillIntent with zero fee pays the full amount to the recipient
+/// Verifies that fee_bps = 0 needs no fee token account and moves nothing to it.
+/// Why: A zero fee must preserve the pre-fee account layout and payout.
+#[tokio::test]
+async fn test_fulfill_intent_zero_fee() {
+    let (solver, recipient, fee) = fulfill_with_fee(0, 500_000).await;
+    assert_eq!(solver, 500_000);
+    assert_eq!(recipient, 500_000);
+    assert_eq!(fee, 0);
+}
+
+/// 25. Test: FulfillIntent splits the amount with a round fee
+/// Verifies that 1% of 500_000 goes to the fee recipient and the rest to the recipient,
+/// while the solver pays the full amount.
+/// Why: The split must add up to the amount the hub releases for.
+#[tokio::test]
+async fn test_fulfill_intent_round_fee() {
+    let (solver, recipient, fee) = fulfill_with_fee(100, 500_000).await;
+    assert_eq!(solver, 500_000);
+    assert_eq!(recipient, 495_000);
+    assert_eq!(fee, 5_000);
+}
+
+/// 26. Test: FulfillIntent rounds the fee down
+/// Verifies that a 1-unit fulfillment with a nonzero fee sends the unit to the recipient.
+/// Why: Pins the rounding direction in the recipient's favor.
+#[tokio::test]
+async fn test_fulfill_intent_fee_rounds_down() {
+    let (solver, recipient, fee) = fulfill_with_fee(100, 1).await;
+    assert_eq!(solver, 999_999);
+    assert_eq!(recipient, 1);
+    assert_eq!(fee, 0);
+}
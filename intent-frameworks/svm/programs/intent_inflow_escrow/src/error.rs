@@ -77,6 +77,21 @@ pub enum EscrowError {
 
     #[error("Intent has expired")]
     IntentExpired,
+
+    #[error("Invalid account data")]
+    InvalidAccountData,
+
+    #[error("Token program is not SPL Token or Token-2022, does not own the mint, or needs the mint account")]
+    InvalidTokenProgram,
+
+    #[error("Sweep recipient is not the escrow requester or their token account")]
+    InvalidSweepRecipient,
+
+    #[error("Refund recipient is not the escrow requester or their token account")]
+    InvalidRefundRecipient,
+
+    #[error("Escrow amount can only be reduced; deposit into a new escrow to increase it")]
+    EscrowIncreaseNotAllowed,
 }
 
 impl From<EscrowError> for ProgramError {
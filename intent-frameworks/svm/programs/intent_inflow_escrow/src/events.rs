@@ -0,0 +1,139 @@
+//! Event definitions for the inflow escrow program.
+//!
+//! Events are borsh-encoded and emitted via `sol_log_data`. They appear in transaction
+//! logs as `Program data: <base64 field> ...`, so monitors can follow the escrow
+//! lifecycle from logs instead of polling account state.
+//!
+//! ## Log schema versions
+//!
+//! - **v1**: two fields, [`EVENT_TAG`] followed by the encoded [`EscrowEvent`].
+//! - **v2** (current): three fields, [`EVENT_TAG`], a one-byte schema version, then the
+//!   encoded [`EscrowEvent`]. The event layout is unchanged from v1.
+//!
+//! Decoders accept every version up to [`EVENT_SCHEMA_VERSION`] and reject newer ones
+//! with [`EventSchemaError::UnsupportedVersion`], so an outdated indexer fails loudly
+//! instead of misparsing. Bump the version whenever the field layout changes.
+//!
+//! The borsh variant index is the event discriminator: new variants must be
+//! appended, and existing variants must not be reordered or changed.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+/// First `sol_log_data` field of every escrow event.
+pub const EVENT_TAG: &[u8] = b"int3nts:escrow_event";
+
+/// Schema version written by [`EscrowEvent::emit`].
+pub const EVENT_SCHEMA_VERSION: u8 = 2;
+
+/// Why a tagged escrow log line could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventSchemaError {
+    /// The line was written with a schema version this decoder does not know
+    UnsupportedVersion(u8),
+    /// The field count or event bytes do not match the schema version
+    Malformed,
+}
+
+impl std::fmt::Display for EventSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported escrow event schema version {} (this decoder supports up to v{})",
+                version, EVENT_SCHEMA_VERSION
+            ),
+            Self::Malformed => write!(f, "Malformed escrow event log data"),
+        }
+    }
+}
+
+impl std::error::Error for EventSchemaError {}
+
+/// Escrow lifecycle events.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub enum EscrowEvent {
+    /// Tokens were deposited into a new escrow (discriminator 0)
+    EscrowCreated {
+        intent_id: [u8; 32],
+        requester: Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+        reserved_solver: Pubkey,
+        expiry: i64,
+        timestamp: i64,
+    },
+    /// Tokens were released from an escrow to the solver, by Claim or fulfillment-proof
+    /// auto-release (discriminator 1)
+    EscrowClaimed {
+        intent_id: [u8; 32],
+        solver: Pubkey,
+        amount: u64,
+        remaining: u64,
+        timestamp: i64,
+    },
+    /// The remaining escrow balance was returned to the requester, by Cancel or
+    /// SweepExpired (discriminator 2)
+    EscrowCancelled {
+        intent_id: [u8; 32],
+        requester: Pubkey,
+        amount: u64,
+        timestamp: i64,
+    },
+    /// The admin replaced the approver stored in the program state (discriminator 3)
+    ApproverRotated {
+        old_approver: Pubkey,
+        new_approver: Pubkey,
+        admin: Pubkey,
+        timestamp: i64,
+    },
+    /// The requester took back part of the escrow with ReduceEscrow (discriminator 4)
+    EscrowReduced {
+        intent_id: [u8; 32],
+        requester: Pubkey,
+        amount: u64,
+        remaining: u64,
+        timestamp: i64,
+    },
+}
+
+impl EscrowEvent {
+    /// Log the event as `sol_log_data([EVENT_TAG, [EVENT_SCHEMA_VERSION], borsh(event)])`.
+    pub fn emit(&self) {
+        // Serializing a fixed-layout enum into a Vec cannot fail
+        let data = self.try_to_vec().unwrap_or_default();
+        sol_log_data(&[EVENT_TAG, &[EVENT_SCHEMA_VERSION], &data]);
+    }
+
+    /// Decode an event from the decoded `sol_log_data` fields of one log line.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(event))` - A v1 or v2 escrow event
+    /// * `Ok(None)` - Data logged by another program (no escrow tag)
+    /// * `Err(EventSchemaError)` - Tagged as an escrow event but from an unknown schema
+    ///   version or with an invalid layout
+    pub fn parse_log_fields(fields: &[&[u8]]) -> Result<Option<Self>, EventSchemaError> {
+        let data = match fields {
+            [] => return Ok(None),
+            [tag, ..] if *tag != EVENT_TAG => return Ok(None),
+            // v1: no version field
+            [_, data] => data,
+            [_, version, data] => match version {
+                [v] if (1..=EVENT_SCHEMA_VERSION).contains(v) => data,
+                [v] => return Err(EventSchemaError::UnsupportedVersion(*v)),
+                _ => return Err(EventSchemaError::Malformed),
+            },
+            _ => return Err(EventSchemaError::Malformed),
+        };
+        Self::try_from_slice(data)
+            .map(Some)
+            .map_err(|_| EventSchemaError::Malformed)
+    }
+
+    /// Like [`Self::parse_log_fields`], but returns `None` for anything that is not a
+    /// decodable escrow event.
+    pub fn from_log_fields(fields: &[&[u8]]) -> Option<Self> {
+        Self::parse_log_fields(fields).ok().flatten()
+    }
+}
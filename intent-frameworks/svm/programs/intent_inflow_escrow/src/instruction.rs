@@ -3,13 +3,17 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::state::EscrowMode;
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub enum EscrowInstruction {
     /// Initialize the escrow program with approver pubkey
     ///
+    /// The payer becomes the admin that may later rotate the approver with `SetApprover`.
+    ///
     /// Accounts expected:
     /// 0. `[writable]` State account (PDA)
-    /// 1. `[signer]` Payer
+    /// 1. `[signer]` Payer (stored as admin)
     /// 2. `[]` System program
     Initialize { approver: Pubkey },
 
@@ -32,8 +36,9 @@ pub enum EscrowInstruction {
     /// 2. `[writable]` Escrow vault (PDA)
     /// 3. `[writable]` Solver token account
     /// 4. `[]` GMP config account (PDA)
-    /// 5. `[signer]` GMP endpoint or relay (trusted caller)
+    /// 5. `[signer]` GMP endpoint signer (PDA `["gmp_signer"]` of `gmp_config.gmp_endpoint`)
     /// 6. `[]` Token program
+    /// 7. `[optional]` Token mint - required for Token-2022 vaults
     GmpReceive {
         /// Source chain ID
         src_chain_id: u32,
@@ -62,14 +67,18 @@ pub enum EscrowInstruction {
     /// Validates escrow against stored GMP requirements and sends EscrowConfirmation to hub.
     /// Expiry is always taken from the hub-provided requirements (no local default).
     ///
+    /// For a native SOL escrow pass the system program as the token mint and token program
+    /// and the requester wallet as the requester token account. The vault then holds
+    /// lamports: the amount plus the rent-exempt reserve, which is returned on sweep.
+    ///
     /// Accounts expected:
     /// 0. `[writable]` Escrow account (PDA)
     /// 1. `[writable, signer]` Requester
     /// 2. `[]` Token mint
     /// 3. `[writable]` Requester token account
     /// 4. `[writable]` Escrow vault (PDA)
-    /// 5. `[]` Reserved solver
-    /// 6. `[]` Token program
+    /// 5. `[]` Reserved solver (ignored in `Open` mode)
+    /// 6. `[]` Token program - SPL Token or Token-2022, must own the mint
     /// 7. `[]` System program
     /// 8. `[]` Rent sysvar
     /// 9. `[writable]` Requirements account (PDA) - validates against GMP requirements
@@ -79,6 +88,11 @@ pub enum EscrowInstruction {
     CreateEscrow {
         intent_id: [u8; 32],
         amount: u64,
+        /// Opaque client correlation tag stored on the escrow (zeros = none).
+        /// Informational only; ignored by program logic.
+        memo: [u8; 32],
+        /// `Reserved` pays out only to the reserved solver; `Open` to any solver.
+        mode: EscrowMode,
     },
 
     /// Claim escrow funds (GMP mode - no signature required)
@@ -87,17 +101,27 @@ pub enum EscrowInstruction {
     /// This instruction is called after GmpReceiveFulfillmentProof marks the
     /// requirements as fulfilled.
     ///
+    /// For a `Reserved` escrow the solver token account must be owned by the
+    /// reserved solver; for an `Open` escrow any solver token account is accepted.
+    /// There is no signer or approver signature: an `Open` claim is gated only by
+    /// `requirements.fulfilled`. The fulfillment proof is the only way to set that
+    /// flag and releases the escrow itself, so in practice Claim finds it claimed.
+    /// Native SOL escrows pay the solver wallet directly: pass it as the solver token
+    /// account and the system program as the token program.
+    ///
     /// Accounts expected:
     /// 0. `[writable]` Escrow account (PDA)
     /// 1. `[]` Requirements account (PDA)
     /// 2. `[writable]` Escrow vault (PDA)
     /// 3. `[writable]` Solver token account
     /// 4. `[]` Token program
+    /// 5. `[optional]` Token mint - required for Token-2022 vaults
     Claim { intent_id: [u8; 32] },
 
     /// Cancel escrow and return funds to requester (only after expiry)
     ///
     /// Only the admin can cancel. Funds always return to the original requester.
+    /// For native SOL escrows pass the requester wallet and the system program.
     ///
     /// Accounts expected:
     /// 0. `[writable]` Escrow account (PDA)
@@ -106,6 +130,7 @@ pub enum EscrowInstruction {
     /// 3. `[writable]` Requester token account
     /// 4. `[]` Token program
     /// 5. `[]` GMP config account (PDA)
+    /// 6. `[optional]` Token mint - required for Token-2022 vaults
     Cancel { intent_id: [u8; 32] },
 
     /// Receive intent requirements from hub via GMP
@@ -129,14 +154,20 @@ pub enum EscrowInstruction {
 
     /// Receive fulfillment proof from hub via GMP (auto-releases escrow)
     ///
+    /// `Reserved` escrows require a solver token account owned by the reserved solver;
+    /// `Open` escrows one owned by the solver named in the proof.
+    /// For native SOL escrows the solver token account is the solver wallet and the
+    /// token program is the system program.
+    ///
     /// Accounts expected:
     /// 0. `[writable]` Requirements account (PDA)
     /// 1. `[writable]` Escrow account (PDA)
     /// 2. `[writable]` Escrow vault (PDA)
     /// 3. `[writable]` Solver token account
     /// 4. `[]` GMP config account (PDA)
-    /// 5. `[signer]` GMP endpoint or relay (trusted caller)
+    /// 5. `[signer]` GMP endpoint signer (PDA `["gmp_signer"]` of `gmp_config.gmp_endpoint`)
     /// 6. `[]` Token program
+    /// 7. `[optional]` Token mint - required for Token-2022 vaults
     GmpReceiveFulfillmentProof {
         /// Source chain ID
         src_chain_id: u32,
@@ -145,4 +176,64 @@ pub enum EscrowInstruction {
         /// GMP payload (FulfillmentProof message)
         payload: Vec<u8>,
     },
+
+    /// Return an expired escrow's balance to the requester and close the escrow (permissionless)
+    ///
+    /// Anyone may call this once the escrow has expired. The vault balance always goes to a
+    /// token account owned by `escrow.requester`, and the rent of the escrow and vault
+    /// accounts goes to the requester, so the caller only pays the transaction fee. This
+    /// keeps funds recoverable when the admin never cancels. For native SOL escrows the
+    /// requester token account is the requester wallet, the token program is the system
+    /// program, and the vault's rent reserve goes back to the requester with the balance.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Escrow account (PDA)
+    /// 1. `[writable]` Escrow vault (PDA)
+    /// 2. `[writable]` Requester token account (owner = escrow requester, mint = escrow mint)
+    /// 3. `[writable]` Requester (receives the reclaimed rent)
+    /// 4. `[]` Token program
+    /// 5. `[writable, optional]` Token mint - required for Token-2022 vaults
+    SweepExpired { intent_id: [u8; 32] },
+
+    /// Replace the approver stored in the program state (admin only)
+    ///
+    /// Claims are authorized by the GMP FulfillmentProof, not by an approver signature,
+    /// so rotation does not affect escrows that already exist.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` State account (PDA)
+    /// 1. `[signer]` Admin (must match `EscrowState.admin`)
+    SetApprover { new_approver: Pubkey },
+
+    /// Return part of an over-provisioned escrow to the requester before expiry (requester only)
+    ///
+    /// Transfers `escrow.amount - new_amount` from the vault back to the requester and
+    /// sets `escrow.amount` to `new_amount`. `new_amount` must be positive, below the
+    /// current amount (increases need a fresh deposit), and at least the hub's
+    /// `amount_required`, so the intent stays funded. Rejected once the escrow is
+    /// claimed or the fulfillment proof has arrived. For native SOL escrows pass the
+    /// requester wallet and the system program.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Escrow account (PDA)
+    /// 1. `[signer]` Requester (must match `escrow.requester`)
+    /// 2. `[writable]` Escrow vault (PDA)
+    /// 3. `[writable]` Requester token account (owner = escrow requester, mint = escrow mint)
+    /// 4. `[]` Token program
+    /// 5. `[]` Requirements account (PDA)
+    /// 6. `[optional]` Token mint - required for Token-2022 vaults
+    ReduceEscrow { intent_id: [u8; 32], new_amount: u64 },
+
+    /// Grow a state account created before the admin was recorded (GMP config admin only)
+    ///
+    /// Reallocates the legacy 40-byte state to the current size and records the signer,
+    /// which must be the GMP config admin, as the admin allowed to run `SetApprover`.
+    /// The signer pays the extra rent. Fails with `InvalidAccountData` on a current state.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` State account (PDA)
+    /// 1. `[]` GMP config account (PDA)
+    /// 2. `[signer, writable]` Admin (must match `GmpConfig.admin`)
+    /// 3. `[]` System program
+    MigrateState,
 }
@@ -2,11 +2,16 @@
 //!
 //! This program provides escrow functionality for cross-chain intents on Solana.
 //! Funds are held in escrow and released to solvers when approver signature checks out.
+//! Escrows hold SPL Token / Token-2022 tokens in a token account vault, or native SOL
+//! in a system-owned vault.
 
 pub mod error;
+pub mod events;
 pub mod instruction;
+pub mod native;
 pub mod processor;
 pub mod state;
+pub mod token;
 
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
@@ -15,5 +20,6 @@ pub use solana_program;
 
 // Re-export for tests
 pub use error::EscrowError;
+pub use events::EscrowEvent;
 pub use instruction::EscrowInstruction;
 pub use state::{Escrow, EscrowState, GmpConfig, StoredIntentRequirements};
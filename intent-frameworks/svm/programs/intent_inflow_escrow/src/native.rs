@@ -0,0 +1,81 @@
+//! Native SOL vault helpers
+//!
+//! Native SOL escrows use the same vault PDA as token escrows, but the vault is a plain
+//! system-owned account holding lamports, so releases are system transfers signed by
+//! the vault PDA. The requester funds the vault with the escrow amount plus whatever it
+//! lacks of the rent-exempt minimum for an empty account. That reserve is not part of
+//! `escrow.amount`: it stays in the vault while the escrow is open, so claims never
+//! leave the vault rent-paying, and goes back to the requester when the vault is swept.
+
+#![allow(deprecated)] // system_instruction deprecation - will migrate when solana_system_interface is stable
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    rent::Rent,
+    system_instruction, system_program,
+};
+
+use crate::error::EscrowError;
+
+/// Check that `program` is the system program, which moves native SOL.
+pub fn check_system_program(program: &AccountInfo) -> ProgramResult {
+    if *program.key != system_program::id() {
+        return Err(EscrowError::InvalidTokenProgram.into());
+    }
+    Ok(())
+}
+
+/// Lamports `vault` needs on top of the escrowed amount to be rent exempt.
+pub fn rent_reserve(vault: &AccountInfo, rent: &Rent) -> u64 {
+    rent.minimum_balance(0).saturating_sub(vault.lamports())
+}
+
+/// Move `amount` lamports plus the vault's rent reserve from `requester` into `vault`.
+pub fn deposit<'a>(
+    system_program: &AccountInfo<'a>,
+    requester: &AccountInfo<'a>,
+    vault: &AccountInfo<'a>,
+    amount: u64,
+    rent: &Rent,
+) -> ProgramResult {
+    check_system_program(system_program)?;
+    let lamports = amount
+        .checked_add(rent_reserve(vault, rent))
+        .ok_or(EscrowError::InvalidAmount)?;
+    invoke(
+        &system_instruction::transfer(requester.key, vault.key, lamports),
+        &[requester.clone(), vault.clone(), system_program.clone()],
+    )
+}
+
+/// Transfer `amount` lamports from `vault` to `destination`, signed by the vault PDA.
+pub fn transfer<'a>(
+    system_program: &AccountInfo<'a>,
+    vault: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    amount: u64,
+    vault_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    check_system_program(system_program)?;
+    invoke_signed(
+        &system_instruction::transfer(vault.key, destination.key, amount),
+        &[vault.clone(), destination.clone(), system_program.clone()],
+        vault_seeds,
+    )
+}
+
+/// Empty `vault` into `destination`, including its rent reserve.
+pub fn close_vault<'a>(
+    system_program: &AccountInfo<'a>,
+    vault: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    vault_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let lamports = vault.lamports();
+    if lamports == 0 {
+        return Ok(());
+    }
+    transfer(system_program, vault, destination, lamports, vault_seeds)
+}
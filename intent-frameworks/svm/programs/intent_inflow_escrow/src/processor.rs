@@ -10,20 +10,23 @@ use solana_program::{
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
     system_instruction,
     sysvar::Sysvar,
 };
-use spl_token::state::Account as TokenAccount;
-
 use gmp_common::messages::{EscrowConfirmation, FulfillmentProof, IntentRequirements};
 
 use crate::{
     error::EscrowError,
+    events::EscrowEvent,
     instruction::EscrowInstruction,
-    state::{seeds, Escrow, EscrowState, GmpConfig, StoredIntentRequirements},
+    native,
+    state::{
+        seeds, Escrow, EscrowMode, EscrowState, GmpConfig, StoredIntentRequirements,
+        NATIVE_SOL_MINT,
+    },
+    token,
 };
 
 pub struct Processor;
@@ -88,18 +91,40 @@ impl Processor {
             EscrowInstruction::CreateEscrow {
                 intent_id,
                 amount,
+                memo,
+                mode,
             } => {
                 msg!("Instruction: CreateEscrow");
-                Self::process_create_escrow(program_id, accounts, intent_id, amount)
+                Self::process_create_escrow(program_id, accounts, intent_id, amount, memo, mode)
             }
             EscrowInstruction::Claim { intent_id } => {
                 msg!("Instruction: Claim - intent_id={:?}", &intent_id[..8]);
                 Self::process_claim(program_id, accounts, intent_id)
             }
+            EscrowInstruction::SweepExpired { intent_id } => {
+                msg!("Instruction: SweepExpired - intent_id={:?}", &intent_id[..8]);
+                Self::process_sweep_expired(program_id, accounts, intent_id)
+            }
+            EscrowInstruction::SetApprover { new_approver } => {
+                msg!("Instruction: SetApprover");
+                Self::process_set_approver(program_id, accounts, new_approver)
+            }
             EscrowInstruction::Cancel { intent_id } => {
                 msg!("Instruction: Cancel");
                 Self::process_cancel(program_id, accounts, intent_id)
             }
+            EscrowInstruction::MigrateState => {
+                msg!("Instruction: MigrateState");
+                Self::process_migrate_state(program_id, accounts)
+            }
+            EscrowInstruction::ReduceEscrow { intent_id, new_amount } => {
+                msg!(
+                    "Instruction: ReduceEscrow - intent_id={:?}, new_amount={}",
+                    &intent_id[..8],
+                    new_amount
+                );
+                Self::process_reduce_escrow(program_id, accounts, intent_id, new_amount)
+            }
             EscrowInstruction::GmpReceiveRequirements {
                 src_chain_id,
                 remote_gmp_endpoint_addr,
@@ -141,6 +166,10 @@ impl Processor {
         let payer = next_account_info(account_info_iter)?;
         let system_program = next_account_info(account_info_iter)?;
 
+        if !payer.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
         // Derive state PDA
         let (state_pda, state_bump) =
             Pubkey::find_program_address(&[seeds::STATE_SEED], program_id);
@@ -166,10 +195,103 @@ impl Processor {
         )?;
 
         // Initialize state
-        let state = EscrowState::new(approver);
+        let state = EscrowState::new(approver, *payer.key);
+        state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
+
+        msg!(
+            "Escrow program initialized with approver: {}, admin: {}",
+            approver,
+            payer.key
+        );
+        Ok(())
+    }
+
+    /// Process SetApprover: the admin replaces the stored approver.
+    fn process_set_approver(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_approver: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let state_account = next_account_info(account_info_iter)?;
+        let admin = next_account_info(account_info_iter)?;
+
+        if !admin.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], program_id);
+        if state_pda != *state_account.key || state_account.owner != program_id {
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        let mut state = EscrowState::try_from_account_data(&state_account.data.borrow())?;
+        if state.admin != *admin.key {
+            return Err(EscrowError::UnauthorizedCaller.into());
+        }
+
+        let old_approver = state.approver;
+        state.approver = new_approver;
+        state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
+
+        msg!("Approver rotated: {} -> {}", old_approver, new_approver);
+        EscrowEvent::ApproverRotated {
+            old_approver,
+            new_approver,
+            admin: *admin.key,
+            timestamp: Clock::get()?.unix_timestamp,
+        }
+        .emit();
+        Ok(())
+    }
+
+    /// Process MigrateState: grow a legacy state account and record the GMP config admin as its admin.
+    fn process_migrate_state(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let state_account = next_account_info(account_info_iter)?;
+        let gmp_config_account = next_account_info(account_info_iter)?;
+        let admin = next_account_info(account_info_iter)?;
+        let system_program = next_account_info(account_info_iter)?;
+
+        if !admin.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], program_id);
+        if state_pda != *state_account.key || state_account.owner != program_id {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        let (config_pda, _) = Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], program_id);
+        if config_pda != *gmp_config_account.key || gmp_config_account.owner != program_id {
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        let config = GmpConfig::try_from_account_data(&gmp_config_account.data.borrow())?;
+        if config.admin != *admin.key {
+            return Err(EscrowError::UnauthorizedCaller.into());
+        }
+
+        if state_account.data_len() != EscrowState::LEGACY_LEN {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        let mut state = EscrowState::try_from_account_data(&state_account.data.borrow())?;
+
+        let rent = Rent::get()?;
+        let shortfall = rent
+            .minimum_balance(EscrowState::LEN)
+            .saturating_sub(state_account.lamports());
+        if shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(admin.key, state_account.key, shortfall),
+                &[admin.clone(), state_account.clone(), system_program.clone()],
+            )?;
+        }
+        state_account.resize(EscrowState::LEN)?;
+
+        state.admin = *admin.key;
         state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
 
-        msg!("Escrow program initialized with approver: {}", approver);
+        msg!("State migrated, admin: {}", admin.key);
         Ok(())
     }
 
@@ -266,6 +388,8 @@ impl Processor {
         accounts: &[AccountInfo],
         intent_id: [u8; 32],
         amount: u64,
+        memo: [u8; 32],
+        mode: EscrowMode,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let escrow_account = next_account_info(account_info_iter)?;
@@ -284,12 +408,24 @@ impl Processor {
         if amount == 0 {
             return Err(EscrowError::InvalidAmount.into());
         }
-        if *reserved_solver.key == Pubkey::default() {
-            return Err(EscrowError::InvalidSolver.into());
-        }
+        // Open escrows have no reserved solver; the account slot is kept for a stable layout
+        let reserved_solver = match mode {
+            EscrowMode::Reserved if *reserved_solver.key == Pubkey::default() => {
+                return Err(EscrowError::InvalidSolver.into());
+            }
+            EscrowMode::Reserved => *reserved_solver.key,
+            EscrowMode::Open => Pubkey::default(),
+        };
         if !requester.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
+        // Native SOL escrows pass the system program as both the mint and the token program
+        let is_native = *token_mint.key == NATIVE_SOL_MINT;
+        if is_native {
+            native::check_system_program(token_program)?;
+        } else {
+            token::check_token_program(token_program, token_mint)?;
+        }
 
         // Validate requirements account PDA
         let (req_pda, _) = Pubkey::find_program_address(
@@ -333,7 +469,7 @@ impl Processor {
         // Check if escrow already exists
         if escrow_account.data_len() > 0 {
             // Account exists, try to deserialize it
-            if let Ok(existing_escrow) = Escrow::try_from_slice(&escrow_account.data.borrow()) {
+            if let Ok(existing_escrow) = Escrow::unpack(&escrow_account.data.borrow()) {
                 // Check if it's a valid escrow (has correct discriminator)
                 if existing_escrow.discriminator == Escrow::DISCRIMINATOR {
                     return Err(EscrowError::EscrowAlreadyExists.into());
@@ -371,51 +507,56 @@ impl Processor {
             &[&[seeds::ESCROW_SEED, &intent_id, &[escrow_bump]]],
         )?;
 
-        // Create vault token account
-        let vault_space = TokenAccount::LEN;
-        let vault_lamports = rent.minimum_balance(vault_space);
+        let amount = if is_native {
+            // The vault stays system-owned and holds the lamports plus its rent reserve
+            native::deposit(system_program, requester, escrow_vault, amount, &rent)?;
+            amount
+        } else {
+            // Create vault token account, sized for the mint's token program and extensions
+            let vault_space = token::vault_account_len(token_program, token_mint)?;
+            let vault_lamports = rent.minimum_balance(vault_space);
 
-        invoke_signed(
-            &system_instruction::create_account(
-                requester.key,
-                escrow_vault.key,
-                vault_lamports,
-                vault_space as u64,
-                &spl_token::id(),
-            ),
-            &[requester.clone(), escrow_vault.clone(), system_program.clone()],
-            &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
-        )?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    requester.key,
+                    escrow_vault.key,
+                    vault_lamports,
+                    vault_space as u64,
+                    token_program.key,
+                ),
+                &[requester.clone(), escrow_vault.clone(), system_program.clone()],
+                &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
+            )?;
 
-        // Initialize vault token account
-        invoke_signed(
-            &spl_token::instruction::initialize_account3(
-                &spl_token::id(),
-                escrow_vault.key,
-                token_mint.key,
-                escrow_account.key, // escrow PDA is the authority
-            )?,
-            &[escrow_vault.clone(), token_mint.clone()],
-            &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
-        )?;
+            // Initialize vault token account
+            invoke_signed(
+                &spl_token_2022::instruction::initialize_account3(
+                    token_program.key,
+                    escrow_vault.key,
+                    token_mint.key,
+                    escrow_account.key, // escrow PDA is the authority
+                )?,
+                &[escrow_vault.clone(), token_mint.clone(), token_program.clone()],
+                &[&[seeds::VAULT_SEED, &intent_id, &[vault_bump]]],
+            )?;
 
-        // Transfer tokens to vault
-        invoke(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                requester_token_account.key,
-                escrow_vault.key,
-                requester.key,
-                &[],
+            // Transfer tokens to vault
+            token::transfer(
+                token_program,
+                requester_token_account,
+                escrow_vault,
+                requester,
+                Some(token_mint),
                 amount,
-            )?,
-            &[
-                requester_token_account.clone(),
-                escrow_vault.clone(),
-                requester.clone(),
-                token_program.clone(),
-            ],
-        )?;
+                &[],
+            )?;
+
+            // Transfer-fee mints withhold part of the transfer; the escrow holds what arrived
+            token::token_balance(escrow_vault)?
+        };
+        if amount < requirements.amount_required {
+            return Err(EscrowError::AmountMismatch.into());
+        }
 
         // Initialize escrow state
         let escrow = Escrow::new(
@@ -423,11 +564,13 @@ impl Processor {
             *token_mint.key,
             amount,
             expiry,
-            *reserved_solver.key,
+            reserved_solver,
             intent_id,
             escrow_bump,
+            memo,
+            mode,
         );
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         // Mark requirements as having escrow created and send EscrowConfirmation
         {
@@ -521,11 +664,24 @@ impl Processor {
             amount,
             expiry
         );
+        EscrowEvent::EscrowCreated {
+            intent_id,
+            requester: *requester.key,
+            token_mint: *token_mint.key,
+            amount,
+            reserved_solver,
+            expiry,
+            timestamp: clock.unix_timestamp,
+        }
+        .emit();
         Ok(())
     }
 
     /// Process Claim instruction (GMP mode - no signature required).
-    /// Requires that the fulfillment proof has been received via GMP.
+    /// Requires that the fulfillment proof has been received via GMP, and for
+    /// `Reserved` escrows a solver token account owned by the reserved solver.
+    /// `Open` escrows are gated only by `requirements.fulfilled`; the proof that sets
+    /// it also releases the escrow, so this path is unreachable for them in practice.
     fn process_claim(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
@@ -537,6 +693,7 @@ impl Processor {
         let escrow_vault = next_account_info(account_info_iter)?;
         let solver_token_account = next_account_info(account_info_iter)?;
         let token_program = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter).ok();
 
         // Validate requirements PDA
         let (req_pda, _) = Pubkey::find_program_address(
@@ -558,7 +715,7 @@ impl Processor {
         }
 
         // Deserialize escrow
-        let mut escrow = Escrow::try_from_slice(&escrow_account.data.borrow())?;
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
 
         // Validate escrow
         if escrow.intent_id != intent_id {
@@ -570,40 +727,42 @@ impl Processor {
         if escrow.amount == 0 {
             return Err(EscrowError::NoDeposit.into());
         }
+        Self::check_escrow_mint(&escrow, token_mint)?;
+
+        let solver = Self::check_release_recipient(&escrow, solver_token_account)?;
 
         let clock = Clock::get()?;
         if clock.unix_timestamp > escrow.expiry {
             return Err(EscrowError::EscrowExpired.into());
         }
 
-        // Transfer tokens from vault to solver
+        // Transfer funds from vault to solver
         let amount = escrow.amount;
-        let escrow_seeds = &[seeds::ESCROW_SEED, &intent_id[..], &[escrow.bump]];
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                escrow_vault.key,
-                solver_token_account.key,
-                escrow_account.key,
-                &[],
-                amount,
-            )?,
-            &[
-                escrow_vault.clone(),
-                solver_token_account.clone(),
-                escrow_account.clone(),
-                token_program.clone(),
-            ],
-            &[escrow_seeds],
+        Self::release_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            solver_token_account,
+            token_program,
+            token_mint,
+            amount,
         )?;
 
         // Update escrow state
         escrow.is_claimed = true;
         escrow.amount = 0;
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         msg!("Escrow claimed: intent_id={:?}, amount={}", &intent_id[..8], amount);
+        EscrowEvent::EscrowClaimed {
+            intent_id,
+            solver,
+            amount,
+            remaining: 0,
+            timestamp: clock.unix_timestamp,
+        }
+        .emit();
         Ok(())
     }
 
@@ -619,9 +778,10 @@ impl Processor {
         let requester_token_account = next_account_info(account_info_iter)?;
         let token_program = next_account_info(account_info_iter)?;
         let gmp_config_account = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter).ok();
 
         // Deserialize escrow
-        let mut escrow = Escrow::try_from_slice(&escrow_account.data.borrow())?;
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
 
         // Validate
         if escrow.intent_id != intent_id {
@@ -636,6 +796,7 @@ impl Processor {
         if !caller.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
+        Self::check_escrow_mint(&escrow, token_mint)?;
 
         // Verify caller is admin (only admin can cancel expired escrows)
         let (config_pda, _) =
@@ -654,34 +815,157 @@ impl Processor {
             return Err(EscrowError::EscrowNotExpiredYet.into());
         }
 
-        // Transfer tokens back to requester
-        let amount = escrow.amount;
-        let escrow_seeds = &[seeds::ESCROW_SEED, &intent_id[..], &[escrow.bump]];
+        // Native SOL goes straight to the requester's wallet
+        if escrow.mint().is_none() && *requester_token_account.key != escrow.requester {
+            return Err(EscrowError::InvalidRefundRecipient.into());
+        }
 
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                escrow_vault.key,
-                requester_token_account.key,
-                escrow_account.key,
-                &[],
-                amount,
-            )?,
-            &[
-                escrow_vault.clone(),
-                requester_token_account.clone(),
-                escrow_account.clone(),
-                token_program.clone(),
-            ],
-            &[escrow_seeds],
+        // Transfer funds back to requester
+        let amount = escrow.amount;
+        Self::release_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            requester_token_account,
+            token_program,
+            token_mint,
+            amount,
         )?;
 
         // Update escrow state
         escrow.is_claimed = true;
         escrow.amount = 0;
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         msg!("Escrow cancelled: intent_id={:?}, amount={}", &intent_id[..8], amount);
+        EscrowEvent::EscrowCancelled {
+            intent_id,
+            requester: escrow.requester,
+            amount,
+            timestamp: clock.unix_timestamp,
+        }
+        .emit();
+        Ok(())
+    }
+
+    fn process_reduce_escrow(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        intent_id: [u8; 32],
+        new_amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let escrow_account = next_account_info(account_info_iter)?;
+        let requester = next_account_info(account_info_iter)?;
+        let escrow_vault = next_account_info(account_info_iter)?;
+        let requester_token_account = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let requirements_account = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter).ok();
+
+        // Validate PDAs
+        let (escrow_pda, _) =
+            Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], program_id);
+        if escrow_pda != *escrow_account.key || escrow_account.owner != program_id {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        let (req_pda, _) = Pubkey::find_program_address(
+            &[seeds::REQUIREMENTS_SEED, &intent_id],
+            program_id,
+        );
+        if req_pda != *requirements_account.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        // Deserialize escrow
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
+
+        // Validate
+        if escrow.intent_id != intent_id {
+            return Err(EscrowError::EscrowDoesNotExist.into());
+        }
+        if escrow.is_claimed {
+            return Err(EscrowError::EscrowAlreadyClaimed.into());
+        }
+        if !requester.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if *requester.key != escrow.requester {
+            return Err(EscrowError::UnauthorizedCaller.into());
+        }
+        if new_amount == 0 || new_amount == escrow.amount {
+            return Err(EscrowError::InvalidAmount.into());
+        }
+        if new_amount > escrow.amount {
+            return Err(EscrowError::EscrowIncreaseNotAllowed.into());
+        }
+        Self::check_escrow_mint(&escrow, token_mint)?;
+
+        // Once the fulfillment proof arrived the balance is owed to the solver, and the
+        // hub was told the intent is funded with at least `amount_required`
+        let requirements =
+            StoredIntentRequirements::try_from_slice(&requirements_account.data.borrow())
+                .map_err(|_| EscrowError::RequirementsNotFound)?;
+        if requirements.fulfilled {
+            return Err(EscrowError::AlreadyFulfilled.into());
+        }
+        if new_amount < requirements.amount_required {
+            return Err(EscrowError::AmountMismatch.into());
+        }
+
+        let clock = Clock::get()?;
+        if clock.unix_timestamp > escrow.expiry {
+            return Err(EscrowError::EscrowExpired.into());
+        }
+
+        // The excess may only go back to the requester
+        match escrow.mint() {
+            None => {
+                if *requester_token_account.key != escrow.requester {
+                    return Err(EscrowError::InvalidRefundRecipient.into());
+                }
+            }
+            Some(mint) => {
+                if requester_token_account.owner != token_program.key {
+                    return Err(EscrowError::InvalidRefundRecipient.into());
+                }
+                let requester_token = token::unpack_token_account(requester_token_account)?;
+                if requester_token.owner != escrow.requester || requester_token.mint != mint {
+                    return Err(EscrowError::InvalidRefundRecipient.into());
+                }
+            }
+        }
+
+        let amount = escrow.amount - new_amount;
+        Self::release_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            requester_token_account,
+            token_program,
+            token_mint,
+            amount,
+        )?;
+
+        escrow.amount = new_amount;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
+
+        msg!(
+            "Escrow reduced: intent_id={:?}, amount={}, remaining={}",
+            &intent_id[..8],
+            amount,
+            escrow.amount
+        );
+        EscrowEvent::EscrowReduced {
+            intent_id,
+            requester: escrow.requester,
+            amount,
+            remaining: escrow.amount,
+            timestamp: clock.unix_timestamp,
+        }
+        .emit();
         Ok(())
     }
 
@@ -814,8 +1098,9 @@ impl Processor {
         let gmp_config_account = next_account_info(account_info_iter)?;
         let gmp_caller = next_account_info(account_info_iter)?;
         let token_program = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter).ok();
 
-        // GMP caller must be a signer (trusted relay or endpoint)
+        // GMP caller must be a signer (the GMP endpoint, checked below)
         if !gmp_caller.is_signer {
             return Err(EscrowError::UnauthorizedGmpSource.into());
         }
@@ -830,6 +1115,15 @@ impl Processor {
         let config = GmpConfig::try_from_slice(&gmp_config_account.data.borrow())
             .map_err(|_| EscrowError::AccountNotInitialized)?;
 
+        // The proof releases funds, so it must come through the configured GMP endpoint,
+        // which signs its CPI with its GMP signer PDA
+        let (gmp_signer, _) =
+            Pubkey::find_program_address(&[seeds::GMP_SIGNER_SEED], &config.gmp_endpoint);
+        if *gmp_caller.key != gmp_signer {
+            msg!("Invalid GMP caller: not the GMP endpoint signer");
+            return Err(EscrowError::UnauthorizedGmpSource.into());
+        }
+
         // Validate source chain matches hub GMP endpoint
         if src_chain_id != config.hub_chain_id {
             msg!(
@@ -868,7 +1162,7 @@ impl Processor {
         }
 
         // Load escrow
-        let mut escrow = Escrow::try_from_slice(&escrow_account.data.borrow())?;
+        let mut escrow = Escrow::unpack(&escrow_account.data.borrow())?;
 
         if escrow.intent_id != proof.intent_id {
             return Err(EscrowError::EscrowDoesNotExist.into());
@@ -879,33 +1173,30 @@ impl Processor {
         if escrow.amount == 0 {
             return Err(EscrowError::NoDeposit.into());
         }
+        Self::check_escrow_mint(&escrow, token_mint)?;
+        let solver = Self::check_release_recipient(&escrow, solver_token_account)?;
+        // Open escrows reserve nobody, so the hub's proof decides who is paid
+        if escrow.mode == EscrowMode::Open && solver != Pubkey::new_from_array(proof.solver_addr) {
+            return Err(EscrowError::InvalidSolver.into());
+        }
 
-        // Transfer tokens from vault to solver
+        // Transfer funds from vault to solver
         let amount = escrow.amount;
-        let escrow_seeds = &[seeds::ESCROW_SEED, &proof.intent_id[..], &[escrow.bump]];
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                escrow_vault.key,
-                solver_token_account.key,
-                escrow_account.key,
-                &[],
-                amount,
-            )?,
-            &[
-                escrow_vault.clone(),
-                solver_token_account.clone(),
-                escrow_account.clone(),
-                token_program.clone(),
-            ],
-            &[escrow_seeds],
+        Self::release_from_vault(
+            program_id,
+            &escrow,
+            escrow_account,
+            escrow_vault,
+            solver_token_account,
+            token_program,
+            token_mint,
+            amount,
         )?;
 
         // Update states
         escrow.is_claimed = true;
         escrow.amount = 0;
-        escrow.serialize(&mut &mut escrow_account.data.borrow_mut()[..])?;
+        escrow.pack(&mut escrow_account.data.borrow_mut())?;
 
         requirements.fulfilled = true;
         requirements.serialize(&mut &mut requirements_account.data.borrow_mut()[..])?;
@@ -915,6 +1206,184 @@ impl Processor {
             &proof.intent_id[..8],
             amount
         );
+        EscrowEvent::EscrowClaimed {
+            intent_id: proof.intent_id,
+            solver,
+            amount,
+            remaining: 0,
+            timestamp: Clock::get()?.unix_timestamp,
+        }
+        .emit();
         Ok(())
     }
+
+    /// Process SweepExpired: return an expired escrow's balance and rent to the requester.
+    /// Permissionless; the recipient is fixed to the requester, not the caller.
+    fn process_sweep_expired(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        intent_id: [u8; 32],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let escrow_account = next_account_info(account_info_iter)?;
+        let escrow_vault = next_account_info(account_info_iter)?;
+        let requester_token_account = next_account_info(account_info_iter)?;
+        let requester = next_account_info(account_info_iter)?;
+        let token_program = next_account_info(account_info_iter)?;
+        let token_mint = next_account_info(account_info_iter).ok();
+
+        // Validate PDAs
+        let (escrow_pda, _) =
+            Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], program_id);
+        if escrow_pda != *escrow_account.key || escrow_account.owner != program_id {
+            return Err(EscrowError::InvalidPda.into());
+        }
+        let (vault_pda, vault_bump) =
+            Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], program_id);
+        if vault_pda != *escrow_vault.key {
+            return Err(EscrowError::InvalidPda.into());
+        }
+
+        // Deserialize escrow
+        let escrow = Escrow::unpack(&escrow_account.data.borrow())?;
+
+        // Validate
+        if escrow.intent_id != intent_id {
+            return Err(EscrowError::EscrowDoesNotExist.into());
+        }
+        if escrow.is_claimed {
+            return Err(EscrowError::EscrowAlreadyClaimed.into());
+        }
+        Self::check_escrow_mint(&escrow, token_mint)?;
+
+        let clock = Clock::get()?;
+        if clock.unix_timestamp <= escrow.expiry {
+            return Err(EscrowError::EscrowNotExpiredYet.into());
+        }
+
+        // Funds and rent may only go to the requester, whoever submits the sweep
+        if *requester.key != escrow.requester {
+            return Err(EscrowError::InvalidSweepRecipient.into());
+        }
+        let amount = escrow.amount;
+        if escrow.mint().is_none() {
+            // Native SOL: the requester wallet receives the balance and the vault's rent reserve
+            if *requester_token_account.key != escrow.requester {
+                return Err(EscrowError::InvalidSweepRecipient.into());
+            }
+            let vault_seeds = &[seeds::VAULT_SEED, &intent_id[..], &[vault_bump]];
+            native::close_vault(token_program, escrow_vault, requester, &[vault_seeds])?;
+        } else {
+            if requester_token_account.owner != token_program.key {
+                return Err(EscrowError::InvalidSweepRecipient.into());
+            }
+            let requester_token = token::unpack_token_account(requester_token_account)?;
+            if requester_token.owner != escrow.requester || requester_token.mint != escrow.token_mint {
+                return Err(EscrowError::InvalidSweepRecipient.into());
+            }
+
+            // Return the vault balance, then close the vault
+            let escrow_seeds = &[seeds::ESCROW_SEED, &intent_id[..], &[escrow.bump]];
+            if amount > 0 {
+                token::transfer(
+                    token_program,
+                    escrow_vault,
+                    requester_token_account,
+                    escrow_account,
+                    token_mint,
+                    amount,
+                    &[escrow_seeds],
+                )?;
+            }
+            token::close_account(
+                token_program,
+                escrow_vault,
+                requester,
+                escrow_account,
+                token_mint,
+                &[escrow_seeds],
+            )?;
+        }
+
+        // Close the escrow account
+        let escrow_lamports = escrow_account.lamports();
+        **escrow_account.lamports.borrow_mut() = 0;
+        **requester.lamports.borrow_mut() = requester
+            .lamports()
+            .checked_add(escrow_lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        escrow_account.data.borrow_mut().fill(0);
+
+        msg!("Escrow swept: intent_id={:?}, amount={}", &intent_id[..8], amount);
+        EscrowEvent::EscrowCancelled {
+            intent_id,
+            requester: escrow.requester,
+            amount,
+            timestamp: clock.unix_timestamp,
+        }
+        .emit();
+        Ok(())
+    }
+
+    /// Move `amount` out of an escrow's vault to `destination`: tokens signed by the escrow
+    /// PDA, or lamports signed by the vault PDA for native SOL escrows.
+    #[allow(clippy::too_many_arguments)]
+    fn release_from_vault<'a>(
+        program_id: &Pubkey,
+        escrow: &Escrow,
+        escrow_account: &AccountInfo<'a>,
+        escrow_vault: &AccountInfo<'a>,
+        destination: &AccountInfo<'a>,
+        token_program: &AccountInfo<'a>,
+        token_mint: Option<&AccountInfo<'a>>,
+        amount: u64,
+    ) -> ProgramResult {
+        if escrow.mint().is_none() {
+            let (vault_pda, vault_bump) = Pubkey::find_program_address(
+                &[seeds::VAULT_SEED, &escrow.intent_id],
+                program_id,
+            );
+            if vault_pda != *escrow_vault.key {
+                return Err(EscrowError::InvalidPda.into());
+            }
+            let vault_seeds = &[seeds::VAULT_SEED, &escrow.intent_id[..], &[vault_bump]];
+            return native::transfer(token_program, escrow_vault, destination, amount, &[vault_seeds]);
+        }
+
+        let escrow_seeds = &[seeds::ESCROW_SEED, &escrow.intent_id[..], &[escrow.bump]];
+        token::transfer(
+            token_program,
+            escrow_vault,
+            destination,
+            escrow_account,
+            token_mint,
+            amount,
+            &[escrow_seeds],
+        )
+    }
+
+    /// Return the solver a release pays: the solver token account's owner, or the wallet
+    /// itself for native SOL. Reserved escrows pay out only to the reserved solver; open
+    /// escrows to any solver.
+    fn check_release_recipient(
+        escrow: &Escrow,
+        solver_token_account: &AccountInfo,
+    ) -> Result<Pubkey, ProgramError> {
+        let solver = match escrow.mint() {
+            Some(_) => token::unpack_token_account(solver_token_account)?.owner,
+            None => *solver_token_account.key,
+        };
+        if escrow.mode == EscrowMode::Reserved && solver != escrow.reserved_solver {
+            return Err(EscrowError::InvalidSolver.into());
+        }
+        Ok(solver)
+    }
+
+    /// Check that an optional mint account passed for a vault transfer is the escrow's mint.
+    fn check_escrow_mint(escrow: &Escrow, token_mint: Option<&AccountInfo>) -> ProgramResult {
+        match token_mint {
+            Some(mint) if *mint.key != escrow.token_mint => Err(EscrowError::TokenMismatch.into()),
+            _ => Ok(()),
+        }
+    }
 }
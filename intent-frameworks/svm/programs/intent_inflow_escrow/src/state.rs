@@ -3,6 +3,20 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::error::EscrowError;
+
+/// Deserialize a fixed-size account after checking its length and discriminator.
+fn parse_account<T: BorshDeserialize>(
+    data: &[u8],
+    discriminator: &[u8; 8],
+    len: usize,
+) -> Result<T, EscrowError> {
+    if data.len() < len || data[..8] != discriminator[..] {
+        return Err(EscrowError::InvalidAccountData);
+    }
+    T::try_from_slice(&data[..len]).map_err(|_| EscrowError::InvalidAccountData)
+}
+
 /// Global escrow state containing the authorized approver
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct EscrowState {
@@ -10,20 +24,52 @@ pub struct EscrowState {
     pub discriminator: [u8; 8],
     /// Authorized approver public key that can approve releases
     pub approver: Pubkey,
+    /// Account that initialized the program; the only signer allowed to rotate the approver
+    pub admin: Pubkey,
 }
 
 impl EscrowState {
     pub const DISCRIMINATOR: [u8; 8] = [0x45, 0x53, 0x43, 0x52, 0x4f, 0x57, 0x53, 0x54]; // "ESCROWST"
-    pub const LEN: usize = 8 + 32; // discriminator + approver pubkey
+    pub const LEN: usize = 8 + 32 + 32; // discriminator + approver pubkey + admin pubkey
+    /// Size of state accounts created before the admin was recorded.
+    /// The admin was appended, so the legacy layout is a prefix; `MigrateState` grows it.
+    pub const LEGACY_LEN: usize = 8 + 32; // discriminator + approver pubkey
+
+    /// Parse account data, rejecting wrong-length or wrong-discriminator accounts.
+    ///
+    /// Legacy 40-byte states parse with an all-zero admin, which no signer matches.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, EscrowError> {
+        if data.len() == Self::LEGACY_LEN {
+            let mut padded = [0u8; Self::LEN];
+            padded[..Self::LEGACY_LEN].copy_from_slice(data);
+            return parse_account(&padded, &Self::DISCRIMINATOR, Self::LEN);
+        }
+        parse_account(data, &Self::DISCRIMINATOR, Self::LEN)
+    }
 
-    pub fn new(approver: Pubkey) -> Self {
+    pub fn new(approver: Pubkey, admin: Pubkey) -> Self {
         Self {
             discriminator: Self::DISCRIMINATOR,
             approver,
+            admin,
         }
     }
 }
 
+/// Who may claim an escrow once its intent is fulfilled
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EscrowMode {
+    /// Only token accounts owned by `reserved_solver` may receive the funds
+    #[default]
+    Reserved,
+    /// First come: any solver token account may receive the funds
+    Open,
+}
+
+/// Mint stored on native SOL escrows: the all-zero key (the system program ID), which is
+/// also the token address the hub sends for native SOL.
+pub const NATIVE_SOL_MINT: Pubkey = Pubkey::new_from_array([0; 32]);
+
 /// Escrow data structure (matches EVM Escrow struct)
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Escrow {
@@ -31,7 +77,7 @@ pub struct Escrow {
     pub discriminator: [u8; 8],
     /// Requester who deposited funds
     pub requester: Pubkey,
-    /// SPL token mint address
+    /// SPL token mint address ([`NATIVE_SOL_MINT`] for native SOL; see [`Escrow::mint`])
     pub token_mint: Pubkey,
     /// Amount deposited
     pub amount: u64,
@@ -45,12 +91,59 @@ pub struct Escrow {
     pub intent_id: [u8; 32],
     /// PDA bump seed
     pub bump: u8,
+    /// Client correlation tag supplied at creation (zeros = none, informational only)
+    pub memo: [u8; 32],
+    /// Claim mode chosen at creation
+    pub mode: EscrowMode,
 }
 
 impl Escrow {
     pub const DISCRIMINATOR: [u8; 8] = [0x45, 0x53, 0x43, 0x52, 0x4f, 0x57, 0x44, 0x41]; // "ESCROWDA"
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1; // 154 bytes
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1 + 32 + 1; // 187 bytes
+    /// Size of escrow accounts created before the memo and claim mode were added.
+    /// Both were appended, so the legacy layout is a prefix.
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 32 + 1; // 154 bytes
 
+    /// Parse account data, rejecting wrong-length or wrong-discriminator accounts.
+    ///
+    /// Legacy 154-byte escrows parse with an all-zero memo in `Reserved` mode.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, EscrowError> {
+        if data.len() == Self::LEGACY_LEN {
+            let mut padded = [0u8; Self::LEN];
+            padded[..Self::LEGACY_LEN].copy_from_slice(data);
+            return parse_account(&padded, &Self::DISCRIMINATOR, Self::LEN);
+        }
+        parse_account(data, &Self::DISCRIMINATOR, Self::LEN)
+    }
+
+    /// Decode an escrow account, reading legacy 154-byte accounts with an all-zero memo
+    /// in `Reserved` mode.
+    pub fn unpack(data: &[u8]) -> std::io::Result<Self> {
+        if data.len() == Self::LEGACY_LEN {
+            let mut padded = [0u8; Self::LEN];
+            padded[..Self::LEGACY_LEN].copy_from_slice(data);
+            return Self::try_from_slice(&padded);
+        }
+        Self::try_from_slice(data)
+    }
+
+    /// Encode into account data, keeping the legacy layout for 154-byte accounts.
+    ///
+    /// The memo and mode are only set at creation, so a legacy escrow never holds
+    /// a memo or the `Open` mode.
+    pub fn pack(&self, data: &mut [u8]) -> std::io::Result<()> {
+        let bytes = self.try_to_vec()?;
+        if data.len() == Self::LEGACY_LEN {
+            if bytes[Self::LEGACY_LEN..].iter().any(|b| *b != 0) {
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+            data.copy_from_slice(&bytes[..Self::LEGACY_LEN]);
+            return Ok(());
+        }
+        self.serialize(&mut &mut data[..])
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         requester: Pubkey,
         token_mint: Pubkey,
@@ -59,6 +152,8 @@ impl Escrow {
         reserved_solver: Pubkey,
         intent_id: [u8; 32],
         bump: u8,
+        memo: [u8; 32],
+        mode: EscrowMode,
     ) -> Self {
         Self {
             discriminator: Self::DISCRIMINATOR,
@@ -70,8 +165,15 @@ impl Escrow {
             reserved_solver,
             intent_id,
             bump,
+            memo,
+            mode,
         }
     }
+
+    /// Mint of the escrowed token, or `None` for a native SOL escrow.
+    pub fn mint(&self) -> Option<Pubkey> {
+        (self.token_mint != NATIVE_SOL_MINT).then_some(self.token_mint)
+    }
 }
 
 /// Stored intent requirements received via GMP from the hub
@@ -103,6 +205,11 @@ impl StoredIntentRequirements {
     pub const DISCRIMINATOR: [u8; 8] = [0x49, 0x4e, 0x54, 0x52, 0x45, 0x51, 0x53, 0x54]; // "INTREQST"
     pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 32 + 8 + 1 + 1 + 1; // 155 bytes
 
+    /// Parse account data, rejecting wrong-length or wrong-discriminator accounts.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, EscrowError> {
+        parse_account(data, &Self::DISCRIMINATOR, Self::LEN)
+    }
+
     pub fn new(
         intent_id: [u8; 32],
         requester_addr: [u8; 32],
@@ -149,6 +256,11 @@ impl GmpConfig {
     pub const DISCRIMINATOR: [u8; 8] = [0x47, 0x4d, 0x50, 0x43, 0x4f, 0x4e, 0x46, 0x47]; // "GMPCONFG"
     pub const LEN: usize = 8 + 32 + 4 + 32 + 32 + 1; // 109 bytes
 
+    /// Parse account data, rejecting wrong-length or wrong-discriminator accounts.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, EscrowError> {
+        parse_account(data, &Self::DISCRIMINATOR, Self::LEN)
+    }
+
     pub fn new(
         admin: Pubkey,
         hub_chain_id: u32,
@@ -174,4 +286,6 @@ pub mod seeds {
     pub const VAULT_SEED: &[u8] = b"vault";
     pub const REQUIREMENTS_SEED: &[u8] = b"requirements";
     pub const GMP_CONFIG_SEED: &[u8] = b"gmp_config";
+    /// Seed of the GMP endpoint's CPI signer, derived under `GmpConfig::gmp_endpoint`
+    pub const GMP_SIGNER_SEED: &[u8] = b"gmp_signer";
 }
@@ -0,0 +1,190 @@
+//! Token program helpers
+//!
+//! Escrow vaults hold tokens of either the SPL Token program or Token-2022. The token
+//! program is passed in as an account and must own the mint. Transfers that have the
+//! mint available use `transfer_checked`, which Token-2022 requires for mints with
+//! extensions such as transfer fees; the classic `transfer` is only used for SPL Token
+//! when a caller omits the optional mint account.
+//!
+//! Withheld transfer fees block closing a Token-2022 account, so [`close_account`]
+//! harvests them to the mint first.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeConfig},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::{Account, Mint},
+};
+
+use crate::error::EscrowError;
+
+/// Whether `program_id` is a token program the escrow supports.
+pub fn is_supported_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == spl_token_2022::id()
+}
+
+/// Check that `token_program` is supported and owns `mint`.
+pub fn check_token_program(token_program: &AccountInfo, mint: &AccountInfo) -> ProgramResult {
+    if !is_supported_token_program(token_program.key) || mint.owner != token_program.key {
+        return Err(EscrowError::InvalidTokenProgram.into());
+    }
+    Ok(())
+}
+
+/// Decimals of `mint` (works for both token programs).
+pub fn mint_decimals(mint: &AccountInfo) -> Result<u8, ProgramError> {
+    let data = mint.data.borrow();
+    Ok(StateWithExtensions::<Mint>::unpack(&data)?.base.decimals)
+}
+
+/// Size of a token account for `mint`, including any account extensions the mint's
+/// extensions require under Token-2022 (e.g. `TransferFeeAmount` for fee mints).
+pub fn vault_account_len(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    if *token_program.key == spl_token::id() {
+        return Ok(spl_token::state::Account::LEN);
+    }
+    let data = mint.data.borrow();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&data)?;
+    let account_extensions =
+        ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+    ExtensionType::try_calculate_account_len::<Account>(&account_extensions)
+}
+
+/// Base state of `token_account` (works for both token programs).
+pub fn unpack_token_account(token_account: &AccountInfo) -> Result<Account, ProgramError> {
+    let data = token_account.data.borrow();
+    Ok(StateWithExtensions::<Account>::unpack(&data)?.base)
+}
+
+/// Token balance of `token_account` (works for both token programs).
+pub fn token_balance(token_account: &AccountInfo) -> Result<u64, ProgramError> {
+    Ok(unpack_token_account(token_account)?.amount)
+}
+
+/// Transfer `amount` from `source` to `destination`, signed by `authority`.
+///
+/// With `mint`, uses `transfer_checked` after checking the token program owns the mint.
+/// Without it, only SPL Token is accepted.
+pub fn transfer<'a>(
+    token_program: &AccountInfo<'a>,
+    source: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    mint: Option<&AccountInfo<'a>>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    match mint {
+        Some(mint) => {
+            check_token_program(token_program, mint)?;
+            let decimals = mint_decimals(mint)?;
+            invoke_signed(
+                &spl_token_2022::instruction::transfer_checked(
+                    token_program.key,
+                    source.key,
+                    mint.key,
+                    destination.key,
+                    authority.key,
+                    &[],
+                    amount,
+                    decimals,
+                )?,
+                &[
+                    source.clone(),
+                    mint.clone(),
+                    destination.clone(),
+                    authority.clone(),
+                    token_program.clone(),
+                ],
+                signer_seeds,
+            )
+        }
+        None => {
+            if *token_program.key != spl_token::id() {
+                return Err(EscrowError::InvalidTokenProgram.into());
+            }
+            invoke_signed(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    source.key,
+                    destination.key,
+                    authority.key,
+                    &[],
+                    amount,
+                )?,
+                &[
+                    source.clone(),
+                    destination.clone(),
+                    authority.clone(),
+                    token_program.clone(),
+                ],
+                signer_seeds,
+            )
+        }
+    }
+}
+
+/// Close `account`, signed by `authority`, sending its rent lamports to `destination`.
+///
+/// With `mint`, withheld Token-2022 transfer fees are harvested to the mint first (the
+/// mint must then be writable). Without it, only SPL Token is accepted.
+pub fn close_account<'a>(
+    token_program: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    mint: Option<&AccountInfo<'a>>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    match mint {
+        Some(mint) => {
+            check_token_program(token_program, mint)?;
+            if *token_program.key == spl_token_2022::id() && has_transfer_fee(mint)? {
+                invoke(
+                    &harvest_withheld_tokens_to_mint(token_program.key, mint.key, &[account.key])?,
+                    &[mint.clone(), account.clone(), token_program.clone()],
+                )?;
+            }
+        }
+        None if *token_program.key != spl_token::id() => {
+            return Err(EscrowError::InvalidTokenProgram.into());
+        }
+        None => {}
+    }
+    invoke_signed(
+        &spl_token_2022::instruction::close_account(
+            token_program.key,
+            account.key,
+            destination.key,
+            authority.key,
+            &[],
+        )?,
+        &[
+            account.clone(),
+            destination.clone(),
+            authority.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// Whether `mint` has the Token-2022 transfer fee extension.
+fn has_transfer_fee(mint: &AccountInfo) -> Result<bool, ProgramError> {
+    let data = mint.data.borrow();
+    Ok(StateWithExtensions::<Mint>::unpack(&data)?
+        .get_extension::<TransferFeeConfig>()
+        .is_ok())
+}
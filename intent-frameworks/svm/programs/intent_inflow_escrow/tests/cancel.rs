@@ -5,10 +5,10 @@ use common::{
     create_gmp_receive_fulfillment_proof_ix, create_gmp_receive_requirements_ix,
     create_set_gmp_config_ix, generate_intent_id, get_token_balance, program_test, read_escrow,
     setup_basic_env, setup_gmp_requirements, setup_gmp_requirements_custom, test_program_id,
-    DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    via_gmp_endpoint, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
-use intent_inflow_escrow::state::seeds;
+use intent_inflow_escrow::state::{seeds, Escrow, EscrowMode};
 use solana_sdk::{
     clock::Clock,
     pubkey::Pubkey,
@@ -421,7 +421,7 @@ async fn test_revert_if_already_claimed() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let claim_tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -624,3 +624,93 @@ async fn test_revert_if_already_cancelled() {
     let result = context.banks_client.process_transaction(cancel_tx2).await;
     assert!(result.is_err(), "Should fail - escrow already cancelled");
 }
+
+/// 7. Test: Cancel a Legacy-Layout Escrow
+/// Verifies that an escrow stored in the 154-byte layout from before the memo and mode were added
+/// can be cancelled after expiry, keeps its size, and refunds the requester.
+/// Why: Escrows created before the upgrade must stay cancellable instead of failing to decode.
+#[tokio::test]
+async fn test_cancel_legacy_escrow() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let clock: Clock = deserialize(&clock_account.data).unwrap();
+    let expiry = (clock.unix_timestamp as u64) + 1;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, expiry).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    // Rewrite the escrow in the legacy layout: the prefix before the memo and mode
+    let mut escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+    escrow_account.data.truncate(Escrow::LEGACY_LEN);
+    context.set_account(&escrow_pda, &escrow_account.into());
+
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let mut clock: Clock = deserialize(&clock_account.data).unwrap();
+    clock.unix_timestamp = expiry as i64 + 1;
+    context.set_sysvar(&clock);
+
+    let cancel_ix = create_cancel_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        env.requester_token,
+        escrow_pda,
+        vault_pda,
+        env.gmp_config_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let cancel_tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(cancel_tx).await.unwrap();
+
+    assert_eq!(get_token_balance(&mut context, env.requester_token).await, 1_000_000);
+    let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+    assert_eq!(escrow_account.data.len(), Escrow::LEGACY_LEN);
+    let escrow = read_escrow(&escrow_account);
+    assert!(escrow.is_claimed);
+    assert_eq!(escrow.amount, 0);
+    assert_eq!(escrow.memo, [0u8; 32]);
+    assert_eq!(escrow.mode, EscrowMode::Reserved);
+}
@@ -1,13 +1,19 @@
 mod common;
 
 use common::{
-    create_escrow_ix, create_gmp_receive_fulfillment_proof_ix, create_gmp_receive_requirements_ix,
-    generate_intent_id, get_token_balance, program_test, read_escrow, read_requirements,
-    setup_basic_env, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    create_env_fulfillment_proof_ix, create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
+    create_gmp_receive_requirements_ix, create_open_escrow_ix, create_solver_fulfillment_proof_ix,
+    create_token_account, generate_intent_id, get_token_balance, program_test, read_escrow,
+    read_requirements, setup_basic_env, setup_gmp_requirements, via_gmp_endpoint,
+    DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
-use intent_inflow_escrow::state::seeds;
-use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use intent_inflow_escrow::state::{seeds, EscrowMode};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
 
 // ============================================================================
 // GMP CLAIM TESTS
@@ -176,7 +182,7 @@ async fn test_claim_with_valid_fulfillment_proof() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let lz_proof_tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -347,7 +353,7 @@ async fn test_prevent_double_fulfillment() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -373,7 +379,7 @@ async fn test_prevent_double_fulfillment() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix2],
+        &[via_gmp_endpoint(gmp_receive_proof_ix2)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -463,7 +469,7 @@ async fn test_revert_if_escrow_does_not_exist() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -472,3 +478,240 @@ async fn test_revert_if_escrow_does_not_exist() {
     let result = context.banks_client.process_transaction(tx).await;
     assert!(result.is_err(), "Should fail - escrow doesn't exist");
 }
+
+// ============================================================================
+// OPEN ESCROW TESTS
+// ============================================================================
+
+/// Helper: Create a funded escrow in `mode` for `env.requester`.
+/// Reserved escrows reserve `env.solver`; open escrows reserve nobody.
+/// Returns (escrow_pda, vault_pda, requirements_pda).
+async fn create_escrow_in_mode(
+    context: &mut solana_program_test::ProgramTestContext,
+    env: &common::TestEnv,
+    intent_id: [u8; 32],
+    amount: u64,
+    mode: EscrowMode,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let requirements_pda =
+        setup_gmp_requirements(context, env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = match mode {
+        EscrowMode::Reserved => create_escrow_ix(
+            env.program_id,
+            intent_id,
+            amount,
+            env.requester.pubkey(),
+            env.mint,
+            env.requester_token,
+            env.solver.pubkey(),
+            requirements_pda,
+        ),
+        EscrowMode::Open => create_open_escrow_ix(
+            env.program_id,
+            intent_id,
+            amount,
+            env.requester.pubkey(),
+            env.mint,
+            env.requester_token,
+            requirements_pda,
+        ),
+    };
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    (escrow_pda, vault_pda, requirements_pda)
+}
+
+/// Helper: Create a token account for a solver other than the reserved one.
+/// Returns (other_solver, other_solver_token).
+async fn create_other_solver_token(
+    context: &mut solana_program_test::ProgramTestContext,
+    env: &common::TestEnv,
+) -> (Pubkey, Pubkey) {
+    let payer = context.payer.insecure_clone();
+    let other_solver = Keypair::new();
+    let token = create_token_account(context, &payer, env.mint, other_solver.pubkey()).await;
+    (other_solver.pubkey(), token)
+}
+
+/// 6. Test: Any solver can claim an open escrow
+/// Verifies that an Open escrow stores no reserved solver and that the fulfillment-proof
+/// release pays out to the token account of the solver the proof names, who was never
+/// reserved.
+/// Why: Open escrows are first come; the hub's fulfillment proof decides who is paid.
+#[tokio::test]
+async fn test_non_reserved_solver_claims_open_escrow() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+    let (escrow_pda, vault_pda, _) =
+        create_escrow_in_mode(&mut context, &env, intent_id, amount, EscrowMode::Open).await;
+    let (other_solver, other_solver_token) = create_other_solver_token(&mut context, &env).await;
+
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+    assert_eq!(escrow.mode, EscrowMode::Open);
+    assert_eq!(escrow.reserved_solver, Pubkey::default());
+
+    let proof_ix = create_solver_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        amount,
+        other_solver,
+        other_solver_token,
+        context.payer.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(proof_tx).await.unwrap();
+
+    assert_eq!(get_token_balance(&mut context, other_solver_token).await, amount);
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, 0);
+}
+
+/// 7. Test: Only the reserved solver can claim a reserved escrow
+/// Verifies that a fulfillment-proof release to a token account owned by another solver
+/// fails and leaves the escrow and requirements untouched, while the reserved solver's
+/// own token account still succeeds.
+/// Why: Reserved escrows must not be redirected to a solver the requester did not choose.
+#[tokio::test]
+async fn test_revert_non_reserved_solver_claims_reserved_escrow() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+    let (escrow_pda, vault_pda, requirements_pda) =
+        create_escrow_in_mode(&mut context, &env, intent_id, amount, EscrowMode::Reserved).await;
+    let (_, other_solver_token) = create_other_solver_token(&mut context, &env).await;
+
+    let proof_ix = create_env_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        amount,
+        other_solver_token,
+        context.payer.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(proof_tx).await;
+    assert!(result.is_err(), "Should fail - token account is not the reserved solver's");
+
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+    assert_eq!(escrow.mode, EscrowMode::Reserved);
+    assert!(!escrow.is_claimed);
+    let req_account = context
+        .banks_client
+        .get_account(requirements_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!read_requirements(&req_account).fulfilled);
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount);
+    assert_eq!(get_token_balance(&mut context, other_solver_token).await, 0);
+
+    let proof_ix = create_env_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        amount,
+        env.solver_token,
+        context.payer.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(proof_tx).await.unwrap();
+    assert_eq!(get_token_balance(&mut context, env.solver_token).await, amount);
+}
+
+/// 8. Test: An open escrow only pays the solver named in the fulfillment proof
+/// Verifies that a proof naming one solver but paying a token account owned by another
+/// fails and leaves the escrow, requirements and vault untouched.
+/// Why: With no reserved solver, the proof is the only binding between fulfiller and payee.
+#[tokio::test]
+async fn test_revert_open_escrow_proof_pays_other_solver() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+    let (escrow_pda, vault_pda, requirements_pda) =
+        create_escrow_in_mode(&mut context, &env, intent_id, amount, EscrowMode::Open).await;
+    let (_, other_solver_token) = create_other_solver_token(&mut context, &env).await;
+
+    // Proof names env.solver but pays the other solver's token account
+    let proof_ix = create_env_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        amount,
+        other_solver_token,
+        context.payer.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(proof_tx).await;
+    assert!(result.is_err(), "Should fail - token account is not the proof's solver's");
+
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!read_escrow(&escrow_account).is_claimed);
+    let req_account = context
+        .banks_client
+        .get_account(requirements_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!read_requirements(&req_account).fulfilled);
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount);
+    assert_eq!(get_token_balance(&mut context, other_solver_token).await, 0);
+}
@@ -2,7 +2,10 @@
 #![allow(deprecated)]
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::program_pack::Pack;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    program_pack::Pack,
+};
 use solana_program_test::{processor, ProgramTest, ProgramTestContext};
 use solana_sdk::system_instruction;
 use solana_sdk::{
@@ -14,10 +17,13 @@ use solana_sdk::{
     transaction::Transaction,
 };
 
-use gmp_common::messages::IntentRequirements;
+use gmp_common::messages::{FulfillmentProof, IntentRequirements};
+use spl_token_2022::extension::{
+    transfer_fee::instruction as transfer_fee_instruction, ExtensionType, StateWithExtensions,
+};
 use intent_inflow_escrow::{
     instruction::EscrowInstruction,
-    state::{seeds, Escrow, EscrowState, StoredIntentRequirements},
+    state::{seeds, Escrow, EscrowMode, EscrowState, StoredIntentRequirements},
 };
 
 // ============================================================================
@@ -31,6 +37,11 @@ pub fn test_program_id() -> Pubkey {
     solana_sdk::pubkey!("Escrow11111111111111111111111111111111111111")
 }
 
+/// Fixed program ID of the mock GMP endpoint configured in `setup_basic_env`.
+pub fn test_gmp_endpoint_id() -> Pubkey {
+    solana_sdk::pubkey!("GmpEndpoint111111111111111111111111111111111")
+}
+
 // ============================================================================
 // DUMMY TEST CONSTANTS
 // ============================================================================
@@ -45,7 +56,31 @@ pub const DUMMY_HUB_GMP_ENDPOINT_ADDR: [u8; 32] = [0u8; 32];
 // TEST HARNESS HELPERS
 // ============================================================================
 
-/// Helper: Build a ProgramTest instance with intent_inflow_escrow + spl_token
+/// Mock GMP endpoint: forwards the instruction to the program in account 0,
+/// signing with its GMP signer PDA like the real endpoint's GmpReceive CPI.
+fn process_mock_gmp_endpoint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let (gmp_signer, bump) = Pubkey::find_program_address(&[seeds::GMP_SIGNER_SEED], program_id);
+    let cpi_instruction = Instruction {
+        program_id: *accounts[0].key,
+        accounts: accounts[1..]
+            .iter()
+            .map(|acc| AccountMeta {
+                pubkey: *acc.key,
+                is_signer: acc.is_signer || *acc.key == gmp_signer,
+                is_writable: acc.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke_signed(&cpi_instruction, accounts, &[&[seeds::GMP_SIGNER_SEED, &[bump]]])
+}
+
+/// Helper: Build a ProgramTest instance with intent_inflow_escrow + spl_token + spl_token_2022
+/// and the mock GMP endpoint
 pub fn program_test() -> ProgramTest {
     let program_id = test_program_id();
     let mut program_test = ProgramTest::new(
@@ -58,6 +93,16 @@ pub fn program_test() -> ProgramTest {
         spl_token::id(),
         processor!(spl_token::processor::Processor::process),
     );
+    program_test.add_program(
+        "spl_token_2022",
+        spl_token_2022::id(),
+        processor!(spl_token_2022::processor::Processor::process),
+    );
+    program_test.add_program(
+        "mock_gmp_endpoint",
+        test_gmp_endpoint_id(),
+        processor!(process_mock_gmp_endpoint),
+    );
     program_test
 }
 
@@ -188,6 +233,133 @@ pub async fn get_token_balance(
     token_state.amount
 }
 
+// ============================================================================
+// TOKEN-2022 HELPERS
+// ============================================================================
+
+/// Helper: Create a Token-2022 mint with a transfer fee of `fee_basis_points` (uncapped)
+pub async fn create_transfer_fee_mint(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    mint_authority: &Keypair,
+    decimals: u8,
+    fee_basis_points: u16,
+) -> Pubkey {
+    let mint = Keypair::new();
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let create_mint_ix = system_instruction::create_account(
+        &payer.pubkey(),
+        &mint.pubkey(),
+        rent.minimum_balance(mint_len),
+        mint_len as u64,
+        &spl_token_2022::id(),
+    );
+    let init_fee_ix = transfer_fee_instruction::initialize_transfer_fee_config(
+        &spl_token_2022::id(),
+        &mint.pubkey(),
+        None,
+        None,
+        fee_basis_points,
+        u64::MAX,
+    )
+    .unwrap();
+    let init_mint_ix = spl_token_2022::instruction::initialize_mint2(
+        &spl_token_2022::id(),
+        &mint.pubkey(),
+        &mint_authority.pubkey(),
+        None,
+        decimals,
+    )
+    .unwrap();
+
+    send_tx(
+        context,
+        payer,
+        &[create_mint_ix, init_fee_ix, init_mint_ix],
+        &[&mint],
+    )
+    .await;
+    mint.pubkey()
+}
+
+/// Helper: Create a Token-2022 token account for a transfer-fee mint
+pub async fn create_transfer_fee_token_account(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    mint: Pubkey,
+    owner: Pubkey,
+) -> Pubkey {
+    let token_account = Keypair::new();
+    let account_len =
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+            ExtensionType::TransferFeeAmount,
+        ])
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let create_ix = system_instruction::create_account(
+        &payer.pubkey(),
+        &token_account.pubkey(),
+        rent.minimum_balance(account_len),
+        account_len as u64,
+        &spl_token_2022::id(),
+    );
+    let init_ix = spl_token_2022::instruction::initialize_account3(
+        &spl_token_2022::id(),
+        &token_account.pubkey(),
+        &mint,
+        &owner,
+    )
+    .unwrap();
+
+    send_tx(context, payer, &[create_ix, init_ix], &[&token_account]).await;
+    token_account.pubkey()
+}
+
+/// Helper: Mint Token-2022 tokens to a token account
+pub async fn mint_to_2022(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    mint: Pubkey,
+    mint_authority: &Keypair,
+    destination: Pubkey,
+    amount: u64,
+) {
+    let ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::id(),
+        &mint,
+        &destination,
+        &mint_authority.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap();
+
+    send_tx(context, payer, &[ix], &[mint_authority]).await;
+}
+
+/// Helper: Read a Token-2022 token account balance (accounts with extensions)
+pub async fn get_token_2022_balance(
+    context: &mut ProgramTestContext,
+    token_account: Pubkey,
+) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
 // ============================================================================
 // PROGRAM HELPERS
 // ============================================================================
@@ -218,6 +390,37 @@ pub async fn initialize_program(
     state_pda
 }
 
+/// Helper: Build a SetApprover instruction signed by `admin`
+pub fn create_set_approver_ix(program_id: Pubkey, admin: Pubkey, new_approver: Pubkey) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data: EscrowInstruction::SetApprover { new_approver }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Helper: Build a MigrateState instruction signed and paid by the GMP config `admin`
+pub fn create_migrate_state_ix(program_id: Pubkey, admin: Pubkey) -> Instruction {
+    let (state_pda, _) = Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+    let (gmp_config_pda, _) = Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: EscrowInstruction::MigrateState.try_to_vec().unwrap(),
+    }
+}
+
 /// Helper: Build a CreateEscrow instruction
 /// Requirements PDA is mandatory - escrow creation always requires GMP requirements.
 /// Expiry comes from the hub-provided requirements, not from the instruction.
@@ -230,6 +433,82 @@ pub fn create_escrow_ix(
     requester_token: Pubkey,
     reserved_solver: Pubkey,
     requirements_pda: Pubkey,
+) -> Instruction {
+    create_escrow_ix_with_memo(
+        program_id,
+        intent_id,
+        amount,
+        requester,
+        token_mint,
+        requester_token,
+        reserved_solver,
+        requirements_pda,
+        [0u8; 32],
+    )
+}
+
+/// Helper: Build a CreateEscrow instruction carrying a client correlation memo
+pub fn create_escrow_ix_with_memo(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    amount: u64,
+    requester: Pubkey,
+    token_mint: Pubkey,
+    requester_token: Pubkey,
+    reserved_solver: Pubkey,
+    requirements_pda: Pubkey,
+    memo: [u8; 32],
+) -> Instruction {
+    create_escrow_ix_with_mode(
+        program_id,
+        intent_id,
+        amount,
+        requester,
+        token_mint,
+        requester_token,
+        reserved_solver,
+        requirements_pda,
+        memo,
+        EscrowMode::Reserved,
+    )
+}
+
+/// Helper: Build an Open-mode CreateEscrow instruction (no reserved solver)
+pub fn create_open_escrow_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    amount: u64,
+    requester: Pubkey,
+    token_mint: Pubkey,
+    requester_token: Pubkey,
+    requirements_pda: Pubkey,
+) -> Instruction {
+    create_escrow_ix_with_mode(
+        program_id,
+        intent_id,
+        amount,
+        requester,
+        token_mint,
+        requester_token,
+        Pubkey::default(),
+        requirements_pda,
+        [0u8; 32],
+        EscrowMode::Open,
+    )
+}
+
+/// Helper: Build a CreateEscrow instruction with an explicit memo and claim mode
+pub fn create_escrow_ix_with_mode(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    amount: u64,
+    requester: Pubkey,
+    token_mint: Pubkey,
+    requester_token: Pubkey,
+    reserved_solver: Pubkey,
+    requirements_pda: Pubkey,
+    memo: [u8; 32],
+    mode: EscrowMode,
 ) -> Instruction {
     let (escrow_pda, _escrow_bump) =
         Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
@@ -255,6 +534,8 @@ pub fn create_escrow_ix(
         data: EscrowInstruction::CreateEscrow {
             intent_id,
             amount,
+            memo,
+            mode,
         }
         .try_to_vec()
         .unwrap(),
@@ -309,6 +590,65 @@ pub fn create_cancel_ix(
     }
 }
 
+/// Helper: Build a SweepExpired instruction returning funds to `requester_token`
+pub fn create_sweep_expired_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    requester: Pubkey,
+    requester_token: Pubkey,
+) -> Instruction {
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow_pda, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(requester_token, false),
+            AccountMeta::new(requester, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: EscrowInstruction::SweepExpired { intent_id }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Helper: Build a ReduceEscrow instruction returning the excess to `requester_token`
+pub fn create_reduce_escrow_ix(
+    program_id: Pubkey,
+    intent_id: [u8; 32],
+    new_amount: u64,
+    requester: Pubkey,
+    requester_token: Pubkey,
+) -> Instruction {
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
+    let (requirements_pda, _) =
+        Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, &intent_id], &program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow_pda, false),
+            AccountMeta::new_readonly(requester, true),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(requester_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(requirements_pda, false),
+        ],
+        data: EscrowInstruction::ReduceEscrow {
+            intent_id,
+            new_amount,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
 /// Helper: Build a SetGmpConfig instruction
 pub fn create_set_gmp_config_ix(
     program_id: Pubkey,
@@ -365,6 +705,9 @@ pub fn create_gmp_receive_requirements_ix(
     }
 }
 
+/// Index of the gmp_caller account in a fulfillment proof instruction
+pub const PROOF_GMP_CALLER_INDEX: usize = 5;
+
 /// Helper: Build an GmpReceiveFulfillmentProof instruction
 pub fn create_gmp_receive_fulfillment_proof_ix(
     program_id: Pubkey,
@@ -399,6 +742,21 @@ pub fn create_gmp_receive_fulfillment_proof_ix(
     }
 }
 
+/// Helper: Deliver a fulfillment proof instruction through the mock GMP endpoint.
+/// The endpoint's GMP signer PDA replaces the gmp_caller account and signs the CPI.
+pub fn via_gmp_endpoint(ix: Instruction) -> Instruction {
+    let (gmp_signer, _) =
+        Pubkey::find_program_address(&[seeds::GMP_SIGNER_SEED], &test_gmp_endpoint_id());
+    let mut accounts = vec![AccountMeta::new_readonly(ix.program_id, false)];
+    accounts.extend(ix.accounts);
+    accounts[1 + PROOF_GMP_CALLER_INDEX] = AccountMeta::new_readonly(gmp_signer, false);
+    Instruction {
+        program_id: test_gmp_endpoint_id(),
+        accounts,
+        data: ix.data,
+    }
+}
+
 /// Helper: Build a generic GmpReceive instruction for requirements (message type 0x01)
 /// This uses the generic GmpReceive variant which routes based on message type.
 pub fn create_gmp_receive_generic_requirements_ix(
@@ -467,7 +825,7 @@ pub fn create_gmp_receive_generic_fulfillment_ix(
 
 /// Helper: Read escrow state from account data
 pub fn read_escrow(account: &solana_sdk::account::Account) -> Escrow {
-    Escrow::try_from_slice(&account.data).unwrap()
+    Escrow::unpack(&account.data).unwrap()
 }
 
 /// Helper: Read global state from account data
@@ -543,7 +901,7 @@ pub async fn setup_basic_env(context: &mut ProgramTestContext) -> TestEnv {
     let state_pda = initialize_program(context, &requester, program_id, approver.pubkey()).await;
 
     // Initialize GMP config with dummy test constants
-    let gmp_endpoint = Pubkey::new_unique(); // Mock GMP endpoint
+    let gmp_endpoint = test_gmp_endpoint_id(); // Mock GMP endpoint
 
     let (gmp_config_pda, _) =
         Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
@@ -678,6 +1036,65 @@ pub async fn setup_gmp_requirements_custom(
     requirements_pda
 }
 
+/// Helper: Build a GmpReceiveFulfillmentProof instruction from the hub endpoint in `env`
+/// that releases the escrow of `intent_id` to `solver_token`. The proof names `env.solver`
+/// as having fulfilled `amount`.
+/// Tests swap the token program or append the mint for non-SPL-Token vaults.
+pub fn create_env_fulfillment_proof_ix(
+    env: &TestEnv,
+    intent_id: [u8; 32],
+    amount: u64,
+    solver_token: Pubkey,
+    gmp_caller: Pubkey,
+) -> Instruction {
+    create_solver_fulfillment_proof_ix(
+        env,
+        intent_id,
+        amount,
+        env.solver.pubkey(),
+        solver_token,
+        gmp_caller,
+    )
+}
+
+/// Helper: Like `create_env_fulfillment_proof_ix`, but the proof names `solver`.
+pub fn create_solver_fulfillment_proof_ix(
+    env: &TestEnv,
+    intent_id: [u8; 32],
+    amount: u64,
+    solver: Pubkey,
+    solver_token: Pubkey,
+    gmp_caller: Pubkey,
+) -> Instruction {
+    let (requirements_pda, _) = Pubkey::find_program_address(
+        &[seeds::REQUIREMENTS_SEED, &intent_id],
+        &env.program_id,
+    );
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    let proof = FulfillmentProof {
+        intent_id,
+        solver_addr: solver.to_bytes(),
+        amount_fulfilled: amount,
+        timestamp: 0,
+    };
+    create_gmp_receive_fulfillment_proof_ix(
+        env.program_id,
+        requirements_pda,
+        escrow_pda,
+        vault_pda,
+        solver_token,
+        env.gmp_config_pda,
+        gmp_caller,
+        env.hub_chain_id,
+        env.hub_gmp_endpoint_addr,
+        proof.encode().to_vec(),
+    )
+}
+
 // ============================================================================
 // ED25519 SIGNATURE HELPERS
 // ============================================================================
@@ -1,7 +1,7 @@
 mod common;
 
 use common::{
-    create_escrow_ix, generate_intent_id, get_token_balance, program_test, read_escrow,
+    create_escrow_ix, create_escrow_ix_with_memo, generate_intent_id, get_token_balance, program_test, read_escrow,
     setup_basic_env, setup_gmp_requirements,
 };
 use intent_inflow_escrow::state::seeds;
@@ -273,3 +273,99 @@ async fn test_set_correct_expiry_timestamp() {
     assert_eq!(escrow.amount, amount);
     assert!(!escrow.is_claimed);
 }
+
+/// 5. Test: Escrow Memo Stored
+/// Verifies that a memo supplied at creation is stored on the escrow account and read back unchanged.
+/// Why: Integrators correlate escrows with off-chain records through the memo.
+#[tokio::test]
+async fn test_create_escrow_stores_memo() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+    let mut memo = [0u8; 32];
+    memo[..12].copy_from_slice(b"order-000042");
+
+    let requirements_pda = setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+    let ix = create_escrow_ix_with_memo(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+        memo,
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+
+    assert_eq!(escrow.memo, memo);
+    assert_eq!(escrow.amount, amount);
+}
+
+/// 6. Test: Escrow Memo Defaults To Empty
+/// Verifies that an escrow created without a memo stores an all-zero memo.
+/// Why: The memo is optional; zeros mean no correlation tag was supplied.
+#[tokio::test]
+async fn test_create_escrow_without_memo_is_empty() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+
+    let requirements_pda = setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+    let ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+
+    assert_eq!(escrow.memo, [0u8; 32]);
+}
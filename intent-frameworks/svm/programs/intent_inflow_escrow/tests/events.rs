@@ -0,0 +1,296 @@
+mod common;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use borsh::BorshSerialize;
+use common::{
+    create_env_fulfillment_proof_ix, create_escrow_ix, generate_intent_id, program_test,
+    setup_basic_env, setup_gmp_requirements, via_gmp_endpoint,
+};
+use intent_inflow_escrow::events::{
+    EscrowEvent, EventSchemaError, EVENT_SCHEMA_VERSION, EVENT_TAG,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use std::sync::Once;
+
+/// Syscall stubs that forward to solana-program-test's, except that `sol_log_data` is
+/// written to the transaction log (as `Program log: Program data: ...`) instead of stdout.
+/// Native `processor!` programs otherwise never put their events in the log messages.
+struct LogDataStubs(Box<dyn SyscallStubs>);
+
+impl SyscallStubs for LogDataStubs {
+    fn sol_log(&self, message: &str) {
+        self.0.sol_log(message)
+    }
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        let encoded: Vec<String> = fields.iter().map(|field| STANDARD.encode(field)).collect();
+        self.0.sol_log(&format!("Program data: {}", encoded.join(" ")))
+    }
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        self.0.sol_invoke_signed(instruction, account_infos, signers_seeds)
+    }
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_clock_sysvar(var_addr)
+    }
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_epoch_schedule_sysvar(var_addr)
+    }
+    fn sol_get_epoch_rewards_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_epoch_rewards_sysvar(var_addr)
+    }
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_fees_sysvar(var_addr)
+    }
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_rent_sysvar(var_addr)
+    }
+    fn sol_get_last_restart_slot(&self, var_addr: *mut u8) -> u64 {
+        self.0.sol_get_last_restart_slot(var_addr)
+    }
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        self.0.sol_get_return_data()
+    }
+    fn sol_set_return_data(&self, data: &[u8]) {
+        self.0.sol_set_return_data(data)
+    }
+    fn sol_get_stack_height(&self) -> u64 {
+        self.0.sol_get_stack_height()
+    }
+}
+
+/// Helper: Install `LogDataStubs` over the stubs solana-program-test installs on its
+/// first start, so call this after starting a test context.
+fn capture_log_data() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let program_test_stubs = set_syscall_stubs(Box::new(NoStubs));
+        set_syscall_stubs(Box::new(LogDataStubs(program_test_stubs)));
+    });
+}
+
+/// Placeholder held only while `capture_log_data` swaps the stubs.
+struct NoStubs;
+impl SyscallStubs for NoStubs {}
+
+/// Helper: Decode every escrow event from a transaction's log messages.
+fn decode_events(logs: &[String]) -> Vec<EscrowEvent> {
+    logs.iter()
+        .filter_map(|line| {
+            // `LogDataStubs` logs event data through `sol_log`
+            let line = line.strip_prefix("Program log: ").unwrap_or(line);
+            line.strip_prefix("Program data: ")
+        })
+        .filter_map(|encoded| {
+            let fields: Vec<Vec<u8>> = encoded
+                .split(' ')
+                .map(|field| STANDARD.decode(field))
+                .collect::<Result<_, _>>()
+                .ok()?;
+            let fields: Vec<&[u8]> = fields.iter().map(|f| f.as_slice()).collect();
+            if fields.first() == Some(&EVENT_TAG) {
+                assert_eq!(fields.get(1), Some(&[EVENT_SCHEMA_VERSION].as_slice()));
+            }
+            EscrowEvent::from_log_fields(&fields)
+        })
+        .collect()
+}
+
+// ============================================================================
+// ENCODING TESTS
+// ============================================================================
+
+/// 1. Test: Event Discriminators Are Stable
+/// Verifies that each event encodes with its fixed variant index as the first byte and
+/// decodes back to the same struct from tagged log fields.
+/// Why: Off-chain log parsers key on the discriminator; reordering variants would
+/// silently misparse every event.
+#[test]
+fn test_event_discriminators_round_trip() {
+    let events = [
+        EscrowEvent::EscrowCreated {
+            intent_id: [1u8; 32],
+            requester: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            amount: 1_000,
+            reserved_solver: Pubkey::new_unique(),
+            expiry: 1_700_000_000,
+            timestamp: 1_600_000_000,
+        },
+        EscrowEvent::EscrowClaimed {
+            intent_id: [2u8; 32],
+            solver: Pubkey::new_unique(),
+            amount: 400,
+            remaining: 600,
+            timestamp: 1_600_000_001,
+        },
+        EscrowEvent::EscrowCancelled {
+            intent_id: [3u8; 32],
+            requester: Pubkey::new_unique(),
+            amount: 600,
+            timestamp: 1_600_000_002,
+        },
+        EscrowEvent::ApproverRotated {
+            old_approver: Pubkey::new_unique(),
+            new_approver: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            timestamp: 1_600_000_003,
+        },
+    ];
+
+    for (discriminator, event) in events.iter().enumerate() {
+        let data = event.try_to_vec().unwrap();
+        assert_eq!(data[0] as usize, discriminator);
+        assert_eq!(
+            EscrowEvent::from_log_fields(&[EVENT_TAG, data.as_slice()]).as_ref(),
+            Some(event)
+        );
+    }
+
+    // Data logged without the escrow tag is not an escrow event
+    let data = events[0].try_to_vec().unwrap();
+    assert_eq!(EscrowEvent::from_log_fields(&[b"other".as_slice(), data.as_slice()]), None);
+    assert_eq!(EscrowEvent::from_log_fields(&[data.as_slice()]), None);
+}
+
+/// 2. Test: Schema Versions Are Branched On
+/// Verifies that v1 (no version field) and v2 (one-byte version field) log lines decode
+/// to the same event, and that a future version is rejected with an error naming it.
+/// Why: Indexers keep reading older logs across upgrades, and an outdated indexer must
+/// fail loudly on a newer layout instead of misparsing it.
+#[test]
+fn test_event_schema_versions() {
+    let event = EscrowEvent::EscrowCancelled {
+        intent_id: [4u8; 32],
+        requester: Pubkey::new_unique(),
+        amount: 250,
+        timestamp: 1_600_000_003,
+    };
+    let data = event.try_to_vec().unwrap();
+
+    let v1 = EscrowEvent::parse_log_fields(&[EVENT_TAG, data.as_slice()]).unwrap();
+    assert_eq!(v1.as_ref(), Some(&event));
+    let v2 = EscrowEvent::parse_log_fields(&[EVENT_TAG, &[2u8], data.as_slice()]).unwrap();
+    assert_eq!(v2.as_ref(), Some(&event));
+
+    let future = EVENT_SCHEMA_VERSION + 1;
+    let err = EscrowEvent::parse_log_fields(&[EVENT_TAG, &[future], data.as_slice()]).unwrap_err();
+    assert_eq!(err, EventSchemaError::UnsupportedVersion(future));
+    assert!(err.to_string().contains(&format!("version {}", future)));
+    assert_eq!(EscrowEvent::from_log_fields(&[EVENT_TAG, &[future], data.as_slice()]), None);
+
+    // Untagged data is not an error, just not ours
+    assert_eq!(EscrowEvent::parse_log_fields(&[b"other".as_slice(), &[2u8], data.as_slice()]), Ok(None));
+}
+
+// ============================================================================
+// EMISSION TESTS
+// ============================================================================
+
+/// 3. Test: CreateEscrow And Fulfillment Proof Emit Decodable Events
+/// Verifies that creating an escrow logs EscrowCreated and the fulfillment-proof
+/// auto-release logs EscrowClaimed, each at the current schema version and carrying the intent ID,
+/// amount, solver, and a timestamp.
+/// Why: Monitors parse these logs instead of repeatedly reading escrow accounts.
+#[tokio::test]
+async fn test_lifecycle_events_emitted() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    capture_log_data();
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(create_tx)
+        .await
+        .unwrap();
+    assert!(result.result.is_ok());
+    let events = decode_events(&result.metadata.unwrap().log_messages);
+    match events.as_slice() {
+        [EscrowEvent::EscrowCreated {
+            intent_id: id,
+            requester,
+            token_mint,
+            amount: created_amount,
+            reserved_solver,
+            timestamp,
+            ..
+        }] => {
+            assert_eq!(*id, intent_id);
+            assert_eq!(*requester, env.requester.pubkey());
+            assert_eq!(*token_mint, env.mint);
+            assert_eq!(*created_amount, amount);
+            assert_eq!(*reserved_solver, env.solver.pubkey());
+            assert!(*timestamp > 0);
+        }
+        other => panic!("Expected one EscrowCreated event, got {:?}", other),
+    }
+
+    let proof_ix = create_env_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        amount,
+        env.solver_token,
+        context.payer.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(proof_tx)
+        .await
+        .unwrap();
+    assert!(result.result.is_ok());
+    let events = decode_events(&result.metadata.unwrap().log_messages);
+    match events.as_slice() {
+        [EscrowEvent::EscrowClaimed {
+            intent_id: id,
+            solver,
+            amount: claimed,
+            remaining,
+            ..
+        }] => {
+            assert_eq!(*id, intent_id);
+            assert_eq!(*solver, env.solver.pubkey());
+            assert_eq!(*claimed, amount);
+            assert_eq!(*remaining, 0);
+        }
+        other => panic!("Expected one EscrowClaimed event, got {:?}", other),
+    }
+}
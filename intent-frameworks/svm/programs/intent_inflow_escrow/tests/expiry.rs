@@ -3,7 +3,7 @@ mod common;
 use common::{
     create_cancel_ix, create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
     generate_intent_id, get_token_balance, program_test, read_escrow, setup_basic_env,
-    setup_gmp_requirements, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    setup_gmp_requirements, via_gmp_endpoint, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::FulfillmentProof;
 use intent_inflow_escrow::state::seeds;
@@ -353,7 +353,7 @@ async fn test_expired_escrow_can_be_fulfilled_via_gmp() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let claim_tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
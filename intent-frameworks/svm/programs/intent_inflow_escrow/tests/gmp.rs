@@ -12,9 +12,11 @@
 mod common;
 
 use common::{
-    create_escrow_ix, create_gmp_receive_fulfillment_proof_ix, create_gmp_receive_requirements_ix,
-    create_set_gmp_config_ix, generate_intent_id, get_token_balance, program_test, read_escrow,
-    read_requirements, setup_basic_env, send_tx, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    create_env_fulfillment_proof_ix, create_escrow_ix, create_gmp_receive_fulfillment_proof_ix,
+    create_gmp_receive_requirements_ix, create_set_gmp_config_ix, generate_intent_id,
+    get_token_balance, program_test, read_escrow, read_requirements, setup_basic_env,
+    setup_gmp_requirements, send_tx, via_gmp_endpoint, DUMMY_HUB_CHAIN_ID,
+    DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
 use intent_inflow_escrow::state::seeds;
@@ -479,7 +481,7 @@ async fn test_receive_fulfillment_proof_releases_escrow() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -598,7 +600,7 @@ async fn test_receive_fulfillment_proof_rejects_unknown_remote_gmp_endpoint() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -702,7 +704,7 @@ async fn test_receive_fulfillment_proof_rejects_already_fulfilled() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -728,7 +730,7 @@ async fn test_receive_fulfillment_proof_rejects_already_fulfilled() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix2],
+        &[via_gmp_endpoint(gmp_receive_proof_ix2)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -1065,6 +1067,69 @@ async fn test_create_escrow_sends_escrow_confirmation() {
     assert!(requirements.escrow_created);
 }
 
+/// 15. Test: Reject direct call (not through the GMP endpoint)
+/// Verifies that a fulfillment proof signed by an arbitrary keypair instead of the
+/// configured GMP endpoint's signer PDA is rejected and the escrow stays funded.
+/// Why: The proof releases funds, so the GMP endpoint must be the single trust point.
+#[tokio::test]
+async fn test_reject_direct_call() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let amount = 500_000u64;
+    let requirements_pda =
+        setup_gmp_requirements(&mut context, &env, intent_id, amount, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let payer = context.payer.insecure_clone();
+    send_tx(&mut context, &payer, &[create_ix], &[&env.requester]).await;
+
+    // Valid proof from the hub, but signed by the payer rather than the GMP endpoint
+    let proof_ix = create_env_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        amount,
+        env.solver_token,
+        payer.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[proof_ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should reject proof not delivered by the GMP endpoint");
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    let escrow = read_escrow(
+        &context
+            .banks_client
+            .get_account(escrow_pda)
+            .await
+            .unwrap()
+            .unwrap(),
+    );
+    assert!(!escrow.is_claimed);
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, amount);
+    assert_eq!(get_token_balance(&mut context, env.solver_token).await, 0);
+}
+
 // ============================================================================
 // FULL WORKFLOW TEST
 // ============================================================================
@@ -1197,7 +1262,7 @@ async fn test_full_inflow_gmp_workflow() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
@@ -1434,7 +1499,7 @@ async fn test_generic_gmp_receive_routes_fulfillment_proof() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
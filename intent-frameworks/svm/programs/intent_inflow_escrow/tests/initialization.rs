@@ -1,11 +1,17 @@
 mod common;
 
 use common::{
-    create_escrow_ix, generate_intent_id, get_token_balance, initialize_program, program_test,
-    read_escrow, read_state, setup_basic_env, setup_gmp_requirements,
+    create_escrow_ix, create_migrate_state_ix, create_set_approver_ix, generate_intent_id, get_token_balance,
+    initialize_program, program_test, read_escrow, read_state, setup_basic_env,
+    setup_gmp_requirements,
+};
+use intent_inflow_escrow::state::{seeds, EscrowState};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
 };
-use intent_inflow_escrow::state::seeds;
-use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
 
 // ============================================================================
 // APPROVER INITIALIZATION TESTS
@@ -37,6 +43,7 @@ async fn test_initialize_approver_address() {
         .unwrap();
     let state = read_state(&state_account);
     assert_eq!(state.approver, approver.pubkey());
+    assert_eq!(state.admin, payer.pubkey());
 }
 
 // ============================================================================
@@ -201,3 +208,124 @@ async fn test_revert_if_amount_is_zero() {
 }
 
 // #5: test_revert_if_amount_is_zero_gmp_variant — not yet implemented for SVM
+
+// ============================================================================
+// APPROVER ROTATION TESTS
+// ============================================================================
+
+/// 6. Test: Admin Rotates Approver
+/// Verifies that the admin stored at initialization can replace the approver.
+/// Why: A compromised approver key must be replaceable without redeploying.
+#[tokio::test]
+async fn test_admin_can_rotate_approver() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let program_id = common::test_program_id();
+    let state_pda =
+        initialize_program(&mut context, &payer, program_id, Keypair::new().pubkey()).await;
+
+    let new_approver = Keypair::new().pubkey();
+    let ix = create_set_approver_ix(program_id, payer.pubkey(), new_approver);
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], blockhash);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let state_account = context
+        .banks_client
+        .get_account(state_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = read_state(&state_account);
+    assert_eq!(state.approver, new_approver);
+    assert_eq!(state.admin, payer.pubkey());
+}
+
+/// 7. Test: Non-Admin Cannot Rotate Approver
+/// Verifies that SetApprover signed by anyone other than the admin fails and leaves the
+/// approver unchanged.
+/// Why: Rotation is the most sensitive admin action; anyone else must be rejected.
+#[tokio::test]
+async fn test_non_admin_cannot_rotate_approver() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.insecure_clone();
+    let program_id = common::test_program_id();
+    let approver = Keypair::new().pubkey();
+    let state_pda = initialize_program(&mut context, &payer, program_id, approver).await;
+
+    let attacker = Keypair::new();
+    let fund_ix = system_instruction::transfer(&payer.pubkey(), &attacker.pubkey(), 1_000_000_000);
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[fund_ix], Some(&payer.pubkey()), &[&payer], blockhash);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let ix = create_set_approver_ix(program_id, attacker.pubkey(), attacker.pubkey());
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should fail - signer is not the admin");
+
+    let state_account = context
+        .banks_client
+        .get_account(state_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_state(&state_account).approver, approver);
+}
+
+/// 8. Test: MigrateState Records the Admin on a Legacy State
+/// Verifies that a 40-byte state from before the admin was recorded rejects SetApprover,
+/// that only the GMP config admin can migrate it, and that after migration the state has
+/// the current size, keeps its approver, and the admin can rotate the approver.
+/// Why: Deployments initialized before SetApprover existed must be able to rotate the approver.
+#[tokio::test]
+async fn test_migrate_legacy_state_sets_admin() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    // Rewrite the state in the legacy layout: the approver without an admin
+    let mut state_account = context.banks_client.get_account(env.state_pda).await.unwrap().unwrap();
+    state_account.data.truncate(EscrowState::LEGACY_LEN);
+    context.set_account(&env.state_pda, &state_account.into());
+
+    // No signer matches the missing admin
+    let rotate_ix = create_set_approver_ix(env.program_id, env.requester.pubkey(), Keypair::new().pubkey());
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[rotate_ix], Some(&env.requester.pubkey()), &[&env.requester], blockhash);
+    assert!(context.banks_client.process_transaction(tx).await.is_err(), "Legacy state has no admin");
+
+    // Only the GMP config admin (the requester in the basic env) may migrate
+    let migrate_ix = create_migrate_state_ix(env.program_id, env.solver.pubkey());
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[migrate_ix], Some(&env.solver.pubkey()), &[&env.solver], blockhash);
+    assert!(context.banks_client.process_transaction(tx).await.is_err(), "Should fail - signer is not the GMP config admin");
+
+    let migrate_ix = create_migrate_state_ix(env.program_id, env.requester.pubkey());
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[migrate_ix], Some(&env.requester.pubkey()), &[&env.requester], blockhash);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let state_account = context.banks_client.get_account(env.state_pda).await.unwrap().unwrap();
+    assert_eq!(state_account.data.len(), EscrowState::LEN);
+    let state = read_state(&state_account);
+    assert_eq!(state.approver, env.approver.pubkey());
+    assert_eq!(state.admin, env.requester.pubkey());
+
+    let new_approver = Keypair::new().pubkey();
+    let rotate_ix = create_set_approver_ix(env.program_id, env.requester.pubkey(), new_approver);
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[rotate_ix], Some(&env.requester.pubkey()), &[&env.requester], blockhash);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let state_account = context.banks_client.get_account(env.state_pda).await.unwrap().unwrap();
+    assert_eq!(read_state(&state_account).approver, new_approver);
+}
@@ -5,7 +5,8 @@ use common::{
     create_gmp_receive_requirements_ix, create_set_gmp_config_ix, create_mint,
     create_token_account, generate_intent_id, get_token_balance, initialize_program, mint_to,
     program_test, read_escrow, send_tx, setup_basic_env, setup_gmp_requirements,
-    setup_gmp_requirements_custom, DUMMY_HUB_CHAIN_ID, DUMMY_HUB_GMP_ENDPOINT_ADDR,
+    setup_gmp_requirements_custom, via_gmp_endpoint, DUMMY_HUB_CHAIN_ID,
+    DUMMY_HUB_GMP_ENDPOINT_ADDR,
 };
 use gmp_common::messages::{FulfillmentProof, IntentRequirements};
 use intent_inflow_escrow::state::seeds;
@@ -124,7 +125,7 @@ async fn test_complete_full_deposit_to_claim_workflow() {
 
     let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
     let claim_tx = Transaction::new_signed_with_payer(
-        &[gmp_receive_proof_ix],
+        &[via_gmp_endpoint(gmp_receive_proof_ix)],
         Some(&gmp_caller.pubkey()),
         &[&gmp_caller],
         blockhash,
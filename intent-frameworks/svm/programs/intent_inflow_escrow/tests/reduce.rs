@@ -0,0 +1,194 @@
+mod common;
+
+use common::{
+    create_env_fulfillment_proof_ix, create_escrow_ix, create_reduce_escrow_ix,
+    create_token_account, generate_intent_id, get_token_balance, program_test, read_escrow,
+    setup_basic_env, setup_gmp_requirements, via_gmp_endpoint, TestEnv,
+};
+use intent_inflow_escrow::state::seeds;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Amount the hub requires for the intent
+const AMOUNT_REQUIRED: u64 = 400_000;
+/// Amount the requester deposits (over-provisioned)
+const DEPOSIT: u64 = 1_000_000;
+
+/// Helper: Create an escrow of `DEPOSIT` for an intent requiring `AMOUNT_REQUIRED`,
+/// returning the intent ID and requirements PDA
+async fn create_over_provisioned_escrow(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+) -> ([u8; 32], Pubkey) {
+    let intent_id = generate_intent_id();
+    let requirements_pda =
+        setup_gmp_requirements(context, env, intent_id, AMOUNT_REQUIRED, u64::MAX).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        DEPOSIT,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+    (intent_id, requirements_pda)
+}
+
+/// Helper: Submit a ReduceEscrow signed by `signer`
+async fn reduce_escrow(
+    context: &mut ProgramTestContext,
+    env: &TestEnv,
+    intent_id: [u8; 32],
+    new_amount: u64,
+    signer: &Keypair,
+    requester_token: Pubkey,
+) -> Result<(), solana_program_test::BanksClientError> {
+    let reduce_ix = create_reduce_escrow_ix(
+        env.program_id,
+        intent_id,
+        new_amount,
+        signer.pubkey(),
+        requester_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[reduce_ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+// ============================================================================
+// REDUCE ESCROW TESTS
+// ============================================================================
+
+/// 1. Test: Requester Reduces An Over-Provisioned Escrow
+/// Verifies that the excess returns to the requester and the escrow keeps the new amount.
+/// Why: A requester who deposited more than the intent needs must not have to cancel
+/// (and race a solver claim) to get the excess back.
+#[tokio::test]
+async fn test_reduce_escrow_returns_excess_to_requester() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, _) = create_over_provisioned_escrow(&mut context, &env).await;
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    assert_eq!(get_token_balance(&mut context, env.requester_token).await, 0);
+
+    let new_amount = 600_000u64;
+    reduce_escrow(&mut context, &env, intent_id, new_amount, &env.requester, env.requester_token)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_token_balance(&mut context, env.requester_token).await,
+        DEPOSIT - new_amount
+    );
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, new_amount);
+    let escrow_account = context.banks_client.get_account(escrow_pda).await.unwrap().unwrap();
+    let escrow = read_escrow(&escrow_account);
+    assert_eq!(escrow.amount, new_amount);
+    assert!(!escrow.is_claimed);
+
+    // Reducing again down to exactly the required amount is allowed
+    reduce_escrow(&mut context, &env, intent_id, AMOUNT_REQUIRED, &env.requester, env.requester_token)
+        .await
+        .unwrap();
+    assert_eq!(get_token_balance(&mut context, vault_pda).await, AMOUNT_REQUIRED);
+}
+
+/// 2. Test: Reduce Rejects Claimed Escrows
+/// Verifies that ReduceEscrow fails once the fulfillment proof released the escrow.
+/// Why: The balance belongs to the solver once the hub confirmed fulfillment.
+#[tokio::test]
+async fn test_reduce_escrow_rejects_claimed_escrow() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, _) = create_over_provisioned_escrow(&mut context, &env).await;
+
+    let proof_ix = create_env_fulfillment_proof_ix(
+        &env,
+        intent_id,
+        AMOUNT_REQUIRED,
+        env.solver_token,
+        env.solver.pubkey(),
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let proof_tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&env.solver.pubkey()),
+        &[&env.solver],
+        blockhash,
+    );
+    context.banks_client.process_transaction(proof_tx).await.unwrap();
+
+    let result =
+        reduce_escrow(&mut context, &env, intent_id, AMOUNT_REQUIRED, &env.requester, env.requester_token).await;
+    assert!(result.is_err(), "Reduce after the fulfillment proof should fail");
+    assert_eq!(get_token_balance(&mut context, env.solver_token).await, DEPOSIT);
+    assert_eq!(get_token_balance(&mut context, env.requester_token).await, 0);
+}
+
+/// 3. Test: Reduce Rejects Increases, Zero, And Amounts Below The Requirement
+/// Verifies that new_amount must be positive, below the current amount, and cover
+/// the hub's amount_required.
+/// Why: Increases need a fresh deposit, and the hub was told the intent is funded.
+#[tokio::test]
+async fn test_reduce_escrow_rejects_invalid_amounts() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, _) = create_over_provisioned_escrow(&mut context, &env).await;
+
+    for new_amount in [DEPOSIT + 1, DEPOSIT, 0, AMOUNT_REQUIRED - 1] {
+        let result =
+            reduce_escrow(&mut context, &env, intent_id, new_amount, &env.requester, env.requester_token).await;
+        assert!(result.is_err(), "new_amount {} should be rejected", new_amount);
+    }
+    assert_eq!(get_token_balance(&mut context, env.requester_token).await, 0);
+}
+
+/// 4. Test: Only The Requester Can Reduce, Only To Their Own Token Account
+/// Verifies that another signer is rejected, and that the requester cannot send the
+/// excess to a token account they do not own.
+/// Why: The excess belongs to the requester; nobody else may move it.
+#[tokio::test]
+async fn test_reduce_escrow_requires_requester() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let (intent_id, _) = create_over_provisioned_escrow(&mut context, &env).await;
+
+    let result =
+        reduce_escrow(&mut context, &env, intent_id, AMOUNT_REQUIRED, &env.solver, env.solver_token).await;
+    assert!(result.is_err(), "Non-requester signer should be rejected");
+
+    let payer = context.payer.insecure_clone();
+    let foreign_token = create_token_account(&mut context, &payer, env.mint, env.solver.pubkey()).await;
+    let result =
+        reduce_escrow(&mut context, &env, intent_id, AMOUNT_REQUIRED, &env.requester, foreign_token).await;
+    assert!(result.is_err(), "Refund to a token account the requester does not own should fail");
+    assert_eq!(get_token_balance(&mut context, foreign_token).await, 0);
+}
@@ -0,0 +1,186 @@
+use borsh::BorshSerialize;
+use intent_inflow_escrow::{
+    state::{Escrow, EscrowMode, EscrowState, GmpConfig, StoredIntentRequirements},
+    EscrowError,
+};
+use solana_sdk::pubkey::Pubkey;
+
+// ============================================================================
+// ROUND-TRIP TESTS
+// ============================================================================
+
+/// 1. Test: EscrowState Round-Trip
+/// Verifies that serialized global state parses back with the same approver and admin.
+/// Why: Downstream tools read state through this helper instead of raw offsets.
+#[test]
+fn test_escrow_state_round_trip() {
+    let state = EscrowState::new(Pubkey::new_unique(), Pubkey::new_unique());
+    let data = state.try_to_vec().unwrap();
+    assert_eq!(data.len(), EscrowState::LEN);
+
+    let parsed = EscrowState::try_from_account_data(&data).unwrap();
+    assert_eq!(parsed.approver, state.approver);
+    assert_eq!(parsed.admin, state.admin);
+}
+
+/// 2. Test: Escrow Round-Trip
+/// Verifies that a serialized escrow parses back field for field.
+/// Why: The CLI and relay tooling display escrow fields from this parse.
+#[test]
+fn test_escrow_round_trip() {
+    let escrow = Escrow::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1_000_000,
+        1_700_000_000,
+        Pubkey::new_unique(),
+        [7u8; 32],
+        254,
+        [9u8; 32],
+        EscrowMode::Open,
+    );
+    let data = escrow.try_to_vec().unwrap();
+    assert_eq!(data.len(), Escrow::LEN);
+
+    let parsed = Escrow::try_from_account_data(&data).unwrap();
+    assert_eq!(parsed.requester, escrow.requester);
+    assert_eq!(parsed.token_mint, escrow.token_mint);
+    assert_eq!(parsed.amount, escrow.amount);
+    assert_eq!(parsed.is_claimed, escrow.is_claimed);
+    assert_eq!(parsed.expiry, escrow.expiry);
+    assert_eq!(parsed.reserved_solver, escrow.reserved_solver);
+    assert_eq!(parsed.intent_id, escrow.intent_id);
+    assert_eq!(parsed.bump, escrow.bump);
+    assert_eq!(parsed.memo, escrow.memo);
+    assert_eq!(parsed.mode, escrow.mode);
+}
+
+/// 3. Test: StoredIntentRequirements Round-Trip
+/// Verifies that serialized requirements parse back field for field.
+/// Why: `has-requirements` reads requirements through this helper.
+#[test]
+fn test_requirements_round_trip() {
+    let requirements =
+        StoredIntentRequirements::new([1u8; 32], [2u8; 32], 500, [3u8; 32], [4u8; 32], 99, 253);
+    let data = requirements.try_to_vec().unwrap();
+    assert_eq!(data.len(), StoredIntentRequirements::LEN);
+
+    let parsed = StoredIntentRequirements::try_from_account_data(&data).unwrap();
+    assert_eq!(parsed.intent_id, requirements.intent_id);
+    assert_eq!(parsed.requester_addr, requirements.requester_addr);
+    assert_eq!(parsed.amount_required, requirements.amount_required);
+    assert_eq!(parsed.token_addr, requirements.token_addr);
+    assert_eq!(parsed.solver_addr, requirements.solver_addr);
+    assert_eq!(parsed.expiry, requirements.expiry);
+    assert_eq!(parsed.escrow_created, requirements.escrow_created);
+    assert_eq!(parsed.fulfilled, requirements.fulfilled);
+    assert_eq!(parsed.bump, requirements.bump);
+}
+
+/// 4. Test: GmpConfig Round-Trip
+/// Verifies that a serialized GMP config parses back field for field.
+/// Why: Every state struct must be readable without hand-rolled offsets.
+#[test]
+fn test_gmp_config_round_trip() {
+    let config = GmpConfig::new(Pubkey::new_unique(), 30106, [5u8; 32], Pubkey::new_unique(), 252);
+    let data = config.try_to_vec().unwrap();
+    assert_eq!(data.len(), GmpConfig::LEN);
+
+    let parsed = GmpConfig::try_from_account_data(&data).unwrap();
+    assert_eq!(parsed.admin, config.admin);
+    assert_eq!(parsed.hub_chain_id, config.hub_chain_id);
+    assert_eq!(parsed.hub_gmp_endpoint_addr, config.hub_gmp_endpoint_addr);
+    assert_eq!(parsed.gmp_endpoint, config.gmp_endpoint);
+    assert_eq!(parsed.bump, config.bump);
+}
+
+// ============================================================================
+// INVALID ACCOUNT DATA TESTS
+// ============================================================================
+
+/// 5. Test: Wrong Discriminator Rejection
+/// Verifies that parsing another account type's bytes as an escrow fails with
+/// InvalidAccountData.
+/// Why: Passing the wrong PDA must not yield a garbage escrow.
+#[test]
+fn test_rejects_wrong_discriminator() {
+    let requirements =
+        StoredIntentRequirements::new([1u8; 32], [2u8; 32], 500, [3u8; 32], [4u8; 32], 99, 253);
+    let mut data = requirements.try_to_vec().unwrap();
+    data.resize(Escrow::LEN, 0);
+
+    let err = Escrow::try_from_account_data(&data).unwrap_err();
+    assert!(matches!(err, EscrowError::InvalidAccountData));
+}
+
+/// 6. Test: Truncated Data Rejection
+/// Verifies that account data shorter than the struct length fails with
+/// InvalidAccountData.
+/// Why: A truncated account must fail loudly instead of parsing zeros; only the exact
+/// legacy sizes are read as the older layout.
+#[test]
+fn test_rejects_truncated_data() {
+    let data = EscrowState::new(Pubkey::new_unique(), Pubkey::new_unique())
+        .try_to_vec()
+        .unwrap();
+
+    let err = EscrowState::try_from_account_data(&data[..EscrowState::LEN - 1]).unwrap_err();
+    assert!(matches!(err, EscrowError::InvalidAccountData));
+    let err = EscrowState::try_from_account_data(&[]).unwrap_err();
+    assert!(matches!(err, EscrowError::InvalidAccountData));
+}
+
+/// 7. Test: Legacy Escrow Layout
+/// Verifies that a 154-byte escrow from before the memo and mode were added parses with an
+/// all-zero memo in Reserved mode and re-encodes in place, and that a legacy account cannot
+/// hold a memo or the Open mode.
+/// Why: Escrows created before the upgrade must stay readable and writable.
+#[test]
+fn test_legacy_escrow_layout() {
+    let mut escrow = Escrow::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1_000_000,
+        1_700_000_000,
+        Pubkey::new_unique(),
+        [7u8; 32],
+        254,
+        [0u8; 32],
+        EscrowMode::Reserved,
+    );
+    let encoded = escrow.try_to_vec().unwrap();
+    let mut legacy = encoded[..Escrow::LEGACY_LEN].to_vec();
+
+    let parsed = Escrow::try_from_account_data(&legacy).unwrap();
+    assert_eq!(parsed.amount, escrow.amount);
+    assert_eq!(parsed.intent_id, escrow.intent_id);
+    assert_eq!(parsed.bump, escrow.bump);
+    assert_eq!(parsed.memo, [0u8; 32]);
+    assert_eq!(parsed.mode, EscrowMode::Reserved);
+
+    let mut unpacked = Escrow::unpack(&legacy).unwrap();
+    unpacked.is_claimed = true;
+    unpacked.pack(&mut legacy).unwrap();
+    assert!(Escrow::unpack(&legacy).unwrap().is_claimed);
+
+    escrow.mode = EscrowMode::Open;
+    assert!(escrow.pack(&mut legacy).is_err());
+    escrow.mode = EscrowMode::Reserved;
+    escrow.memo = [9u8; 32];
+    assert!(escrow.pack(&mut legacy).is_err());
+}
+
+/// 8. Test: Legacy EscrowState Layout
+/// Verifies that a 40-byte state from before the admin was recorded parses with its
+/// approver and an all-zero admin.
+/// Why: Tools must read old deployments, and no signer may match the missing admin until
+/// MigrateState records one.
+#[test]
+fn test_legacy_escrow_state_layout() {
+    let state = EscrowState::new(Pubkey::new_unique(), Pubkey::new_unique());
+    let data = state.try_to_vec().unwrap();
+
+    let parsed = EscrowState::try_from_account_data(&data[..EscrowState::LEGACY_LEN]).unwrap();
+    assert_eq!(parsed.approver, state.approver);
+    assert_eq!(parsed.admin, Pubkey::default());
+}
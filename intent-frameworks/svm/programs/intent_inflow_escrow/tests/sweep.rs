@@ -0,0 +1,222 @@
+mod common;
+
+use bincode::deserialize;
+use common::{
+    create_escrow_ix, create_sweep_expired_ix, create_token_account, generate_intent_id,
+    get_token_balance, program_test, read_escrow, setup_basic_env, setup_gmp_requirements,
+    TestEnv,
+};
+use intent_inflow_escrow::state::seeds;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, sysvar,
+    transaction::Transaction,
+};
+
+/// Helper: Create a 1_000_000 escrow expiring one second from now, returning the intent ID
+async fn create_short_lived_escrow(context: &mut ProgramTestContext, env: &TestEnv) -> [u8; 32] {
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let clock: Clock = deserialize(&clock_account.data).unwrap();
+
+    let intent_id = generate_intent_id();
+    let amount = 1_000_000u64;
+    let expiry = (clock.unix_timestamp as u64) + 1;
+    let requirements_pda = setup_gmp_requirements(context, env, intent_id, amount, expiry).await;
+
+    let create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        amount,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(create_tx).await.unwrap();
+    intent_id
+}
+
+/// Helper: Advance the Clock sysvar past the escrow's expiry
+async fn expire_escrow(context: &mut ProgramTestContext, escrow_pda: Pubkey) {
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let escrow = read_escrow(&escrow_account);
+    let clock_account = context
+        .banks_client
+        .get_account(sysvar::clock::id())
+        .await
+        .unwrap()
+        .unwrap();
+    let mut clock: Clock = deserialize(&clock_account.data).unwrap();
+    clock.unix_timestamp = escrow.expiry + 1;
+    context.set_sysvar(&clock);
+}
+
+/// Helper: Create and fund a keeper that is neither requester, solver, nor admin
+async fn create_keeper(context: &mut ProgramTestContext) -> Keypair {
+    let keeper = Keypair::new();
+    let payer = context.payer.insecure_clone();
+    let fund_ix = system_instruction::transfer(&payer.pubkey(), &keeper.pubkey(), 1_000_000_000);
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[fund_ix], Some(&payer.pubkey()), &[&payer], blockhash);
+    context.banks_client.process_transaction(tx).await.unwrap();
+    keeper
+}
+
+// ============================================================================
+// SWEEP TESTS
+// ============================================================================
+
+/// 1. Test: Sweep Before Expiry Prevention
+/// Verifies that SweepExpired fails while the escrow has not expired.
+/// Why: Funds must stay locked until expiry so the solver can still be paid.
+#[tokio::test]
+async fn test_sweep_rejected_before_expiry() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let keeper = create_keeper(&mut context).await;
+
+    let intent_id = create_short_lived_escrow(&mut context, &env).await;
+
+    let sweep_ix = create_sweep_expired_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        env.requester_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix],
+        Some(&keeper.pubkey()),
+        &[&keeper],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should fail - escrow has not expired yet");
+}
+
+/// 2. Test: Permissionless Sweep After Expiry
+/// Verifies that an unrelated keeper can sweep an expired escrow, that the vault balance
+/// returns to the requester's token account, and that the escrow and vault accounts are
+/// closed with their rent credited to the requester.
+/// Why: Funds must stay recoverable after expiry without the admin or requester signing.
+#[tokio::test]
+async fn test_sweep_after_expiry_returns_funds_and_rent() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let keeper = create_keeper(&mut context).await;
+
+    let intent_id = create_short_lived_escrow(&mut context, &env).await;
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+    expire_escrow(&mut context, escrow_pda).await;
+
+    let escrow_rent = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let vault_rent = context
+        .banks_client
+        .get_account(vault_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let requester_lamports_before = context
+        .banks_client
+        .get_balance(env.requester.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(get_token_balance(&mut context, env.requester_token).await, 0);
+
+    let sweep_ix = create_sweep_expired_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        env.requester_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix],
+        Some(&keeper.pubkey()),
+        &[&keeper],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(get_token_balance(&mut context, env.requester_token).await, 1_000_000);
+    assert!(context.banks_client.get_account(escrow_pda).await.unwrap().is_none());
+    assert!(context.banks_client.get_account(vault_pda).await.unwrap().is_none());
+    let requester_lamports_after = context
+        .banks_client
+        .get_balance(env.requester.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        requester_lamports_after,
+        requester_lamports_before + escrow_rent + vault_rent
+    );
+}
+
+/// 3. Test: Sweep Destination Is Fixed To The Requester
+/// Verifies that SweepExpired fails when the keeper passes their own token account as
+/// the destination.
+/// Why: The sweep is permissionless, so the recipient must not be caller-chosen.
+#[tokio::test]
+async fn test_sweep_rejects_non_requester_destination() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let keeper = create_keeper(&mut context).await;
+    let payer = context.payer.insecure_clone();
+    let keeper_token = create_token_account(&mut context, &payer, env.mint, keeper.pubkey()).await;
+
+    let intent_id = create_short_lived_escrow(&mut context, &env).await;
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    expire_escrow(&mut context, escrow_pda).await;
+
+    let sweep_ix = create_sweep_expired_ix(
+        env.program_id,
+        intent_id,
+        env.requester.pubkey(),
+        keeper_token,
+    );
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix],
+        Some(&keeper.pubkey()),
+        &[&keeper],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should fail - destination is not the requester's token account");
+    assert_eq!(get_token_balance(&mut context, keeper_token).await, 0);
+}
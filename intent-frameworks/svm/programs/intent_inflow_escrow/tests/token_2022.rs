@@ -0,0 +1,190 @@
+mod common;
+
+use common::{
+    create_env_fulfillment_proof_ix, create_escrow_ix, create_transfer_fee_mint,
+    create_transfer_fee_token_account, generate_intent_id, get_token_2022_balance,
+    mint_to_2022, program_test, read_escrow, setup_basic_env, setup_gmp_requirements_custom,
+    via_gmp_endpoint,
+};
+use intent_inflow_escrow::state::seeds;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+// ============================================================================
+// TOKEN-2022 TESTS
+// ============================================================================
+
+/// Index of the token program account in CreateEscrow
+const CREATE_TOKEN_PROGRAM_INDEX: usize = 6;
+
+/// Index of the token program account in GmpReceiveFulfillmentProof
+const PROOF_TOKEN_PROGRAM_INDEX: usize = 6;
+
+/// 1. Test: Transfer-Fee Mint Escrow Lifecycle
+/// Verifies that an escrow for a Token-2022 mint with a 1% transfer fee records the
+/// post-fee amount that reached the vault, and that the fulfillment-proof release with
+/// the mint account pays the solver the vault balance minus the fee on the release
+/// transfer. A release without the mint account is rejected.
+/// Why: Token-2022 requires transfer_checked for fee mints, and escrow accounting must
+/// match the tokens the vault actually holds.
+#[tokio::test]
+async fn test_transfer_fee_mint_escrow_lifecycle() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+    let payer = context.payer.insecure_clone();
+
+    let mint_authority = Keypair::new();
+    let mint = create_transfer_fee_mint(&mut context, &payer, &mint_authority, 6, 100).await;
+    let requester_token =
+        create_transfer_fee_token_account(&mut context, &payer, mint, env.requester.pubkey())
+            .await;
+    let solver_token =
+        create_transfer_fee_token_account(&mut context, &payer, mint, env.solver.pubkey()).await;
+    mint_to_2022(
+        &mut context,
+        &payer,
+        mint,
+        &mint_authority,
+        requester_token,
+        1_000_000,
+    )
+    .await;
+
+    // 1% of 1_000_000 is withheld on deposit, so the requirement is the post-fee amount
+    let intent_id = generate_intent_id();
+    let requirements_pda = setup_gmp_requirements_custom(
+        &mut context,
+        env.program_id,
+        env.gmp_config_pda,
+        env.hub_chain_id,
+        env.hub_gmp_endpoint_addr,
+        intent_id,
+        env.requester.pubkey(),
+        mint,
+        env.solver.pubkey(),
+        990_000,
+        u64::MAX,
+    )
+    .await;
+
+    let mut create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        1_000_000,
+        env.requester.pubkey(),
+        mint,
+        requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    create_ix.accounts[CREATE_TOKEN_PROGRAM_INDEX] =
+        AccountMeta::new_readonly(spl_token_2022::id(), false);
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_pda, _) =
+        Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &env.program_id);
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &env.program_id);
+
+    assert_eq!(get_token_2022_balance(&mut context, vault_pda).await, 990_000);
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(read_escrow(&escrow_account).amount, 990_000);
+
+    let mut proof_ix =
+        create_env_fulfillment_proof_ix(&env, intent_id, 990_000, solver_token, payer.pubkey());
+    proof_ix.accounts[PROOF_TOKEN_PROGRAM_INDEX] =
+        AccountMeta::new_readonly(spl_token_2022::id(), false);
+
+    // Without the mint account, Token-2022 transfers cannot be checked
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix.clone())],
+        Some(&payer.pubkey()),
+        &[&payer],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should fail - Token-2022 release without mint account");
+
+    proof_ix.accounts.push(AccountMeta::new_readonly(mint, false));
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[via_gmp_endpoint(proof_ix)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // 1% of 990_000 is withheld on release
+    assert_eq!(get_token_2022_balance(&mut context, vault_pda).await, 0);
+    assert_eq!(get_token_2022_balance(&mut context, solver_token).await, 980_100);
+}
+
+/// 2. Test: Token Program Must Own The Mint
+/// Verifies that CreateEscrow fails when the token program account does not own the mint.
+/// Why: The vault would otherwise be created under a program that cannot move the tokens.
+#[tokio::test]
+async fn test_rejects_token_program_mismatch() {
+    let program_test = program_test();
+    let mut context = program_test.start_with_context().await;
+    let env = setup_basic_env(&mut context).await;
+
+    let intent_id = generate_intent_id();
+    let requirements_pda = setup_gmp_requirements_custom(
+        &mut context,
+        env.program_id,
+        env.gmp_config_pda,
+        env.hub_chain_id,
+        env.hub_gmp_endpoint_addr,
+        intent_id,
+        env.requester.pubkey(),
+        env.mint,
+        env.solver.pubkey(),
+        500_000,
+        u64::MAX,
+    )
+    .await;
+
+    // env.mint is an SPL Token mint
+    let mut create_ix = create_escrow_ix(
+        env.program_id,
+        intent_id,
+        500_000,
+        env.requester.pubkey(),
+        env.mint,
+        env.requester_token,
+        env.solver.pubkey(),
+        requirements_pda,
+    );
+    create_ix.accounts[CREATE_TOKEN_PROGRAM_INDEX] =
+        AccountMeta::new_readonly(spl_token_2022::id(), false);
+
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&env.requester.pubkey()),
+        &[&env.requester],
+        blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+    assert!(result.is_err(), "Should fail - token program does not own the mint");
+}
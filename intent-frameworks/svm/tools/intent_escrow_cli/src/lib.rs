@@ -4,13 +4,18 @@
 
 use std::{collections::HashMap, error::Error};
 
+pub mod output;
+
 // ============================================================================
 // OPTION PARSING
 // ============================================================================
 
+/// Options that take no value. Present flags map to `"true"`.
+pub const FLAG_OPTIONS: &[&str] = &["simulate", "json", "no-preflight", "open", "native-sol"];
+
 /// Parse command-line arguments into a key-value map.
 ///
-/// Arguments must be in the form `--key value`.
+/// Arguments must be in the form `--key value`, except for [`FLAG_OPTIONS`].
 pub fn parse_options(args: &[String]) -> Result<HashMap<String, String>, Box<dyn Error>> {
     let mut options = HashMap::new();
     let mut index = 0;
@@ -18,6 +23,11 @@ pub fn parse_options(args: &[String]) -> Result<HashMap<String, String>, Box<dyn
         let key = args[index]
             .strip_prefix("--")
             .ok_or("Expected option in --key format")?;
+        if FLAG_OPTIONS.contains(&key) {
+            options.insert(key.to_string(), "true".to_string());
+            index += 1;
+            continue;
+        }
         let value = args
             .get(index + 1)
             .ok_or("Missing value for option")?
@@ -64,6 +74,20 @@ pub fn parse_i64(value: &str) -> Result<i64, Box<dyn Error>> {
     Ok(value.parse::<i64>()?)
 }
 
+/// Parse a `--token-program` value into a token program ID.
+///
+/// Accepts `spl-token` (the classic SPL Token program) or `token-2022`.
+pub fn parse_token_program(value: &str) -> Result<solana_sdk::pubkey::Pubkey, Box<dyn Error>> {
+    match value {
+        "spl-token" => Ok(spl_token::id()),
+        "token-2022" => Ok(spl_token_2022::id()),
+        other => Err(format!(
+            "Invalid --token-program '{other}': expected 'spl-token' or 'token-2022'"
+        )
+        .into()),
+    }
+}
+
 /// Parse a hex string into a 32-byte array with left-padding.
 ///
 /// Accepts hex strings with or without 0x prefix. Short strings are
@@ -121,3 +145,96 @@ pub fn hex_to_bytes32(hex_string: &str) -> [u8; 32] {
     }
     bytes
 }
+
+// ============================================================================
+// PREFLIGHT CHECKS
+// ============================================================================
+
+/// Check a source token account against what a transfer out of it needs.
+///
+/// Returns an error listing every mismatch (mint, owner, balance) so the user can fix
+/// them all before paying for a transaction that would fail on-chain.
+pub fn check_token_account(
+    account: &solana_sdk::pubkey::Pubkey,
+    actual_mint: &solana_sdk::pubkey::Pubkey,
+    actual_owner: &solana_sdk::pubkey::Pubkey,
+    balance: u64,
+    expected_mint: &solana_sdk::pubkey::Pubkey,
+    expected_owner: &solana_sdk::pubkey::Pubkey,
+    required_amount: u64,
+) -> Result<(), Box<dyn Error>> {
+    let mut mismatches = Vec::new();
+    if actual_mint != expected_mint {
+        mismatches.push(format!("mint is {actual_mint}, expected {expected_mint}"));
+    }
+    if actual_owner != expected_owner {
+        mismatches.push(format!("owner is {actual_owner}, expected {expected_owner}"));
+    }
+    if balance < required_amount {
+        mismatches.push(format!("balance is {balance}, need at least {required_amount}"));
+    }
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "Token account {account} failed preflight checks: {} (pass --no-preflight to skip)",
+        mismatches.join("; ")
+    )
+    .into())
+}
+
+// ============================================================================
+// ESCROW STATUS
+// ============================================================================
+
+/// Lifecycle state of an escrow, as reported by `watch-escrow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscrowStatus {
+    /// Funded and waiting for a claim
+    Created,
+    /// Released to the solver after the fulfillment proof arrived
+    Claimed,
+    /// Past expiry and not yet claimed or cancelled
+    Expired,
+    /// Refunded to the requester after expiry
+    Cancelled,
+}
+
+impl EscrowStatus {
+    /// Derive the status from escrow and requirements account fields.
+    ///
+    /// Claim and cancel both mark the escrow as claimed and drain it; only a claim
+    /// follows a fulfillment proof, so `fulfilled` tells the two apart.
+    pub fn from_escrow(is_claimed: bool, fulfilled: bool, expiry: i64, now: i64) -> Self {
+        match (is_claimed, fulfilled) {
+            (true, true) => EscrowStatus::Claimed,
+            (true, false) => EscrowStatus::Cancelled,
+            (false, _) if now > expiry => EscrowStatus::Expired,
+            (false, _) => EscrowStatus::Created,
+        }
+    }
+
+    /// Lowercase name printed on each transition.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EscrowStatus::Created => "created",
+            EscrowStatus::Claimed => "claimed",
+            EscrowStatus::Expired => "expired",
+            EscrowStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Process exit code once `watch-escrow` stops at this status, or `None` to keep polling.
+    ///
+    /// Claimed exits 0; expired and cancelled exit 2 since the escrow will not be claimed.
+    pub fn exit_code(self) -> Option<i32> {
+        match self {
+            EscrowStatus::Created => None,
+            EscrowStatus::Claimed => Some(0),
+            EscrowStatus::Expired | EscrowStatus::Cancelled => Some(2),
+        }
+    }
+}
+
+/// Exit code of `watch-escrow` when `--timeout-ms` elapses first (as with `timeout(1)`).
+pub const WATCH_TIMEOUT_EXIT_CODE: i32 = 124;
@@ -1,11 +1,14 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use intent_inflow_escrow::{
     instruction::EscrowInstruction,
-    state::{seeds, Escrow, EscrowState, StoredIntentRequirements},
+    state::{seeds, Escrow, EscrowMode, EscrowState, StoredIntentRequirements, NATIVE_SOL_MINT},
 };
 use intent_escrow_cli::{
-    parse_32_byte_hex, parse_i64, parse_intent_id, parse_options, parse_signature, parse_u32,
-    parse_u64, required_option,
+    check_token_account,
+    output::{format_error, CliOutput, TxOutcome},
+    parse_32_byte_hex, parse_i64, parse_intent_id, parse_options, parse_signature,
+    parse_token_program, parse_u32, parse_u64, required_option, EscrowStatus,
+    WATCH_TIMEOUT_EXIT_CODE,
 };
 use intent_gmp::{
     instruction::NativeGmpInstruction,
@@ -24,9 +27,15 @@ use solana_sdk::{
     sysvar,
     transaction::Transaction,
 };
-use solana_program::program_pack::Pack;
-use spl_token::state::Account as TokenAccount;
-use std::{collections::HashMap, env, error::Error, str::FromStr};
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    str::FromStr,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 // ============================================================================
 // CLI ENTRYPOINT
@@ -34,7 +43,8 @@ use std::{collections::HashMap, env, error::Error, str::FromStr};
 
 fn main() {
     if let Err(error) = run() {
-        eprintln!("[intent_escrow_cli] Error: {error}");
+        let json = env::args().any(|arg| arg == "--json");
+        eprintln!("{}", format_error(json, &error));
         std::process::exit(1);
     }
 }
@@ -64,11 +74,7 @@ fn run() -> Result<(), Box<dyn Error>> {
     if command == "gmp-init" {
         let gmp_program_id = match options.get("gmp-program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --gmp-program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "gmp-program-id", command),
         };
         return handle_gmp_init(&client, &options, gmp_program_id);
     }
@@ -76,11 +82,7 @@ fn run() -> Result<(), Box<dyn Error>> {
     if command == "gmp-add-relay" {
         let gmp_program_id = match options.get("gmp-program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --gmp-program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "gmp-program-id", command),
         };
         return handle_gmp_add_relay(&client, &options, gmp_program_id);
     }
@@ -88,11 +90,7 @@ fn run() -> Result<(), Box<dyn Error>> {
     if command == "gmp-set-remote-gmp-endpoint-addr" {
         let gmp_program_id = match options.get("gmp-program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --gmp-program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "gmp-program-id", command),
         };
         return handle_gmp_set_remote_gmp_endpoint_addr(&client, &options, gmp_program_id);
     }
@@ -100,24 +98,73 @@ fn run() -> Result<(), Box<dyn Error>> {
     if command == "gmp-set-routing" {
         let gmp_program_id = match options.get("gmp-program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --gmp-program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "gmp-program-id", command),
         };
         return handle_gmp_set_routing(&client, &options, gmp_program_id);
     }
 
+    if command == "gmp-set-payload-version-mode" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_set_payload_version_mode(&client, &options, gmp_program_id);
+    }
+
+    if command == "gmp-set-trusted-remote-mode" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_set_trusted_remote_mode(&client, &options, gmp_program_id);
+    }
+
+    if command == "gmp-set-relay-limit" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_set_relay_limit(&client, &options, gmp_program_id);
+    }
+
+    if command == "gmp-set-treasury" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_set_treasury(&client, &options, gmp_program_id);
+    }
+
+    if command == "gmp-pause" || command == "gmp-unpause" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_set_paused(&client, &options, gmp_program_id, command == "gmp-pause");
+    }
+
+    if command == "gmp-prune-delivered" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_prune_delivered(&client, &options, gmp_program_id);
+    }
+
+    #[cfg(feature = "test-only")]
+    if command == "gmp-reset-nonce" {
+        let gmp_program_id = match options.get("gmp-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "gmp-program-id", command),
+        };
+        return handle_gmp_reset_nonce(&client, &options, gmp_program_id);
+    }
+
     // Outflow commands use --outflow-program-id
     if command == "outflow-init" {
         let outflow_program_id = match options.get("outflow-program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --outflow-program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "outflow-program-id", command),
         };
         return handle_outflow_init(&client, &options, outflow_program_id);
     }
@@ -125,24 +172,24 @@ fn run() -> Result<(), Box<dyn Error>> {
     if command == "outflow-update-hub-config" {
         let outflow_program_id = match options.get("outflow-program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --outflow-program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "outflow-program-id", command),
         };
         return handle_outflow_update_hub_config(&client, &options, outflow_program_id);
     }
 
+    if command == "outflow-set-fee" {
+        let outflow_program_id = match options.get("outflow-program-id") {
+            Some(value) => parse_pubkey(value)?,
+            None => missing_required_option(&options, "outflow-program-id", command),
+        };
+        return handle_outflow_set_fee(&client, &options, outflow_program_id);
+    }
+
     // Escrow GMP config command
     if command == "escrow-set-gmp-config" {
         let program_id = match options.get("program-id") {
             Some(value) => parse_pubkey(value)?,
-            None => {
-                eprintln!("Error: --program-id is required for '{}'", command);
-                print_usage();
-                std::process::exit(1);
-            }
+            None => missing_required_option(&options, "program-id", command),
         };
         return handle_escrow_set_gmp_config(&client, &options, program_id);
     }
@@ -150,20 +197,20 @@ fn run() -> Result<(), Box<dyn Error>> {
     // All other commands require program-id
     let program_id = match options.get("program-id") {
         Some(value) => parse_pubkey(value)?,
-        None => {
-            eprintln!("Error: --program-id is required for '{}'", command);
-            print_usage();
-            std::process::exit(1);
-        }
+        None => missing_required_option(&options, "program-id", command),
     };
 
     match command {
         "initialize" => handle_initialize(&client, &options, program_id),
+        "set-approver" => handle_set_approver(&client, &options, program_id),
+        "migrate-state" => handle_migrate_state(&client, &options, program_id),
         "create-escrow" => handle_create_escrow(&client, &options, program_id),
         "claim" => handle_claim(&client, &options, program_id),
         "cancel" => handle_cancel(&client, &options, program_id),
         "get-escrow" => handle_get_escrow(&client, &options, program_id),
+        "watch-escrow" => handle_watch_escrow(&client, &options, program_id),
         "has-requirements" => handle_has_requirements(&client, &options, program_id),
+        "derive-pdas" => handle_derive_pdas(&client, &options, program_id),
         _ => {
             print_usage();
             Ok(())
@@ -180,6 +227,7 @@ fn handle_initialize(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let approver = parse_pubkey(required_option(options, "approver")?)?;
 
@@ -196,9 +244,71 @@ fn handle_initialize(
         data: EscrowInstruction::Initialize { approver }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Initialize signature: {signature}");
-    println!("State PDA: {state_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("Initialize signature", &signature);
+    out.field("State PDA", "state_pda", state_pda.to_string());
+    out.finish();
+    Ok(())
+}
+
+fn handle_set_approver(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let admin = read_keypair(options, "admin")?;
+    let new_approver = parse_pubkey(required_option(options, "new-approver")?)?;
+
+    let (state_pda, _state_bump) =
+        Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new_readonly(admin.pubkey(), true),
+        ],
+        data: EscrowInstruction::SetApprover { new_approver }.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[&admin])?;
+    out.transaction("Set approver signature", &signature);
+    out.field("New approver", "new_approver", new_approver.to_string());
+    out.finish();
+    Ok(())
+}
+
+fn handle_migrate_state(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let admin = read_keypair(options, "admin")?;
+
+    let (state_pda, _state_bump) =
+        Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
+    let (gmp_config_pda, _) =
+        Pubkey::find_program_address(&[seeds::GMP_CONFIG_SEED], &program_id);
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new(admin.pubkey(), true),
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: EscrowInstruction::MigrateState.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[&admin])?;
+    out.transaction("Migrate state signature", &signature);
+    out.field("Admin", "admin", admin.pubkey().to_string());
+    out.finish();
     Ok(())
 }
 
@@ -207,16 +317,43 @@ fn handle_create_escrow(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let requester = read_keypair(options, "requester")?;
 
-    let token_mint = parse_pubkey(required_option(options, "token-mint")?)?;
-    let requester_token = parse_pubkey(required_option(options, "requester-token")?)?;
-    let solver = parse_pubkey(required_option(options, "solver")?)?;
+    // Native SOL escrows use the system program as mint and token program and are funded
+    // from the requester wallet, so they take no token arguments
+    let native_sol = options.contains_key("native-sol");
+    let (token_mint, requester_token, token_program) = if native_sol {
+        (NATIVE_SOL_MINT, requester.pubkey(), solana_sdk::system_program::id())
+    } else {
+        (
+            parse_pubkey(required_option(options, "token-mint")?)?,
+            parse_pubkey(required_option(options, "requester-token")?)?,
+            token_program_option(options)?,
+        )
+    };
+    // Open escrows have no reserved solver, so --solver is only required without --open
+    let mode = if options.contains_key("open") {
+        EscrowMode::Open
+    } else {
+        EscrowMode::Reserved
+    };
+    let solver = match mode {
+        EscrowMode::Reserved => parse_pubkey(required_option(options, "solver")?)?,
+        EscrowMode::Open => Pubkey::default(),
+    };
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
     let amount = parse_u64(required_option(options, "amount")?)?;
     // Expiry comes from hub-provided GMP requirements, not from CLI
 
+    // Optional client correlation memo (zeros = none)
+    let memo = options
+        .get("memo")
+        .map(|v| parse_32_byte_hex(v))
+        .transpose()?
+        .unwrap_or([0u8; 32]);
+
     // Optional GMP endpoint for sending EscrowConfirmation
     let gmp_endpoint = options
         .get("gmp-endpoint")
@@ -230,16 +367,37 @@ fn handle_create_escrow(
         .transpose()?
         .unwrap_or(1);
 
+    if native_sol && !options.contains_key("no-preflight") {
+        let balance = client.get_balance(&requester_token)?;
+        if balance < amount {
+            return Err(format!(
+                "Requester {requester_token} holds {balance} lamports, need at least {amount} (pass --no-preflight to skip)"
+            )
+            .into());
+        }
+    } else if !options.contains_key("no-preflight") {
+        let account = client
+            .get_account(&requester_token)
+            .map_err(|e| format!("Requester token account {requester_token} not readable: {e}"))?;
+        let token_state = StateWithExtensions::<TokenAccount>::unpack(&account.data)?;
+        check_token_account(
+            &requester_token,
+            &token_state.base.mint,
+            &token_state.base.owner,
+            token_state.base.amount,
+            &token_mint,
+            &requester.pubkey(),
+            amount,
+        )?;
+    }
+
     // Read current global outbound nonce for message PDA derivation (0 if no messages sent yet)
     let current_nonce = if let Some(gmp_program) = gmp_endpoint {
         let (nonce_pda, _) =
             Pubkey::find_program_address(&[b"nonce_out"], &gmp_program);
         match client.get_account_data(&nonce_pda) {
-            Ok(data) if data.len() >= 9 => {
-                // OutboundNonceAccount: disc(1) + nonce(8)
-                u64::from_le_bytes(data[1..9].try_into().unwrap_or([0; 8]))
-            }
-            _ => 0,
+            Ok(data) => intent_gmp::state::OutboundNonceAccount::try_from_slice(&data)?.nonce,
+            Err(_) => 0,
         }
     } else {
         0
@@ -249,8 +407,11 @@ fn handle_create_escrow(
         program_id,
         intent_id,
         amount,
+        memo,
+        mode,
         requester.pubkey(),
         token_mint,
+        token_program,
         requester_token,
         solver,
         gmp_endpoint,
@@ -258,13 +419,14 @@ fn handle_create_escrow(
         current_nonce,
     )?;
 
-    let signature = send_tx(client, &[create_ix], &payer, &[&requester])?;
+    let signature = send_tx(client, options, &[create_ix], &payer, &[&requester])?;
     let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
     let (vault_pda, _) = Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
 
-    println!("Create escrow signature: {signature}");
-    println!("Escrow PDA: {escrow_pda}");
-    println!("Vault PDA: {vault_pda}");
+    out.transaction("Create escrow signature", &signature);
+    out.field("Escrow PDA", "escrow_pda", escrow_pda.to_string());
+    out.field("Vault PDA", "vault_pda", vault_pda.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -273,10 +435,12 @@ fn handle_claim(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
     let signature = parse_signature(required_option(options, "signature")?)?;
     let solver_token = parse_pubkey(required_option(options, "solver-token")?)?;
+    let token_program = token_program_option(options)?;
 
     let (state_pda, _state_bump) =
         Pubkey::find_program_address(&[seeds::STATE_SEED], &program_id);
@@ -284,7 +448,7 @@ fn handle_claim(
     let (vault_pda, _) = Pubkey::find_program_address(&[seeds::VAULT_SEED, &intent_id], &program_id);
 
     let state_account = client.get_account(&state_pda)?;
-    let state = EscrowState::try_from_slice(&state_account.data)?;
+    let state = EscrowState::try_from_account_data(&state_account.data)?;
 
     let ed25519_ix = new_ed25519_instruction_with_signature(
         &intent_id,
@@ -292,6 +456,9 @@ fn handle_claim(
         &state.approver.to_bytes(),
     );
 
+    let token_mint = escrow_token_mint(client, escrow_pda)?;
+    let token_program = vault_program(token_mint, token_program);
+
     let claim_ix = build_claim_ix(
         program_id,
         intent_id,
@@ -300,10 +467,13 @@ fn handle_claim(
         state_pda,
         vault_pda,
         solver_token,
+        token_program,
+        token_mint,
     )?;
 
-    let signature = send_tx(client, &[ed25519_ix, claim_ix], &payer, &[])?;
-    println!("Claim signature: {signature}");
+    let signature = send_tx(client, options, &[ed25519_ix, claim_ix], &payer, &[])?;
+    out.transaction("Claim signature", &signature);
+    out.finish();
     Ok(())
 }
 
@@ -312,20 +482,120 @@ fn handle_cancel(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let admin = read_keypair(options, "admin")?;
     let requester_token = parse_pubkey(required_option(options, "requester-token")?)?;
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
+    let token_program = token_program_option(options)?;
+
+    let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
+    let token_mint = escrow_token_mint(client, escrow_pda)?;
+    let token_program = vault_program(token_mint, token_program);
 
     let cancel_ix = build_cancel_ix(
         program_id,
         intent_id,
         admin.pubkey(),
         requester_token,
+        token_program,
+        token_mint,
     )?;
 
-    let signature = send_tx(client, &[cancel_ix], &payer, &[&admin])?;
-    println!("Cancel signature: {signature}");
+    let signature = send_tx(client, options, &[cancel_ix], &payer, &[&admin])?;
+    out.transaction("Cancel signature", &signature);
+    out.finish();
+    Ok(())
+}
+
+fn handle_derive_pdas(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
+    let gmp_program = options
+        .get("gmp-program-id")
+        .map(|v| parse_pubkey(v))
+        .transpose()?;
+    let hub_chain_id = options
+        .get("hub-chain-id")
+        .map(|v| parse_u32(v))
+        .transpose()?;
+
+    fn print_pda(out: &mut CliOutput, label: &str, seeds_desc: &str, seeds: &[&[u8]], owner: &Pubkey) {
+        let (pda, bump) = Pubkey::find_program_address(seeds, owner);
+        out.record(
+            format!("{label:<24} {pda}  seeds={seeds_desc} bump={bump}"),
+            &label.replace('-', "_"),
+            serde_json::json!({ "address": pda.to_string(), "seeds": seeds_desc, "bump": bump }),
+        );
+    }
+
+    out.field("Escrow program", "escrow_program", program_id.to_string());
+    print_pda(&mut out, "state", "[\"state\"]", &[seeds::STATE_SEED], &program_id);
+    print_pda(&mut out, "escrow", "[\"escrow\", intent_id]", &[seeds::ESCROW_SEED, &intent_id], &program_id);
+    print_pda(&mut out, "vault", "[\"vault\", intent_id]", &[seeds::VAULT_SEED, &intent_id], &program_id);
+    print_pda(
+        &mut out,
+        "requirements",
+        "[\"requirements\", intent_id]",
+        &[seeds::REQUIREMENTS_SEED, &intent_id],
+        &program_id,
+    );
+    print_pda(&mut out, "gmp-config", "[\"gmp_config\"]", &[seeds::GMP_CONFIG_SEED], &program_id);
+
+    if let Some(gmp_program) = gmp_program {
+        // Read current global outbound nonce: the next Send uses this message PDA
+        let (nonce_pda, _) = Pubkey::find_program_address(&[gmp_seeds::NONCE_OUT_SEED], &gmp_program);
+        let current_nonce = match client.get_account_data(&nonce_pda) {
+            Ok(data) => intent_gmp::state::OutboundNonceAccount::try_from_slice(&data)?.nonce,
+            Err(_) => 0,
+        };
+        let nonce_bytes = current_nonce.to_le_bytes();
+
+        out.note("");
+        out.field("GMP endpoint program", "gmp_endpoint_program", gmp_program.to_string());
+        print_pda(&mut out, "gmp-endpoint-config", "[\"config\"]", &[gmp_seeds::CONFIG_SEED], &gmp_program);
+        print_pda(&mut out, "nonce-out", "[\"nonce_out\"]", &[gmp_seeds::NONCE_OUT_SEED], &gmp_program);
+        print_pda(
+            &mut out,
+            "message",
+            &format!("[\"message\", {current_nonce} (current nonce, le)]"),
+            &[gmp_seeds::MESSAGE_SEED, &nonce_bytes],
+            &gmp_program,
+        );
+        print_pda(&mut out, "routing", "[\"routing\"]", &[gmp_seeds::ROUTING_SEED], &gmp_program);
+        print_pda(
+            &mut out,
+            "delivered-requirements",
+            "[\"delivered\", intent_id, [0x01]]",
+            &[gmp_seeds::DELIVERED_SEED, &intent_id, &[0x01]],
+            &gmp_program,
+        );
+        print_pda(
+            &mut out,
+            "delivered-fulfillment",
+            "[\"delivered\", intent_id, [0x03]]",
+            &[gmp_seeds::DELIVERED_SEED, &intent_id, &[0x03]],
+            &gmp_program,
+        );
+        if let Some(hub_chain_id) = hub_chain_id {
+            let chain_id_bytes = hub_chain_id.to_le_bytes();
+            print_pda(
+                &mut out,
+                "remote-gmp-endpoint",
+                &format!("[\"remote_gmp_endpoint\", {hub_chain_id} (le)]"),
+                &[gmp_seeds::REMOTE_GMP_ENDPOINT_SEED, &chain_id_bytes],
+                &gmp_program,
+            );
+        }
+    } else if hub_chain_id.is_some() {
+        out.note("Note: --hub-chain-id only applies with --gmp-program-id");
+    }
+
+    out.finish();
     Ok(())
 }
 
@@ -334,43 +604,123 @@ fn handle_get_escrow(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
     let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
     let account = client.get_account(&escrow_pda)?;
-    let escrow = Escrow::try_from_slice(&account.data)?;
-
-    println!("Escrow PDA: {escrow_pda}");
-    println!("Requester: {}", escrow.requester);
-    println!("Token mint: {}", escrow.token_mint);
-    println!("Amount: {}", escrow.amount);
-    println!("Expiry: {}", escrow.expiry);
-    println!("Reserved solver: {}", escrow.reserved_solver);
-    println!("Claimed: {}", escrow.is_claimed);
+    let escrow = Escrow::try_from_account_data(&account.data)?;
+
+    out.field("Escrow PDA", "escrow_pda", escrow_pda.to_string());
+    out.field("Requester", "requester", escrow.requester.to_string());
+    out.field("Token mint", "token_mint", escrow.token_mint.to_string());
+    out.field("Amount", "amount", escrow.amount);
+    out.field("Expiry", "expiry", escrow.expiry);
+    out.field("Mode", "mode", format!("{:?}", escrow.mode));
+    out.field("Reserved solver", "reserved_solver", escrow.reserved_solver.to_string());
+    out.field("Claimed", "claimed", escrow.is_claimed);
+    out.field("Memo", "memo", format!("0x{}", hex::encode(escrow.memo)));
+    out.finish();
     Ok(())
 }
 
+/// Poll the escrow until it is claimed, expires or `--timeout-ms` elapses.
+///
+/// Prints each status transition and exits with [`EscrowStatus::exit_code`], or
+/// [`WATCH_TIMEOUT_EXIT_CODE`] on timeout. Without `--timeout-ms` it polls until the
+/// escrow settles.
+fn handle_watch_escrow(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
+    let interval = Duration::from_millis(
+        options
+            .get("interval-ms")
+            .map(|v| parse_u64(v))
+            .transpose()?
+            .unwrap_or(2_000),
+    );
+    let deadline = options
+        .get("timeout-ms")
+        .map(|v| parse_u64(v))
+        .transpose()?
+        .map(|ms| Instant::now() + Duration::from_millis(ms));
+
+    let (escrow_pda, _) = Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
+    let (req_pda, _) =
+        Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, &intent_id], &program_id);
+    out.field("Escrow PDA", "escrow_pda", escrow_pda.to_string());
+
+    let mut previous: Option<EscrowStatus> = None;
+    loop {
+        let account = client.get_account(&escrow_pda)?;
+        let escrow = Escrow::try_from_account_data(&account.data)?;
+        // Only a claimed escrow needs the requirements account (claim vs cancel)
+        let fulfilled = escrow.is_claimed
+            && match client.get_account(&req_pda) {
+                Ok(account) => StoredIntentRequirements::try_from_account_data(&account.data)?.fulfilled,
+                Err(_) => false,
+            };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let status = EscrowStatus::from_escrow(escrow.is_claimed, fulfilled, escrow.expiry, now);
+
+        if previous != Some(status) {
+            let line = match previous {
+                Some(prev) => format!("Status: {} -> {}", prev.as_str(), status.as_str()),
+                None => format!("Status: {}", status.as_str()),
+            };
+            out.record(line, "transitions", serde_json::json!(status.as_str()));
+            previous = Some(status);
+        }
+
+        if let Some(code) = status.exit_code() {
+            out.field("Final status", "status", status.as_str());
+            out.finish();
+            std::process::exit(code);
+        }
+        let sleep_for = match deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    out.note("Timed out waiting for the escrow to be claimed or expire");
+                    out.field("Final status", "status", "timeout");
+                    out.finish();
+                    std::process::exit(WATCH_TIMEOUT_EXIT_CODE);
+                }
+                interval.min(remaining)
+            }
+            None => interval,
+        };
+        thread::sleep(sleep_for);
+    }
+}
+
 fn handle_has_requirements(
     client: &RpcClient,
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
     let (req_pda, _) =
         Pubkey::find_program_address(&[seeds::REQUIREMENTS_SEED, &intent_id], &program_id);
     match client.get_account(&req_pda) {
         Ok(account) => {
-            let req = StoredIntentRequirements::try_from_slice(&account.data)?;
-            println!("HasRequirements: true");
-            println!("Requirements PDA: {req_pda}");
-            println!("Amount required: {}", req.amount_required);
-            println!("Expiry: {}", req.expiry);
-            println!("Escrow created: {}", req.escrow_created);
-            println!("Fulfilled: {}", req.fulfilled);
+            let req = StoredIntentRequirements::try_from_account_data(&account.data)?;
+            out.field("HasRequirements", "has_requirements", true);
+            out.field("Requirements PDA", "requirements_pda", req_pda.to_string());
+            out.field("Amount required", "amount_required", req.amount_required);
+            out.field("Expiry", "expiry", req.expiry);
+            out.field("Escrow created", "escrow_created", req.escrow_created);
+            out.field("Fulfilled", "fulfilled", req.fulfilled);
         }
         Err(_) => {
-            println!("HasRequirements: false");
+            out.field("HasRequirements", "has_requirements", false);
         }
     }
+    out.finish();
     Ok(())
 }
 
@@ -378,11 +728,13 @@ fn handle_get_token_balance(
     client: &RpcClient,
     options: &HashMap<String, String>,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let token_account = parse_pubkey(required_option(options, "token-account")?)?;
     let account = client.get_account(&token_account)?;
-    let token_state = TokenAccount::unpack(&account.data)?;
-    println!("Token account: {token_account}");
-    println!("Balance: {}", token_state.amount);
+    let token_state = StateWithExtensions::<TokenAccount>::unpack(&account.data)?;
+    out.field("Token account", "token_account", token_account.to_string());
+    out.field("Balance", "balance", token_state.base.amount);
+    out.finish();
     Ok(())
 }
 
@@ -395,6 +747,7 @@ fn handle_escrow_set_gmp_config(
     options: &HashMap<String, String>,
     program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let hub_chain_id = parse_u32(required_option(options, "hub-chain-id")?)?;
     let hub_gmp_endpoint_addr = parse_32_byte_hex(required_option(options, "hub-address")?)?;
@@ -418,9 +771,10 @@ fn handle_escrow_set_gmp_config(
         .try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Escrow SetGmpConfig signature: {signature}");
-    println!("GMP Config PDA: {gmp_config_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("Escrow SetGmpConfig signature", &signature);
+    out.field("GMP Config PDA", "gmp_config_pda", gmp_config_pda.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -433,6 +787,7 @@ fn handle_gmp_init(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let chain_id = parse_u32(required_option(options, "chain-id")?)?;
 
@@ -450,9 +805,10 @@ fn handle_gmp_init(
         data: NativeGmpInstruction::Initialize { chain_id }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP Initialize signature: {signature}");
-    println!("Config PDA: {config_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP Initialize signature", &signature);
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -461,6 +817,7 @@ fn handle_gmp_add_relay(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let relay_pubkey = parse_pubkey(required_option(options, "relay")?)?;
 
@@ -481,9 +838,10 @@ fn handle_gmp_add_relay(
         data: NativeGmpInstruction::AddRelay { relay: relay_pubkey }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP AddRelay signature: {signature}");
-    println!("Relay PDA: {relay_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP AddRelay signature", &signature);
+    out.field("Relay PDA", "relay_pda", relay_pda.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -492,6 +850,7 @@ fn handle_gmp_set_remote_gmp_endpoint_addr(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let src_chain_id = parse_u32(required_option(options, "src-chain-id")?)?;
     let addr = parse_32_byte_hex(required_option(options, "addr")?)?;
@@ -513,9 +872,10 @@ fn handle_gmp_set_remote_gmp_endpoint_addr(
         data: NativeGmpInstruction::SetRemoteGmpEndpointAddr { src_chain_id, addr }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP SetRemoteGmpEndpointAddr signature: {signature}");
-    println!("Remote GMP endpoint PDA: {remote_gmp_endpoint_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP SetRemoteGmpEndpointAddr signature", &signature);
+    out.field("Remote GMP endpoint PDA", "remote_gmp_endpoint_pda", remote_gmp_endpoint_pda.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -524,6 +884,7 @@ fn handle_gmp_set_routing(
     options: &HashMap<String, String>,
     gmp_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let outflow_validator = parse_pubkey(required_option(options, "outflow-validator")?)?;
     let intent_escrow = parse_pubkey(required_option(options, "intent-escrow")?)?;
@@ -545,11 +906,309 @@ fn handle_gmp_set_routing(
         data: NativeGmpInstruction::SetRouting { outflow_validator, intent_escrow }.try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("GMP SetRouting signature: {signature}");
-    println!("Routing PDA: {routing_pda}");
-    println!("Outflow validator: {outflow_validator}");
-    println!("Intent escrow: {intent_escrow}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP SetRouting signature", &signature);
+    out.field("Routing PDA", "routing_pda", routing_pda.to_string());
+    out.field("Outflow validator", "outflow_validator", outflow_validator.to_string());
+    out.field("Intent escrow", "intent_escrow", intent_escrow.to_string());
+    out.finish();
+    Ok(())
+}
+
+fn handle_gmp_set_payload_version_mode(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let lenient = match required_option(options, "mode")? {
+        "strict" => false,
+        "lenient" => true,
+        other => return Err(format!("Invalid --mode '{other}': expected 'strict' or 'lenient'").into()),
+    };
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new(config_pda, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // admin
+            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: NativeGmpInstruction::SetPayloadVersionMode { lenient }.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP SetPayloadVersionMode signature", &signature);
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.field("Lenient", "lenient", lenient);
+    out.finish();
+    Ok(())
+}
+
+fn handle_gmp_set_trusted_remote_mode(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let require_trusted_remote = match required_option(options, "mode")? {
+        "strict" => true,
+        "permissive" => false,
+        other => return Err(format!("Invalid --mode '{other}': expected 'strict' or 'permissive'").into()),
+    };
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new(config_pda, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // admin
+            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: NativeGmpInstruction::SetTrustedRemoteMode { require_trusted_remote }.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP SetTrustedRemoteMode signature", &signature);
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.field("Require trusted remote", "require_trusted_remote", require_trusted_remote);
+    out.finish();
+    Ok(())
+}
+
+fn handle_gmp_set_relay_limit(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let relay_pubkey = parse_pubkey(required_option(options, "relay")?)?;
+    let max_per_window = parse_u32(required_option(options, "max-per-window")?)?;
+    let window_secs = match options.get("window-secs") {
+        Some(value) => parse_u32(value)?,
+        None if max_per_window == 0 => 0,
+        None => return Err("--window-secs is required when --max-per-window is non-zero".into()),
+    };
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+    let (relay_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::RELAY_SEED, relay_pubkey.as_ref()], &gmp_program_id);
+
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(relay_pda, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // admin
+            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: NativeGmpInstruction::SetRelayLimit {
+            relay: relay_pubkey,
+            max_per_window,
+            window_secs,
+        }
+        .try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP SetRelayLimit signature", &signature);
+    out.field("Relay PDA", "relay_pda", relay_pda.to_string());
+    out.record(
+        format!("Max per window: {max_per_window} (0 = unlimited)"),
+        "max_per_window",
+        max_per_window.into(),
+    );
+    out.field("Window seconds", "window_seconds", window_secs);
+    out.finish();
+    Ok(())
+}
+
+fn handle_gmp_set_treasury(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let treasury = parse_pubkey(required_option(options, "treasury")?)?;
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new(config_pda, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // admin
+            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: NativeGmpInstruction::SetTreasury { treasury }.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP SetTreasury signature", &signature);
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.field("Treasury", "treasury", treasury.to_string());
+    out.finish();
+    Ok(())
+}
+
+fn handle_gmp_set_paused(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+    paused: bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+
+    let instruction = if paused {
+        NativeGmpInstruction::Pause
+    } else {
+        NativeGmpInstruction::Unpause
+    };
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new(config_pda, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // admin
+            AccountMeta::new(payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: instruction.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction(
+        if paused { "GMP Pause signature" } else { "GMP Unpause signature" },
+        &signature,
+    );
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.field("Paused", "paused", paused);
+    out.finish();
+    Ok(())
+}
+
+fn handle_gmp_prune_delivered(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let intent_id = parse_intent_id(required_option(options, "intent-id")?)?;
+    let msg_type = u8::try_from(parse_u32(required_option(options, "msg-type")?)?)
+        .map_err(|_| "--msg-type must be 0..=255")?;
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+    let (delivered_pda, _) = Pubkey::find_program_address(
+        &[gmp_seeds::DELIVERED_SEED, &intent_id, &[msg_type]],
+        &gmp_program_id,
+    );
+    // Rent always goes to the configured treasury
+    let treasury =
+        intent_gmp::state::ConfigAccount::try_from_slice(&client.get_account_data(&config_pda)?)?
+            .treasury;
+
+    let ix = Instruction {
+        program_id: gmp_program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(delivered_pda, false),
+            AccountMeta::new(treasury, false),
+        ],
+        data: NativeGmpInstruction::PruneDelivered { intent_id, msg_type }.try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("GMP PruneDelivered signature", &signature);
+    out.field("Delivered PDA", "delivered_pda", delivered_pda.to_string());
+    out.field("Treasury", "treasury", treasury.to_string());
+    out.finish();
+    Ok(())
+}
+
+/// Message accounts closed per ResetNonce transaction (keeps the tx under the size limit).
+#[cfg(feature = "test-only")]
+const RESET_NONCE_CLOSE_BATCH: usize = 20;
+
+#[cfg(feature = "test-only")]
+fn handle_gmp_reset_nonce(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    gmp_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let dst_chain_id = parse_u32(required_option(options, "dst-chain-id")?)?;
+
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::CONFIG_SEED], &gmp_program_id);
+    let (nonce_pda, _) =
+        Pubkey::find_program_address(&[gmp_seeds::NONCE_OUT_SEED], &gmp_program_id);
+
+    // Read current global outbound nonce (0 if no messages sent yet)
+    let current_nonce = match client.get_account_data(&nonce_pda) {
+        Ok(data) => intent_gmp::state::OutboundNonceAccount::try_from_slice(&data)?.nonce,
+        Err(_) => 0,
+    };
+
+    // Collect message accounts that still exist so their nonces can be reused
+    let mut message_pdas = Vec::new();
+    for nonce in 0..current_nonce {
+        let (message_pda, _) = Pubkey::find_program_address(
+            &[gmp_seeds::MESSAGE_SEED, &nonce.to_le_bytes()],
+            &gmp_program_id,
+        );
+        if client.get_account(&message_pda).is_ok() {
+            message_pdas.push(message_pda);
+        }
+    }
+
+    // Reset is idempotent, so close accounts in chunks that fit a transaction
+    let mut chunks: Vec<&[Pubkey]> = message_pdas.chunks(RESET_NONCE_CLOSE_BATCH).collect();
+    if chunks.is_empty() {
+        chunks.push(&[]);
+    }
+    for chunk in chunks {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(nonce_pda, false),
+            AccountMeta::new(payer.pubkey(), true), // admin (receives closed message rent)
+        ];
+        accounts.extend(chunk.iter().map(|pda| AccountMeta::new(*pda, false)));
+
+        let ix = Instruction {
+            program_id: gmp_program_id,
+            accounts,
+            data: NativeGmpInstruction::ResetNonce { dst_chain_id }.try_to_vec()?,
+        };
+
+        let signature = send_tx(client, options, &[ix], &payer, &[])?;
+        out.transaction("GMP ResetNonce signature", &signature);
+    }
+
+    out.field("Nonce PDA", "nonce_pda", nonce_pda.to_string());
+    out.field("Previous nonce", "previous_nonce", current_nonce);
+    out.field("Closed message accounts", "closed_message_accounts", message_pdas.len());
+    out.finish();
     Ok(())
 }
 
@@ -562,6 +1221,7 @@ fn handle_outflow_init(
     options: &HashMap<String, String>,
     outflow_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let gmp_endpoint = parse_pubkey(required_option(options, "gmp-endpoint")?)?;
     let hub_chain_id = parse_u32(required_option(options, "hub-chain-id")?)?;
@@ -585,9 +1245,10 @@ fn handle_outflow_init(
         .try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Outflow Initialize signature: {signature}");
-    println!("Config PDA: {config_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("Outflow Initialize signature", &signature);
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -596,6 +1257,7 @@ fn handle_outflow_update_hub_config(
     options: &HashMap<String, String>,
     outflow_program_id: Pubkey,
 ) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
     let payer = read_keypair(options, "payer")?;
     let hub_chain_id = parse_u32(required_option(options, "hub-chain-id")?)?;
     let hub_gmp_endpoint_addr = parse_32_byte_hex(required_option(options, "hub-address")?)?;
@@ -616,9 +1278,46 @@ fn handle_outflow_update_hub_config(
         .try_to_vec()?,
     };
 
-    let signature = send_tx(client, &[ix], &payer, &[])?;
-    println!("Outflow UpdateHubConfig signature: {signature}");
-    println!("Config PDA: {config_pda}");
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("Outflow UpdateHubConfig signature", &signature);
+    out.field("Config PDA", "config_pda", config_pda.to_string());
+    out.finish();
+    Ok(())
+}
+
+fn handle_outflow_set_fee(
+    client: &RpcClient,
+    options: &HashMap<String, String>,
+    outflow_program_id: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = CliOutput::from_options(options);
+    let payer = read_keypair(options, "payer")?;
+    let fee_bps = required_option(options, "fee-bps")?.parse::<u16>()?;
+    let fee_recipient = parse_pubkey(required_option(options, "fee-recipient")?)?;
+
+    let (config_pda, _config_bump) =
+        Pubkey::find_program_address(&[outflow_seeds::CONFIG_SEED], &outflow_program_id);
+
+    let ix = Instruction {
+        program_id: outflow_program_id,
+        accounts: vec![
+            AccountMeta::new(config_pda, false),
+            AccountMeta::new_readonly(payer.pubkey(), true), // admin (signer)
+            AccountMeta::new(payer.pubkey(), true),          // payer (legacy config realloc)
+            AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+        ],
+        data: OutflowInstruction::SetFee {
+            fee_bps,
+            fee_recipient,
+        }
+        .try_to_vec()?,
+    };
+
+    let signature = send_tx(client, options, &[ix], &payer, &[])?;
+    out.transaction("Outflow SetFee signature", &signature);
+    out.field("Fee (bps)", "fee_bps", fee_bps);
+    out.field("Fee recipient", "fee_recipient", fee_recipient.to_string());
+    out.finish();
     Ok(())
 }
 
@@ -630,8 +1329,11 @@ fn build_create_escrow_ix(
     program_id: Pubkey,
     intent_id: [u8; 32],
     amount: u64,
+    memo: [u8; 32],
+    mode: EscrowMode,
     requester: Pubkey,
     token_mint: Pubkey,
+    token_program: Pubkey,
     requester_token: Pubkey,
     reserved_solver: Pubkey,
     gmp_endpoint: Option<Pubkey>,
@@ -650,7 +1352,7 @@ fn build_create_escrow_ix(
         AccountMeta::new(requester_token, false),
         AccountMeta::new(vault_pda, false),
         AccountMeta::new_readonly(reserved_solver, false),
-        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
     ];
@@ -694,11 +1396,14 @@ fn build_create_escrow_ix(
         data: EscrowInstruction::CreateEscrow {
             intent_id,
             amount,
+            memo,
+            mode,
         }
         .try_to_vec()?,
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_claim_ix(
     program_id: Pubkey,
     intent_id: [u8; 32],
@@ -707,6 +1412,8 @@ fn build_claim_ix(
     state_pda: Pubkey,
     vault_pda: Pubkey,
     solver_token: Pubkey,
+    token_program: Pubkey,
+    token_mint: Pubkey,
 ) -> Result<Instruction, Box<dyn Error>> {
     Ok(Instruction {
         program_id,
@@ -716,7 +1423,8 @@ fn build_claim_ix(
             AccountMeta::new(vault_pda, false),
             AccountMeta::new(solver_token, false),
             AccountMeta::new_readonly(sysvar::instructions::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(token_mint, false),
         ],
         data: EscrowInstruction::Claim {
             intent_id,
@@ -730,6 +1438,8 @@ fn build_cancel_ix(
     intent_id: [u8; 32],
     admin: Pubkey,
     requester_token: Pubkey,
+    token_program: Pubkey,
+    token_mint: Pubkey,
 ) -> Result<Instruction, Box<dyn Error>> {
     let (escrow_pda, _escrow_bump) =
         Pubkey::find_program_address(&[seeds::ESCROW_SEED, &intent_id], &program_id);
@@ -745,8 +1455,9 @@ fn build_cancel_ix(
             AccountMeta::new(admin, true),
             AccountMeta::new(vault_pda, false),
             AccountMeta::new(requester_token, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new_readonly(token_mint, false),
         ],
         data: EscrowInstruction::Cancel { intent_id }.try_to_vec()?,
     })
@@ -756,12 +1467,17 @@ fn build_cancel_ix(
 // TRANSACTION HELPERS
 // ============================================================================
 
+/// Sign and send a transaction, or only simulate it when `--simulate` is set.
+///
+/// A simulation returns the compute units consumed and the program logs, or an error
+/// carrying the logs if the transaction would fail so the CLI exits non-zero.
 fn send_tx(
     client: &RpcClient,
+    options: &HashMap<String, String>,
     instructions: &[Instruction],
     payer: &Keypair,
     signers: &[&Keypair],
-) -> Result<solana_sdk::signature::Signature, Box<dyn Error>> {
+) -> Result<TxOutcome, Box<dyn Error>> {
     let blockhash = client.get_latest_blockhash()?;
     let mut all_signers = Vec::with_capacity(signers.len() + 1);
     all_signers.push(payer);
@@ -777,8 +1493,21 @@ fn send_tx(
         &all_signers,
         blockhash,
     );
+
+    if options.contains_key("simulate") {
+        let result = client.simulate_transaction(&tx)?.value;
+        let logs = result.logs.unwrap_or_default();
+        if let Some(err) = result.err {
+            return Err(format!("Simulation failed: {err}\n  {}", logs.join("\n  ")).into());
+        }
+        return Ok(TxOutcome::Simulated {
+            units_consumed: result.units_consumed,
+            logs,
+        });
+    }
+
     let signature = client.send_and_confirm_transaction(&tx)?;
-    Ok(signature)
+    Ok(TxOutcome::Sent(signature))
 }
 
 // ============================================================================
@@ -797,32 +1526,96 @@ fn parse_pubkey(value: &str) -> Result<Pubkey, Box<dyn Error>> {
     Ok(Pubkey::from_str(value)?)
 }
 
+/// Token program from `--token-program` (defaults to SPL Token).
+fn token_program_option(options: &HashMap<String, String>) -> Result<Pubkey, Box<dyn Error>> {
+    options
+        .get("token-program")
+        .map_or(Ok(spl_token::id()), |value| parse_token_program(value))
+}
+
+/// Mint of an existing escrow, passed to claim/cancel so vault transfers are checked.
+fn escrow_token_mint(client: &RpcClient, escrow_pda: Pubkey) -> Result<Pubkey, Box<dyn Error>> {
+    let account = client.get_account(&escrow_pda)?;
+    Ok(Escrow::try_from_account_data(&account.data)?.token_mint)
+}
+
+/// Program that moves funds out of the vault: the system program for native SOL escrows.
+fn vault_program(token_mint: Pubkey, token_program: Pubkey) -> Pubkey {
+    if token_mint == NATIVE_SOL_MINT {
+        solana_sdk::system_program::id()
+    } else {
+        token_program
+    }
+}
+
 // ============================================================================
 // USAGE
 // ============================================================================
 
+/// Report a missing required option and exit non-zero.
+///
+/// JSON mode prints only the error object so stderr stays machine-readable.
+fn missing_required_option(options: &HashMap<String, String>, option: &str, command: &str) -> ! {
+    let message = format!("--{option} is required for '{command}'");
+    if options.contains_key("json") {
+        eprintln!("{}", format_error(true, &message));
+    } else {
+        eprintln!("Error: {message}");
+        print_usage();
+    }
+    std::process::exit(1);
+}
+
 fn print_usage() {
     eprintln!(
         r#"SVM Intent Escrow CLI
 
 Usage:
-  intent_escrow_cli <command> [--option value]...
+  intent_escrow_cli <command> [--option value]... [--simulate] [--json]
+
+  --simulate         Simulate instead of sending: prints compute units and program logs,
+                     exits non-zero if the transaction would fail. Applies to every
+                     command that submits a transaction.
+  --json             Print one JSON object on stdout instead of labelled lines; errors
+                     are printed as {{"error": "..."}} on stderr.
 
 Escrow Commands:
   initialize         --program-id <pubkey> --payer <keypair> --approver <pubkey> [--rpc <url>]
+                     Note: the payer becomes the admin allowed to run set-approver
+  set-approver       --program-id <pubkey> --payer <keypair> --admin <keypair> --new-approver <pubkey>
+                     [--rpc <url>]
+  migrate-state      --program-id <pubkey> --payer <keypair> --admin <keypair> [--rpc <url>]
+                     Note: grows a state created before set-approver; --admin must be the GMP config admin
   escrow-set-gmp-config  --program-id <pubkey> --payer <keypair> --hub-chain-id <u32>
                          --hub-address <hex> --gmp-endpoint <pubkey> [--rpc <url>]
   create-escrow      --program-id <pubkey> --payer <keypair> --requester <keypair> --token-mint <pubkey>
                      --requester-token <pubkey> --solver <pubkey> --intent-id <hex> --amount <u64>
-                     [--expiry <i64>] [--gmp-endpoint <pubkey>] [--hub-chain-id <u32>] [--rpc <url>]
+                     [--expiry <i64>] [--gmp-endpoint <pubkey>] [--hub-chain-id <u32>] [--memo <hex>]
+                     [--token-program <spl-token|token-2022>] [--rpc <url>]
                      Note: --gmp-endpoint enables sending EscrowConfirmation back to hub
+                     [--no-preflight] [--open] [--native-sol]
+                     Note: --memo attaches an opaque client correlation tag (up to 32 bytes)
+                     Note: --open creates a first-come escrow any solver can claim; --solver is
+                     then not needed
+                     Note: --native-sol escrows lamports from the requester wallet; --token-mint,
+                     --requester-token and --token-program are then not needed
+                     Note: checks the requester token account's mint, owner and balance before
+                     sending; --no-preflight skips this
   claim              --program-id <pubkey> --payer <keypair> --solver-token <pubkey> --intent-id <hex>
-                     --signature <hex> [--rpc <url>]
+                     --signature <hex> [--token-program <spl-token|token-2022>] [--rpc <url>]
   cancel             --program-id <pubkey> --payer <keypair> --admin <keypair> --requester-token <pubkey>
-                     --intent-id <hex> [--rpc <url>]
+                     --intent-id <hex> [--token-program <spl-token|token-2022>] [--rpc <url>]
+                     Note: for native SOL escrows pass wallets as --solver-token/--requester-token
   get-escrow         --program-id <pubkey> --intent-id <hex> [--rpc <url>]
+  watch-escrow       --program-id <pubkey> --intent-id <hex> [--interval-ms <u64>] [--timeout-ms <u64>]
+                     [--rpc <url>]
+                     Note: prints status transitions; exits 0 when claimed, 2 when expired
+                     or cancelled, 124 on timeout (default interval 2000 ms, no timeout)
   has-requirements   --program-id <pubkey> --intent-id <hex> [--rpc <url>]
   get-token-balance  --token-account <pubkey> [--rpc <url>]
+  derive-pdas        --program-id <pubkey> --intent-id <hex> [--gmp-program-id <pubkey>]
+                     [--hub-chain-id <u32>] [--rpc <url>]
+                     Note: read-only; GMP message PDA uses the current outbound nonce
 
 GMP Endpoint Commands:
   gmp-init           --gmp-program-id <pubkey> --payer <keypair> --chain-id <u32> [--rpc <url>]
@@ -831,6 +1624,22 @@ GMP Endpoint Commands:
                           --addr <hex> [--rpc <url>]
   gmp-set-routing    --gmp-program-id <pubkey> --payer <keypair> --outflow-validator <pubkey>
                      --intent-escrow <pubkey> [--rpc <url>]
+  gmp-set-payload-version-mode  --gmp-program-id <pubkey> --payer <keypair> --mode <strict|lenient>
+                     [--rpc <url>]
+  gmp-set-trusted-remote-mode  --gmp-program-id <pubkey> --payer <keypair> --mode <strict|permissive>
+                     [--rpc <url>]
+  gmp-set-relay-limit  --gmp-program-id <pubkey> --payer <keypair> --relay <pubkey>
+                     --max-per-window <u32> [--window-secs <u32>] [--rpc <url>]
+                     Note: --max-per-window 0 removes the limit
+  gmp-set-treasury   --gmp-program-id <pubkey> --payer <keypair> --treasury <pubkey> [--rpc <url>]
+  gmp-pause          --gmp-program-id <pubkey> --payer <keypair> [--rpc <url>]
+                     Note: Send and DeliverMessage fail until gmp-unpause
+  gmp-unpause        --gmp-program-id <pubkey> --payer <keypair> [--rpc <url>]
+  gmp-prune-delivered  --gmp-program-id <pubkey> --payer <keypair> --intent-id <hex> --msg-type <u8>
+                     [--rpc <url>]
+                     Note: fails until the delivered message is older than the retention period
+  gmp-reset-nonce    --gmp-program-id <pubkey> --payer <keypair> --dst-chain-id <u32> [--rpc <url>]
+                     Note: only available when built with --features test-only
 
 Outflow Validator Commands:
   outflow-init       --outflow-program-id <pubkey> --payer <keypair> --gmp-endpoint <pubkey>
@@ -838,6 +1647,9 @@ Outflow Validator Commands:
   outflow-update-hub-config
                      --outflow-program-id <pubkey> --payer <keypair>
                      --hub-chain-id <u32> --hub-address <hex> [--rpc <url>]
+  outflow-set-fee    --outflow-program-id <pubkey> --payer <keypair> --fee-bps <u16>
+                     --fee-recipient <pubkey> [--rpc <url>]
+                     Note: --fee-bps 0 disables the fee; solvers then pass no fee token account
         "#
     );
 }
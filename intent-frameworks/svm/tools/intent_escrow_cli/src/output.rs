@@ -0,0 +1,117 @@
+//! Output formatting for CLI commands.
+//!
+//! Handlers report results through [`CliOutput`] instead of printing directly, so the
+//! same handler serves both the default human-readable lines and `--json` mode.
+
+use serde_json::{Map, Value};
+use std::{collections::HashMap, fmt::Display};
+
+/// Result of sending a transaction: a confirmed signature, or a simulation when
+/// `--simulate` is set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxOutcome {
+    Sent(solana_sdk::signature::Signature),
+    Simulated {
+        /// Compute units the simulation consumed, if the RPC reported them
+        units_consumed: Option<u64>,
+        /// Program log lines from the simulation
+        logs: Vec<String>,
+    },
+}
+
+/// Where and how a command reports its results.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliOutput {
+    /// `Label: value` lines printed to stdout as they are reported
+    Human,
+    /// Fields collected into one JSON object, printed by [`CliOutput::finish`]
+    Json(Map<String, Value>),
+}
+
+impl CliOutput {
+    /// JSON mode when `--json` was given, human output otherwise.
+    pub fn from_options(options: &HashMap<String, String>) -> Self {
+        if options.contains_key("json") {
+            CliOutput::Json(Map::new())
+        } else {
+            CliOutput::Human
+        }
+    }
+
+    /// Report a field with its own human-readable line.
+    ///
+    /// In JSON mode the line is dropped and `value` is stored under `key`. Reporting
+    /// the same key again collects the values into an array.
+    pub fn record(&mut self, human_line: impl Display, key: &str, value: Value) {
+        match self {
+            CliOutput::Human => println!("{human_line}"),
+            CliOutput::Json(fields) => match fields.get_mut(key) {
+                Some(Value::Array(values)) => values.push(value),
+                Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+                None => {
+                    fields.insert(key.to_string(), value);
+                }
+            },
+        }
+    }
+
+    /// Report a field printed as `label: value` in human mode.
+    pub fn field<V: Into<Value> + Display>(&mut self, label: &str, key: &str, value: V) {
+        let line = format!("{label}: {value}");
+        self.record(line, key, value.into());
+    }
+
+    /// Print a human-only line; JSON mode omits it.
+    pub fn note(&mut self, line: impl Display) {
+        if let CliOutput::Human = self {
+            println!("{line}");
+        }
+    }
+
+    /// Report a transaction outcome as `label: <signature>`.
+    ///
+    /// Simulations report `simulated`, `units_consumed` and `logs` instead of a
+    /// signature; human mode prints the compute units and log lines first.
+    pub fn transaction(&mut self, label: &str, outcome: &TxOutcome) {
+        match outcome {
+            TxOutcome::Sent(signature) => self.field(label, "signature", signature.to_string()),
+            TxOutcome::Simulated {
+                units_consumed,
+                logs,
+            } => {
+                let units = match units_consumed {
+                    Some(units) => units.to_string(),
+                    None => "unknown".to_string(),
+                };
+                self.record(
+                    format!("Simulation compute units consumed: {units}"),
+                    "units_consumed",
+                    units_consumed.map_or(Value::Null, Value::from),
+                );
+                for line in logs {
+                    self.note(format!("  {line}"));
+                }
+                if let CliOutput::Json(fields) = self {
+                    fields.insert("logs".to_string(), Value::from(logs.clone()));
+                }
+                self.record(format!("{label}: (simulated, not sent)"), "simulated", Value::Bool(true));
+            }
+        }
+    }
+
+    /// Print the collected JSON object (no-op in human mode).
+    pub fn finish(self) {
+        if let CliOutput::Json(fields) = self {
+            println!("{}", Value::Object(fields));
+        }
+    }
+}
+
+/// Format an error for stderr: `{"error": "..."}` in JSON mode, plain text otherwise.
+pub fn format_error(json: bool, error: &dyn Display) -> String {
+    if json {
+        serde_json::json!({ "error": error.to_string() }).to_string()
+    } else {
+        format!("[intent_escrow_cli] Error: {error}")
+    }
+}
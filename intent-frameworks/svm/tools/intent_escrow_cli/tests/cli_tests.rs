@@ -1,8 +1,11 @@
 //! Unit tests for CLI parsing functions
 
 use intent_escrow_cli::{
-    hex_to_bytes32, parse_32_byte_hex, parse_options, parse_u32, required_option,
+    check_token_account, hex_to_bytes32, EscrowStatus,
+    output::{format_error, CliOutput, TxOutcome}, parse_32_byte_hex, parse_options, parse_token_program, parse_u32,
+    required_option,
 };
+use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
 
 // ============================================================================
@@ -171,6 +174,48 @@ fn test_parse_options_rejects_missing_value() {
     assert!(result.is_err());
 }
 
+/// What is tested: parse_options accepts --simulate without a value between other options
+/// Why: Dry runs are opt-in flags; requiring a value would swallow the next option name.
+#[test]
+fn test_parse_options_simulate_flag() {
+    let args = vec![
+        "--simulate".to_string(),
+        "--rpc".to_string(),
+        "http://localhost:8899".to_string(),
+    ];
+    let options = parse_options(&args).unwrap();
+    assert_eq!(options.get("simulate").unwrap(), "true");
+    assert_eq!(options.get("rpc").unwrap(), "http://localhost:8899");
+}
+
+/// What is tested: parse_options accepts --open without a value before other options
+/// Why: create-escrow --open is a switch; reading a value would swallow the next option name.
+#[test]
+fn test_parse_options_open_flag() {
+    let args = vec![
+        "--open".to_string(),
+        "--amount".to_string(),
+        "1000".to_string(),
+    ];
+    let options = parse_options(&args).unwrap();
+    assert_eq!(options.get("open").unwrap(), "true");
+    assert_eq!(options.get("amount").unwrap(), "1000");
+}
+
+/// What is tested: parse_options accepts --native-sol without a value
+/// Why: create-escrow --native-sol replaces the token arguments; it must not consume the next option.
+#[test]
+fn test_parse_options_native_sol_flag() {
+    let args = vec![
+        "--native-sol".to_string(),
+        "--amount".to_string(),
+        "1000".to_string(),
+    ];
+    let options = parse_options(&args).unwrap();
+    assert_eq!(options.get("native-sol").unwrap(), "true");
+    assert_eq!(options.get("amount").unwrap(), "1000");
+}
+
 /// What is tested: parse_options rejects arguments without -- prefix
 /// Why: "rpc http://..." is ambiguous. Requiring -- prefix makes the CLI consistent with standard conventions.
 #[test]
@@ -203,6 +248,26 @@ fn test_required_option_missing() {
     assert!(result.unwrap_err().to_string().contains("chain-id"));
 }
 
+// ============================================================================
+// parse_token_program TESTS
+// ============================================================================
+
+/// What is tested: parse_token_program maps both accepted names to their program IDs
+/// Why: The escrow creates the vault under this program; picking the wrong one makes Token-2022 mints unusable.
+#[test]
+fn test_parse_token_program_known_names() {
+    assert_eq!(parse_token_program("spl-token").unwrap(), spl_token::id());
+    assert_eq!(parse_token_program("token-2022").unwrap(), spl_token_2022::id());
+}
+
+/// What is tested: parse_token_program rejects unknown names and names the flag
+/// Why: A typo must not silently fall back to SPL Token for a Token-2022 mint.
+#[test]
+fn test_parse_token_program_rejects_unknown() {
+    let err = parse_token_program("token2022").unwrap_err();
+    assert!(err.to_string().contains("--token-program"));
+}
+
 // ============================================================================
 // hex_to_bytes32 TESTS
 // ============================================================================
@@ -248,3 +313,144 @@ fn test_hex_to_bytes32_odd_length_pads_nibble() {
 fn test_hex_to_bytes32_invalid_hex_panics() {
     hex_to_bytes32("0xZZZZ");
 }
+
+// ============================================================================
+// OUTPUT TESTS
+// ============================================================================
+
+/// What is tested: --json selects JSON output and collects fields into one object
+/// Why: Scripts parse a single JSON object per command; a dropped or mistyped field breaks them.
+#[test]
+fn test_cli_output_json_collects_fields() {
+    let args = vec!["--json".to_string()];
+    let options = parse_options(&args).unwrap();
+    let mut out = CliOutput::from_options(&options);
+    out.field("Escrow PDA", "escrow_pda", "abc".to_string());
+    out.field("Amount", "amount", 42u64);
+    out.note("human-only line");
+    match out {
+        CliOutput::Json(fields) => {
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields["escrow_pda"], "abc");
+            assert_eq!(fields["amount"], 42);
+        }
+        CliOutput::Human => panic!("expected JSON output"),
+    }
+}
+
+/// What is tested: without --json output stays human-readable
+/// Why: The default line-based output must not change for existing users and scripts.
+#[test]
+fn test_cli_output_defaults_to_human() {
+    let options = HashMap::new();
+    assert_eq!(CliOutput::from_options(&options), CliOutput::Human);
+}
+
+/// What is tested: reporting the same key twice collects the values into an array
+/// Why: Commands that list several accounts must not silently overwrite earlier entries.
+#[test]
+fn test_cli_output_json_repeated_key_becomes_array() {
+    let mut out = CliOutput::Json(Default::default());
+    out.field("Relay", "relay", "a".to_string());
+    out.field("Relay", "relay", "b".to_string());
+    out.field("Relay", "relay", "c".to_string());
+    match out {
+        CliOutput::Json(fields) => assert_eq!(fields["relay"], serde_json::json!(["a", "b", "c"])),
+        CliOutput::Human => panic!("expected JSON output"),
+    }
+}
+
+/// What is tested: a simulated transaction reports simulated, units_consumed and logs
+/// Why: --simulate --json is used to estimate compute budgets; the fields must be machine-readable.
+#[test]
+fn test_cli_output_json_simulated_transaction() {
+    let mut out = CliOutput::Json(Default::default());
+    let outcome = TxOutcome::Simulated {
+        units_consumed: Some(1234),
+        logs: vec!["Program log: ok".to_string()],
+    };
+    out.transaction("Initialize signature", &outcome);
+    match out {
+        CliOutput::Json(fields) => {
+            assert_eq!(fields["simulated"], true);
+            assert_eq!(fields["units_consumed"], 1234);
+            assert_eq!(fields["logs"], serde_json::json!(["Program log: ok"]));
+            assert!(!fields.contains_key("signature"));
+        }
+        CliOutput::Human => panic!("expected JSON output"),
+    }
+}
+
+/// What is tested: format_error emits {"error": ...} in JSON mode and the prefixed line otherwise
+/// Why: JSON consumers read stderr as JSON; human output keeps the existing error prefix.
+#[test]
+fn test_format_error_modes() {
+    let json: serde_json::Value = serde_json::from_str(&format_error(true, &"boom")).unwrap();
+    assert_eq!(json, serde_json::json!({ "error": "boom" }));
+    assert_eq!(format_error(false, &"boom"), "[intent_escrow_cli] Error: boom");
+}
+
+// ============================================================================
+// check_token_account TESTS
+// ============================================================================
+
+/// What is tested: check_token_account accepts a matching account with enough balance
+/// Why: The preflight check must not block valid create-escrow calls.
+#[test]
+fn test_check_token_account_ok() {
+    let (account, mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    assert!(check_token_account(&account, &mint, &owner, 100, &mint, &owner, 100).is_ok());
+}
+
+/// What is tested: check_token_account reports every mismatch in one error
+/// Why: Users should see all problems at once instead of fixing them one failed run at a time.
+#[test]
+fn test_check_token_account_lists_all_mismatches() {
+    let (account, mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let (other_mint, other_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let err = check_token_account(&account, &other_mint, &other_owner, 5, &mint, &owner, 10)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(&format!("mint is {other_mint}, expected {mint}")));
+    assert!(err.contains(&format!("owner is {other_owner}, expected {owner}")));
+    assert!(err.contains("balance is 5, need at least 10"));
+    assert!(err.contains("--no-preflight"));
+}
+
+/// What is tested: parse_options treats --no-preflight as a flag
+/// Why: Without a value the next option would otherwise be consumed as its argument.
+#[test]
+fn test_parse_options_no_preflight_flag() {
+    let args = vec![
+        "--no-preflight".to_string(),
+        "--amount".to_string(),
+        "10".to_string(),
+    ];
+    let options = parse_options(&args).unwrap();
+    assert_eq!(options.get("no-preflight").unwrap(), "true");
+    assert_eq!(options.get("amount").unwrap(), "10");
+}
+
+// ============================================================================
+// EscrowStatus TESTS
+// ============================================================================
+
+/// What is tested: EscrowStatus::from_escrow tells claimed from cancelled via the fulfilled flag
+/// Why: Both paths set is_claimed and drain the escrow; watch-escrow must not report a refund as a claim.
+#[test]
+fn test_escrow_status_claimed_vs_cancelled() {
+    assert_eq!(EscrowStatus::from_escrow(true, true, 100, 200), EscrowStatus::Claimed);
+    assert_eq!(EscrowStatus::from_escrow(true, false, 100, 200), EscrowStatus::Cancelled);
+    assert_eq!(EscrowStatus::Claimed.exit_code(), Some(0));
+    assert_eq!(EscrowStatus::Cancelled.exit_code(), Some(2));
+}
+
+/// What is tested: an unclaimed escrow is created up to and including expiry, then expired
+/// Why: The program allows claims while now <= expiry, so watch-escrow must keep polling until then.
+#[test]
+fn test_escrow_status_expiry_boundary() {
+    assert_eq!(EscrowStatus::from_escrow(false, false, 100, 100), EscrowStatus::Created);
+    assert_eq!(EscrowStatus::from_escrow(false, true, 100, 101), EscrowStatus::Expired);
+    assert_eq!(EscrowStatus::Created.exit_code(), None);
+    assert_eq!(EscrowStatus::Expired.exit_code(), Some(2));
+}
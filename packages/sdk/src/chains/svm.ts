@@ -36,6 +36,10 @@ export type EscrowAccount = {
   reservedSolver: PublicKey;
   intentId: Uint8Array;
   bump: number;
+  /** Client correlation tag supplied at creation (all zeros = none) */
+  memo: Uint8Array;
+  /** Open escrows can be claimed by any solver; reserved ones only by reservedSolver */
+  open: boolean;
 };
 
 // ============================================================================
@@ -172,6 +176,8 @@ export function parseEscrowAccount(data: Buffer): EscrowAccount {
   const reservedSolver = new PublicKey(data.slice(89, 121));
   const intentId = data.slice(121, 153);
   const bump = data.readUInt8(153);
+  const memo = data.slice(154, 186);
+  const open = data.readUInt8(186) === 1; // EscrowMode: Reserved=0, Open=1
 
   return {
     requester,
@@ -182,6 +188,8 @@ export function parseEscrowAccount(data: Buffer): EscrowAccount {
     reservedSolver,
     intentId,
     bump,
+    memo,
+    open,
   };
 }
 
@@ -258,13 +266,21 @@ function encodeU64(value: bigint | number): Buffer {
   return buffer;
 }
 
-function encodeCreateEscrowData(intentId: string, amount: bigint): Buffer {
+function encodeCreateEscrowData(
+  intentId: string,
+  amount: bigint,
+  memo?: string,
+  open?: boolean
+): Buffer {
   const intentIdBytes = Buffer.from(svmHexToBytes(intentId));
+  const memoBytes = memo ? Buffer.from(svmHexToBytes(memo)) : Buffer.alloc(32);
 
   return Buffer.concat([
     Buffer.from([3]), // EscrowInstruction::CreateEscrow (index 3: Initialize=0, GmpReceive=1, SetGmpConfig=2, CreateEscrow=3)
     intentIdBytes,
     encodeU64(amount),
+    memoBytes,
+    Buffer.from([open ? 1 : 0]), // EscrowMode: Reserved=0, Open=1
   ]);
 }
 
@@ -299,6 +315,10 @@ export function buildCreateEscrowInstruction(params: {
   reservedSolver: PublicKey;
   programId: PublicKey;
   gmpParams?: CreateEscrowGmpParams;
+  /** Optional client correlation tag (32-byte hex), stored on the escrow */
+  memo?: string;
+  /** Create a first-come escrow any solver can claim (reservedSolver is then ignored) */
+  open?: boolean;
 }): TransactionInstruction {
   const programId = params.programId;
   const [escrowPda] = getEscrowPda(params.intentId, programId);
@@ -358,7 +378,7 @@ export function buildCreateEscrowInstruction(params: {
   return new TransactionInstruction({
     programId,
     keys,
-    data: encodeCreateEscrowData(params.intentId, params.amount),
+    data: encodeCreateEscrowData(params.intentId, params.amount, params.memo, params.open),
   });
 }
 
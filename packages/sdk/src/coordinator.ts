@@ -141,6 +141,8 @@ export class CoordinatorClient {
     base_fee_in_move: number;
     move_rate: number;
     fee_bps: number;
+    stale?: boolean;
+    rate_age_secs?: number;
   }>> {
     const params = new URLSearchParams({
       offered_chain_id: offeredChainId.toString(),
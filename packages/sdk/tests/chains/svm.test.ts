@@ -41,6 +41,7 @@ const SOLVER = DUMMY_SOLVER_ADDR_SVM;
 const STATE_PDA = DUMMY_STATE_PDA;
 const ESCROW_PDA = DUMMY_ESCROW_PDA;
 const VAULT_PDA = DUMMY_VAULT_PDA;
+const MEMO = `0x${'ab'.repeat(32)}`;
 
 // ============================================================================
 // ADDRESS HELPER TESTS
@@ -122,7 +123,7 @@ describe('parseEscrowAccount', () => {
   /// Verifies that parseEscrowAccount correctly decodes raw escrow account data.
   /// Why: UI needs a stable decoding of on-chain escrow data.
   it('should parse escrow account data into a structured object', () => {
-    const data = Buffer.alloc(154);
+    const data = Buffer.alloc(187);
     Buffer.from('intent00').copy(data, 0);
     Buffer.from(REQUESTER.toBytes()).copy(data, 8);
     Buffer.from(TOKEN_MINT.toBytes()).copy(data, 40);
@@ -132,6 +133,8 @@ describe('parseEscrowAccount', () => {
     Buffer.from(SOLVER.toBytes()).copy(data, 89);
     Buffer.from(svmHexToBytes(INTENT_ID)).copy(data, 121);
     data.writeUInt8(42, 153);
+    Buffer.from(svmHexToBytes(MEMO)).copy(data, 154);
+    data.writeUInt8(1, 186);
 
     const escrow = parseEscrowAccount(data);
     expect(escrow.requester.toBase58()).toBe(REQUESTER.toBase58());
@@ -142,6 +145,8 @@ describe('parseEscrowAccount', () => {
     expect(escrow.reservedSolver.toBase58()).toBe(SOLVER.toBase58());
     expect(Buffer.from(escrow.intentId).toString('hex')).toBe(INTENT_ID.slice(2));
     expect(escrow.bump).toBe(42);
+    expect(Buffer.from(escrow.memo).toString('hex')).toBe(MEMO.slice(2));
+    expect(escrow.open).toBe(true);
   });
 });
 
@@ -170,7 +175,25 @@ describe('instruction builders', () => {
     expect(Buffer.from(instruction.data.subarray(1, 33))).toEqual(
       Buffer.from(svmHexToBytes(INTENT_ID))
     );
-    expect(instruction.data).toHaveLength(1 + 32 + 8);
+    expect(instruction.data).toHaveLength(1 + 32 + 8 + 32 + 1);
+    // No memo supplied: memo bytes default to zeros
+    expect(Buffer.from(instruction.data.subarray(41, 73))).toEqual(Buffer.alloc(32));
+    // Not open: mode defaults to Reserved
+    expect(instruction.data[73]).toBe(0);
+
+    const withMemo = buildCreateEscrowInstruction({
+      intentId: INTENT_ID,
+      amount: BigInt(500),
+      requester: REQUESTER,
+      requesterToken: REQUESTER,
+      tokenMint: TOKEN_MINT,
+      reservedSolver: SOLVER,
+      programId: PROGRAM_ID,
+      memo: MEMO,
+      open: true,
+    });
+    expect(Buffer.from(withMemo.data.subarray(41, 73))).toEqual(Buffer.from(svmHexToBytes(MEMO)));
+    expect(withMemo.data[73]).toBe(1);
   });
 
   /// 10. Test: Claim Instruction Layout
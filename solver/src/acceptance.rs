@@ -2,9 +2,14 @@
 //!
 //! Determines whether the solver should sign a draftintent based on:
 //! - Token pair validation (must be in configured supported pairs)
-//! - Exchange rate validation (offered amount must meet required rate for the pair)
+//! - Exchange rate validation (offered amount must meet required rate for the pair,
+//!   within the pair's slippage tolerance)
+//! - Fee validation (embedded fee must cover base fee plus percentage fee)
+//! - Profit validation (optional per-pair minimum profit after the gas estimate)
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 /// Token pair identifier for exchange rate lookup
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -26,6 +31,10 @@ pub struct TokenPairInfo {
     /// How many offered-token smallest units per 1 MOVE smallest unit (Octa).
     /// e.g., for USD tokens (6 decimals) with MOVE (8 decimals) at 1:1 price: 0.01
     pub move_rate: f64,
+    /// Minimum profit in basis points of the solver's cost (None skips the check)
+    pub min_profit_bps: Option<u64>,
+    /// How far (basis points) the draft's rate may fall short of `rate`
+    pub max_slippage_bps: u64,
 }
 
 /// Acceptance config structure
@@ -40,7 +49,7 @@ pub struct AcceptanceConfig {
 }
 
 /// Draft-intent data from coordinator API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DraftintentData {
     pub intent_id: String,          // Intent ID (hex string)
     pub offered_token: String,      // Contract address
@@ -56,7 +65,69 @@ pub struct DraftintentData {
 #[derive(Debug)]
 pub enum AcceptanceResult {
     Accept,
-    Reject(String),  // Reason for rejection
+    Reject(RejectionReason),
+}
+
+/// Why a draft intent was rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectionReason {
+    /// The token pair is not configured
+    UnsupportedPair {
+        offered_chain_id: u64,
+        offered_token: String,
+        desired_chain_id: u64,
+        desired_token: String,
+    },
+    /// The offered amount is below the configured rate, beyond the slippage tolerance
+    RateBelowRequired {
+        offered_amount: u64,
+        required_offered: u64,
+        rate: f64,
+        /// How far the draft's rate falls short of `rate`, in basis points
+        slippage_bps: u64,
+        max_slippage_bps: u64,
+    },
+    /// The fee embedded in the draft does not cover the solver's minimum fee
+    FeeBelowRequired {
+        fee_in_offered_token: u64,
+        required_fee: u64,
+        base_fee_in_move: u64,
+        min_fee_offered: u64,
+        fee_bps: u64,
+    },
+    /// Profit after the gas estimate is below the pair's floor
+    ProfitBelowMinimum {
+        /// Profit in basis points of the solver's cost (negative for a loss)
+        profit_bps: i64,
+        min_profit_bps: u64,
+    },
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedPair { offered_chain_id, offered_token, desired_chain_id, desired_token } => write!(
+                f,
+                "Token pair not supported: {}:{} -> {}:{}",
+                offered_chain_id, offered_token, desired_chain_id, desired_token
+            ),
+            Self::RateBelowRequired { offered_amount, required_offered, rate, slippage_bps, max_slippage_bps } => write!(
+                f,
+                "Swap rejected: offered {} < required {} (rate: {} offered/desired, slippage {} bps > max {} bps)",
+                offered_amount, required_offered, rate, slippage_bps, max_slippage_bps
+            ),
+            Self::FeeBelowRequired { fee_in_offered_token, required_fee, base_fee_in_move, min_fee_offered, fee_bps } => write!(
+                f,
+                "Fee rejected: fee_in_offered_token {} < required {} (base_fee_in_move: {} MOVE, min_fee_offered: {}, fee_bps: {})",
+                fee_in_offered_token, required_fee, base_fee_in_move, min_fee_offered, fee_bps
+            ),
+            Self::ProfitBelowMinimum { profit_bps, min_profit_bps } => write!(
+                f,
+                "Profit rejected: {} bps after gas estimate < minimum {} bps",
+                profit_bps, min_profit_bps
+            ),
+        }
+    }
 }
 
 /// Calculate the required fee for a given offered amount and fee parameters.
@@ -103,11 +174,12 @@ pub fn evaluate_draft_acceptance(draft: &DraftintentData, config: &AcceptanceCon
     let info = match config.token_pairs.get(&pair) {
         Some(info) => *info,
         None => {
-            return AcceptanceResult::Reject(format!(
-                "Token pair not supported: {}:{} -> {}:{}",
-                draft.offered_chain_id, draft.offered_token,
-                draft.desired_chain_id, draft.desired_token
-            ));
+            return AcceptanceResult::Reject(RejectionReason::UnsupportedPair {
+                offered_chain_id: draft.offered_chain_id,
+                offered_token: draft.offered_token.clone(),
+                desired_chain_id: draft.desired_chain_id,
+                desired_token: draft.desired_token.clone(),
+            });
         }
     };
 
@@ -116,11 +188,16 @@ pub fn evaluate_draft_acceptance(draft: &DraftintentData, config: &AcceptanceCon
     // required_offered = desired_amount * exchange_rate
     let required_offered = (draft.desired_amount as f64 * info.rate) as u64;
 
-    if draft.offered_amount < required_offered {
-        return AcceptanceResult::Reject(format!(
-            "Swap rejected: offered {} < required {} (rate: {} offered/desired)",
-            draft.offered_amount, required_offered, info.rate
-        ));
+    // The draft may fall short of the configured rate by at most max_slippage_bps
+    let slippage_bps = calculate_shortfall_bps(draft.offered_amount, required_offered);
+    if slippage_bps > info.max_slippage_bps {
+        return AcceptanceResult::Reject(RejectionReason::RateBelowRequired {
+            offered_amount: draft.offered_amount,
+            required_offered,
+            rate: info.rate,
+            slippage_bps,
+            max_slippage_bps: info.max_slippage_bps,
+        });
     }
 
     // Convert base_fee_in_move from MOVE to offered token using the pair's move_rate.
@@ -130,13 +207,46 @@ pub fn evaluate_draft_acceptance(draft: &DraftintentData, config: &AcceptanceCon
     // Validate fee_in_offered_token meets solver's minimum requirements
     let required_fee = calculate_required_fee(draft.offered_amount, min_fee_offered, info.fee_bps);
     if draft.fee_in_offered_token < required_fee {
-        return AcceptanceResult::Reject(format!(
-            "Fee rejected: fee_in_offered_token {} < required {} (base_fee_in_move: {} MOVE, min_fee_offered: {}, fee_bps: {})",
-            draft.fee_in_offered_token, required_fee, config.base_fee_in_move, min_fee_offered, info.fee_bps
-        ));
+        return AcceptanceResult::Reject(RejectionReason::FeeBelowRequired {
+            fee_in_offered_token: draft.fee_in_offered_token,
+            required_fee,
+            base_fee_in_move: config.base_fee_in_move,
+            min_fee_offered,
+            fee_bps: info.fee_bps,
+        });
+    }
+
+    // Profit after gas: the base fee (in offered token) is the solver's gas estimate
+    if let Some(min_profit_bps) = info.min_profit_bps {
+        let cost = required_offered.saturating_add(min_fee_offered);
+        let profit_bps = calculate_profit_bps(draft.offered_amount, cost);
+        if profit_bps < min_profit_bps as i64 {
+            return AcceptanceResult::Reject(RejectionReason::ProfitBelowMinimum {
+                profit_bps,
+                min_profit_bps,
+            });
+        }
     }
 
     AcceptanceResult::Accept
 }
 
+/// How far `offered` falls short of `required`, in basis points of `required`
+/// (rounded up; 0 when `offered` covers `required`).
+pub fn calculate_shortfall_bps(offered: u64, required: u64) -> u64 {
+    if offered >= required || required == 0 {
+        return 0;
+    }
+    let shortfall = (required - offered) as u128 * 10000;
+    shortfall.div_ceil(required as u128) as u64
+}
 
+/// Profit of receiving `offered` against `cost`, in basis points of `cost`
+/// (rounded down; negative for a loss).
+pub fn calculate_profit_bps(offered: u64, cost: u64) -> i64 {
+    if cost == 0 {
+        return i64::MAX;
+    }
+    let profit = offered as i128 - cost as i128;
+    (profit * 10000).div_euclid(cost as i128) as i64
+}
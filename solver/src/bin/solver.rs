@@ -19,6 +19,7 @@
 //! ```
 
 use anyhow::{Context, Result};
+use chain_clients_common::init_logging;
 use clap::Parser;
 use solver::{
     chains::HubChainClient,
@@ -39,6 +40,10 @@ struct Args {
     /// Path to solver configuration file (default: solver.toml or SOLVER_CONFIG_PATH env var)
     #[arg(short, long)]
     config: Option<String>,
+
+    /// Log one JSON object per line (same as LOG_FORMAT=json)
+    #[arg(long)]
+    log_json: bool,
 }
 
 #[tokio::main]
@@ -47,7 +52,7 @@ async fn main() -> Result<()> {
     let args = Args::parse();
 
     // Initialize structured logging
-    tracing_subscriber::fmt::init();
+    init_logging("solver", args.log_json);
 
     info!("Starting Solver Service");
 
@@ -280,7 +285,8 @@ async fn main() -> Result<()> {
 
     // Create shared intent tracker
     let tracker = Arc::new(IntentTracker::new(&config)?);
-    info!("Intent tracker initialized");
+    let recovered = tracker.recover().await?;
+    info!("Intent tracker initialized ({} intent(s) recovered)", recovered);
 
     // Create liquidity monitor
     let liquidity_monitor = Arc::new(
@@ -315,12 +321,21 @@ async fn main() -> Result<()> {
             }
         }
 
-        // Intent tracker loop (polls hub chain for created intents)
+        // Heartbeat loop (reports liveness to the coordinator)
+        result = signing_service.run_heartbeat() => {
+            if let Err(e) = result {
+                error!("Heartbeat loop error: {}", e);
+            }
+        }
+
+        // Intent tracker loop (polls hub chain for created intents, then drops
+        // reservations for drafts that reached a terminal state)
         _ = async {
             loop {
                 if let Err(e) = tracker.poll_for_created_intents().await {
                     error!("Intent tracker error: {}", e);
                 }
+                liquidity_monitor.reconcile_with_tracker(&tracker).await;
                 tokio::time::sleep(polling_interval).await;
             }
         } => {}
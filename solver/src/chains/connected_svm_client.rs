@@ -64,6 +64,8 @@ pub struct EscrowAccount {
     pub reserved_solver: Pubkey,
     pub intent_id: [u8; 32],
     pub bump: u8,
+    pub memo: [u8; 32],
+    pub mode: u8,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -270,7 +272,7 @@ impl ConnectedSvmClient {
     ///
     /// Builds and submits the `outflow_validator::FulfillIntent` instruction which:
     /// 1. Validates the solver is authorized and requirements exist
-    /// 2. Transfers tokens from solver to recipient
+    /// 2. Transfers tokens from solver to recipient (minus the protocol fee, if set)
     /// 3. Sends FulfillmentProof back to hub via GMP
     ///
     /// The hub will automatically release tokens when it receives the FulfillmentProof.
@@ -337,6 +339,26 @@ impl ConnectedSvmClient {
         let solver_token = get_associated_token_address(&solver.pubkey(), &token_mint)?;
         let recipient_token = get_associated_token_address(&recipient, &token_mint)?;
 
+        // Read config to see whether a protocol fee is taken, which adds the fee
+        // recipient's token account after the GMP endpoint program.
+        // Parse config: discriminator(1) + admin(32) + gmp_endpoint(32) + hub_chain_id(4) + hub_addr(32) + bump(1) + fee_bps(2) + fee_recipient(32)
+        // Accounts created before the fee was added end after bump (102 bytes) and take no fee.
+        let config_data = self.rpc_client
+            .get_account_data(&config_pda)
+            .context("Failed to fetch outflow validator config account")?;
+        let fee_token = if config_data.len() >= 136 {
+            let fee_bps = u16::from_le_bytes([config_data[102], config_data[103]]);
+            let fee_recipient = Pubkey::try_from(&config_data[104..136])
+                .context("Failed to parse fee_recipient from config")?;
+            if fee_bps > 0 {
+                Some(get_associated_token_address(&fee_recipient, &token_mint)?)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
         // Derive GMP endpoint PDAs
         let (gmp_config_pda, _) = Pubkey::find_program_address(
             &[b"config"],
@@ -373,26 +395,33 @@ impl ConnectedSvmClient {
         let mut instruction_data = vec![2u8]; // FulfillIntent variant index
         instruction_data.extend_from_slice(&intent_bytes);
 
+        let mut accounts = vec![
+            // FulfillIntent accounts
+            AccountMeta::new(requirements_pda, false),
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new_readonly(solver.pubkey(), true),
+            AccountMeta::new(solver_token, false),
+            AccountMeta::new(recipient_token, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(spl_token_program_id()?, false),
+            AccountMeta::new_readonly(gmp_endpoint_id, false),
+        ];
+        if let Some(fee_token) = fee_token {
+            accounts.push(AccountMeta::new(fee_token, false)); // fee recipient token account
+        }
+        accounts.extend([
+            // GMP Send accounts (passed through to CPI)
+            AccountMeta::new_readonly(gmp_config_pda, false),
+            AccountMeta::new(gmp_nonce_out_pda, false),
+            AccountMeta::new_readonly(solver.pubkey(), true), // sender
+            AccountMeta::new(solver.pubkey(), true),          // payer
+            AccountMeta::new_readonly(system_program_id()?, false),
+            AccountMeta::new(message_pda, false),             // message account
+        ]);
+
         let fulfill_ix = Instruction {
             program_id: outflow_program_id,
-            accounts: vec![
-                // FulfillIntent accounts
-                AccountMeta::new(requirements_pda, false),
-                AccountMeta::new_readonly(config_pda, false),
-                AccountMeta::new_readonly(solver.pubkey(), true),
-                AccountMeta::new(solver_token, false),
-                AccountMeta::new(recipient_token, false),
-                AccountMeta::new_readonly(token_mint, false),
-                AccountMeta::new_readonly(spl_token_program_id()?, false),
-                AccountMeta::new_readonly(gmp_endpoint_id, false),
-                // GMP Send accounts (passed through to CPI)
-                AccountMeta::new_readonly(gmp_config_pda, false),
-                AccountMeta::new(gmp_nonce_out_pda, false),
-                AccountMeta::new_readonly(solver.pubkey(), true), // sender
-                AccountMeta::new(solver.pubkey(), true),          // payer
-                AccountMeta::new_readonly(system_program_id()?, false),
-                AccountMeta::new(message_pda, false),             // message account
-            ],
+            accounts,
             data: instruction_data,
         };
 
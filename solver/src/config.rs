@@ -3,6 +3,7 @@
 //! This module handles loading and managing configuration for the solver service.
 //! Configuration includes coordinator connection, chain settings, and acceptance criteria.
 
+use chain_clients_common::expand_env_vars_in_toml;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
@@ -89,6 +90,10 @@ pub struct ServiceConfig {
     pub coordinator_url: String,
     /// Polling interval for checking pending drafts in milliseconds
     pub polling_interval_ms: u64,
+    /// Interval between signed heartbeats to the coordinator in milliseconds (keeps the
+    /// solver eligible for drafts when the coordinator tracks solver liveness)
+    #[serde(default = "default_heartbeat_interval_ms")]
+    pub heartbeat_interval_ms: u64,
     /// E2E testing mode: if true, use aptos CLI with profiles; if false, use movement CLI with private keys
     #[serde(default)]
     pub e2e_mode: bool,
@@ -98,6 +103,18 @@ pub struct ServiceConfig {
     /// Solver acceptance API port
     #[serde(default = "default_acceptance_api_port")]
     pub acceptance_api_port: u16,
+    /// Seconds an inflow escrow or intent may stay unmatched before alerting (unset = disabled)
+    #[serde(default)]
+    pub unmatched_alert_threshold_secs: Option<u64>,
+    /// Webhook URL that receives unmatched alerts as JSON POSTs (unset = log only)
+    #[serde(default)]
+    pub alert_webhook_url: Option<String>,
+    /// JSON file that persists in-flight tracked intents across restarts (unset = memory only)
+    #[serde(default)]
+    pub intent_store_path: Option<String>,
+    /// Maximum intents each fulfillment service processes at once (1 = sequential)
+    #[serde(default = "default_max_concurrent_fulfillments")]
+    pub max_concurrent_fulfillments: u64,
 }
 
 /// Configuration for a blockchain connection.
@@ -185,6 +202,14 @@ fn default_network_name() -> String {
     "localhost".to_string()
 }
 
+fn default_heartbeat_interval_ms() -> u64 {
+    15_000
+}
+
+fn default_max_concurrent_fulfillments() -> u64 {
+    1
+}
+
 fn default_acceptance_api_host() -> String {
     "127.0.0.1".to_string()
 }
@@ -229,6 +254,14 @@ pub struct TokenPairConfig {
     /// How many offered-token smallest units per 1 MOVE smallest unit (Octa).
     /// e.g., for USD tokens (6 decimals) with MOVE (8 decimals) at 1:1 price: 0.01
     pub move_rate: f64,
+    /// Minimum profit in basis points of the solver's cost (desired amount at `ratio`
+    /// plus the base fee as gas estimate). Unset skips the profit check.
+    #[serde(default)]
+    pub min_profit_bps: Option<u64>,
+    /// How far, in basis points, a draft's rate may fall short of `ratio` and still be
+    /// accepted. Default 0: the draft must meet `ratio` exactly.
+    #[serde(default)]
+    pub max_slippage_bps: u64,
 }
 
 /// Solver signing configuration.
@@ -268,7 +301,7 @@ impl SolverConfig {
         if std::path::Path::new(&config_path).exists() {
             // Load existing configuration
             let content = std::fs::read_to_string(&config_path)?;
-            let config: SolverConfig = toml::from_str(&content)?;
+            let config: SolverConfig = parse_toml(&config_path, &content)?;
             // Validate configuration
             config.validate()?;
             Ok(config)
@@ -306,6 +339,7 @@ impl SolverConfig {
     /// Validates the configuration for consistency and correctness.
     ///
     /// Checks:
+    /// - Each field holds a usable value (required fields per chain type, URL schemes, numeric ranges)
     /// - At least one connected chain is configured
     /// - Hub and connected chains have different chain IDs
     /// - All connected chains have unique chain IDs
@@ -317,6 +351,8 @@ impl SolverConfig {
     /// * `Ok(())` - Configuration is valid
     /// * `Err(anyhow::Error)` - Validation failed with error message
     pub fn validate(&self) -> anyhow::Result<()> {
+        self.validate_fields()?;
+
         // Check at least one connected chain is configured
         if self.connected_chain.is_empty() {
             return Err(anyhow::anyhow!(
@@ -406,6 +442,18 @@ impl SolverConfig {
                     pair.target_token
                 ));
             }
+
+            // Validate max_slippage_bps is within range (0-10000 basis points = 0-100%)
+            if pair.max_slippage_bps > 10000 {
+                return Err(anyhow::anyhow!(
+                    "Invalid max_slippage_bps {} for token pair {}:{} -> {}:{}: must be <= 10000 (100%)",
+                    pair.max_slippage_bps,
+                    pair.source_chain_id,
+                    pair.source_token,
+                    pair.target_chain_id,
+                    pair.target_token
+                ));
+            }
         }
 
         // Validate liquidity config
@@ -421,6 +469,11 @@ impl SolverConfig {
                     "Configuration error: liquidity.in_flight_timeout_secs must be > 0"
                 ));
             }
+            if liq.reservation_ledger_path.as_deref().is_some_and(|p| p.trim().is_empty()) {
+                return Err(anyhow::anyhow!(
+                    "Configuration error: liquidity.reservation_ledger_path must not be empty (omit it to keep reservations in memory)"
+                ));
+            }
             for threshold in &liq.thresholds {
                 let chain_type = self.chain_type_for_id(threshold.chain_id)
                     .ok_or_else(|| anyhow::anyhow!(
@@ -483,6 +536,62 @@ impl SolverConfig {
         Ok(())
     }
 
+    /// Validates individual fields, reporting the first invalid one by its TOML path
+    /// (e.g. `service.acceptance_api_port must be 1..65535, got 0`).
+    fn validate_fields(&self) -> anyhow::Result<()> {
+        require_http_url("service.coordinator_url", &self.service.coordinator_url)?;
+        require_positive("service.polling_interval_ms", self.service.polling_interval_ms)?;
+        require_positive("service.heartbeat_interval_ms", self.service.heartbeat_interval_ms)?;
+        require_non_empty("service.acceptance_api_host", &self.service.acceptance_api_host)?;
+        require_port("service.acceptance_api_port", self.service.acceptance_api_port)?;
+        require_positive(
+            "service.max_concurrent_fulfillments",
+            self.service.max_concurrent_fulfillments,
+        )?;
+        if let Some(threshold) = self.service.unmatched_alert_threshold_secs {
+            require_positive("service.unmatched_alert_threshold_secs", threshold)?;
+        }
+        if let Some(ref url) = self.service.alert_webhook_url {
+            require_http_url("service.alert_webhook_url", url)?;
+        }
+        if self.service.intent_store_path.as_deref().is_some_and(|p| p.trim().is_empty()) {
+            return Err(anyhow::anyhow!(
+                "Configuration error: service.intent_store_path must not be empty (omit it to keep intents in memory)"
+            ));
+        }
+
+        require_non_empty("hub_chain.name", &self.hub_chain.name)?;
+        require_http_url("hub_chain.rpc_url", &self.hub_chain.rpc_url)?;
+        require_non_empty("hub_chain.module_addr", &self.hub_chain.module_addr)?;
+
+        for (i, chain) in self.connected_chain.iter().enumerate() {
+            let field = format!("connected_chain[{}]", i);
+            match chain {
+                ConnectedChainConfig::Mvm(cfg) => {
+                    require_non_empty(&format!("{}.name", field), &cfg.name)?;
+                    require_http_url(&format!("{}.rpc_url", field), &cfg.rpc_url)?;
+                    require_non_empty(&format!("{}.module_addr", field), &cfg.module_addr)?;
+                }
+                ConnectedChainConfig::Evm(cfg) => {
+                    require_non_empty(&format!("{}.name", field), &cfg.name)?;
+                    require_http_url(&format!("{}.rpc_url", field), &cfg.rpc_url)?;
+                    require_non_empty(&format!("{}.escrow_contract_addr", field), &cfg.escrow_contract_addr)?;
+                    require_non_empty(&format!("{}.private_key_env", field), &cfg.private_key_env)?;
+                }
+                ConnectedChainConfig::Svm(cfg) => {
+                    require_non_empty(&format!("{}.name", field), &cfg.name)?;
+                    require_http_url(&format!("{}.rpc_url", field), &cfg.rpc_url)?;
+                    require_non_empty(&format!("{}.escrow_program_id", field), &cfg.escrow_program_id)?;
+                    require_non_empty(&format!("{}.private_key_env", field), &cfg.private_key_env)?;
+                }
+            }
+        }
+
+        require_non_empty("solver.address", &self.solver.address)?;
+
+        Ok(())
+    }
+
     /// Converts token pair configs to TokenPair structs with rate and fee info.
     ///
     /// This is a helper method for the acceptance module and API to use.
@@ -505,6 +614,8 @@ impl SolverConfig {
                 rate: pair.ratio,
                 fee_bps: pair.fee_bps,
                 move_rate: pair.move_rate,
+                min_profit_bps: pair.min_profit_bps,
+                max_slippage_bps: pair.max_slippage_bps,
             });
         }
 
@@ -524,6 +635,10 @@ pub struct LiquidityMonitorConfig {
     /// How long before an in-flight commitment is considered failed and released (seconds)
     #[serde(default = "default_in_flight_timeout_secs")]
     pub in_flight_timeout_secs: u64,
+    /// File to persist in-flight reservations to, so they survive restarts.
+    /// Unset keeps reservations in memory only.
+    #[serde(default)]
+    pub reservation_ledger_path: Option<String>,
     /// Minimum balance thresholds per chain+token
     #[serde(rename = "threshold", default)]
     pub thresholds: Vec<LiquidityThresholdConfig>,
@@ -630,3 +745,47 @@ fn validate_hex_token(token: &str, expected_len: usize) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn require_non_empty(field: &str, value: &str) -> anyhow::Result<()> {
+    if value.trim().is_empty() {
+        anyhow::bail!("Configuration error: {} must not be empty", field);
+    }
+    Ok(())
+}
+
+fn require_positive(field: &str, value: u64) -> anyhow::Result<()> {
+    if value == 0 {
+        anyhow::bail!("Configuration error: {} must be greater than 0, got 0", field);
+    }
+    Ok(())
+}
+
+fn require_port(field: &str, port: u16) -> anyhow::Result<()> {
+    if port == 0 {
+        anyhow::bail!("Configuration error: {} must be 1..65535, got 0", field);
+    }
+    Ok(())
+}
+
+fn require_http_url(field: &str, url: &str) -> anyhow::Result<()> {
+    require_non_empty(field, url)?;
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        anyhow::bail!(
+            "Configuration error: {} must start with http:// or https://, got '{}'",
+            field,
+            url
+        );
+    }
+    Ok(())
+}
+
+/// Parses TOML read from `source`, expanding `${ENV_VAR}` references in string values.
+fn parse_toml<T: serde::de::DeserializeOwned>(source: &str, content: &str) -> anyhow::Result<T> {
+    let mut value: toml::Value = toml::from_str(content).map_err(|e| {
+        anyhow::anyhow!("Configuration error: failed to parse '{}': {}", source, e)
+    })?;
+    expand_env_vars_in_toml(&mut value)
+        .map_err(|e| anyhow::anyhow!("Configuration error: in '{}': {}", source, e))?;
+    value.try_into().map_err(|e| {
+        anyhow::anyhow!("Configuration error: failed to parse '{}': {}", source, e)
+    })
+}
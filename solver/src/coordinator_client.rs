@@ -3,8 +3,9 @@
 //! HTTP client for communicating with the coordinator (drafts, negotiation).
 
 use anyhow::{Context, Result};
+use ed25519_dalek::{Signer, SigningKey};
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 // ============================================================================
 // API RESPONSE WRAPPER
@@ -61,6 +62,17 @@ pub struct SignatureSubmission {
     pub public_key: String,
 }
 
+/// Body actually posted for a signature submission: the submission plus the draft it is
+/// for and when it was sent, so the request signature cannot be replayed on another
+/// draft or later.
+#[derive(Serialize)]
+struct SignedSignatureSubmission<'a> {
+    #[serde(flatten)]
+    submission: &'a SignatureSubmission,
+    draft_id: &'a str,
+    timestamp: u64,
+}
+
 /// Response structure for signature submission.
 #[derive(Debug, Clone, Deserialize)]
 pub struct SignatureSubmissionResponse {
@@ -81,6 +93,24 @@ pub struct SignatureResponse {
     pub timestamp: u64,
 }
 
+/// Request structure for a solver heartbeat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolverHeartbeat {
+    /// Hub solver address sending the heartbeat
+    pub solver_hub_addr: String,
+    /// Unix timestamp at which the heartbeat was sent
+    pub timestamp: u64,
+}
+
+/// Response structure for a solver heartbeat.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SolverHeartbeatResponse {
+    /// Hub solver address that sent the heartbeat
+    pub solver_hub_addr: String,
+    /// Last-seen timestamp recorded by the coordinator
+    pub last_seen: u64,
+}
+
 // ============================================================================
 // COORDINATOR CLIENT
 // ============================================================================
@@ -94,6 +124,8 @@ pub struct CoordinatorClient {
     base_url: String,
     /// HTTP client instance
     client: reqwest::blocking::Client,
+    /// Ed25519 private key used to sign signature submissions and heartbeats (X-Solver-Signature header)
+    request_signing_key: Option<[u8; 32]>,
 }
 
 impl CoordinatorClient {
@@ -116,9 +148,25 @@ impl CoordinatorClient {
         Self {
             base_url: base_url.into(),
             client,
+            request_signing_key: None,
         }
     }
 
+    /// Sign signature submissions and heartbeats with the solver's hub private key.
+    ///
+    /// The Ed25519 signature over the raw request body is sent in the `X-Solver-Signature`
+    /// header so the coordinator can verify it against the solver's registered public key.
+    /// Signature submission bodies name the draft and carry a timestamp, so the signature
+    /// is bound to that draft and time.
+    ///
+    /// # Arguments
+    ///
+    /// * `private_key` - Solver's Ed25519 private key (32 bytes)
+    pub fn with_request_signing_key(mut self, private_key: [u8; 32]) -> Self {
+        self.request_signing_key = Some(private_key);
+        self
+    }
+
     /// Poll for pending draftintents.
     ///
     /// Returns all pending drafts (all solvers see all drafts).
@@ -171,10 +219,18 @@ impl CoordinatorClient {
     ) -> Result<SignatureSubmissionResponse> {
         let url = format!("{}/draftintent/{}/signature", self.base_url, draft_id);
 
+        let signed_submission = SignedSignatureSubmission {
+            submission,
+            draft_id,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .context("System clock is before the Unix epoch")?
+                .as_secs(),
+        };
+        let body = serde_json::to_vec(&signed_submission)
+            .context("Failed to serialize signature submission")?;
         let http_response = self
-            .client
-            .post(&url)
-            .json(submission)
+            .signed_post(&url, body)
             .send()
             .context("Failed to send POST /draftintent/:id/signature request")?;
 
@@ -199,4 +255,60 @@ impl CoordinatorClient {
 
         Ok(response.data.context("Missing data in successful response")?)
     }
+
+    /// Report to the coordinator that this solver is online.
+    ///
+    /// The coordinator only accepts heartbeats signed with the solver's registered key,
+    /// so the client must be created with `with_request_signing_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `solver_hub_addr` - Hub address of this solver
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SolverHeartbeatResponse)` - Heartbeat recorded
+    /// * `Err(anyhow::Error)` - Failed to send heartbeat or coordinator rejected it
+    pub fn send_heartbeat(&self, solver_hub_addr: &str) -> Result<SolverHeartbeatResponse> {
+        let url = format!("{}/solver/heartbeat", self.base_url);
+
+        let heartbeat = SolverHeartbeat {
+            solver_hub_addr: solver_hub_addr.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .context("System clock is before the Unix epoch")?
+                .as_secs(),
+        };
+        let body = serde_json::to_vec(&heartbeat).context("Failed to serialize heartbeat")?;
+        let response: ApiResponse<SolverHeartbeatResponse> = self
+            .signed_post(&url, body)
+            .send()
+            .context("Failed to send POST /solver/heartbeat request")?
+            .json()
+            .context("Failed to parse POST /solver/heartbeat response")?;
+
+        if !response.success {
+            return Err(anyhow::anyhow!(
+                "Coordinator API error: {}",
+                response.error.unwrap_or_else(|| "Unknown error".to_string())
+            ));
+        }
+
+        response.data.context("Missing data in successful response")
+    }
+
+    /// Build a JSON POST request, signing the raw body into the `X-Solver-Signature`
+    /// header when a request signing key is set.
+    fn signed_post(&self, url: &str, body: Vec<u8>) -> reqwest::blocking::RequestBuilder {
+        let mut http_request = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(private_key) = &self.request_signing_key {
+            let signing_key = SigningKey::from_bytes(private_key);
+            let request_signature = hex::encode(signing_key.sign(&body).to_bytes());
+            http_request = http_request.header("X-Solver-Signature", request_signature);
+        }
+        http_request.body(body)
+    }
 }
@@ -0,0 +1,93 @@
+//! Solver Error Types
+//!
+//! Typed errors for the acceptance, signing and fulfillment paths, so the service
+//! loops can tell a retriable RPC timeout from a permanent rejection. Lower layers
+//! (chain clients, CLI wrappers) still return `anyhow::Error`; the constructors here
+//! classify those at the boundary of the core paths.
+
+/// Error from the acceptance, signing or fulfillment paths.
+#[derive(Debug, thiserror::Error)]
+pub enum SolverError {
+    /// An RPC or CLI call timed out; the operation may succeed on the next poll
+    #[error("RPC timed out: {0}")]
+    RpcTimeout(String),
+    /// The draft or intent cannot be accepted as submitted (malformed or invalid)
+    #[error("Intent rejected: {0}")]
+    IntentRejected(String),
+    /// Loading the solver key or signing the intent hash failed
+    #[error("Signing failed: {0}")]
+    SigningFailed(String),
+    /// The fulfillment transaction was submitted and failed on-chain
+    #[error("Fulfillment reverted: {0}")]
+    FulfillmentReverted(String),
+    /// The solver configuration does not cover the requested chain or token
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+    /// Any other failure
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+/// Result type of the solver's core paths.
+pub type SolverResult<T> = std::result::Result<T, SolverError>;
+
+/// Lowercase markers of a timed-out RPC or CLI call in an error message.
+const TIMEOUT_MARKERS: &[&str] = &["timed out", "timeout", "deadline has elapsed"];
+
+/// Lowercase markers of a transaction that failed on-chain.
+const REVERT_MARKERS: &[&str] = &[
+    "reverted",
+    "move abort",
+    "move run failed",
+    "transaction failed",
+    "script failed",
+];
+
+impl SolverError {
+    /// Whether the failed operation should simply be retried on the next poll,
+    /// without counting against the retry budget.
+    pub fn is_retriable(&self) -> bool {
+        matches!(self, SolverError::RpcTimeout(_))
+    }
+
+    /// Classifies an RPC error: timeouts become [`SolverError::RpcTimeout`],
+    /// anything else [`SolverError::Other`].
+    pub fn from_rpc(error: anyhow::Error) -> Self {
+        if is_timeout(&error) {
+            SolverError::RpcTimeout(format!("{:#}", error))
+        } else {
+            SolverError::Other(error)
+        }
+    }
+
+    /// Classifies a fulfillment error: timeouts become [`SolverError::RpcTimeout`],
+    /// on-chain failures [`SolverError::FulfillmentReverted`], anything else
+    /// [`SolverError::Other`].
+    pub fn from_fulfillment(error: anyhow::Error) -> Self {
+        if is_timeout(&error) {
+            return SolverError::RpcTimeout(format!("{:#}", error));
+        }
+        let message = format!("{:#}", error).to_lowercase();
+        if REVERT_MARKERS.iter().any(|marker| message.contains(marker)) {
+            SolverError::FulfillmentReverted(format!("{:#}", error))
+        } else {
+            SolverError::Other(error)
+        }
+    }
+}
+
+/// Whether any error in the chain is a timeout.
+fn is_timeout(error: &anyhow::Error) -> bool {
+    let typed = error.chain().any(|cause| {
+        cause.downcast_ref::<reqwest::Error>().is_some_and(|e| e.is_timeout())
+            || cause
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == std::io::ErrorKind::TimedOut)
+            || cause.is::<tokio::time::error::Elapsed>()
+    });
+    if typed {
+        return true;
+    }
+    let message = format!("{:#}", error).to_lowercase();
+    TIMEOUT_MARKERS.iter().any(|marker| message.contains(marker))
+}
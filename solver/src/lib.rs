@@ -7,6 +7,7 @@ pub mod api;
 pub mod chains;
 pub mod config;
 pub mod crypto;
+pub mod error;
 pub mod service;
 pub mod coordinator_client;
 
@@ -14,6 +15,7 @@ pub mod coordinator_client;
 pub use acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, TokenPair, TokenPairInfo, calculate_required_fee, convert_base_fee_in_move_to_offered};
 pub use chains::{ConnectedEvmClient, ConnectedMvmClient, HubChainClient};
 pub use config::{SolverConfig, SolverSigningConfig};
+pub use error::{SolverError, SolverResult};
 pub use crypto::{get_intent_hash, get_private_key_from_profile, sign_intent_hash};
 pub use service::inflow::InflowService;
 pub use service::outflow::OutflowService;
@@ -21,6 +23,6 @@ pub use service::signing::SigningService;
 pub use service::tracker::{IntentState, IntentTracker, TrackedIntent, MAX_OUTFLOW_RETRIES};
 pub use coordinator_client::{
     ApiResponse, CoordinatorClient, PendingDraft, SignatureSubmission,
-    SignatureSubmissionResponse,
+    SignatureSubmissionResponse, SolverHeartbeat, SolverHeartbeatResponse,
 };
 
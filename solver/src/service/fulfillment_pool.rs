@@ -0,0 +1,97 @@
+//! Bounded Concurrent Fulfillment
+//!
+//! Runs a batch of per-intent fulfillments concurrently, with at most
+//! `service.max_concurrent_fulfillments` in flight at once. Fulfillments signed by
+//! the same solver account (same desired chain, whatever the token) are serialized,
+//! so two transactions never race on the account's balance or the chain's nonce or
+//! sequence number.
+//!
+//! Each fulfillment service owns one pool. A fulfillment waiting on its account lock
+//! does not hold a permit, so it never blocks intents on other chains.
+
+use crate::service::tracker::TrackedIntent;
+use futures::future::join_all;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
+
+/// Runs fulfillments concurrently up to a bound, serializing per signing account.
+pub struct FulfillmentPool {
+    /// Permits for in-flight fulfillments
+    permits: Arc<Semaphore>,
+    /// Maximum in-flight fulfillments
+    max_in_flight: usize,
+    /// Per-account locks, keyed by `account_key`; pruned after every batch
+    account_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl FulfillmentPool {
+    /// Creates a pool that runs at most `max_in_flight` fulfillments at once (minimum 1).
+    pub fn new(max_in_flight: usize) -> Self {
+        let max_in_flight = max_in_flight.max(1);
+        Self {
+            permits: Arc::new(Semaphore::new(max_in_flight)),
+            max_in_flight,
+            account_locks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Maximum in-flight fulfillments.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Runs `fulfill` for every item and returns the results in input order.
+    ///
+    /// Items whose `account_key` is equal run one after another; all others run
+    /// concurrently up to the pool bound.
+    pub async fn run_all<T, R, K, F, Fut>(&self, items: Vec<T>, account_key: K, fulfill: F) -> Vec<R>
+    where
+        K: Fn(&T) -> String,
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = R>,
+    {
+        // Account lock first, then a permit: a queued same-account item holds no permit
+        let tasks = items.into_iter().map(|item| {
+            let account_lock = self.account_lock(account_key(&item));
+            let fulfillment = fulfill(item);
+            async move {
+                let _account = account_lock.lock().await;
+                let _permit = self
+                    .permits
+                    .acquire()
+                    .await
+                    .expect("fulfillment semaphore is never closed");
+                fulfillment.await
+            }
+        });
+        let results = join_all(tasks).await;
+
+        // Drop locks no batch holds anymore so the map does not grow with every token seen
+        self.account_locks
+            .lock()
+            .unwrap()
+            .retain(|_, lock| Arc::strong_count(lock) > 1);
+        results
+    }
+
+    /// Returns the lock for `key`, creating it on first use.
+    fn account_lock(&self, key: String) -> Arc<tokio::sync::Mutex<()>> {
+        self.account_locks
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_default()
+            .clone()
+    }
+}
+
+/// Key of the solver account that signs a fulfillment: the desired chain.
+///
+/// Inflow intents pay out on the hub and outflow intents on the connected chain; in
+/// both cases the solver signs on `desired_chain_id` with its single key for that
+/// chain, so every token on the chain shares one nonce or sequence number.
+pub fn signer_account_key(intent: &TrackedIntent) -> String {
+    intent.draft_data.desired_chain_id.to_string()
+}
@@ -10,13 +10,16 @@
 
 use crate::chains::{ConnectedEvmClient, ConnectedMvmClient, ConnectedSvmClient, HubChainClient};
 use crate::config::{ConnectedChainConfig, SolverConfig};
+use crate::error::{SolverError, SolverResult};
 use chain_clients_common::normalize_intent_id;
+use crate::service::fulfillment_pool::{signer_account_key, FulfillmentPool};
 use crate::service::liquidity::LiquidityMonitor;
+use crate::service::mismatch::{MismatchKind, MismatchTracker, UnmatchedAlertNotifier};
 use crate::service::tracker::{IntentTracker, TrackedIntent};
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{error, info, warn};
 
 /// Inflow fulfillment service that monitors escrows and fulfills intents
@@ -37,6 +40,12 @@ pub struct InflowService {
     liquidity_monitor: Arc<LiquidityMonitor>,
     /// Last poll summary (pending_count, escrow_count) — suppresses repeated identical logs
     last_poll_summary: Mutex<Option<(usize, usize)>>,
+    /// Unmatched escrow/intent tracker (None when `unmatched_alert_threshold_secs` is unset)
+    mismatch_tracker: Option<MismatchTracker>,
+    /// Notifier for unmatched alerts (log + optional webhook)
+    alert_notifier: UnmatchedAlertNotifier,
+    /// Bounds concurrent hub fulfillments and serializes same-account ones
+    fulfillment_pool: FulfillmentPool,
 }
 
 /// Helper struct for matching escrow events to intents
@@ -83,6 +92,14 @@ impl InflowService {
             }
         }
 
+        let mismatch_tracker = config
+            .service
+            .unmatched_alert_threshold_secs
+            .map(MismatchTracker::new);
+        let alert_notifier = UnmatchedAlertNotifier::new(config.service.alert_webhook_url.clone());
+        let fulfillment_pool =
+            FulfillmentPool::new(config.service.max_concurrent_fulfillments as usize);
+
         Ok(Self {
             config,
             tracker,
@@ -92,6 +109,9 @@ impl InflowService {
             svm_clients,
             liquidity_monitor,
             last_poll_summary: Mutex::new(None),
+            mismatch_tracker,
+            alert_notifier,
+            fulfillment_pool,
         })
     }
 
@@ -111,7 +131,17 @@ impl InflowService {
             .get_intents_ready_for_fulfillment(Some(true))
             .await;
 
-        if pending_intents.is_empty() {
+        // Signed inflow intents whose escrow may appear before the hub intent; only
+        // needed for unmatched-escrow alerting
+        let awaiting_hub_intents = if self.mismatch_tracker.is_some() {
+            self.tracker.get_signed_inflow_intents().await
+        } else {
+            Vec::new()
+        };
+
+        if pending_intents.is_empty() && awaiting_hub_intents.is_empty() {
+            self.track_unmatched(&[], &[], &[], &[]).await;
+
             // Debug: check if there are any Created intents at all
             let all_created = self.tracker.get_intents_ready_for_fulfillment(None).await;
             if !all_created.is_empty() {
@@ -252,14 +282,86 @@ impl InflowService {
             info!("Matched {} intents with escrows", matched_intents.len());
         }
 
+        self.track_unmatched(
+            &pending_intents,
+            &awaiting_hub_intents,
+            &evm_svm_escrow_events,
+            &matched_intents,
+        )
+        .await;
+
         Ok(matched_intents)
     }
 
+    /// Updates unmatched escrow/intent tracking after a poll and raises due alerts.
+    ///
+    /// - Escrows observed for signed intents whose hub intent is not yet seen are
+    ///   tracked as `EscrowWithoutIntent`
+    /// - Created inflow intents without a matching escrow are tracked as
+    ///   `IntentWithoutEscrow`
+    /// - Matched intents are cleared; entries whose intent is no longer pending
+    ///   (fulfilled, expired, untracked) are dropped
+    ///
+    /// No-op when `service.unmatched_alert_threshold_secs` is unset.
+    async fn track_unmatched(
+        &self,
+        pending_intents: &[TrackedIntent],
+        awaiting_hub_intents: &[TrackedIntent],
+        escrow_events: &[EscrowMatch],
+        matched_intents: &[(TrackedIntent, String)],
+    ) {
+        let Some(ref mismatch_tracker) = self.mismatch_tracker else {
+            return;
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let awaiting_hub_ids: HashSet<String> = awaiting_hub_intents
+            .iter()
+            .map(|intent| normalize_intent_id(&intent.intent_id))
+            .collect();
+        let pending_ids: HashSet<String> = pending_intents
+            .iter()
+            .map(|intent| normalize_intent_id(&intent.intent_id))
+            .collect();
+        let matched_ids: HashSet<String> = matched_intents
+            .iter()
+            .map(|(intent, _)| normalize_intent_id(&intent.intent_id))
+            .collect();
+
+        for escrow in escrow_events {
+            let intent_id = normalize_intent_id(&escrow.intent_id);
+            if awaiting_hub_ids.contains(&intent_id) {
+                mismatch_tracker.observe(&intent_id, MismatchKind::EscrowWithoutIntent, now);
+            }
+        }
+        for intent_id in &pending_ids {
+            if matched_ids.contains(intent_id) {
+                mismatch_tracker.resolve(intent_id);
+            } else {
+                mismatch_tracker.observe(intent_id, MismatchKind::IntentWithoutEscrow, now);
+            }
+        }
+
+        // Entries no longer awaiting a match (fulfilled, expired, untracked) are dropped
+        mismatch_tracker.retain(|intent_id, kind| match kind {
+            MismatchKind::EscrowWithoutIntent => awaiting_hub_ids.contains(intent_id),
+            MismatchKind::IntentWithoutEscrow => pending_ids.contains(intent_id),
+        });
+
+        for alert in mismatch_tracker.due_alerts(now) {
+            self.alert_notifier.notify(&alert).await;
+        }
+    }
+
     /// Fulfills an inflow intent on the hub chain
     ///
     /// Calls `fulfill_inflow_intent` on the hub chain to provide tokens
     /// to the requester. This should be called after detecting a matching escrow
-    /// on the connected chain.
+    /// on the connected chain. The CLI call runs on a blocking thread so concurrent
+    /// fulfillments do not stall each other.
     ///
     /// # Arguments
     ///
@@ -269,26 +371,80 @@ impl InflowService {
     /// # Returns
     ///
     /// * `Ok(String)` - Transaction hash
-    /// * `Err(anyhow::Error)` - Failed to fulfill intent
-    pub fn fulfill_inflow_intent(
+    /// * `Err(SolverError)` - Failed to fulfill intent; `RpcTimeout` is worth retrying
+    pub async fn fulfill_inflow_intent(
         &self,
         intent: &TrackedIntent,
         payment_amount: u64,
-    ) -> Result<String> {
+    ) -> SolverResult<String> {
         let intent_addr = intent
             .intent_addr
-            .as_ref()
+            .clone()
             .context("Intent address not set (intent not yet created on-chain)")?;
 
-        self.hub_client
-            .fulfill_inflow_intent(intent_addr, payment_amount)
+        let hub_config = self.config.hub_chain.clone();
+        tokio::task::spawn_blocking(move || {
+            let hub_client = HubChainClient::new(&hub_config)?;
+            hub_client.fulfill_inflow_intent(&intent_addr, payment_amount)
+        })
+        .await
+        .context("Failed to spawn blocking task for hub inflow fulfillment")
+        .and_then(|result| result)
+        .map_err(SolverError::from_fulfillment)
+    }
+
+    /// Fulfills one inflow intent whose escrow was found, then marks it fulfilled
+    /// and releases its liquidity budget. Failures are logged; the next poll retries.
+    async fn fulfill_matched_intent(&self, intent: TrackedIntent, escrow_id: String) {
+        info!(
+            "Found escrow {} for inflow intent: {}",
+            escrow_id, intent.intent_id
+        );
+
+        // Fulfill intent on hub chain
+        match self
+            .fulfill_inflow_intent(&intent, intent.draft_data.desired_amount)
+            .await
+        {
+            Ok(tx_hash) => {
+                info!(
+                    "Successfully fulfilled inflow intent {} on hub chain: {}",
+                    intent.intent_id, tx_hash
+                );
+                // Mark intent as fulfilled IMMEDIATELY after successful fulfillment
+                // This prevents retrying fulfillment on next poll
+                if let Err(e) = self.tracker.mark_fulfilled(&intent.draft_id).await {
+                    warn!("Failed to mark intent as fulfilled: {}", e);
+                }
+                // Release liquidity budget for this draft
+                self.liquidity_monitor.release(&intent.draft_id).await;
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                if e.is_retriable() || msg.contains("E_ESCROW_NOT_CONFIRMED") {
+                    warn!(
+                        "Inflow intent {} not yet confirmed on hub (will retry): {}",
+                        intent.intent_id, e
+                    );
+                } else {
+                    error!(
+                        "Failed to fulfill inflow intent {}: {}",
+                        intent.intent_id, e
+                    );
+                }
+            }
+        }
+
+        // GMP auto-release: tokens are transferred to solver automatically when
+        // FulfillmentProof arrives on connected chain. No action needed from solver.
     }
 
     /// Runs the inflow fulfillment service loop
     ///
     /// This function continuously:
     /// 1. Polls for escrows matching tracked inflow intents
-    /// 2. Fulfills intents on hub chain when escrows are detected
+    /// 2. Fulfills intents on hub chain when escrows are detected, up to
+    ///    `service.max_concurrent_fulfillments` at once
     /// 3. Releases escrows after getting fulfillment confirmation
     ///
     /// The loop runs at the configured polling interval.
@@ -302,52 +458,15 @@ impl InflowService {
         loop {
             match self.poll_for_escrows().await {
                 Ok(intents_with_escrows) => {
-                    for (intent, escrow_id) in intents_with_escrows {
-                        info!(
-                            "Found escrow {} for inflow intent: {}",
-                            escrow_id, intent.intent_id
-                        );
-
-                        // Fulfill intent on hub chain
-                        match self
-                            .fulfill_inflow_intent(&intent, intent.draft_data.desired_amount)
-                        {
-                            Ok(tx_hash) => {
-                                info!(
-                                    "Successfully fulfilled inflow intent {} on hub chain: {}",
-                                    intent.intent_id, tx_hash
-                                );
-                                // Mark intent as fulfilled IMMEDIATELY after successful fulfillment
-                                // This prevents retrying fulfillment on next poll
-                                if let Err(e) =
-                                    self.tracker.mark_fulfilled(&intent.draft_id).await
-                                {
-                                    warn!("Failed to mark intent as fulfilled: {}", e);
-                                }
-                                // Release liquidity budget for this draft
-                                self.liquidity_monitor.release(&intent.draft_id).await;
-                            }
-                            Err(e) => {
-                                let msg = e.to_string();
-                                if msg.contains("E_ESCROW_NOT_CONFIRMED") {
-                                    warn!(
-                                        "Inflow intent {} not yet confirmed on hub (will retry): {}",
-                                        intent.intent_id, e
-                                    );
-                                } else {
-                                    error!(
-                                        "Failed to fulfill inflow intent {}: {}",
-                                        intent.intent_id, e
-                                    );
-                                }
-                                continue;
-                            }
-                        }
-
-                        // GMP auto-release: tokens are transferred to solver automatically when
-                        // FulfillmentProof arrives on connected chain. No action needed from solver.
-                        // The solver can immediately move on to the next intent.
-                    }
+                    // Independent intents are fulfilled concurrently; intents paying from
+                    // the same hub token account are serialized by the pool
+                    self.fulfillment_pool
+                        .run_all(
+                            intents_with_escrows,
+                            |(intent, _)| signer_account_key(intent),
+                            |(intent, escrow_id)| self.fulfill_matched_intent(intent, escrow_id),
+                        )
+                        .await;
                 }
                 Err(e) => {
                     error!("Failed to poll for escrows: {}", e);
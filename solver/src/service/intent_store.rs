@@ -0,0 +1,83 @@
+//! Intent Store Persistence
+//!
+//! Persists the intent tracker's in-flight intents to a JSON file so a solver restart
+//! can resume them. Without it, a restart forgets every signed or created intent, and
+//! an outflow whose connected-chain fulfillment already went out is never claimed on hub.
+//!
+//! Only non-terminal intents (Signed, Created) are stored; Fulfilled, Expired and
+//! Failed intents are dropped on the next save.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::service::tracker::TrackedIntent;
+
+/// On-disk file layout.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IntentStoreFile {
+    intents: Vec<TrackedIntent>,
+}
+
+/// Reads and writes the intent store file.
+#[derive(Debug, Clone)]
+pub struct IntentStore {
+    path: PathBuf,
+}
+
+impl IntentStore {
+    /// Creates a store backed by `path`. The file is created on first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the store file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads persisted intents.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TrackedIntent>)` - Stored intents (empty if the file does not exist yet)
+    /// * `Err(anyhow::Error)` - File exists but could not be read or parsed
+    pub fn load(&self) -> Result<Vec<TrackedIntent>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path).with_context(|| {
+            format!("Failed to read intent store '{}'", self.path.display())
+        })?;
+        let file: IntentStoreFile = serde_json::from_str(&content).with_context(|| {
+            format!("Failed to parse intent store '{}'", self.path.display())
+        })?;
+        Ok(file.intents)
+    }
+
+    /// Replaces the store contents with the non-terminal entries of `intents`.
+    ///
+    /// Writes to a temporary file and renames it over the store, so a crash mid-write
+    /// leaves the previous store intact.
+    pub fn save<'a>(&self, intents: impl IntoIterator<Item = &'a TrackedIntent>) -> Result<()> {
+        let file = IntentStoreFile {
+            intents: intents
+                .into_iter()
+                .filter(|i| !i.state.is_terminal())
+                .cloned()
+                .collect(),
+        };
+        let content = serde_json::to_string_pretty(&file)
+            .context("Failed to serialize intent store")?;
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        std::fs::write(&tmp_path, content).with_context(|| {
+            format!("Failed to write intent store '{}'", self.path.display())
+        })?;
+        std::fs::rename(&tmp_path, &self.path).with_context(|| {
+            format!("Failed to replace intent store '{}'", self.path.display())
+        })?;
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 use solana_sdk::pubkey::Pubkey;
@@ -15,6 +15,8 @@ use tracing::{error, info, warn};
 
 use crate::chains::{ConnectedEvmClient, ConnectedMvmClient, ConnectedSvmClient, HubChainClient};
 use crate::config::{gas_token_for_chain_type, ConnectedChainConfig, LiquidityMonitorConfig, SolverConfig};
+use crate::service::reservation_ledger::{PersistedReservation, ReservationLedger};
+use crate::service::tracker::{IntentState, IntentTracker};
 
 /// Identifies a specific token on a specific chain.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -65,6 +67,8 @@ pub struct LiquidityMonitor {
     svm_clients: HashMap<u64, ConnectedSvmClient>,
     /// Solver wallet address on each chain, keyed by chain_id.
     solver_addresses: HashMap<u64, String>,
+    /// On-disk copy of in-flight reservations (None = in-memory only)
+    ledger: Option<ReservationLedger>,
 }
 
 impl LiquidityMonitor {
@@ -165,6 +169,41 @@ impl LiquidityMonitor {
             });
         }
 
+        // Restore reservations persisted before the last restart
+        let ledger = liquidity_config
+            .reservation_ledger_path
+            .as_ref()
+            .map(ReservationLedger::new);
+        if let Some(ref ledger) = ledger {
+            let restored = ledger.load()?;
+            let now_unix = unix_now();
+            for reservation in &restored {
+                let age = Duration::from_secs(now_unix.saturating_sub(reservation.reserved_at));
+                let chain_token = ChainToken {
+                    chain_id: reservation.chain_id,
+                    token: reservation.token.clone(),
+                };
+                initial_state
+                    .entry(chain_token)
+                    .or_insert(TokenLiquidity {
+                        confirmed_balance: 0,
+                        last_updated: Instant::now(),
+                        in_flight: Vec::new(),
+                    })
+                    .in_flight
+                    .push(InFlightCommitment {
+                        draft_id: reservation.draft_id.clone(),
+                        amount: reservation.amount,
+                        committed_at: Instant::now().checked_sub(age).unwrap_or_else(Instant::now),
+                    });
+            }
+            info!(
+                "Restored {} reservation(s) from ledger {}",
+                restored.len(),
+                ledger.path().display()
+            );
+        }
+
         info!(
             "Liquidity monitor initialized: tracking {} token(s) across chains",
             initial_state.len()
@@ -179,6 +218,7 @@ impl LiquidityMonitor {
             evm_clients,
             svm_clients,
             solver_addresses,
+            ledger,
         })
     }
 
@@ -246,6 +286,7 @@ impl LiquidityMonitor {
             liquidity.available_budget()
         );
 
+        self.persist(&state);
         Ok(())
     }
 
@@ -275,11 +316,61 @@ impl LiquidityMonitor {
             if liquidity.in_flight.len() < before {
                 liquidity.confirmed_balance = liquidity.confirmed_balance.saturating_sub(spent);
                 info!("Released budget for draft {}", draft_id);
+                self.persist(&state);
                 return;
             }
         }
     }
 
+    /// Drop reservations whose draft reached a terminal state in `tracker`
+    /// (fulfilled, expired, or failed) without being released.
+    ///
+    /// Unlike `release`, the confirmed balance is left alone: an expired or failed
+    /// draft never spent the funds, and the next balance poll corrects the rest.
+    /// Drafts unknown to the tracker (e.g. restored after a restart) are kept until
+    /// the in-flight timeout.
+    ///
+    /// # Returns
+    ///
+    /// Number of reservations dropped.
+    pub async fn reconcile_with_tracker(&self, tracker: &IntentTracker) -> usize {
+        let draft_ids: Vec<String> = {
+            let state = self.state.read().await;
+            state
+                .values()
+                .flat_map(|l| l.in_flight.iter().map(|c| c.draft_id.clone()))
+                .collect()
+        };
+
+        let mut stale = std::collections::HashSet::new();
+        for draft_id in draft_ids {
+            if let Some(intent) = tracker.get_intent(&draft_id).await {
+                if matches!(
+                    intent.state,
+                    IntentState::Fulfilled | IntentState::Expired | IntentState::Failed
+                ) {
+                    stale.insert(draft_id);
+                }
+            }
+        }
+        if stale.is_empty() {
+            return 0;
+        }
+
+        let mut state = self.state.write().await;
+        let mut removed = 0;
+        for liquidity in state.values_mut() {
+            let before = liquidity.in_flight.len();
+            liquidity.in_flight.retain(|c| !stale.contains(&c.draft_id));
+            removed += before - liquidity.in_flight.len();
+        }
+        if removed > 0 {
+            info!("Reconciled away {} stale reservation(s) for terminal drafts", removed);
+            self.persist(&state);
+        }
+        removed
+    }
+
     /// Check if there is sufficient budget for a given amount on a chain+token.
     pub async fn has_sufficient_budget(&self, chain_token: &ChainToken, amount: u64) -> bool {
         let state = self.state.read().await;
@@ -466,6 +557,7 @@ impl LiquidityMonitor {
     async fn cleanup_expired_commitments(&self) {
         let timeout = Duration::from_secs(self.config.in_flight_timeout_secs);
         let mut state = self.state.write().await;
+        let mut any_removed = false;
 
         for (chain_token, liquidity) in state.iter_mut() {
             let before = liquidity.in_flight.len();
@@ -487,12 +579,41 @@ impl LiquidityMonitor {
             });
             let removed = before - liquidity.in_flight.len();
             if removed > 0 {
+                any_removed = true;
                 info!(
                     "Cleaned up {} expired commitment(s) for chain {} token {}",
                     removed, chain_token.chain_id, chain_token.token
                 );
             }
         }
+        if any_removed {
+            self.persist(&state);
+        }
+    }
+
+    /// Write all in-flight reservations to the ledger, if one is configured.
+    ///
+    /// Failures are logged and not propagated; the in-memory state stays authoritative.
+    fn persist(&self, state: &HashMap<ChainToken, TokenLiquidity>) {
+        let Some(ref ledger) = self.ledger else {
+            return;
+        };
+        let now_unix = unix_now();
+        let reservations: Vec<PersistedReservation> = state
+            .iter()
+            .flat_map(|(chain_token, liquidity)| {
+                liquidity.in_flight.iter().map(move |c| PersistedReservation {
+                    draft_id: c.draft_id.clone(),
+                    chain_id: chain_token.chain_id,
+                    token: chain_token.token.clone(),
+                    amount: c.amount,
+                    reserved_at: now_unix.saturating_sub(c.committed_at.elapsed().as_secs()),
+                })
+            })
+            .collect();
+        if let Err(e) = ledger.save(&reservations) {
+            error!("Failed to persist reservation ledger: {:#}", e);
+        }
     }
 
     /// Log warnings for any chain+token where available budget is below threshold.
@@ -548,6 +669,14 @@ fn to_base58_pubkey(value: &str) -> Result<String> {
     }
 }
 
+/// Current Unix time in seconds.
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
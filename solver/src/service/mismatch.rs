@@ -0,0 +1,218 @@
+//! Unmatched Escrow / Intent Tracking
+//!
+//! Tracks inflow intents and escrows that have been observed on one side of the
+//! cross-chain link but not the other. A mismatch that persists past the configured
+//! threshold raises a single prominent alert (error log plus optional webhook POST);
+//! the entry is cleared once the match arrives.
+//!
+//! Two kinds of mismatch are tracked:
+//! - **Escrow without intent**: an escrow for one of our signed intents exists on the
+//!   connected chain, but the hub intent has not been observed
+//! - **Intent without escrow**: the hub intent exists, but no matching escrow has been
+//!   observed on the connected chain
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+/// Which side of the cross-chain link is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MismatchKind {
+    /// Escrow observed on the connected chain, hub intent not observed
+    EscrowWithoutIntent,
+    /// Hub intent observed, escrow not observed on the connected chain
+    IntentWithoutEscrow,
+}
+
+/// Alert raised when a mismatch outlives the threshold. Also the webhook body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnmatchedAlert {
+    /// Service that raised the alert
+    pub service: String,
+    /// Normalized intent ID linking the escrow and the hub intent
+    pub intent_id: String,
+    /// Which side is missing
+    pub kind: MismatchKind,
+    /// Seconds since the mismatch was first observed
+    pub age_secs: u64,
+}
+
+/// A tracked mismatch.
+#[derive(Debug, Clone)]
+struct UnmatchedEntry {
+    kind: MismatchKind,
+    first_seen: u64,
+    alerted: bool,
+}
+
+// ============================================================================
+// TRACKER
+// ============================================================================
+
+/// Tracks unmatched escrows/intents by intent ID and alerts once per mismatch.
+pub struct MismatchTracker {
+    /// Age (seconds) after which an unmatched entry raises an alert
+    threshold_secs: u64,
+    /// Unmatched entries keyed by normalized intent ID
+    entries: Mutex<HashMap<String, UnmatchedEntry>>,
+}
+
+impl MismatchTracker {
+    /// Creates a tracker that alerts on mismatches older than `threshold_secs`.
+    pub fn new(threshold_secs: u64) -> Self {
+        Self {
+            threshold_secs,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records that `intent_id` is unmatched as of `now`.
+    ///
+    /// The first observation starts the age clock; later observations keep it. If the
+    /// missing side changes, the entry restarts as a new mismatch.
+    pub fn observe(&self, intent_id: &str, kind: MismatchKind, now: u64) {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(intent_id) {
+            Some(entry) if entry.kind == kind => {}
+            _ => {
+                entries.insert(
+                    intent_id.to_string(),
+                    UnmatchedEntry {
+                        kind,
+                        first_seen: now,
+                        alerted: false,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Clears the entry for `intent_id` because its match arrived.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - An entry was cleared
+    /// * `false` - The intent ID was not tracked
+    pub fn resolve(&self, intent_id: &str) -> bool {
+        let removed = self.entries.lock().unwrap().remove(intent_id);
+        match removed {
+            Some(entry) => {
+                if entry.alerted {
+                    info!(
+                        "Unmatched {:?} for intent {} resolved: match arrived",
+                        entry.kind, intent_id
+                    );
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drops entries for which `keep` returns false (e.g. the intent expired or is no
+    /// longer tracked), without treating them as matched.
+    pub fn retain<F>(&self, mut keep: F)
+    where
+        F: FnMut(&str, MismatchKind) -> bool,
+    {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|intent_id, entry| keep(intent_id, entry.kind));
+    }
+
+    /// Returns alerts for entries that passed the threshold and have not alerted yet.
+    ///
+    /// Each entry is returned at most once for as long as it stays unmatched.
+    pub fn due_alerts(&self, now: u64) -> Vec<UnmatchedAlert> {
+        let mut entries = self.entries.lock().unwrap();
+        let mut alerts: Vec<UnmatchedAlert> = entries
+            .iter_mut()
+            .filter(|(_, entry)| !entry.alerted)
+            .filter_map(|(intent_id, entry)| {
+                let age_secs = now.saturating_sub(entry.first_seen);
+                if age_secs < self.threshold_secs {
+                    return None;
+                }
+                entry.alerted = true;
+                Some(UnmatchedAlert {
+                    service: "solver".to_string(),
+                    intent_id: intent_id.clone(),
+                    kind: entry.kind,
+                    age_secs,
+                })
+            })
+            .collect();
+        alerts.sort_by(|a, b| a.intent_id.cmp(&b.intent_id));
+        alerts
+    }
+
+    /// Number of currently unmatched entries.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Whether no entries are currently unmatched.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// ============================================================================
+// NOTIFIER
+// ============================================================================
+
+/// Sends unmatched alerts to the log and the optional notification webhook.
+#[derive(Debug, Clone)]
+pub struct UnmatchedAlertNotifier {
+    webhook_url: Option<String>,
+    client: reqwest::Client,
+}
+
+impl UnmatchedAlertNotifier {
+    /// Create a notifier. With no webhook URL, alerts are only logged.
+    pub fn new(webhook_url: Option<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .no_proxy()
+            .build()
+            .unwrap_or_default();
+        Self { webhook_url, client }
+    }
+
+    /// Log the alert as critical and fire the webhook.
+    ///
+    /// Webhook failures are logged and never propagated; alerting must not
+    /// interrupt the inflow loop.
+    pub async fn notify(&self, alert: &UnmatchedAlert) {
+        error!(
+            "ALERT: intent {} unmatched for {}s ({:?}); cross-chain linking may be broken",
+            alert.intent_id, alert.age_secs, alert.kind
+        );
+
+        let Some(ref url) = self.webhook_url else {
+            return;
+        };
+
+        match self.client.post(url).json(alert).send().await {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => warn!(
+                "Alert webhook {} returned status {} for intent {}",
+                url,
+                response.status(),
+                alert.intent_id
+            ),
+            Err(e) => warn!(
+                "Failed to send alert webhook for intent {}: {}",
+                alert.intent_id, e
+            ),
+        }
+    }
+}
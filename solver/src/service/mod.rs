@@ -3,16 +3,24 @@
 //! This module contains service implementations for the solver,
 //! including the signing service loop, intent tracking, and fulfillment services.
 
+pub mod fulfillment_pool;
 pub mod inflow;
+pub mod intent_store;
 pub mod liquidity;
+pub mod mismatch;
 pub mod outflow;
+pub mod reservation_ledger;
 pub mod signing;
 pub mod tracker;
 
 // Re-export for convenience
+pub use fulfillment_pool::FulfillmentPool;
 pub use inflow::InflowService;
+pub use intent_store::IntentStore;
 pub use liquidity::LiquidityMonitor;
+pub use mismatch::{MismatchKind, MismatchTracker, UnmatchedAlert};
 pub use outflow::OutflowService;
+pub use reservation_ledger::{PersistedReservation, ReservationLedger};
 pub use signing::{parse_draft_data, SigningService};
 pub use tracker::{IntentState, IntentTracker, TrackedIntent};
 
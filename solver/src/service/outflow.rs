@@ -12,6 +12,8 @@
 
 use crate::chains::{ConnectedEvmClient, ConnectedMvmClient, ConnectedSvmClient, HubChainClient};
 use crate::config::{ConnectedChainConfig, SolverConfig};
+use crate::error::{SolverError, SolverResult};
+use crate::service::fulfillment_pool::{signer_account_key, FulfillmentPool};
 use crate::service::liquidity::LiquidityMonitor;
 use crate::service::tracker::{IntentTracker, TrackedIntent};
 use anyhow::{Context, Result};
@@ -34,6 +36,8 @@ pub struct OutflowService {
     svm_clients: HashMap<u64, ConnectedSvmClient>,
     /// Liquidity monitor for releasing budget after fulfillment
     liquidity_monitor: Arc<LiquidityMonitor>,
+    /// Bounds concurrent connected-chain transfers and serializes same-account ones
+    fulfillment_pool: FulfillmentPool,
 }
 
 impl OutflowService {
@@ -72,6 +76,9 @@ impl OutflowService {
             }
         }
 
+        let fulfillment_pool =
+            FulfillmentPool::new(config.service.max_concurrent_fulfillments as usize);
+
         Ok(Self {
             config,
             tracker,
@@ -79,6 +86,7 @@ impl OutflowService {
             evm_clients,
             svm_clients,
             liquidity_monitor,
+            fulfillment_pool,
         })
     }
     
@@ -127,74 +135,110 @@ impl OutflowService {
         }
 
         info!("Found {} pending outflow intent(s)", pending_intents.len());
-        let mut executed_transfers = Vec::new();
 
         let current_time = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs();
 
-        for intent in pending_intents {
-            if intent.outflow_attempted {
-                warn!(
-                    "Skipping outflow intent {}: transfer already succeeded",
-                    intent.intent_id
-                );
-                continue;
-            }
+        // Independent intents are fulfilled concurrently; intents paying from the same
+        // connected-chain token account are serialized by the pool
+        let executed_transfers = self
+            .fulfillment_pool
+            .run_all(
+                pending_intents,
+                signer_account_key,
+                |intent| self.execute_pending_intent(intent, current_time),
+            )
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
 
-            // Skip intents in backoff period
-            if intent.next_retry_after > current_time {
-                tracing::debug!(
-                    "Skipping outflow intent {}: in backoff (retry after {}, now {})",
-                    intent.intent_id, intent.next_retry_after, current_time
-                );
-                continue;
-            }
+        Ok(executed_transfers)
+    }
 
-            // Get requester_addr_connected_chain from intent
-            let requester_addr_connected_chain = match self.get_requester_address_connected_chain(&intent).await {
-                Ok(addr) => addr,
-                Err(e) => {
-                    warn!("Failed to get requester_addr_connected_chain for intent {}: {}", intent.intent_id, e);
-                    continue;
-                }
-            };
+    /// Executes the connected-chain transfer for one pending outflow intent.
+    ///
+    /// Skips intents whose transfer already succeeded or that are in backoff, and
+    /// records non-retriable failures against `MAX_OUTFLOW_RETRIES`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((TrackedIntent, String))` - The intent and transaction hash of the transfer
+    /// * `None` - Skipped or failed; the next poll decides whether to try again
+    async fn execute_pending_intent(
+        &self,
+        intent: TrackedIntent,
+        current_time: u64,
+    ) -> Option<(TrackedIntent, String)> {
+        // Connected-chain fulfillment already succeeded; the hub claim is handled by
+        // claim_pending_outflows()
+        if intent.outflow_attempted {
+            tracing::debug!(
+                "Skipping outflow intent {}: transfer already succeeded, awaiting hub claim",
+                intent.intent_id
+            );
+            return None;
+        }
 
-            // Execute fulfillment on connected chain via GMP
-            let tx_hash = match self.execute_connected_transfer(&intent, &requester_addr_connected_chain).await {
-                Ok(hash) => hash,
-                Err(e) => {
-                    let error_msg = format!("{:#}", e);
-                    error!(
-                        "Failed to execute fulfillment for intent {} (attempt {}/{}): {}",
-                        intent.intent_id,
-                        intent.outflow_attempt_count + 1,
-                        crate::service::tracker::MAX_OUTFLOW_RETRIES,
-                        error_msg
-                    );
-                    // Record failure — increments retry count, sets backoff, or transitions to Failed
-                    if let Err(record_err) = self.tracker.record_outflow_failure(&intent.intent_id, &error_msg).await {
-                        error!("Failed to record outflow failure for intent {}: {}", intent.intent_id, record_err);
-                    }
-                    continue;
-                }
-            };
+        // Skip intents in backoff period
+        if intent.next_retry_after > current_time {
+            tracing::debug!(
+                "Skipping outflow intent {}: in backoff (retry after {}, now {})",
+                intent.intent_id, intent.next_retry_after, current_time
+            );
+            return None;
+        }
 
-            // Mark as attempted only AFTER successful transfer to prevent duplicate transfers
-            if let Err(e) = self.tracker.mark_outflow_attempted(&intent.intent_id).await {
-                error!(
-                    "Failed to mark outflow intent {} as attempted: {}",
+        // Get requester_addr_connected_chain from intent
+        let requester_addr_connected_chain = match self.get_requester_address_connected_chain(&intent).await {
+            Ok(addr) => addr,
+            Err(e) => {
+                warn!("Failed to get requester_addr_connected_chain for intent {}: {}", intent.intent_id, e);
+                return None;
+            }
+        };
+
+        // Execute fulfillment on connected chain via GMP
+        let tx_hash = match self.execute_connected_transfer(&intent, &requester_addr_connected_chain).await {
+            Ok(hash) => hash,
+            Err(e) if e.is_retriable() => {
+                // Not counted against MAX_OUTFLOW_RETRIES; the next poll tries again
+                warn!(
+                    "Transient error executing fulfillment for intent {} (will retry): {}",
                     intent.intent_id, e
                 );
-                // Continue anyway - transfer already succeeded
+                return None;
             }
-
-            info!("Executed GMP outflow fulfillment for intent {}: tx_hash={}", intent.intent_id, tx_hash);
-            executed_transfers.push((intent, tx_hash));
+            Err(e) => {
+                let error_msg = format!("{:#}", e);
+                error!(
+                    "Failed to execute fulfillment for intent {} (attempt {}/{}): {}",
+                    intent.intent_id,
+                    intent.outflow_attempt_count + 1,
+                    crate::service::tracker::MAX_OUTFLOW_RETRIES,
+                    error_msg
+                );
+                // Record failure — increments retry count, sets backoff, or transitions to Failed
+                if let Err(record_err) = self.tracker.record_outflow_failure(&intent.intent_id, &error_msg).await {
+                    error!("Failed to record outflow failure for intent {}: {}", intent.intent_id, record_err);
+                }
+                return None;
+            }
+        };
+
+        // Mark as attempted only AFTER successful transfer to prevent duplicate transfers
+        if let Err(e) = self.tracker.mark_outflow_attempted(&intent.intent_id).await {
+            error!(
+                "Failed to mark outflow intent {} as attempted: {}",
+                intent.intent_id, e
+            );
+            // Continue anyway - transfer already succeeded
         }
 
-        Ok(executed_transfers)
+        info!("Executed GMP outflow fulfillment for intent {}: tx_hash={}", intent.intent_id, tx_hash);
+        Some((intent, tx_hash))
     }
 
     /// Waits for GMP IntentRequirements to arrive on the MVM connected chain,
@@ -250,8 +294,21 @@ impl OutflowService {
             tokio::time::sleep(poll_interval).await;
         }
 
-        // Requirements are available, execute fulfillment
-        client.fulfill_outflow_via_gmp(&intent.intent_id, desired_token)
+        // Requirements are available, execute fulfillment. The CLI call blocks, so it
+        // runs off the runtime to keep concurrent fulfillments moving.
+        let Some(ConnectedChainConfig::Mvm(chain_config)) =
+            self.config.get_connected_chain_by_id(chain_id).cloned()
+        else {
+            anyhow::bail!("No MVM chain config for chain ID {}", chain_id);
+        };
+        let intent_id = intent.intent_id.clone();
+        let desired_token = desired_token.clone();
+        tokio::task::spawn_blocking(move || {
+            let client = ConnectedMvmClient::new(&chain_config)?;
+            client.fulfill_outflow_via_gmp(&intent_id, &desired_token)
+        })
+        .await
+        .context("Failed to spawn blocking task for MVM GMP fulfillment")?
     }
 
     /// Waits for GMP IntentRequirements to arrive on the SVM connected chain,
@@ -360,8 +417,20 @@ impl OutflowService {
             tokio::time::sleep(poll_interval).await;
         }
 
-        // Requirements are available, execute fulfillment
-        client.fulfill_outflow_via_gmp(&intent.intent_id, desired_token)
+        // Requirements are available, execute fulfillment off the runtime (blocking script call)
+        let Some(ConnectedChainConfig::Evm(chain_config)) =
+            self.config.get_connected_chain_by_id(chain_id).cloned()
+        else {
+            anyhow::bail!("No EVM chain config for chain ID {}", chain_id);
+        };
+        let intent_id = intent.intent_id.clone();
+        let desired_token = desired_token.clone();
+        tokio::task::spawn_blocking(move || {
+            let client = ConnectedEvmClient::new(&chain_config)?;
+            client.fulfill_outflow_via_gmp(&intent_id, &desired_token)
+        })
+        .await
+        .context("Failed to spawn blocking task for EVM GMP fulfillment")?
     }
 
     /// Waits for FulfillmentProof to be delivered to the hub via GMP, then calls
@@ -440,22 +509,27 @@ impl OutflowService {
     /// # Returns
     ///
     /// * `Ok(String)` - Transaction hash from connected chain fulfillment
-    /// * `Err(anyhow::Error)` - Failed to execute transfer
+    /// * `Err(SolverError)` - Failed to execute transfer; `RpcTimeout` is worth retrying
     async fn execute_connected_transfer(
         &self,
         intent: &TrackedIntent,
         _recipient: &str,
-    ) -> Result<String> {
+    ) -> SolverResult<String> {
         // Determine target chain based on intent's desired_chain_id
-        let (chain_type, chain_id) = self.get_target_chain_for_intent(intent)
-            .context("No configured connected chain matches intent's desired_chain_id")?;
-
-        match chain_type {
+        let (chain_type, chain_id) = self.get_target_chain_for_intent(intent).ok_or_else(|| {
+            SolverError::ConfigError(format!(
+                "No configured connected chain matches intent's desired_chain_id {}",
+                intent.draft_data.desired_chain_id
+            ))
+        })?;
+
+        let result = match chain_type {
             "mvm" => self.execute_mvm_gmp_fulfillment(intent, chain_id).await,
             "evm" => self.execute_evm_gmp_fulfillment(intent, chain_id).await,
             "svm" => self.execute_svm_gmp_fulfillment(intent, chain_id).await,
-            _ => anyhow::bail!("Unknown chain type: {}", chain_type),
-        }
+            _ => return Err(SolverError::ConfigError(format!("Unknown chain type: {}", chain_type))),
+        };
+        result.map_err(SolverError::from_fulfillment)
     }
 
     /// Gets the requester's address on the connected chain from the intent object
@@ -474,6 +548,67 @@ impl OutflowService {
             .context("requester_addr_connected_chain not set. This may happen if the intent is inflow (not outflow) or the event data didn't include this field.")
     }
 
+    /// Claims outflow intents on hub whose connected-chain fulfillment already succeeded
+    ///
+    /// Covers intents fulfilled in this polling cycle as well as intents recovered after a
+    /// restart between the connected-chain fulfillment and the hub claim. A failed claim
+    /// goes through `record_outflow_failure`, so it is retried with backoff and moves to
+    /// `Failed` once retries are exhausted.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(TrackedIntent, String)>` - List of (intent, hub_transaction_hash) tuples
+    pub async fn claim_pending_outflows(&self) -> Vec<(TrackedIntent, String)> {
+        let awaiting = self.tracker.get_outflow_intents_awaiting_hub_claim().await;
+        let current_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut claimed = Vec::new();
+
+        for intent in awaiting {
+            // Skip intents in backoff period
+            if intent.next_retry_after > current_time {
+                tracing::debug!(
+                    "Skipping hub claim for outflow intent {}: in backoff (retry after {}, now {})",
+                    intent.intent_id, intent.next_retry_after, current_time
+                );
+                continue;
+            }
+
+            info!(
+                "Waiting for FulfillmentProof delivery to hub for outflow intent {}",
+                intent.intent_id
+            );
+            match self.wait_for_proof_and_fulfill_hub(&intent).await {
+                Ok(hub_tx_hash) => {
+                    info!(
+                        "Successfully fulfilled outflow intent {} on hub: hub_tx={}",
+                        intent.intent_id, hub_tx_hash
+                    );
+                    if let Err(e) = self.tracker.mark_fulfilled(&intent.draft_id).await {
+                        error!("Failed to mark intent {} as fulfilled: {}", intent.draft_id, e);
+                    }
+                    // Release liquidity budget for this draft
+                    self.liquidity_monitor.release(&intent.draft_id).await;
+                    claimed.push((intent, hub_tx_hash));
+                }
+                Err(e) => {
+                    let error_msg = format!("{:#}", e);
+                    error!(
+                        "Failed to complete hub fulfillment for outflow intent {}: {}",
+                        intent.intent_id, error_msg
+                    );
+                    if let Err(record_err) = self.tracker.record_outflow_failure(&intent.intent_id, &error_msg).await {
+                        error!("Failed to record outflow failure for intent {}: {}", intent.intent_id, record_err);
+                    }
+                }
+            }
+        }
+
+        claimed
+    }
+
     /// Main service loop that continuously processes outflow intents
     ///
     /// This loop:
@@ -492,30 +627,10 @@ impl OutflowService {
             match self.poll_and_execute_transfers().await {
                 Ok(executed_transfers) => {
                     for (intent, tx_hash) in executed_transfers {
-                        // All chains use GMP: wait for FulfillmentProof, then claim on hub
                         info!(
-                            "Connected chain fulfillment complete for outflow intent {} (tx={}), waiting for FulfillmentProof delivery to hub",
+                            "Connected chain fulfillment complete for outflow intent {} (tx={})",
                             intent.intent_id, tx_hash
                         );
-                        match self.wait_for_proof_and_fulfill_hub(&intent).await {
-                            Ok(hub_tx_hash) => {
-                                info!(
-                                    "Successfully fulfilled outflow intent {} on hub: hub_tx={}",
-                                    intent.intent_id, hub_tx_hash
-                                );
-                                if let Err(e) = self.tracker.mark_fulfilled(&intent.draft_id).await {
-                                    error!("Failed to mark intent {} as fulfilled: {}", intent.draft_id, e);
-                                }
-                                // Release liquidity budget for this draft
-                                self.liquidity_monitor.release(&intent.draft_id).await;
-                            }
-                            Err(e) => {
-                                error!(
-                                    "Failed to complete hub fulfillment for outflow intent {}: {}",
-                                    intent.intent_id, e
-                                );
-                            }
-                        }
                     }
                 }
                 Err(e) => {
@@ -523,6 +638,9 @@ impl OutflowService {
                 }
             }
 
+            // All chains use GMP: wait for FulfillmentProof, then claim on hub
+            self.claim_pending_outflows().await;
+
             tokio::time::sleep(polling_interval).await;
         }
     }
@@ -0,0 +1,93 @@
+//! Reservation Ledger Persistence
+//!
+//! Persists the liquidity monitor's in-flight budget reservations to a JSON file so
+//! they survive a solver restart. Without it, a restart forgets every reservation and
+//! the solver may accept drafts against capital that is already committed.
+//!
+//! Entries are keyed by draft ID (the key the signing service reserves under) and
+//! record wall-clock reservation time, so the in-flight timeout keeps counting across
+//! restarts.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// One persisted in-flight reservation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedReservation {
+    /// Draft ID the budget was reserved for
+    pub draft_id: String,
+    /// Chain the reserved token lives on
+    pub chain_id: u64,
+    /// Reserved token address
+    pub token: String,
+    /// Reserved amount
+    pub amount: u64,
+    /// When the reservation was made (Unix timestamp, seconds)
+    pub reserved_at: u64,
+}
+
+/// On-disk file layout.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LedgerFile {
+    reservations: Vec<PersistedReservation>,
+}
+
+/// Reads and writes the reservation ledger file.
+#[derive(Debug, Clone)]
+pub struct ReservationLedger {
+    path: PathBuf,
+}
+
+impl ReservationLedger {
+    /// Creates a ledger backed by `path`. The file is created on first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the ledger file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads persisted reservations.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PersistedReservation>)` - Stored reservations (empty if the file does not exist yet)
+    /// * `Err(anyhow::Error)` - File exists but could not be read or parsed
+    pub fn load(&self) -> Result<Vec<PersistedReservation>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path).with_context(|| {
+            format!("Failed to read reservation ledger '{}'", self.path.display())
+        })?;
+        let file: LedgerFile = serde_json::from_str(&content).with_context(|| {
+            format!("Failed to parse reservation ledger '{}'", self.path.display())
+        })?;
+        Ok(file.reservations)
+    }
+
+    /// Replaces the ledger contents with `reservations`.
+    ///
+    /// Writes to a temporary file and renames it over the ledger, so a crash mid-write
+    /// leaves the previous ledger intact.
+    pub fn save(&self, reservations: &[PersistedReservation]) -> Result<()> {
+        let file = LedgerFile {
+            reservations: reservations.to_vec(),
+        };
+        let content = serde_json::to_string_pretty(&file)
+            .context("Failed to serialize reservation ledger")?;
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        std::fs::write(&tmp_path, content).with_context(|| {
+            format!("Failed to write reservation ledger '{}'", self.path.display())
+        })?;
+        std::fs::rename(&tmp_path, &self.path).with_context(|| {
+            format!("Failed to replace reservation ledger '{}'", self.path.display())
+        })?;
+        Ok(())
+    }
+}
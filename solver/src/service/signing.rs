@@ -6,6 +6,7 @@
 use crate::acceptance::{evaluate_draft_acceptance, AcceptanceConfig, AcceptanceResult, DraftintentData};
 use crate::config::SolverConfig;
 use crate::crypto::{get_intent_hash, get_private_key_from_profile, sign_intent_hash};
+use crate::error::{SolverError, SolverResult};
 use crate::service::liquidity::{ChainToken, LiquidityMonitor};
 use crate::service::tracker::IntentTracker;
 use crate::coordinator_client::{PendingDraft, CoordinatorClient};
@@ -92,6 +93,40 @@ impl SigningService {
         }
     }
 
+    /// Run the heartbeat loop.
+    ///
+    /// Sends a heartbeat signed with the solver's hub key to the coordinator at the
+    /// configured interval, so a coordinator that tracks solver liveness keeps
+    /// assigning drafts to this solver. Failed heartbeats are logged and retried
+    /// on the next tick.
+    ///
+    /// Runs indefinitely until the service is stopped.
+    pub async fn run_heartbeat(&self) -> Result<()> {
+        let heartbeat_interval = Duration::from_millis(self.config.service.heartbeat_interval_ms);
+
+        info!("Starting heartbeat loop (interval: {:?})", heartbeat_interval);
+
+        loop {
+            let base_url = self.config.service.coordinator_url.clone();
+            let profile = self.config.solver.profile.clone();
+            let solver_hub_addr = self.config.solver.address.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let private_key = load_solver_private_key(&profile)?;
+                let client = CoordinatorClient::new(&base_url).with_request_signing_key(private_key);
+                client.send_heartbeat(&solver_hub_addr)
+            })
+            .await
+            .context("Failed to spawn blocking task")?;
+
+            match result {
+                Ok(response) => debug!("Heartbeat recorded (last_seen: {})", response.last_seen),
+                Err(e) => warn!("Failed to send heartbeat: {:#}", e),
+            }
+
+            tokio::time::sleep(heartbeat_interval).await;
+        }
+    }
+
     /// Poll coordinator for pending drafts and process them.
     ///
     /// # Returns
@@ -142,6 +177,10 @@ impl SigningService {
                     processed_set.insert(draft.draft_id.clone());
                     debug!("Draft {} was not accepted or already signed", draft.draft_id);
                 }
+                Err(e) if e.is_retriable() => {
+                    // Leave unprocessed so the next poll retries it (until the draft expires)
+                    warn!("Transient error processing draft {} (will retry): {}", draft.draft_id, e);
+                }
                 Err(e) => {
                     error!("Error processing draft {}: {}", draft.draft_id, e);
                     // Mark as processed even on error to avoid repeated error logs
@@ -164,8 +203,9 @@ impl SigningService {
     ///
     /// # Returns
     ///
-    /// * `Result<bool>` - `true` if draft was signed and submitted, `false` otherwise
-    pub async fn process_draft(&self, draft: &PendingDraft) -> Result<bool> {
+    /// * `Ok(bool)` - `true` if draft was signed and submitted, `false` otherwise
+    /// * `Err(SolverError)` - Why processing failed; `RpcTimeout` is worth retrying
+    pub async fn process_draft(&self, draft: &PendingDraft) -> SolverResult<bool> {
         // Check if draft has expired
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -178,7 +218,9 @@ impl SigningService {
         }
 
         // Parse draft data
-        let draft_data = self.parse_draft_data(&draft.draft_data)?;
+        let draft_data = self
+            .parse_draft_data(&draft.draft_data)
+            .map_err(|e| SolverError::IntentRejected(format!("{:#}", e)))?;
 
         // Evaluate acceptance
         match evaluate_draft_acceptance(&draft_data, &self.acceptance_config) {
@@ -189,7 +231,12 @@ impl SigningService {
                     token: draft_data.desired_token.clone(),
                 };
 
-                if !self.liquidity_monitor.has_budget_after_spend(&target, draft_data.desired_amount).await? {
+                let has_budget = self
+                    .liquidity_monitor
+                    .has_budget_after_spend(&target, draft_data.desired_amount)
+                    .await
+                    .map_err(config_error)?;
+                if !has_budget {
                     warn!(
                         "Draft {} rejected: insufficient budget for {} on chain {} token {} (must retain threshold after spend)",
                         draft.draft_id, draft_data.desired_amount, target.chain_id, target.token
@@ -198,8 +245,11 @@ impl SigningService {
                 }
 
                 // A) Target chain: gas token must be above threshold
-                let gas_target = self.liquidity_monitor.gas_token_for_chain(draft_data.desired_chain_id)?;
-                if !self.liquidity_monitor.is_above_threshold(&gas_target).await? {
+                let gas_target = self
+                    .liquidity_monitor
+                    .gas_token_for_chain(draft_data.desired_chain_id)
+                    .map_err(config_error)?;
+                if !self.liquidity_monitor.is_above_threshold(&gas_target).await.map_err(config_error)? {
                     warn!(
                         "Draft {} rejected: gas token below threshold on target chain {}",
                         draft.draft_id, draft_data.desired_chain_id
@@ -208,8 +258,11 @@ impl SigningService {
                 }
 
                 // B) Source chain: gas token must be above threshold
-                let gas_source = self.liquidity_monitor.gas_token_for_chain(draft_data.offered_chain_id)?;
-                if !self.liquidity_monitor.is_above_threshold(&gas_source).await? {
+                let gas_source = self
+                    .liquidity_monitor
+                    .gas_token_for_chain(draft_data.offered_chain_id)
+                    .map_err(config_error)?;
+                if !self.liquidity_monitor.is_above_threshold(&gas_source).await.map_err(config_error)? {
                     warn!(
                         "Draft {} rejected: gas token below threshold on source chain {}",
                         draft.draft_id, draft_data.offered_chain_id
@@ -262,12 +315,13 @@ impl SigningService {
     ///
     /// # Returns
     ///
-    /// * `Result<bool>` - `true` if signature was successfully submitted, `false` if already signed (FCFS)
+    /// * `Ok(bool)` - `true` if signature was successfully submitted, `false` if already signed (FCFS)
+    /// * `Err(SolverError)` - `SigningFailed`, `ConfigError`, or the classified submission error
     async fn sign_and_submit(
         &self,
         draft: &PendingDraft,
         draft_data: &DraftintentData,
-    ) -> Result<bool> {
+    ) -> SolverResult<bool> {
         // Get solver profile and address from config
         let profile = self.config.solver.profile.clone();
         let solver_hub_addr = self.config.solver.address.clone();
@@ -275,7 +329,7 @@ impl SigningService {
         // Get module address and chain number from hub chain config
         let module_addr = self.config.hub_chain.module_addr
             .strip_prefix("0x")
-            .context("Module address must start with 0x")?
+            .ok_or_else(|| SolverError::ConfigError("Module address must start with 0x".to_string()))?
             .to_string();
         let hub_rpc_url = self.config.hub_chain.rpc_url.clone();
 
@@ -293,22 +347,8 @@ impl SigningService {
         let e2e_mode = self.config.service.e2e_mode;
 
         // Get private key, intent hash, and sign - all blocking operations
-        let (signature_hex, public_key_hex) = tokio::task::spawn_blocking(move || -> Result<(String, String)> {
-            // Get private key - check env var first (testnet mode), fall back to profile (E2E mode)
-            let private_key = if let Ok(key_str) = std::env::var("MOVEMENT_SOLVER_PRIVATE_KEY") {
-                let key_hex = key_str.strip_prefix("0x").unwrap_or(&key_str);
-                let key_bytes = hex::decode(key_hex)
-                    .context("Failed to decode MOVEMENT_SOLVER_PRIVATE_KEY from hex")?;
-                if key_bytes.len() != 32 {
-                    anyhow::bail!("MOVEMENT_SOLVER_PRIVATE_KEY must be 32 bytes (64 hex chars)");
-                }
-                let mut key_array = [0u8; 32];
-                key_array.copy_from_slice(&key_bytes);
-                key_array
-            } else {
-                get_private_key_from_profile(&profile)
-                    .context("Failed to get private key from profile or MOVEMENT_SOLVER_PRIVATE_KEY env var")?
-            };
+        let (signature_hex, public_key_hex, private_key) = tokio::task::spawn_blocking(move || -> Result<(String, String, [u8; 32])> {
+            let private_key = load_solver_private_key(&profile)?;
 
             // Get intent hash
             let hash = get_intent_hash(
@@ -337,11 +377,11 @@ impl SigningService {
             let signature_hex = hex::encode(signature_bytes);
             let public_key_hex = hex::encode(public_key_bytes);
 
-            Ok((signature_hex, public_key_hex))
+            Ok((signature_hex, public_key_hex, private_key))
         })
         .await
         .context("Failed to spawn blocking task for signing")?
-        .map_err(|e| anyhow::anyhow!("Signing failed: {:?}", e))?;
+        .map_err(|e| SolverError::SigningFailed(format!("{:#}", e)))?;
 
         // Get solver address again for submission
         let solver_hub_addr = self.config.solver.address.clone();
@@ -357,7 +397,7 @@ impl SigningService {
             public_key: public_key_hex,
         };
         let result = tokio::task::spawn_blocking(move || {
-            let client = CoordinatorClient::new(&base_url);
+            let client = CoordinatorClient::new(&base_url).with_request_signing_key(private_key);
             client.submit_signature(&draft_id_for_submit, &submission)
         })
         .await
@@ -386,13 +426,39 @@ impl SigningService {
                     warn!("Draft {} already signed by another solver (FCFS)", draft_id_for_log);
                     Ok(false)
                 } else {
-                    Err(e).context("Failed to submit signature")
+                    Err(SolverError::from_rpc(e.context("Failed to submit signature")))
                 }
             }
         }
     }
 }
 
+/// Load the solver's hub private key.
+///
+/// Checks the `MOVEMENT_SOLVER_PRIVATE_KEY` env var first (testnet mode) and falls back
+/// to the CLI profile (E2E mode). Blocking: call from `spawn_blocking`.
+fn load_solver_private_key(profile: &str) -> Result<[u8; 32]> {
+    if let Ok(key_str) = std::env::var("MOVEMENT_SOLVER_PRIVATE_KEY") {
+        let key_hex = key_str.strip_prefix("0x").unwrap_or(&key_str);
+        let key_bytes = hex::decode(key_hex)
+            .context("Failed to decode MOVEMENT_SOLVER_PRIVATE_KEY from hex")?;
+        if key_bytes.len() != 32 {
+            anyhow::bail!("MOVEMENT_SOLVER_PRIVATE_KEY must be 32 bytes (64 hex chars)");
+        }
+        let mut key_array = [0u8; 32];
+        key_array.copy_from_slice(&key_bytes);
+        Ok(key_array)
+    } else {
+        get_private_key_from_profile(profile)
+            .context("Failed to get private key from profile or MOVEMENT_SOLVER_PRIVATE_KEY env var")
+    }
+}
+
+/// Classifies a liquidity lookup failure, which means the chain or token is not configured.
+fn config_error(error: anyhow::Error) -> SolverError {
+    SolverError::ConfigError(format!("{:#}", error))
+}
+
 /// Parse draft data from JSON value.
 ///
 /// Extracts intent fields from the JSON structure returned by the coordinator.
@@ -9,10 +9,18 @@
 //!    The tracker detects this via `poll_for_created_intents()` and updates state to Created.
 //! 3. **Fulfilled Intent (Fulfilled state)**: Intent has been fulfilled by the solver.
 //!
+//! Outflow intents pass through one more step inside `Created`: once the connected-chain
+//! fulfillment (which sends the GMP FulfillmentProof) succeeds, `outflow_attempted` is set
+//! and the intent waits for the hub claim.
+//!
 //! The tracker distinguishes between inflow and outflow intents for fulfillment routing.
+//!
+//! When `service.intent_store_path` is set, every transition is written to an
+//! [`IntentStore`] and `recover()` reloads in-flight intents on startup.
 
 use anyhow::{Context, Result};
 use chain_clients_common::normalize_intent_id_to_64_chars;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -20,6 +28,7 @@ use tokio::sync::RwLock;
 use crate::acceptance::DraftintentData;
 use crate::chains::HubChainClient;
 use crate::config::{ChainConfig, SolverConfig};
+use crate::service::intent_store::IntentStore;
 
 /// Maximum number of outflow fulfillment attempts before transitioning to Failed
 pub const MAX_OUTFLOW_RETRIES: u32 = 3;
@@ -28,7 +37,7 @@ pub const MAX_OUTFLOW_RETRIES: u32 = 3;
 const INITIAL_BACKOFF_SECS: u64 = 5;
 
 /// State of a tracked intent
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IntentState {
     /// Draft-intent has been signed and submitted to coordinator, waiting for on-chain intent creation
     Signed,
@@ -42,8 +51,15 @@ pub enum IntentState {
     Failed,
 }
 
+impl IntentState {
+    /// Whether the intent has left the active lifecycle (Fulfilled, Expired or Failed)
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, IntentState::Fulfilled | IntentState::Expired | IntentState::Failed)
+    }
+}
+
 /// A tracked intent with its state and metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrackedIntent {
     /// Draft ID from coordinator
     pub draft_id: String,
@@ -83,6 +99,8 @@ pub struct IntentTracker {
     hub_client: HubChainClient,
     /// Hub chain configuration
     hub_config: ChainConfig,
+    /// Persistent store for in-flight intents (None keeps intents in memory only)
+    store: Option<IntentStore>,
 }
 
 impl IntentTracker {
@@ -106,9 +124,56 @@ impl IntentTracker {
             processed_transactions: Arc::new(RwLock::new(std::collections::HashSet::new())),
             hub_client,
             hub_config: config.hub_chain.clone(),
+            store: config.service.intent_store_path.as_ref().map(IntentStore::new),
         })
     }
 
+    /// Reloads in-flight intents from the intent store
+    ///
+    /// Called once at service startup, before the service loops run. Restored intents
+    /// resume from their persisted state: Signed intents wait for on-chain creation again,
+    /// Created intents keep their retry count and backoff, and outflow intents whose
+    /// connected-chain fulfillment already succeeded go straight to the hub claim.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - Number of intents restored (0 when no store is configured)
+    /// * `Err(anyhow::Error)` - Store file exists but could not be read
+    pub async fn recover(&self) -> Result<usize> {
+        let Some(ref store) = self.store else {
+            return Ok(0);
+        };
+        let restored = store.load()?;
+        let count = restored.len();
+
+        {
+            let mut intents = self.intents.write().await;
+            let mut addresses = self.requester_addresses.write().await;
+            for intent in restored {
+                if intent.state.is_terminal() {
+                    continue;
+                }
+                addresses.insert(intent.requester_addr.clone());
+                intents.insert(intent.draft_id.clone(), intent);
+            }
+        }
+
+        tracing::info!("Recovered {} intent(s) from store {}", count, store.path().display());
+        Ok(count)
+    }
+
+    /// Write all tracked intents to the store, if one is configured.
+    ///
+    /// Failures are logged and not propagated; the in-memory state stays authoritative.
+    fn persist(&self, intents: &HashMap<String, TrackedIntent>) {
+        let Some(ref store) = self.store else {
+            return;
+        };
+        if let Err(e) = store.save(intents.values()) {
+            tracing::error!("Failed to persist intent store: {:#}", e);
+        }
+    }
+
     /// Adds a signed draftintent to tracking
     ///
     /// Called after successfully submitting a signature to the coordinator.
@@ -159,6 +224,7 @@ impl IntentTracker {
 
         let mut intents = self.intents.write().await;
         intents.insert(draft_id, tracked);
+        self.persist(&intents);
 
         Ok(())
     }
@@ -279,6 +345,7 @@ impl IntentTracker {
 
         if updated_count > 0 {
             tracing::info!("Updated {} intent(s) to Created state", updated_count);
+            self.persist(&intents);
         }
 
         Ok(updated_count)
@@ -339,6 +406,56 @@ impl IntentTracker {
             .collect()
     }
 
+    /// Gets inflow intents that are signed but not yet observed on the hub chain
+    ///
+    /// These are the intents whose escrow may already exist on a connected chain
+    /// before the hub intent is created.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<TrackedIntent>` - List of unexpired inflow intents in Signed state
+    pub async fn get_signed_inflow_intents(&self) -> Vec<TrackedIntent> {
+        let intents = self.intents.read().await;
+        let hub_chain_id = self.hub_config.chain_id;
+        let current_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        intents
+            .values()
+            .filter(|intent| {
+                intent.state == IntentState::Signed
+                    && intent.expiry_time >= current_time
+                    && intent.draft_data.desired_chain_id == hub_chain_id
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Gets outflow intents whose connected-chain fulfillment succeeded but whose hub
+    /// claim is still outstanding
+    ///
+    /// Includes intents recovered after a restart between the two steps.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<TrackedIntent>` - Created outflow intents with `outflow_attempted` set
+    pub async fn get_outflow_intents_awaiting_hub_claim(&self) -> Vec<TrackedIntent> {
+        let intents = self.intents.read().await;
+        let hub_chain_id = self.hub_config.chain_id;
+
+        intents
+            .values()
+            .filter(|intent| {
+                intent.state == IntentState::Created
+                    && intent.outflow_attempted
+                    && intent.draft_data.offered_chain_id == hub_chain_id
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Marks an intent as fulfilled
     ///
     /// # Arguments
@@ -375,6 +492,7 @@ impl IntentTracker {
                 tracing::debug!("Removed requester {} from tracking (no active intents)", requester);
             }
 
+            self.persist(&intents);
             Ok(())
         } else {
             anyhow::bail!("Intent not found: {}", draft_id)
@@ -391,6 +509,7 @@ impl IntentTracker {
         for (_draft_id, intent) in intents.iter_mut() {
             if intent.intent_id == intent_id {
                 intent.outflow_attempted = true;
+                self.persist(&intents);
                 return Ok(());
             }
         }
@@ -422,6 +541,7 @@ impl IntentTracker {
                         intent_id, intent.outflow_attempt_count, error
                     );
                     intent.state = IntentState::Failed;
+                    self.persist(&intents);
                     return Ok(IntentState::Failed);
                 }
 
@@ -439,7 +559,9 @@ impl IntentTracker {
                     intent_id, backoff_secs, error
                 );
 
-                return Ok(intent.state.clone());
+                let state = intent.state.clone();
+                self.persist(&intents);
+                return Ok(state);
             }
         }
         anyhow::bail!("Intent not found: {}", intent_id)
@@ -448,7 +570,8 @@ impl IntentTracker {
     /// Gets a tracked intent by draft ID
     ///
     /// # Note
-    /// This method is primarily for testing. In production, use `get_intents_ready_for_fulfillment()`.
+    /// Fulfillment services should use `get_intents_ready_for_fulfillment()`. Used directly
+    /// by tests and by liquidity reservation reconciliation.
     pub async fn get_intent(&self, draft_id: &str) -> Option<TrackedIntent> {
         let intents = self.intents.read().await;
         intents.get(draft_id).cloned()
@@ -462,6 +585,7 @@ impl IntentTracker {
         let mut intents = self.intents.write().await;
         if let Some(intent) = intents.get_mut(draft_id) {
             intent.state = state;
+            self.persist(&intents);
             Ok(())
         } else {
             anyhow::bail!("Intent not found: {}", draft_id)
@@ -513,6 +637,7 @@ impl IntentTracker {
 
         // Check if requester addresses should be removed (no active intents remaining)
         if !addresses_to_check.is_empty() {
+            self.persist(&intents);
             let mut requester_addresses = self.requester_addresses.write().await;
             for requester_addr in addresses_to_check {
                 let has_active = intents.values().any(|i| {
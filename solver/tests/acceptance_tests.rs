@@ -3,7 +3,7 @@
 //! These tests verify that the solver correctly evaluates draft intents
 //! based on token types and amounts.
 
-use solver::acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, TokenPairInfo, calculate_required_fee, convert_base_fee_in_move_to_offered, evaluate_draft_acceptance};
+use solver::acceptance::{AcceptanceConfig, AcceptanceResult, DraftintentData, RejectionReason, TokenPairInfo, calculate_profit_bps, calculate_required_fee, calculate_shortfall_bps, convert_base_fee_in_move_to_offered, evaluate_draft_acceptance};
 use std::collections::HashMap;
 
 #[path = "helpers.rs"]
@@ -26,7 +26,7 @@ fn test_config() -> AcceptanceConfig {
     // Token A -> Token B (1:1 rate, 0.5% fee)
     token_pairs.insert(
         create_default_token_pair(),
-        TokenPairInfo { rate: 1.0, fee_bps: 50, move_rate: 1.0 },
+        TokenPairInfo { rate: 1.0, fee_bps: 50, move_rate: 1.0, min_profit_bps: None, max_slippage_bps: 0 },
     );
 
     // Token A -> Token C (chain 2) (0.5 rate: 1 Token C = 0.5 Token A, cross-chain, 0.5% fee)
@@ -35,7 +35,7 @@ fn test_config() -> AcceptanceConfig {
             desired_token: DUMMY_TOKEN_ADDR_UNSUPPORTED.to_string(), // Different token address on chain 2 to test multiple token pairs
             ..create_default_token_pair()
         },
-        TokenPairInfo { rate: 0.5, fee_bps: 50, move_rate: 0.5 },
+        TokenPairInfo { rate: 0.5, fee_bps: 50, move_rate: 0.5, min_profit_bps: None, max_slippage_bps: 0 },
     );
 
     AcceptanceConfig {
@@ -128,7 +128,7 @@ fn test_config_with_fees() -> AcceptanceConfig {
     let mut token_pairs = HashMap::new();
     token_pairs.insert(
         create_default_token_pair(),
-        TokenPairInfo { rate: 1.0, fee_bps: 50, move_rate: 1.0 },
+        TokenPairInfo { rate: 1.0, fee_bps: 50, move_rate: 1.0, min_profit_bps: None, max_slippage_bps: 0 },
     );
     AcceptanceConfig { base_fee_in_move: 1000, token_pairs }
 }
@@ -229,7 +229,7 @@ fn test_fee_zero_accepted_when_no_fees() {
     let mut token_pairs = HashMap::new();
     token_pairs.insert(
         create_default_token_pair(),
-        TokenPairInfo { rate: 1.0, fee_bps: 0, move_rate: 1.0 },
+        TokenPairInfo { rate: 1.0, fee_bps: 0, move_rate: 1.0, min_profit_bps: None, max_slippage_bps: 0 },
     );
     let config = AcceptanceConfig {
         base_fee_in_move: 0,
@@ -242,4 +242,101 @@ fn test_fee_zero_accepted_when_no_fees() {
     assert!(matches!(evaluate_draft_acceptance(&draft, &config), AcceptanceResult::Accept));
 }
 
+// ============================================================================
+// SLIPPAGE AND PROFIT TESTS
+// ============================================================================
 
+/// Create a no-fee config with the given slippage tolerance and profit floor (1:1 rate)
+fn test_config_with_policy(max_slippage_bps: u64, min_profit_bps: Option<u64>, base_fee_in_move: u64) -> AcceptanceConfig {
+    let mut token_pairs = HashMap::new();
+    token_pairs.insert(
+        create_default_token_pair(),
+        TokenPairInfo { rate: 1.0, fee_bps: 0, move_rate: 1.0, min_profit_bps, max_slippage_bps },
+    );
+    AcceptanceConfig { base_fee_in_move, token_pairs }
+}
+
+/// Test that calculate_shortfall_bps and calculate_profit_bps round conservatively
+/// What is tested: Basis point helpers used by the slippage and profit checks
+/// Why: Rounding must never let a draft through that is outside the configured bounds
+#[test]
+fn test_bps_helpers() {
+    assert_eq!(calculate_shortfall_bps(1000000, 1000000), 0);
+    assert_eq!(calculate_shortfall_bps(1100000, 1000000), 0);
+    assert_eq!(calculate_shortfall_bps(990000, 1000000), 100);
+    // 100.01 bps short rounds up to 101
+    assert_eq!(calculate_shortfall_bps(989999, 1000000), 101);
+
+    assert_eq!(calculate_profit_bps(1010000, 1000000), 100);
+    // 99.99 bps profit rounds down to 99
+    assert_eq!(calculate_profit_bps(1009999, 1000000), 99);
+    assert_eq!(calculate_profit_bps(990000, 1000000), -100);
+}
+
+/// Test that a draft within the slippage tolerance is accepted
+/// What is tested: Rate check allows a shortfall up to max_slippage_bps
+/// Why: Solvers can tolerate small rate movements between quoting and signing
+#[test]
+fn test_slippage_within_tolerance_accepted() {
+    let config = test_config_with_policy(100, None, 0);
+    let draft = DraftintentData {
+        offered_amount: 990000, // Exactly 1% short of 1:1
+        fee_in_offered_token: 0,
+        ..create_default_draft_data()
+    };
+    assert!(matches!(evaluate_draft_acceptance(&draft, &config), AcceptanceResult::Accept));
+}
+
+/// Test that a draft beyond the slippage tolerance is rejected with the rate reason
+/// What is tested: Rate check rejects shortfalls above max_slippage_bps
+/// Why: The tolerance must be a hard bound, and the rejection must say why
+#[test]
+fn test_slippage_beyond_tolerance_rejected() {
+    let config = test_config_with_policy(100, None, 0);
+    let draft = DraftintentData {
+        offered_amount: 989999, // Just over 1% short of 1:1
+        fee_in_offered_token: 0,
+        ..create_default_draft_data()
+    };
+    match evaluate_draft_acceptance(&draft, &config) {
+        AcceptanceResult::Reject(RejectionReason::RateBelowRequired { slippage_bps, max_slippage_bps, .. }) => {
+            assert_eq!(slippage_bps, 101);
+            assert_eq!(max_slippage_bps, 100);
+        }
+        other => panic!("Expected RateBelowRequired, got {:?}", other),
+    }
+}
+
+/// Test that a draft just above the profit floor is accepted
+/// What is tested: Profit check with base fee as gas estimate (cost = 1000000 + 1000)
+/// Why: Drafts that clear min_profit_bps after gas must be signed
+#[test]
+fn test_profit_just_above_minimum_accepted() {
+    let config = test_config_with_policy(0, Some(50), 1000);
+    let draft = DraftintentData {
+        offered_amount: 1006005, // (1006005 - 1001000) * 10000 / 1001000 = 50 bps
+        fee_in_offered_token: 1000,
+        ..create_default_draft_data()
+    };
+    assert!(matches!(evaluate_draft_acceptance(&draft, &config), AcceptanceResult::Accept));
+}
+
+/// Test that a draft just below the profit floor is rejected with the profit reason
+/// What is tested: Profit check rejects drafts one unit short of min_profit_bps
+/// Why: Solvers must not sign trades that do not cover gas plus their margin
+#[test]
+fn test_profit_just_below_minimum_rejected() {
+    let config = test_config_with_policy(0, Some(50), 1000);
+    let draft = DraftintentData {
+        offered_amount: 1006004, // Rounds down to 49 bps
+        fee_in_offered_token: 1000,
+        ..create_default_draft_data()
+    };
+    match evaluate_draft_acceptance(&draft, &config) {
+        AcceptanceResult::Reject(RejectionReason::ProfitBelowMinimum { profit_bps, min_profit_bps }) => {
+            assert_eq!(profit_bps, 49);
+            assert_eq!(min_profit_bps, 50);
+        }
+        other => panic!("Expected ProfitBelowMinimum, got {:?}", other),
+    }
+}
@@ -38,6 +38,8 @@ fn create_test_config() -> SolverConfig {
                 ratio: 1.0,
                 fee_bps: 50,
                 move_rate: 1.0,
+                min_profit_bps: None,
+                max_slippage_bps: 0,
             }],
         },
         liquidity: liq,
@@ -126,6 +128,8 @@ fn test_config_validation_unknown_chain_id_in_token_pair() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let result = config.validate();
@@ -155,6 +159,8 @@ fn test_config_validation_rejects_svm_invalid_hex_length() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let result = config.validate();
@@ -184,6 +190,8 @@ fn test_config_validation_rejects_invalid_svm_base58_token() {
         ratio: 1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let result = config.validate();
@@ -204,6 +212,8 @@ fn test_config_validation_fee_bps_too_high() {
         ratio: 1.0,
         fee_bps: 10001,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let result = config.validate();
@@ -224,11 +234,58 @@ fn test_config_validation_fee_bps_max_accepted() {
         ratio: 1.0,
         fee_bps: 10000,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     assert!(config.validate().is_ok());
 }
 
+/// What is tested: SolverConfig::validate() rejects max_slippage_bps > 10000
+/// Why: A slippage tolerance above 100% is nonsensical
+#[test]
+fn test_config_validation_max_slippage_bps_too_high() {
+    let mut config = create_test_config();
+    config.acceptance.token_pairs = vec![TokenPairConfig {
+        source_chain_id: 1,
+        source_token: DUMMY_TOKEN_ADDR_HUB.to_string(),
+        target_chain_id: 2,
+        target_token: DUMMY_TOKEN_ADDR_MVMCON.to_string(),
+        ratio: 1.0,
+        fee_bps: 50,
+        move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 10001,
+    }];
+
+    let result = config.validate();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("max_slippage_bps"));
+}
+
+/// What is tested: SolverConfig::get_token_pairs() propagates the acceptance policy
+/// Why: min_profit_bps and max_slippage_bps must reach the acceptance logic unchanged
+#[test]
+fn test_get_token_pairs_with_policy() {
+    let mut config = create_test_config();
+    config.acceptance.token_pairs = vec![TokenPairConfig {
+        source_chain_id: 1,
+        source_token: DUMMY_TOKEN_ADDR_HUB.to_string(),
+        target_chain_id: 2,
+        target_token: DUMMY_TOKEN_ADDR_MVMCON.to_string(),
+        ratio: 1.0,
+        fee_bps: 50,
+        move_rate: 1.0,
+        min_profit_bps: Some(25),
+        max_slippage_bps: 100,
+    }];
+
+    let pairs = config.get_token_pairs().unwrap();
+    let info = pairs[&create_default_token_pair()];
+    assert_eq!(info.min_profit_bps, Some(25));
+    assert_eq!(info.max_slippage_bps, 100);
+}
+
 /// What is tested: SolverConfig::get_token_pairs() propagates fee params
 /// Why: Ensure base_fee_in_move and fee_bps are carried through correctly
 #[test]
@@ -243,6 +300,8 @@ fn test_get_token_pairs_with_fees() {
         ratio: 1.0,
         fee_bps: 100,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     assert_eq!(config.acceptance.base_fee_in_move, 500);
@@ -264,6 +323,8 @@ fn test_config_validation_negative_exchange_rate() {
         ratio: -1.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let result = config.validate();
@@ -284,6 +345,8 @@ fn test_config_validation_zero_exchange_rate() {
         ratio: 0.0,
         fee_bps: 50,
         move_rate: 1.0,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let result = config.validate();
@@ -323,6 +386,8 @@ fn test_get_token_pairs_multiple() {
         ratio: 0.5,
         fee_bps: 50,
         move_rate: 0.5,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     });
 
     let pairs = config.get_token_pairs().unwrap();
@@ -352,6 +417,8 @@ fn test_get_token_pairs_token_address() {
         ratio: 0.5,
         fee_bps: 50,
         move_rate: 0.5,
+        min_profit_bps: None,
+        max_slippage_bps: 0,
     }];
 
     let pairs = config.get_token_pairs().unwrap();
@@ -543,6 +610,7 @@ address = "0xccc"
     assert_eq!(config.service.coordinator_url, "http://127.0.0.1:3333");
     assert_eq!(config.hub_chain.chain_id, 1);
     assert_eq!(config.acceptance.token_pairs.len(), 1);
+    assert_eq!(config.service.heartbeat_interval_ms, 15_000); // Default when omitted
     
     // Cleanup
     std::env::remove_var("SOLVER_CONFIG_PATH");
@@ -561,3 +629,231 @@ fn test_config_load_file_not_found() {
     assert!(result.unwrap_err().to_string().contains("not found"));
 }
 
+
+/// What is tested: SolverConfig::load_from_path() reports the file and offending value on parse errors
+/// Why: A typo in a chain `type` must point at the file and key rather than a bare serde error
+#[test]
+fn test_config_load_rejects_unknown_chain_type() {
+    use std::fs;
+
+    let test_config_dir = ".tmp/test_config_unknown_type";
+    let test_config_file = format!("{}/solver.toml", test_config_dir);
+    fs::create_dir_all(test_config_dir).unwrap();
+    fs::write(
+        &test_config_file,
+        r#"
+[service]
+coordinator_url = "http://127.0.0.1:3333"
+polling_interval_ms = 2000
+
+[hub_chain]
+name = "hub-chain"
+rpc_url = "http://127.0.0.1:8080/v1"
+chain_id = 1
+module_addr = "0x1"
+profile = "hub-profile"
+
+[[connected_chain]]
+type = "cosmos"
+name = "connected-chain"
+"#,
+    )
+    .unwrap();
+
+    let err = SolverConfig::load_from_path(Some(test_config_file.as_str())).unwrap_err().to_string();
+
+    fs::remove_file(&test_config_file).unwrap();
+    fs::remove_dir(test_config_dir).unwrap();
+
+    assert!(err.contains(&test_config_file), "Error should name the file: {}", err);
+    assert!(err.contains("cosmos"), "Error should show the bad value: {}", err);
+}
+
+/// What is tested: SolverConfig::load_from_path() expands `${ENV_VAR}` references and names a missing one
+/// Why: RPC URLs vary per deployment and are set through the environment rather than edited into the file
+#[test]
+fn test_config_load_expands_env_vars() {
+    use std::fs;
+
+    let test_config_dir = ".tmp/test_config_env_vars";
+    let test_config_file = format!("{}/solver.toml", test_config_dir);
+    fs::create_dir_all(test_config_dir).unwrap();
+    let toml_content = |hub_rpc_url: &str| {
+        format!(
+            r#"
+[service]
+coordinator_url = "http://127.0.0.1:3333"
+polling_interval_ms = 2000
+
+[hub_chain]
+name = "hub-chain"
+rpc_url = "{hub_rpc_url}"
+chain_id = 1
+module_addr = "0x1"
+profile = "hub-profile"
+
+[[connected_chain]]
+type = "mvm"
+name = "connected-chain"
+rpc_url = "http://127.0.0.1:8082/v1"
+chain_id = 2
+module_addr = "0x2"
+profile = "connected-profile"
+
+[acceptance]
+base_fee_in_move = 1000000
+[[acceptance.tokenpair]]
+source_chain_id = 1
+source_token = "{hub_token}"
+target_chain_id = 2
+target_token = "{con_token}"
+ratio = 1.0
+fee_bps = 50
+move_rate = 1.0
+
+[liquidity]
+balance_poll_interval_ms = 10000
+in_flight_timeout_secs = 300
+
+[[liquidity.threshold]]
+chain_id = 2
+token = "{con_token}"
+min_balance = 100
+
+[[liquidity.threshold]]
+chain_id = 1
+token = "{gas}"
+min_balance = 100
+
+[[liquidity.threshold]]
+chain_id = 2
+token = "{gas}"
+min_balance = 100
+
+[solver]
+profile = "hub-profile"
+address = "0xccc"
+"#,
+            hub_token = DUMMY_TOKEN_ADDR_HUB,
+            con_token = DUMMY_TOKEN_ADDR_MVMCON,
+            gas = GAS_TOKEN_MVM,
+        )
+    };
+
+    std::env::set_var("SOLVER_TEST_HUB_HOST", "10.0.0.5");
+    fs::write(&test_config_file, toml_content("http://${SOLVER_TEST_HUB_HOST}:8080/v1")).unwrap();
+    let config = SolverConfig::load_from_path(Some(test_config_file.as_str())).unwrap();
+    assert_eq!(config.hub_chain.rpc_url, "http://10.0.0.5:8080/v1");
+
+    std::env::remove_var("SOLVER_TEST_MISSING_HUB_RPC");
+    fs::write(&test_config_file, toml_content("${SOLVER_TEST_MISSING_HUB_RPC}")).unwrap();
+    let err = SolverConfig::load_from_path(Some(test_config_file.as_str())).unwrap_err().to_string();
+
+    fs::remove_file(&test_config_file).unwrap();
+    fs::remove_dir(test_config_dir).unwrap();
+
+    assert!(err.contains("SOLVER_TEST_MISSING_HUB_RPC"), "Error should name the variable: {}", err);
+    assert!(err.contains("hub_chain.rpc_url"), "Error should name the field: {}", err);
+}
+
+// ============================================================================
+// FIELD VALIDATION TESTS
+// ============================================================================
+
+/// What is tested: SolverConfig::validate() rejects a zero acceptance API port with its field path
+/// Why: Operators need to know which setting to fix, not just that the config is invalid
+#[test]
+fn test_config_validation_rejects_zero_acceptance_api_port() {
+    let mut config = create_test_config();
+    config.service.acceptance_api_port = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("service.acceptance_api_port must be 1..65535, got 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: SolverConfig::validate() rejects a zero unmatched alert threshold with its field path
+/// Why: A zero threshold would alert on every escrow and intent before it had any chance to match
+#[test]
+fn test_config_validation_rejects_zero_unmatched_alert_threshold() {
+    let mut config = create_test_config();
+    config.service.unmatched_alert_threshold_secs = Some(0);
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("service.unmatched_alert_threshold_secs must be greater than 0, got 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: SolverConfig::validate() rejects zero max_concurrent_fulfillments with its field path
+/// Why: A zero bound would leave the fulfillment services unable to process any intent
+#[test]
+fn test_config_validation_rejects_zero_max_concurrent_fulfillments() {
+    let mut config = create_test_config();
+    config.service.max_concurrent_fulfillments = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("service.max_concurrent_fulfillments must be greater than 0, got 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: SolverConfig::validate() rejects zero heartbeat_interval_ms with its field path
+/// Why: A zero interval would send heartbeats to the coordinator in a tight loop
+#[test]
+fn test_config_validation_rejects_zero_heartbeat_interval() {
+    let mut config = create_test_config();
+    config.service.heartbeat_interval_ms = 0;
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("service.heartbeat_interval_ms must be greater than 0, got 0"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: SolverConfig::validate() rejects a coordinator URL without a scheme
+/// Why: reqwest would otherwise fail on every draft poll with an opaque builder error
+#[test]
+fn test_config_validation_rejects_coordinator_url_without_scheme() {
+    let mut config = create_test_config();
+    config.service.coordinator_url = "127.0.0.1:3333".to_string();
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("service.coordinator_url must start with http:// or https://, got '127.0.0.1:3333'"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
+/// What is tested: SolverConfig::validate() names the connected chain index and the missing field
+/// Why: With several connected chains, the error must point at the right section
+#[test]
+fn test_config_validation_rejects_missing_svm_private_key_env() {
+    let mut config = create_test_config();
+    config.connected_chain.push(ConnectedChainConfig::Svm(SvmChainConfig {
+        name: "connected-svm-chain".to_string(),
+        rpc_url: "http://127.0.0.1:8899".to_string(),
+        chain_id: 4,
+        escrow_program_id: DUMMY_SVM_ESCROW_PROGRAM_ID.to_string(),
+        private_key_env: String::new(),
+        gmp_endpoint_program_id: None,
+        outflow_validator_program_id: None,
+    }));
+
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("connected_chain[1].private_key_env must not be empty"),
+        "Unexpected error: {}",
+        err
+    );
+}
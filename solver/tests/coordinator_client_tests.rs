@@ -3,6 +3,7 @@
 use serde_json::json;
 use solver::{
     ApiResponse, CoordinatorClient, PendingDraft, SignatureSubmission, SignatureSubmissionResponse,
+    SolverHeartbeat,
 };
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -395,6 +396,152 @@ fn test_submit_signature_other_error() {
     );
 }
 
+/// What is tested: submit_signature() signs a body that names the draft and carries a current timestamp
+/// Why: The coordinator rejects signed submissions whose body is for another draft or stale, so a captured request cannot be replayed
+#[test]
+fn test_submit_signature_signed_for_draft() {
+    use ed25519_dalek::{Signature, SigningKey, Verifier};
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let (mock_server, base_url) = rt.block_on(async {
+        let mock_server = MockServer::start().await;
+
+        let response = json!({
+            "success": true,
+            "data": {
+                "draft_id": DUMMY_DRAFT_ID,
+                "status": "signed"
+            },
+            "error": null
+        });
+
+        Mock::given(method("POST"))
+            .and(path(format!("/draftintent/{}/signature", DUMMY_DRAFT_ID)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&mock_server)
+            .await;
+
+        let base_url = mock_server.uri().to_string();
+        (mock_server, base_url)
+    });
+
+    let private_key = [7u8; 32];
+    let client = CoordinatorClient::new(base_url).with_request_signing_key(private_key);
+    let submission = SignatureSubmission {
+        solver_hub_addr: DUMMY_SOLVER_ADDR_HUB.to_string(),
+        signature: "0x".to_string() + &"a".repeat(128),
+        public_key: "0x".to_string() + &"b".repeat(64),
+    };
+    client.submit_signature(DUMMY_DRAFT_ID, &submission).unwrap();
+
+    let requests = rt.block_on(mock_server.received_requests()).unwrap();
+    assert_eq!(requests.len(), 1);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["solver_hub_addr"], DUMMY_SOLVER_ADDR_HUB);
+    assert_eq!(body["draft_id"], DUMMY_DRAFT_ID);
+    assert!(body["timestamp"].as_u64().unwrap() > 0);
+
+    let (_, signature_values) = requests[0]
+        .headers
+        .iter()
+        .find(|(name, _)| name.as_str() == "x-solver-signature")
+        .expect("submission must carry an X-Solver-Signature header");
+    let signature_bytes: [u8; 64] = hex::decode(signature_values[0].as_str()).unwrap().try_into().unwrap();
+    SigningKey::from_bytes(&private_key)
+        .verifying_key()
+        .verify(&requests[0].body, &Signature::from_bytes(&signature_bytes))
+        .expect("submission signature must verify over the raw body");
+}
+
+
+// ----------------------------------------------------------------------------
+// send_heartbeat() tests
+// ----------------------------------------------------------------------------
+
+/// What is tested: send_heartbeat() posts a current timestamp signed with the request signing key
+/// Why: The coordinator only records heartbeats whose X-Solver-Signature verifies against the solver's registered key
+#[test]
+fn test_send_heartbeat_signed() {
+    use ed25519_dalek::{Signature, SigningKey, Verifier};
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let (mock_server, base_url) = rt.block_on(async {
+        let mock_server = MockServer::start().await;
+
+        let response = json!({
+            "success": true,
+            "data": {
+                "solver_hub_addr": DUMMY_SOLVER_ADDR_HUB,
+                "last_seen": 1000000
+            },
+            "error": null
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/solver/heartbeat"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&mock_server)
+            .await;
+
+        let base_url = mock_server.uri().to_string();
+        (mock_server, base_url)
+    });
+
+    let private_key = [7u8; 32];
+    let client = CoordinatorClient::new(base_url).with_request_signing_key(private_key);
+    let result = client.send_heartbeat(DUMMY_SOLVER_ADDR_HUB).unwrap();
+    assert_eq!(result.solver_hub_addr, DUMMY_SOLVER_ADDR_HUB);
+    assert_eq!(result.last_seen, 1000000);
+
+    let requests = rt.block_on(mock_server.received_requests()).unwrap();
+    assert_eq!(requests.len(), 1);
+    let heartbeat: SolverHeartbeat = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(heartbeat.solver_hub_addr, DUMMY_SOLVER_ADDR_HUB);
+    assert!(heartbeat.timestamp > 0);
+
+    let (_, signature_values) = requests[0]
+        .headers
+        .iter()
+        .find(|(name, _)| name.as_str() == "x-solver-signature")
+        .expect("heartbeat must carry an X-Solver-Signature header");
+    let signature_hex = signature_values[0].as_str();
+    let signature_bytes: [u8; 64] = hex::decode(signature_hex).unwrap().try_into().unwrap();
+    SigningKey::from_bytes(&private_key)
+        .verifying_key()
+        .verify(&requests[0].body, &Signature::from_bytes(&signature_bytes))
+        .expect("heartbeat signature must verify over the raw body");
+}
+
+/// What is tested: send_heartbeat() surfaces a coordinator rejection
+/// Why: The heartbeat loop logs rejected heartbeats (e.g. unregistered solver) instead of treating them as recorded
+#[test]
+fn test_send_heartbeat_rejected() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let (_mock_server, base_url) = rt.block_on(async {
+        let mock_server = MockServer::start().await;
+
+        let response = json!({
+            "success": false,
+            "data": null,
+            "error": "Request signature does not match the solver's registered key"
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/solver/heartbeat"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(response))
+            .mount(&mock_server)
+            .await;
+
+        let base_url = mock_server.uri().to_string();
+        (mock_server, base_url)
+    });
+
+    let client = CoordinatorClient::new(base_url).with_request_signing_key([7u8; 32]);
+    let result = client.send_heartbeat(DUMMY_SOLVER_ADDR_HUB);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("registered key"));
+}
 
 // ----------------------------------------------------------------------------
 // Error handling tests
@@ -0,0 +1,59 @@
+//! Unit tests for solver error classification
+//!
+//! Tests how errors from chain clients and CLI calls are mapped to `SolverError`
+//! variants, which the service loops use to decide whether to retry.
+
+use solver::SolverError;
+
+// ============================================================================
+// CLASSIFICATION TESTS
+// ============================================================================
+
+/// What is tested: SolverError::from_rpc() maps timeout errors to RpcTimeout
+/// Why: The signing loop retries a draft on the next poll only for retriable errors
+#[test]
+fn test_from_rpc_classifies_timeouts() {
+    let io_timeout = anyhow::Error::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "read"))
+        .context("Failed to submit signature");
+    assert!(matches!(SolverError::from_rpc(io_timeout), SolverError::RpcTimeout(_)));
+
+    let message_timeout = anyhow::anyhow!("error sending request: operation timed out");
+    let err = SolverError::from_rpc(message_timeout);
+    assert!(err.is_retriable());
+
+    let other = SolverError::from_rpc(anyhow::anyhow!("HTTP 500 Internal Server Error"));
+    assert!(matches!(other, SolverError::Other(_)));
+    assert!(!other.is_retriable());
+}
+
+/// What is tested: SolverError::from_fulfillment() separates on-chain failures from timeouts
+/// Why: A reverted fulfillment counts against the retry budget, a timeout does not
+#[test]
+fn test_from_fulfillment_classifies_reverts() {
+    let reverted = anyhow::anyhow!("Hardhat fulfill-outflow-intent script failed:\nstderr: execution reverted: E_EXPIRED");
+    let err = SolverError::from_fulfillment(reverted);
+    assert!(matches!(err, SolverError::FulfillmentReverted(_)));
+    assert!(!err.is_retriable());
+    assert!(err.to_string().contains("E_EXPIRED"));
+
+    let abort = anyhow::anyhow!("aptos move run failed:\nstderr: Move abort in 0x1::intent: E_ESCROW_NOT_CONFIRMED");
+    let err = SolverError::from_fulfillment(abort);
+    assert!(matches!(err, SolverError::FulfillmentReverted(_)));
+    assert!(err.to_string().contains("E_ESCROW_NOT_CONFIRMED"));
+
+    // A CLI failure caused by a timeout is retriable, not a revert
+    let timed_out = anyhow::anyhow!("aptos move run failed:\nstderr: request timed out");
+    assert!(SolverError::from_fulfillment(timed_out).is_retriable());
+
+    let other = SolverError::from_fulfillment(anyhow::anyhow!("No MVM client for chain ID 9"));
+    assert!(matches!(other, SolverError::Other(_)));
+}
+
+/// What is tested: SolverError converts into anyhow::Error and keeps its message
+/// Why: The binary and non-core callers still propagate errors with anyhow
+#[test]
+fn test_solver_error_converts_to_anyhow() {
+    let err: anyhow::Error = SolverError::IntentRejected("Missing or invalid intent_id".to_string()).into();
+    assert_eq!(err.to_string(), "Intent rejected: Missing or invalid intent_id");
+    assert!(err.downcast_ref::<SolverError>().is_some());
+}
@@ -0,0 +1,168 @@
+//! Unit tests for bounded concurrent fulfillment
+
+use solver::acceptance::DraftintentData;
+use solver::service::fulfillment_pool::signer_account_key;
+use solver::service::tracker::{IntentState, TrackedIntent};
+use solver::service::FulfillmentPool;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[path = "helpers.rs"]
+mod test_helpers;
+use test_helpers::{
+    DUMMY_INTENT_ID, DUMMY_REQUESTER_ADDR_HUB, DUMMY_TOKEN_ADDR_EVM, DUMMY_TOKEN_ADDR_HUB,
+    DUMMY_TOKEN_ADDR_MVMCON,
+};
+
+/// How long each simulated fulfillment takes
+const FULFILLMENT_TIME: Duration = Duration::from_millis(50);
+
+// ============================================================================
+// HELPER FUNCTIONS
+// ============================================================================
+
+/// Records how many simulated fulfillments run at once, overall and per account.
+#[derive(Default)]
+struct ConcurrencyProbe {
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+    account_in_flight: Mutex<HashMap<String, usize>>,
+    max_account_in_flight: Mutex<HashMap<String, usize>>,
+}
+
+impl ConcurrencyProbe {
+    /// Simulates one fulfillment spending from `account`.
+    async fn fulfill(&self, account: String) -> String {
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+        {
+            let mut per_account = self.account_in_flight.lock().unwrap();
+            let count = per_account.entry(account.clone()).or_default();
+            *count += 1;
+            let mut max = self.max_account_in_flight.lock().unwrap();
+            let max_count = max.entry(account.clone()).or_default();
+            *max_count = (*max_count).max(*count);
+        }
+
+        tokio::time::sleep(FULFILLMENT_TIME).await;
+
+        *self.account_in_flight.lock().unwrap().get_mut(&account).unwrap() -= 1;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        account
+    }
+
+    fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    fn max_account_in_flight(&self, account: &str) -> usize {
+        self.max_account_in_flight.lock().unwrap()[account]
+    }
+}
+
+/// Creates a created intent that pays out `desired_token` on `desired_chain_id`.
+fn create_intent(draft_id: &str, desired_chain_id: u64, desired_token: &str) -> TrackedIntent {
+    TrackedIntent {
+        draft_id: draft_id.to_string(),
+        intent_id: DUMMY_INTENT_ID.to_string(),
+        state: IntentState::Created,
+        draft_data: DraftintentData {
+            intent_id: DUMMY_INTENT_ID.to_string(),
+            offered_token: DUMMY_TOKEN_ADDR_HUB.to_string(),
+            offered_amount: 1000000,
+            offered_chain_id: 1,
+            desired_token: desired_token.to_string(),
+            desired_amount: 1000000,
+            desired_chain_id,
+            fee_in_offered_token: 0,
+        },
+        requester_addr: DUMMY_REQUESTER_ADDR_HUB.to_string(),
+        expiry_time: 0,
+        intent_addr: None,
+        requester_addr_connected_chain: None,
+        outflow_attempted: false,
+        outflow_attempt_count: 0,
+        next_retry_after: 0,
+    }
+}
+
+// ============================================================================
+// FULFILLMENT POOL TESTS
+// ============================================================================
+
+/// What is tested: Independent fulfillments run concurrently up to the bound and no further
+/// Why: The bound is the operator's throughput knob; exceeding it would overload RPCs and CLIs
+#[tokio::test]
+async fn test_independent_fulfillments_run_concurrently_up_to_bound() {
+    let pool = FulfillmentPool::new(3);
+    let probe = ConcurrencyProbe::default();
+    let accounts: Vec<String> = (0..6).map(|i| format!("{}:{}", i, DUMMY_TOKEN_ADDR_HUB)).collect();
+
+    let results = pool
+        .run_all(accounts.clone(), |account| account.clone(), |account| probe.fulfill(account))
+        .await;
+
+    assert_eq!(results, accounts, "results must come back in input order");
+    assert_eq!(probe.max_in_flight(), 3);
+}
+
+/// What is tested: Fulfillments spending from the same token account never overlap
+/// Why: Concurrent transfers from one account race on its balance and the chain nonce
+#[tokio::test]
+async fn test_same_account_fulfillments_do_not_overlap() {
+    let pool = FulfillmentPool::new(4);
+    let probe = ConcurrencyProbe::default();
+    let shared = format!("1:{}", DUMMY_TOKEN_ADDR_HUB);
+    let accounts = vec![
+        shared.clone(),
+        format!("2:{}", DUMMY_TOKEN_ADDR_MVMCON),
+        shared.clone(),
+        format!("3:{}", DUMMY_TOKEN_ADDR_EVM),
+    ];
+
+    pool.run_all(accounts, |account| account.clone(), |account| probe.fulfill(account))
+        .await;
+
+    assert_eq!(probe.max_account_in_flight(&shared), 1);
+    // The other accounts still ran alongside the shared one
+    assert_eq!(probe.max_in_flight(), 3);
+}
+
+/// What is tested: A pool bound of 1 runs fulfillments one at a time
+/// Why: max_concurrent_fulfillments = 1 (the default) must keep the previous sequential behavior
+#[tokio::test]
+async fn test_bound_of_one_is_sequential() {
+    let pool = FulfillmentPool::new(1);
+    let probe = ConcurrencyProbe::default();
+    let accounts: Vec<String> = (0..3).map(|i| format!("{}:{}", i, DUMMY_TOKEN_ADDR_HUB)).collect();
+
+    pool.run_all(accounts, |account| account.clone(), |account| probe.fulfill(account))
+        .await;
+
+    assert_eq!(pool.max_in_flight(), 1);
+    assert_eq!(probe.max_in_flight(), 1);
+}
+
+/// What is tested: Intents with different tokens on the same chain share one signer lock
+/// Why: The solver signs every transaction on a chain with one account, so different
+/// tokens on that chain still race on its nonce or sequence number
+#[tokio::test]
+async fn test_same_chain_different_tokens_do_not_overlap() {
+    let pool = FulfillmentPool::new(4);
+    let probe = ConcurrencyProbe::default();
+    let intents = vec![
+        create_intent("draft-1", 31337, DUMMY_TOKEN_ADDR_EVM),
+        create_intent("draft-2", 31337, "0x000000000000000000000000000000000000000b"),
+        create_intent("draft-3", 2, DUMMY_TOKEN_ADDR_MVMCON),
+    ];
+    assert_eq!(signer_account_key(&intents[0]), signer_account_key(&intents[1]));
+
+    pool.run_all(intents, signer_account_key, |intent| probe.fulfill(signer_account_key(&intent)))
+        .await;
+
+    assert_eq!(probe.max_account_in_flight("31337"), 1);
+    // The other chain still ran alongside
+    assert_eq!(probe.max_in_flight(), 2);
+}
@@ -138,9 +138,14 @@ pub fn create_default_service_config() -> solver::config::ServiceConfig {
     solver::config::ServiceConfig {
         coordinator_url: "http://127.0.0.1:3333".to_string(),
         polling_interval_ms: 2000,
+        heartbeat_interval_ms: 15_000,
         e2e_mode: false,
         acceptance_api_host: "127.0.0.1".to_string(),
         acceptance_api_port: 4444,
+        unmatched_alert_threshold_secs: None,
+        alert_webhook_url: None,
+        intent_store_path: None,
+        max_concurrent_fulfillments: 1,
     }
 }
 
@@ -205,6 +210,7 @@ pub fn create_default_liquidity_config() -> solver::config::LiquidityMonitorConf
     solver::config::LiquidityMonitorConfig {
         balance_poll_interval_ms: 10_000,
         in_flight_timeout_secs: 300,
+        reservation_ledger_path: None,
         thresholds: Vec::new(),
     }
 }
@@ -217,6 +223,7 @@ pub fn create_mvm_pair_liquidity_config() -> solver::config::LiquidityMonitorCon
     solver::config::LiquidityMonitorConfig {
         balance_poll_interval_ms: 10_000,
         in_flight_timeout_secs: 300,
+        reservation_ledger_path: None,
         thresholds: vec![
             // Target token on hub chain (for inflow: solver spends USDhub)
             solver::config::LiquidityThresholdConfig {
@@ -0,0 +1,56 @@
+//! Unit tests for unmatched escrow/intent tracking
+
+use solver::service::mismatch::{MismatchKind, MismatchTracker};
+
+#[path = "helpers.rs"]
+mod test_helpers;
+use test_helpers::DUMMY_INTENT_ID;
+
+/// Alert threshold used by the tests (seconds)
+const THRESHOLD_SECS: u64 = 60;
+
+/// Time of the first observation (Unix seconds)
+const FIRST_SEEN: u64 = 1_000_000;
+
+// ============================================================================
+// MISMATCH TRACKER TESTS
+// ============================================================================
+
+/// What is tested: An unmatched escrow past the threshold alerts exactly once and is cleared on match
+/// Why: Persistent cross-chain linking failures must page an operator, but repeated polls must not
+/// spam the alert, and a late match must clear it
+#[test]
+fn test_unmatched_escrow_alerts_once_and_clears_on_match() {
+    let tracker = MismatchTracker::new(THRESHOLD_SECS);
+
+    tracker.observe(DUMMY_INTENT_ID, MismatchKind::EscrowWithoutIntent, FIRST_SEEN);
+    assert!(tracker.due_alerts(FIRST_SEEN + THRESHOLD_SECS - 1).is_empty());
+
+    // Later polls keep observing the same escrow without resetting its age
+    tracker.observe(DUMMY_INTENT_ID, MismatchKind::EscrowWithoutIntent, FIRST_SEEN + 30);
+    let alerts = tracker.due_alerts(FIRST_SEEN + THRESHOLD_SECS);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].intent_id, DUMMY_INTENT_ID);
+    assert_eq!(alerts[0].kind, MismatchKind::EscrowWithoutIntent);
+    assert_eq!(alerts[0].age_secs, THRESHOLD_SECS);
+
+    tracker.observe(DUMMY_INTENT_ID, MismatchKind::EscrowWithoutIntent, FIRST_SEEN + 90);
+    assert!(tracker.due_alerts(FIRST_SEEN + 2 * THRESHOLD_SECS).is_empty());
+
+    assert!(tracker.resolve(DUMMY_INTENT_ID));
+    assert!(tracker.is_empty());
+    assert!(tracker.due_alerts(FIRST_SEEN + 10 * THRESHOLD_SECS).is_empty());
+}
+
+/// What is tested: Entries dropped by retain() never alert
+/// Why: An intent that expires or is fulfilled elsewhere is no longer a linking failure
+#[test]
+fn test_retain_drops_entries_without_alerting() {
+    let tracker = MismatchTracker::new(THRESHOLD_SECS);
+
+    tracker.observe(DUMMY_INTENT_ID, MismatchKind::IntentWithoutEscrow, FIRST_SEEN);
+    tracker.retain(|_, _| false);
+
+    assert!(tracker.is_empty());
+    assert!(tracker.due_alerts(FIRST_SEEN + THRESHOLD_SECS).is_empty());
+}
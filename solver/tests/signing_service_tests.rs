@@ -59,6 +59,8 @@ fn create_test_solver_config() -> solver::config::SolverConfig {
                 ratio: 0.5,
                 fee_bps: 50,
                 move_rate: 0.5,
+                min_profit_bps: None,
+                max_slippage_bps: 0,
             }],
         },
         liquidity: liq,
@@ -126,6 +126,8 @@ fn create_mock_escrow_response(is_claimed: bool) -> serde_json::Value {
         reserved_solver: Pubkey::default(),
         intent_id: [0u8; 32],
         bump: 255,
+        memo: [0u8; 32],
+        mode: 0,
     };
 
     // Serialize to borsh and base64-encode
@@ -623,3 +623,108 @@ async fn test_outflow_failure_backoff_increases_exponentially() {
     assert!(second_retry_after > first_retry_after);
 }
 
+
+// ============================================================================
+// INTENT STORE / RECOVERY TESTS
+// ============================================================================
+
+/// Per-test intent store file in the temp directory.
+fn intent_store_path(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "solver-intents-{}-{}.json",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+/// Create a tracker persisting intents to `path`.
+fn create_tracker_with_store(path: &std::path::Path) -> IntentTracker {
+    let mut config = create_default_solver_config();
+    config.service.intent_store_path = Some(path.to_string_lossy().to_string());
+    IntentTracker::new(&config).unwrap()
+}
+
+/// What is tested: recover() is a no-op without a configured intent store
+/// Why: Solvers that do not set intent_store_path keep the in-memory behaviour
+#[tokio::test]
+async fn test_recover_without_store() {
+    let config = create_default_solver_config();
+    let tracker = IntentTracker::new(&config).unwrap();
+    assert_eq!(tracker.recover().await.unwrap(), 0);
+}
+
+/// What is tested: A crash after a transient connected-chain failure resumes with retry state intact
+/// Why: The retry count and backoff must survive a restart, or a failing outflow is retried forever
+#[tokio::test]
+async fn test_recover_after_crash_during_fulfillment() {
+    let path = intent_store_path("fulfilling");
+
+    {
+        let tracker = create_tracker_with_store(&path);
+        tracker
+            .add_signed_intent(
+                DUMMY_DRAFT_ID.to_string(),
+                create_default_draft_data_outflow(),
+                DUMMY_REQUESTER_ADDR_EVM.to_string(),
+                DUMMY_EXPIRY,
+            )
+            .await
+            .unwrap();
+        tracker.set_intent_state(DUMMY_DRAFT_ID, IntentState::Created).await.unwrap();
+        tracker.record_outflow_failure(DUMMY_INTENT_ID, "rpc timeout").await.unwrap();
+        // Simulated crash: tracker dropped without a clean shutdown
+    }
+
+    let restarted = create_tracker_with_store(&path);
+    assert_eq!(restarted.recover().await.unwrap(), 1);
+
+    let tracked = restarted.get_intent(DUMMY_DRAFT_ID).await.unwrap();
+    assert_eq!(tracked.state, IntentState::Created);
+    assert_eq!(tracked.outflow_attempt_count, 1);
+    assert!(tracked.next_retry_after > 0);
+    assert!(!tracked.outflow_attempted);
+    assert_eq!(restarted.get_intents_ready_for_fulfillment(Some(false)).await.len(), 1);
+    assert!(restarted.get_outflow_intents_awaiting_hub_claim().await.is_empty());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// What is tested: A crash between the connected-chain fulfillment and the hub claim resumes at the claim
+/// Why: The GMP proof is already sent; the solver must claim on hub without fulfilling twice
+#[tokio::test]
+async fn test_recover_after_crash_between_fulfillment_and_claim() {
+    let path = intent_store_path("proof-sent");
+
+    {
+        let tracker = create_tracker_with_store(&path);
+        tracker
+            .add_signed_intent(
+                DUMMY_DRAFT_ID.to_string(),
+                create_default_draft_data_outflow(),
+                DUMMY_REQUESTER_ADDR_EVM.to_string(),
+                DUMMY_EXPIRY,
+            )
+            .await
+            .unwrap();
+        tracker.set_intent_state(DUMMY_DRAFT_ID, IntentState::Created).await.unwrap();
+        tracker.mark_outflow_attempted(DUMMY_INTENT_ID).await.unwrap();
+        // Simulated crash before the hub claim
+    }
+
+    let restarted = create_tracker_with_store(&path);
+    assert_eq!(restarted.recover().await.unwrap(), 1);
+
+    let awaiting = restarted.get_outflow_intents_awaiting_hub_claim().await;
+    assert_eq!(awaiting.len(), 1);
+    assert_eq!(awaiting[0].draft_id, DUMMY_DRAFT_ID);
+    assert!(awaiting[0].outflow_attempted);
+
+    // Once claimed, the intent is dropped from the store
+    restarted.mark_fulfilled(DUMMY_DRAFT_ID).await.unwrap();
+    let after_claim = create_tracker_with_store(&path);
+    assert_eq!(after_claim.recover().await.unwrap(), 0);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -1174,8 +1174,12 @@ else
     deployed_status=$(check_solana_program "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL"); mark "$deployed_status"
     # ConfigAccount: disc=1 base64=AQ==, size=38
     config_pda=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "AQ==" 38); mark "$config_pda"
-    # RemoteGmpEndpoint: disc=3 base64=Aw==, size=38
-    remote_gmp_endpoint=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "Aw==" 38); mark "$remote_gmp_endpoint"
+    # RemoteGmpEndpoint: disc=3 base64=Aw==, size=42 without type overrides (38 for legacy accounts)
+    remote_gmp_endpoint=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "Aw==" 42)
+    if [ "$remote_gmp_endpoint" != "✅" ]; then
+        remote_gmp_endpoint=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "Aw==" 38)
+    fi
+    mark "$remote_gmp_endpoint"
     # RoutingConfig: disc=6 base64=Bg==, size=66
     routing_cfg=$(check_solana_has_account "$SOLANA_GMP_PROGRAM_ID" "$SOLANA_RPC_URL" "Bg==" 66); mark "$routing_cfg"
     print_check "Deployed" "$deployed_status" "" "      " "program executable on-chain"